@@ -1,5 +1,5 @@
 use super::{
-    config::{Config, EventType},
+    config::{Config, EventType, ResourceType},
     InMemoryCache,
 };
 
@@ -22,6 +22,20 @@ impl InMemoryCacheBuilder {
         InMemoryCache::new_with_config(self.0)
     }
 
+    /// Sets whether to index guild emojis by name.
+    ///
+    /// When enabled, custom emojis can be looked up by their name within a
+    /// guild via [`InMemoryCache::emoji_by_name`].
+    ///
+    /// Defaults to false.
+    ///
+    /// [`InMemoryCache::emoji_by_name`]: struct.InMemoryCache.html#method.emoji_by_name
+    pub fn emoji_name_index(mut self, emoji_name_index: bool) -> Self {
+        self.0.emoji_name_index = emoji_name_index;
+
+        self
+    }
+
     /// Sets the list of event types for the cache to handle.
     ///
     /// Defaults to all types.
@@ -39,6 +53,21 @@ impl InMemoryCacheBuilder {
 
         self
     }
+
+    /// Sets the types of resources for the cache to store.
+    ///
+    /// Events are still processed for the enabled resources, so for example
+    /// members are cached from a [`GuildCreate`] event even if guilds
+    /// themselves are not.
+    ///
+    /// Defaults to all types.
+    ///
+    /// [`GuildCreate`]: ../twilight_model/gateway/payload/struct.GuildCreate.html
+    pub fn resource_types(mut self, resource_types: ResourceType) -> Self {
+        self.0.resource_types = resource_types;
+
+        self
+    }
 }
 
 #[cfg(test)]
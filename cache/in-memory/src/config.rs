@@ -37,6 +37,27 @@ bitflags! {
         const VOICE_SERVER_UPDATE = 1 << 30;
         const VOICE_STATE_UPDATE = 1 << 31;
         const WEBHOOKS_UPDATE = 1 << 32;
+        const REACTION_REMOVE_EMOJI = 1 << 33;
+        const THREAD_CREATE = 1 << 34;
+        const THREAD_DELETE = 1 << 35;
+        const THREAD_UPDATE = 1 << 36;
+    }
+}
+
+bitflags! {
+    /// Bitflags of the types of resources stored in the cache.
+    pub struct ResourceType: u64 {
+        /// Guild channels, private channels and groups.
+        const CHANNEL = 1;
+        const EMOJI = 1 << 1;
+        const GUILD = 1 << 2;
+        const MEMBER = 1 << 3;
+        const MESSAGE = 1 << 4;
+        const PRESENCE = 1 << 5;
+        const ROLE = 1 << 6;
+        /// Users, including the current user.
+        const USER = 1 << 7;
+        const VOICE_STATE = 1 << 8;
     }
 }
 
@@ -45,11 +66,24 @@ bitflags! {
 /// [`InMemoryCache`]: struct.InMemoryCache.html
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Config {
+    pub(super) emoji_name_index: bool,
     pub(super) event_types: EventType,
     pub(super) message_cache_size: usize,
+    pub(super) resource_types: ResourceType,
 }
 
 impl Config {
+    /// Returns whether guild emojis are indexed by name.
+    pub fn emoji_name_index(&self) -> bool {
+        self.emoji_name_index
+    }
+
+    /// Returns a mutable reference to whether guild emojis are indexed by
+    /// name.
+    pub fn emoji_name_index_mut(&mut self) -> &mut bool {
+        &mut self.emoji_name_index
+    }
+
     /// Returns an immutable reference to the event types enabled.
     pub fn event_types(&self) -> EventType {
         self.event_types
@@ -69,20 +103,32 @@ impl Config {
     pub fn message_cache_size_mut(&mut self) -> &mut usize {
         &mut self.message_cache_size
     }
+
+    /// Returns an immutable reference to the resource types enabled.
+    pub fn resource_types(&self) -> ResourceType {
+        self.resource_types
+    }
+
+    /// Returns a mutable reference to the resource types enabled.
+    pub fn resource_types_mut(&mut self) -> &mut ResourceType {
+        &mut self.resource_types
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            emoji_name_index: false,
             event_types: EventType::all(),
             message_cache_size: 100,
+            resource_types: ResourceType::all(),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, EventType};
+    use super::{Config, EventType, ResourceType};
 
     #[test]
     #[allow(clippy::cognitive_complexity)]
@@ -120,21 +166,47 @@ mod tests {
         assert_eq!(1 << 30, EventType::VOICE_SERVER_UPDATE.bits());
         assert_eq!(1 << 31, EventType::VOICE_STATE_UPDATE.bits());
         assert_eq!(1 << 32, EventType::WEBHOOKS_UPDATE.bits());
+        assert_eq!(1 << 33, EventType::REACTION_REMOVE_EMOJI.bits());
+        assert_eq!(1 << 34, EventType::THREAD_CREATE.bits());
+        assert_eq!(1 << 35, EventType::THREAD_DELETE.bits());
+        assert_eq!(1 << 36, EventType::THREAD_UPDATE.bits());
+    }
+
+    #[test]
+    fn test_resource_type_const_values() {
+        assert_eq!(1, ResourceType::CHANNEL.bits());
+        assert_eq!(1 << 1, ResourceType::EMOJI.bits());
+        assert_eq!(1 << 2, ResourceType::GUILD.bits());
+        assert_eq!(1 << 3, ResourceType::MEMBER.bits());
+        assert_eq!(1 << 4, ResourceType::MESSAGE.bits());
+        assert_eq!(1 << 5, ResourceType::PRESENCE.bits());
+        assert_eq!(1 << 6, ResourceType::ROLE.bits());
+        assert_eq!(1 << 7, ResourceType::USER.bits());
+        assert_eq!(1 << 8, ResourceType::VOICE_STATE.bits());
     }
 
     #[test]
     fn test_defaults() {
         let conf = Config {
+            emoji_name_index: false,
             event_types: EventType::all(),
             message_cache_size: 100,
+            resource_types: ResourceType::all(),
         };
         let default = Config::default();
+        assert_eq!(conf.emoji_name_index, default.emoji_name_index);
         assert_eq!(conf.event_types, default.event_types);
         assert_eq!(conf.message_cache_size, default.message_cache_size);
+        assert_eq!(conf.resource_types, default.resource_types);
     }
 
     #[test]
     fn test_config_fields() {
-        static_assertions::assert_fields!(Config: event_types, message_cache_size);
+        static_assertions::assert_fields!(
+            Config: emoji_name_index,
+            event_types,
+            message_cache_size,
+            resource_types
+        );
     }
 }
@@ -0,0 +1,393 @@
+use super::{
+    config::ResourceType,
+    model::{CachedEmoji, CachedGuild, CachedMember, CachedMessage, CachedPresence, CachedRole},
+    InMemoryCache,
+};
+use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Write,
+    sync::Arc,
+};
+use twilight_model::{
+    channel::{Group, GuildChannel, PrivateChannel},
+    id::GuildId,
+    user::{CurrentUser, User},
+    voice::VoiceState,
+};
+
+/// Exporting the state of the cache failed.
+///
+/// This is returned from [`InMemoryCache::export`].
+///
+/// [`InMemoryCache::export`]: struct.InMemoryCache.html#method.export
+#[derive(Debug)]
+pub struct ExportError {
+    kind: ExportErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ExportError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ExportErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ExportErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            ExportErrorType::Serializing => f.write_str("failed to serialize the cache state"),
+            ExportErrorType::Writing => f.write_str("failed to write the cache state"),
+        }
+    }
+}
+
+impl Error for ExportError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ExportError`] that occurred.
+///
+/// [`ExportError`]: struct.ExportError.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ExportErrorType {
+    /// Serializing the cached resources failed.
+    Serializing,
+    /// Writing the serialized resources to the writer failed.
+    Writing,
+}
+
+/// Snapshot of the selected resources of the cache.
+///
+/// Resources that weren't selected are omitted rather than being empty, so
+/// that it's clear from the output what was exported. Each list is sorted by
+/// ID for stable output.
+#[derive(Default, Serialize)]
+struct Snapshot {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channels: Option<Vec<Arc<GuildChannel>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    current_user: Option<Arc<CurrentUser>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emojis: Option<Vec<Arc<CachedEmoji>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    groups: Option<Vec<Arc<Group>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    guilds: Option<Vec<Arc<CachedGuild>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    members: Option<Vec<Arc<CachedMember>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    messages: Option<Vec<Arc<CachedMessage>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presences: Option<Vec<Arc<CachedPresence>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private_channels: Option<Vec<Arc<PrivateChannel>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    roles: Option<Vec<Arc<CachedRole>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    users: Option<Vec<Arc<User>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    voice_states: Option<Vec<Arc<VoiceState>>>,
+}
+
+impl InMemoryCache {
+    /// Export the selected resources of the cache as pretty-printed JSON.
+    ///
+    /// If a guild ID is provided, then only resources belonging to that guild
+    /// are exported; private channels, groups, and the current user are then
+    /// omitted. Users are exported if they're known to be in the guild.
+    ///
+    /// This is intended for debugging, such as including the actual state of
+    /// the cache in a bug report. It's an O(n) operation over each selected
+    /// resource type, so avoid calling it frequently on large caches.
+    ///
+    /// # Examples
+    ///
+    /// Export the guilds and roles in the cache to stdout:
+    ///
+    /// ```
+    /// use twilight_cache_inmemory::{InMemoryCache, ResourceType};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let cache = InMemoryCache::new();
+    /// cache.export(
+    ///     std::io::stdout(),
+    ///     ResourceType::GUILD | ResourceType::ROLE,
+    ///     None,
+    /// )?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExportErrorType::Serializing`] if serializing the resources
+    /// failed.
+    ///
+    /// Returns [`ExportErrorType::Writing`] if writing to the writer failed.
+    ///
+    /// [`ExportErrorType::Serializing`]: enum.ExportErrorType.html#variant.Serializing
+    /// [`ExportErrorType::Writing`]: enum.ExportErrorType.html#variant.Writing
+    pub fn export(
+        &self,
+        writer: impl Write,
+        resource_types: ResourceType,
+        guild_id: Option<GuildId>,
+    ) -> Result<(), ExportError> {
+        let snapshot = self.snapshot(resource_types, guild_id);
+
+        serde_json::to_writer_pretty(writer, &snapshot).map_err(|source| {
+            let kind = if source.is_io() {
+                ExportErrorType::Writing
+            } else {
+                ExportErrorType::Serializing
+            };
+
+            ExportError {
+                kind,
+                source: Some(Box::new(source)),
+            }
+        })
+    }
+
+    fn snapshot(&self, resource_types: ResourceType, guild_id: Option<GuildId>) -> Snapshot {
+        let in_guild = |id: GuildId| guild_id.is_none() || guild_id == Some(id);
+        let mut snapshot = Snapshot::default();
+
+        if resource_types.contains(ResourceType::CHANNEL) {
+            let mut channels = self
+                .0
+                .channels_guild
+                .iter()
+                .filter(|item| in_guild(item.guild_id))
+                .map(|item| Arc::clone(&item.data))
+                .collect::<Vec<_>>();
+            channels.sort_by_key(|channel| channel.id());
+            snapshot.channels.replace(channels);
+
+            if guild_id.is_none() {
+                let mut groups = self
+                    .0
+                    .groups
+                    .iter()
+                    .map(|r| Arc::clone(r.value()))
+                    .collect::<Vec<_>>();
+                groups.sort_by_key(|group| group.id);
+                snapshot.groups.replace(groups);
+
+                let mut private_channels = self
+                    .0
+                    .channels_private
+                    .iter()
+                    .map(|r| Arc::clone(r.value()))
+                    .collect::<Vec<_>>();
+                private_channels.sort_by_key(|channel| channel.id);
+                snapshot.private_channels.replace(private_channels);
+            }
+        }
+
+        if resource_types.contains(ResourceType::EMOJI) {
+            let mut emojis = self
+                .0
+                .emojis
+                .iter()
+                .filter(|item| in_guild(item.guild_id))
+                .map(|item| Arc::clone(&item.data))
+                .collect::<Vec<_>>();
+            emojis.sort_by_key(|emoji| emoji.id);
+            snapshot.emojis.replace(emojis);
+        }
+
+        if resource_types.contains(ResourceType::GUILD) {
+            let mut guilds = self
+                .0
+                .guilds
+                .iter()
+                .filter(|r| in_guild(*r.key()))
+                .map(|r| Arc::clone(r.value()))
+                .collect::<Vec<_>>();
+            guilds.sort_by_key(|guild| guild.id);
+            snapshot.guilds.replace(guilds);
+        }
+
+        if resource_types.contains(ResourceType::MEMBER) {
+            let mut members = self
+                .0
+                .members
+                .iter()
+                .filter(|r| in_guild(r.key().0))
+                .map(|r| Arc::clone(r.value()))
+                .collect::<Vec<_>>();
+            members.sort_by_key(|member| (member.guild_id, member.user.id));
+            snapshot.members.replace(members);
+        }
+
+        if resource_types.contains(ResourceType::MESSAGE) {
+            let mut messages = self
+                .0
+                .messages
+                .iter()
+                .flat_map(|r| r.value().values().cloned().collect::<Vec<_>>())
+                .filter(|message| guild_id.is_none() || message.guild_id == guild_id)
+                .collect::<Vec<_>>();
+            messages.sort_by_key(|message| message.id);
+            snapshot.messages.replace(messages);
+        }
+
+        if resource_types.contains(ResourceType::PRESENCE) {
+            let mut presences = self
+                .0
+                .presences
+                .iter()
+                .filter(|r| in_guild(r.key().0))
+                .map(|r| (*r.key(), Arc::clone(r.value())))
+                .collect::<Vec<_>>();
+            presences.sort_by_key(|(key, _)| *key);
+            snapshot
+                .presences
+                .replace(presences.into_iter().map(|(_, p)| p).collect());
+        }
+
+        if resource_types.contains(ResourceType::ROLE) {
+            let mut roles = self
+                .0
+                .roles
+                .iter()
+                .filter(|item| in_guild(item.guild_id))
+                .map(|item| Arc::clone(&item.data))
+                .collect::<Vec<_>>();
+            roles.sort_by_key(|role| role.id);
+            snapshot.roles.replace(roles);
+        }
+
+        if resource_types.contains(ResourceType::USER) {
+            let mut users = self
+                .0
+                .users
+                .iter()
+                .filter(|r| match guild_id {
+                    Some(id) => r.value().1.contains(&id),
+                    None => true,
+                })
+                .map(|r| Arc::clone(&r.value().0))
+                .collect::<Vec<_>>();
+            users.sort_by_key(|user| user.id);
+            snapshot.users.replace(users);
+
+            if guild_id.is_none() {
+                snapshot.current_user = self.current_user();
+            }
+        }
+
+        if resource_types.contains(ResourceType::VOICE_STATE) {
+            let mut voice_states = self
+                .0
+                .voice_states
+                .iter()
+                .filter(|r| in_guild(r.key().0))
+                .map(|r| (*r.key(), Arc::clone(r.value())))
+                .collect::<Vec<_>>();
+            voice_states.sort_by_key(|(key, _)| *key);
+            snapshot
+                .voice_states
+                .replace(voice_states.into_iter().map(|(_, vs)| vs).collect());
+        }
+
+        snapshot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExportError, ExportErrorType};
+    use crate::{InMemoryCache, ResourceType};
+    use serde_json::Value;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        guild::{Permissions, Role},
+        id::{GuildId, RoleId},
+    };
+
+    assert_impl_all!(ExportErrorType: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ExportError: Debug, Error, Send, Sync);
+
+    fn role(id: RoleId) -> Role {
+        Role {
+            color: 0,
+            hoist: false,
+            id,
+            managed: false,
+            mentionable: false,
+            name: "test".to_owned(),
+            permissions: Permissions::empty(),
+            position: 0,
+        }
+    }
+
+    #[test]
+    fn test_export_selected_resources() {
+        let cache = InMemoryCache::new();
+        cache.cache_role(GuildId(1), role(RoleId(2)));
+        cache.cache_role(GuildId(3), role(RoleId(4)));
+
+        let mut buf = Vec::new();
+        cache.export(&mut buf, ResourceType::ROLE, None).unwrap();
+        let value = serde_json::from_slice::<Value>(&buf).unwrap();
+        let object = value.as_object().unwrap();
+
+        // Only the selected resource type is present.
+        assert_eq!(1, object.len());
+        assert_eq!(2, object["roles"].as_array().unwrap().len());
+    }
+
+    #[test]
+    fn test_export_guild_filter() {
+        let cache = InMemoryCache::new();
+        cache.cache_role(GuildId(1), role(RoleId(2)));
+        cache.cache_role(GuildId(3), role(RoleId(4)));
+
+        let mut buf = Vec::new();
+        cache
+            .export(&mut buf, ResourceType::all(), Some(GuildId(3)))
+            .unwrap();
+        let value = serde_json::from_slice::<Value>(&buf).unwrap();
+
+        let roles = value["roles"].as_array().unwrap();
+        assert_eq!(1, roles.len());
+        assert_eq!("4", roles[0]["id"]);
+        assert!(value.get("private_channels").is_none());
+        assert!(value["guilds"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_export_writing_error() {
+        let cache = InMemoryCache::new();
+        let mut buf = [0; 1];
+        let error = cache
+            .export(&mut buf[..], ResourceType::all(), None)
+            .unwrap_err();
+
+        assert_eq!(&ExportErrorType::Writing, error.kind());
+        assert!(error.into_source().is_some());
+    }
+}
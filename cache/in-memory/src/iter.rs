@@ -0,0 +1,166 @@
+use super::{
+    model::{CachedEmoji, CachedGuild, CachedMember, CachedMessage, CachedPresence, CachedRole},
+    InMemoryCache,
+};
+use dashmap::DashMap;
+use std::{hash::Hash, sync::Arc};
+use twilight_model::{
+    channel::{Group, GuildChannel, PrivateChannel},
+    user::User,
+    voice::VoiceState,
+};
+
+/// Iterators over the resources of an [`InMemoryCache`].
+///
+/// Each iterator is over a snapshot of the resources taken when the method is
+/// called, so no locks on the cache are held while iterating, and the cache
+/// may be updated in the meantime. Created via [`InMemoryCache::iter`].
+///
+/// # Examples
+///
+/// Find the guilds that don't have a role with a given name:
+///
+/// ```
+/// use twilight_cache_inmemory::InMemoryCache;
+///
+/// let cache = InMemoryCache::new();
+///
+/// let missing = cache
+///     .iter()
+///     .guilds()
+///     .filter(|guild| {
+///         cache
+///             .guild_roles(guild.id)
+///             .unwrap_or_default()
+///             .into_iter()
+///             .filter_map(|id| cache.role(id))
+///             .all(|role| &*role.name != "muted")
+///     })
+///     .count();
+///
+/// assert_eq!(0, missing);
+/// ```
+///
+/// [`InMemoryCache`]: struct.InMemoryCache.html
+/// [`InMemoryCache::iter`]: struct.InMemoryCache.html#method.iter
+#[derive(Clone, Debug)]
+pub struct InMemoryCacheIter<'a>(&'a InMemoryCache);
+
+impl<'a> InMemoryCacheIter<'a> {
+    pub(super) fn new(cache: &'a InMemoryCache) -> Self {
+        Self(cache)
+    }
+
+    /// Iterate over the cached guild channels.
+    pub fn channels(&self) -> impl Iterator<Item = Arc<GuildChannel>> {
+        snapshot(&self.0 .0.channels_guild, |item| Arc::clone(&item.data))
+    }
+
+    /// Iterate over the cached emojis.
+    pub fn emojis(&self) -> impl Iterator<Item = Arc<CachedEmoji>> {
+        snapshot(&self.0 .0.emojis, |item| Arc::clone(&item.data))
+    }
+
+    /// Iterate over the cached groups.
+    pub fn groups(&self) -> impl Iterator<Item = Arc<Group>> {
+        snapshot(&self.0 .0.groups, Arc::clone)
+    }
+
+    /// Iterate over the cached guilds.
+    pub fn guilds(&self) -> impl Iterator<Item = Arc<CachedGuild>> {
+        snapshot(&self.0 .0.guilds, Arc::clone)
+    }
+
+    /// Iterate over the cached members of all guilds.
+    pub fn members(&self) -> impl Iterator<Item = Arc<CachedMember>> {
+        snapshot(&self.0 .0.members, Arc::clone)
+    }
+
+    /// Iterate over the cached messages of all channels.
+    pub fn messages(&self) -> impl Iterator<Item = Arc<CachedMessage>> {
+        let mut messages = Vec::new();
+
+        for channel in self.0 .0.messages.iter() {
+            messages.extend(channel.value().values().cloned());
+        }
+
+        messages.into_iter()
+    }
+
+    /// Iterate over the cached presences of all guilds.
+    pub fn presences(&self) -> impl Iterator<Item = Arc<CachedPresence>> {
+        snapshot(&self.0 .0.presences, Arc::clone)
+    }
+
+    /// Iterate over the cached private channels.
+    pub fn private_channels(&self) -> impl Iterator<Item = Arc<PrivateChannel>> {
+        snapshot(&self.0 .0.channels_private, Arc::clone)
+    }
+
+    /// Iterate over the cached roles of all guilds.
+    pub fn roles(&self) -> impl Iterator<Item = Arc<CachedRole>> {
+        snapshot(&self.0 .0.roles, |item| Arc::clone(&item.data))
+    }
+
+    /// Iterate over the cached users.
+    pub fn users(&self) -> impl Iterator<Item = Arc<User>> {
+        snapshot(&self.0 .0.users, |(user, _)| Arc::clone(user))
+    }
+
+    /// Iterate over the cached voice states of all guilds.
+    pub fn voice_states(&self) -> impl Iterator<Item = Arc<VoiceState>> {
+        snapshot(&self.0 .0.voice_states, Arc::clone)
+    }
+}
+
+/// Collect the values of a map so that its locks aren't held while iterating.
+fn snapshot<K: Eq + Hash, V, T>(
+    map: &DashMap<K, V>,
+    f: impl Fn(&V) -> Arc<T>,
+) -> impl Iterator<Item = Arc<T>> {
+    map.iter()
+        .map(|entry| f(entry.value()))
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InMemoryCacheIter;
+    use crate::InMemoryCache;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        gateway::payload::RoleCreate,
+        guild::{Permissions, Role},
+        id::{GuildId, RoleId},
+    };
+
+    assert_impl_all!(InMemoryCacheIter<'_>: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_iter_roles() {
+        let cache = InMemoryCache::new();
+        assert_eq!(0, cache.iter().roles().count());
+
+        for id in 1..=2 {
+            cache.update(&RoleCreate {
+                guild_id: GuildId(1),
+                role: Role {
+                    color: 0,
+                    hoist: false,
+                    id: RoleId(id),
+                    managed: false,
+                    mentionable: false,
+                    name: "test".to_owned(),
+                    permissions: Permissions::empty(),
+                    position: 0,
+                },
+            });
+        }
+
+        let mut ids = cache.iter().roles().map(|role| role.id).collect::<Vec<_>>();
+        ids.sort();
+        assert_eq!(vec![RoleId(1), RoleId(2)], ids);
+    }
+}
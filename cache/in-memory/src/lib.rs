@@ -51,26 +51,37 @@ pub mod model;
 
 mod builder;
 mod config;
+mod export;
+mod iter;
+mod stats;
 mod updates;
+mod view;
 
 pub use self::{
     builder::InMemoryCacheBuilder,
-    config::{Config, EventType},
+    config::{Config, EventType, ResourceType},
+    export::{ExportError, ExportErrorType},
+    iter::InMemoryCacheIter,
+    stats::InMemoryCacheStats,
     updates::UpdateCache,
+    view::CacheView,
 };
 
 use self::model::*;
 use dashmap::{mapref::entry::Entry, DashMap, DashSet};
 use std::{
     borrow::Cow,
-    collections::{BTreeMap, BTreeSet, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     hash::Hash,
     sync::{Arc, Mutex},
 };
 use twilight_model::{
-    channel::{Group, GuildChannel, PrivateChannel},
+    channel::{
+        permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        Group, GuildChannel, PrivateChannel,
+    },
     gateway::presence::{Presence, UserOrId},
-    guild::{Emoji, Guild, Member, PartialMember, Role},
+    guild::{Emoji, Guild, Member, PartialMember, Permissions, Role},
     id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, UserId},
     user::{CurrentUser, User},
     voice::VoiceState,
@@ -139,13 +150,18 @@ struct InMemoryCacheRef {
     guilds: DashMap<GuildId, Arc<CachedGuild>>,
     guild_channels: DashMap<GuildId, HashSet<ChannelId>>,
     guild_emojis: DashMap<GuildId, HashSet<EmojiId>>,
+    /// Mapping of guilds and the names of their emojis, if enabled.
+    guild_emoji_names: DashMap<GuildId, HashMap<String, EmojiId>>,
     guild_members: DashMap<GuildId, HashSet<UserId>>,
     guild_presences: DashMap<GuildId, HashSet<UserId>>,
     guild_roles: DashMap<GuildId, HashSet<RoleId>>,
     members: DashMap<(GuildId, UserId), Arc<CachedMember>>,
     messages: DashMap<ChannelId, BTreeMap<MessageId, Arc<CachedMessage>>>,
     presences: DashMap<(GuildId, UserId), Arc<CachedPresence>>,
-    roles: DashMap<RoleId, GuildItem<Role>>,
+    roles: DashMap<RoleId, GuildItem<CachedRole>>,
+    /// Interned strings shared by many cached items, such as guild features
+    /// and role names.
+    strings: DashSet<Arc<str>>,
     unavailable_guilds: DashSet<GuildId>,
     users: DashMap<UserId, (Arc<User>, BTreeSet<GuildId>)>,
     /// Mapping of channels and the users currently connected.
@@ -227,6 +243,33 @@ impl InMemoryCache {
         (*self.0.config).clone()
     }
 
+    /// Create a read-only view of the cache.
+    ///
+    /// The view can't process events and reflects all updates made to this
+    /// cache.
+    pub fn view(&self) -> CacheView {
+        CacheView::from(self)
+    }
+
+    /// Create an interface for iterating over the resources in the cache.
+    ///
+    /// Refer to [`InMemoryCacheIter`] for more information.
+    ///
+    /// [`InMemoryCacheIter`]: struct.InMemoryCacheIter.html
+    pub fn iter(&self) -> InMemoryCacheIter<'_> {
+        InMemoryCacheIter::new(self)
+    }
+
+    /// Create an interface for retrieving the number of resources in the
+    /// cache.
+    ///
+    /// Refer to [`InMemoryCacheStats`] for more information.
+    ///
+    /// [`InMemoryCacheStats`]: struct.InMemoryCacheStats.html
+    pub fn stats(&self) -> InMemoryCacheStats<'_> {
+        InMemoryCacheStats::new(self)
+    }
+
     /// Update the cache with an event from the gateway.
     pub fn update(&self, value: &impl UpdateCache) {
         value.update(self);
@@ -264,6 +307,24 @@ impl InMemoryCache {
         self.0.emojis.get(&emoji_id).map(|x| Arc::clone(&x.data))
     }
 
+    /// Gets an emoji in a guild by its name.
+    ///
+    /// This requires [`InMemoryCacheBuilder::emoji_name_index`] to be enabled;
+    /// otherwise `None` is always returned. If multiple emojis in the guild
+    /// share a name, the one most recently cached is returned.
+    ///
+    /// This is an O(1) operation. This requires both the [`GUILDS`] and
+    /// [`GUILD_EMOJIS`] intents.
+    ///
+    /// [`GUILDS`]: ../twilight_model/gateway/struct.Intents.html#associatedconstant.GUILDS
+    /// [`GUILD_EMOJIS`]: ../twilight_model/gateway/struct.Intents.html#associatedconstant.GUILD_EMOJIS
+    /// [`InMemoryCacheBuilder::emoji_name_index`]: struct.InMemoryCacheBuilder.html#method.emoji_name_index
+    pub fn emoji_by_name(&self, guild_id: GuildId, name: &str) -> Option<Arc<CachedEmoji>> {
+        let emoji_id = *self.0.guild_emoji_names.get(&guild_id)?.get(name)?;
+
+        self.emoji(emoji_id)
+    }
+
     /// Gets a group by ID.
     ///
     /// This is an O(1) operation.
@@ -379,6 +440,71 @@ impl InMemoryCache {
         channel.get(&message_id).cloned()
     }
 
+    /// Gets the permission overwrites of a guild channel that apply to a
+    /// member, resolved into a single allow and deny pair.
+    ///
+    /// The `@everyone` overwrite is applied first, followed by the combined
+    /// overwrites of the member's roles, and finally the member's own
+    /// overwrite, as described in Discord's documentation. A permission is
+    /// never both allowed and denied in the returned overwrite. The kind of
+    /// the returned overwrite is always the member.
+    ///
+    /// Returns `None` if the channel or member isn't cached.
+    ///
+    /// This is an O(n) operation, where n is the amount of overwrites in the
+    /// channel. This requires both the [`GUILDS`] and [`GUILD_MEMBERS`]
+    /// intents.
+    ///
+    /// [`GUILDS`]: ../twilight_model/gateway/struct.Intents.html#associatedconstant.GUILDS
+    /// [`GUILD_MEMBERS`]: ../twilight_model/gateway/struct.Intents.html#associatedconstant.GUILD_MEMBERS
+    pub fn permission_overwrite(
+        &self,
+        channel_id: ChannelId,
+        user_id: UserId,
+    ) -> Option<PermissionOverwrite> {
+        let (channel, guild_id) = self
+            .0
+            .channels_guild
+            .get(&channel_id)
+            .map(|item| (Arc::clone(&item.data), item.guild_id))?;
+        let member = self.member(guild_id, user_id)?;
+
+        let mut everyone = (Permissions::empty(), Permissions::empty());
+        let mut roles = (Permissions::empty(), Permissions::empty());
+        let mut user = (Permissions::empty(), Permissions::empty());
+
+        for overwrite in channel.permission_overwrites() {
+            match overwrite.kind {
+                PermissionOverwriteType::Role(role_id) if role_id.0 == guild_id.0 => {
+                    everyone = (overwrite.allow, overwrite.deny);
+                }
+                PermissionOverwriteType::Role(role_id) if member.roles.contains(&role_id) => {
+                    roles.0 |= overwrite.allow;
+                    roles.1 |= overwrite.deny;
+                }
+                PermissionOverwriteType::Member(id) if id == user_id => {
+                    user = (overwrite.allow, overwrite.deny);
+                }
+                PermissionOverwriteType::Member(_) | PermissionOverwriteType::Role(_) => {}
+            }
+        }
+
+        let (allow, deny) = [everyone, roles, user].iter().fold(
+            (Permissions::empty(), Permissions::empty()),
+            |(allow, deny), (layer_allow, layer_deny)| {
+                let allow = (allow - *layer_deny) | *layer_allow;
+
+                (allow, (deny | *layer_deny) - allow)
+            },
+        );
+
+        Some(PermissionOverwrite {
+            allow,
+            deny,
+            kind: PermissionOverwriteType::Member(user_id),
+        })
+    }
+
     /// Gets a presence by, optionally, guild ID, and user ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_PRESENCES`] intent.
@@ -408,7 +534,7 @@ impl InMemoryCache {
     /// This is an O(1) operation. This requires the [`GUILDS`] intent.
     ///
     /// [`GUILDS`]: ../twilight_model/gateway/struct.Intents.html#associatedconstant.GUILDS
-    pub fn role(&self, role_id: RoleId) -> Option<Arc<Role>> {
+    pub fn role(&self, role_id: RoleId) -> Option<Arc<CachedRole>> {
         self.0
             .roles
             .get(&role_id)
@@ -465,13 +591,48 @@ impl InMemoryCache {
             .expect("current user poisoned")
             .take();
         self.0.emojis.clear();
+        self.0.guild_emoji_names.clear();
         self.0.guilds.clear();
         self.0.presences.clear();
         self.0.roles.clear();
+        self.0.strings.clear();
         self.0.users.clear();
         self.0.voice_state_guilds.clear();
     }
 
+    /// Intern a string, returning the existing copy if it's already interned.
+    ///
+    /// Interned strings are kept until they're pruned after the items holding
+    /// them are removed, so this should only be used for strings with few
+    /// distinct values.
+    fn intern(&self, value: &str) -> Arc<str> {
+        if let Some(interned) = self.0.strings.get(value) {
+            return Arc::clone(interned.key());
+        }
+
+        let interned = Arc::<str>::from(value);
+        self.0.strings.insert(Arc::clone(&interned));
+
+        interned
+    }
+
+    /// Remove interned strings that are no longer held outside of the cache's
+    /// set of interned strings.
+    fn prune_strings(&self) {
+        self.0
+            .strings
+            .retain(|interned| Arc::strong_count(interned) > 1);
+    }
+
+    /// Determine whether the cache is configured to store a type of resource.
+    fn wants(&self, resource_type: ResourceType) -> bool {
+        self.0.config.resource_types().contains(resource_type)
+    }
+
+    fn intern_all(&self, values: &[String]) -> Vec<Arc<str>> {
+        values.iter().map(|value| self.intern(value)).collect()
+    }
+
     fn cache_current_user(&self, mut current_user: CurrentUser) {
         let mut user = self.0.current_user.lock().expect("current user poisoned");
 
@@ -505,10 +666,19 @@ impl InMemoryCache {
             GuildChannel::Category(ref mut c) => {
                 c.guild_id.replace(guild_id);
             }
+            GuildChannel::NewsThread(ref mut c) => {
+                c.guild_id.replace(guild_id);
+            }
+            GuildChannel::PrivateThread(ref mut c) => {
+                c.guild_id.replace(guild_id);
+            }
+            GuildChannel::PublicThread(ref mut c) => {
+                c.guild_id.replace(guild_id);
+            }
             GuildChannel::Text(ref mut c) => {
                 c.guild_id.replace(guild_id);
             }
-            GuildChannel::Voice(ref mut c) => {
+            GuildChannel::Stage(ref mut c) | GuildChannel::Voice(ref mut c) => {
                 c.guild_id.replace(guild_id);
             }
         }
@@ -524,6 +694,10 @@ impl InMemoryCache {
     }
 
     fn cache_emoji(&self, guild_id: GuildId, emoji: Emoji) -> Arc<CachedEmoji> {
+        if self.0.config.emoji_name_index() {
+            self.cache_emoji_name(guild_id, &emoji);
+        }
+
         match self.0.emojis.get(&emoji.id) {
             Some(e) if *e.data == emoji => return Arc::clone(&e.data),
             Some(_) | None => {}
@@ -562,12 +736,45 @@ impl InMemoryCache {
         cached
     }
 
+    fn cache_emoji_name(&self, guild_id: GuildId, emoji: &Emoji) {
+        let old_name = self.0.emojis.get(&emoji.id).map(|e| e.data.name.clone());
+        let mut names = self.0.guild_emoji_names.entry(guild_id).or_default();
+
+        if let Some(old_name) = old_name {
+            if old_name != emoji.name && names.get(&old_name) == Some(&emoji.id) {
+                names.remove(&old_name);
+            }
+        }
+
+        names.insert(emoji.name.clone(), emoji.id);
+    }
+
     fn cache_emojis(&self, guild_id: GuildId, emojis: impl IntoIterator<Item = Emoji>) {
         for emoji in emojis {
             self.cache_emoji(guild_id, emoji);
         }
     }
 
+    /// Delete an emoji from the cache.
+    ///
+    /// The emoji data, the emoji entry in its guild's list of emojis, and its
+    /// name index entry will be deleted.
+    fn delete_emoji(&self, emoji_id: EmojiId) -> Option<Arc<CachedEmoji>> {
+        let GuildItem { data, guild_id } = self.0.emojis.remove(&emoji_id)?.1;
+
+        if let Some(mut guild_emojis) = self.0.guild_emojis.get_mut(&guild_id) {
+            guild_emojis.remove(&emoji_id);
+        }
+
+        if let Some(mut names) = self.0.guild_emoji_names.get_mut(&guild_id) {
+            if names.get(&data.name) == Some(&emoji_id) {
+                names.remove(&data.name);
+            }
+        }
+
+        Some(data)
+    }
+
     fn cache_group(&self, group: Group) -> Arc<Group> {
         upsert_item(&self.0.groups, group.id, group)
     }
@@ -577,17 +784,40 @@ impl InMemoryCache {
         // always has a place to put them.
         self.0.guild_channels.insert(guild.id, HashSet::new());
         self.0.guild_emojis.insert(guild.id, HashSet::new());
+        self.0.guild_emoji_names.remove(&guild.id);
         self.0.guild_members.insert(guild.id, HashSet::new());
         self.0.guild_presences.insert(guild.id, HashSet::new());
         self.0.guild_roles.insert(guild.id, HashSet::new());
         self.0.voice_state_guilds.insert(guild.id, HashSet::new());
 
-        self.cache_guild_channels(guild.id, guild.channels.into_iter().map(|(_, v)| v));
-        self.cache_emojis(guild.id, guild.emojis.into_iter().map(|(_, v)| v));
-        self.cache_members(guild.id, guild.members.into_iter().map(|(_, v)| v));
-        self.cache_presences(guild.id, guild.presences.into_iter().map(|(_, v)| v));
-        self.cache_roles(guild.id, guild.roles.into_iter().map(|(_, v)| v));
-        self.cache_voice_states(guild.voice_states.into_iter().map(|(_, v)| v));
+        if self.wants(ResourceType::CHANNEL) {
+            self.cache_guild_channels(guild.id, guild.channels.into_iter().map(|(_, v)| v));
+            self.cache_guild_channels(guild.id, guild.threads.into_iter().map(|(_, v)| v));
+        }
+
+        if self.wants(ResourceType::EMOJI) {
+            self.cache_emojis(guild.id, guild.emojis.into_iter().map(|(_, v)| v));
+        }
+
+        if self.wants(ResourceType::MEMBER) {
+            self.cache_members(guild.id, guild.members.into_iter().map(|(_, v)| v));
+        }
+
+        if self.wants(ResourceType::PRESENCE) {
+            self.cache_presences(guild.id, guild.presences.into_iter().map(|(_, v)| v));
+        }
+
+        if self.wants(ResourceType::ROLE) {
+            self.cache_roles(guild.id, guild.roles.into_iter().map(|(_, v)| v));
+        }
+
+        if self.wants(ResourceType::VOICE_STATE) {
+            self.cache_voice_states(guild.voice_states.into_iter().map(|(_, v)| v));
+        }
+
+        if !self.wants(ResourceType::GUILD) {
+            return;
+        }
 
         let guild = CachedGuild {
             id: guild.id,
@@ -599,7 +829,7 @@ impl InMemoryCache {
             description: guild.description,
             discovery_splash: guild.discovery_splash,
             explicit_content_filter: guild.explicit_content_filter,
-            features: guild.features,
+            features: self.intern_all(&guild.features),
             icon: guild.icon,
             joined_at: guild.joined_at,
             large: guild.large,
@@ -612,10 +842,10 @@ impl InMemoryCache {
             owner: guild.owner,
             owner_id: guild.owner_id,
             permissions: guild.permissions,
-            preferred_locale: guild.preferred_locale,
+            preferred_locale: self.intern(&guild.preferred_locale),
             premium_subscription_count: guild.premium_subscription_count,
             premium_tier: guild.premium_tier,
-            region: guild.region,
+            region: self.intern(&guild.region),
             rules_channel_id: guild.rules_channel_id,
             splash: guild.splash,
             system_channel_id: guild.system_channel_id,
@@ -643,10 +873,10 @@ impl InMemoryCache {
         let cached = Arc::new(CachedMember {
             deaf: member.deaf,
             guild_id,
-            joined_at: member.joined_at,
+            joined_at: member.joined_at.map(String::into_boxed_str),
             mute: member.mute,
-            nick: member.nick,
-            premium_since: member.premium_since,
+            nick: member.nick.map(String::into_boxed_str),
+            premium_since: member.premium_since.map(String::into_boxed_str),
             roles: member.roles,
             user,
         });
@@ -680,9 +910,9 @@ impl InMemoryCache {
         let cached = Arc::new(CachedMember {
             deaf: member.deaf,
             guild_id,
-            joined_at: member.joined_at.to_owned(),
+            joined_at: member.joined_at.as_deref().map(Box::from),
             mute: member.mute,
-            nick: member.nick.to_owned(),
+            nick: member.nick.as_deref().map(Box::from),
             premium_since: None,
             roles: member.roles.to_owned(),
             user,
@@ -738,7 +968,7 @@ impl InMemoryCache {
         }
     }
 
-    fn cache_role(&self, guild_id: GuildId, role: Role) -> Arc<Role> {
+    fn cache_role(&self, guild_id: GuildId, role: Role) -> Arc<CachedRole> {
         // Insert the role into the guild_roles map
         self.0
             .guild_roles
@@ -746,11 +976,57 @@ impl InMemoryCache {
             .or_default()
             .insert(role.id);
 
+        match self.0.roles.get(&role.id) {
+            Some(r) if *r.data == role => return Arc::clone(&r.data),
+            Some(_) | None => {}
+        }
+
+        let cached = Arc::new(CachedRole {
+            color: role.color,
+            hoist: role.hoist,
+            id: role.id,
+            managed: role.managed,
+            mentionable: role.mentionable,
+            name: self.intern(&role.name),
+            permissions: role.permissions,
+            position: role.position,
+        });
+
         // Insert the role into the all roles map
-        upsert_guild_item(&self.0.roles, guild_id, role.id, role)
+        let old = self.0.roles.insert(
+            role.id,
+            GuildItem {
+                data: Arc::clone(&cached),
+                guild_id,
+            },
+        );
+
+        if let Some(old) = old {
+            self.release_role_name(old.data);
+        }
+
+        cached
+    }
+
+    /// Remove the interned name of a role that is no longer cached if no other
+    /// item holds the name.
+    ///
+    /// If the role is still held outside of the cache then its name is left
+    /// to be pruned with the strings of removed guilds.
+    fn release_role_name(&self, role: Arc<CachedRole>) {
+        if let Ok(role) = Arc::try_unwrap(role) {
+            // Held by the set of interned strings and by this role.
+            self.0
+                .strings
+                .remove_if(&*role.name, |interned| Arc::strong_count(interned) == 2);
+        }
     }
 
     fn cache_user(&self, user: Cow<User>, guild_id: Option<GuildId>) -> Arc<User> {
+        if !self.wants(ResourceType::USER) {
+            return Arc::new(user.into_owned());
+        }
+
         match self.0.users.get_mut(&user.id) {
             Some(mut u) if *u.0 == *user => {
                 if let Some(guild_id) = guild_id {
@@ -878,14 +1154,17 @@ impl InMemoryCache {
         Some(data)
     }
 
-    fn delete_role(&self, role_id: RoleId) -> Option<Arc<Role>> {
-        let role = self.0.roles.remove(&role_id).map(|(_, v)| v)?;
+    fn delete_role(&self, role_id: RoleId) {
+        let role = match self.0.roles.remove(&role_id) {
+            Some((_, role)) => role,
+            None => return,
+        };
 
         if let Some(mut roles) = self.0.guild_roles.get_mut(&role.guild_id) {
             roles.remove(&role_id);
         }
 
-        Some(role.data)
+        self.release_role_name(role.data);
     }
 }
 
@@ -898,11 +1177,16 @@ fn presence_user_id(presence: &Presence) -> UserId {
 
 #[cfg(test)]
 mod tests {
-    use crate::InMemoryCache;
-    use std::{borrow::Cow, collections::HashMap};
+    use crate::{InMemoryCache, ResourceType};
+    use std::{borrow::Cow, collections::HashMap, sync::Arc};
     use twilight_model::{
-        channel::{ChannelType, GuildChannel, TextChannel},
-        gateway::payload::{MemberRemove, RoleDelete},
+        channel::{
+            permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+            ChannelType, GuildChannel, TextChannel,
+        },
+        gateway::payload::{
+            GuildDelete, MemberAdd, MemberRemove, RoleCreate, RoleDelete, UnavailableGuild,
+        },
         guild::{
             DefaultMessageNotificationLevel, Emoji, ExplicitContentFilter, Guild, Member, MfaLevel,
             Permissions, PremiumTier, Role, SystemChannelFlags, VerificationLevel,
@@ -942,6 +1226,56 @@ mod tests {
         }
     }
 
+    fn guild(id: GuildId) -> Guild {
+        Guild {
+            id,
+            afk_channel_id: None,
+            afk_timeout: 300,
+            application_id: None,
+            banner: None,
+            channels: HashMap::new(),
+            default_message_notifications: DefaultMessageNotificationLevel::Mentions,
+            description: None,
+            discovery_splash: None,
+            emojis: HashMap::new(),
+            explicit_content_filter: ExplicitContentFilter::AllMembers,
+            features: vec![],
+            icon: None,
+            joined_at: Some("".to_owned()),
+            large: false,
+            lazy: Some(true),
+            max_members: Some(50),
+            max_presences: Some(100),
+            member_count: Some(25),
+            members: HashMap::new(),
+            mfa_level: MfaLevel::Elevated,
+            name: "this is a guild".to_owned(),
+            owner: Some(false),
+            owner_id: UserId(456),
+            permissions: Some(Permissions::SEND_MESSAGES),
+            preferred_locale: "en-GB".to_owned(),
+            premium_subscription_count: Some(0),
+            premium_tier: PremiumTier::None,
+            presences: HashMap::new(),
+            region: "us-east".to_owned(),
+            roles: HashMap::new(),
+            splash: None,
+            system_channel_id: None,
+            system_channel_flags: SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS,
+            rules_channel_id: None,
+            threads: HashMap::new(),
+            unavailable: false,
+            verification_level: VerificationLevel::VeryHigh,
+            voice_states: HashMap::new(),
+            vanity_url_code: None,
+            widget_channel_id: None,
+            widget_enabled: None,
+            max_video_channel_users: None,
+            approximate_member_count: None,
+            approximate_presence_count: None,
+        }
+    }
+
     fn member(id: UserId, guild_id: GuildId) -> Member {
         Member {
             deaf: false,
@@ -1041,50 +1375,8 @@ mod tests {
         );
 
         let guild = Guild {
-            id: GuildId(123),
-            afk_channel_id: None,
-            afk_timeout: 300,
-            application_id: None,
-            banner: None,
             channels,
-            default_message_notifications: DefaultMessageNotificationLevel::Mentions,
-            description: None,
-            discovery_splash: None,
-            emojis: HashMap::new(),
-            explicit_content_filter: ExplicitContentFilter::AllMembers,
-            features: vec![],
-            icon: None,
-            joined_at: Some("".to_owned()),
-            large: false,
-            lazy: Some(true),
-            max_members: Some(50),
-            max_presences: Some(100),
-            member_count: Some(25),
-            members: HashMap::new(),
-            mfa_level: MfaLevel::Elevated,
-            name: "this is a guild".to_owned(),
-            owner: Some(false),
-            owner_id: UserId(456),
-            permissions: Some(Permissions::SEND_MESSAGES),
-            preferred_locale: "en-GB".to_owned(),
-            premium_subscription_count: Some(0),
-            premium_tier: PremiumTier::None,
-            presences: HashMap::new(),
-            region: "us-east".to_owned(),
-            roles: HashMap::new(),
-            splash: None,
-            system_channel_id: None,
-            system_channel_flags: SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS,
-            rules_channel_id: None,
-            unavailable: false,
-            verification_level: VerificationLevel::VeryHigh,
-            voice_states: HashMap::new(),
-            vanity_url_code: None,
-            widget_channel_id: None,
-            widget_enabled: None,
-            max_video_channel_users: None,
-            approximate_member_count: None,
-            approximate_presence_count: None,
+            ..guild(GuildId(123))
         };
 
         let cache = InMemoryCache::new();
@@ -1104,6 +1396,104 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_intern() {
+        let cache = InMemoryCache::new();
+        let community = cache.intern("COMMUNITY");
+
+        assert_eq!("COMMUNITY", &*community);
+        assert!(Arc::ptr_eq(&community, &cache.intern("COMMUNITY")));
+        assert!(!Arc::ptr_eq(&community, &cache.intern("NEWS")));
+        assert_eq!(
+            vec!["COMMUNITY", "NEWS"],
+            cache
+                .intern_all(&["COMMUNITY".to_owned(), "NEWS".to_owned()])
+                .iter()
+                .map(|feature| &**feature)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_guilds_share_interned_strings() {
+        let cache = InMemoryCache::new();
+
+        for id in 1..=100 {
+            cache.cache_guild(Guild {
+                features: vec!["COMMUNITY".to_owned(), "NEWS".to_owned()],
+                ..guild(GuildId(id))
+            });
+        }
+
+        // The 100 guilds hold 400 strings between their features, locales and
+        // regions, but only the 4 distinct values are stored.
+        assert_eq!(4, cache.0.strings.len());
+
+        let first = cache.guild(GuildId(1)).unwrap();
+        let last = cache.guild(GuildId(100)).unwrap();
+        assert!(Arc::ptr_eq(&first.features[0], &last.features[0]));
+        assert!(Arc::ptr_eq(&first.preferred_locale, &last.preferred_locale));
+        assert!(Arc::ptr_eq(&first.region, &last.region));
+    }
+
+    #[test]
+    fn test_roles_share_interned_names() {
+        let cache = InMemoryCache::new();
+        cache.cache_role(GuildId(1), role(RoleId(1)));
+        cache.cache_role(GuildId(2), role(RoleId(2)));
+        assert_eq!(1, cache.0.strings.len());
+
+        let first = cache.role(RoleId(1)).unwrap();
+        let second = cache.role(RoleId(2)).unwrap();
+        assert!(Arc::ptr_eq(&first.name, &second.name));
+        drop((first, second));
+
+        // The name is still held by the second role.
+        cache.update(&RoleDelete {
+            guild_id: GuildId(1),
+            role_id: RoleId(1),
+        });
+        assert_eq!(1, cache.0.strings.len());
+
+        // Renaming the last role holding the name releases it.
+        cache.cache_role(
+            GuildId(2),
+            Role {
+                name: "renamed".to_owned(),
+                ..role(RoleId(2))
+            },
+        );
+        assert_eq!(1, cache.0.strings.len());
+        assert!(cache.0.strings.contains("renamed"));
+    }
+
+    #[test]
+    fn test_prune_strings() {
+        let cache = InMemoryCache::new();
+        cache.cache_guild(guild(GuildId(1)));
+        cache.cache_guild(Guild {
+            region: "europe".to_owned(),
+            ..guild(GuildId(2))
+        });
+        assert_eq!(3, cache.0.strings.len());
+
+        // The region of the removed guild is no longer held by any guild.
+        cache.update(&GuildDelete {
+            id: GuildId(2),
+            unavailable: false,
+        });
+        assert_eq!(2, cache.0.strings.len());
+        assert!(!cache.0.strings.contains("europe"));
+
+        // Strings still held outside of the cache aren't pruned.
+        let guild = cache.guild(GuildId(1)).unwrap();
+        cache.update(&UnavailableGuild { id: GuildId(1) });
+        assert_eq!(2, cache.0.strings.len());
+        drop(guild);
+        cache.prune_strings();
+        assert!(cache.0.strings.is_empty());
+    }
+
     #[test]
     fn test_syntax_update() {
         let cache = InMemoryCache::new();
@@ -1113,6 +1503,23 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_resource_types() {
+        let cache = InMemoryCache::builder()
+            .resource_types(ResourceType::ROLE)
+            .build();
+
+        cache.update(&MemberAdd(member(UserId(2), GuildId(1))));
+        assert!(cache.member(GuildId(1), UserId(2)).is_none());
+        assert!(cache.user(UserId(2)).is_none());
+
+        cache.update(&RoleCreate {
+            guild_id: GuildId(1),
+            role: role(RoleId(3)),
+        });
+        assert!(cache.role(RoleId(3)).is_some());
+    }
+
     #[test]
     fn test_cache_user_guild_state() {
         let user_id = UserId(2);
@@ -1483,4 +1890,118 @@ mod tests {
             assert!(guild_2_emoji_ids.iter().all(|id| guild_emojis.contains(id)));
         }
     }
+
+    #[test]
+    fn test_emoji_by_name() {
+        let cache = InMemoryCache::builder().emoji_name_index(true).build();
+        let mut emoji = emoji(EmojiId(1), None);
+        emoji.name = "foo".to_owned();
+        cache.cache_emoji(GuildId(1), emoji.clone());
+
+        assert_eq!(
+            EmojiId(1),
+            cache.emoji_by_name(GuildId(1), "foo").unwrap().id
+        );
+        assert!(cache.emoji_by_name(GuildId(2), "foo").is_none());
+
+        // Renaming the emoji removes the previous name from the index.
+        emoji.name = "bar".to_owned();
+        cache.cache_emoji(GuildId(1), emoji);
+        assert!(cache.emoji_by_name(GuildId(1), "foo").is_none());
+        assert_eq!(
+            EmojiId(1),
+            cache.emoji_by_name(GuildId(1), "bar").unwrap().id
+        );
+
+        cache.delete_emoji(EmojiId(1));
+        assert!(cache.emoji_by_name(GuildId(1), "bar").is_none());
+    }
+
+    #[test]
+    fn test_emoji_by_name_disabled() {
+        let cache = InMemoryCache::new();
+        cache.cache_emoji(GuildId(1), emoji(EmojiId(1), None));
+
+        assert!(cache.emoji(EmojiId(1)).is_some());
+        assert!(cache.emoji_by_name(GuildId(1), "test").is_none());
+    }
+
+    #[test]
+    fn test_permission_overwrite() {
+        let cache = InMemoryCache::new();
+        let (guild_id, channel_id, user_id) = (GuildId(1), ChannelId(2), UserId(3));
+        let overwrite = |allow, deny, kind| PermissionOverwrite { allow, deny, kind };
+
+        let mut member = member(user_id, guild_id);
+        member.roles = vec![RoleId(4), RoleId(5)];
+        cache.cache_member(guild_id, member);
+
+        assert!(cache.permission_overwrite(channel_id, user_id).is_none());
+
+        cache.cache_guild_channel(
+            guild_id,
+            GuildChannel::Text(TextChannel {
+                id: channel_id,
+                guild_id: Some(guild_id),
+                kind: ChannelType::GuildText,
+                last_message_id: None,
+                last_pin_timestamp: None,
+                name: "test".to_owned(),
+                nsfw: false,
+                permission_overwrites: vec![
+                    // @everyone can't send messages or add reactions.
+                    overwrite(
+                        Permissions::empty(),
+                        Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS,
+                        PermissionOverwriteType::Role(RoleId(1)),
+                    ),
+                    // The member's first role can send messages...
+                    overwrite(
+                        Permissions::SEND_MESSAGES,
+                        Permissions::EMBED_LINKS,
+                        PermissionOverwriteType::Role(RoleId(4)),
+                    ),
+                    // ...while the second role denies attaching files.
+                    overwrite(
+                        Permissions::empty(),
+                        Permissions::ATTACH_FILES,
+                        PermissionOverwriteType::Role(RoleId(5)),
+                    ),
+                    // A role the member doesn't have is ignored.
+                    overwrite(
+                        Permissions::ADMINISTRATOR,
+                        Permissions::empty(),
+                        PermissionOverwriteType::Role(RoleId(6)),
+                    ),
+                    // The member's own overwrite allows embedding links.
+                    overwrite(
+                        Permissions::EMBED_LINKS,
+                        Permissions::empty(),
+                        PermissionOverwriteType::Member(user_id),
+                    ),
+                    // Another member's overwrite is ignored.
+                    overwrite(
+                        Permissions::MANAGE_MESSAGES,
+                        Permissions::empty(),
+                        PermissionOverwriteType::Member(UserId(7)),
+                    ),
+                ],
+                parent_id: None,
+                position: 0,
+                rate_limit_per_user: None,
+                topic: None,
+            }),
+        );
+
+        let resolved = cache.permission_overwrite(channel_id, user_id).unwrap();
+        assert_eq!(
+            Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS,
+            resolved.allow
+        );
+        assert_eq!(
+            Permissions::ADD_REACTIONS | Permissions::ATTACH_FILES,
+            resolved.deny
+        );
+        assert_eq!(PermissionOverwriteType::Member(user_id), resolved.kind);
+    }
 }
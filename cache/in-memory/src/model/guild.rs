@@ -1,4 +1,5 @@
 use serde::Serialize;
+use std::sync::Arc;
 use twilight_model::{
     guild::{
         DefaultMessageNotificationLevel, ExplicitContentFilter, MfaLevel, Permissions, PremiumTier,
@@ -18,7 +19,9 @@ pub struct CachedGuild {
     pub description: Option<String>,
     pub discovery_splash: Option<String>,
     pub explicit_content_filter: ExplicitContentFilter,
-    pub features: Vec<String>,
+    /// Features are interned, so each feature is only stored once for all of
+    /// the guilds in the cache.
+    pub features: Vec<Arc<str>>,
     pub icon: Option<String>,
     pub joined_at: Option<String>,
     pub large: bool,
@@ -31,10 +34,12 @@ pub struct CachedGuild {
     pub owner: Option<bool>,
     pub owner_id: UserId,
     pub permissions: Option<Permissions>,
-    pub preferred_locale: String,
+    /// Interned like the features.
+    pub preferred_locale: Arc<str>,
     pub premium_subscription_count: Option<u64>,
     pub premium_tier: PremiumTier,
-    pub region: String,
+    /// Interned like the features.
+    pub region: Arc<str>,
     pub rules_channel_id: Option<ChannelId>,
     pub splash: Option<String>,
     pub system_channel_id: Option<ChannelId>,
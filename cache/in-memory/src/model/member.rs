@@ -10,10 +10,10 @@ use twilight_model::{
 pub struct CachedMember {
     pub deaf: bool,
     pub guild_id: GuildId,
-    pub joined_at: Option<String>,
+    pub joined_at: Option<Box<str>>,
     pub mute: bool,
-    pub nick: Option<String>,
-    pub premium_since: Option<String>,
+    pub nick: Option<Box<str>>,
+    pub premium_since: Option<Box<str>>,
     pub roles: Vec<RoleId>,
     pub user: Arc<User>,
 }
@@ -22,17 +22,17 @@ impl PartialEq<Member> for CachedMember {
     fn eq(&self, other: &Member) -> bool {
         (
             self.deaf,
-            self.joined_at.as_ref(),
+            self.joined_at.as_deref(),
             self.mute,
-            &self.nick,
-            self.premium_since.as_ref(),
+            self.nick.as_deref(),
+            self.premium_since.as_deref(),
             &self.roles,
         ) == (
             other.deaf,
-            other.joined_at.as_ref(),
+            other.joined_at.as_deref(),
             other.mute,
-            &other.nick,
-            other.premium_since.as_ref(),
+            other.nick.as_deref(),
+            other.premium_since.as_deref(),
             &other.roles,
         )
     }
@@ -42,15 +42,15 @@ impl PartialEq<&PartialMember> for CachedMember {
     fn eq(&self, other: &&PartialMember) -> bool {
         (
             self.deaf,
-            self.joined_at.as_ref(),
+            self.joined_at.as_deref(),
             self.mute,
-            &self.nick,
+            self.nick.as_deref(),
             &self.roles,
         ) == (
             other.deaf,
-            other.joined_at.as_ref(),
+            other.joined_at.as_deref(),
             other.mute,
-            &other.nick,
+            other.nick.as_deref(),
             &other.roles,
         )
     }
@@ -72,7 +72,7 @@ mod tests {
             guild_id: GuildId(3),
             joined_at: None,
             mute: true,
-            nick: Some("member nick".to_owned()),
+            nick: Some("member nick".into()),
             premium_since: None,
             roles: Vec::new(),
             user: Arc::new(user()),
@@ -20,8 +20,8 @@ pub struct CachedMessage {
     pub attachments: Vec<Attachment>,
     pub author: UserId,
     pub channel_id: ChannelId,
-    pub content: String,
-    pub edited_timestamp: Option<String>,
+    pub content: Box<str>,
+    pub edited_timestamp: Option<Box<str>>,
     pub embeds: Vec<Embed>,
     pub flags: Option<MessageFlags>,
     pub guild_id: Option<GuildId>,
@@ -34,7 +34,7 @@ pub struct CachedMessage {
     pub pinned: bool,
     pub reactions: Vec<MessageReaction>,
     pub reference: Option<MessageReference>,
-    pub timestamp: String,
+    pub timestamp: Box<str>,
     pub tts: bool,
     pub webhook_id: Option<WebhookId>,
 }
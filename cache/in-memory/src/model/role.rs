@@ -0,0 +1,88 @@
+use serde::Serialize;
+use std::sync::Arc;
+use twilight_model::{
+    guild::{Permissions, Role},
+    id::RoleId,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct CachedRole {
+    pub color: u32,
+    pub hoist: bool,
+    pub id: RoleId,
+    pub managed: bool,
+    pub mentionable: bool,
+    /// Names are interned, so names shared by roles of many guilds, such as
+    /// `@everyone`, are only stored once.
+    pub name: Arc<str>,
+    pub permissions: Permissions,
+    pub position: i64,
+}
+
+impl PartialEq<Role> for CachedRole {
+    fn eq(&self, other: &Role) -> bool {
+        self.color == other.color
+            && self.hoist == other.hoist
+            && self.id == other.id
+            && self.managed == other.managed
+            && self.mentionable == other.mentionable
+            && *self.name == *other.name
+            && self.permissions == other.permissions
+            && self.position == other.position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CachedRole;
+    use std::{fmt::Debug, sync::Arc};
+    use twilight_model::{
+        guild::{Permissions, Role},
+        id::RoleId,
+    };
+
+    #[test]
+    fn test_eq_role() {
+        let role = Role {
+            color: 0,
+            hoist: true,
+            id: RoleId(1),
+            managed: false,
+            mentionable: true,
+            name: "foo".to_owned(),
+            permissions: Permissions::SEND_MESSAGES,
+            position: 2,
+        };
+        let cached = CachedRole {
+            color: 0,
+            hoist: true,
+            id: RoleId(1),
+            managed: false,
+            mentionable: true,
+            name: Arc::from("foo"),
+            permissions: Permissions::SEND_MESSAGES,
+            position: 2,
+        };
+
+        assert_eq!(cached, role);
+    }
+
+    #[test]
+    fn test_fields() {
+        static_assertions::assert_fields!(
+            CachedRole: color,
+            hoist,
+            id,
+            managed,
+            mentionable,
+            name,
+            permissions,
+            position
+        );
+    }
+
+    #[test]
+    fn test_impls() {
+        static_assertions::assert_impl_all!(CachedRole: Clone, Debug, Eq, PartialEq);
+    }
+}
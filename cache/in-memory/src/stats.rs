@@ -0,0 +1,200 @@
+use super::InMemoryCache;
+use twilight_model::id::{ChannelId, GuildId};
+
+/// Counts of the resources in an [`InMemoryCache`].
+///
+/// Each count is taken when the method is called. Created via
+/// [`InMemoryCache::stats`].
+///
+/// # Examples
+///
+/// Get the number of cached guilds and messages, such as to export as
+/// metrics:
+///
+/// ```
+/// use twilight_cache_inmemory::InMemoryCache;
+///
+/// let cache = InMemoryCache::new();
+/// let stats = cache.stats();
+///
+/// println!("guilds: {}", stats.guilds());
+/// println!("messages: {}", stats.messages());
+/// ```
+///
+/// [`InMemoryCache`]: struct.InMemoryCache.html
+/// [`InMemoryCache::stats`]: struct.InMemoryCache.html#method.stats
+#[derive(Clone, Debug)]
+pub struct InMemoryCacheStats<'a>(&'a InMemoryCache);
+
+impl<'a> InMemoryCacheStats<'a> {
+    pub(super) fn new(cache: &'a InMemoryCache) -> Self {
+        Self(cache)
+    }
+
+    /// Number of cached guild channels.
+    pub fn channels(&self) -> usize {
+        self.0 .0.channels_guild.len()
+    }
+
+    /// Number of messages cached in a channel.
+    ///
+    /// Returns `None` if the channel has no cached messages.
+    pub fn channel_messages(&self, channel_id: ChannelId) -> Option<usize> {
+        let channel = self.0 .0.messages.get(&channel_id)?;
+
+        Some(channel.len())
+    }
+
+    /// Number of cached emojis.
+    pub fn emojis(&self) -> usize {
+        self.0 .0.emojis.len()
+    }
+
+    /// Number of cached groups.
+    pub fn groups(&self) -> usize {
+        self.0 .0.groups.len()
+    }
+
+    /// Number of cached channels in a guild.
+    ///
+    /// Returns `None` if the guild isn't cached.
+    pub fn guild_channels(&self, guild_id: GuildId) -> Option<usize> {
+        let channels = self.0 .0.guild_channels.get(&guild_id)?;
+
+        Some(channels.len())
+    }
+
+    /// Number of cached emojis in a guild.
+    ///
+    /// Returns `None` if the guild isn't cached.
+    pub fn guild_emojis(&self, guild_id: GuildId) -> Option<usize> {
+        let emojis = self.0 .0.guild_emojis.get(&guild_id)?;
+
+        Some(emojis.len())
+    }
+
+    /// Number of cached members in a guild.
+    ///
+    /// Returns `None` if the guild isn't cached.
+    pub fn guild_members(&self, guild_id: GuildId) -> Option<usize> {
+        let members = self.0 .0.guild_members.get(&guild_id)?;
+
+        Some(members.len())
+    }
+
+    /// Number of cached presences in a guild.
+    ///
+    /// Returns `None` if the guild isn't cached.
+    pub fn guild_presences(&self, guild_id: GuildId) -> Option<usize> {
+        let presences = self.0 .0.guild_presences.get(&guild_id)?;
+
+        Some(presences.len())
+    }
+
+    /// Number of cached roles in a guild.
+    ///
+    /// Returns `None` if the guild isn't cached.
+    pub fn guild_roles(&self, guild_id: GuildId) -> Option<usize> {
+        let roles = self.0 .0.guild_roles.get(&guild_id)?;
+
+        Some(roles.len())
+    }
+
+    /// Number of cached voice states in a guild.
+    ///
+    /// Returns `None` if the guild isn't cached.
+    pub fn guild_voice_states(&self, guild_id: GuildId) -> Option<usize> {
+        let voice_states = self.0 .0.voice_state_guilds.get(&guild_id)?;
+
+        Some(voice_states.len())
+    }
+
+    /// Number of cached guilds.
+    pub fn guilds(&self) -> usize {
+        self.0 .0.guilds.len()
+    }
+
+    /// Number of cached members of all guilds.
+    pub fn members(&self) -> usize {
+        self.0 .0.members.len()
+    }
+
+    /// Number of cached messages of all channels.
+    pub fn messages(&self) -> usize {
+        self.0
+             .0
+            .messages
+            .iter()
+            .map(|channel| channel.value().len())
+            .sum()
+    }
+
+    /// Number of cached presences of all guilds.
+    pub fn presences(&self) -> usize {
+        self.0 .0.presences.len()
+    }
+
+    /// Number of cached private channels.
+    pub fn private_channels(&self) -> usize {
+        self.0 .0.channels_private.len()
+    }
+
+    /// Number of cached roles of all guilds.
+    pub fn roles(&self) -> usize {
+        self.0 .0.roles.len()
+    }
+
+    /// Number of guilds that are unavailable.
+    pub fn unavailable_guilds(&self) -> usize {
+        self.0 .0.unavailable_guilds.len()
+    }
+
+    /// Number of cached users.
+    pub fn users(&self) -> usize {
+        self.0 .0.users.len()
+    }
+
+    /// Number of cached voice states of all guilds.
+    pub fn voice_states(&self) -> usize {
+        self.0 .0.voice_states.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InMemoryCacheStats;
+    use crate::InMemoryCache;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        gateway::payload::RoleCreate,
+        guild::{Permissions, Role},
+        id::{GuildId, RoleId},
+    };
+
+    assert_impl_all!(InMemoryCacheStats<'_>: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_stats_roles() {
+        let cache = InMemoryCache::new();
+        assert_eq!(0, cache.stats().roles());
+        assert!(cache.stats().guild_roles(GuildId(1)).is_none());
+
+        cache.update(&RoleCreate {
+            guild_id: GuildId(1),
+            role: Role {
+                color: 0,
+                hoist: false,
+                id: RoleId(2),
+                managed: false,
+                mentionable: false,
+                name: "test".to_owned(),
+                permissions: Permissions::empty(),
+                position: 0,
+            },
+        });
+
+        assert_eq!(1, cache.stats().roles());
+        assert_eq!(0, cache.stats().messages());
+    }
+}
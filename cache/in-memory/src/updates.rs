@@ -1,8 +1,11 @@
-use super::{config::EventType, InMemoryCache};
+use super::{
+    config::{EventType, ResourceType},
+    InMemoryCache,
+};
 use dashmap::DashMap;
 use std::{borrow::Cow, collections::HashSet, hash::Hash, ops::Deref, sync::Arc};
 use twilight_model::{
-    channel::{message::MessageReaction, Channel, GuildChannel},
+    channel::{message::MessageReaction, Channel, GuildChannel, ReactionType},
     gateway::{event::Event, payload::*, presence::Presence},
     guild::GuildStatus,
     id::GuildId,
@@ -24,6 +27,10 @@ impl UpdateCache for Event {
         use Event::*;
 
         match self {
+            AutoModerationActionExecution(_) => {}
+            AutoModerationRuleCreate(_) => {}
+            AutoModerationRuleDelete(_) => {}
+            AutoModerationRuleUpdate(_) => {}
             BanAdd(_) => {}
             BanRemove(_) => {}
             ChannelCreate(v) => c.update(v),
@@ -40,7 +47,13 @@ impl UpdateCache for Event {
             GuildDelete(v) => c.update(v.deref()),
             GuildEmojisUpdate(v) => c.update(v),
             GuildIntegrationsUpdate(v) => c.update(v),
+            GuildScheduledEventCreate(_) => {}
+            GuildScheduledEventDelete(_) => {}
+            GuildScheduledEventUpdate(_) => {}
+            GuildScheduledEventUserAdd(_) => {}
+            GuildScheduledEventUserRemove(_) => {}
             GuildUpdate(v) => c.update(v.deref()),
+            InteractionCreate(_) => {}
             InviteCreate(_) => {}
             InviteDelete(_) => {}
             MemberAdd(v) => c.update(v.deref()),
@@ -56,7 +69,7 @@ impl UpdateCache for Event {
             ReactionAdd(v) => c.update(v.deref()),
             ReactionRemove(v) => c.update(v.deref()),
             ReactionRemoveAll(v) => c.update(v),
-            ReactionRemoveEmoji(_) => {}
+            ReactionRemoveEmoji(v) => c.update(v),
             Ready(v) => c.update(v.deref()),
             Resumed => {}
             RoleCreate(v) => c.update(v),
@@ -69,6 +82,15 @@ impl UpdateCache for Event {
             ShardReconnecting(_) => {}
             ShardPayload(_) => {}
             ShardResuming(_) => {}
+            StageInstanceCreate(_) => {}
+            StageInstanceDelete(_) => {}
+            StageInstanceUpdate(_) => {}
+            ThreadCreate(v) => c.update(v.deref()),
+            ThreadDelete(v) => c.update(v),
+            ThreadListSync(_) => {}
+            ThreadMemberUpdate(_) => {}
+            ThreadMembersUpdate(_) => {}
+            ThreadUpdate(v) => c.update(v.deref()),
             TypingStart(v) => c.update(v.deref()),
             UnavailableGuild(v) => c.update(v),
             UserUpdate(v) => c.update(v),
@@ -85,7 +107,7 @@ impl UpdateCache for BanRemove {}
 
 impl UpdateCache for ChannelCreate {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::CHANNEL_CREATE) {
+        if !guard(cache, EventType::CHANNEL_CREATE) || !cache.wants(ResourceType::CHANNEL) {
             return;
         }
 
@@ -107,7 +129,7 @@ impl UpdateCache for ChannelCreate {
 
 impl UpdateCache for ChannelDelete {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::CHANNEL_DELETE) {
+        if !guard(cache, EventType::CHANNEL_DELETE) || !cache.wants(ResourceType::CHANNEL) {
             return;
         }
 
@@ -127,7 +149,7 @@ impl UpdateCache for ChannelDelete {
 
 impl UpdateCache for ChannelPinsUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::CHANNEL_PINS_UPDATE) {
+        if !guard(cache, EventType::CHANNEL_PINS_UPDATE) || !cache.wants(ResourceType::CHANNEL) {
             return;
         }
 
@@ -155,7 +177,7 @@ impl UpdateCache for ChannelPinsUpdate {
 
 impl UpdateCache for ChannelUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::CHANNEL_UPDATE) {
+        if !guard(cache, EventType::CHANNEL_UPDATE) || !cache.wants(ResourceType::CHANNEL) {
             return;
         }
 
@@ -209,6 +231,7 @@ impl UpdateCache for GuildDelete {
 
         remove_ids(&cache.0.guild_channels, &cache.0.channels_guild, id);
         remove_ids(&cache.0.guild_emojis, &cache.0.emojis, id);
+        cache.0.guild_emoji_names.remove(&id);
         remove_ids(&cache.0.guild_roles, &cache.0.roles, id);
         // Clear out a guilds voice states when a guild leaves
         cache.0.voice_state_guilds.remove(&id);
@@ -224,15 +247,35 @@ impl UpdateCache for GuildDelete {
                 cache.0.presences.remove(&(id, user_id));
             }
         }
+
+        cache.prune_strings();
     }
 }
 
 impl UpdateCache for GuildEmojisUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::GUILD_EMOJIS_UPDATE) {
+        if !guard(cache, EventType::GUILD_EMOJIS_UPDATE) || !cache.wants(ResourceType::EMOJI) {
             return;
         }
 
+        // The event contains the full list of the guild's emojis, so remove
+        // any cached emojis that are no longer present.
+        let removed = cache
+            .0
+            .guild_emojis
+            .get(&self.guild_id)
+            .map(|ids| {
+                ids.iter()
+                    .filter(|id| !self.emojis.contains_key(id))
+                    .copied()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        for emoji_id in removed {
+            cache.delete_emoji(emoji_id);
+        }
+
         cache.cache_emojis(self.guild_id, self.emojis.values().cloned());
     }
 }
@@ -241,7 +284,7 @@ impl UpdateCache for GuildIntegrationsUpdate {}
 
 impl UpdateCache for GuildUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::GUILD_UPDATE) {
+        if !guard(cache, EventType::GUILD_UPDATE) || !cache.wants(ResourceType::GUILD) {
             return;
         }
 
@@ -252,7 +295,7 @@ impl UpdateCache for GuildUpdate {
             guild.banner = self.banner.clone();
             guild.default_message_notifications = self.default_message_notifications;
             guild.description = self.description.clone();
-            guild.features = self.features.clone();
+            guild.features = cache.intern_all(&self.features);
             guild.icon = self.icon.clone();
             guild.max_members = self.max_members;
             guild.max_presences = Some(self.max_presences.unwrap_or(25000));
@@ -261,12 +304,12 @@ impl UpdateCache for GuildUpdate {
             guild.owner = self.owner;
             guild.owner_id = self.owner_id;
             guild.permissions = self.permissions;
-            guild.preferred_locale = self.preferred_locale.clone();
+            guild.preferred_locale = cache.intern(&self.preferred_locale);
             guild.premium_tier = self.premium_tier;
             guild
                 .premium_subscription_count
                 .replace(self.premium_subscription_count.unwrap_or_default());
-            guild.region = self.region.clone();
+            guild.region = cache.intern(&self.region);
             guild.splash = self.splash.clone();
             guild.system_channel_id = self.system_channel_id;
             guild.verification_level = self.verification_level;
@@ -274,12 +317,16 @@ impl UpdateCache for GuildUpdate {
             guild.widget_channel_id = self.widget_channel_id;
             guild.widget_enabled = self.widget_enabled;
         };
+
+        // The guild may no longer hold its previous features, locale, or
+        // region.
+        cache.prune_strings();
     }
 }
 
 impl UpdateCache for MemberAdd {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::MEMBER_ADD) {
+        if !guard(cache, EventType::MEMBER_ADD) || !cache.wants(ResourceType::MEMBER) {
             return;
         }
 
@@ -296,7 +343,7 @@ impl UpdateCache for MemberAdd {
 
 impl UpdateCache for MemberChunk {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::MEMBER_CHUNK) {
+        if !guard(cache, EventType::MEMBER_CHUNK) || !cache.wants(ResourceType::MEMBER) {
             return;
         }
 
@@ -312,7 +359,7 @@ impl UpdateCache for MemberChunk {
 
 impl UpdateCache for MemberRemove {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::MEMBER_REMOVE) {
+        if !guard(cache, EventType::MEMBER_REMOVE) || !cache.wants(ResourceType::MEMBER) {
             return;
         }
 
@@ -343,7 +390,7 @@ impl UpdateCache for MemberRemove {
 
 impl UpdateCache for MemberUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::MEMBER_UPDATE) {
+        if !guard(cache, EventType::MEMBER_UPDATE) || !cache.wants(ResourceType::MEMBER) {
             return;
         }
 
@@ -353,9 +400,9 @@ impl UpdateCache for MemberUpdate {
         };
         let mut member = Arc::make_mut(&mut member);
 
-        member.nick = self.nick.clone();
+        member.nick = self.nick.as_deref().map(Box::from);
         member.roles = self.roles.clone();
-        member.joined_at.replace(self.joined_at.clone());
+        member.joined_at.replace(self.joined_at.as_str().into());
     }
 }
 
@@ -365,18 +412,25 @@ impl UpdateCache for MessageCreate {
             return;
         }
 
-        let mut channel = cache.0.messages.entry(self.0.channel_id).or_default();
+        if cache.wants(ResourceType::MESSAGE) {
+            let mut channel = cache.0.messages.entry(self.0.channel_id).or_default();
 
-        if channel.len() > cache.0.config.message_cache_size() {
-            if let Some(k) = channel.iter().next_back().map(|x| *x.0) {
-                channel.remove(&k);
+            // Evict the oldest message to stay within the configured size.
+            if channel.len() >= cache.0.config.message_cache_size() {
+                if let Some(k) = channel.keys().next().copied() {
+                    channel.remove(&k);
+                }
             }
-        }
 
-        channel.insert(self.0.id, Arc::new(From::from(self.0.clone())));
+            channel.insert(self.0.id, Arc::new(From::from(self.0.clone())));
+        }
 
         let user = cache.cache_user(Cow::Borrowed(&self.author), self.guild_id);
 
+        if !cache.wants(ResourceType::MEMBER) {
+            return;
+        }
+
         if let (Some(member), Some(guild_id)) = (&self.member, self.guild_id) {
             cache.cache_borrowed_partial_member(guild_id, member, user);
         }
@@ -385,7 +439,7 @@ impl UpdateCache for MessageCreate {
 
 impl UpdateCache for MessageDelete {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::MESSAGE_DELETE) {
+        if !guard(cache, EventType::MESSAGE_DELETE) || !cache.wants(ResourceType::MESSAGE) {
             return;
         }
 
@@ -396,7 +450,7 @@ impl UpdateCache for MessageDelete {
 
 impl UpdateCache for MessageDeleteBulk {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::MESSAGE_DELETE_BULK) {
+        if !guard(cache, EventType::MESSAGE_DELETE_BULK) || !cache.wants(ResourceType::MESSAGE) {
             return;
         }
 
@@ -410,7 +464,7 @@ impl UpdateCache for MessageDeleteBulk {
 
 impl UpdateCache for MessageUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::MESSAGE_UPDATE) {
+        if !guard(cache, EventType::MESSAGE_UPDATE) || !cache.wants(ResourceType::MESSAGE) {
             return;
         }
 
@@ -464,7 +518,7 @@ impl UpdateCache for MessageUpdate {
 
 impl UpdateCache for PresenceUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::PRESENCE_UPDATE) {
+        if !guard(cache, EventType::PRESENCE_UPDATE) || !cache.wants(ResourceType::PRESENCE) {
             return;
         }
 
@@ -482,7 +536,7 @@ impl UpdateCache for PresenceUpdate {
 
 impl UpdateCache for ReactionAdd {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::REACTION_ADD) {
+        if !guard(cache, EventType::REACTION_ADD) || !cache.wants(ResourceType::MESSAGE) {
             return;
         }
 
@@ -522,7 +576,7 @@ impl UpdateCache for ReactionAdd {
 
 impl UpdateCache for ReactionRemove {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::REACTION_REMOVE) {
+        if !guard(cache, EventType::REACTION_REMOVE) || !cache.wants(ResourceType::MESSAGE) {
             return;
         }
 
@@ -555,7 +609,7 @@ impl UpdateCache for ReactionRemove {
 
 impl UpdateCache for ReactionRemoveAll {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::REACTION_REMOVE_ALL) {
+        if !guard(cache, EventType::REACTION_REMOVE_ALL) || !cache.wants(ResourceType::MESSAGE) {
             return;
         }
 
@@ -571,13 +625,36 @@ impl UpdateCache for ReactionRemoveAll {
     }
 }
 
+impl UpdateCache for ReactionRemoveEmoji {
+    fn update(&self, cache: &InMemoryCache) {
+        if !guard(cache, EventType::REACTION_REMOVE_EMOJI) || !cache.wants(ResourceType::MESSAGE) {
+            return;
+        }
+
+        let mut channel = cache.0.messages.entry(self.channel_id).or_default();
+
+        let message = match channel.get_mut(&self.message_id) {
+            Some(message) => message,
+            None => return,
+        };
+
+        let msg = Arc::make_mut(message);
+        msg.reactions.retain(|reaction| match &reaction.emoji {
+            ReactionType::Custom { id, .. } => self.emoji.id != Some(*id),
+            ReactionType::Unicode { name } => self.emoji.id.is_some() || self.emoji.name != *name,
+        });
+    }
+}
+
 impl UpdateCache for Ready {
     fn update(&self, cache: &InMemoryCache) {
         if !guard(cache, EventType::READY) {
             return;
         }
 
-        cache.cache_current_user(self.user.clone());
+        if cache.wants(ResourceType::USER) {
+            cache.cache_current_user(self.user.clone());
+        }
 
         for status in self.guilds.values() {
             match status {
@@ -594,22 +671,17 @@ impl UpdateCache for Ready {
 
 impl UpdateCache for RoleCreate {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::ROLE_CREATE) {
+        if !guard(cache, EventType::ROLE_CREATE) || !cache.wants(ResourceType::ROLE) {
             return;
         }
 
-        super::upsert_guild_item(
-            &cache.0.roles,
-            self.guild_id,
-            self.role.id,
-            self.role.clone(),
-        );
+        cache.cache_role(self.guild_id, self.role.clone());
     }
 }
 
 impl UpdateCache for RoleDelete {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::ROLE_DELETE) {
+        if !guard(cache, EventType::ROLE_DELETE) || !cache.wants(ResourceType::ROLE) {
             return;
         }
 
@@ -619,7 +691,7 @@ impl UpdateCache for RoleDelete {
 
 impl UpdateCache for RoleUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::ROLE_UPDATE) {
+        if !guard(cache, EventType::ROLE_UPDATE) || !cache.wants(ResourceType::ROLE) {
             return;
         }
 
@@ -627,6 +699,40 @@ impl UpdateCache for RoleUpdate {
     }
 }
 
+impl UpdateCache for ThreadCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !guard(cache, EventType::THREAD_CREATE) || !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        if let Some(gid) = self.0.guild_id() {
+            cache.cache_guild_channel(gid, self.0.clone());
+        }
+    }
+}
+
+impl UpdateCache for ThreadDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if !guard(cache, EventType::THREAD_DELETE) || !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        cache.delete_guild_channel(self.id);
+    }
+}
+
+impl UpdateCache for ThreadUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !guard(cache, EventType::THREAD_UPDATE) || !cache.wants(ResourceType::CHANNEL) {
+            return;
+        }
+
+        if let Some(gid) = self.0.guild_id() {
+            cache.cache_guild_channel(gid, self.0.clone());
+        }
+    }
+}
+
 impl UpdateCache for TypingStart {}
 
 impl UpdateCache for UnavailableGuild {
@@ -637,12 +743,13 @@ impl UpdateCache for UnavailableGuild {
 
         cache.0.guilds.remove(&self.id);
         cache.0.unavailable_guilds.insert(self.id);
+        cache.prune_strings();
     }
 }
 
 impl UpdateCache for UserUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::USER_UPDATE) {
+        if !guard(cache, EventType::USER_UPDATE) || !cache.wants(ResourceType::USER) {
             return;
         }
 
@@ -660,7 +767,7 @@ impl UpdateCache for VoiceServerUpdate {
 
 impl UpdateCache for VoiceStateUpdate {
     fn update(&self, cache: &InMemoryCache) {
-        if !guard(cache, EventType::VOICE_STATE_UPDATE) {
+        if !guard(cache, EventType::VOICE_STATE_UPDATE) || !cache.wants(ResourceType::VOICE_STATE) {
             return;
         }
 
@@ -676,8 +783,12 @@ mod tests {
     use crate::config::EventType;
     use std::collections::HashMap;
     use twilight_model::{
+        channel::{
+            message::{MessageFlags, MessageType},
+            Message, Reaction,
+        },
         channel::{ChannelType, GuildChannel, TextChannel},
-        gateway::payload::ChannelDelete,
+        gateway::payload::{reaction_remove_emoji::PartialEmoji, ChannelDelete},
         guild::DefaultMessageNotificationLevel,
         guild::ExplicitContentFilter,
         guild::Guild,
@@ -686,7 +797,8 @@ mod tests {
         guild::PremiumTier,
         guild::SystemChannelFlags,
         guild::VerificationLevel,
-        id::{ChannelId, GuildId, UserId},
+        id::{ChannelId, EmojiId, GuildId, MessageId, UserId},
+        user::User,
         voice::VoiceState,
     };
 
@@ -711,6 +823,66 @@ mod tests {
         (guild_id, channel_id, channel)
     }
 
+    fn message(channel_id: ChannelId, message_id: MessageId) -> Message {
+        Message {
+            activity: None,
+            application: None,
+            attachments: Vec::new(),
+            author: User {
+                avatar: None,
+                bot: false,
+                discriminator: "0001".to_owned(),
+                email: None,
+                flags: None,
+                id: UserId(3),
+                locale: None,
+                mfa_enabled: None,
+                name: "test".to_owned(),
+                premium_type: None,
+                public_flags: None,
+                system: None,
+                verified: None,
+            },
+            channel_id,
+            components: Vec::new(),
+            content: "ping".into(),
+            edited_timestamp: None,
+            embeds: Vec::new(),
+            flags: Some(MessageFlags::empty()),
+            guild_id: Some(GuildId(1)),
+            id: message_id,
+            kind: MessageType::Regular,
+            member: None,
+            mention_channels: Vec::new(),
+            mention_everyone: false,
+            mention_roles: Vec::new(),
+            mentions: HashMap::new(),
+            pinned: false,
+            reactions: Vec::new(),
+            reference: None,
+            sticker_items: Vec::new(),
+            timestamp: "".into(),
+            tts: false,
+            webhook_id: None,
+        }
+    }
+
+    fn reaction(
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: ReactionType,
+        user_id: UserId,
+    ) -> Reaction {
+        Reaction {
+            channel_id,
+            emoji,
+            guild_id: Some(GuildId(1)),
+            member: None,
+            message_id,
+            user_id,
+        }
+    }
+
     #[test]
     fn test_guild_update() {
         let cache = InMemoryCache::new();
@@ -753,6 +925,7 @@ mod tests {
             splash: None,
             system_channel_flags: SystemChannelFlags::empty(),
             system_channel_id: None,
+            threads: HashMap::new(),
             unavailable: false,
             vanity_url_code: None,
             verification_level: VerificationLevel::VeryHigh,
@@ -878,7 +1051,7 @@ mod tests {
             .event_types(EventType::MESSAGE_CREATE)
             .message_cache_size(1)
             .build();
-        let msg = Message {
+        let mut msg = Message {
             activity: None,
             application: None,
             attachments: Vec::new(),
@@ -898,7 +1071,8 @@ mod tests {
                 verified: None,
             },
             channel_id: ChannelId(2),
-            content: "ping".to_owned(),
+            components: Vec::new(),
+            content: "ping".into(),
             edited_timestamp: None,
             embeds: Vec::new(),
             flags: Some(MessageFlags::empty()),
@@ -919,12 +1093,13 @@ mod tests {
             pinned: false,
             reactions: Vec::new(),
             reference: None,
-            timestamp: String::new(),
+            sticker_items: Vec::new(),
+            timestamp: "".into(),
             tts: false,
             webhook_id: None,
         };
 
-        cache.update(&MessageCreate(msg));
+        cache.update(&MessageCreate(msg.clone()));
 
         {
             let entry = cache.0.users.get(&UserId(3)).unwrap();
@@ -938,5 +1113,130 @@ mod tests {
             let entry = cache.0.messages.get(&ChannelId(2)).unwrap();
             assert_eq!(entry.value().len(), 1);
         }
+
+        // The oldest message is evicted once the channel is full.
+        msg.id = MessageId(5);
+        cache.update(&MessageCreate(msg));
+
+        assert!(cache.message(ChannelId(2), MessageId(4)).is_none());
+        assert!(cache.message(ChannelId(2), MessageId(5)).is_some());
+    }
+
+    #[test]
+    fn test_guild_emojis_update_removes_deleted() {
+        use twilight_model::{guild::Emoji, id::EmojiId};
+
+        fn emoji(id: u64, name: &str) -> Emoji {
+            Emoji {
+                animated: false,
+                available: true,
+                id: EmojiId(id),
+                managed: false,
+                name: name.to_owned(),
+                require_colons: true,
+                roles: Vec::new(),
+                user: None,
+            }
+        }
+
+        let cache = InMemoryCache::builder().emoji_name_index(true).build();
+        let mut emojis = HashMap::new();
+        emojis.insert(EmojiId(1), emoji(1, "foo"));
+        emojis.insert(EmojiId(2), emoji(2, "bar"));
+
+        cache.update(&GuildEmojisUpdate {
+            emojis: emojis.clone(),
+            guild_id: GuildId(1),
+        });
+        assert_eq!(2, cache.guild_emojis(GuildId(1)).unwrap().len());
+
+        emojis.remove(&EmojiId(2));
+        cache.update(&GuildEmojisUpdate {
+            emojis,
+            guild_id: GuildId(1),
+        });
+
+        assert!(cache.emoji(EmojiId(2)).is_none());
+        assert!(cache.emoji_by_name(GuildId(1), "bar").is_none());
+        assert_eq!(1, cache.guild_emojis(GuildId(1)).unwrap().len());
+        assert!(cache.emoji_by_name(GuildId(1), "foo").is_some());
+    }
+
+    #[test]
+    fn test_reactions() {
+        let cache = InMemoryCache::new();
+        let (channel_id, message_id) = (ChannelId(2), MessageId(4));
+        let custom = ReactionType::Custom {
+            animated: false,
+            id: EmojiId(6),
+            name: Some("foo".to_owned()),
+        };
+        let unicode = ReactionType::Unicode {
+            name: "🍎".to_owned(),
+        };
+
+        cache.update(&MessageCreate(message(channel_id, message_id)));
+
+        for user_id in 1..=2 {
+            cache.update(&ReactionAdd(reaction(
+                channel_id,
+                message_id,
+                custom.clone(),
+                UserId(user_id),
+            )));
+            cache.update(&ReactionAdd(reaction(
+                channel_id,
+                message_id,
+                unicode.clone(),
+                UserId(user_id),
+            )));
+        }
+
+        {
+            let msg = cache.message(channel_id, message_id).unwrap();
+            assert_eq!(2, msg.reactions.len());
+            assert!(msg.reactions.iter().all(|r| r.count == 2));
+        }
+
+        cache.update(&ReactionRemove(reaction(
+            channel_id,
+            message_id,
+            unicode.clone(),
+            UserId(1),
+        )));
+
+        {
+            let msg = cache.message(channel_id, message_id).unwrap();
+            let reaction = msg.reactions.iter().find(|r| r.emoji == unicode).unwrap();
+            assert_eq!(1, reaction.count);
+        }
+
+        cache.update(&ReactionRemoveEmoji {
+            channel_id,
+            emoji: PartialEmoji {
+                id: Some(EmojiId(6)),
+                name: "foo".to_owned(),
+            },
+            guild_id: GuildId(1),
+            message_id,
+        });
+
+        {
+            let msg = cache.message(channel_id, message_id).unwrap();
+            assert_eq!(1, msg.reactions.len());
+            assert_eq!(unicode, msg.reactions[0].emoji);
+        }
+
+        cache.update(&ReactionRemoveAll {
+            channel_id,
+            message_id,
+            guild_id: Some(GuildId(1)),
+        });
+
+        assert!(cache
+            .message(channel_id, message_id)
+            .unwrap()
+            .reactions
+            .is_empty());
     }
 }
@@ -0,0 +1,305 @@
+use super::{
+    model::{CachedEmoji, CachedGuild, CachedMember, CachedMessage, CachedPresence, CachedRole},
+    Config, InMemoryCache, InMemoryCacheIter, InMemoryCacheStats,
+};
+use std::{collections::HashSet, sync::Arc};
+use twilight_model::{
+    channel::{permission_overwrite::PermissionOverwrite, Group, GuildChannel, PrivateChannel},
+    id::{ChannelId, EmojiId, GuildId, MessageId, RoleId, UserId},
+    user::{CurrentUser, User},
+    voice::VoiceState,
+};
+
+/// Read-only handle to an [`InMemoryCache`].
+///
+/// A view exposes only the retrieval methods of the cache; it can't be used
+/// to process events or otherwise mutate the cache. This is useful for giving
+/// parts of an application lookup access without also allowing them to change
+/// the cache's state.
+///
+/// Like the cache itself, a view is cheap to clone and can be sent to other
+/// threads. A view reflects all updates made to the cache it was created from.
+///
+/// # Examples
+///
+/// Create a view of a cache:
+///
+/// ```
+/// use twilight_cache_inmemory::InMemoryCache;
+/// use twilight_model::id::GuildId;
+///
+/// let cache = InMemoryCache::new();
+/// let view = cache.view();
+///
+/// assert!(view.guild(GuildId(1)).is_none());
+/// ```
+///
+/// [`InMemoryCache`]: struct.InMemoryCache.html
+#[derive(Clone, Debug)]
+pub struct CacheView(InMemoryCache);
+
+impl CacheView {
+    /// Returns a copy of the config of the cache.
+    pub fn config(&self) -> Config {
+        self.0.config()
+    }
+
+    /// Create an interface for iterating over the resources in the cache.
+    ///
+    /// Refer to [`InMemoryCache::iter`] for more information.
+    ///
+    /// [`InMemoryCache::iter`]: struct.InMemoryCache.html#method.iter
+    pub fn iter(&self) -> InMemoryCacheIter<'_> {
+        self.0.iter()
+    }
+
+    /// Create an interface for retrieving the number of resources in the
+    /// cache.
+    ///
+    /// Refer to [`InMemoryCache::stats`] for more information.
+    ///
+    /// [`InMemoryCache::stats`]: struct.InMemoryCache.html#method.stats
+    pub fn stats(&self) -> InMemoryCacheStats<'_> {
+        self.0.stats()
+    }
+
+    /// Gets the current user.
+    ///
+    /// Refer to [`InMemoryCache::current_user`] for more information.
+    ///
+    /// [`InMemoryCache::current_user`]: struct.InMemoryCache.html#method.current_user
+    pub fn current_user(&self) -> Option<Arc<CurrentUser>> {
+        self.0.current_user()
+    }
+
+    /// Gets an emoji by ID.
+    ///
+    /// Refer to [`InMemoryCache::emoji`] for more information.
+    ///
+    /// [`InMemoryCache::emoji`]: struct.InMemoryCache.html#method.emoji
+    pub fn emoji(&self, emoji_id: EmojiId) -> Option<Arc<CachedEmoji>> {
+        self.0.emoji(emoji_id)
+    }
+
+    /// Gets an emoji in a guild by its name.
+    ///
+    /// Refer to [`InMemoryCache::emoji_by_name`] for more information.
+    ///
+    /// [`InMemoryCache::emoji_by_name`]: struct.InMemoryCache.html#method.emoji_by_name
+    pub fn emoji_by_name(&self, guild_id: GuildId, name: &str) -> Option<Arc<CachedEmoji>> {
+        self.0.emoji_by_name(guild_id, name)
+    }
+
+    /// Gets a group by ID.
+    ///
+    /// Refer to [`InMemoryCache::group`] for more information.
+    ///
+    /// [`InMemoryCache::group`]: struct.InMemoryCache.html#method.group
+    pub fn group(&self, channel_id: ChannelId) -> Option<Arc<Group>> {
+        self.0.group(channel_id)
+    }
+
+    /// Gets a guild by ID.
+    ///
+    /// Refer to [`InMemoryCache::guild`] for more information.
+    ///
+    /// [`InMemoryCache::guild`]: struct.InMemoryCache.html#method.guild
+    pub fn guild(&self, guild_id: GuildId) -> Option<Arc<CachedGuild>> {
+        self.0.guild(guild_id)
+    }
+
+    /// Gets a channel by ID.
+    ///
+    /// Refer to [`InMemoryCache::guild_channel`] for more information.
+    ///
+    /// [`InMemoryCache::guild_channel`]: struct.InMemoryCache.html#method.guild_channel
+    pub fn guild_channel(&self, channel_id: ChannelId) -> Option<Arc<GuildChannel>> {
+        self.0.guild_channel(channel_id)
+    }
+
+    /// Gets the set of channels in a guild.
+    ///
+    /// Refer to [`InMemoryCache::guild_channels`] for more information.
+    ///
+    /// [`InMemoryCache::guild_channels`]: struct.InMemoryCache.html#method.guild_channels
+    pub fn guild_channels(&self, guild_id: GuildId) -> Option<HashSet<ChannelId>> {
+        self.0.guild_channels(guild_id)
+    }
+
+    /// Gets the set of emojis in a guild.
+    ///
+    /// Refer to [`InMemoryCache::guild_emojis`] for more information.
+    ///
+    /// [`InMemoryCache::guild_emojis`]: struct.InMemoryCache.html#method.guild_emojis
+    pub fn guild_emojis(&self, guild_id: GuildId) -> Option<HashSet<EmojiId>> {
+        self.0.guild_emojis(guild_id)
+    }
+
+    /// Gets the set of members in a guild.
+    ///
+    /// Refer to [`InMemoryCache::guild_members`] for more information.
+    ///
+    /// [`InMemoryCache::guild_members`]: struct.InMemoryCache.html#method.guild_members
+    pub fn guild_members(&self, guild_id: GuildId) -> Option<HashSet<UserId>> {
+        self.0.guild_members(guild_id)
+    }
+
+    /// Gets the set of presences in a guild.
+    ///
+    /// Refer to [`InMemoryCache::guild_presences`] for more information.
+    ///
+    /// [`InMemoryCache::guild_presences`]: struct.InMemoryCache.html#method.guild_presences
+    pub fn guild_presences(&self, guild_id: GuildId) -> Option<HashSet<UserId>> {
+        self.0.guild_presences(guild_id)
+    }
+
+    /// Gets the set of roles in a guild.
+    ///
+    /// Refer to [`InMemoryCache::guild_roles`] for more information.
+    ///
+    /// [`InMemoryCache::guild_roles`]: struct.InMemoryCache.html#method.guild_roles
+    pub fn guild_roles(&self, guild_id: GuildId) -> Option<HashSet<RoleId>> {
+        self.0.guild_roles(guild_id)
+    }
+
+    /// Gets a member by guild ID and user ID.
+    ///
+    /// Refer to [`InMemoryCache::member`] for more information.
+    ///
+    /// [`InMemoryCache::member`]: struct.InMemoryCache.html#method.member
+    pub fn member(&self, guild_id: GuildId, user_id: UserId) -> Option<Arc<CachedMember>> {
+        self.0.member(guild_id, user_id)
+    }
+
+    /// Gets a message by channel ID and message ID.
+    ///
+    /// Refer to [`InMemoryCache::message`] for more information.
+    ///
+    /// [`InMemoryCache::message`]: struct.InMemoryCache.html#method.message
+    pub fn message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Option<Arc<CachedMessage>> {
+        self.0.message(channel_id, message_id)
+    }
+
+    /// Gets the permission overwrites of a guild channel that apply to a
+    /// member, resolved into a single allow and deny pair.
+    ///
+    /// Refer to [`InMemoryCache::permission_overwrite`] for more information.
+    ///
+    /// [`InMemoryCache::permission_overwrite`]: struct.InMemoryCache.html#method.permission_overwrite
+    pub fn permission_overwrite(
+        &self,
+        channel_id: ChannelId,
+        user_id: UserId,
+    ) -> Option<PermissionOverwrite> {
+        self.0.permission_overwrite(channel_id, user_id)
+    }
+
+    /// Gets a presence by guild ID and user ID.
+    ///
+    /// Refer to [`InMemoryCache::presence`] for more information.
+    ///
+    /// [`InMemoryCache::presence`]: struct.InMemoryCache.html#method.presence
+    pub fn presence(&self, guild_id: GuildId, user_id: UserId) -> Option<Arc<CachedPresence>> {
+        self.0.presence(guild_id, user_id)
+    }
+
+    /// Gets a private channel by ID.
+    ///
+    /// Refer to [`InMemoryCache::private_channel`] for more information.
+    ///
+    /// [`InMemoryCache::private_channel`]: struct.InMemoryCache.html#method.private_channel
+    pub fn private_channel(&self, channel_id: ChannelId) -> Option<Arc<PrivateChannel>> {
+        self.0.private_channel(channel_id)
+    }
+
+    /// Gets a role by ID.
+    ///
+    /// Refer to [`InMemoryCache::role`] for more information.
+    ///
+    /// [`InMemoryCache::role`]: struct.InMemoryCache.html#method.role
+    pub fn role(&self, role_id: RoleId) -> Option<Arc<CachedRole>> {
+        self.0.role(role_id)
+    }
+
+    /// Gets a user by ID.
+    ///
+    /// Refer to [`InMemoryCache::user`] for more information.
+    ///
+    /// [`InMemoryCache::user`]: struct.InMemoryCache.html#method.user
+    pub fn user(&self, user_id: UserId) -> Option<Arc<User>> {
+        self.0.user(user_id)
+    }
+
+    /// Gets the voice states within a voice channel.
+    ///
+    /// Refer to [`InMemoryCache::voice_channel_states`] for more information.
+    ///
+    /// [`InMemoryCache::voice_channel_states`]: struct.InMemoryCache.html#method.voice_channel_states
+    pub fn voice_channel_states(&self, channel_id: ChannelId) -> Option<Vec<Arc<VoiceState>>> {
+        self.0.voice_channel_states(channel_id)
+    }
+
+    /// Gets a voice state by user ID and guild ID.
+    ///
+    /// Refer to [`InMemoryCache::voice_state`] for more information.
+    ///
+    /// [`InMemoryCache::voice_state`]: struct.InMemoryCache.html#method.voice_state
+    pub fn voice_state(&self, user_id: UserId, guild_id: GuildId) -> Option<Arc<VoiceState>> {
+        self.0.voice_state(user_id, guild_id)
+    }
+}
+
+impl From<InMemoryCache> for CacheView {
+    fn from(cache: InMemoryCache) -> Self {
+        Self(cache)
+    }
+}
+
+impl From<&InMemoryCache> for CacheView {
+    fn from(cache: &InMemoryCache) -> Self {
+        Self(cache.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CacheView;
+    use crate::InMemoryCache;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        gateway::payload::RoleCreate,
+        guild::Permissions,
+        guild::Role,
+        id::{GuildId, RoleId},
+    };
+
+    assert_impl_all!(CacheView: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_view_reflects_updates() {
+        let cache = InMemoryCache::new();
+        let view = cache.view();
+        assert!(view.role(RoleId(2)).is_none());
+
+        cache.update(&RoleCreate {
+            guild_id: GuildId(1),
+            role: Role {
+                color: 0,
+                hoist: false,
+                id: RoleId(2),
+                managed: false,
+                mentionable: false,
+                name: "test".to_owned(),
+                permissions: Permissions::empty(),
+                position: 0,
+            },
+        });
+
+        assert!(view.role(RoleId(2)).is_some());
+    }
+}
@@ -1,14 +1,75 @@
+use crate::Options;
 use std::{
-    fmt::{Debug, Formatter, Result as FmtResult},
-    str::CharIndices,
+    borrow::Cow,
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    ops::Range,
+    str::{CharIndices, FromStr},
 };
 
+/// Parsing an argument into a type failed.
+///
+/// This is returned from [`Arguments::next_parse`] and
+/// [`Arguments::remainder_parse`].
+///
+/// [`Arguments::next_parse`]: struct.Arguments.html#method.next_parse
+/// [`Arguments::remainder_parse`]: struct.Arguments.html#method.remainder_parse
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseArgumentError<E> {
+    /// The argument wasn't provided.
+    Missing,
+    /// The argument was provided but couldn't be parsed into the type.
+    Invalid {
+        /// Argument that failed to parse.
+        argument: String,
+        /// Reason for the error.
+        source: E,
+    },
+}
+
+impl<E> Display for ParseArgumentError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Missing => f.write_str("the argument is missing"),
+            Self::Invalid { argument, .. } => {
+                f.write_str("the argument ")?;
+                Debug::fmt(argument, f)?;
+
+                f.write_str(" is invalid")
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseArgumentError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Missing => None,
+            Self::Invalid { source, .. } => Some(source),
+        }
+    }
+}
+
 /// An iterator over command arguments.
+///
+/// Arguments are separated by Unicode whitespace, which includes
+/// non-breaking and ideographic spaces as well as newlines. Since arguments
+/// are only split on whitespace, multi-character symbols such as emojis
+/// joined by zero width joiners and letters with combining accents are never
+/// split apart.
+///
+/// An argument containing whitespace can be wrapped in double quotes, and
+/// quotes or whitespace can be escaped with a backslash; use
+/// [`next_unescaped`] to get an argument with its escapes removed.
+///
+/// [`next_unescaped`]: #method.next_unescaped
 #[derive(Clone)]
 pub struct Arguments<'a> {
     buf: &'a str,
     indices: CharIndices<'a>,
     idx: usize,
+    offset: usize,
 }
 
 impl<'a> Arguments<'a> {
@@ -17,6 +78,67 @@ impl<'a> Arguments<'a> {
         Self::from(buf)
     }
 
+    /// Returns a new iterator of arguments from a buffer that starts at a
+    /// byte offset within the content it was parsed from.
+    pub(crate) fn with_offset(buf: &'a str, offset: usize) -> Self {
+        let mut arguments = Self::from(buf);
+        arguments.offset += offset;
+
+        arguments
+    }
+
+    /// Returns the byte offset of the arguments within the content they were
+    /// parsed from.
+    ///
+    /// For arguments of a [`Command`] this is the offset within the buffer
+    /// passed to the parser, and otherwise it's the offset within the buffer
+    /// passed to [`new`], which may start with whitespace.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`new`]: #method.new
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the next argument and the range of bytes it spans within the
+    /// content the arguments were parsed from.
+    ///
+    /// Refer to [`offset`] for what the content is. The span of a quoted
+    /// argument doesn't include its quotes, and the span of an argument with
+    /// escapes covers the argument as it was written.
+    ///
+    /// This is useful for pointing at the exact argument that failed to
+    /// validate in an error message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_command_parser::{CommandParserConfig, Parser};
+    ///
+    /// let mut config = CommandParserConfig::new();
+    /// config.add_prefix("!");
+    /// config.add_command("ban", false);
+    /// let parser = Parser::new(config);
+    ///
+    /// let content = r#"!ban 123 "being rude""#;
+    /// let mut command = parser.parse(content).unwrap();
+    /// assert_eq!(Some((5..8, "123")), command.arguments.next_span());
+    ///
+    /// let (span, argument) = command.arguments.next_span().unwrap();
+    /// assert_eq!("being rude", argument);
+    /// assert_eq!("being rude", &content[span]);
+    /// ```
+    ///
+    /// [`offset`]: #method.offset
+    pub fn next_span(&mut self) -> Option<(Range<usize>, &'a str)> {
+        let argument = self.next()?;
+        // Arguments are always slices of the buffer, so their position can
+        // be found from their address.
+        let start = self.offset + (argument.as_ptr() as usize - self.buf.as_ptr() as usize);
+
+        Some((start..start + argument.len(), argument))
+    }
+
     /// Returns a view of the underlying buffer of arguments.
     ///
     /// This is exactly like [`std::str::Chars::as_str`].
@@ -63,6 +185,166 @@ impl<'a> Arguments<'a> {
     pub fn into_remainder(self) -> Option<&'a str> {
         self.buf.get(self.idx..)
     }
+
+    /// Parses the remaining arguments into named options and positional
+    /// arguments.
+    ///
+    /// An argument starting with `--` is an option, and the following argument
+    /// is its value unless the option is one of the provided switches, the
+    /// following argument is another option, or there are no more arguments.
+    /// All arguments after a lone `--` are positional. An option can be
+    /// passed as positional by escaping it, such as `\--name`.
+    ///
+    /// If an option is provided multiple times then the last value is used.
+    /// Backslash escapes are removed from the names, values, and positional
+    /// arguments.
+    ///
+    /// # Examples
+    ///
+    /// Parse the arguments of a ban command with a `--days` option and a
+    /// `--silent` switch:
+    ///
+    /// ```rust
+    /// use twilight_command_parser::Arguments;
+    ///
+    /// let args = Arguments::new(r#"123 --silent --days 7 "being rude""#);
+    /// let options = args.into_options(&["silent"]);
+    ///
+    /// assert!(options.contains("silent"));
+    /// assert_eq!(Some("7"), options.get("days"));
+    /// assert_eq!(["123", "being rude"], options.positional());
+    /// ```
+    pub fn into_options(mut self, switches: &[&str]) -> Options<'a> {
+        let mut options = Options::default();
+        let mut pending: Option<Cow<'a, str>> = None;
+
+        while let Some(argument) = self.next() {
+            if argument == "--" {
+                if let Some(name) = pending.take() {
+                    options.options.insert(name, None);
+                }
+
+                options.positional.extend(self.by_ref().map(unescape));
+
+                break;
+            }
+
+            if let Some(name) = argument.strip_prefix("--") {
+                if let Some(name) = pending.take() {
+                    options.options.insert(name, None);
+                }
+
+                let name = unescape(name);
+
+                if switches.contains(&name.as_ref()) {
+                    options.options.insert(name, None);
+                } else {
+                    pending.replace(name);
+                }
+            } else if let Some(name) = pending.take() {
+                options.options.insert(name, Some(unescape(argument)));
+            } else {
+                options.positional.push(unescape(argument));
+            }
+        }
+
+        if let Some(name) = pending {
+            options.options.insert(name, None);
+        }
+
+        options
+    }
+
+    /// Parses the next argument into a type.
+    ///
+    /// # Examples
+    ///
+    /// Parse a user ID and a number of days:
+    ///
+    /// ```rust
+    /// use twilight_command_parser::{Arguments, ParseArgumentError};
+    ///
+    /// let mut args = Arguments::new("123 seven");
+    /// assert_eq!(Ok(123), args.next_parse::<u64>());
+    /// assert!(matches!(
+    ///     args.next_parse::<u8>(),
+    ///     Err(ParseArgumentError::Invalid { .. }),
+    /// ));
+    /// assert!(matches!(
+    ///     args.next_parse::<u8>(),
+    ///     Err(ParseArgumentError::Missing),
+    /// ));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseArgumentError::Missing`] if there are no more
+    /// arguments.
+    ///
+    /// Returns [`ParseArgumentError::Invalid`] if the argument couldn't be
+    /// parsed into the type. The argument is consumed either way.
+    ///
+    /// [`ParseArgumentError::Invalid`]: enum.ParseArgumentError.html#variant.Invalid
+    /// [`ParseArgumentError::Missing`]: enum.ParseArgumentError.html#variant.Missing
+    pub fn next_parse<T: FromStr>(&mut self) -> Result<T, ParseArgumentError<T::Err>> {
+        parse_argument(self.next_unescaped().as_deref())
+    }
+
+    /// Returns the next argument with its backslash escapes removed.
+    ///
+    /// Arguments may contain quotes and spaces by escaping them with a
+    /// backslash, such as `\"` or `\ `, and a backslash itself may be escaped
+    /// as `\\`. The [`Iterator`] implementation returns arguments with their
+    /// escapes as they are in the buffer; this removes them, only allocating
+    /// if the argument contains any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_command_parser::Arguments;
+    ///
+    /// let mut args = Arguments::new(r#"reason "said \"hi\" twice" more"#);
+    /// assert_eq!(Some("reason"), args.next_unescaped().as_deref());
+    /// assert_eq!(Some(r#"said "hi" twice"#), args.next_unescaped().as_deref());
+    /// assert_eq!(Some("more"), args.next_unescaped().as_deref());
+    /// assert!(args.next_unescaped().is_none());
+    /// ```
+    ///
+    /// [`Iterator`]: #impl-Iterator
+    pub fn next_unescaped(&mut self) -> Option<Cow<'a, str>> {
+        self.next().map(unescape)
+    }
+
+    /// Parses the remainder of the buffer that hasn't been parsed into a type.
+    ///
+    /// This is like [`into_remainder`], but parses the remainder.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_command_parser::Arguments;
+    ///
+    /// let mut args = Arguments::new("ban spamming in general");
+    /// assert_eq!(Some("ban"), args.next());
+    /// assert_eq!(
+    ///     Ok("spamming in general".to_owned()),
+    ///     args.remainder_parse::<String>(),
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseArgumentError::Missing`] if the remainder is empty.
+    ///
+    /// Returns [`ParseArgumentError::Invalid`] if the remainder couldn't be
+    /// parsed into the type.
+    ///
+    /// [`into_remainder`]: #method.into_remainder
+    /// [`ParseArgumentError::Invalid`]: enum.ParseArgumentError.html#variant.Invalid
+    /// [`ParseArgumentError::Missing`]: enum.ParseArgumentError.html#variant.Missing
+    pub fn remainder_parse<T: FromStr>(self) -> Result<T, ParseArgumentError<T::Err>> {
+        parse_argument(self.into_remainder().map(str::trim))
+    }
 }
 
 impl<'a> From<&'a str> for Arguments<'a> {
@@ -71,6 +353,7 @@ impl<'a> From<&'a str> for Arguments<'a> {
             buf: buf.trim(),
             indices: buf.trim().char_indices(),
             idx: 0,
+            offset: buf.len() - buf.trim_start().len(),
         }
     }
 }
@@ -80,6 +363,7 @@ impl<'a> Debug for Arguments<'a> {
         f.debug_struct("Arguments")
             .field("buf", &self.buf)
             .field("idx", &self.idx)
+            .field("offset", &self.offset)
             .finish()
     }
 }
@@ -94,27 +378,37 @@ impl<'a> Iterator for Arguments<'a> {
         }
 
         let mut start_idx = self.idx;
+        let mut escaped = false;
         let mut quoted = false;
         let mut started = false;
 
         while let Some((i, ch)) = self.indices.next() {
-            if quoted {
+            if escaped {
+                // The escaped character is part of the argument, even if it'd
+                // otherwise be a quote or space.
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if quoted {
                 if ch == '"' {
                     let v = self.buf.get(start_idx..i);
                     self.idx = i + 1;
 
                     return v.map(str::trim);
                 }
-            } else if ch == ' ' {
+            } else if ch.is_whitespace() {
                 if started {
                     let v = self.buf.get(start_idx..i);
-                    self.idx = i + 1;
+                    // Whitespace such as an ideographic space may be more
+                    // than one byte long.
+                    self.idx = i + ch.len_utf8();
 
                     return v.map(str::trim);
                 } else {
+                    // Skip the whitespace before an argument, which may be
+                    // repeated after a quoted argument.
                     self.idx = i;
                     start_idx = i;
-                    started = true;
                     continue;
                 }
             } else if ch == '"' {
@@ -135,14 +429,76 @@ impl<'a> Iterator for Arguments<'a> {
     }
 }
 
+/// Remove the backslash escapes from an argument.
+fn unescape(argument: &str) -> Cow<'_, str> {
+    if !argument.contains('\\') {
+        return Cow::Borrowed(argument);
+    }
+
+    let mut unescaped = String::with_capacity(argument.len());
+    let mut chars = argument.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch == '\\' {
+            // A trailing backslash has nothing to escape, so keep it.
+            unescaped.push(chars.next().unwrap_or('\\'));
+        } else {
+            unescaped.push(ch);
+        }
+    }
+
+    Cow::Owned(unescaped)
+}
+
+fn parse_argument<T: FromStr>(argument: Option<&str>) -> Result<T, ParseArgumentError<T::Err>> {
+    let argument = match argument {
+        Some("") | None => return Err(ParseArgumentError::Missing),
+        Some(argument) => argument,
+    };
+
+    argument
+        .parse()
+        .map_err(|source| ParseArgumentError::Invalid {
+            argument: argument.to_owned(),
+            source,
+        })
+}
+
 #[allow(clippy::non_ascii_literal)]
 #[cfg(test)]
 mod tests {
-    use super::Arguments;
+    use super::{Arguments, ParseArgumentError};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{error::Error, fmt::Debug, num::ParseIntError};
 
     assert_impl_all!(Arguments<'_>: Clone, Debug, From<&'static str>, Iterator, Send, Sync);
+    assert_impl_all!(ParseArgumentError<ParseIntError>: Clone, Debug, Error, Send, Sync);
+
+    #[test]
+    fn test_next_parse() {
+        let mut args = Arguments::new("1 two");
+        assert_eq!(Ok(1), args.next_parse::<u8>());
+
+        match args.next_parse::<u8>() {
+            Err(ParseArgumentError::Invalid { argument, .. }) => assert_eq!("two", argument),
+            other => panic!("not invalid: {:?}", other),
+        }
+
+        assert_eq!(Err(ParseArgumentError::Missing), args.next_parse::<u8>());
+    }
+
+    #[test]
+    fn test_remainder_parse() {
+        let args = Arguments::new("1  ");
+        assert_eq!(Ok(1), args.remainder_parse::<u8>());
+
+        let mut args = Arguments::new("1");
+        assert_eq!(Some("1"), args.next());
+        assert_eq!(
+            Err(ParseArgumentError::Missing),
+            args.remainder_parse::<u8>()
+        );
+    }
 
     #[test]
     fn test_as_str() {
@@ -222,4 +578,109 @@ mod tests {
         assert_eq!(Some("kewl"), args.next());
         assert_eq!(None, args.next());
     }
+
+    #[test]
+    fn test_escaped_quotes() {
+        let mut args = Arguments::new(r#"ban "spamming \"memes\"" now"#);
+        assert_eq!(Some("ban"), args.next());
+        assert_eq!(Some(r#"spamming \"memes\""#), args.next());
+        assert_eq!(Some("now"), args.next());
+        assert_eq!(None, args.next());
+
+        let mut args = Arguments::new(r#"ban "spamming \"memes\"" now"#);
+        assert_eq!(Some("ban"), args.next_unescaped().as_deref());
+        assert_eq!(
+            Some(r#"spamming "memes""#),
+            args.next_unescaped().as_deref()
+        );
+        assert_eq!(Some("now"), args.next_unescaped().as_deref());
+        assert_eq!(None, args.next_unescaped());
+    }
+
+    #[test]
+    fn test_escaped_spaces_and_backslashes() {
+        let mut args = Arguments::new(r"one\ argument C:\\ \");
+        assert_eq!(Some("one argument"), args.next_unescaped().as_deref());
+        assert_eq!(Some(r"C:\"), args.next_unescaped().as_deref());
+        assert_eq!(Some(r"\"), args.next_unescaped().as_deref());
+        assert_eq!(None, args.next_unescaped());
+    }
+
+    #[test]
+    fn test_next_parse_unescapes() {
+        let mut args = Arguments::new(r#""\"quoted\"""#);
+        assert_eq!(Ok(r#""quoted""#.to_owned()), args.next_parse::<String>());
+    }
+
+    #[test]
+    fn test_into_options() {
+        let args = Arguments::new(r#"a --key "some value" --switch b --last"#);
+        let options = args.into_options(&["switch"]);
+        assert_eq!(Some("some value"), options.get("key"));
+        assert!(options.contains("switch"));
+        assert!(options.get("switch").is_none());
+        assert!(options.contains("last"));
+        assert!(!options.contains("b"));
+        assert_eq!(["a", "b"], options.positional());
+    }
+
+    #[test]
+    fn test_into_options_terminator() {
+        let args = Arguments::new(r"--flag -- --not-an-option \--escaped");
+        let options = args.into_options(&[]);
+        assert!(options.contains("flag"));
+        assert!(options.get("flag").is_none());
+        assert_eq!(["--not-an-option", "--escaped"], options.positional());
+
+        let options = Arguments::new(r"\--escaped --a --b 2").into_options(&[]);
+        assert_eq!(["--escaped"], options.positional());
+        assert!(options.get("a").is_none());
+        assert_eq!(Some("2"), options.get("b"));
+    }
+
+    #[test]
+    fn test_next_span() {
+        let buf = r#"  one "two three"  four\ five "#;
+        let mut args = Arguments::new(buf);
+        assert_eq!(2, args.offset());
+
+        for expected in &["one", "two three", r"four\ five"] {
+            let (span, argument) = args.next_span().unwrap();
+            assert_eq!(*expected, argument);
+            assert_eq!(*expected, &buf[span]);
+        }
+
+        assert!(args.next_span().is_none());
+    }
+
+    #[test]
+    fn test_unicode_whitespace() {
+        let mut args = Arguments::new("one\u{a0}two\u{3000}three\nfour\tfive");
+        assert_eq!(Some("one"), args.next());
+        assert_eq!(Some("two"), args.next());
+        assert_eq!(Some("three"), args.next());
+        assert_eq!(Some("four\tfive"), args.clone().into_remainder());
+        assert_eq!(Some("four"), args.next());
+        assert_eq!(Some("five"), args.next());
+        assert_eq!(None, args.next());
+
+        let mut args = Arguments::new("移動\u{3000}\u{3000}「東京」");
+        assert_eq!(Some("移動"), args.next());
+        assert_eq!(Some("「東京」"), args.next());
+        assert_eq!(None, args.next());
+    }
+
+    #[test]
+    fn test_grapheme_clusters() {
+        // A family emoji joined by zero width joiners, a flag, and letters
+        // with combining accents.
+        let family = "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}";
+        let buf = [family, "\u{1f1ee}\u{1f1ea} cafe\u{301} n\u{303}"].join(" ");
+        let mut args = Arguments::new(&buf);
+        assert_eq!(Some(family), args.next());
+        assert_eq!(Some("\u{1f1ee}\u{1f1ea}"), args.next());
+        assert_eq!(Some("cafe\u{301}"), args.next());
+        assert_eq!(Some("n\u{303}"), args.next());
+        assert_eq!(None, args.next());
+    }
 }
@@ -8,8 +8,11 @@ use crate::CaseSensitivity;
 /// [`Parser`]: struct.Parser.html
 #[derive(Clone, Debug, Default)]
 pub struct CommandParserConfig<'a> {
+    pub(crate) case_insensitive: bool,
     pub(crate) commands: Vec<CaseSensitivity>,
     pub(crate) prefixes: Vec<Cow<'a, str>>,
+    /// Subcommands and the space-separated path of their parent command.
+    pub(crate) subcommands: Vec<(String, CaseSensitivity)>,
 }
 
 impl<'a> CommandParserConfig<'a> {
@@ -18,6 +21,44 @@ impl<'a> CommandParserConfig<'a> {
         Self::default()
     }
 
+    /// Returns whether all prefixes and commands are matched
+    /// case-insensitively.
+    ///
+    /// Refer to [`set_case_insensitive`] for more information.
+    ///
+    /// [`set_case_insensitive`]: #method.set_case_insensitive
+    pub fn case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Sets whether all prefixes and commands are matched case-insensitively.
+    ///
+    /// When enabled, commands are matched case-insensitively regardless of
+    /// whether they were added as case-sensitive, and prefixes are matched
+    /// case-insensitively too. Matching uses Unicode case folding, so
+    /// `"STRASSE"` matches `"straße"`.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_command_parser::{CommandParserConfig, Parser};
+    ///
+    /// let mut config = CommandParserConfig::new();
+    /// config.add_prefix("bot!");
+    /// config.add_command("help", true);
+    /// config.set_case_insensitive(true);
+    ///
+    /// let parser = Parser::new(config);
+    /// let command = parser.parse("BOT!Help").unwrap();
+    /// assert_eq!("bot!", command.prefix);
+    /// assert_eq!("help", command.name);
+    /// ```
+    pub fn set_case_insensitive(&mut self, case_insensitive: bool) {
+        self.case_insensitive = case_insensitive;
+    }
+
     /// Returns an iterator of immutable references to the commands.
     pub fn commands(&self) -> Commands<'_> {
         Commands {
@@ -113,6 +154,84 @@ impl<'a> CommandParserConfig<'a> {
         self.commands.retain(|c| c != command.as_ref());
     }
 
+    /// Add a subcommand of a command or of another subcommand.
+    ///
+    /// The parent is the space-separated path of the command names as they
+    /// were added, such as `"config"` or `"config set"`. When a command is
+    /// parsed, its arguments are matched against its subcommands in turn and
+    /// the matched path is returned in [`Command::path`].
+    ///
+    /// Returns whether the subcommand was added; it isn't if the parent
+    /// already has a matching subcommand.
+    ///
+    /// # Examples
+    ///
+    /// Add the commands `config get` and `config set prefix`:
+    ///
+    /// ```rust
+    /// use twilight_command_parser::{CommandParserConfig, Parser};
+    ///
+    /// let mut config = CommandParserConfig::new();
+    /// config.add_prefix("!");
+    /// config.add_command("config", false);
+    /// config.add_subcommand("config", "get", false);
+    /// config.add_subcommand("config", "set", false);
+    /// config.add_subcommand("config set", "prefix", false);
+    ///
+    /// let parser = Parser::new(config);
+    /// let command = parser.parse("!config set prefix ?").unwrap();
+    /// assert_eq!("config", command.name);
+    /// assert_eq!(vec!["config", "set", "prefix"], command.path);
+    /// assert_eq!("?", command.arguments.as_str());
+    /// ```
+    ///
+    /// [`Command::path`]: struct.Command.html#structfield.path
+    pub fn add_subcommand(
+        &mut self,
+        parent: impl Into<String>,
+        name: impl Into<String>,
+        case_sensitive: bool,
+    ) -> bool {
+        let (parent, name) = (parent.into(), name.into());
+        let command = if case_sensitive {
+            CaseSensitivity::Sensitive(name)
+        } else {
+            CaseSensitivity::Insensitive(name.into())
+        };
+
+        if self
+            .subcommands
+            .iter()
+            .any(|(p, c)| *p == parent && *c == command)
+        {
+            false
+        } else {
+            self.subcommands.push((parent, command));
+            true
+        }
+    }
+
+    /// Removes a subcommand of a command.
+    ///
+    /// Any subcommands of the parent that would match the subcommand provided
+    /// are removed. Subcommands of the removed subcommand are kept, but can't
+    /// be matched until it's added again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_command_parser::CommandParserConfig;
+    ///
+    /// let mut config = CommandParserConfig::new();
+    /// config.add_command("config", false);
+    /// config.add_subcommand("config", "get", false);
+    /// config.remove_subcommand("config", "get");
+    /// ```
+    pub fn remove_subcommand(&mut self, parent: impl AsRef<str>, name: impl AsRef<str>) {
+        let (parent, name) = (parent.as_ref(), name.as_ref());
+        self.subcommands.retain(|(p, c)| p != parent || c != name);
+    }
+
     /// Adds a prefix to the list of prefixes.
     ///
     /// # Examples
@@ -157,6 +276,66 @@ impl<'a> CommandParserConfig<'a> {
         let pos = self.prefixes.iter().position(|e| *e == needle)?;
         Some(self.prefixes.remove(pos))
     }
+
+    /// Adds mentions of a user as prefixes, such as the bot's user ID.
+    ///
+    /// Both forms of a mention are added, `<@id>` and `<@!id>`, and may be
+    /// followed by whitespace before the command name. The [`Command::prefix`]
+    /// of a parsed command is the form of the mention that was used.
+    ///
+    /// Returns whether either form was added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_command_parser::{CommandParserConfig, Parser};
+    ///
+    /// let mut config = CommandParserConfig::new();
+    /// config.add_command("ping", false);
+    /// config.add_mention_prefix(123);
+    /// assert_eq!(2, config.prefixes().len());
+    ///
+    /// let parser = Parser::new(config);
+    /// let command = parser.parse("<@!123> ping").unwrap();
+    /// assert_eq!("<@!123>", command.prefix);
+    /// assert_eq!("ping", command.name);
+    /// ```
+    ///
+    /// [`Command::prefix`]: struct.Command.html#structfield.prefix
+    pub fn add_mention_prefix(&mut self, user_id: u64) -> bool {
+        let [mention, nickname] = mention_prefixes(user_id);
+        let added = self.add_prefix(mention);
+
+        self.add_prefix(nickname) || added
+    }
+
+    /// Removes the mentions of a user from the list of prefixes.
+    ///
+    /// Returns whether either form of the mention was removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_command_parser::CommandParserConfig;
+    ///
+    /// let mut config = CommandParserConfig::new();
+    /// config.add_mention_prefix(123);
+    /// assert!(config.remove_mention_prefix(123));
+    /// assert_eq!(0, config.prefixes().len());
+    /// ```
+    pub fn remove_mention_prefix(&mut self, user_id: u64) -> bool {
+        let [mention, nickname] = mention_prefixes(user_id);
+        let removed = self.remove_prefix(mention).is_some();
+
+        self.remove_prefix(nickname).is_some() || removed
+    }
+}
+
+/// Both forms of a mention of a user.
+fn mention_prefixes(user_id: u64) -> [String; 2] {
+    let id = user_id.to_string();
+
+    [["<@", &id, ">"].concat(), ["<@!", &id, ">"].concat()]
 }
 
 pub struct Commands<'a> {
@@ -246,9 +425,37 @@ mod tests {
     #[test]
     fn test_getters() {
         let mut config = CommandParserConfig::new();
+        assert!(!config.case_insensitive());
         assert!(config.commands().len() == 0);
         assert!(config.commands_mut().len() == 0);
         assert!(config.prefixes().len() == 0);
         assert!(config.prefixes_mut().len() == 0);
     }
+
+    #[test]
+    fn test_subcommands() {
+        let mut config = CommandParserConfig::new();
+        assert!(config.add_subcommand("config", "get", false));
+        assert!(!config.add_subcommand("config", "get", false));
+        assert!(config.add_subcommand("other", "get", false));
+        assert_eq!(2, config.subcommands.len());
+
+        config.remove_subcommand("config", "Get");
+        assert_eq!(1, config.subcommands.len());
+    }
+
+    #[test]
+    fn test_mention_prefix() {
+        let mut config = CommandParserConfig::new();
+        assert!(config.add_mention_prefix(1));
+        assert!(!config.add_mention_prefix(1));
+        assert_eq!(
+            vec!["<@1>", "<@!1>"],
+            config.prefixes().map(AsRef::as_ref).collect::<Vec<&str>>()
+        );
+
+        assert!(config.remove_mention_prefix(1));
+        assert!(!config.remove_mention_prefix(1));
+        assert_eq!(0, config.prefixes().len());
+    }
 }
@@ -67,11 +67,15 @@
 mod arguments;
 mod casing;
 mod config;
+mod options;
 mod parser;
+mod shared;
 
 pub use self::{
-    arguments::Arguments,
+    arguments::{Arguments, ParseArgumentError},
     casing::CaseSensitivity,
     config::CommandParserConfig,
+    options::Options,
     parser::{Command, Parser},
+    shared::SharedParser,
 };
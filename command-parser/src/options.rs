@@ -0,0 +1,48 @@
+use std::{borrow::Cow, collections::HashMap};
+
+/// Named options and positional arguments parsed out of [`Arguments`].
+///
+/// This is returned from [`Arguments::into_options`]; refer to it for how
+/// arguments are parsed.
+///
+/// [`Arguments`]: struct.Arguments.html
+/// [`Arguments::into_options`]: struct.Arguments.html#method.into_options
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Options<'a> {
+    pub(crate) options: HashMap<Cow<'a, str>, Option<Cow<'a, str>>>,
+    pub(crate) positional: Vec<Cow<'a, str>>,
+}
+
+impl<'a> Options<'a> {
+    /// Returns whether an option or switch was provided.
+    pub fn contains(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    /// Returns the value of an option.
+    ///
+    /// Returns `None` if the option wasn't provided or was provided without a
+    /// value, such as a switch.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.options.get(name)?.as_deref()
+    }
+
+    /// Returns a map of the names of the provided options to their values.
+    pub fn options(&self) -> &HashMap<Cow<'a, str>, Option<Cow<'a, str>>> {
+        &self.options
+    }
+
+    /// Returns the arguments that aren't options or their values, in order.
+    pub fn positional(&self) -> &[Cow<'a, str>] {
+        &self.positional
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Options;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(Options<'_>: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+}
@@ -1,4 +1,6 @@
-use crate::{Arguments, CommandParserConfig};
+use crate::{Arguments, CaseSensitivity, CommandParserConfig};
+use std::iter;
+use unicase::UniCase;
 
 /// Indicator that a command was used.
 #[derive(Clone, Debug)]
@@ -9,6 +11,13 @@ pub struct Command<'a> {
     pub arguments: Arguments<'a>,
     /// The name of the command that was called.
     pub name: &'a str,
+    /// The names of the command and then each subcommand that was called.
+    ///
+    /// For a command without matching subcommands, this only contains
+    /// [`name`].
+    ///
+    /// [`name`]: #structfield.name
+    pub path: Vec<&'a str>,
     /// The prefix used to call the command.
     pub prefix: &'a str,
 }
@@ -94,6 +103,103 @@ impl<'a> Parser<'a> {
         self.parse_with_prefix(prefix, buf)
     }
 
+    /// Returns the name of the configured command closest to the one in a
+    /// buffer, if the buffer has a configured prefix but no matching command.
+    ///
+    /// This can be used to reply to a mistyped command with a suggestion, such
+    /// as "did you mean `ban`?". Commands are compared by their edit distance,
+    /// where swapping two adjacent characters is one edit, and only commands
+    /// within one edit per three characters of the name given in the buffer,
+    /// and at least one edit, are suggested. Commands that are
+    /// case-insensitive are compared case-insensitively.
+    ///
+    /// Returns `None` if the buffer doesn't start with a configured prefix,
+    /// matches a command, or no command is close enough.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_command_parser::{CommandParserConfig, Parser};
+    ///
+    /// let mut config = CommandParserConfig::new();
+    /// config.add_prefix("!");
+    /// config.add_command("ban", false);
+    /// config.add_command("kick", false);
+    ///
+    /// let parser = Parser::new(config);
+    /// assert_eq!(Some("ban"), parser.suggest("!bna someone"));
+    /// assert!(parser.suggest("!ban someone").is_none());
+    /// assert!(parser.suggest("!unrelated").is_none());
+    /// ```
+    pub fn suggest(&'a self, buf: &'a str) -> Option<&'a str> {
+        let prefix = self.find_prefix(buf)?;
+
+        if self.parse_with_prefix(prefix, buf).is_some() {
+            return None;
+        }
+
+        let word = self.strip_prefix(prefix, buf)?.split_whitespace().next()?;
+        let folded = word.to_lowercase();
+        let max_distance = (word.chars().count() / 3).max(1);
+
+        self.config
+            .commands
+            .iter()
+            .map(|command| {
+                let distance = match command {
+                    CaseSensitivity::Sensitive(name) if !self.config.case_insensitive => {
+                        edit_distance(name, word)
+                    }
+                    _ => edit_distance(&command.as_ref().to_lowercase(), &folded),
+                };
+
+                (distance, command.as_ref())
+            })
+            .filter(|(distance, _)| *distance <= max_distance)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, name)| name)
+    }
+
+    /// Parse a command out of a buffer with the first matching prefix of a
+    /// list, instead of the configured prefixes.
+    ///
+    /// This can be used for dynamic prefixes, such as prefixes that each guild
+    /// can configure, by looking up the prefixes of the guild that a message
+    /// was sent in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use twilight_command_parser::{CommandParserConfig, Parser};
+    ///
+    /// let mut config = CommandParserConfig::new();
+    /// config.add_command("ping", false);
+    ///
+    /// let parser = Parser::new(config);
+    ///
+    /// let mut guild_prefixes = HashMap::new();
+    /// guild_prefixes.insert(1, vec!["!", "?"]);
+    /// guild_prefixes.insert(2, vec!["="]);
+    ///
+    /// let prefixes = |guild_id| guild_prefixes.get(&guild_id).into_iter().flatten().copied();
+    ///
+    /// assert!(parser.parse_with_prefixes(prefixes(1), "?ping").is_some());
+    /// assert!(parser.parse_with_prefixes(prefixes(2), "?ping").is_none());
+    /// assert!(parser.parse_with_prefixes(prefixes(3), "?ping").is_none());
+    /// ```
+    pub fn parse_with_prefixes(
+        &'a self,
+        prefixes: impl IntoIterator<Item = &'a str>,
+        buf: &'a str,
+    ) -> Option<Command<'a>> {
+        let prefix = prefixes
+            .into_iter()
+            .find(|prefix| self.strip_prefix(prefix, buf).is_some())?;
+
+        self.parse_with_prefix(prefix, buf)
+    }
+
     /// Parse a command out of a buffer with a specific prefix.
     ///
     /// Instead of using the list of set prefixes, give a specific prefix
@@ -121,31 +227,38 @@ impl<'a> Parser<'a> {
     ///
     /// [`Command`]: struct.Command.html
     pub fn parse_with_prefix(&'a self, prefix: &'a str, buf: &'a str) -> Option<Command<'a>> {
-        if !buf.starts_with(prefix) {
-            return None;
+        let command_buf = self.strip_prefix(prefix, buf)?;
+        let word = command_buf.split_whitespace().next()?;
+        let command = self.find_command(word)?;
+
+        // Advance past the whitespace that was between the prefix and the
+        // command name, and then the command name as it was written, which
+        // may differ in length from the configured name when case folded.
+        let idx = command_buf.len() - command_buf.trim_start().len() + word.len();
+        let mut arguments_buf = command_buf.get(idx..)?;
+        let mut path = vec![command];
+
+        while let Some(word) = arguments_buf.split_whitespace().next() {
+            if let Some(subcommand) = self.find_subcommand(&path.join(" "), word) {
+                let idx = arguments_buf.len() - arguments_buf.trim_start().len() + word.len();
+                arguments_buf = arguments_buf.get(idx..)?;
+                path.push(subcommand);
+            } else {
+                break;
+            }
         }
 
-        let mut idx = prefix.len();
-        let command_buf = buf.get(idx..)?;
-        let command = self.find_command(command_buf)?;
-
-        idx += command.len();
-
-        // Advance from the amount of whitespace that was between the prefix and
-        // the command name.
-        idx += command_buf.len() - command_buf.trim_start().len();
-
         Some(Command {
-            arguments: Arguments::new(buf.get(idx..)?),
+            arguments: Arguments::with_offset(arguments_buf, buf.len() - arguments_buf.len()),
             name: command,
+            path,
             prefix,
         })
     }
 
-    fn find_command(&'a self, buf: &'a str) -> Option<&'a str> {
-        let buf = buf.split_whitespace().next()?;
+    fn find_command(&'a self, word: &str) -> Option<&'a str> {
         self.config.commands.iter().find_map(|command| {
-            if command == buf {
+            if self.command_matches(command, word) {
                 Some(command.as_ref())
             } else {
                 None
@@ -153,15 +266,95 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn find_subcommand(&'a self, parent: &str, word: &str) -> Option<&'a str> {
+        self.config.subcommands.iter().find_map(|(p, command)| {
+            if p == parent && self.command_matches(command, word) {
+                Some(command.as_ref())
+            } else {
+                None
+            }
+        })
+    }
+
+    fn command_matches(&self, command: &CaseSensitivity, word: &str) -> bool {
+        if self.config.case_insensitive {
+            UniCase::new(command.as_ref()) == UniCase::new(word)
+        } else {
+            command == word
+        }
+    }
+
     fn find_prefix(&self, buf: &str) -> Option<&str> {
         self.config.prefixes.iter().find_map(|prefix| {
-            if buf.starts_with(prefix.as_ref()) {
+            if self.strip_prefix(prefix, buf).is_some() {
                 Some(prefix.as_ref())
             } else {
                 None
             }
         })
     }
+
+    /// Returns the remainder of the buffer after a prefix, if the buffer
+    /// starts with it.
+    fn strip_prefix<'b>(&self, prefix: &str, buf: &'b str) -> Option<&'b str> {
+        if !self.config.case_insensitive {
+            return if buf.starts_with(prefix) {
+                buf.get(prefix.len()..)
+            } else {
+                None
+            };
+        }
+
+        // The case folded form of a string may be a different length than
+        // the string, so check each character boundary of the buffer that
+        // could be the end of the prefix. Case folding a character never
+        // expands it to more than three times its length.
+        let prefix = UniCase::new(prefix);
+        let max_len = prefix.len() * 3;
+
+        iter::once(0)
+            .chain(buf.char_indices().skip(1).map(|(idx, _)| idx))
+            .chain(iter::once(buf.len()))
+            .take_while(|idx| *idx <= max_len)
+            .find(|idx| UniCase::new(&buf[..*idx]) == prefix)
+            .map(|idx| &buf[idx..])
+    }
+}
+
+/// Edit distance between two strings counted in characters, where an edit is
+/// an insertion, deletion, substitution, or transposition of two adjacent
+/// characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let width = b.len() + 1;
+    // Distances between the prefixes of `a` and `b`, row by row.
+    let mut distances = vec![0; (a.len() + 1) * width];
+
+    for i in 0..=a.len() {
+        distances[i * width] = i;
+    }
+
+    for (j, distance) in distances[..width].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[(i - 1) * width + j] + 1)
+                .min(distances[i * width + j - 1] + 1)
+                .min(distances[(i - 1) * width + j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[(i - 2) * width + j - 2] + 1);
+            }
+
+            distances[i * width + j] = distance;
+        }
+    }
+
+    distances[a.len() * width + b.len()]
 }
 
 impl<'a, T: Into<CommandParserConfig<'a>>> From<T> for Parser<'a> {
@@ -176,7 +369,7 @@ mod tests {
     use static_assertions::{assert_fields, assert_impl_all};
     use std::fmt::Debug;
 
-    assert_fields!(Command<'_>: arguments, name, prefix);
+    assert_fields!(Command<'_>: arguments, name, path, prefix);
     assert_impl_all!(Command<'_>: Clone, Debug, Send, Sync);
     assert_impl_all!(Parser<'_>: Clone, Debug, Send, Sync);
 
@@ -296,6 +489,120 @@ mod tests {
         assert_eq!("echo", command.name);
     }
 
+    #[test]
+    fn test_dynamic_prefixes() {
+        let parser = simple_config();
+        let prefixes = ["=", "!!"];
+
+        let command = parser
+            .parse_with_prefixes(prefixes.iter().copied(), "!!echo a")
+            .unwrap();
+        assert_eq!("!!", command.prefix);
+        assert_eq!("echo", command.name);
+        assert_eq!(Some("a"), command.arguments.into_remainder());
+
+        // The configured prefixes aren't used.
+        assert!(parser
+            .parse_with_prefixes(prefixes.iter().copied(), "!echo a")
+            .is_none());
+        assert!(parser.parse_with_prefixes(Vec::new(), "=echo").is_none());
+    }
+
+    #[test]
+    fn test_case_insensitive_config() {
+        let mut parser = simple_config();
+        let config = parser.config_mut();
+        config.add_prefix("stra\u{df}e ");
+        config.add_command("\u{3b4}elta", true);
+
+        assert!(parser.parse("!ECHO foo").is_some());
+        assert!(parser.parse("STRASSE \u{394}ELTA").is_none());
+        parser.config_mut().set_case_insensitive(true);
+
+        let mut command = parser.parse("STRASSE \u{394}ELTA foo").unwrap();
+        assert_eq!("stra\u{df}e ", command.prefix);
+        assert_eq!("\u{3b4}elta", command.name);
+        assert_eq!(Some("foo"), command.arguments.next());
+
+        let command = parser.parse_with_prefix("x!", "X!echo").unwrap();
+        assert_eq!("x!", command.prefix);
+    }
+
+    #[test]
+    fn test_subcommands() {
+        let mut parser = simple_config();
+        let config = parser.config_mut();
+        config.add_command("config", false);
+        config.add_subcommand("config", "get", false);
+        config.add_subcommand("config", "set", false);
+        config.add_subcommand("config set", "prefix", true);
+
+        let command = parser.parse("!config  SET prefix  ?").unwrap();
+        assert_eq!("config", command.name);
+        assert_eq!(vec!["config", "set", "prefix"], command.path);
+        assert_eq!("?", command.arguments.as_str());
+
+        // Matching stops at the first argument that isn't a subcommand.
+        let command = parser.parse("!config set PREFIX get").unwrap();
+        assert_eq!(vec!["config", "set"], command.path);
+        assert_eq!("PREFIX get", command.arguments.as_str());
+
+        let command = parser.parse("!echo get").unwrap();
+        assert_eq!(vec!["echo"], command.path);
+        assert_eq!("get", command.arguments.as_str());
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(0, super::edit_distance("echo", "echo"));
+        assert_eq!(1, super::edit_distance("echo", "ecxo"));
+        assert_eq!(1, super::edit_distance("echo", "ceho"));
+        assert_eq!(2, super::edit_distance("echo", "cehx"));
+        assert_eq!(3, super::edit_distance("", "abc"));
+        assert_eq!(1, super::edit_distance("wei\u{df}", "weis"));
+    }
+
+    #[test]
+    fn test_suggest() {
+        let mut parser = simple_config();
+        parser.config_mut().add_command("Echoes", true);
+
+        assert_eq!(Some("echo"), parser.suggest("!ecoh foo"));
+        assert_eq!(Some("echo"), parser.suggest("!ECHOO"));
+        assert_eq!(Some("Echoes"), parser.suggest("!Echoess"));
+        assert!(parser.suggest("!echo").is_none());
+        assert!(parser.suggest("?ecoh").is_none());
+        assert!(parser.suggest("!ping").is_none());
+    }
+
+    #[test]
+    fn test_argument_spans() {
+        let parser = simple_config();
+        let buf = "!echo  foo \"bar\"";
+        let mut command = parser.parse(buf).unwrap();
+        assert_eq!(7, command.arguments.offset());
+
+        let (span, argument) = command.arguments.next_span().unwrap();
+        assert_eq!((7..10, "foo"), (span.clone(), argument));
+        assert_eq!("foo", &buf[span]);
+        assert_eq!(Some((12..15, "bar")), command.arguments.next_span());
+    }
+
+    #[test]
+    fn test_mention_prefix() {
+        let mut parser = simple_config();
+        parser.config_mut().add_mention_prefix(123);
+
+        let command = parser.parse("<@123> echo foo").unwrap();
+        assert_eq!("<@123>", command.prefix);
+        assert_eq!("echo", command.name);
+        assert_eq!(Some("foo"), command.arguments.clone().next());
+
+        let command = parser.parse("<@!123>echo").unwrap();
+        assert_eq!("<@!123>", command.prefix);
+        assert!(parser.parse("<@1234> echo").is_none());
+    }
+
     #[test]
     fn test_prefix_mention() {
         let mut config = CommandParserConfig::new();
@@ -307,6 +614,7 @@ mod tests {
             mut arguments,
             name,
             prefix,
+            ..
         } = parser.parse("foo dump test").unwrap();
         assert_eq!("foo", prefix);
         assert_eq!("dump", name);
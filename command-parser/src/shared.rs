@@ -0,0 +1,136 @@
+use crate::{CommandParserConfig, Parser};
+use std::{
+    borrow::Cow,
+    sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+/// A [`Parser`] behind shared ownership whose configuration can be changed
+/// while it's in use.
+///
+/// Clones of the handle refer to the same parser, so it can be given to an
+/// event handler parsing messages while another task adds or removes
+/// prefixes and commands, such as when a guild's custom prefix is loaded
+/// from a database.
+///
+/// Commands are parsed through a read guard returned by [`read`]; the
+/// configuration can't be changed while a guard is held, so drop it before
+/// doing anything long-running with the parsed command.
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_command_parser::{CommandParserConfig, SharedParser};
+///
+/// let mut config = CommandParserConfig::new();
+/// config.add_prefix("!");
+/// config.add_command("ping", false);
+///
+/// let parser = SharedParser::new(config);
+/// let handle = parser.clone();
+/// handle.add_prefix("?");
+///
+/// let guard = parser.read();
+/// let command = guard.parse("?ping").unwrap();
+/// assert_eq!("ping", command.name);
+/// ```
+///
+/// [`Parser`]: struct.Parser.html
+/// [`read`]: #method.read
+#[derive(Clone, Debug)]
+pub struct SharedParser(Arc<RwLock<Parser<'static>>>);
+
+impl SharedParser {
+    /// Creates a new shared parser from a given configuration.
+    pub fn new(config: impl Into<CommandParserConfig<'static>>) -> Self {
+        Self(Arc::new(RwLock::new(Parser::new(config))))
+    }
+
+    /// Returns a read guard of the parser, used to parse commands.
+    pub fn read(&self) -> RwLockReadGuard<'_, Parser<'static>> {
+        // The parser's state is always valid, even if a writer panicked.
+        self.0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns a write guard of the parser, used to change its configuration
+    /// via [`Parser::config_mut`].
+    ///
+    /// [`Parser::config_mut`]: struct.Parser.html#method.config_mut
+    pub fn write(&self) -> RwLockWriteGuard<'_, Parser<'static>> {
+        self.0.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Adds a command to the list of commands.
+    ///
+    /// Refer to [`CommandParserConfig::add_command`] for more information.
+    ///
+    /// [`CommandParserConfig::add_command`]: struct.CommandParserConfig.html#method.add_command
+    pub fn add_command(&self, name: impl Into<String>, case_sensitive: bool) -> bool {
+        self.write().config_mut().add_command(name, case_sensitive)
+    }
+
+    /// Removes a command from the list of commands.
+    ///
+    /// Refer to [`CommandParserConfig::remove_command`] for more information.
+    ///
+    /// [`CommandParserConfig::remove_command`]: struct.CommandParserConfig.html#method.remove_command
+    pub fn remove_command(&self, command: impl AsRef<str>) {
+        self.write().config_mut().remove_command(command);
+    }
+
+    /// Adds a prefix to the list of prefixes.
+    ///
+    /// Refer to [`CommandParserConfig::add_prefix`] for more information.
+    ///
+    /// [`CommandParserConfig::add_prefix`]: struct.CommandParserConfig.html#method.add_prefix
+    pub fn add_prefix(&self, prefix: impl Into<Cow<'static, str>>) -> bool {
+        self.write().config_mut().add_prefix(prefix)
+    }
+
+    /// Removes a prefix from the list of prefixes.
+    ///
+    /// Refer to [`CommandParserConfig::remove_prefix`] for more information.
+    ///
+    /// [`CommandParserConfig::remove_prefix`]: struct.CommandParserConfig.html#method.remove_prefix
+    pub fn remove_prefix(&self, prefix: impl Into<Cow<'static, str>>) -> Option<Cow<'static, str>> {
+        self.write().config_mut().remove_prefix(prefix)
+    }
+}
+
+impl From<Parser<'static>> for SharedParser {
+    fn from(parser: Parser<'static>) -> Self {
+        Self(Arc::new(RwLock::new(parser)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SharedParser;
+    use crate::{CommandParserConfig, Parser};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, thread};
+
+    assert_impl_all!(SharedParser: Clone, Debug, From<Parser<'static>>, Send, Sync);
+
+    #[test]
+    fn test_shared_updates() {
+        let mut config = CommandParserConfig::new();
+        config.add_prefix("!");
+        let parser = SharedParser::new(config);
+        assert!(parser.read().parse("!ping").is_none());
+
+        let handle = parser.clone();
+        thread::spawn(move || {
+            handle.add_command("ping", false);
+            handle.add_prefix(String::from("?"));
+        })
+        .join()
+        .unwrap();
+
+        assert!(parser.read().parse("!ping").is_some());
+        assert!(parser.read().parse("?ping").is_some());
+
+        parser.remove_command("ping");
+        assert_eq!(Some("?".into()), parser.remove_prefix("?"));
+        assert!(parser.read().parse("!ping").is_none());
+    }
+}
@@ -94,7 +94,7 @@ pub enum EmbedDescriptionError {
         /// included.
         description: String,
     },
-    /// Description is longer than 2048 UTF-16 code points.
+    /// Description is longer than 4096 UTF-16 code points.
     TooLong {
         /// Provided description.
         description: String,
@@ -168,7 +168,7 @@ impl EmbedBuilder {
     /// This is used by [`description`].
     ///
     /// [`description`]: #method.description
-    pub const DESCRIPTION_LENGTH_LIMIT: usize = 2048;
+    pub const DESCRIPTION_LENGTH_LIMIT: usize = 4096;
 
     /// The maximum number of fields that can be in an embed.
     ///
@@ -671,7 +671,7 @@ mod tests {
         Sync
     );
     const_assert!(EmbedBuilder::COLOR_MAXIMUM == 0xff_ff_ff);
-    const_assert!(EmbedBuilder::DESCRIPTION_LENGTH_LIMIT == 2048);
+    const_assert!(EmbedBuilder::DESCRIPTION_LENGTH_LIMIT == 4096);
     const_assert!(EmbedBuilder::EMBED_FIELD_LIMIT == 25);
     const_assert!(EmbedBuilder::EMBED_LENGTH_LIMIT == 6000);
     const_assert!(EmbedBuilder::TITLE_LENGTH_LIMIT == 256);
@@ -0,0 +1,245 @@
+use super::{
+    context::Context,
+    event::FromEvent,
+    handler::{HandlerResult, Handlers},
+    Framework,
+};
+use std::future::Future;
+use twilight_cache_inmemory::InMemoryCache;
+use twilight_gateway::cluster::{ClusterBuilder, ClusterStartError};
+use twilight_model::gateway::{
+    event::Event,
+    payload::{
+        AutoModerationActionExecution, AutoModerationRuleCreate, AutoModerationRuleDelete,
+        AutoModerationRuleUpdate, BanAdd, BanRemove, ChannelCreate, ChannelDelete,
+        ChannelPinsUpdate, ChannelUpdate, GuildCreate, GuildDelete, GuildEmojisUpdate,
+        GuildIntegrationsUpdate, GuildScheduledEventCreate, GuildScheduledEventDelete,
+        GuildScheduledEventUpdate, GuildScheduledEventUserAdd, GuildScheduledEventUserRemove,
+        GuildUpdate, InteractionCreate, InviteCreate, InviteDelete, MemberAdd, MemberChunk,
+        MemberRemove, MemberUpdate, MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
+        PresenceUpdate, ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji, Ready,
+        RoleCreate, RoleDelete, RoleUpdate, StageInstanceCreate, StageInstanceDelete,
+        StageInstanceUpdate, ThreadCreate, ThreadDelete, ThreadListSync, ThreadMemberUpdate,
+        ThreadMembersUpdate, ThreadUpdate, TypingStart, UnavailableGuild, UserUpdate,
+        VoiceServerUpdate, VoiceStateUpdate, WebhooksUpdate,
+    },
+};
+use twilight_standby::Standby;
+
+macro_rules! typed_handlers {
+    ($($method:ident => $payload:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Register a handler of `", stringify!($payload), "` events.")]
+            ///
+            /// Refer to [`on`] for more information.
+            ///
+            /// [`on`]: #method.on
+            pub fn $method<F, Fut>(self, handler: F) -> Self
+            where
+                F: Fn(Context, $payload) -> Fut + Send + Sync + 'static,
+                Fut: Future<Output = HandlerResult> + Send + 'static,
+            {
+                self.on::<$payload, F, Fut>(handler)
+            }
+        )*
+    };
+}
+
+/// Builder to configure and construct a [`Framework`].
+///
+/// [`Framework`]: struct.Framework.html
+#[must_use = "must be built into a framework"]
+pub struct FrameworkBuilder {
+    cache: Option<InMemoryCache>,
+    cluster: ClusterBuilder,
+    handlers: Handlers,
+    standby: Option<Standby>,
+}
+
+impl FrameworkBuilder {
+    /// Create a builder to configure and construct a [`Framework`] running a
+    /// cluster.
+    ///
+    /// The HTTP client of the cluster is used by the framework, so a custom
+    /// client can be set via [`ClusterBuilder::http_client`].
+    ///
+    /// [`ClusterBuilder::http_client`]: https://docs.rs/twilight-gateway/*/twilight_gateway/cluster/struct.ClusterBuilder.html#method.http_client
+    /// [`Framework`]: struct.Framework.html
+    pub fn new(cluster: ClusterBuilder) -> Self {
+        Self {
+            cache: None,
+            cluster,
+            handlers: Handlers::default(),
+            standby: None,
+        }
+    }
+
+    /// Consume the builder, creating the cluster and returning a configured
+    /// [`Framework`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClusterStartError`] if the cluster couldn't be created.
+    ///
+    /// [`ClusterStartError`]: https://docs.rs/twilight-gateway/*/twilight_gateway/cluster/struct.ClusterStartError.html
+    /// [`Framework`]: struct.Framework.html
+    pub async fn build(self) -> Result<Framework, ClusterStartError> {
+        let cluster = self.cluster.build().await?;
+
+        Ok(Framework {
+            cache: self.cache.unwrap_or_default(),
+            handlers: self.handlers.into(),
+            http: cluster.config().http_client().clone(),
+            cluster,
+            standby: self.standby.unwrap_or_default(),
+        })
+    }
+
+    /// Set the cache to update with events.
+    ///
+    /// Defaults to a cache with the default configuration.
+    pub fn cache(mut self, cache: InMemoryCache) -> Self {
+        self.cache.replace(cache);
+
+        self
+    }
+
+    /// Set the standby to process events with.
+    ///
+    /// Defaults to a standby with the default configuration.
+    pub fn standby(mut self, standby: Standby) -> Self {
+        self.standby.replace(standby);
+
+        self
+    }
+
+    /// Register middleware, which runs before the handlers of each event and
+    /// returns whether the handlers should be called.
+    ///
+    /// Middleware runs in the order it was registered in, and once one
+    /// returns `false` the rest don't run. The cache and standby always
+    /// receive the event, even when middleware stops it.
+    ///
+    /// # Examples
+    ///
+    /// Ignore messages from bots:
+    ///
+    /// ```rust,no_run
+    /// use twilight_framework::Framework;
+    /// use twilight_gateway::{Event, Intents};
+    ///
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let framework = Framework::builder("token", Intents::GUILD_MESSAGES)
+    ///     .middleware(|_, event| match event {
+    ///         Event::MessageCreate(message) => !message.author.bot,
+    ///         _ => true,
+    ///     })
+    ///     .build()
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    pub fn middleware<F>(mut self, middleware: F) -> Self
+    where
+        F: Fn(&Context, &Event) -> bool + Send + Sync + 'static,
+    {
+        self.handlers.middleware.push(Box::new(middleware));
+
+        self
+    }
+
+    /// Register a handler of events with a payload of a type.
+    ///
+    /// Handlers are spawned as tasks, so multiple handlers of an event run
+    /// concurrently. Errors returned by handlers are logged.
+    ///
+    /// The methods such as [`on_message_create`] register handlers of each
+    /// type of event.
+    ///
+    /// [`on_message_create`]: #method.on_message_create
+    pub fn on<T, F, Fut>(mut self, handler: F) -> Self
+    where
+        T: FromEvent,
+        F: Fn(Context, T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.handlers.push_typed(handler);
+
+        self
+    }
+
+    /// Register a handler of every event, including those of the shards'
+    /// connections.
+    pub fn on_event<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Context, Event) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.handlers.push_any(handler);
+
+        self
+    }
+
+    typed_handlers!(
+        on_auto_moderation_action_execution => AutoModerationActionExecution,
+        on_auto_moderation_rule_create => AutoModerationRuleCreate,
+        on_auto_moderation_rule_delete => AutoModerationRuleDelete,
+        on_auto_moderation_rule_update => AutoModerationRuleUpdate,
+        on_ban_add => BanAdd,
+        on_ban_remove => BanRemove,
+        on_channel_create => ChannelCreate,
+        on_channel_delete => ChannelDelete,
+        on_channel_pins_update => ChannelPinsUpdate,
+        on_channel_update => ChannelUpdate,
+        on_guild_create => GuildCreate,
+        on_guild_delete => GuildDelete,
+        on_guild_emojis_update => GuildEmojisUpdate,
+        on_guild_integrations_update => GuildIntegrationsUpdate,
+        on_guild_scheduled_event_create => GuildScheduledEventCreate,
+        on_guild_scheduled_event_delete => GuildScheduledEventDelete,
+        on_guild_scheduled_event_update => GuildScheduledEventUpdate,
+        on_guild_scheduled_event_user_add => GuildScheduledEventUserAdd,
+        on_guild_scheduled_event_user_remove => GuildScheduledEventUserRemove,
+        on_guild_update => GuildUpdate,
+        on_interaction_create => InteractionCreate,
+        on_invite_create => InviteCreate,
+        on_invite_delete => InviteDelete,
+        on_member_add => MemberAdd,
+        on_member_chunk => MemberChunk,
+        on_member_remove => MemberRemove,
+        on_member_update => MemberUpdate,
+        on_message_create => MessageCreate,
+        on_message_delete => MessageDelete,
+        on_message_delete_bulk => MessageDeleteBulk,
+        on_message_update => MessageUpdate,
+        on_presence_update => PresenceUpdate,
+        on_reaction_add => ReactionAdd,
+        on_reaction_remove => ReactionRemove,
+        on_reaction_remove_all => ReactionRemoveAll,
+        on_reaction_remove_emoji => ReactionRemoveEmoji,
+        on_ready => Ready,
+        on_role_create => RoleCreate,
+        on_role_delete => RoleDelete,
+        on_role_update => RoleUpdate,
+        on_stage_instance_create => StageInstanceCreate,
+        on_stage_instance_delete => StageInstanceDelete,
+        on_stage_instance_update => StageInstanceUpdate,
+        on_thread_create => ThreadCreate,
+        on_thread_delete => ThreadDelete,
+        on_thread_list_sync => ThreadListSync,
+        on_thread_member_update => ThreadMemberUpdate,
+        on_thread_members_update => ThreadMembersUpdate,
+        on_thread_update => ThreadUpdate,
+        on_typing_start => TypingStart,
+        on_unavailable_guild => UnavailableGuild,
+        on_user_update => UserUpdate,
+        on_voice_server_update => VoiceServerUpdate,
+        on_voice_state_update => VoiceStateUpdate,
+        on_webhooks_update => WebhooksUpdate,
+    );
+}
+
+impl From<ClusterBuilder> for FrameworkBuilder {
+    fn from(cluster: ClusterBuilder) -> Self {
+        Self::new(cluster)
+    }
+}
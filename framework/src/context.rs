@@ -0,0 +1,45 @@
+use twilight_cache_inmemory::InMemoryCache;
+use twilight_gateway::Cluster;
+use twilight_http::Client;
+use twilight_standby::Standby;
+
+/// Resources of the framework, passed to handlers and middleware along with
+/// each event.
+///
+/// A context is cheap to clone, and clones point to the same resources.
+#[derive(Clone, Debug)]
+pub struct Context {
+    pub(crate) cache: InMemoryCache,
+    pub(crate) cluster: Cluster,
+    pub(crate) http: Client,
+    pub(crate) shard_id: u64,
+    pub(crate) standby: Standby,
+}
+
+impl Context {
+    /// Cache, which has already been updated with the event.
+    pub fn cache(&self) -> &InMemoryCache {
+        &self.cache
+    }
+
+    /// Cluster that received the event, which can be used to send commands
+    /// to its shards.
+    pub fn cluster(&self) -> &Cluster {
+        &self.cluster
+    }
+
+    /// HTTP client of the cluster.
+    pub fn http(&self) -> &Client {
+        &self.http
+    }
+
+    /// ID of the shard that received the event.
+    pub fn shard_id(&self) -> u64 {
+        self.shard_id
+    }
+
+    /// Standby, which has already processed the event.
+    pub fn standby(&self) -> &Standby {
+        &self.standby
+    }
+}
@@ -0,0 +1,161 @@
+use twilight_model::gateway::{
+    event::{Event, EventType},
+    payload::{
+        AutoModerationActionExecution, AutoModerationRuleCreate, AutoModerationRuleDelete,
+        AutoModerationRuleUpdate, BanAdd, BanRemove, ChannelCreate, ChannelDelete,
+        ChannelPinsUpdate, ChannelUpdate, GuildCreate, GuildDelete, GuildEmojisUpdate,
+        GuildIntegrationsUpdate, GuildScheduledEventCreate, GuildScheduledEventDelete,
+        GuildScheduledEventUpdate, GuildScheduledEventUserAdd, GuildScheduledEventUserRemove,
+        GuildUpdate, InteractionCreate, InviteCreate, InviteDelete, MemberAdd, MemberChunk,
+        MemberRemove, MemberUpdate, MessageCreate, MessageDelete, MessageDeleteBulk, MessageUpdate,
+        PresenceUpdate, ReactionAdd, ReactionRemove, ReactionRemoveAll, ReactionRemoveEmoji, Ready,
+        RoleCreate, RoleDelete, RoleUpdate, StageInstanceCreate, StageInstanceDelete,
+        StageInstanceUpdate, ThreadCreate, ThreadDelete, ThreadListSync, ThreadMemberUpdate,
+        ThreadMembersUpdate, ThreadUpdate, TypingStart, UnavailableGuild, UserUpdate,
+        VoiceServerUpdate, VoiceStateUpdate, WebhooksUpdate,
+    },
+};
+
+/// Payload of a dispatch event that handlers can be registered for.
+///
+/// Implemented for each payload in [`twilight_model::gateway::payload`] that
+/// is received as an [`Event`].
+///
+/// [`Event`]: https://docs.rs/twilight-model/*/twilight_model/gateway/event/enum.Event.html
+/// [`twilight_model::gateway::payload`]: https://docs.rs/twilight-model/*/twilight_model/gateway/payload/index.html
+pub trait FromEvent: Sized {
+    /// Type of the event that the payload is received in.
+    const KIND: EventType;
+
+    /// Take the payload out of an event, returning `None` if the event is of
+    /// another type.
+    fn from_event(event: Event) -> Option<Self>;
+}
+
+macro_rules! from_event {
+    ($($payload:ident),* $(,)?) => {
+        $(
+            impl FromEvent for $payload {
+                const KIND: EventType = EventType::$payload;
+
+                fn from_event(event: Event) -> Option<Self> {
+                    match event {
+                        Event::$payload(payload) => Some(payload),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! from_boxed_event {
+    ($($payload:ident),* $(,)?) => {
+        $(
+            impl FromEvent for $payload {
+                const KIND: EventType = EventType::$payload;
+
+                fn from_event(event: Event) -> Option<Self> {
+                    match event {
+                        Event::$payload(payload) => Some(*payload),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_event!(
+    BanAdd,
+    BanRemove,
+    ChannelCreate,
+    ChannelDelete,
+    ChannelPinsUpdate,
+    ChannelUpdate,
+    GuildEmojisUpdate,
+    GuildIntegrationsUpdate,
+    GuildScheduledEventUserAdd,
+    GuildScheduledEventUserRemove,
+    InviteDelete,
+    MemberChunk,
+    MemberRemove,
+    MessageDelete,
+    MessageDeleteBulk,
+    ReactionRemoveAll,
+    ReactionRemoveEmoji,
+    RoleCreate,
+    RoleDelete,
+    RoleUpdate,
+    StageInstanceCreate,
+    StageInstanceDelete,
+    StageInstanceUpdate,
+    ThreadDelete,
+    ThreadMemberUpdate,
+    ThreadMembersUpdate,
+    UnavailableGuild,
+    UserUpdate,
+    VoiceServerUpdate,
+    WebhooksUpdate,
+);
+
+from_boxed_event!(
+    AutoModerationRuleUpdate,
+    AutoModerationRuleDelete,
+    AutoModerationRuleCreate,
+    AutoModerationActionExecution,
+    GuildCreate,
+    GuildDelete,
+    GuildScheduledEventCreate,
+    GuildScheduledEventDelete,
+    GuildScheduledEventUpdate,
+    GuildUpdate,
+    InteractionCreate,
+    InviteCreate,
+    MemberAdd,
+    MemberUpdate,
+    MessageCreate,
+    MessageUpdate,
+    PresenceUpdate,
+    ReactionAdd,
+    ReactionRemove,
+    Ready,
+    ThreadCreate,
+    ThreadListSync,
+    ThreadUpdate,
+    TypingStart,
+    VoiceStateUpdate,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::FromEvent;
+    use twilight_model::{
+        gateway::{
+            event::{Event, EventType},
+            payload::{MessageDelete, RoleDelete},
+        },
+        id::{ChannelId, GuildId, MessageId, RoleId},
+    };
+
+    #[test]
+    fn test_from_event() {
+        let delete = MessageDelete {
+            channel_id: ChannelId(1),
+            guild_id: None,
+            id: MessageId(2),
+        };
+
+        assert_eq!(EventType::MessageDelete, MessageDelete::KIND);
+        assert_eq!(
+            Some(delete.clone()),
+            MessageDelete::from_event(Event::MessageDelete(delete))
+        );
+        assert!(RoleDelete::from_event(Event::RoleDelete(RoleDelete {
+            guild_id: GuildId(1),
+            role_id: RoleId(2),
+        }))
+        .is_some());
+        assert!(RoleDelete::from_event(Event::GatewayHeartbeat(1)).is_none());
+    }
+}
@@ -0,0 +1,60 @@
+use super::{context::Context, event::FromEvent};
+use futures_util::future::{self, BoxFuture, FutureExt};
+use std::{collections::HashMap, error::Error, future::Future};
+use twilight_model::gateway::event::{Event, EventType};
+
+/// Result returned by handlers.
+///
+/// Errors are logged by the framework.
+pub type HandlerResult = Result<(), Box<dyn Error + Send + Sync>>;
+
+/// Type-erased handler of events.
+pub(crate) type Handler =
+    Box<dyn Fn(Context, Event) -> BoxFuture<'static, HandlerResult> + Send + Sync>;
+
+/// Type-erased middleware.
+pub(crate) type Middleware = Box<dyn Fn(&Context, &Event) -> bool + Send + Sync>;
+
+/// Registered handlers and middleware.
+#[derive(Default)]
+pub(crate) struct Handlers {
+    /// Handlers of every event.
+    pub(crate) any: Vec<Handler>,
+    /// Middleware, in the order it runs in.
+    pub(crate) middleware: Vec<Middleware>,
+    /// Handlers of events of a type.
+    pub(crate) typed: HashMap<EventType, Vec<Handler>>,
+}
+
+impl Handlers {
+    pub(crate) fn push_any<F, Fut>(&mut self, handler: F)
+    where
+        F: Fn(Context, Event) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        self.any.push(Box::new(move |context, event| {
+            handler(context, event).boxed()
+        }));
+    }
+
+    pub(crate) fn push_typed<T, F, Fut>(&mut self, handler: F)
+    where
+        T: FromEvent,
+        F: Fn(Context, T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = HandlerResult> + Send + 'static,
+    {
+        let handler: Handler = Box::new(move |context, event| match T::from_event(event) {
+            Some(payload) => handler(context, payload).boxed(),
+            None => future::ok(()).boxed(),
+        });
+
+        self.typed.entry(T::KIND).or_default().push(handler);
+    }
+
+    /// Handlers of an event, starting with those of every event.
+    pub(crate) fn get(&self, kind: EventType) -> impl Iterator<Item = &Handler> {
+        self.any
+            .iter()
+            .chain(self.typed.get(&kind).into_iter().flatten())
+    }
+}
@@ -0,0 +1,309 @@
+//! # twilight-framework
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-framework` is a batteries-included entry point to the
+//! [`twilight-rs`] ecosystem, running the event loop that bots otherwise
+//! write themselves on top of the low-level crates.
+//!
+//! Register handlers of typed events, such as [`on_message_create`], and
+//! middleware when building a [`Framework`], and then [`run`] it. The
+//! framework brings up a cluster and, for each event it receives:
+//!
+//! 1. updates the cache with the event;
+//! 2. processes the event with standby, fulfilling any futures waiting for
+//!    it;
+//! 3. runs the middleware, which may stop the event from reaching handlers;
+//!    and
+//! 4. spawns the handlers of the event as tasks, logging any errors they
+//!    return.
+//!
+//! Handlers receive a [`Context`] with the cache, cluster, HTTP client, and
+//! standby, along with the event's payload.
+//!
+//! ## Examples
+//!
+//! Reply "Pong!" to messages saying "!ping":
+//!
+//! ```rust,no_run
+//! use std::env;
+//! use twilight_framework::{Context, Framework, HandlerResult};
+//! use twilight_gateway::Intents;
+//! use twilight_model::gateway::payload::MessageCreate;
+//!
+//! async fn ping(context: Context, message: MessageCreate) -> HandlerResult {
+//!     if &*message.content == "!ping" {
+//!         context
+//!             .http()
+//!             .create_message(message.channel_id)
+//!             .content("Pong!")?
+//!             .await?;
+//!     }
+//!
+//!     Ok(())
+//! }
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let intents = Intents::GUILD_MESSAGES;
+//! let framework = Framework::builder(env::var("DISCORD_TOKEN")?, intents)
+//!     .on_message_create(ping)
+//!     .build()
+//!     .await?;
+//!
+//! framework.run().await;
+//! # Ok(()) }
+//! ```
+//!
+//! [`Context`]: struct.Context.html
+//! [`Framework`]: struct.Framework.html
+//! [`on_message_create`]: struct.FrameworkBuilder.html#method.on_message_create
+//! [`run`]: struct.Framework.html#method.run
+//! [`twilight-rs`]: https://github.com/twilight-rs/twilight
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-stable-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+mod builder;
+mod context;
+mod event;
+mod handler;
+
+pub use self::{
+    builder::FrameworkBuilder, context::Context, event::FromEvent, handler::HandlerResult,
+};
+
+use self::handler::Handlers;
+use futures_util::stream::StreamExt;
+use std::sync::Arc;
+use twilight_cache_inmemory::InMemoryCache;
+use twilight_gateway::{Cluster, Event, Intents};
+use twilight_http::Client;
+use twilight_standby::Standby;
+
+/// Event dispatcher driving a cluster, cache, and standby.
+///
+/// Refer to the crate-level documentation for more information.
+pub struct Framework {
+    cache: InMemoryCache,
+    cluster: Cluster,
+    handlers: Arc<Handlers>,
+    http: Client,
+    standby: Standby,
+}
+
+impl Framework {
+    /// Create a builder to configure and construct a framework running a
+    /// cluster with the default configuration.
+    ///
+    /// Use [`FrameworkBuilder::new`] to provide a configured cluster.
+    ///
+    /// [`FrameworkBuilder::new`]: struct.FrameworkBuilder.html#method.new
+    pub fn builder(token: impl Into<String>, intents: Intents) -> FrameworkBuilder {
+        FrameworkBuilder::new(Cluster::builder(token, intents))
+    }
+
+    /// Cache updated with events.
+    pub fn cache(&self) -> &InMemoryCache {
+        &self.cache
+    }
+
+    /// Cluster that events are received from.
+    pub fn cluster(&self) -> &Cluster {
+        &self.cluster
+    }
+
+    /// HTTP client of the cluster.
+    pub fn http(&self) -> &Client {
+        &self.http
+    }
+
+    /// Standby processing events.
+    pub fn standby(&self) -> &Standby {
+        &self.standby
+    }
+
+    /// Bring up the cluster and dispatch its events until its shards are
+    /// shut down, such as via [`Cluster::down`].
+    ///
+    /// [`Cluster::down`]: https://docs.rs/twilight-gateway/*/twilight_gateway/cluster/struct.Cluster.html#method.down
+    pub async fn run(&self) {
+        let mut events = self.cluster.events();
+        let cluster = self.cluster.clone();
+
+        tokio::spawn(async move {
+            cluster.up().await;
+        });
+
+        while let Some((shard_id, event)) = events.next().await {
+            self.dispatch(shard_id, &event);
+        }
+    }
+
+    fn dispatch(&self, shard_id: u64, event: &Event) {
+        self.cache.update(event);
+        self.standby.process(event);
+
+        let context = Context {
+            cache: self.cache.clone(),
+            cluster: self.cluster.clone(),
+            http: self.http.clone(),
+            shard_id,
+            standby: self.standby.clone(),
+        };
+
+        if !self
+            .handlers
+            .middleware
+            .iter()
+            .all(|middleware| middleware(&context, event))
+        {
+            return;
+        }
+
+        let kind = event.kind();
+
+        for handler in self.handlers.get(kind) {
+            let future = handler(context.clone(), event.clone());
+
+            tokio::spawn(async move {
+                if let Err(source) = future.await {
+                    tracing::warn!("handler of {:?} event failed: {}", kind, source);
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Context, Framework, FrameworkBuilder, FromEvent};
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::TryFrom,
+        fmt::Debug,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+    use tokio::sync::mpsc;
+    use twilight_gateway::{
+        cluster::{Cluster, ShardScheme},
+        Event, Intents,
+    };
+    use twilight_model::{
+        gateway::payload::{MessageDelete, RoleDelete},
+        id::{ChannelId, GuildId, MessageId, RoleId},
+    };
+
+    assert_impl_all!(Context: Clone, Debug, Send, Sync);
+    assert_impl_all!(Framework: Send, Sync);
+    assert_impl_all!(FrameworkBuilder: Send, Sync);
+    assert_impl_all!(MessageDelete: FromEvent);
+
+    fn delete() -> MessageDelete {
+        MessageDelete {
+            channel_id: ChannelId(1),
+            guild_id: None,
+            id: MessageId(2),
+        }
+    }
+
+    async fn framework(builder: impl FnOnce(FrameworkBuilder) -> FrameworkBuilder) -> Framework {
+        let scheme = ShardScheme::try_from((0..=0, 1)).unwrap();
+        let cluster = Cluster::builder("token", Intents::GUILD_MESSAGES).shard_scheme(scheme);
+
+        builder(FrameworkBuilder::new(cluster))
+            .build()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_dispatch() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_any = Arc::clone(&calls);
+
+        let framework = framework(move |builder| {
+            builder
+                .on_message_delete(move |context, event| {
+                    let tx = tx.clone();
+
+                    async move {
+                        tx.send((context.shard_id(), event))?;
+
+                        Ok(())
+                    }
+                })
+                .on_role_delete(|_, _| async { panic!("handler of another event called") })
+                .on_event(move |_, _| {
+                    calls_any.fetch_add(1, Ordering::SeqCst);
+
+                    async { Ok(()) }
+                })
+        })
+        .await;
+
+        framework.dispatch(0, &Event::MessageDelete(delete()));
+
+        assert_eq!(Some((0, delete())), rx.recv().await);
+        assert_eq!(1, calls.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_middleware() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_handler = Arc::clone(&calls);
+        let calls_middleware = Arc::clone(&calls);
+
+        let framework = framework(move |builder| {
+            builder
+                .middleware(|_, event| !matches!(event, Event::RoleDelete(_)))
+                .middleware(move |_, _| {
+                    calls_middleware.fetch_add(1, Ordering::SeqCst);
+
+                    true
+                })
+                .on_event(move |_, _| {
+                    calls_handler.fetch_add(1, Ordering::SeqCst);
+
+                    async { Ok(()) }
+                })
+        })
+        .await;
+
+        framework.dispatch(
+            0,
+            &Event::RoleDelete(RoleDelete {
+                guild_id: GuildId(1),
+                role_id: RoleId(2),
+            }),
+        );
+
+        // The event was stopped by the first middleware.
+        assert_eq!(0, calls.load(Ordering::SeqCst));
+
+        framework.dispatch(0, &Event::MessageDelete(delete()));
+
+        // The second middleware and the handler were called.
+        assert_eq!(2, calls.load(Ordering::SeqCst));
+    }
+}
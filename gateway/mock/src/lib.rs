@@ -0,0 +1,197 @@
+//! # twilight-gateway-mock
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-gateway-mock` is a local websocket server speaking the Discord
+//! gateway protocol, for testing shards and clusters without connecting to
+//! Discord.
+//!
+//! A [`MockGateway`] listens on a local port, and its [URL] can be given to
+//! shards via `ShardBuilder::gateway_url`. Each connection from a shard is
+//! accepted as a [`MockSession`], which scripts the server's side of the
+//! session: sending a HELLO, receiving the shard's commands such as its
+//! IDENTIFY, sending dispatch events, and closing the connection with a close
+//! code. Heartbeats from the shard are acknowledged automatically.
+//!
+//! Payloads are compressed as a zlib stream, as Discord does when shards
+//! connect with `compress=zlib-stream`.
+//!
+//! ## Examples
+//!
+//! Accept a shard's connection, wait for it to identify, and send it a READY
+//! and a dispatch event:
+//!
+//! ```rust,no_run
+//! use serde_json::json;
+//! use twilight_gateway_mock::MockGateway;
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut gateway = MockGateway::bind().await?;
+//!
+//! // Start a shard with `gateway.url()` as its gateway URL here.
+//!
+//! let mut session = gateway.accept().await?;
+//! session.hello(41_250)?;
+//!
+//! let identify = session.next_command().await.expect("shard disconnected");
+//! assert_eq!(2, identify["op"]);
+//!
+//! session.ready("session id")?;
+//! session.dispatch(
+//!     "MESSAGE_DELETE",
+//!     json!({
+//!         "channel_id": "1",
+//!         "id": "2",
+//!     }),
+//! )?;
+//!
+//! // Close the connection as if the token was invalid.
+//! session.close(4004, "Authentication failed.")?;
+//! # Ok(()) }
+//! ```
+//!
+//! [`MockGateway`]: struct.MockGateway.html
+//! [`MockSession`]: struct.MockSession.html
+//! [URL]: struct.MockGateway.html#method.url
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-stable-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+mod session;
+
+pub use self::session::{MockSession, SendError};
+
+use async_tungstenite::tungstenite::Error as TungsteniteError;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::Error as IoError,
+};
+use tokio::net::TcpListener;
+
+/// Accepting a connection from a shard failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum AcceptError {
+    /// Accepting the TCP connection failed.
+    Accepting {
+        /// Reason for the error.
+        source: IoError,
+    },
+    /// The websocket handshake with the shard failed.
+    Handshaking {
+        /// Reason for the error.
+        source: TungsteniteError,
+    },
+}
+
+impl Display for AcceptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Accepting { .. } => f.write_str("accepting the connection failed"),
+            Self::Handshaking { .. } => f.write_str("the websocket handshake failed"),
+        }
+    }
+}
+
+impl Error for AcceptError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Accepting { source } => Some(source),
+            Self::Handshaking { source } => Some(source),
+        }
+    }
+}
+
+/// Local websocket server speaking the Discord gateway protocol.
+///
+/// Refer to the crate-level documentation for more information.
+#[derive(Debug)]
+pub struct MockGateway {
+    listener: TcpListener,
+    url: String,
+}
+
+impl MockGateway {
+    /// Bind a server to a free port on the loopback interface.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IoError`] if binding to a port failed.
+    ///
+    /// [`IoError`]: https://doc.rust-lang.org/std/io/struct.Error.html
+    pub async fn bind() -> Result<Self, IoError> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("ws://{}", listener.local_addr()?);
+
+        Ok(Self { listener, url })
+    }
+
+    /// URL of the server, to use as the gateway URL of shards.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Wait for a shard to connect, returning its session.
+    ///
+    /// Shards reconnect to the same URL when they resume or reconnect, in
+    /// which case the new connection is accepted by calling this again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AcceptError::Accepting`] if accepting the connection failed.
+    ///
+    /// Returns [`AcceptError::Handshaking`] if the websocket handshake failed.
+    ///
+    /// [`AcceptError::Accepting`]: enum.AcceptError.html#variant.Accepting
+    /// [`AcceptError::Handshaking`]: enum.AcceptError.html#variant.Handshaking
+    pub async fn accept(&mut self) -> Result<MockSession, AcceptError> {
+        let (stream, _) = self
+            .listener
+            .accept()
+            .await
+            .map_err(|source| AcceptError::Accepting { source })?;
+
+        let stream = async_tungstenite::tokio::accept_async(stream)
+            .await
+            .map_err(|source| AcceptError::Handshaking { source })?;
+
+        Ok(MockSession::new(stream))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AcceptError, MockGateway};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+
+    assert_fields!(AcceptError::Accepting: source);
+    assert_fields!(AcceptError::Handshaking: source);
+    assert_impl_all!(AcceptError: Debug, Error, Send, Sync);
+    assert_impl_all!(MockGateway: Debug, Send, Sync);
+
+    #[tokio::test]
+    async fn test_bind() {
+        let gateway = MockGateway::bind().await.unwrap();
+
+        assert!(gateway.url().starts_with("ws://127.0.0.1:"));
+    }
+}
@@ -0,0 +1,479 @@
+use async_tungstenite::{
+    tokio::TokioAdapter,
+    tungstenite::{
+        protocol::{frame::coding::CloseCode, CloseFrame},
+        Message,
+    },
+    WebSocketStream,
+};
+use flate2::{Compress, Compression, FlushCompress};
+use futures_channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use futures_util::{
+    sink::SinkExt,
+    stream::{SplitSink, SplitStream, StreamExt},
+};
+use serde::Serialize;
+use serde_json::{json, Error as JsonError, Value};
+use std::{
+    borrow::Cow,
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::net::TcpStream;
+
+type Stream = WebSocketStream<TokioAdapter<TcpStream>>;
+
+/// Sending a payload to a shard failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SendError {
+    /// The connection to the shard is closed.
+    Closed,
+    /// Serializing the payload as JSON failed.
+    Serializing {
+        /// Reason for the error.
+        source: JsonError,
+    },
+}
+
+impl Display for SendError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Closed => f.write_str("the connection to the shard is closed"),
+            Self::Serializing { .. } => f.write_str("serializing the payload failed"),
+        }
+    }
+}
+
+impl Error for SendError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Closed => None,
+            Self::Serializing { source } => Some(source),
+        }
+    }
+}
+
+/// Message for the task writing to the connection.
+enum Outgoing {
+    Close(CloseFrame<'static>),
+    Payload(Vec<u8>),
+}
+
+/// State shared with the task reading from the connection.
+#[derive(Debug)]
+struct State {
+    ack_heartbeats: AtomicBool,
+    close_code: Mutex<Option<u16>>,
+    heartbeats: AtomicU64,
+}
+
+/// Server's side of a shard's connection to a [`MockGateway`].
+///
+/// Payloads are sent in the order the methods are called in. Commands from
+/// the shard other than heartbeats are received via [`next_command`].
+///
+/// [`MockGateway`]: struct.MockGateway.html
+/// [`next_command`]: #method.next_command
+#[derive(Debug)]
+pub struct MockSession {
+    commands: UnboundedReceiver<Value>,
+    sequence: u64,
+    state: Arc<State>,
+    tx: UnboundedSender<Outgoing>,
+}
+
+impl MockSession {
+    pub(crate) fn new(stream: Stream) -> Self {
+        let (sink, stream) = stream.split();
+        let (commands_tx, commands) = mpsc::unbounded();
+        let (tx, rx) = mpsc::unbounded();
+        let state = Arc::new(State {
+            ack_heartbeats: AtomicBool::new(true),
+            close_code: Mutex::new(None),
+            heartbeats: AtomicU64::new(0),
+        });
+
+        tokio::spawn(write(sink, rx));
+        tokio::spawn(read(stream, commands_tx, Arc::clone(&state), tx.clone()));
+
+        Self {
+            commands,
+            sequence: 0,
+            state,
+            tx,
+        }
+    }
+
+    /// Set whether heartbeats from the shard are acknowledged.
+    ///
+    /// Disabling acknowledgements simulates a zombied connection. Defaults
+    /// to `true`.
+    pub fn ack_heartbeats(&self, ack_heartbeats: bool) {
+        self.state
+            .ack_heartbeats
+            .store(ack_heartbeats, Ordering::Release);
+    }
+
+    /// Close code sent by the shard when it closed the connection, if it has.
+    pub fn client_close_code(&self) -> Option<u16> {
+        self.state.close_code.lock().ok().and_then(|code| *code)
+    }
+
+    /// Number of heartbeats received from the shard.
+    pub fn heartbeats(&self) -> u64 {
+        self.state.heartbeats.load(Ordering::Acquire)
+    }
+
+    /// Sequence of the last dispatch event sent.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Set the sequence of the last dispatch event sent, such as to continue
+    /// the sequence of a resumed session.
+    pub fn set_sequence(&mut self, sequence: u64) {
+        self.sequence = sequence;
+    }
+
+    /// Wait for the next command from the shard, such as an IDENTIFY or a
+    /// RESUME, returning `None` once the connection is closed.
+    ///
+    /// Heartbeats aren't returned, and are counted by [`heartbeats`] instead.
+    ///
+    /// [`heartbeats`]: #method.heartbeats
+    pub async fn next_command(&mut self) -> Option<Value> {
+        self.commands.next().await
+    }
+
+    /// Close the connection with a close code, such as 4004 for an invalid
+    /// token.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SendError::Closed`] if the connection is already closed.
+    ///
+    /// [`SendError::Closed`]: enum.SendError.html#variant.Closed
+    pub fn close(&self, code: u16, reason: &str) -> Result<(), SendError> {
+        self.tx
+            .unbounded_send(Outgoing::Close(CloseFrame {
+                code: CloseCode::from(code),
+                reason: Cow::Owned(reason.to_owned()),
+            }))
+            .map_err(|_| SendError::Closed)
+    }
+
+    /// Send a dispatch event with the next sequence, such as `MESSAGE_CREATE`
+    /// and its data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SendError::Closed`] if the connection is closed.
+    ///
+    /// Returns [`SendError::Serializing`] if serializing the data failed.
+    ///
+    /// [`SendError::Closed`]: enum.SendError.html#variant.Closed
+    /// [`SendError::Serializing`]: enum.SendError.html#variant.Serializing
+    pub fn dispatch(&mut self, kind: &str, data: impl Serialize) -> Result<(), SendError> {
+        let data =
+            serde_json::to_value(data).map_err(|source| SendError::Serializing { source })?;
+        self.sequence += 1;
+
+        self.send(&json!({
+            "d": data,
+            "op": 0,
+            "s": self.sequence,
+            "t": kind,
+        }))
+    }
+
+    /// Send a HELLO with the interval that the shard should heartbeat at, in
+    /// milliseconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SendError::Closed`] if the connection is closed.
+    ///
+    /// [`SendError::Closed`]: enum.SendError.html#variant.Closed
+    pub fn hello(&self, heartbeat_interval: u64) -> Result<(), SendError> {
+        self.send(&json!({
+            "d": {
+                "heartbeat_interval": heartbeat_interval,
+            },
+            "op": 10,
+        }))
+    }
+
+    /// Send an INVALID SESSION, telling the shard whether it may resume.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SendError::Closed`] if the connection is closed.
+    ///
+    /// [`SendError::Closed`]: enum.SendError.html#variant.Closed
+    pub fn invalidate_session(&self, resumable: bool) -> Result<(), SendError> {
+        self.send(&json!({
+            "d": resumable,
+            "op": 9,
+        }))
+    }
+
+    /// Send a READY dispatch event for a session with no guilds, as a bot
+    /// user with an ID of 1.
+    ///
+    /// Use [`dispatch`] to send a READY with other data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SendError::Closed`] if the connection is closed.
+    ///
+    /// [`SendError::Closed`]: enum.SendError.html#variant.Closed
+    /// [`dispatch`]: #method.dispatch
+    pub fn ready(&mut self, session_id: &str) -> Result<(), SendError> {
+        self.dispatch(
+            "READY",
+            json!({
+                "guilds": [],
+                "session_id": session_id,
+                "user": {
+                    "avatar": null,
+                    "bot": true,
+                    "discriminator": "0001",
+                    "id": "1",
+                    "mfa_enabled": false,
+                    "username": "mock",
+                    "verified": true,
+                },
+                "v": 8,
+            }),
+        )
+    }
+
+    /// Send a RECONNECT, telling the shard to reconnect and resume.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SendError::Closed`] if the connection is closed.
+    ///
+    /// [`SendError::Closed`]: enum.SendError.html#variant.Closed
+    pub fn reconnect(&self) -> Result<(), SendError> {
+        self.send(&json!({
+            "d": null,
+            "op": 7,
+        }))
+    }
+
+    /// Send a RESUMED dispatch event with the next sequence, completing a
+    /// resume.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SendError::Closed`] if the connection is closed.
+    ///
+    /// [`SendError::Closed`]: enum.SendError.html#variant.Closed
+    pub fn resumed(&mut self) -> Result<(), SendError> {
+        self.dispatch("RESUMED", Value::Null)
+    }
+
+    /// Send a raw payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SendError::Closed`] if the connection is closed.
+    ///
+    /// Returns [`SendError::Serializing`] if serializing the payload failed.
+    ///
+    /// [`SendError::Closed`]: enum.SendError.html#variant.Closed
+    /// [`SendError::Serializing`]: enum.SendError.html#variant.Serializing
+    pub fn send(&self, payload: &impl Serialize) -> Result<(), SendError> {
+        let payload =
+            serde_json::to_vec(payload).map_err(|source| SendError::Serializing { source })?;
+
+        self.tx
+            .unbounded_send(Outgoing::Payload(payload))
+            .map_err(|_| SendError::Closed)
+    }
+}
+
+/// Write messages to the connection, compressing payloads into a zlib stream.
+async fn write(mut sink: SplitSink<Stream, Message>, mut rx: UnboundedReceiver<Outgoing>) {
+    let mut compress = Compress::new(Compression::default(), true);
+
+    while let Some(outgoing) = rx.next().await {
+        let (message, close) = match outgoing {
+            Outgoing::Close(frame) => (Message::Close(Some(frame)), true),
+            Outgoing::Payload(payload) => match deflate(&mut compress, &payload) {
+                Some(bytes) => (Message::Binary(bytes), false),
+                None => break,
+            },
+        };
+
+        if sink.send(message).await.is_err() || close {
+            break;
+        }
+    }
+}
+
+/// Read commands from the connection, acknowledging heartbeats.
+async fn read(
+    mut stream: SplitStream<Stream>,
+    commands: UnboundedSender<Value>,
+    state: Arc<State>,
+    tx: UnboundedSender<Outgoing>,
+) {
+    while let Some(Ok(message)) = stream.next().await {
+        let bytes = match message {
+            Message::Binary(bytes) => bytes,
+            Message::Text(text) => text.into_bytes(),
+            Message::Close(frame) => {
+                if let Ok(mut code) = state.close_code.lock() {
+                    *code = frame.map(|frame| frame.code.into());
+                }
+
+                break;
+            }
+            Message::Ping(_) | Message::Pong(_) => continue,
+        };
+
+        let command = match serde_json::from_slice::<Value>(&bytes) {
+            Ok(command) if command["op"] == 1 => {
+                state.heartbeats.fetch_add(1, Ordering::AcqRel);
+
+                if state.ack_heartbeats.load(Ordering::Acquire) {
+                    let ack = serde_json::to_vec(&json!({ "op": 11 })).unwrap_or_default();
+                    let _ = tx.unbounded_send(Outgoing::Payload(ack));
+                }
+
+                continue;
+            }
+            Ok(command) => command,
+            Err(_) => continue,
+        };
+
+        if commands.unbounded_send(command).is_err() {
+            break;
+        }
+    }
+}
+
+/// Compress a payload, flushing it so that it ends with the zlib suffix that
+/// shards wait for.
+fn deflate(compress: &mut Compress, payload: &[u8]) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(payload.len() + 64);
+    let before = compress.total_in();
+
+    loop {
+        let offset = usize::try_from(compress.total_in() - before).ok()?;
+
+        compress
+            .compress_vec(&payload[offset..], &mut bytes, FlushCompress::Sync)
+            .ok()?;
+
+        let consumed = usize::try_from(compress.total_in() - before).ok()? == payload.len();
+
+        if consumed && bytes.len() < bytes.capacity() {
+            return Some(bytes);
+        }
+
+        bytes.reserve(bytes.capacity());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MockSession, SendError};
+    use crate::MockGateway;
+    use async_tungstenite::tungstenite::{Error as TungsteniteError, Message};
+    use flate2::{Decompress, FlushDecompress};
+    use futures_util::{
+        sink::SinkExt,
+        stream::{Stream, StreamExt},
+    };
+    use serde_json::{json, Value};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+
+    assert_fields!(SendError::Serializing: source);
+    assert_impl_all!(MockSession: Debug, Send, Sync);
+    assert_impl_all!(SendError: Debug, Error, Send, Sync);
+
+    /// Receive and decompress a payload as a shard would.
+    async fn receive(
+        client: &mut (impl Stream<Item = Result<Message, TungsteniteError>> + Unpin),
+        decompress: &mut Decompress,
+    ) -> Value {
+        let bytes = match client.next().await.unwrap().unwrap() {
+            Message::Binary(bytes) => bytes,
+            other => panic!("expected a binary message: {:?}", other),
+        };
+        assert_eq!([0, 0, 0xFF, 0xFF], bytes[bytes.len() - 4..]);
+
+        let mut out = Vec::with_capacity(1024);
+        decompress
+            .decompress_vec(&bytes, &mut out, FlushDecompress::Sync)
+            .unwrap();
+
+        serde_json::from_slice(&out).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_session() {
+        let mut gateway = MockGateway::bind().await.unwrap();
+        let url = format!("{}/?v=9&compress=zlib-stream", gateway.url());
+        let (client, session) = tokio::join!(
+            async_tungstenite::tokio::connect_async(url),
+            gateway.accept()
+        );
+        let (mut client, _) = client.unwrap();
+        let mut session = session.unwrap();
+        let mut decompress = Decompress::new(true);
+
+        session.hello(100).unwrap();
+        session.dispatch("TYPING_START", json!({})).unwrap();
+        session.ready("id").unwrap();
+
+        assert_eq!(
+            json!({"d": {"heartbeat_interval": 100}, "op": 10}),
+            receive(&mut client, &mut decompress).await
+        );
+
+        let typing = receive(&mut client, &mut decompress).await;
+        assert_eq!(json!(1), typing["s"]);
+        assert_eq!(json!("TYPING_START"), typing["t"]);
+
+        let ready = receive(&mut client, &mut decompress).await;
+        assert_eq!(json!(2), ready["s"]);
+        assert_eq!(json!("id"), ready["d"]["session_id"]);
+
+        client
+            .send(Message::Binary(br#"{"op":1,"d":2}"#.to_vec()))
+            .await
+            .unwrap();
+        client
+            .send(Message::Binary(br#"{"op":2,"d":{}}"#.to_vec()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            json!({"op": 2, "d": {}}),
+            session.next_command().await.unwrap()
+        );
+        assert_eq!(1, session.heartbeats());
+        assert_eq!(
+            json!({"op": 11}),
+            receive(&mut client, &mut decompress).await
+        );
+
+        session.close(4004, "Authentication failed.").unwrap();
+
+        match client.next().await.unwrap().unwrap() {
+            Message::Close(Some(frame)) => assert_eq!(4004, u16::from(frame.code)),
+            other => panic!("expected a close message: {:?}", other),
+        }
+    }
+}
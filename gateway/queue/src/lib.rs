@@ -28,16 +28,20 @@
 //! queue. The most common reason to need this is if you have clusters in
 //! multiple processes. You'll need a broker to manage ratelimiting across them
 //! all so a [`Queue`] trait is provided that shards can use to make requests to
-//! create sessions.
+//! create sessions. The [`RemoteQueue`] is a client for such a broker over
+//! HTTP, such as the [`gateway-queue`] broker.
 //!
+//! [`gateway-queue`]: https://github.com/twilight-rs/gateway-queue
 //! [`LargeBotQueue`]: struct.LargeBotQueue.html
 //! [`LocalQueue`]: struct.LocalQueue.html
+//! [`RemoteQueue`]: struct.RemoteQueue.html
 //! [Sharding for Very Large Bots]: https://discord.com/developers/docs/topics/gateway#sharding-for-very-large-bots
 
 mod day_limiter;
 mod large_bot_queue;
+mod remote_queue;
 
-pub use large_bot_queue::LargeBotQueue;
+pub use self::{large_bot_queue::LargeBotQueue, remote_queue::RemoteQueue};
 
 use day_limiter::DayLimiter;
 use futures_channel::{
@@ -0,0 +1,98 @@
+use super::Queue;
+use reqwest::Client;
+use std::{future::Future, pin::Pin, time::Duration};
+use tokio::time::delay_for;
+
+/// Queue that requests the ability to initialize sessions from a remote
+/// broker over HTTP, such as the [`gateway-queue`] broker.
+///
+/// This is for clusters in multiple processes: each process uses a remote
+/// queue pointing to the same broker, which serializes the session
+/// initializations of all of them.
+///
+/// For each request a `GET` request is sent to the URL of the broker with
+/// the ID of the shard as the `shard` query parameter, and the shard can
+/// initialize its session once the broker responds. If the broker can't be
+/// reached or responds with an error, the request is retried.
+///
+/// # Examples
+///
+/// Use a broker running on the local machine as the queue of a shard:
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use twilight_gateway_queue::{Queue, RemoteQueue};
+///
+/// # fn main() {
+/// let queue: Arc<Box<dyn Queue>> = Arc::new(Box::new(RemoteQueue::new("http://127.0.0.1:80")));
+/// # }
+/// ```
+///
+/// [`gateway-queue`]: https://github.com/twilight-rs/gateway-queue
+#[derive(Clone, Debug)]
+pub struct RemoteQueue {
+    http: Client,
+    url: String,
+}
+
+impl RemoteQueue {
+    /// Delay before retrying a failed request to the broker.
+    const RETRY_DELAY: Duration = Duration::from_secs(5);
+
+    /// Create a new remote queue with the URL of the broker.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self::with_client(Client::new(), url)
+    }
+
+    /// Create a new remote queue with the URL of the broker, using an
+    /// existing HTTP client.
+    pub fn with_client(http: Client, url: impl Into<String>) -> Self {
+        Self {
+            http,
+            url: url.into(),
+        }
+    }
+}
+
+impl Queue for RemoteQueue {
+    /// Request to be able to identify with the gateway. The returned future
+    /// will resolve once the broker has responded with the allowance.
+    fn request(&'_ self, [id, total]: [u64; 2]) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            tracing::info!("shard {}/{} waiting for allowance", id, total);
+
+            loop {
+                let response = self
+                    .http
+                    .get(&self.url)
+                    .query(&[("shard", id)])
+                    .send()
+                    .await
+                    .and_then(reqwest::Response::error_for_status);
+
+                match response {
+                    Ok(_) => return,
+                    Err(source) => {
+                        tracing::warn!(
+                            "shard {}/{} failed to request allowance, retrying: {}",
+                            id,
+                            total,
+                            source
+                        );
+                    }
+                }
+
+                delay_for(Self::RETRY_DELAY).await;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Queue, RemoteQueue};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(RemoteQueue: Clone, Debug, Queue, Send, Sync);
+}
@@ -2,7 +2,10 @@ use super::{
     config::Config as ClusterConfig,
     r#impl::{Cluster, ClusterStartError},
 };
-use crate::shard::{LargeThresholdError, ResumeSession, ShardBuilder};
+use crate::{
+    shard::{Encoding, LargeThresholdError, Reconnect, ResumeSession, ShardBuilder},
+    EventTypeFlags,
+};
 use std::{
     collections::HashMap,
     convert::TryFrom,
@@ -17,7 +20,63 @@ use twilight_model::gateway::{payload::update_status::UpdateStatusInfo, Intents}
 
 /// Starting a cluster failed.
 #[derive(Debug)]
-pub enum ShardSchemeRangeError {
+pub struct ShardSchemeRangeError {
+    kind: ShardSchemeRangeErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ShardSchemeRangeError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ShardSchemeRangeErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ShardSchemeRangeErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ShardSchemeRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ShardSchemeRangeErrorType::IdTooLarge { end, start, total } => {
+                f.write_fmt(format_args!(
+                    "The shard ID range {}-{}/{} is larger than the total",
+                    start, end, total
+                ))
+            }
+        }
+    }
+}
+
+impl Error for ShardSchemeRangeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ShardSchemeRangeError`] that occurred.
+///
+/// [`ShardSchemeRangeError`]: struct.ShardSchemeRangeError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ShardSchemeRangeErrorType {
     /// Start of the shard range was greater than the end or total.
     IdTooLarge {
         /// Last shard in the range to manage.
@@ -29,19 +88,6 @@ pub enum ShardSchemeRangeError {
     },
 }
 
-impl Display for ShardSchemeRangeError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::IdTooLarge { end, start, total } => f.write_fmt(format_args!(
-                "The shard ID range {}-{}/{} is larger than the total",
-                start, end, total
-            )),
-        }
-    }
-}
-
-impl Error for ShardSchemeRangeError {}
-
 /// The method of sharding to use.
 ///
 /// By default this is [`Auto`].
@@ -55,6 +101,12 @@ pub enum ShardScheme {
     ///
     /// For example, if Discord recommends 10 shards, then all 10 shards will be
     /// started.
+    ///
+    /// If no queue was configured and the bot may identify multiple shards at
+    /// once, then a [`LargeBotQueue`] with a bucket for each concurrent
+    /// identify is used.
+    ///
+    /// [`LargeBotQueue`]: ../queue/struct.LargeBotQueue.html
     Auto,
     /// Specifies to start a range of shards.
     ///
@@ -105,7 +157,10 @@ impl<T: RangeBounds<u64>> TryFrom<(T, u64)> for ShardScheme {
         };
 
         if start > end {
-            return Err(ShardSchemeRangeError::IdTooLarge { end, start, total });
+            return Err(ShardSchemeRangeError {
+                kind: ShardSchemeRangeErrorType::IdTooLarge { end, start, total },
+                source: None,
+            });
         }
 
         Ok(Self::Range {
@@ -164,6 +219,7 @@ impl ClusterBuilder {
                 shard_config: shard_config.0,
                 shard_scheme: ShardScheme::Auto,
                 queue: Arc::new(Box::new(LocalQueue::new())),
+                queue_configured: false,
                 resume_sessions: HashMap::new(),
             },
             ShardBuilder::new(token, intents),
@@ -174,29 +230,49 @@ impl ClusterBuilder {
     ///
     /// # Errors
     ///
-    /// Returns [`ClusterStartError::RetrievingGatewayInfo`] if there was an
+    /// Returns [`ClusterStartErrorType::RetrievingGatewayInfo`] if there was an
     /// HTTP error Retrieving the gateway information.
     ///
-    /// [`ClusterStartError::RetrievingGatewayInfo`]: enum.ClusterStartError.html#variant.RetrievingGatewayInfo
+    /// [`ClusterStartErrorType::RetrievingGatewayInfo`]: enum.ClusterStartErrorType.html#variant.RetrievingGatewayInfo
     pub async fn build(mut self) -> Result<Cluster, ClusterStartError> {
-        if self.0.shard_config.gateway_url.is_none() {
-            let gateway_url = (self.1)
-                .0
-                .http_client
-                .gateway()
-                .authed()
-                .await
-                .ok()
-                .map(|s| s.url);
-
-            self = self.gateway_url(gateway_url);
-        }
-
         self.0.shard_config = (self.1).0;
 
         Cluster::new_with_config(self.0).await
     }
 
+    /// Set whether payloads are compressed with zlib by the gateway.
+    ///
+    /// Refer to [`ShardBuilder::compression`] for the default value.
+    ///
+    /// [`ShardBuilder::compression`]: ../shard/struct.ShardBuilder.html#method.compression
+    pub fn compression(mut self, compression: bool) -> Self {
+        self.1 = self.1.compression(compression);
+
+        self
+    }
+
+    /// Set the encoding of the payloads sent to and received from the gateway.
+    ///
+    /// Refer to [`ShardBuilder::encoding`] for the default value.
+    ///
+    /// [`ShardBuilder::encoding`]: ../shard/struct.ShardBuilder.html#method.encoding
+    pub fn encoding(mut self, encoding: Encoding) -> Self {
+        self.1 = self.1.encoding(encoding);
+
+        self
+    }
+
+    /// Set the event types that the shards emit.
+    ///
+    /// Refer to [`ShardBuilder::event_types`] for more information.
+    ///
+    /// [`ShardBuilder::event_types`]: ../shard/struct.ShardBuilder.html#method.event_types
+    pub fn event_types(mut self, event_types: EventTypeFlags) -> Self {
+        self.1 = self.1.event_types(event_types);
+
+        self
+    }
+
     /// Set the URL that will be used to connect to the gateway.
     pub fn gateway_url(mut self, gateway_url: Option<String>) -> Self {
         self.1 = self.1.gateway_url(gateway_url);
@@ -224,14 +300,14 @@ impl ClusterBuilder {
     ///
     /// # Errors
     ///
-    /// Returns [`LargeThresholdError::TooFew`] if the provided value is below
+    /// Returns [`LargeThresholdErrorType::TooFew`] if the provided value is below
     /// 50.
     ///
-    /// Returns [`LargeThresholdError::TooMany`] if the provided value is above
+    /// Returns [`LargeThresholdErrorType::TooMany`] if the provided value is above
     /// 250.
     ///
-    /// [`LargeThresholdError::TooFew`]: ../shard/enum.LargeThresholdError.html#variant.TooFew
-    /// [`LargeThresholdError::TooMany`]: ../shard/enum.LargeThresholdError.html#variant.TooMany
+    /// [`LargeThresholdErrorType::TooFew`]: ../shard/enum.LargeThresholdErrorType.html#variant.TooFew
+    /// [`LargeThresholdErrorType::TooMany`]: ../shard/enum.LargeThresholdErrorType.html#variant.TooMany
     /// [`ShardBuilder::large_treshold`]: ../shard/ShardBuilder.html#method.large_threshold
     pub fn large_threshold(mut self, large_threshold: u64) -> Result<Self, LargeThresholdError> {
         self.1 = self.1.large_threshold(large_threshold)?;
@@ -250,6 +326,17 @@ impl ClusterBuilder {
         self
     }
 
+    /// Set how the shards reconnect when their sessions can't be resumed.
+    ///
+    /// Refer to [`Reconnect`] for the default value.
+    ///
+    /// [`Reconnect`]: ../shard/struct.Reconnect.html
+    pub fn reconnect(mut self, reconnect: Reconnect) -> Self {
+        self.1 = self.1.reconnect(reconnect);
+
+        self
+    }
+
     /// Set the scheme to use for shard managing.
     ///
     /// For example, [`ShardScheme::Auto`] means that the cluster will
@@ -297,9 +384,17 @@ impl ClusterBuilder {
     ///
     /// Refer to the [`queue`] module for more information.
     ///
+    /// Defaults to a [`LocalQueue`], or a [`LargeBotQueue`] when using
+    /// [`ShardScheme::Auto`] with a bot that may identify multiple shards at
+    /// once.
+    ///
+    /// [`LargeBotQueue`]: ../queue/struct.LargeBotQueue.html
+    /// [`LocalQueue`]: ../queue/struct.LocalQueue.html
+    /// [`ShardScheme::Auto`]: enum.ShardScheme.html#variant.Auto
     /// [`queue`]: ../queue/index.html
     pub fn queue(mut self, queue: Arc<Box<dyn Queue>>) -> Self {
         self.0.queue = Arc::clone(&queue);
+        self.0.queue_configured = true;
         self.1 = self.1.queue(queue);
 
         self
@@ -329,7 +424,7 @@ impl<T: Into<String>> From<(T, Intents)> for ClusterBuilder {
 
 #[cfg(test)]
 mod tests {
-    use super::{ClusterBuilder, ShardScheme, ShardSchemeRangeError};
+    use super::{ClusterBuilder, ShardScheme, ShardSchemeRangeError, ShardSchemeRangeErrorType};
     use crate::Intents;
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{
@@ -339,9 +434,10 @@ mod tests {
         hash::Hash,
     };
 
-    assert_fields!(ShardSchemeRangeError::IdTooLarge: end, start, total);
+    assert_fields!(ShardSchemeRangeErrorType::IdTooLarge: end, start, total);
     assert_fields!(ShardScheme::Range: from, to, total);
     assert_impl_all!(ClusterBuilder: Debug, From<(String, Intents)>, Send, Sync);
+    assert_impl_all!(ShardSchemeRangeErrorType: Debug, Send, Sync);
     assert_impl_all!(ShardSchemeRangeError: Debug, Display, Error, Send, Sync);
     assert_impl_all!(
         ShardScheme: Clone,
@@ -13,6 +13,7 @@ pub struct Config {
     pub(super) shard_config: ShardConfig,
     pub(super) shard_scheme: ShardScheme,
     pub(super) queue: Arc<Box<dyn Queue>>,
+    pub(super) queue_configured: bool,
     pub(super) resume_sessions: HashMap<u64, ResumeSession>,
 }
 
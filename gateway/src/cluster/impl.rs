@@ -3,7 +3,7 @@ use super::{
     config::Config,
 };
 use crate::{
-    shard::{CommandError, Information, ResumeSession, Shard},
+    shard::{Information, ResumeSession, Shard},
     EventTypeFlags, Intents,
 };
 use futures_util::{
@@ -12,36 +12,53 @@ use futures_util::{
 };
 use std::{
     collections::HashMap,
+    convert::TryFrom,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
     iter::FromIterator,
     sync::{Arc, Mutex},
 };
-use twilight_http::Error as HttpError;
+use twilight_gateway_queue::LargeBotQueue;
 use twilight_model::gateway::event::Event;
 
 /// Sending a command to a shard failed.
 #[derive(Debug)]
-#[non_exhaustive]
-pub enum ClusterCommandError {
-    /// The shard exists, but sending the provided value failed.
-    Sending {
-        /// Reason for the error.
-        source: CommandError,
-    },
-    /// Provided shard ID does not exist.
-    ShardNonexistent {
-        /// Provided shard ID.
-        id: u64,
-    },
+pub struct ClusterCommandError {
+    kind: ClusterCommandErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ClusterCommandError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ClusterCommandErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ClusterCommandErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for ClusterCommandError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::Sending { source } => Display::fmt(source, f),
-            Self::ShardNonexistent { id } => {
-                f.write_fmt(format_args!("shard {} does not exist", id,))
+        match &self.kind {
+            ClusterCommandErrorType::Sending => f.write_str("sending the command failed"),
+            ClusterCommandErrorType::ShardNonexistent { id } => {
+                f.write_fmt(format_args!("shard {} does not exist", id))
             }
         }
     }
@@ -49,34 +66,62 @@ impl Display for ClusterCommandError {
 
 impl Error for ClusterCommandError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Self::Sending { source } => Some(source),
-            Self::ShardNonexistent { .. } => None,
-        }
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
     }
 }
 
-/// Starting a cluster failed.
+/// Type of [`ClusterCommandError`] that occurred.
+///
+/// [`ClusterCommandError`]: struct.ClusterCommandError.html
 #[derive(Debug)]
 #[non_exhaustive]
-pub enum ClusterStartError {
-    /// Retrieving the bot's gateway information via the HTTP API failed.
+pub enum ClusterCommandErrorType {
+    /// The shard exists, but sending the provided value failed.
     ///
-    /// This can occur when using [automatic sharding] and retrieval of the
-    /// number of recommended number of shards to start fails, which can happen
-    /// due to something like a network or response parsing issue.
+    /// The source is a [`CommandError`].
     ///
-    /// [automatic sharding]: enum.ShardScheme.html#variant.Auto
-    RetrievingGatewayInfo {
-        /// Reason for the error.
-        source: HttpError,
+    /// [`CommandError`]: ../shard/struct.CommandError.html
+    Sending,
+    /// Provided shard ID does not exist.
+    ShardNonexistent {
+        /// Provided shard ID.
+        id: u64,
     },
 }
 
+/// Starting a cluster failed.
+#[derive(Debug)]
+pub struct ClusterStartError {
+    kind: ClusterStartErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ClusterStartError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ClusterStartErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ClusterStartErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
 impl Display for ClusterStartError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::RetrievingGatewayInfo { .. } => {
+        match &self.kind {
+            ClusterStartErrorType::RetrievingGatewayInfo => {
                 f.write_str("getting the bot's gateway info failed")
             }
         }
@@ -85,12 +130,28 @@ impl Display for ClusterStartError {
 
 impl Error for ClusterStartError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Self::RetrievingGatewayInfo { source } => Some(source),
-        }
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
     }
 }
 
+/// Type of [`ClusterStartError`] that occurred.
+///
+/// [`ClusterStartError`]: struct.ClusterStartError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ClusterStartErrorType {
+    /// Retrieving the bot's gateway information via the HTTP API failed.
+    ///
+    /// This can occur when using [automatic sharding] and retrieval of the
+    /// number of recommended number of shards to start fails, which can happen
+    /// due to something like a network or response parsing issue.
+    ///
+    /// [automatic sharding]: enum.ShardScheme.html#variant.Auto
+    RetrievingGatewayInfo,
+}
+
 #[derive(Debug)]
 struct ClusterRef {
     config: Config,
@@ -117,10 +178,10 @@ impl Cluster {
     ///
     /// # Errors
     ///
-    /// Returns [`ClusterStartError::RetrievingGatewayInfo`] if there was an
+    /// Returns [`ClusterStartErrorType::RetrievingGatewayInfo`] if there was an
     /// HTTP error Retrieving the gateway information.
     ///
-    /// [`ClusterStartError::RetrievingGatewayInfo`]: enum.ClusterStartError.html#variant.RetrievingGatewayInfo
+    /// [`ClusterStartErrorType::RetrievingGatewayInfo`]: enum.ClusterStartErrorType.html#variant.RetrievingGatewayInfo
     /// [`builder`]: #method.builder
     pub async fn new(
         token: impl Into<String>,
@@ -130,21 +191,53 @@ impl Cluster {
     }
 
     pub(super) async fn new_with_config(mut config: Config) -> Result<Self, ClusterStartError> {
-        let [from, to, total] = match config.shard_scheme() {
+        // The gateway information is required to automatically shard, but is
+        // otherwise only used to retrieve the URL to connect to.
+        let (gateway, [from, to, total]) = match config.shard_scheme().clone() {
             ShardScheme::Auto => {
-                let http = config.http_client();
-
-                let gateway = http
+                let gateway = config
+                    .http_client()
                     .gateway()
                     .authed()
                     .await
-                    .map_err(|source| ClusterStartError::RetrievingGatewayInfo { source })?;
+                    .map_err(|source| ClusterStartError {
+                        kind: ClusterStartErrorType::RetrievingGatewayInfo,
+                        source: Some(Box::new(source)),
+                    })?;
+
+                let buckets = gateway.session_start_limit.max_concurrency;
+
+                if !config.queue_configured && buckets > 1 {
+                    tracing::debug!(buckets, "using large bot queue");
+
+                    let queue = LargeBotQueue::new(
+                        usize::try_from(buckets).unwrap_or(usize::MAX),
+                        config.http_client(),
+                    )
+                    .await;
+                    config.queue = Arc::new(Box::new(queue));
+                    config.shard_config.queue = Arc::clone(&config.queue);
+                }
+
+                let total = gateway.shards;
 
-                [0, gateway.shards - 1, gateway.shards]
+                (Some(gateway), [0, total - 1, total])
+            }
+            ShardScheme::Range { from, to, total } => {
+                let gateway = if config.shard_config.gateway_url.is_none() {
+                    config.http_client().gateway().authed().await.ok()
+                } else {
+                    None
+                };
+
+                (gateway, [from, to, total])
             }
-            ShardScheme::Range { from, to, total } => [*from, *to, *total],
         };
 
+        if config.shard_config.gateway_url.is_none() {
+            config.shard_config.gateway_url = gateway.map(|info| info.url);
+        }
+
         #[cfg(feature = "metrics")]
         {
             use std::convert::TryInto;
@@ -307,50 +400,58 @@ impl Cluster {
     ///
     /// # Errors
     ///
-    /// Returns [`ClusterCommandError::Sending`] if the shard exists, but
+    /// Returns [`ClusterCommandErrorType::Sending`] if the shard exists, but
     /// sending it failed.
     ///
-    /// Returns [`ClusterCommandError::ShardNonexistent`] if the provided shard
+    /// Returns [`ClusterCommandErrorType::ShardNonexistent`] if the provided shard
     /// ID does not exist in the cluster.
     ///
-    /// [`ClusterCommandError::Sending`]: enum.ClusterCommandError.html#variant.Sending
-    /// [`ClusterCommandError::ShardNonexistent`]: enum.ClusterCommandError.html#variant.ShardNonexistent
+    /// [`ClusterCommandErrorType::Sending`]: enum.ClusterCommandErrorType.html#variant.Sending
+    /// [`ClusterCommandErrorType::ShardNonexistent`]: enum.ClusterCommandErrorType.html#variant.ShardNonexistent
     pub async fn command(
         &self,
         id: u64,
         value: &impl serde::Serialize,
     ) -> Result<(), ClusterCommandError> {
-        let shard = self
-            .shard(id)
-            .ok_or(ClusterCommandError::ShardNonexistent { id })?;
+        let shard = self.shard(id).ok_or(ClusterCommandError {
+            kind: ClusterCommandErrorType::ShardNonexistent { id },
+            source: None,
+        })?;
 
         shard
             .command(value)
             .await
-            .map_err(|source| ClusterCommandError::Sending { source })
+            .map_err(|source| ClusterCommandError {
+                kind: ClusterCommandErrorType::Sending,
+                source: Some(Box::new(source)),
+            })
     }
 
     /// Send a raw command to the specified shard.
     ///
     /// # Errors
     ///
-    /// Returns [`ClusterCommandError::Sending`] if the shard exists, but
+    /// Returns [`ClusterCommandErrorType::Sending`] if the shard exists, but
     /// sending it failed.
     ///
-    /// Returns [`ClusterCommandError::ShardNonexistent`] if the provided shard
+    /// Returns [`ClusterCommandErrorType::ShardNonexistent`] if the provided shard
     /// ID does not exist in the cluster.
     ///
-    /// [`ClusterCommandError::Sending`]: enum.ClusterCommandError.html#variant.Sending
-    /// [`ClusterCommandError::ShardNonexistent`]: enum.ClusterCommandError.html#variant.ShardNonexistent
+    /// [`ClusterCommandErrorType::Sending`]: enum.ClusterCommandErrorType.html#variant.Sending
+    /// [`ClusterCommandErrorType::ShardNonexistent`]: enum.ClusterCommandErrorType.html#variant.ShardNonexistent
     pub async fn command_raw(&self, id: u64, value: Vec<u8>) -> Result<(), ClusterCommandError> {
-        let shard = self
-            .shard(id)
-            .ok_or(ClusterCommandError::ShardNonexistent { id })?;
+        let shard = self.shard(id).ok_or(ClusterCommandError {
+            kind: ClusterCommandErrorType::ShardNonexistent { id },
+            source: None,
+        })?;
 
         shard
             .command_raw(value)
             .await
-            .map_err(|source| ClusterCommandError::Sending { source })
+            .map_err(|source| ClusterCommandError {
+                kind: ClusterCommandErrorType::Sending,
+                source: Some(Box::new(source)),
+            })
     }
 
     /// Return a stream of events from all shards managed by this Cluster.
@@ -440,14 +541,17 @@ impl Cluster {
 
 #[cfg(test)]
 mod tests {
-    use super::{Cluster, ClusterCommandError, ClusterStartError};
+    use super::{
+        Cluster, ClusterCommandError, ClusterCommandErrorType, ClusterStartError,
+        ClusterStartErrorType,
+    };
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{error::Error, fmt::Debug};
 
-    assert_fields!(ClusterCommandError::Sending: source);
-    assert_fields!(ClusterCommandError::ShardNonexistent: id);
+    assert_fields!(ClusterCommandErrorType::ShardNonexistent: id);
+    assert_impl_all!(ClusterCommandErrorType: Debug, Send, Sync);
     assert_impl_all!(ClusterCommandError: Debug, Error, Send, Sync);
-    assert_fields!(ClusterStartError::RetrievingGatewayInfo: source);
+    assert_impl_all!(ClusterStartErrorType: Debug, Send, Sync);
     assert_impl_all!(ClusterStartError: Debug, Error, Send, Sync);
     assert_impl_all!(Cluster: Clone, Debug, Send, Sync);
 }
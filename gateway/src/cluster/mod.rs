@@ -38,14 +38,14 @@
 //!         Event::ShardDisconnected { .. } => {
 //!             println!("Shard {} is now disconnected", shard_id);
 //!         },
-//!         Event::MessageCreate(msg) if msg.content == "!latency" => {
+//!         Event::MessageCreate(msg) if &*msg.content == "!latency" => {
 //!             if let Some(shard) = cluster.shard(shard_id) {
 //!                 if let Ok(info) = shard.info() {
 //!                     println!("Shard {}'s latency is {:?}", shard_id, info.latency());
 //!                 }
 //!             }
 //!         },
-//!         Event::MessageCreate(msg) if msg.content == "!shutdown" => {
+//!         Event::MessageCreate(msg) if &*msg.content == "!shutdown" => {
 //!             println!("Got a shutdown request from shard {}", shard_id);
 //!
 //!             cluster.down();
@@ -60,7 +60,10 @@ mod config;
 mod r#impl;
 
 pub use self::{
-    builder::{ClusterBuilder, ShardScheme, ShardSchemeRangeError},
+    builder::{ClusterBuilder, ShardScheme, ShardSchemeRangeError, ShardSchemeRangeErrorType},
     config::Config,
-    r#impl::{Cluster, ClusterCommandError, ClusterStartError},
+    r#impl::{
+        Cluster, ClusterCommandError, ClusterCommandErrorType, ClusterStartError,
+        ClusterStartErrorType,
+    },
 };
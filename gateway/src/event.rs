@@ -5,7 +5,15 @@ use twilight_model::gateway::event::EventType;
 bitflags! {
     /// Bitflags representing all of the possible types of events.
     #[non_exhaustive]
-    pub struct EventTypeFlags: u64 {
+    pub struct EventTypeFlags: u128 {
+        /// An action of an auto moderation rule has been executed.
+        const AUTO_MODERATION_ACTION_EXECUTION = 1 << 65;
+        /// An auto moderation rule has been created.
+        const AUTO_MODERATION_RULE_CREATE = 1 << 66;
+        /// An auto moderation rule has been deleted.
+        const AUTO_MODERATION_RULE_DELETE = 1 << 67;
+        /// An auto moderation rule has been updated.
+        const AUTO_MODERATION_RULE_UPDATE = 1 << 68;
         /// User has been banned from a guild.
         const BAN_ADD = 1;
         /// User has been unbanned from a guild.
@@ -41,8 +49,20 @@ bitflags! {
         const GUILD_EMOJIS_UPDATE = 1 << 12;
         /// A guild's integrations have been updated.
         const GUILD_INTEGRATIONS_UPDATE = 1 << 13;
+        /// A scheduled event has been created in a guild.
+        const GUILD_SCHEDULED_EVENT_CREATE = 1 << 60;
+        /// A scheduled event has been deleted from a guild.
+        const GUILD_SCHEDULED_EVENT_DELETE = 1 << 61;
+        /// A scheduled event in a guild has been updated.
+        const GUILD_SCHEDULED_EVENT_UPDATE = 1 << 62;
+        /// A user has subscribed to a scheduled event.
+        const GUILD_SCHEDULED_EVENT_USER_ADD = 1 << 63;
+        /// A user has unsubscribed from a scheduled event.
+        const GUILD_SCHEDULED_EVENT_USER_REMOVE = 1 << 64;
         /// A guild has been updated.
         const GUILD_UPDATE = 1 << 14;
+        /// An interaction has been invoked by a user.
+        const INTERACTION_CREATE = 1 << 50;
         /// Invite for a channel has been created.
         const INVITE_CREATE = 1 << 46;
         /// Invite for a channel has been deleted.
@@ -105,6 +125,24 @@ bitflags! {
         const SHARD_RECONNECTING = 1 << 37;
         /// Shard is resuming a session with the gateway.
         const SHARD_RESUMING = 1 << 38;
+        /// A stage instance has been created.
+        const STAGE_INSTANCE_CREATE = 1 << 57;
+        /// A stage instance has been deleted.
+        const STAGE_INSTANCE_DELETE = 1 << 58;
+        /// A stage instance has been updated.
+        const STAGE_INSTANCE_UPDATE = 1 << 59;
+        /// A thread has been created or the current user has been added to a private thread.
+        const THREAD_CREATE = 1 << 51;
+        /// A thread has been deleted.
+        const THREAD_DELETE = 1 << 52;
+        /// Threads of channels the current user has gained access to have been synced.
+        const THREAD_LIST_SYNC = 1 << 53;
+        /// Thread member of the current user has been updated.
+        const THREAD_MEMBER_UPDATE = 1 << 54;
+        /// Members have been added to or removed from a thread.
+        const THREAD_MEMBERS_UPDATE = 1 << 55;
+        /// A thread has been updated.
+        const THREAD_UPDATE = 1 << 56;
         /// User has begun typing in a channel.
         const TYPING_START = 1 << 39;
         /// Guild is unavailable, potentially due to an outage.
@@ -123,6 +161,12 @@ bitflags! {
 impl From<EventType> for EventTypeFlags {
     fn from(event_type: EventType) -> Self {
         match event_type {
+            EventType::AutoModerationActionExecution => {
+                EventTypeFlags::AUTO_MODERATION_ACTION_EXECUTION
+            }
+            EventType::AutoModerationRuleCreate => EventTypeFlags::AUTO_MODERATION_RULE_CREATE,
+            EventType::AutoModerationRuleDelete => EventTypeFlags::AUTO_MODERATION_RULE_DELETE,
+            EventType::AutoModerationRuleUpdate => EventTypeFlags::AUTO_MODERATION_RULE_UPDATE,
             EventType::BanAdd => EventTypeFlags::BAN_ADD,
             EventType::BanRemove => EventTypeFlags::BAN_REMOVE,
             EventType::ChannelCreate => EventTypeFlags::CHANNEL_CREATE,
@@ -139,7 +183,15 @@ impl From<EventType> for EventTypeFlags {
             EventType::GuildDelete => EventTypeFlags::GUILD_DELETE,
             EventType::GuildEmojisUpdate => EventTypeFlags::GUILD_EMOJIS_UPDATE,
             EventType::GuildIntegrationsUpdate => EventTypeFlags::GUILD_INTEGRATIONS_UPDATE,
+            EventType::GuildScheduledEventCreate => EventTypeFlags::GUILD_SCHEDULED_EVENT_CREATE,
+            EventType::GuildScheduledEventDelete => EventTypeFlags::GUILD_SCHEDULED_EVENT_DELETE,
+            EventType::GuildScheduledEventUpdate => EventTypeFlags::GUILD_SCHEDULED_EVENT_UPDATE,
+            EventType::GuildScheduledEventUserAdd => EventTypeFlags::GUILD_SCHEDULED_EVENT_USER_ADD,
+            EventType::GuildScheduledEventUserRemove => {
+                EventTypeFlags::GUILD_SCHEDULED_EVENT_USER_REMOVE
+            }
             EventType::GuildUpdate => EventTypeFlags::GUILD_UPDATE,
+            EventType::InteractionCreate => EventTypeFlags::INTERACTION_CREATE,
             EventType::InviteCreate => EventTypeFlags::INVITE_CREATE,
             EventType::InviteDelete => EventTypeFlags::INVITE_DELETE,
             EventType::MemberAdd => EventTypeFlags::MEMBER_ADD,
@@ -168,6 +220,15 @@ impl From<EventType> for EventTypeFlags {
             EventType::ShardReconnecting => EventTypeFlags::SHARD_RECONNECTING,
             EventType::ShardPayload => EventTypeFlags::SHARD_PAYLOAD,
             EventType::ShardResuming => EventTypeFlags::SHARD_RESUMING,
+            EventType::StageInstanceCreate => EventTypeFlags::STAGE_INSTANCE_CREATE,
+            EventType::StageInstanceDelete => EventTypeFlags::STAGE_INSTANCE_DELETE,
+            EventType::StageInstanceUpdate => EventTypeFlags::STAGE_INSTANCE_UPDATE,
+            EventType::ThreadCreate => EventTypeFlags::THREAD_CREATE,
+            EventType::ThreadDelete => EventTypeFlags::THREAD_DELETE,
+            EventType::ThreadListSync => EventTypeFlags::THREAD_LIST_SYNC,
+            EventType::ThreadMemberUpdate => EventTypeFlags::THREAD_MEMBER_UPDATE,
+            EventType::ThreadMembersUpdate => EventTypeFlags::THREAD_MEMBERS_UPDATE,
+            EventType::ThreadUpdate => EventTypeFlags::THREAD_UPDATE,
             EventType::TypingStart => EventTypeFlags::TYPING_START,
             EventType::UnavailableGuild => EventTypeFlags::UNAVAILABLE_GUILD,
             EventType::UserUpdate => EventTypeFlags::USER_UPDATE,
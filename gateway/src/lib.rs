@@ -17,6 +17,22 @@
 //! manages and proxies all of their events under one unified stream. This is
 //! useful to use if you have a large bot in over 1000 or 2000 guilds.
 //!
+//! The raw payloads received by shards can be received by subscribing to
+//! [`EventTypeFlags::SHARD_PAYLOAD`], such as to forward them to another
+//! process or to handle event types that aren't modelled yet. Their opcode,
+//! sequence, and event type are read without deserializing the rest of the
+//! payload. Dispatch events that no listener subscribed to aren't
+//! deserialized at all.
+//!
+//! The payloads received by shards can be recorded and replayed as events
+//! with the [`replay`] module, such as for load testing the cache or
+//! reproducing bugs in event handlers.
+//!
+//! Payloads are encoded as JSON by default. Shards can instead use Erlang's
+//! External Term Format (ETF), which is cheaper to decode, by configuring
+//! [`ShardBuilder::encoding`] or [`ClusterBuilder::encoding`] with
+//! [`Encoding::Etf`].
+//!
 //! ## Features
 //!
 //! ### Deserialization
@@ -77,11 +93,45 @@
 //!
 //! `stock-zlib` enabled by default.
 //!
+//! Transport compression can be disabled with
+//! [`ShardBuilder::compression`], in which case payloads are received
+//! uncompressed.
+//!
 //! Enabling **only** `simd-zlib` will make the library use [`zlib-ng`] which is a modern
 //! fork of zlib that is faster and more effective, but it needs `cmake` to compile.
 //!
+//! ## Tracing
+//!
+//! Each shard connects and processes events in a `shard` span at the info
+//! level, with the fields:
+//!
+//! - `shard_id`: ID of the shard;
+//! - `shard_total`: total number of shards used by the bot.
+//!
+//! Filtering on these, such as with the `shard{shard_id=3}` directive of
+//! `tracing-subscriber`'s `EnvFilter`, shows the logs of a single shard.
+//!
+//! While a payload is processed, these spans are entered within it at the
+//! trace level:
+//!
+//! - `msg`, while the payload is decompressed, and `clear`, while the buffer
+//!   is cleared afterwards;
+//! - `bytes`, while the payload is sent to listeners of shard payloads, with
+//!   the `op`, `seq`, and `event_type` fields;
+//! - `event`, while the deserialized event is sent to listeners, with the
+//!   `event` field;
+//! - `beginning to iterate over listeners`, with the `event_type` and
+//!   `listener_count` fields, and `sending to listener` within it for each
+//!   listener, with the `id` and `event_type` fields.
+//!
+//! [`ClusterBuilder::encoding`]: cluster/struct.ClusterBuilder.html#method.encoding
+//! [`Encoding::Etf`]: shard/enum.Encoding.html#variant.Etf
+//! [`EventTypeFlags::SHARD_PAYLOAD`]: struct.EventTypeFlags.html#associatedconstant.SHARD_PAYLOAD
+//! [`ShardBuilder::compression`]: shard/struct.ShardBuilder.html#method.compression
+//! [`ShardBuilder::encoding`]: shard/struct.ShardBuilder.html#method.encoding
 //! [`async-tungstenite`]: https://crates.io/crates/async-tungstenite
 //! [`native-tls`]: https://crates.io/crates/native-tls
+//! [`replay`]: replay/index.html
 //! [`rustls`]: https://crates.io/crates/rustls
 //! [`serde_json`]: https://crates.io/crates/serde_json
 //! [`simd-json`]: https://crates.io/crates/simd-json
@@ -109,6 +159,7 @@
 #![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
 
 pub mod cluster;
+pub mod replay;
 pub mod shard;
 
 mod event;
@@ -25,7 +25,11 @@ struct ListenersRef<T> {
     //
     // If listener 1 wants message creates and listener 2 wants message deletes,
     // then this will contain the bits of both.
-    event_types: AtomicU64,
+    //
+    // The flags don't fit in a single atomic, so the low and high halves are
+    // stored separately.
+    event_types_high: AtomicU64,
+    event_types_low: AtomicU64,
     id: AtomicU64,
     listeners: DashMap<u64, Listener<T>>,
 }
@@ -33,7 +37,8 @@ struct ListenersRef<T> {
 impl<T> Default for ListenersRef<T> {
     fn default() -> Self {
         Self {
-            event_types: AtomicU64::new(0),
+            event_types_high: AtomicU64::new(0),
+            event_types_low: AtomicU64::new(0),
             id: AtomicU64::new(0),
             listeners: DashMap::new(),
         }
@@ -63,7 +68,9 @@ impl<T> Listeners<T> {
     /// If listener 1 has requested message creates and listener 2 has requested
     /// message deletes, then this returns bitflags with both flipped on.
     pub fn event_types(&self) -> EventTypeFlags {
-        let bits = self.0.event_types.load(Ordering::SeqCst);
+        let high = self.0.event_types_high.load(Ordering::SeqCst);
+        let low = self.0.event_types_low.load(Ordering::SeqCst);
+        let bits = u128::from(high) << 64 | u128::from(low);
 
         EventTypeFlags::from_bits_truncate(bits)
     }
@@ -89,7 +96,15 @@ impl<T> Listeners<T> {
                 acc
             });
 
-        self.0.event_types.store(flags.bits(), Ordering::SeqCst);
+        let bits = flags.bits();
+
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            self.0
+                .event_types_high
+                .store((bits >> 64) as u64, Ordering::SeqCst);
+            self.0.event_types_low.store(bits as u64, Ordering::SeqCst);
+        }
     }
 }
 
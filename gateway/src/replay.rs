@@ -0,0 +1,478 @@
+//! Record the payloads received by shards and replay them as events.
+//!
+//! A [`Recorder`] writes raw gateway payloads to a file with the time they
+//! were received at, and a [`Replayer`] reads them back and deserializes them
+//! into the same [`Event`]s that a shard would have emitted. This allows
+//! cache and event handler logic to be load tested or debugged against a
+//! recorded session without connecting to Discord.
+//!
+//! Payloads are received from a shard by subscribing to
+//! [`EventTypeFlags::SHARD_PAYLOAD`].
+//!
+//! # Format
+//!
+//! Recordings are text files with one payload per line. Each line is the
+//! number of milliseconds since the recording started, a tab, and the JSON of
+//! the payload. Discord never sends payloads with raw newlines, so they don't
+//! need to be escaped.
+//!
+//! # Examples
+//!
+//! Record the payloads received by a shard to a file:
+//!
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use std::{env, fs::File, io::BufWriter};
+//! use twilight_gateway::{replay::Recorder, EventTypeFlags, Intents, Shard};
+//! use twilight_model::gateway::event::Event;
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut shard = Shard::new(env::var("DISCORD_TOKEN")?, Intents::GUILD_MESSAGES);
+//! let mut payloads = shard.some_events(EventTypeFlags::SHARD_PAYLOAD);
+//! shard.start().await?;
+//!
+//! let mut recorder = Recorder::new(BufWriter::new(File::create("session.txt")?));
+//!
+//! while let Some(event) = payloads.next().await {
+//!     if let Event::ShardPayload(payload) = event {
+//!         recorder.record(&payload.bytes)?;
+//!     }
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! Replay them into a cache, as fast as they can be read:
+//!
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use std::{fs::File, io::BufReader};
+//! use twilight_gateway::replay::Replayer;
+//!
+//! # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let replayer = Replayer::new(BufReader::new(File::open("session.txt")?));
+//! let mut events = Box::pin(replayer.events());
+//!
+//! while let Some(event) = events.next().await {
+//!     println!("{:?}", event?.kind());
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! [`Event`]: ../../twilight_model/gateway/event/enum.Event.html
+//! [`EventTypeFlags::SHARD_PAYLOAD`]: ../struct.EventTypeFlags.html#associatedconstant.SHARD_PAYLOAD
+//! [`Recorder`]: struct.Recorder.html
+//! [`Replayer`]: struct.Replayer.html
+
+use crate::shard::json::{self, GatewayEventParsingErrorType};
+use futures_util::stream::{self, Stream};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{BufRead, Error as IoError, ErrorKind as IoErrorKind, Result as IoResult, Write},
+    time::{Duration, Instant},
+};
+use twilight_model::gateway::event::{Event, GatewayEventDeserializerOwned};
+
+/// Replaying a recording failed.
+#[derive(Debug)]
+pub struct ReplayError {
+    kind: ReplayErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ReplayError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ReplayErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ReplayErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ReplayErrorType::Deserializing { line, .. } => f.write_fmt(format_args!(
+                "the payload on line {} is not a valid event",
+                line
+            )),
+            ReplayErrorType::LineInvalid { line } => f.write_fmt(format_args!(
+                "line {} is not a timestamp and a payload separated by a tab",
+                line
+            )),
+            ReplayErrorType::PayloadInvalid { line } => {
+                f.write_fmt(format_args!("the payload on line {} has no opcode", line))
+            }
+            ReplayErrorType::Reading { .. } => f.write_str("reading from the recording failed"),
+        }
+    }
+}
+
+impl Error for ReplayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ReplayError`] that occurred.
+///
+/// [`ReplayError`]: struct.ReplayError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ReplayErrorType {
+    /// A payload couldn't be deserialized into an event.
+    Deserializing {
+        /// Number of the line of the payload, starting at 1.
+        line: usize,
+    },
+    /// A line isn't a timestamp and a payload separated by a tab.
+    LineInvalid {
+        /// Number of the line, starting at 1.
+        line: usize,
+    },
+    /// A payload doesn't have an opcode, so it isn't a gateway payload.
+    PayloadInvalid {
+        /// Number of the line of the payload, starting at 1.
+        line: usize,
+    },
+    /// Reading from the recording failed.
+    Reading,
+}
+
+/// Recorder of gateway payloads with the time they were received at.
+///
+/// Timestamps are relative to when the recorder was created. Refer to the
+/// [module-level] documentation for more information.
+///
+/// [module-level]: index.html
+#[derive(Debug)]
+pub struct Recorder<W> {
+    started: Instant,
+    writer: W,
+}
+
+impl<W: Write> Recorder<W> {
+    /// Create a recorder writing to a writer.
+    ///
+    /// The writer should be buffered, since each payload is written with
+    /// multiple calls.
+    pub fn new(writer: W) -> Self {
+        Self {
+            started: Instant::now(),
+            writer,
+        }
+    }
+
+    /// Record a payload, received now.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`] if the payload contains a
+    /// newline, which can't be recorded.
+    ///
+    /// Returns any error from writing to the writer.
+    ///
+    /// [`InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    pub fn record(&mut self, payload: &[u8]) -> IoResult<()> {
+        let elapsed = u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        self.record_at(elapsed, payload)
+    }
+
+    /// Record a payload, received a number of milliseconds after the
+    /// recording started.
+    ///
+    /// This can be used to create recordings with deterministic timestamps,
+    /// such as for tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`record`].
+    ///
+    /// [`record`]: #method.record
+    pub fn record_at(&mut self, millis: u64, payload: &[u8]) -> IoResult<()> {
+        if payload.contains(&b'\n') {
+            return Err(IoError::new(
+                IoErrorKind::InvalidInput,
+                "payload contains a newline",
+            ));
+        }
+
+        self.writer.write_fmt(format_args!("{}\t", millis))?;
+        self.writer.write_all(payload)?;
+        self.writer.write_all(b"\n")
+    }
+
+    /// Flush the writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from flushing the writer.
+    pub fn flush(&mut self) -> IoResult<()> {
+        self.writer.flush()
+    }
+
+    /// Consume the recorder, returning the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Replayer of recorded gateway payloads as a stream of events.
+///
+/// Refer to the [module-level] documentation for more information.
+///
+/// [module-level]: index.html
+#[derive(Debug)]
+#[must_use = "replayers do nothing unless their events are streamed"]
+pub struct Replayer<R> {
+    reader: R,
+    realtime: bool,
+}
+
+impl<R: BufRead> Replayer<R> {
+    /// Create a replayer reading a recording from a reader.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            realtime: false,
+        }
+    }
+
+    /// Set whether to wait between events for as long as there was between
+    /// their payloads when they were recorded.
+    ///
+    /// The default is `false`, replaying events as fast as they're read.
+    pub fn realtime(mut self, realtime: bool) -> Self {
+        self.realtime = realtime;
+
+        self
+    }
+
+    /// Stream the events of the recording.
+    ///
+    /// The stream ends after the last payload, or after the first error.
+    /// Reading from the reader blocks, so the reader should usually be an
+    /// in-memory buffer or a buffered file.
+    pub fn events(self) -> impl Stream<Item = Result<Event, ReplayError>> {
+        let state = ReplayState {
+            failed: false,
+            line: 0,
+            previous: None,
+            realtime: self.realtime,
+            reader: self.reader,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            if state.failed {
+                return None;
+            }
+
+            let (millis, payload) = match state.next_line() {
+                Ok(Some(line)) => line,
+                Ok(None) => return None,
+                Err(source) => {
+                    state.failed = true;
+
+                    return Some((Err(source), state));
+                }
+            };
+
+            if state.realtime {
+                let previous = state.previous.unwrap_or(millis);
+
+                if let Some(wait) = millis.checked_sub(previous) {
+                    tokio::time::delay_for(Duration::from_millis(wait)).await;
+                }
+            }
+
+            state.previous = Some(millis);
+
+            let event = parse(state.line, payload);
+            state.failed = event.is_err();
+
+            Some((event, state))
+        })
+    }
+}
+
+struct ReplayState<R> {
+    failed: bool,
+    line: usize,
+    previous: Option<u64>,
+    realtime: bool,
+    reader: R,
+}
+
+impl<R: BufRead> ReplayState<R> {
+    /// Read the next non-empty line, returning its timestamp and payload.
+    fn next_line(&mut self) -> Result<Option<(u64, String)>, ReplayError> {
+        let mut buf = String::new();
+
+        loop {
+            buf.clear();
+
+            if self
+                .reader
+                .read_line(&mut buf)
+                .map_err(|source| ReplayError {
+                    kind: ReplayErrorType::Reading,
+                    source: Some(Box::new(source)),
+                })?
+                == 0
+            {
+                return Ok(None);
+            }
+
+            self.line += 1;
+
+            let len = buf.trim_end_matches(&['\r', '\n'][..]).len();
+
+            if len > 0 {
+                buf.truncate(len);
+
+                break;
+            }
+        }
+
+        let line = self.line;
+        let mut parts = buf.splitn(2, '\t');
+
+        match (parts.next().map(str::parse), parts.next()) {
+            (Some(Ok(millis)), Some(payload)) => Ok(Some((millis, payload.to_owned()))),
+            _ => Err(ReplayError {
+                kind: ReplayErrorType::LineInvalid { line },
+                source: None,
+            }),
+        }
+    }
+}
+
+/// Parse a recorded payload into an event.
+fn parse(line: usize, mut payload: String) -> Result<Event, ReplayError> {
+    let (op, sequence, event_type) = GatewayEventDeserializerOwned::from_json(&payload)
+        .ok_or(ReplayError {
+            kind: ReplayErrorType::PayloadInvalid { line },
+            source: None,
+        })?
+        .into_parts();
+
+    json::parse_gateway_event(op, sequence, event_type.as_deref(), &mut payload)
+        .map(Event::from)
+        .map_err(|source| {
+            let (kind, source) = source.into_parts();
+
+            match kind {
+                GatewayEventParsingErrorType::Deserializing => ReplayError {
+                    kind: ReplayErrorType::Deserializing { line },
+                    source,
+                },
+                GatewayEventParsingErrorType::DeserializingEtf
+                | GatewayEventParsingErrorType::PayloadInvalid => ReplayError {
+                    kind: ReplayErrorType::PayloadInvalid { line },
+                    source: None,
+                },
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Recorder, ReplayError, ReplayErrorType, Replayer};
+    use futures::StreamExt;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug, io::Cursor};
+    use twilight_model::{gateway::event::Event, id::MessageId};
+
+    assert_fields!(ReplayErrorType::Deserializing: line);
+    assert_fields!(ReplayErrorType::LineInvalid: line);
+    assert_fields!(ReplayErrorType::PayloadInvalid: line);
+    assert_impl_all!(ReplayErrorType: Debug, Send, Sync);
+    assert_impl_all!(ReplayError: Debug, Error, Send, Sync);
+    assert_impl_all!(Recorder<Vec<u8>>: Debug, Send, Sync);
+    assert_impl_all!(Replayer<Cursor<Vec<u8>>>: Debug, Send, Sync);
+
+    const HEARTBEAT_ACK: &[u8] = br#"{"op":11,"d":null}"#;
+    const MESSAGE_DELETE: &[u8] =
+        br#"{"op":0,"s":2,"t":"MESSAGE_DELETE","d":{"channel_id":"1","id":"2"}}"#;
+
+    async fn replay(recording: &str) -> Vec<Result<Event, ReplayError>> {
+        let replayer = Replayer::new(Cursor::new(recording.as_bytes().to_vec()));
+
+        replayer.events().collect().await
+    }
+
+    #[test]
+    fn test_record() {
+        let mut recorder = Recorder::new(Vec::new());
+        recorder.record_at(0, HEARTBEAT_ACK).unwrap();
+        recorder.record_at(1500, MESSAGE_DELETE).unwrap();
+
+        assert!(recorder.record(b"{\n}").is_err());
+        assert_eq!(
+            format!(
+                "0\t{}\n1500\t{}\n",
+                String::from_utf8_lossy(HEARTBEAT_ACK),
+                String::from_utf8_lossy(MESSAGE_DELETE),
+            ),
+            String::from_utf8(recorder.into_inner()).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_replay() {
+        let mut recorder = Recorder::new(Vec::new());
+        recorder.record_at(0, HEARTBEAT_ACK).unwrap();
+        recorder.record_at(10, MESSAGE_DELETE).unwrap();
+
+        let recording = String::from_utf8(recorder.into_inner()).unwrap();
+        let events = replay(&format!("\n{}\n", recording)).await;
+
+        assert_eq!(2, events.len());
+        assert!(matches!(events[0], Ok(Event::GatewayHeartbeatAck)));
+        assert!(matches!(
+            &events[1],
+            Ok(Event::MessageDelete(delete)) if delete.id == MessageId(2)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_replay_invalid() {
+        let events = replay("0\t{\"op\":11,\"d\":null}\nnot a line\n0\t{\"op\":11}\n").await;
+
+        assert_eq!(2, events.len());
+        assert!(events[0].is_ok());
+        assert!(matches!(
+            events[1].as_ref().unwrap_err().kind(),
+            ReplayErrorType::LineInvalid { line: 2 }
+        ));
+
+        let events = replay("0\t{\"d\":null}\n").await;
+        assert!(matches!(
+            events[0].as_ref().unwrap_err().kind(),
+            ReplayErrorType::PayloadInvalid { line: 1 }
+        ));
+
+        let events = replay("0\t{\"op\":0,\"s\":1,\"t\":\"MESSAGE_DELETE\",\"d\":{}}\n").await;
+        let error = events[0].as_ref().unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ReplayErrorType::Deserializing { line: 1 }
+        ));
+        assert!(error.source().is_some());
+    }
+}
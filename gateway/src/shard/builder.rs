@@ -1,4 +1,5 @@
-use super::{config::Config, Shard};
+use super::{config::Config, Encoding, Reconnect, ResumeSession, Shard};
+use crate::EventTypeFlags;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -14,7 +15,63 @@ use twilight_model::gateway::{payload::update_status::UpdateStatusInfo, Intents}
 ///
 /// [`ShardBuilder::large_threshold`]: struct.ShardBuilder.html#method.large_threshold
 #[derive(Debug)]
-pub enum LargeThresholdError {
+pub struct LargeThresholdError {
+    kind: LargeThresholdErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl LargeThresholdError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &LargeThresholdErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        LargeThresholdErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for LargeThresholdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            LargeThresholdErrorType::TooFew { .. } => {
+                f.write_str("provided large threshold value is fewer than 50")
+            }
+            LargeThresholdErrorType::TooMany { .. } => {
+                f.write_str("provided large threshold value is more than 250")
+            }
+        }
+    }
+}
+
+impl Error for LargeThresholdError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`LargeThresholdError`] that occurred.
+///
+/// [`LargeThresholdError`]: struct.LargeThresholdError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LargeThresholdErrorType {
     /// Provided large threshold value is too few in number.
     TooFew {
         /// Provided value.
@@ -27,37 +84,41 @@ pub enum LargeThresholdError {
     },
 }
 
-impl Display for LargeThresholdError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::TooFew { .. } => f.write_str("provided large threshold value is fewer than 50"),
-            Self::TooMany { .. } => f.write_str("provided large threshold value is more than 250"),
-        }
-    }
-}
-
-impl Error for LargeThresholdError {}
-
 /// Shard ID configuration is invalid.
 ///
 /// Returned by [`ShardBuilder::shard`].
 ///
 /// [`ShardBuilder::shard`]: struct.ShardBuilder.html#method.shard
 #[derive(Debug)]
-pub enum ShardIdError {
-    /// Provided shard ID is higher than provided total shard count.
-    IdTooLarge {
-        /// Shard ID.
-        id: u64,
-        /// Total shard count.
-        total: u64,
-    },
+pub struct ShardIdError {
+    kind: ShardIdErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ShardIdError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ShardIdErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ShardIdErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for ShardIdError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::IdTooLarge { id, total } => f.write_fmt(format_args!(
+        match &self.kind {
+            ShardIdErrorType::IdTooLarge { id, total } => f.write_fmt(format_args!(
                 "provided shard ID {} is larger than the total {}",
                 id, total,
             )),
@@ -65,7 +126,28 @@ impl Display for ShardIdError {
     }
 }
 
-impl Error for ShardIdError {}
+impl Error for ShardIdError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ShardIdError`] that occurred.
+///
+/// [`ShardIdError`]: struct.ShardIdError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ShardIdErrorType {
+    /// Provided shard ID is higher than provided total shard count.
+    IdTooLarge {
+        /// Shard ID.
+        id: u64,
+        /// Total shard count.
+        total: u64,
+    },
+}
 
 /// Builder to configure and construct a shard.
 ///
@@ -111,12 +193,16 @@ impl ShardBuilder {
         }
 
         Self(Config {
+            compression: true,
+            encoding: Encoding::default(),
+            event_types: EventTypeFlags::all(),
             gateway_url: None,
             http_client: HttpClient::new(token.clone()),
             intents,
             large_threshold: 250,
             presence: None,
             queue: Arc::new(Box::new(LocalQueue::new())),
+            reconnect: Reconnect::default(),
             shard: [0, 1],
             token,
             session_id: None,
@@ -129,6 +215,73 @@ impl ShardBuilder {
         Shard::new_with_config(self.0)
     }
 
+    /// Set whether payloads are compressed with zlib by the gateway.
+    ///
+    /// Compressed payloads are decompressed incrementally as they're received,
+    /// which uses less bandwidth at the cost of some CPU time and the memory
+    /// of the decompression buffers. Statistics about the buffers are available
+    /// through [`Information::compression`].
+    ///
+    /// Default is true.
+    ///
+    /// [`Information::compression`]: struct.Information.html#method.compression
+    pub fn compression(mut self, compression: bool) -> Self {
+        self.0.compression = compression;
+
+        self
+    }
+
+    /// Set the encoding of the payloads sent to and received from the gateway.
+    ///
+    /// Default is [`Encoding::Json`].
+    ///
+    /// [`Encoding::Json`]: enum.Encoding.html#variant.Json
+    pub fn encoding(mut self, encoding: Encoding) -> Self {
+        self.0.encoding = encoding;
+
+        self
+    }
+
+    /// Set the event types that the shard emits.
+    ///
+    /// Dispatch events of other types aren't deserialized, even if a stream
+    /// returned by [`Shard::some_events`] or [`Shard::events`] would receive
+    /// them. This is useful to skip deserializing high-volume events that
+    /// aren't used, such as [`EventTypeFlags::PRESENCE_UPDATE`] or
+    /// [`EventTypeFlags::TYPING_START`].
+    ///
+    /// The events needed by the shard to maintain its session are always
+    /// processed.
+    ///
+    /// Default is [`EventTypeFlags::all`].
+    ///
+    /// # Examples
+    ///
+    /// Skip deserializing presence updates and typing starts:
+    ///
+    /// ```rust,no_run
+    /// use twilight_gateway::{EventTypeFlags, Intents, Shard};
+    ///
+    /// let event_types = EventTypeFlags::all()
+    ///     - EventTypeFlags::PRESENCE_UPDATE
+    ///     - EventTypeFlags::TYPING_START;
+    ///
+    /// let shard = Shard::builder("token", Intents::GUILD_MESSAGES)
+    ///     .event_types(event_types)
+    ///     .build();
+    /// ```
+    ///
+    /// [`EventTypeFlags::all`]: ../struct.EventTypeFlags.html#method.all
+    /// [`EventTypeFlags::PRESENCE_UPDATE`]: ../struct.EventTypeFlags.html#associatedconstant.PRESENCE_UPDATE
+    /// [`EventTypeFlags::TYPING_START`]: ../struct.EventTypeFlags.html#associatedconstant.TYPING_START
+    /// [`Shard::events`]: struct.Shard.html#method.events
+    /// [`Shard::some_events`]: struct.Shard.html#method.some_events
+    pub fn event_types(mut self, event_types: EventTypeFlags) -> Self {
+        self.0.event_types = event_types;
+
+        self
+    }
+
     /// Set the URL used for connecting to Discord's gateway
     pub fn gateway_url(mut self, gateway_url: Option<String>) -> Self {
         self.0.gateway_url = gateway_url;
@@ -159,25 +312,31 @@ impl ShardBuilder {
     ///
     /// # Errors
     ///
-    /// Returns [`LargeThresholdError::TooFew`] if the provided value is below
+    /// Returns [`LargeThresholdErrorType::TooFew`] if the provided value is below
     /// 50.
     ///
-    /// Returns [`LargeThresholdError::TooMany`] if the provided value is above
+    /// Returns [`LargeThresholdErrorType::TooMany`] if the provided value is above
     /// 250.
     ///
-    /// [`LargeThresholdError::TooFew`]: enum.LargeThresholdError.html#variant.TooFew
-    /// [`LargeThresholdError::TooMany`]: enum.LargeThresholdError.html#variant.TooMany
+    /// [`LargeThresholdErrorType::TooFew`]: enum.LargeThresholdErrorType.html#variant.TooFew
+    /// [`LargeThresholdErrorType::TooMany`]: enum.LargeThresholdErrorType.html#variant.TooMany
     pub fn large_threshold(mut self, large_threshold: u64) -> Result<Self, LargeThresholdError> {
         match large_threshold {
             0..=49 => {
-                return Err(LargeThresholdError::TooFew {
-                    value: large_threshold,
+                return Err(LargeThresholdError {
+                    kind: LargeThresholdErrorType::TooFew {
+                        value: large_threshold,
+                    },
+                    source: None,
                 })
             }
             50..=250 => {}
             251..=u64::MAX => {
-                return Err(LargeThresholdError::TooMany {
-                    value: large_threshold,
+                return Err(LargeThresholdError {
+                    kind: LargeThresholdErrorType::TooMany {
+                        value: large_threshold,
+                    },
+                    source: None,
                 })
             }
         }
@@ -214,6 +373,36 @@ impl ShardBuilder {
         self
     }
 
+    /// Set how the shard reconnects when its session can't be resumed.
+    ///
+    /// Refer to [`Reconnect`] for the default value.
+    ///
+    /// [`Reconnect`]: struct.Reconnect.html
+    pub fn reconnect(mut self, reconnect: Reconnect) -> Self {
+        self.0.reconnect = reconnect;
+
+        self
+    }
+
+    /// Set the session to resume when starting the shard.
+    ///
+    /// The session can be retrieved from a running shard via
+    /// [`Shard::resume_session`] or when shutting it down via
+    /// [`Shard::shutdown_resumable`], such as to persist it across process
+    /// restarts. The shard will then resume the session instead of
+    /// identifying a new one, replaying the events that were missed.
+    ///
+    /// If the session is invalid, then the shard will identify a new one.
+    ///
+    /// [`Shard::resume_session`]: struct.Shard.html#method.resume_session
+    /// [`Shard::shutdown_resumable`]: struct.Shard.html#method.shutdown_resumable
+    pub fn resume_session(mut self, resume_session: ResumeSession) -> Self {
+        self.0.session_id = Some(resume_session.session_id);
+        self.0.sequence = Some(resume_session.sequence);
+
+        self
+    }
+
     /// Set the shard ID to connect as, and the total number of shards used by
     /// the bot.
     ///
@@ -244,15 +433,18 @@ impl ShardBuilder {
     ///
     /// # Errors
     ///
-    /// Returns [`ShardIdError::IdTooLarge`] if the shard ID to connect as is
+    /// Returns [`ShardIdErrorType::IdTooLarge`] if the shard ID to connect as is
     /// larger than the total.
     ///
-    /// [`ShardIdError::IdTooLarge`]: enum.ShardIdError.html#variant.IdTooLarge
+    /// [`ShardIdErrorType::IdTooLarge`]: enum.ShardIdErrorType.html#variant.IdTooLarge
     pub fn shard(mut self, shard_id: u64, shard_total: u64) -> Result<Self, ShardIdError> {
         if shard_id >= shard_total {
-            return Err(ShardIdError::IdTooLarge {
-                id: shard_id,
-                total: shard_total,
+            return Err(ShardIdError {
+                kind: ShardIdErrorType::IdTooLarge {
+                    id: shard_id,
+                    total: shard_total,
+                },
+                source: None,
             });
         }
 
@@ -270,13 +462,17 @@ impl<T: Into<String>> From<(T, Intents)> for ShardBuilder {
 
 #[cfg(test)]
 mod tests {
-    use super::{LargeThresholdError, ShardBuilder, ShardIdError};
+    use super::{
+        LargeThresholdError, LargeThresholdErrorType, ResumeSession, ShardBuilder, ShardIdError,
+        ShardIdErrorType,
+    };
     use crate::Intents;
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{error::Error, fmt::Debug};
 
-    assert_fields!(LargeThresholdError::TooFew: value);
-    assert_fields!(LargeThresholdError::TooMany: value);
+    assert_fields!(LargeThresholdErrorType::TooFew: value);
+    assert_fields!(LargeThresholdErrorType::TooMany: value);
+    assert_impl_all!(LargeThresholdErrorType: Debug, Send, Sync);
     assert_impl_all!(LargeThresholdError: Debug, Error, Send, Sync);
     assert_impl_all!(
         ShardBuilder: Clone,
@@ -285,6 +481,18 @@ mod tests {
         Send,
         Sync
     );
-    assert_fields!(ShardIdError::IdTooLarge: id, total);
+    assert_fields!(ShardIdErrorType::IdTooLarge: id, total);
+    assert_impl_all!(ShardIdErrorType: Debug, Send, Sync);
     assert_impl_all!(ShardIdError: Debug, Error, Send, Sync);
+
+    #[tokio::test]
+    async fn test_resume_session() {
+        let builder = ShardBuilder::new("token", Intents::empty()).resume_session(ResumeSession {
+            session_id: "abc".to_owned(),
+            sequence: 10,
+        });
+
+        assert_eq!(Some("abc"), builder.0.session_id.as_deref());
+        assert_eq!(Some(10), builder.0.sequence);
+    }
 }
@@ -1,3 +1,5 @@
+use super::{Encoding, Reconnect};
+use crate::EventTypeFlags;
 use std::sync::Arc;
 use twilight_gateway_queue::Queue;
 use twilight_http::Client;
@@ -11,12 +13,16 @@ use twilight_model::gateway::{payload::update_status::UpdateStatusInfo, Intents}
 /// [`Shard::builder`]: struct.Shard.html#method.builder
 #[derive(Clone, Debug)]
 pub struct Config {
+    pub(crate) compression: bool,
+    pub(crate) encoding: Encoding,
+    pub(crate) event_types: EventTypeFlags,
     pub(crate) gateway_url: Option<String>,
     pub(crate) http_client: Client,
     pub(super) intents: Intents,
     pub(super) large_threshold: u64,
     pub(super) presence: Option<UpdateStatusInfo>,
-    pub(super) queue: Arc<Box<dyn Queue>>,
+    pub(crate) queue: Arc<Box<dyn Queue>>,
+    pub(crate) reconnect: Reconnect,
     pub(crate) shard: [u64; 2],
     pub(super) token: String,
     pub(crate) session_id: Option<String>,
@@ -24,6 +30,26 @@ pub struct Config {
 }
 
 impl Config {
+    /// Return whether payloads are compressed with zlib by the gateway.
+    pub fn compression(&self) -> bool {
+        self.compression
+    }
+
+    /// Return the encoding of the payloads sent to and received from the
+    /// gateway.
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Return a copy of the event types that the shard emits.
+    ///
+    /// Refer to [`ShardBuilder::event_types`] for the default value.
+    ///
+    /// [`ShardBuilder::event_types`]: struct.ShardBuilder.html#method.event_types
+    pub fn event_types(&self) -> EventTypeFlags {
+        self.event_types
+    }
+
     /// Return an immutable reference to the url used to connect to the gateway.
     pub fn gateway_url(&self) -> Option<&str> {
         self.gateway_url.as_deref()
@@ -60,6 +86,12 @@ impl Config {
         self.shard
     }
 
+    /// Return an immutable reference to how the shard reconnects when its
+    /// session can't be resumed.
+    pub fn reconnect(&self) -> &Reconnect {
+        &self.reconnect
+    }
+
     /// Return an immutable reference to the token used to authenticate with
     /// when identifying with the gateway.
     pub fn token(&self) -> &str {
@@ -0,0 +1,62 @@
+/// Encoding of the payloads sent to and received from the gateway.
+///
+/// Discord's gateway encodes payloads as either JSON or in the Erlang
+/// External Term Format (ETF). ETF payloads are binary and are generally
+/// smaller and faster to decode than JSON, which can matter for bots that
+/// receive a large number of events.
+///
+/// Raw payloads, such as the bytes of [`Event::ShardPayload`] events or the
+/// payloads passed to [`Shard::command_raw`], are in the configured encoding.
+///
+/// [`Event::ShardPayload`]: ../../twilight_model/gateway/event/enum.Event.html#variant.ShardPayload
+/// [`Shard::command_raw`]: struct.Shard.html#method.command_raw
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Encoding {
+    /// Erlang External Term Format.
+    Etf,
+    /// JavaScript Object Notation.
+    Json,
+}
+
+impl Encoding {
+    /// Name of the encoding as used in the URL to connect to the gateway.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Etf => "etf",
+            Self::Json => "json",
+        }
+    }
+}
+
+impl Default for Encoding {
+    fn default() -> Self {
+        Self::Json
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Encoding;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        Encoding: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_name() {
+        assert_eq!("etf", Encoding::Etf.name());
+        assert_eq!("json", Encoding::Json.name());
+        assert_eq!(Encoding::Json, Encoding::default());
+    }
+}
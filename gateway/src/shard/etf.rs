@@ -0,0 +1,701 @@
+//! Decoding and encoding of gateway payloads in the Erlang External Term
+//! Format.
+//!
+//! Payloads are deserialized directly from the received bytes, borrowing
+//! strings from them where possible. Outgoing payloads are small and rare in
+//! comparison, so they're converted to a JSON value before being encoded.
+
+use super::json::{GatewayEventParsingError, GatewayEventParsingErrorType};
+use serde::{
+    de::{
+        self,
+        value::{BorrowedStrDeserializer, SeqDeserializer},
+        DeserializeSeed, IgnoredAny, MapAccess, SeqAccess, Visitor,
+    },
+    forward_to_deserialize_any, Deserialize, Serialize,
+};
+use serde_json::{Map, Number, Value};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str,
+};
+use twilight_model::gateway::event::{GatewayEvent, GatewayEventDeserializer};
+
+/// Version byte that every encoded term starts with.
+const VERSION: u8 = 131;
+
+const NEW_FLOAT_EXT: u8 = 70;
+const SMALL_INTEGER_EXT: u8 = 97;
+const INTEGER_EXT: u8 = 98;
+const FLOAT_EXT: u8 = 99;
+const ATOM_EXT: u8 = 100;
+const SMALL_TUPLE_EXT: u8 = 104;
+const LARGE_TUPLE_EXT: u8 = 105;
+const NIL_EXT: u8 = 106;
+const STRING_EXT: u8 = 107;
+const LIST_EXT: u8 = 108;
+const BINARY_EXT: u8 = 109;
+const SMALL_BIG_EXT: u8 = 110;
+const LARGE_BIG_EXT: u8 = 111;
+const SMALL_ATOM_EXT: u8 = 115;
+const MAP_EXT: u8 = 116;
+const ATOM_UTF8_EXT: u8 = 118;
+const SMALL_ATOM_UTF8_EXT: u8 = 119;
+
+#[derive(Debug)]
+pub struct EtfError {
+    kind: EtfErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl EtfError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &EtfErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (EtfErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn new(kind: EtfErrorType) -> Self {
+        Self { kind, source: None }
+    }
+}
+
+impl Display for EtfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            EtfErrorType::Deserializing { message } => f.write_str(message),
+            EtfErrorType::Eof => f.write_str("payload ended before the term was complete"),
+            EtfErrorType::IntegerTooLarge => f.write_str("integer doesn't fit into 64 bits"),
+            EtfErrorType::ListImproper => f.write_str("list has a tail that isn't an empty list"),
+            EtfErrorType::Serializing { .. } => f.write_str("serializing the value failed"),
+            EtfErrorType::TagUnsupported { tag } => write!(f, "term tag {} is unsupported", tag),
+            EtfErrorType::TextInvalid => f.write_str("text of the term is invalid"),
+            EtfErrorType::VersionInvalid { version } => write!(f, "version {} is invalid", version),
+        }
+    }
+}
+
+impl Error for EtfError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`EtfError`] that occurred.
+///
+/// [`EtfError`]: struct.EtfError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EtfErrorType {
+    /// Deserializing a value failed.
+    Deserializing {
+        /// Message describing the failure.
+        message: String,
+    },
+    /// Payload ended before a term was complete.
+    Eof,
+    /// Integer doesn't fit into 64 bits.
+    IntegerTooLarge,
+    /// List has a tail other than an empty list.
+    ListImproper,
+    /// Serializing a value to be encoded failed.
+    Serializing,
+    /// Term has a tag that isn't supported.
+    TagUnsupported {
+        /// Tag of the term.
+        tag: u8,
+    },
+    /// Text of an atom or float isn't valid.
+    TextInvalid,
+    /// Payload doesn't start with the version byte.
+    VersionInvalid {
+        /// First byte of the payload.
+        version: u8,
+    },
+}
+
+impl de::Error for EtfError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::new(EtfErrorType::Deserializing {
+            message: msg.to_string(),
+        })
+    }
+}
+
+/// Deserializer of a term from a payload.
+pub struct Deserializer<'de> {
+    input: &'de [u8],
+}
+
+impl<'de> Deserializer<'de> {
+    /// Create a deserializer for a payload, checking its version byte.
+    pub fn from_slice(input: &'de [u8]) -> Result<Self, EtfError> {
+        match input.split_first() {
+            Some((&VERSION, input)) => Ok(Self { input }),
+            Some((&version, _)) => Err(EtfError::new(EtfErrorType::VersionInvalid { version })),
+            None => Err(EtfError::new(EtfErrorType::Eof)),
+        }
+    }
+
+    fn peek(&self) -> Result<u8, EtfError> {
+        self.input
+            .first()
+            .copied()
+            .ok_or_else(|| EtfError::new(EtfErrorType::Eof))
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'de [u8], EtfError> {
+        if self.input.len() < len {
+            return Err(EtfError::new(EtfErrorType::Eof));
+        }
+
+        let (taken, rest) = self.input.split_at(len);
+        self.input = rest;
+
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> Result<u8, EtfError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, EtfError> {
+        let bytes = self.take(2)?;
+
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, EtfError> {
+        let bytes = self.take(4)?;
+
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn len(&mut self) -> Result<usize, EtfError> {
+        usize::try_from(self.u32()?).map_err(|_| EtfError::new(EtfErrorType::Eof))
+    }
+
+    fn text(&mut self, len: usize) -> Result<&'de str, EtfError> {
+        str::from_utf8(self.take(len)?).map_err(|_| EtfError::new(EtfErrorType::TextInvalid))
+    }
+
+    /// Read the text of an atom, if the next term is one.
+    fn atom(&mut self) -> Result<Option<&'de str>, EtfError> {
+        let len = match self.peek()? {
+            ATOM_EXT | ATOM_UTF8_EXT => {
+                self.u8()?;
+
+                usize::from(self.u16()?)
+            }
+            SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => {
+                self.u8()?;
+
+                usize::from(self.u8()?)
+            }
+            _ => return Ok(None),
+        };
+
+        self.text(len).map(Some)
+    }
+
+    /// Read the magnitude and sign of a big integer with a number of bytes.
+    fn big(&mut self, len: usize) -> Result<(u64, bool), EtfError> {
+        let negative = self.u8()? != 0;
+        let digits = self.take(len)?;
+
+        if digits.iter().skip(8).any(|digit| *digit != 0) {
+            return Err(EtfError::new(EtfErrorType::IntegerTooLarge));
+        }
+
+        let magnitude = digits
+            .iter()
+            .take(8)
+            .rev()
+            .fold(0, |magnitude, digit| magnitude << 8 | u64::from(*digit));
+
+        Ok((magnitude, negative))
+    }
+
+    /// Skip the list tail after the elements of a list.
+    fn list_tail(&mut self) -> Result<(), EtfError> {
+        if self.u8()? == NIL_EXT {
+            Ok(())
+        } else {
+            Err(EtfError::new(EtfErrorType::ListImproper))
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = EtfError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if let Some(atom) = self.atom()? {
+            return match atom {
+                "nil" => visitor.visit_unit(),
+                "true" => visitor.visit_bool(true),
+                "false" => visitor.visit_bool(false),
+                atom => visitor.visit_borrowed_str(atom),
+            };
+        }
+
+        match self.u8()? {
+            SMALL_INTEGER_EXT => visitor.visit_u64(u64::from(self.u8()?)),
+            INTEGER_EXT => {
+                #[allow(clippy::cast_possible_wrap)]
+                let value = self.u32()? as i32;
+
+                match u64::try_from(value) {
+                    Ok(value) => visitor.visit_u64(value),
+                    Err(_) => visitor.visit_i64(i64::from(value)),
+                }
+            }
+            NEW_FLOAT_EXT => {
+                let bytes = self.take(8)?;
+                let mut array = [0; 8];
+                array.copy_from_slice(bytes);
+
+                visitor.visit_f64(f64::from_be_bytes(array))
+            }
+            FLOAT_EXT => {
+                let text = self.text(31)?.trim_end_matches('\0');
+
+                visitor.visit_f64(
+                    text.parse()
+                        .map_err(|_| EtfError::new(EtfErrorType::TextInvalid))?,
+                )
+            }
+            tag @ (SMALL_BIG_EXT | LARGE_BIG_EXT) => {
+                let len = if tag == SMALL_BIG_EXT {
+                    usize::from(self.u8()?)
+                } else {
+                    self.len()?
+                };
+
+                match self.big(len)? {
+                    (magnitude, false) => visitor.visit_u64(magnitude),
+                    (magnitude, true) => {
+                        let value = i64::try_from(-i128::from(magnitude))
+                            .map_err(|_| EtfError::new(EtfErrorType::IntegerTooLarge))?;
+
+                        visitor.visit_i64(value)
+                    }
+                }
+            }
+            BINARY_EXT => {
+                let len = self.len()?;
+                let bytes = self.take(len)?;
+
+                match str::from_utf8(bytes) {
+                    Ok(text) => visitor.visit_borrowed_str(text),
+                    Err(_) => visitor.visit_borrowed_bytes(bytes),
+                }
+            }
+            STRING_EXT => {
+                let len = usize::from(self.u16()?);
+                let bytes = self.take(len)?;
+
+                visitor.visit_seq(SeqDeserializer::new(bytes.iter().copied()))
+            }
+            NIL_EXT => visitor.visit_seq(Elements {
+                de: self,
+                remaining: 0,
+            }),
+            tag @ (LIST_EXT | SMALL_TUPLE_EXT | LARGE_TUPLE_EXT) => {
+                let len = if tag == SMALL_TUPLE_EXT {
+                    usize::from(self.u8()?)
+                } else {
+                    self.len()?
+                };
+
+                let mut elements = Elements {
+                    de: &mut *self,
+                    remaining: len,
+                };
+                let value = visitor.visit_seq(&mut elements)?;
+
+                while elements.remaining > 0 {
+                    elements.next_element::<IgnoredAny>()?;
+                }
+
+                if tag == LIST_EXT {
+                    self.list_tail()?;
+                }
+
+                Ok(value)
+            }
+            MAP_EXT => {
+                let len = self.len()?;
+
+                let mut entries = Entries {
+                    de: &mut *self,
+                    remaining: len,
+                };
+                let value = visitor.visit_map(&mut entries)?;
+
+                while entries.remaining > 0 {
+                    entries.next_entry::<IgnoredAny, IgnoredAny>()?;
+                }
+
+                Ok(value)
+            }
+            tag => Err(EtfError::new(EtfErrorType::TagUnsupported { tag })),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let input = self.input;
+
+        if self.atom()? == Some("nil") {
+            return visitor.visit_none();
+        }
+
+        self.input = input;
+
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let text = match self.atom()? {
+            Some(atom) => atom,
+            None if self.peek()? == BINARY_EXT => {
+                self.u8()?;
+                let len = self.len()?;
+
+                self.text(len)?
+            }
+            None => return self.deserialize_any(visitor),
+        };
+
+        visitor.visit_enum(BorrowedStrDeserializer::new(text))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// Elements of a list or tuple.
+struct Elements<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de> SeqAccess<'de> for Elements<'_, 'de> {
+    type Error = EtfError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// Entries of a map.
+struct Entries<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de> MapAccess<'de> for Entries<'_, 'de> {
+    type Error = EtfError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// Opcode, sequence, and event type of a payload.
+#[derive(Deserialize)]
+struct Header<'a> {
+    op: u8,
+    s: Option<u64>,
+    #[serde(borrow)]
+    t: Option<&'a str>,
+}
+
+/// Deserialize a value from a payload.
+pub fn from_slice<'de, T: Deserialize<'de>>(input: &'de [u8]) -> Result<T, EtfError> {
+    T::deserialize(&mut Deserializer::from_slice(input)?)
+}
+
+/// Read the opcode, sequence, and event type of a payload.
+///
+/// Returns `None` if the payload isn't a map with an opcode.
+pub fn header(input: &[u8]) -> Option<(u8, Option<u64>, Option<&str>)> {
+    let header = from_slice::<Header<'_>>(input).ok()?;
+
+    Some((header.op, header.s, header.t))
+}
+
+/// Parse a gateway event from a payload with its headers.
+///
+/// # Errors
+///
+/// Returns [`GatewayEventParsingErrorType::DeserializingEtf`] if the payload
+/// failed to deserialize.
+///
+/// [`GatewayEventParsingErrorType::DeserializingEtf`]: ../json/enum.GatewayEventParsingErrorType.html#variant.DeserializingEtf
+pub fn parse_gateway_event(
+    op: u8,
+    sequence: Option<u64>,
+    event_type: Option<&str>,
+    input: &[u8],
+) -> Result<GatewayEvent, GatewayEventParsingError> {
+    let gateway_deserializer = GatewayEventDeserializer::new(op, sequence, event_type);
+
+    Deserializer::from_slice(input)
+        .and_then(|mut deserializer| gateway_deserializer.deserialize(&mut deserializer))
+        .map_err(|source| {
+            tracing::debug!("invalid ETF: {:?}", input);
+
+            GatewayEventParsingError {
+                kind: GatewayEventParsingErrorType::DeserializingEtf,
+                source: Some(Box::new(source)),
+            }
+        })
+}
+
+/// Encode a value as a payload.
+pub fn to_vec(value: &impl Serialize) -> Result<Vec<u8>, EtfError> {
+    let value = serde_json::to_value(value).map_err(|source| EtfError {
+        kind: EtfErrorType::Serializing,
+        source: Some(Box::new(source)),
+    })?;
+
+    let mut output = vec![VERSION];
+    encode(&value, &mut output);
+
+    Ok(output)
+}
+
+fn encode(value: &Value, output: &mut Vec<u8>) {
+    match value {
+        Value::Null => encode_atom("nil", output),
+        Value::Bool(true) => encode_atom("true", output),
+        Value::Bool(false) => encode_atom("false", output),
+        Value::Number(number) => encode_number(number, output),
+        Value::String(text) => encode_binary(text, output),
+        Value::Array(elements) => {
+            if !elements.is_empty() {
+                output.push(LIST_EXT);
+                output.extend_from_slice(&encode_len(elements.len()));
+
+                for element in elements {
+                    encode(element, output);
+                }
+            }
+
+            output.push(NIL_EXT);
+        }
+        Value::Object(entries) => encode_map(entries, output),
+    }
+}
+
+fn encode_atom(atom: &str, output: &mut Vec<u8>) {
+    output.push(SMALL_ATOM_UTF8_EXT);
+    // Only the atoms of constants are encoded, which are all short.
+    #[allow(clippy::cast_possible_truncation)]
+    output.push(atom.len() as u8);
+    output.extend_from_slice(atom.as_bytes());
+}
+
+fn encode_binary(text: &str, output: &mut Vec<u8>) {
+    output.push(BINARY_EXT);
+    output.extend_from_slice(&encode_len(text.len()));
+    output.extend_from_slice(text.as_bytes());
+}
+
+fn encode_len(len: usize) -> [u8; 4] {
+    u32::try_from(len).unwrap_or(u32::MAX).to_be_bytes()
+}
+
+fn encode_map(entries: &Map<String, Value>, output: &mut Vec<u8>) {
+    output.push(MAP_EXT);
+    output.extend_from_slice(&encode_len(entries.len()));
+
+    for (key, value) in entries {
+        encode_binary(key, output);
+        encode(value, output);
+    }
+}
+
+fn encode_number(number: &Number, output: &mut Vec<u8>) {
+    if let Some(value) = number.as_u64() {
+        if let Ok(value) = u8::try_from(value) {
+            output.push(SMALL_INTEGER_EXT);
+            output.push(value);
+        } else if let Ok(value) = i32::try_from(value) {
+            output.push(INTEGER_EXT);
+            output.extend_from_slice(&value.to_be_bytes());
+        } else {
+            encode_big(value, false, output);
+        }
+    } else if let Some(value) = number.as_i64() {
+        if let Ok(value) = i32::try_from(value) {
+            output.push(INTEGER_EXT);
+            output.extend_from_slice(&value.to_be_bytes());
+        } else {
+            encode_big(value.unsigned_abs(), true, output);
+        }
+    } else if let Some(value) = number.as_f64() {
+        output.push(NEW_FLOAT_EXT);
+        output.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+fn encode_big(magnitude: u64, negative: bool, output: &mut Vec<u8>) {
+    let digits = magnitude.to_le_bytes();
+    let len = digits
+        .iter()
+        .rposition(|digit| *digit != 0)
+        .map_or(0, |idx| idx + 1);
+
+    output.push(SMALL_BIG_EXT);
+    // There are at most 8 digits.
+    #[allow(clippy::cast_possible_truncation)]
+    output.push(len as u8);
+    output.push(u8::from(negative));
+    output.extend_from_slice(&digits[..len]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EtfError, EtfErrorType, MAP_EXT, VERSION};
+    use serde_json::{json, Value};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        gateway::event::GatewayEvent,
+        id::{GuildId, RoleId},
+    };
+
+    assert_impl_all!(EtfErrorType: Debug, Send, Sync);
+    assert_impl_all!(EtfError: Debug, Error, Send, Sync);
+
+    #[test]
+    fn test_round_trip() {
+        let value = json!({
+            "array": [1, -2, 300, -70_000, 5_000_000_000_u64, -5_000_000_000_i64],
+            "bool": true,
+            "empty": [],
+            "float": 1.5,
+            "null": null,
+            "object": { "text": "hello" },
+            "snowflake": 18_446_744_073_709_551_615_u64,
+        });
+
+        let bytes = super::to_vec(&value).unwrap();
+        assert_eq!(&[VERSION, MAP_EXT], &bytes[..2]);
+        assert_eq!(value, super::from_slice::<Value>(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_parse_gateway_event() {
+        let bytes = super::to_vec(&json!({
+            "d": { "guild_id": 1, "role_id": "2" },
+            "op": 0,
+            "s": 7,
+            "t": "GUILD_ROLE_DELETE",
+        }))
+        .unwrap();
+
+        let (op, seq, event_type) = super::header(&bytes).unwrap();
+        assert_eq!(
+            (0, Some(7), Some("GUILD_ROLE_DELETE")),
+            (op, seq, event_type)
+        );
+
+        let event = super::parse_gateway_event(op, seq, event_type, &bytes).unwrap();
+        assert!(matches!(
+            event,
+            GatewayEvent::Dispatch(7, event)
+                if matches!(
+                    &*event,
+                    twilight_model::gateway::event::DispatchEvent::RoleDelete(delete)
+                        if delete.guild_id == GuildId(1) && delete.role_id == RoleId(2)
+                )
+        ));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            super::from_slice::<Value>(&[]).unwrap_err().kind(),
+            EtfErrorType::Eof
+        ));
+        assert!(matches!(
+            super::from_slice::<Value>(&[1]).unwrap_err().kind(),
+            EtfErrorType::VersionInvalid { version: 1 }
+        ));
+        assert!(matches!(
+            super::from_slice::<Value>(&[VERSION, 1])
+                .unwrap_err()
+                .kind(),
+            EtfErrorType::TagUnsupported { tag: 1 }
+        ));
+        assert!(super::header(&[VERSION, super::NIL_EXT]).is_none());
+    }
+}
@@ -1,23 +1,27 @@
 use super::{
     builder::ShardBuilder,
     config::Config,
+    etf,
     event::Events,
     json,
-    processor::{ConnectingError, Latency, Session, ShardProcessor},
+    processor::{
+        Compression, ConnectingError, ConnectingErrorType, Latency, Session, ShardProcessor,
+    },
     sink::ShardSink,
     stage::Stage,
+    Encoding,
 };
 use crate::{listener::Listeners, EventTypeFlags, Intents};
 use async_tungstenite::tungstenite::{
     protocol::{frame::coding::CloseCode, CloseFrame},
-    Error as TungsteniteError, Message,
+    Message,
 };
-use futures_channel::mpsc::TrySendError;
 use futures_util::{
     future::{self, AbortHandle},
     stream::StreamExt,
 };
 use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
     error::Error,
@@ -25,53 +29,73 @@ use std::{
     sync::{atomic::Ordering, Arc},
 };
 use tokio::sync::watch::Receiver as WatchReceiver;
-use twilight_http::Error as HttpError;
+use tracing::Instrument;
 use twilight_model::gateway::event::Event;
-use url::ParseError as UrlParseError;
-
-#[cfg(not(feature = "simd-json"))]
-use serde_json::Error as JsonError;
-#[cfg(feature = "simd-json")]
-use simd_json::Error as JsonError;
 
 /// Sending a command failed.
 #[derive(Debug)]
-#[non_exhaustive]
-pub enum CommandError {
-    /// Sending the payload over the WebSocket failed. This is indicative of a
-    /// shutdown shard.
-    Sending {
-        /// Reason for the error.
-        source: TrySendError<Message>,
-    },
-    /// Serializing the payload as JSON failed.
-    Serializing {
-        /// Reason for the error.
-        source: JsonError,
-    },
-    /// Shard's session is inactive because the shard hasn't been started.
-    SessionInactive {
-        /// Reason for the error.
-        source: SessionInactiveError,
-    },
+pub struct CommandError {
+    kind: CommandErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CommandError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CommandErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CommandErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for CommandError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.write_str("the shard session is inactive and has not been started")
+        match &self.kind {
+            CommandErrorType::Sending => {
+                f.write_str("sending the message over the websocket failed")
+            }
+            CommandErrorType::Serializing => f.write_str("serializing the value as json failed"),
+            CommandErrorType::SessionInactive => {
+                f.write_str("the shard session is inactive and has not been started")
+            }
+        }
     }
 }
 
 impl Error for CommandError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Self::Sending { source } => Some(source),
-            Self::Serializing { source } => Some(source),
-            Self::SessionInactive { source } => Some(source),
-        }
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
     }
 }
 
+/// Type of [`CommandError`] that occurred.
+///
+/// [`CommandError`]: struct.CommandError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CommandErrorType {
+    /// Sending the payload over the WebSocket failed. This is indicative of a
+    /// shutdown shard.
+    Sending,
+    /// Serializing the payload as JSON failed.
+    Serializing,
+    /// Shard's session is inactive because the shard hasn't been started.
+    SessionInactive,
+}
+
 /// Shard's session is inactive.
 ///
 /// This means that the shard has not yet been started.
@@ -89,37 +113,41 @@ impl Error for SessionInactiveError {}
 
 /// Starting a shard and connecting to the gateway failed.
 #[derive(Debug)]
-#[non_exhaustive]
-pub enum ShardStartError {
-    /// Establishing a connection to the gateway failed.
-    Establishing {
-        /// Reason for the error.
-        source: TungsteniteError,
-    },
-    /// Parsing the gateway URL provided by Discord to connect to the gateway
-    /// failed due to an invalid URL.
-    ParsingGatewayUrl {
-        /// Reason for the error.
-        source: UrlParseError,
-        /// URL that couldn't be parsed.
-        url: String,
-    },
-    /// Retrieving the gateway URL via the Twilight HTTP client failed.
-    RetrievingGatewayUrl {
-        /// The reason for the error.
-        source: HttpError,
-    },
+pub struct ShardStartError {
+    kind: ShardStartErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ShardStartError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ShardStartErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ShardStartErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for ShardStartError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::Establishing { source } => Display::fmt(source, f),
-            Self::ParsingGatewayUrl { source, url } => f.write_fmt(format_args!(
-                "the gateway url `{}` is invalid: {}",
-                url, source,
-            )),
-            Self::RetrievingGatewayUrl { .. } => {
+        match &self.kind {
+            ShardStartErrorType::Establishing => {
+                f.write_str("establishing a connection to the gateway failed")
+            }
+            ShardStartErrorType::ParsingGatewayUrl { url } => {
+                f.write_fmt(format_args!("the gateway url `{}` is invalid", url))
+            }
+            ShardStartErrorType::RetrievingGatewayUrl => {
                 f.write_str("retrieving the gateway URL via HTTP failed")
             }
         }
@@ -128,34 +156,69 @@ impl Display for ShardStartError {
 
 impl Error for ShardStartError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Self::Establishing { source } => Some(source),
-            Self::ParsingGatewayUrl { source, .. } => Some(source),
-            Self::RetrievingGatewayUrl { source } => Some(source),
-        }
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
     }
 }
 
 impl From<ConnectingError> for ShardStartError {
     fn from(error: ConnectingError) -> Self {
-        match error {
-            ConnectingError::Establishing { source } => Self::Establishing { source },
-            ConnectingError::ParsingUrl { source, url } => Self::ParsingGatewayUrl { source, url },
+        let (kind, source) = error.into_parts();
+
+        match kind {
+            ConnectingErrorType::Establishing => Self {
+                kind: ShardStartErrorType::Establishing,
+                source,
+            },
+            ConnectingErrorType::ParsingUrl { url } => Self {
+                kind: ShardStartErrorType::ParsingGatewayUrl { url },
+                source,
+            },
         }
     }
 }
 
-/// Information about a shard, including its latency, current session sequence,
-/// and connection stage.
+/// Type of [`ShardStartError`] that occurred.
+///
+/// [`ShardStartError`]: struct.ShardStartError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ShardStartErrorType {
+    /// Establishing a connection to the gateway failed.
+    Establishing,
+    /// Parsing the gateway URL provided by Discord to connect to the gateway
+    /// failed due to an invalid URL.
+    ParsingGatewayUrl {
+        /// URL that couldn't be parsed.
+        url: String,
+    },
+    /// Retrieving the gateway URL via the Twilight HTTP client failed.
+    RetrievingGatewayUrl,
+}
+
+/// Information about a shard, including its latency, current session ID and
+/// sequence, and connection stage.
 #[derive(Clone, Debug)]
 pub struct Information {
+    compression: Option<Compression>,
     id: u64,
     latency: Latency,
     seq: u64,
+    session_id: Option<String>,
     stage: Stage,
 }
 
 impl Information {
+    /// Return an immutable reference to statistics about the decompression
+    /// of payloads.
+    ///
+    /// This is `None` if transport compression is disabled or no payload has
+    /// been received yet.
+    pub fn compression(&self) -> Option<&Compression> {
+        self.compression.as_ref()
+    }
+
     /// Return the ID of the shard.
     pub fn id(&self) -> u64 {
         self.id
@@ -165,6 +228,11 @@ impl Information {
     ///
     /// This includes the average latency over all time, and the latency
     /// information for the 5 most recent heartbeats.
+    ///
+    /// Refer to [`Latency::last`] for the latency of the most recent
+    /// heartbeat, such as for a `ping` command.
+    ///
+    /// [`Latency::last`]: struct.Latency.html#method.last
     pub fn latency(&self) -> &Latency {
         &self.latency
     }
@@ -180,6 +248,14 @@ impl Information {
         self.seq
     }
 
+    /// ID of the current session.
+    ///
+    /// This is `None` if the shard hasn't received a `Ready` event for the
+    /// session yet.
+    pub fn session_id(&self) -> Option<&str> {
+        self.session_id.as_deref()
+    }
+
     /// Current stage of the shard.
     ///
     /// For example, once a shard is fully booted then it will be [`Connected`].
@@ -190,7 +266,14 @@ impl Information {
     }
 }
 /// Details to resume a gateway session.
-#[derive(Clone, Debug)]
+///
+/// This can be serialized to persist it across process restarts, and then
+/// passed to [`ShardBuilder::resume_session`] or
+/// [`ClusterBuilder::resume_sessions`].
+///
+/// [`ClusterBuilder::resume_sessions`]: ../cluster/struct.ClusterBuilder.html#method.resume_sessions
+/// [`ShardBuilder::resume_session`]: struct.ShardBuilder.html#method.resume_session
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ResumeSession {
     /// ID of the session being resumed.
     pub session_id: String,
@@ -322,18 +405,18 @@ impl Shard {
     ///
     /// # Errors
     ///
-    /// Returns [`ShardStartError::Establishing`] if establishing a connection
+    /// Returns [`ShardStartErrorType::Establishing`] if establishing a connection
     /// to the gateway failed.
     ///
-    /// Returns [`ShardStartError::ParsingGatewayUrl`] if the gateway URL
+    /// Returns [`ShardStartErrorType::ParsingGatewayUrl`] if the gateway URL
     /// couldn't be parsed.
     ///
-    /// Returns [`ShardStartError::RetrievingGatewayUrl`] if the gateway URL
+    /// Returns [`ShardStartErrorType::RetrievingGatewayUrl`] if the gateway URL
     /// couldn't be retrieved from the HTTP API.
     ///
-    /// [`ShardStartError::Establishing`]: enum.ShardStartError.html#variant.Establishing
-    /// [`ShardStartError::ParsingGatewayUrl`]: enum.ShardStartError.html#variant.ParsingGatewayUrl
-    /// [`ShardStartError::RetrievingGatewayUrl`]: enum.ShardStartError.html#variant.RetrievingGatewayUrl
+    /// [`ShardStartErrorType::Establishing`]: enum.ShardStartErrorType.html#variant.Establishing
+    /// [`ShardStartErrorType::ParsingGatewayUrl`]: enum.ShardStartErrorType.html#variant.ParsingGatewayUrl
+    /// [`ShardStartErrorType::RetrievingGatewayUrl`]: enum.ShardStartErrorType.html#variant.RetrievingGatewayUrl
     pub async fn start(&mut self) -> Result<(), ShardStartError> {
         let url = if let Some(u) = self.0.config.gateway_url.clone() {
             u
@@ -344,16 +427,23 @@ impl Shard {
                 .gateway()
                 .authed()
                 .await
-                .map_err(|source| ShardStartError::RetrievingGatewayUrl { source })?
+                .map_err(|source| ShardStartError {
+                    kind: ShardStartErrorType::RetrievingGatewayUrl,
+                    source: Some(Box::new(source)),
+                })?
                 .url
         };
 
+        let [shard_id, shard_total] = self.0.config.shard();
+        let span = tracing::info_span!("shard", shard_id, shard_total);
+
         let config = Arc::clone(&self.0.config);
         let listeners = self.0.listeners.clone();
         let (processor, wrx) = ShardProcessor::new(config, url, listeners)
+            .instrument(span.clone())
             .await
             .map_err(ShardStartError::from)?;
-        let (fut, handle) = future::abortable(processor.run());
+        let (fut, handle) = future::abortable(processor.run().instrument(span));
 
         tokio::spawn(async move {
             let _ = fut.await;
@@ -446,11 +536,18 @@ impl Shard {
     /// [`SessionInactiveError`]: struct.SessionInactiveError.html
     pub fn info(&self) -> Result<Information, SessionInactiveError> {
         let session = self.session()?;
+        let compression = session
+            .compression
+            .lock()
+            .expect("compression poisoned")
+            .clone();
 
         Ok(Information {
+            compression,
             id: self.config().shard()[0],
             latency: session.heartbeats.latency(),
             seq: session.seq(),
+            session_id: session.id(),
             stage: session.stage(),
         })
     }
@@ -478,46 +575,61 @@ impl Shard {
     ///
     /// # Errors
     ///
-    /// Returns [`CommandError::Sending`] if the message could not be sent
+    /// Returns [`CommandErrorType::Sending`] if the message could not be sent
     /// over the websocket. This indicates the shard is currently restarting.
     ///
-    /// Returns [`CommandError::Serializing`] if the provided value failed to
-    /// serialize into JSON.
+    /// Returns [`CommandErrorType::Serializing`] if the provided value failed to
+    /// serialize into the configured [`Encoding`].
     ///
-    /// Returns [`CommandError::SessionInactive`] if the shard has not been
+    /// Returns [`CommandErrorType::SessionInactive`] if the shard has not been
     /// started.
     ///
-    /// [`CommandError::Sending`]: enum.CommandError.html#variant.Sending
-    /// [`CommandError::Serializing`]: enum.CommandError.html#variant.Serializing
-    /// [`CommandError::SessionInactive`]: enum.CommandError.html#variant.SessionInactive
+    /// [`CommandErrorType::Sending`]: enum.CommandErrorType.html#variant.Sending
+    /// [`CommandErrorType::Serializing`]: enum.CommandErrorType.html#variant.Serializing
+    /// [`CommandErrorType::SessionInactive`]: enum.CommandErrorType.html#variant.SessionInactive
+    /// [`Encoding`]: enum.Encoding.html
     pub async fn command(&self, value: &impl serde::Serialize) -> Result<(), CommandError> {
-        let json = json::to_vec(value).map_err(|source| CommandError::Serializing { source })?;
-        self.command_raw(json).await
+        let bytes = match self.0.config.encoding() {
+            Encoding::Etf => etf::to_vec(value).map_err(|source| CommandError {
+                kind: CommandErrorType::Serializing,
+                source: Some(Box::new(source)),
+            })?,
+            Encoding::Json => json::to_vec(value).map_err(|source| CommandError {
+                kind: CommandErrorType::Serializing,
+                source: Some(Box::new(source)),
+            })?,
+        };
+
+        self.command_raw(bytes).await
     }
 
     /// Send a raw command over the gateway.
     ///
+    /// The payload must be in the configured [`Encoding`].
+    ///
     /// This method should be used with caution, [`command`] should be preferred.
     ///
     /// # Errors
     ///
-    /// Returns [`CommandError::Sending`] if the message could not be sent
+    /// Returns [`CommandErrorType::Sending`] if the message could not be sent
     /// over the websocket. This indicates the shard is currently restarting.
     ///
-    /// Returns [`CommandError::Serializing`] if the provided value failed to
+    /// Returns [`CommandErrorType::Serializing`] if the provided value failed to
     /// serialize into JSON.
     ///
-    /// Returns [`CommandError::SessionInactive`] if the shard has not been
+    /// Returns [`CommandErrorType::SessionInactive`] if the shard has not been
     /// started.
     ///
     /// [`command`]: #method.command
-    /// [`CommandError::Sending`]: enum.CommandError.html#variant.Sending
-    /// [`CommandError::Serializing`]: enum.CommandError.html#variant.Serializing
-    /// [`CommandError::SessionInactive`]: enum.CommandError.html#variant.SessionInactive
+    /// [`Encoding`]: enum.Encoding.html
+    /// [`CommandErrorType::Sending`]: enum.CommandErrorType.html#variant.Sending
+    /// [`CommandErrorType::Serializing`]: enum.CommandErrorType.html#variant.Serializing
+    /// [`CommandErrorType::SessionInactive`]: enum.CommandErrorType.html#variant.SessionInactive
     pub async fn command_raw(&self, value: Vec<u8>) -> Result<(), CommandError> {
-        let session = self
-            .session()
-            .map_err(|source| CommandError::SessionInactive { source })?;
+        let session = self.session().map_err(|source| CommandError {
+            kind: CommandErrorType::SessionInactive,
+            source: Some(Box::new(source)),
+        })?;
         let message = Message::Binary(value);
 
         // Tick ratelimiter.
@@ -526,7 +638,10 @@ impl Shard {
         session
             .tx
             .unbounded_send(message)
-            .map_err(|source| CommandError::Sending { source })
+            .map_err(|source| CommandError {
+                kind: CommandErrorType::Sending,
+                source: Some(Box::new(source)),
+            })
     }
 
     /// Shut down the shard.
@@ -556,9 +671,11 @@ impl Shard {
     /// The shard will cleanly close the connection by sending a restart close
     /// code, causing Discord to keep the bot as showing online. The connection
     /// will be resumable by using the provided session resume information
-    /// to [`ClusterBuilder::resume_sessions`].
+    /// to [`ClusterBuilder::resume_sessions`] or
+    /// [`ShardBuilder::resume_session`].
     ///
     /// [`ClusterBuilder::resume_sessions`]: ../cluster/struct.ClusterBuilder.html#method.resume_sessions
+    /// [`ShardBuilder::resume_session`]: struct.ShardBuilder.html#method.resume_session
     pub fn shutdown_resumable(&self) -> (u64, Option<ResumeSession>) {
         self.0.listeners.remove_all();
 
@@ -578,19 +695,41 @@ impl Shard {
             reason: Cow::from("Closing in a resumable way"),
         }));
 
-        let session_id = session.id();
-        let sequence = session.seq.load(Ordering::Relaxed);
+        let data = Self::resume_session_of(&session);
 
         session.stop_heartbeater();
 
-        let data = session_id.map(|id| ResumeSession {
-            session_id: id,
-            sequence,
-        });
-
         (shard_id, data)
     }
 
+    /// Return the details to resume the current session.
+    ///
+    /// Returns `None` if the shard hasn't identified a session yet. The
+    /// sequence increases as events are received, so the details should be
+    /// retrieved as late as possible, such as when shutting down via
+    /// [`shutdown_resumable`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SessionInactiveError`] if the shard's session is inactive.
+    ///
+    /// [`SessionInactiveError`]: struct.SessionInactiveError.html
+    /// [`shutdown_resumable`]: #method.shutdown_resumable
+    pub fn resume_session(&self) -> Result<Option<ResumeSession>, SessionInactiveError> {
+        let session = self.session()?;
+
+        Ok(Self::resume_session_of(&session))
+    }
+
+    fn resume_session_of(session: &Session) -> Option<ResumeSession> {
+        let sequence = session.seq.load(Ordering::Relaxed);
+
+        session.id().map(|session_id| ResumeSession {
+            session_id,
+            sequence,
+        })
+    }
+
     /// Return a handle to the current session.
     ///
     /// # Errors
@@ -608,18 +747,28 @@ impl Shard {
 #[cfg(test)]
 mod tests {
     use super::{
-        CommandError, ConnectingError, Information, ResumeSession, SessionInactiveError, Shard,
-        ShardStartError,
+        CommandError, CommandErrorType, ConnectingError, ConnectingErrorType, Information,
+        ResumeSession, SessionInactiveError, Shard, ShardStartError, ShardStartErrorType,
     };
+    use serde::{Deserialize, Serialize};
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::{error::Error, fmt::Debug};
+    use std::{error::Error, fmt::Debug, hash::Hash};
 
-    assert_fields!(CommandError::Sending: source);
-    assert_fields!(CommandError::Serializing: source);
-    assert_fields!(CommandError::SessionInactive: source);
+    assert_impl_all!(CommandErrorType: Debug, Send, Sync);
     assert_impl_all!(CommandError: Debug, Error, Send, Sync);
     assert_impl_all!(Information: Clone, Debug, Send, Sync);
-    assert_impl_all!(ResumeSession: Clone, Debug, Send, Sync);
+    assert_fields!(ResumeSession: session_id, sequence);
+    assert_impl_all!(
+        ResumeSession: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
     assert_impl_all!(
         SessionInactiveError: Clone,
         Debug,
@@ -629,9 +778,8 @@ mod tests {
         Send,
         Sync
     );
-    assert_fields!(ShardStartError::Establishing: source);
-    assert_fields!(ShardStartError::ParsingGatewayUrl: source, url);
-    assert_fields!(ShardStartError::RetrievingGatewayUrl: source);
+    assert_fields!(ShardStartErrorType::ParsingGatewayUrl: url);
+    assert_impl_all!(ShardStartErrorType: Debug, Send, Sync);
     assert_impl_all!(
         ShardStartError: Debug,
         Error,
@@ -640,4 +788,23 @@ mod tests {
         Sync
     );
     assert_impl_all!(Shard: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_shard_start_error_from_connecting() {
+        let source = url::Url::parse("not a url").unwrap_err();
+        let error = ShardStartError::from(ConnectingError {
+            kind: ConnectingErrorType::ParsingUrl {
+                url: "not a url".to_owned(),
+            },
+            source: Some(Box::new(source)),
+        });
+        assert!(matches!(
+            error.kind(),
+            ShardStartErrorType::ParsingGatewayUrl { url } if url == "not a url"
+        ));
+        assert_eq!("the gateway url `not a url` is invalid", error.to_string());
+
+        let (_, source) = error.into_parts();
+        assert!(source.unwrap().downcast::<url::ParseError>().is_ok());
+    }
 }
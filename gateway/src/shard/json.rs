@@ -1,7 +1,7 @@
 #[cfg(not(feature = "simd-json"))]
-pub use serde_json::{from_slice, from_str, to_string, to_vec, Error as JsonError};
+pub use serde_json::{from_slice, to_vec};
 #[cfg(feature = "simd-json")]
-pub use simd_json::{from_slice, from_str, to_string, to_vec, Error as JsonError};
+pub use simd_json::{from_slice, to_vec};
 
 use std::{
     error::Error,
@@ -10,50 +10,78 @@ use std::{
 use twilight_model::gateway::event::GatewayEvent;
 
 #[derive(Debug)]
-pub enum GatewayEventParsingError {
-    /// Deserializing the GatewayEvent payload from JSON failed.
-    Deserializing {
-        /// Reason for the error.
-        source: JsonError,
-    },
-    /// The payload received from Discord was an unrecognized or invalid
-    /// structure.
-    ///
-    /// The payload was either invalid JSON or did not contain the necessary
-    /// "op" key in the object.
-    PayloadInvalid,
+pub struct GatewayEventParsingError {
+    pub(crate) kind: GatewayEventParsingErrorType,
+    pub(crate) source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl GatewayEventParsingError {
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        GatewayEventParsingErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for GatewayEventParsingError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::Deserializing { source } => Display::fmt(source, f),
-            Self::PayloadInvalid => f.write_str("payload is an invalid json structure"),
+        match &self.kind {
+            GatewayEventParsingErrorType::Deserializing => {
+                f.write_str("deserializing the payload failed")
+            }
+            GatewayEventParsingErrorType::DeserializingEtf => {
+                f.write_str("deserializing the payload as etf failed")
+            }
+            GatewayEventParsingErrorType::PayloadInvalid => {
+                f.write_str("payload is an invalid json structure")
+            }
         }
     }
 }
 
 impl Error for GatewayEventParsingError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Self::Deserializing { source } => Some(source),
-            Self::PayloadInvalid => None,
-        }
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
     }
 }
 
+/// Type of [`GatewayEventParsingError`] that occurred.
+///
+/// [`GatewayEventParsingError`]: struct.GatewayEventParsingError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GatewayEventParsingErrorType {
+    /// Deserializing the GatewayEvent payload from JSON failed.
+    Deserializing,
+    /// Deserializing the GatewayEvent payload from ETF failed.
+    DeserializingEtf,
+    /// The payload received from Discord was an unrecognized or invalid
+    /// structure.
+    ///
+    /// The payload was either invalid JSON or did not contain the necessary
+    /// "op" key in the object.
+    PayloadInvalid,
+}
+
 /// Parse a gateway event from a string using `serde_json` with headers.
 ///
 /// # Errors
 ///
-/// Returns [`GatewayEventParsingError::PayloadInvalid`] if the payload wasn't a valid
+/// Returns [`GatewayEventParsingErrorType::PayloadInvalid`] if the payload wasn't a valid
 /// `GatewayEvent` data structure.
 ///
-/// Returns [`GatewayEventParsingError::Deserializing`] if the payload failed to
+/// Returns [`GatewayEventParsingErrorType::Deserializing`] if the payload failed to
 /// deserialize.
 ///
-/// [`GatewayEventParsingError::PayloadInvalid`]: enum.GatewayEventParsingError.html#variant.PayloadInvalid
-/// [`GatewayEventParsingError::Deserializing`]: enum.GatewayEventParsingError.html#variant.Deserializing
+/// [`GatewayEventParsingErrorType::PayloadInvalid`]: enum.GatewayEventParsingErrorType.html#variant.PayloadInvalid
+/// [`GatewayEventParsingErrorType::Deserializing`]: enum.GatewayEventParsingErrorType.html#variant.Deserializing
 #[cfg(not(feature = "simd-json"))]
 #[allow(dead_code)]
 pub fn parse_gateway_event(
@@ -74,7 +102,10 @@ pub fn parse_gateway_event(
         .map_err(|source| {
             tracing::debug!("invalid JSON: {}", json);
 
-            GatewayEventParsingError::Deserializing { source }
+            GatewayEventParsingError {
+                kind: GatewayEventParsingErrorType::Deserializing,
+                source: Some(Box::new(source)),
+            }
         })
 }
 
@@ -82,14 +113,14 @@ pub fn parse_gateway_event(
 ///
 /// # Errors
 ///
-/// Returns [`GatewayEventParsingError::PayloadInvalid`] if the payload wasn't a valid
+/// Returns [`GatewayEventParsingErrorType::PayloadInvalid`] if the payload wasn't a valid
 /// `GatewayEvent` data structure.
 ///
-/// Returns [`GatewayEventParsingError::Deserializing`] if the payload failed to
+/// Returns [`GatewayEventParsingErrorType::Deserializing`] if the payload failed to
 /// deserialize.
 ///
-/// [`GatewayEventParsingError::PayloadInvalid`]: enum.GatewayEventParsingError.html#variant.PayloadInvalid
-/// [`GatewayEventParsingError::Deserializing`]: enum.GatewayEventParsingError.html#variant.Deserializing
+/// [`GatewayEventParsingErrorType::PayloadInvalid`]: enum.GatewayEventParsingErrorType.html#variant.PayloadInvalid
+/// [`GatewayEventParsingErrorType::Deserializing`]: enum.GatewayEventParsingErrorType.html#variant.Deserializing
 #[allow(unsafe_code)]
 #[cfg(feature = "simd-json")]
 #[allow(dead_code)]
@@ -111,24 +142,30 @@ pub fn parse_gateway_event(
     // UTF-8 valid, but that's fine because it won't be used again.
     let json_bytes = unsafe { json.as_bytes_mut() };
 
-    let mut json_deserializer = Deserializer::from_slice(json_bytes)
-        .map_err(|_| GatewayEventParsingError::PayloadInvalid)?;
+    let mut json_deserializer =
+        Deserializer::from_slice(json_bytes).map_err(|_| GatewayEventParsingError {
+            kind: GatewayEventParsingErrorType::PayloadInvalid,
+            source: None,
+        })?;
 
     gateway_deserializer
         .deserialize(&mut json_deserializer)
         .map_err(|source| {
             tracing::debug!("invalid JSON: {}", json);
 
-            GatewayEventParsingError::Deserializing { source }
+            GatewayEventParsingError {
+                kind: GatewayEventParsingErrorType::Deserializing,
+                source: Some(Box::new(source)),
+            }
         })
 }
 
 #[cfg(test)]
 mod tests {
-    use super::GatewayEventParsingError;
-    use static_assertions::{assert_fields, assert_impl_all};
+    use super::{GatewayEventParsingError, GatewayEventParsingErrorType};
+    use static_assertions::assert_impl_all;
     use std::{error::Error, fmt::Debug};
 
-    assert_fields!(GatewayEventParsingError::Deserializing: source);
+    assert_impl_all!(GatewayEventParsingErrorType: Debug, Send, Sync);
     assert_impl_all!(GatewayEventParsingError: Debug, Error, Send, Sync);
 }
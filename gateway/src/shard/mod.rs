@@ -26,20 +26,28 @@ pub mod stage;
 
 mod builder;
 mod config;
+mod encoding;
+mod etf;
 mod event;
 mod r#impl;
-mod json;
+pub(crate) mod json;
 mod processor;
+mod reconnect;
 mod sink;
 
 pub use self::{
-    builder::{LargeThresholdError, ShardBuilder, ShardIdError},
+    builder::{
+        LargeThresholdError, LargeThresholdErrorType, ShardBuilder, ShardIdError, ShardIdErrorType,
+    },
     config::Config,
+    encoding::Encoding,
     event::Events,
-    processor::heartbeat::Latency,
+    processor::{heartbeat::Latency, Compression},
     r#impl::{
-        CommandError, Information, ResumeSession, SessionInactiveError, Shard, ShardStartError,
+        CommandError, CommandErrorType, Information, ResumeSession, SessionInactiveError, Shard,
+        ShardStartError, ShardStartErrorType,
     },
+    reconnect::Reconnect,
     sink::ShardSink,
     stage::Stage,
 };
@@ -1,11 +1,15 @@
-use super::super::json::{self, GatewayEventParsingError};
+use super::super::{
+    etf,
+    json::{self, GatewayEventParsingError},
+};
 use crate::{listener::Listeners, EventTypeFlags};
+use bytes::Bytes;
 use std::{
     convert::TryFrom,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::gateway::event::{shard::Payload, Event};
+use twilight_model::gateway::event::{shard::Payload, Event, GatewayEvent};
 
 #[derive(Debug)]
 pub enum EmitJsonError {
@@ -49,13 +53,20 @@ impl Error for EmitJsonError {
 /// common operations.
 #[derive(Clone, Debug)]
 pub struct Emitter {
+    event_types: EventTypeFlags,
     listeners: Listeners<Event>,
 }
 
 impl Emitter {
     /// Create a new emitter for events and bytes.
-    pub fn new(listeners: Listeners<Event>) -> Self {
-        Self { listeners }
+    ///
+    /// Only the provided event types are emitted, even if a listener wants
+    /// others.
+    pub fn new(listeners: Listeners<Event>, event_types: EventTypeFlags) -> Self {
+        Self {
+            event_types,
+            listeners,
+        }
     }
 
     /// Consume the emitter, returning the inner listeners.
@@ -65,23 +76,30 @@ impl Emitter {
 
     /// Determine if any of the listeners want a certain event type.
     pub fn wants(&self, event_type: EventTypeFlags) -> bool {
-        self.listeners.event_types().contains(event_type)
+        self.event_types.contains(event_type) && self.listeners.event_types().contains(event_type)
     }
 
-    /// Send some bytes to listeners that have subscribed to shard payloads.
+    /// Send some bytes and their headers to listeners that have subscribed to
+    /// shard payloads.
     ///
     /// Shard payload events aren't subscribed to by default and must be opted in
     /// to. If a listener has subscribed to them, then the input bytes will be
-    /// cloned. This means that for most users, this will be a cheap check.
+    /// copied once and shared between listeners. This means that for most
+    /// users, this will be a cheap check.
     #[tracing::instrument(level = "trace")]
-    pub fn bytes(&self, bytes: &[u8]) {
+    pub fn bytes(&self, bytes: &[u8], op: u8, seq: Option<u64>, event_type: Option<&str>) {
         if !self.wants(EventTypeFlags::SHARD_PAYLOAD) {
             return;
         }
 
+        let bytes = Bytes::copy_from_slice(bytes);
+
         self.send(EventTypeFlags::SHARD_PAYLOAD, |_| {
             Event::ShardPayload(Payload {
-                bytes: bytes.to_vec(),
+                bytes: bytes.clone(),
+                event_type: event_type.map(ToOwned::to_owned),
+                op,
+                sequence: seq,
             })
         });
     }
@@ -124,6 +142,33 @@ impl Emitter {
         seq: Option<u64>,
         event_type: Option<&str>,
         json: &mut str,
+    ) -> Result<(), EmitJsonError> {
+        self.parse(op, event_type, || {
+            json::parse_gateway_event(op, seq, event_type, json)
+        })
+    }
+
+    /// Emit an ETF payload that hasn't been deserialized yet, but only if at
+    /// least one of the listeners wants the event type.
+    pub fn etf(
+        &self,
+        op: u8,
+        seq: Option<u64>,
+        event_type: Option<&str>,
+        etf: &[u8],
+    ) -> Result<(), EmitJsonError> {
+        self.parse(op, event_type, || {
+            etf::parse_gateway_event(op, seq, event_type, etf)
+        })
+    }
+
+    /// Parse and emit a payload with the given parser if at least one of the
+    /// listeners wants the event type.
+    fn parse(
+        &self,
+        op: u8,
+        event_type: Option<&str>,
+        parse: impl FnOnce() -> Result<GatewayEvent, GatewayEventParsingError>,
     ) -> Result<(), EmitJsonError> {
         let flag = EventTypeFlags::try_from((op, event_type)).map_err(|(op, event_type)| {
             EmitJsonError::EventTypeUnknown {
@@ -136,8 +181,7 @@ impl Emitter {
             return Ok(());
         }
 
-        let gateway_event = json::parse_gateway_event(op, seq, event_type, json)
-            .map_err(|source| EmitJsonError::Parsing { source })?;
+        let gateway_event = parse().map_err(|source| EmitJsonError::Parsing { source })?;
         self.event(Event::from(gateway_event));
 
         Ok(())
@@ -172,6 +216,9 @@ impl Emitter {
 }
 
 #[cfg(test)]
+// `try_next` is deprecated in newer versions of `futures-channel` in favour of
+// `try_recv`, which older versions don't have.
+#[allow(deprecated)]
 mod tests {
     use super::Emitter;
     use crate::{listener::Listeners, Event, EventTypeFlags};
@@ -180,8 +227,8 @@ mod tests {
     fn test_bytes_send() {
         let listeners = Listeners::default();
         let mut rx = listeners.add(EventTypeFlags::SHARD_PAYLOAD);
-        let emitter = Emitter::new(listeners);
-        emitter.bytes(&[1]);
+        let emitter = Emitter::new(listeners, EventTypeFlags::all());
+        emitter.bytes(&[1], 11, None, None);
         assert_eq!(1, emitter.listeners.len());
 
         assert!(matches!(rx.try_next(), Ok(Some(_))));
@@ -192,7 +239,7 @@ mod tests {
     fn test_event_removes_closed_channels() {
         let listeners = Listeners::default();
         let _ = listeners.add(EventTypeFlags::default());
-        let emitter = Emitter::new(listeners);
+        let emitter = Emitter::new(listeners, EventTypeFlags::all());
         emitter.event(Event::GatewayReconnect);
         assert!(emitter.listeners.all().is_empty());
     }
@@ -202,7 +249,7 @@ mod tests {
         let listeners = Listeners::default();
         let mut rx1 = listeners.add(EventTypeFlags::default());
         let mut rx2 = listeners.add(EventTypeFlags::default());
-        let emitter = Emitter::new(listeners);
+        let emitter = Emitter::new(listeners, EventTypeFlags::all());
         emitter.event(Event::GatewayReconnect);
         assert_eq!(2, emitter.listeners.len());
 
@@ -213,4 +260,18 @@ mod tests {
         assert!(rx1.try_next().is_err());
         assert!(rx2.try_next().is_err());
     }
+
+    #[test]
+    fn test_event_types_filtered() {
+        let listeners = Listeners::default();
+        let mut rx = listeners.add(EventTypeFlags::default());
+        let emitter = Emitter::new(listeners, EventTypeFlags::GATEWAY_RECONNECT);
+        assert!(emitter.wants(EventTypeFlags::GATEWAY_RECONNECT));
+        assert!(!emitter.wants(EventTypeFlags::GATEWAY_HELLO));
+
+        emitter.event(Event::GatewayHello(1));
+        assert!(rx.try_next().is_err());
+        emitter.event(Event::GatewayReconnect);
+        assert!(matches!(rx.try_next(), Ok(Some(_))));
+    }
 }
@@ -1,4 +1,7 @@
-use super::{super::json, session::SessionSendError};
+use super::{
+    super::Encoding,
+    session::{self, SessionSendError, SessionSendErrorType},
+};
 use async_tungstenite::tungstenite::Message as TungsteniteMessage;
 use futures_channel::mpsc::UnboundedSender;
 use std::{
@@ -46,6 +49,18 @@ impl Latency {
         self.heartbeats
     }
 
+    /// The time it took to receive an acknowledgement for the most recently
+    /// acknowledged heartbeat.
+    ///
+    /// This is what a `ping` command would typically report.
+    ///
+    /// # Note
+    ///
+    /// If this is None, the shard has not received a heartbeat yet.
+    pub fn last(&self) -> Option<Duration> {
+        self.recent.back().copied()
+    }
+
     /// The 5 most recent latency times.
     ///
     /// Index 0 is the oldest, 4 is the most recent.
@@ -53,6 +68,23 @@ impl Latency {
         &self.recent
     }
 
+    /// The average time it took to receive an acknowledgement for the 5 most
+    /// recent heartbeats.
+    ///
+    /// Unlike [`average`], this reflects recent changes in the network
+    /// connection, which makes it more suitable for health checks.
+    ///
+    /// # Note
+    ///
+    /// If this is None, the shard has not received a heartbeat yet.
+    ///
+    /// [`average`]: #method.average
+    pub fn recent_average(&self) -> Option<Duration> {
+        let count = self.recent.len().try_into().ok()?;
+
+        self.recent.iter().sum::<Duration>().checked_div(count)
+    }
+
     /// When the last heartbeat acknowledgement was received.
     pub fn received(&self) -> Option<Instant> {
         self.received
@@ -169,6 +201,7 @@ impl Default for Heartbeats {
 }
 
 pub struct Heartbeater {
+    encoding: Encoding,
     heartbeats: Arc<Heartbeats>,
     interval: u64,
     seq: Arc<AtomicU64>,
@@ -181,8 +214,10 @@ impl Heartbeater {
         interval: u64,
         seq: Arc<AtomicU64>,
         tx: UnboundedSender<TungsteniteMessage>,
+        encoding: Encoding,
     ) -> Self {
         Self {
+            encoding,
             heartbeats,
             interval,
             seq,
@@ -225,13 +260,15 @@ impl Heartbeater {
 
             let seq = self.seq.load(Ordering::Acquire);
             let heartbeat = Heartbeat::new(seq);
-            let bytes = json::to_vec(&heartbeat)
-                .map_err(|source| SessionSendError::Serializing { source })?;
+            let bytes = session::encode(self.encoding, &heartbeat)?;
 
             tracing::debug!(seq, "sending heartbeat");
             self.tx
                 .unbounded_send(TungsteniteMessage::Binary(bytes))
-                .map_err(|source| SessionSendError::Sending { source })?;
+                .map_err(|source| SessionSendError {
+                    kind: SessionSendErrorType::Sending,
+                    source: Some(Box::new(source)),
+                })?;
             tracing::debug!(seq, "sent heartbeat");
             self.heartbeats.send();
         }
@@ -240,9 +277,31 @@ impl Heartbeater {
 
 #[cfg(test)]
 mod tests {
-    use super::Latency;
+    use super::{Heartbeats, Latency};
     use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use std::{collections::VecDeque, fmt::Debug, time::Duration};
 
     assert_impl_all!(Latency: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_latency() {
+        let latency = Heartbeats::default().latency();
+        assert!(latency.average().is_none());
+        assert!(latency.last().is_none());
+        assert!(latency.recent_average().is_none());
+
+        let latency = Latency {
+            average: Some(Duration::from_millis(100)),
+            heartbeats: 12,
+            recent: [40, 50, 60, 70, 80]
+                .iter()
+                .copied()
+                .map(Duration::from_millis)
+                .collect::<VecDeque<_>>(),
+            received: None,
+            sent: None,
+        };
+        assert_eq!(Some(Duration::from_millis(80)), latency.last());
+        assert_eq!(Some(Duration::from_millis(60)), latency.recent_average());
+    }
 }
@@ -1,19 +1,20 @@
 use super::{
     super::{
         config::Config,
-        json::{self, GatewayEventParsingError},
+        etf,
+        json::{self, GatewayEventParsingError, GatewayEventParsingErrorType},
         stage::Stage,
-        ShardStream,
+        Encoding, ShardStream,
     },
     emitter::{EmitJsonError, Emitter},
     inflater::Inflater,
-    session::{Session, SessionSendError},
+    session::{Session, SessionSendError, SessionSendErrorType},
     socket_forwarder::SocketForwarder,
 };
 use crate::{event::EventTypeFlags, listener::Listeners};
 use async_tungstenite::tungstenite::{
     protocol::{frame::coding::CloseCode, CloseFrame},
-    Error as TungsteniteError, Message,
+    Message,
 };
 use flate2::DecompressError;
 use futures_channel::mpsc::{TrySendError, UnboundedReceiver};
@@ -26,7 +27,6 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     str::{self, Utf8Error},
     sync::{atomic::Ordering, Arc},
-    time::Duration,
 };
 use tokio::sync::watch::{
     channel as watch_channel, Receiver as WatchReceiver, Sender as WatchSender,
@@ -43,37 +43,69 @@ use twilight_model::gateway::{
     },
     Intents, OpCode,
 };
-use url::{ParseError as UrlParseError, Url};
+use url::Url;
 
 /// Connecting to the gateway failed.
 #[derive(Debug)]
-#[non_exhaustive]
-pub enum ConnectingError {
-    Establishing { source: TungsteniteError },
-    ParsingUrl { source: UrlParseError, url: String },
+pub struct ConnectingError {
+    pub(crate) kind: ConnectingErrorType,
+    pub(crate) source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ConnectingError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ConnectingErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ConnectingErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for ConnectingError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::Establishing { source } => Display::fmt(source, f),
-            Self::ParsingUrl { source, url } => f.write_fmt(format_args!(
-                "the gateway url `{}` is invalid: {}",
-                url, source,
-            )),
+        match &self.kind {
+            ConnectingErrorType::Establishing => f.write_str("establishing the connection failed"),
+            ConnectingErrorType::ParsingUrl { url } => {
+                f.write_fmt(format_args!("the gateway url `{}` is invalid", url))
+            }
         }
     }
 }
 
 impl Error for ConnectingError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Self::Establishing { source } => Some(source),
-            Self::ParsingUrl { source, .. } => Some(source),
-        }
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
     }
 }
 
+/// Type of [`ConnectingError`] that occurred.
+///
+/// [`ConnectingError`]: struct.ConnectingError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConnectingErrorType {
+    /// Establishing a connection to the gateway failed.
+    Establishing,
+    /// Gateway URL couldn't be parsed.
+    ParsingUrl {
+        /// Gateway URL that couldn't be parsed.
+        url: String,
+    },
+}
+
 #[derive(Debug)]
 enum ProcessError {
     /// Provided event type and/or opcode combination doesn't match a known
@@ -150,7 +182,7 @@ impl Error for ProcessError {
 #[non_exhaustive]
 enum ReceivingEventError {
     /// Provided authorization token is invalid.
-    AuthorizationInvalid { shard_id: u64, token: String },
+    AuthorizationInvalid { shard_id: u64 },
     /// Decompressing a frame from Discord failed.
     Decompressing {
         /// Reason for the error.
@@ -201,7 +233,7 @@ impl ReceivingEventError {
 impl Display for ReceivingEventError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
-            Self::AuthorizationInvalid { shard_id, .. } => f.write_fmt(format_args!(
+            Self::AuthorizationInvalid { shard_id } => f.write_fmt(format_args!(
                 "the authorization token for shard {} is invalid",
                 shard_id
             )),
@@ -219,7 +251,17 @@ impl Display for ReceivingEventError {
     }
 }
 
-impl Error for ReceivingEventError {}
+impl Error for ReceivingEventError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Decompressing { source } => Some(source),
+            Self::AuthorizationInvalid { .. }
+            | Self::EventStreamEnded
+            | Self::IntentsDisallowed { .. }
+            | Self::IntentsInvalid { .. } => None,
+        }
+    }
+}
 
 #[derive(Deserialize)]
 struct ReadyMinimal {
@@ -238,12 +280,18 @@ pub struct ShardProcessor {
     inflater: Inflater,
     url: String,
     resume: Option<(u64, String)>,
+    /// Whether the configured number of reconnect attempts ran out, which
+    /// stops the processor.
+    stopped: bool,
     wtx: WatchSender<Arc<Session>>,
 }
 
 impl ShardProcessor {
     /// Gateway version to use in the URL to connect to the gateway.
-    const GATEWAY_VERSION: u64 = 6;
+    ///
+    /// Starting with version 8 the gateway requires intents to be specified
+    /// when identifying.
+    const GATEWAY_VERSION: u64 = 9;
 
     pub async fn new(
         config: Arc<Config>,
@@ -262,9 +310,17 @@ impl ShardProcessor {
 
         let properties = IdentifyProperties::new("twilight.rs", "twilight.rs", OS, "", "");
 
-        url.push_str("?v=8&compress=zlib-stream");
+        url.push_str(&format!(
+            "?v={}&encoding={}",
+            Self::GATEWAY_VERSION,
+            config.encoding().name(),
+        ));
+
+        if config.compression() {
+            url.push_str("&compress=zlib-stream");
+        }
 
-        let emitter = Emitter::new(listeners);
+        let emitter = Emitter::new(listeners, config.event_types());
         emitter.event(Event::ShardConnecting(Connecting {
             gateway: url.clone(),
             shard_id: config.shard()[0],
@@ -275,7 +331,7 @@ impl ShardProcessor {
             forwarder.run().await;
         });
 
-        let session = Arc::new(Session::new(tx));
+        let session = Arc::new(Session::new(tx, config.encoding()));
         if resumable {
             session.set_id(config.session_id.clone().unwrap());
             session
@@ -294,6 +350,7 @@ impl ShardProcessor {
             inflater: Inflater::new(shard_id),
             url,
             resume: None,
+            stopped: false,
             wtx,
         };
 
@@ -306,7 +363,7 @@ impl ShardProcessor {
     }
 
     pub async fn run(mut self) {
-        loop {
+        while !self.stopped {
             match self.next_payload().await {
                 Ok(v) => v,
                 Err(source) => {
@@ -329,12 +386,7 @@ impl ShardProcessor {
             };
 
             if let Err(source) = self.process().await {
-                tracing::warn!(
-                    shard_id = self.config.shard()[0],
-                    shard_total = self.config.shard()[1],
-                    "processing incoming event failed: {:?}",
-                    source,
-                );
+                tracing::warn!("processing incoming event failed: {:?}", source);
 
                 if source.fatal() {
                     tracing::debug!("error processing event; reconnecting");
@@ -348,37 +400,52 @@ impl ShardProcessor {
     }
 
     async fn process(&mut self) -> Result<(), ProcessError> {
+        let encoding = self.config.encoding();
+
         let (op, seq, event_type) = {
-            let json = str::from_utf8_mut(self.inflater.buffer_mut())
-                .map_err(|source| ProcessError::PayloadNotUtf8 { source })?;
+            // Unfortunately lifetimes and mutability requirements conflict
+            // here if we return an immutable reference to the event type, so
+            // we're going to have to take ownership of this if we don't want
+            // to do anything too dangerous. It should be a good trade-off
+            // either way.
+            let header = match encoding {
+                Encoding::Etf => etf::header(self.inflater.buffer_ref())
+                    .map(|(op, seq, event_type)| (op, seq, event_type.map(ToOwned::to_owned))),
+                Encoding::Json => {
+                    let json = str::from_utf8_mut(self.inflater.buffer_mut())
+                        .map_err(|source| ProcessError::PayloadNotUtf8 { source })?;
+
+                    tracing::trace!(%json, "Received JSON");
+
+                    GatewayEventDeserializer::from_json(json).map(|deserializer| {
+                        let (op, seq, event_type) = deserializer.into_parts();
+
+                        (op, seq, event_type.map(ToOwned::to_owned))
+                    })
+                }
+            };
 
-            tracing::trace!(%json, "Received JSON");
             let emitter = self.emitter.clone();
 
-            let (op, seq, event_type) =
-                if let Some(deserializer) = GatewayEventDeserializer::from_json(json) {
-                    let (op, seq, event_type) = deserializer.into_parts();
+            let (op, seq, event_type) = if let Some(header) = header {
+                header
+            } else {
+                tracing::warn!(
+                    json = ?self.inflater.buffer_ref(),
+                    seq = self.session.seq(),
+                    stage = ?self.session.stage(),
+                    "received payload without opcode",
+                );
+
+                return Err(ProcessError::ParsingPayload {
+                    source: GatewayEventParsingError {
+                        kind: GatewayEventParsingErrorType::PayloadInvalid,
+                        source: None,
+                    },
+                });
+            };
 
-                    // Unfortunately lifetimes and mutability requirements
-                    // conflict here if we return an immutable reference to the
-                    // event type, so we're going to have to take ownership of
-                    // this if we don't want to do anything too dangerous. It
-                    // should be a good trade-off either way.
-                    (op, seq, event_type.map(ToOwned::to_owned))
-                } else {
-                    tracing::warn!(
-                        json = ?self.inflater.buffer_ref(),
-                        shard_id = self.config.shard()[0],
-                        shard_total = self.config.shard()[1],
-                        seq = self.session.seq(),
-                        stage = ?self.session.stage(),
-                        "received payload without opcode",
-                    );
-
-                    return Err(ProcessError::ParsingPayload {
-                        source: GatewayEventParsingError::PayloadInvalid,
-                    });
-                };
+            emitter.bytes(self.inflater.buffer_ref(), op, seq, event_type.as_deref());
 
             // We can do a few little optimisation tricks here. For the
             // "heartbeat ack" and "reconnect" opcodes we can construct
@@ -398,8 +465,14 @@ impl ShardProcessor {
                 } else if op == OpCode::Reconnect as u8 {
                     GatewayEvent::Reconnect
                 } else {
-                    json::parse_gateway_event(op, seq, event_type.as_deref(), json)
-                        .map_err(|source| ProcessError::ParsingPayload { source })?
+                    parse_gateway_event(
+                        encoding,
+                        self.inflater.buffer_mut(),
+                        op,
+                        seq,
+                        event_type.as_deref(),
+                    )
+                    .map_err(|source| ProcessError::ParsingPayload { source })?
                 };
 
                 self.process_gateway_event(&gateway_event).await?;
@@ -426,11 +499,19 @@ impl ShardProcessor {
 
                 return Ok(());
             } else if event_type.as_deref() == Some("READY") {
-                let ready = json::from_slice::<ReadyMinimal>(self.inflater.buffer_mut()).map_err(
-                    |source| ProcessError::ParsingPayload {
-                        source: GatewayEventParsingError::Deserializing { source },
-                    },
-                )?;
+                let ready = match encoding {
+                    Encoding::Etf => etf::from_slice::<ReadyMinimal>(self.inflater.buffer_ref())
+                        .map_err(|source| GatewayEventParsingError {
+                            kind: GatewayEventParsingErrorType::DeserializingEtf,
+                            source: Some(Box::new(source)),
+                        }),
+                    Encoding::Json => json::from_slice::<ReadyMinimal>(self.inflater.buffer_mut())
+                        .map_err(|source| GatewayEventParsingError {
+                            kind: GatewayEventParsingErrorType::Deserializing,
+                            source: Some(Box::new(source)),
+                        }),
+                }
+                .map_err(|source| ProcessError::ParsingPayload { source })?;
                 self.process_ready(&ready.d);
                 emitter.event(Event::Ready(Box::new(ready.d)));
 
@@ -442,18 +523,30 @@ impl ShardProcessor {
             (op, seq, event_type)
         };
 
-        // We already know from earlier that the payload is valid UTF-8, so we
-        // can skip having to re-validate here since it hasn't been mutated.
-        let json = unsafe { str::from_utf8_unchecked_mut(self.inflater.buffer_mut()) };
+        let emitted = match encoding {
+            Encoding::Etf => self.emitter.etf(
+                op,
+                Some(seq),
+                event_type.as_deref(),
+                self.inflater.buffer_ref(),
+            ),
+            Encoding::Json => {
+                // We already know from earlier that the payload is valid UTF-8,
+                // so we can skip having to re-validate here since it hasn't
+                // been mutated.
+                let json = unsafe { str::from_utf8_unchecked_mut(self.inflater.buffer_mut()) };
+
+                self.emitter
+                    .json(op, Some(seq), event_type.as_deref(), json)
+            }
+        };
 
-        self.emitter
-            .json(op, Some(seq), event_type.as_deref(), json)
-            .map_err(|source| match source {
-                EmitJsonError::Parsing { source } => ProcessError::ParsingPayload { source },
-                EmitJsonError::EventTypeUnknown { event_type, op } => {
-                    ProcessError::EventTypeUnknown { event_type, op }
-                }
-            })
+        emitted.map_err(|source| match source {
+            EmitJsonError::Parsing { source } => ProcessError::ParsingPayload { source },
+            EmitJsonError::EventTypeUnknown { event_type, op } => {
+                ProcessError::EventTypeUnknown { event_type, op }
+            }
+        })
     }
 
     fn process_ready(&mut self, ready: &Ready) {
@@ -596,7 +689,7 @@ impl ShardProcessor {
         if let Err(source) = self.session.send(payload) {
             tracing::warn!("sending message failed: {:?}", source);
 
-            if matches!(source, SessionSendError::Sending { .. }) {
+            if matches!(source.kind(), SessionSendErrorType::Sending) {
                 self.reconnect().await;
             }
 
@@ -648,22 +741,39 @@ impl ShardProcessor {
     ///
     /// If a ping or pong are received, then they are ignored.
     ///
-    /// Text messages aren't sent by Discord, so they are left unhandled.
+    /// If transport compression is disabled then binary and text payloads are
+    /// copied into the inflater buffer as they are.
     async fn handle_message<'a>(
         &'a mut self,
         msg: &'a mut Message,
     ) -> Result<bool, ReceivingEventError> {
         match msg {
-            Message::Binary(bin) => {
+            Message::Binary(bin) if self.config.compression() => {
                 self.inflater.extend(&bin[..]);
 
-                let bytes = match self.inflater.msg() {
-                    Ok(Some(bytes)) => bytes,
+                match self.inflater.msg() {
+                    Ok(Some(_)) => {}
                     Ok(None) => return Ok(false),
                     Err(source) => return Err(ReceivingEventError::Decompressing { source }),
-                };
+                }
+
+                self.session
+                    .compression
+                    .lock()
+                    .expect("compression poisoned")
+                    .replace(self.inflater.compression());
+
+                Ok(true)
+            }
+            // Without transport compression ETF payloads are sent as binary
+            // messages and JSON payloads as text messages.
+            Message::Binary(bin) => {
+                self.inflater.uncompressed(&bin[..]);
 
-                self.emitter.bytes(bytes);
+                Ok(true)
+            }
+            Message::Text(text) => {
+                self.inflater.uncompressed(text.as_bytes());
 
                 Ok(true)
             }
@@ -672,9 +782,7 @@ impl ShardProcessor {
 
                 Ok(false)
             }
-            // Discord doesn't appear to send Text messages, so we can ignore
-            // these.
-            Message::Ping(_) | Message::Pong(_) | Message::Text(_) => Ok(false),
+            Message::Ping(_) | Message::Pong(_) => Ok(false),
         }
     }
 
@@ -697,7 +805,6 @@ impl ShardProcessor {
                 CloseCode::Library(4004) => {
                     return Err(ReceivingEventError::AuthorizationInvalid {
                         shard_id: self.config.shard()[0],
-                        token: self.config.token().to_owned(),
                     });
                 }
                 CloseCode::Library(4013) => {
@@ -722,14 +829,19 @@ impl ShardProcessor {
     }
 
     async fn connect(url: &str) -> Result<ShardStream, ConnectingError> {
-        let url = Url::parse(url).map_err(|source| ConnectingError::ParsingUrl {
-            source,
-            url: url.to_owned(),
+        let url = Url::parse(url).map_err(|source| ConnectingError {
+            kind: ConnectingErrorType::ParsingUrl {
+                url: url.to_owned(),
+            },
+            source: Some(Box::new(source)),
         })?;
 
         let (stream, _) = async_tungstenite::tokio::connect_async(url)
             .await
-            .map_err(|source| ConnectingError::Establishing { source })?;
+            .map_err(|source| ConnectingError {
+                kind: ConnectingErrorType::Establishing,
+                source: Some(Box::new(source)),
+            })?;
 
         tracing::debug!("Shook hands with remote");
 
@@ -762,13 +874,30 @@ impl ShardProcessor {
     async fn reconnect(&mut self) {
         tracing::info!("reconnection started");
 
-        let mut wait = Duration::from_secs(1);
+        let reconnect = self.config.reconnect().clone();
+        let mut attempts = 0;
+        let mut delay = reconnect.initial_delay;
 
         loop {
+            if matches!(reconnect.max_attempts, Some(max) if attempts >= max) {
+                tracing::warn!(
+                    shard_id = self.config.shard()[0],
+                    shard_total = self.config.shard()[1],
+                    attempts,
+                    "giving up reconnecting",
+                );
+                self.stopped = true;
+
+                return;
+            }
+
+            attempts += 1;
+            let wait = reconnect.wait(delay);
+
             tracing::debug!(
                 shard_id = self.config.shard()[0],
                 shard_total = self.config.shard()[1],
-                wait_in_seconds = wait.as_secs(),
+                ?wait,
                 "waiting before attempting a reconnect",
             );
             tokio::time::delay_for(wait).await;
@@ -784,10 +913,7 @@ impl ShardProcessor {
                 Ok(s) => s,
                 Err(why) => {
                     tracing::warn!("reconnecting failed: {:?}", why);
-
-                    if wait < Duration::from_secs(128) {
-                        wait *= 2;
-                    }
+                    delay = reconnect.next_delay(delay);
 
                     continue;
                 }
@@ -860,7 +986,7 @@ impl ShardProcessor {
         tokio::spawn(forwarder.run());
 
         self.rx = rx;
-        self.session = Arc::new(Session::new(tx));
+        self.session = Arc::new(Session::new(tx, self.config.encoding()));
 
         if let Err(why) = self.wtx.broadcast(Arc::clone(&self.session)) {
             tracing::error!("failed to broadcast new session: {:?}", why);
@@ -870,3 +996,23 @@ impl ShardProcessor {
         self.inflater.reset();
     }
 }
+
+/// Parse a gateway event from a payload in the given encoding.
+///
+/// JSON payloads must have already been validated as UTF-8.
+fn parse_gateway_event(
+    encoding: Encoding,
+    buffer: &mut [u8],
+    op: u8,
+    seq: Option<u64>,
+    event_type: Option<&str>,
+) -> Result<GatewayEvent, GatewayEventParsingError> {
+    match encoding {
+        Encoding::Etf => etf::parse_gateway_event(op, seq, event_type, buffer),
+        Encoding::Json => {
+            let json = unsafe { str::from_utf8_unchecked_mut(buffer) };
+
+            json::parse_gateway_event(op, seq, event_type, json)
+        }
+    }
+}
@@ -4,6 +4,43 @@ use std::{convert::TryInto, mem};
 const ZLIB_SUFFIX: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
 const INTERNAL_BUFFER_SIZE: usize = 32 * 1024;
 
+/// Statistics about the decompression of a [`Shard`]'s payloads.
+///
+/// This is obtained through [`Information::compression`]. The statistics
+/// cover the current websocket connection.
+///
+/// [`Information::compression`]: struct.Information.html#method.compression
+/// [`Shard`]: struct.Shard.html
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Compression {
+    buffer_capacity: usize,
+    compressed_capacity: usize,
+    total_in: u64,
+    total_out: u64,
+}
+
+impl Compression {
+    /// Capacity of the buffer holding decompressed payloads, in bytes.
+    pub fn buffer_capacity(&self) -> usize {
+        self.buffer_capacity
+    }
+
+    /// Capacity of the buffer holding compressed payloads, in bytes.
+    pub fn compressed_capacity(&self) -> usize {
+        self.compressed_capacity
+    }
+
+    /// Total number of compressed bytes received.
+    pub fn total_in(&self) -> u64 {
+        self.total_in
+    }
+
+    /// Total number of bytes the received bytes decompressed to.
+    pub fn total_out(&self) -> u64 {
+        self.total_out
+    }
+}
+
 #[derive(Debug)]
 pub struct Inflater {
     decompress: Decompress,
@@ -37,6 +74,24 @@ impl Inflater {
         self.buffer.as_mut_slice()
     }
 
+    /// Return statistics about the decompression of payloads.
+    pub fn compression(&self) -> Compression {
+        Compression {
+            buffer_capacity: self.buffer.capacity(),
+            compressed_capacity: self.compressed.capacity(),
+            total_in: self.decompress.total_in(),
+            total_out: self.decompress.total_out(),
+        }
+    }
+
+    /// Replace the buffer with an uncompressed payload.
+    ///
+    /// This is used when transport compression is disabled.
+    pub fn uncompressed(&mut self, slice: &[u8]) {
+        self.buffer.clear();
+        self.buffer.extend_from_slice(slice);
+    }
+
     /// Extend the internal compressed buffer with bytes.
     pub fn extend(&mut self, slice: &[u8]) {
         self.compressed.extend_from_slice(&slice);
@@ -100,7 +155,11 @@ impl Inflater {
             self.decompress.total_in() as f64 / self.decompress.total_out() as f64;
         let saved_percentage_readable = saved_percentage * 100.0;
 
-        let saved_kib = (self.decompress.total_out() - self.decompress.total_in()) / 1_024;
+        let saved_kib = self
+            .decompress
+            .total_out()
+            .saturating_sub(self.decompress.total_in())
+            / 1_024;
 
         tracing::trace!(
             saved_kib = saved_kib,
@@ -190,3 +249,44 @@ impl Inflater {
         self.countdown_to_resize = u8::MAX;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Compression, Inflater};
+    use flate2::{Compress, Compression as Level, FlushCompress};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(Compression: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    const PAYLOAD: &[u8] = br#"{"op":11,"d":null}"#;
+
+    #[test]
+    fn test_msg_compressed() {
+        let mut compress = Compress::new(Level::default(), true);
+        let mut compressed = Vec::with_capacity(128);
+        compress
+            .compress_vec(PAYLOAD, &mut compressed, FlushCompress::Sync)
+            .unwrap();
+
+        let mut inflater = Inflater::new([0, 1]);
+        let (first, second) = compressed.split_at(2);
+        inflater.extend(first);
+        assert!(inflater.msg().unwrap().is_none());
+        inflater.extend(second);
+        assert_eq!(PAYLOAD, &inflater.msg().unwrap().unwrap()[..]);
+
+        let compression = inflater.compression();
+        assert_eq!(compressed.len() as u64, compression.total_in());
+        assert_eq!(PAYLOAD.len() as u64, compression.total_out());
+        assert!(compression.buffer_capacity() >= PAYLOAD.len());
+    }
+
+    #[test]
+    fn test_uncompressed() {
+        let mut inflater = Inflater::new([0, 1]);
+        inflater.uncompressed(PAYLOAD);
+        assert_eq!(PAYLOAD, inflater.buffer_ref());
+        assert_eq!(0, inflater.compression().total_in());
+    }
+}
@@ -8,6 +8,7 @@ mod socket_forwarder;
 
 pub use self::{
     heartbeat::Latency,
-    r#impl::{ConnectingError, ShardProcessor},
+    inflater::Compression,
+    r#impl::{ConnectingError, ConnectingErrorType, ShardProcessor},
     session::Session,
 };
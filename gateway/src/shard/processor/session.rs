@@ -1,6 +1,7 @@
 use super::{
-    super::{json, stage::Stage},
+    super::{etf, json, stage::Stage, Encoding},
     heartbeat::{Heartbeater, Heartbeats},
+    inflater::Compression,
 };
 use async_tungstenite::tungstenite::{protocol::CloseFrame, Message as TungsteniteMessage};
 use futures_channel::mpsc::{TrySendError, UnboundedSender};
@@ -23,41 +24,55 @@ use std::{
 use tokio::time::{self as tokio_time, Throttle};
 use twilight_model::gateway::payload::Heartbeat;
 
-#[cfg(not(feature = "simd-json"))]
-use serde_json::Error as JsonError;
-#[cfg(feature = "simd-json")]
-use simd_json::Error as JsonError;
-
 #[derive(Debug)]
-pub enum SessionSendError {
-    Sending {
-        source: TrySendError<TungsteniteMessage>,
-    },
-    Serializing {
-        source: JsonError,
-    },
+pub struct SessionSendError {
+    pub(crate) kind: SessionSendErrorType,
+    pub(crate) source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl SessionSendError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &SessionSendErrorType {
+        &self.kind
+    }
 }
 
 impl Display for SessionSendError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::Serializing { source } => Display::fmt(source, f),
-            Self::Sending { source } => Display::fmt(source, f),
+        match &self.kind {
+            SessionSendErrorType::Serializing => f.write_str("serializing the payload failed"),
+            SessionSendErrorType::SerializingEtf => {
+                f.write_str("serializing the payload as etf failed")
+            }
+            SessionSendErrorType::Sending => f.write_str("sending the message failed"),
         }
     }
 }
 
 impl Error for SessionSendError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Self::Sending { source } => Some(source),
-            Self::Serializing { source } => Some(source),
-        }
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
     }
 }
 
+/// Type of [`SessionSendError`] that occurred.
+///
+/// [`SessionSendError`]: struct.SessionSendError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SessionSendErrorType {
+    Sending,
+    Serializing,
+    SerializingEtf,
+}
+
 #[derive(Debug)]
 pub struct Session {
+    pub compression: MutexSync<Option<Compression>>,
+    pub encoding: Encoding,
     // Needs to be Arc so it can be cloned in the `Drop` impl when spawned on
     // the runtime.
     pub heartbeater_handle: Arc<MutexSync<Option<AbortHandle>>>,
@@ -71,8 +86,10 @@ pub struct Session {
 }
 
 impl Session {
-    pub fn new(tx: UnboundedSender<TungsteniteMessage>) -> Self {
+    pub fn new(tx: UnboundedSender<TungsteniteMessage>, encoding: Encoding) -> Self {
         Self {
+            compression: MutexSync::new(None),
+            encoding,
             heartbeater_handle: Arc::new(MutexSync::new(None)),
             heartbeats: Arc::new(Heartbeats::default()),
             heartbeat_interval: AtomicU64::new(0),
@@ -92,22 +109,26 @@ impl Session {
     ///
     /// # Errors
     ///
-    /// Returns [`SessionSendError::Serializing`] when there is an error
-    /// serializing the payload into an acceptable format.
+    /// Returns [`SessionSendErrorType::Serializing`] or
+    /// [`SessionSendErrorType::SerializingEtf`] when there is an error serializing
+    /// the payload into the session's encoding.
     ///
-    /// Returns [`SessionSendError::Sending`] when the receiving channel has hung
+    /// Returns [`SessionSendErrorType::Sending`] when the receiving channel has hung
     /// up. This will only happen when the shard has either not started or has
     /// already shutdown.
     ///
-    /// [`SessionSendError::Serializing`]: enum.SessionSendError.html#variant.Serializing
-    /// [`SessionSendError::Sending`]: enum.SessionSendError.html#variant.Sending
+    /// [`SessionSendErrorType::Serializing`]: enum.SessionSendErrorType.html#variant.Serializing
+    /// [`SessionSendErrorType::SerializingEtf`]: enum.SessionSendErrorType.html#variant.SerializingEtf
+    /// [`SessionSendErrorType::Sending`]: enum.SessionSendErrorType.html#variant.Sending
     pub fn send(&self, payload: impl Serialize) -> Result<(), SessionSendError> {
-        let bytes =
-            json::to_vec(&payload).map_err(|source| SessionSendError::Serializing { source })?;
+        let bytes = encode(self.encoding, &payload)?;
 
         self.tx
             .unbounded_send(TungsteniteMessage::Binary(bytes))
-            .map_err(|source| SessionSendError::Sending { source })?;
+            .map_err(|source| SessionSendError {
+                kind: SessionSendErrorType::Sending,
+                source: Some(Box::new(source)),
+            })?;
 
         Ok(())
     }
@@ -181,7 +202,8 @@ impl Session {
         let seq = Arc::clone(&self.seq);
         let heartbeats = Arc::clone(&self.heartbeats);
 
-        let heartbeater = Heartbeater::new(heartbeats, interval, seq, self.tx.clone()).run();
+        let heartbeater =
+            Heartbeater::new(heartbeats, interval, seq, self.tx.clone(), self.encoding).run();
         let (fut, handle) = future::abortable(heartbeater);
 
         tokio::spawn(fut);
@@ -202,3 +224,17 @@ impl Drop for Session {
         self.stop_heartbeater();
     }
 }
+
+/// Serialize a payload in an encoding.
+pub fn encode(encoding: Encoding, payload: &impl Serialize) -> Result<Vec<u8>, SessionSendError> {
+    match encoding {
+        Encoding::Etf => etf::to_vec(payload).map_err(|source| SessionSendError {
+            kind: SessionSendErrorType::SerializingEtf,
+            source: Some(Box::new(source)),
+        }),
+        Encoding::Json => json::to_vec(payload).map_err(|source| SessionSendError {
+            kind: SessionSendErrorType::Serializing,
+            source: Some(Box::new(source)),
+        }),
+    }
+}
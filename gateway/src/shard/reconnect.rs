@@ -0,0 +1,127 @@
+use std::{
+    collections::hash_map::RandomState,
+    convert::TryFrom,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// Configuration for how a shard reconnects when its session can't be
+/// resumed.
+///
+/// The delay before each attempt starts at the [`initial_delay`] and doubles
+/// after each failed attempt, up to the [`max_delay`]. By default a shard
+/// waits 1 second at first, at most 128 seconds, never gives up, and doesn't
+/// add jitter.
+///
+/// Once a shard gives up reconnecting it stops, and its event streams end.
+///
+/// # Examples
+///
+/// Give up after 10 attempts, and add jitter so that many shards
+/// reconnecting at once don't retry in lockstep:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use twilight_gateway::shard::Reconnect;
+///
+/// let mut reconnect = Reconnect::new(Duration::from_secs(1), Duration::from_secs(60), 10);
+/// reconnect.jitter = true;
+/// ```
+///
+/// [`initial_delay`]: #structfield.initial_delay
+/// [`max_delay`]: #structfield.max_delay
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Reconnect {
+    /// The delay before the first attempt to reconnect.
+    pub initial_delay: Duration,
+    /// Whether each delay is randomly shortened by up to half.
+    ///
+    /// The default is `false`.
+    pub jitter: bool,
+    /// The number of attempts to reconnect before giving up, or `None` to
+    /// never give up.
+    pub max_attempts: Option<u32>,
+    /// The maximum delay between attempts to reconnect.
+    pub max_delay: Duration,
+}
+
+impl Reconnect {
+    /// Configure the delays between attempts to reconnect and the number of
+    /// attempts, if limited.
+    pub fn new(
+        initial_delay: Duration,
+        max_delay: Duration,
+        max_attempts: impl Into<Option<u32>>,
+    ) -> Self {
+        Self {
+            initial_delay,
+            jitter: false,
+            max_attempts: max_attempts.into(),
+            max_delay,
+        }
+    }
+
+    /// Delay to wait before the attempt after one with the given delay.
+    pub(crate) fn next_delay(&self, delay: Duration) -> Duration {
+        (delay * 2).min(self.max_delay)
+    }
+
+    /// Delay to actually wait for, applying jitter to a delay if enabled.
+    pub(crate) fn wait(&self, delay: Duration) -> Duration {
+        if !self.jitter {
+            return delay;
+        }
+
+        // Each random state is seeded differently, so hashing nothing gives a
+        // random number without depending on a random number generator.
+        let random = RandomState::new().build_hasher().finish();
+        let permille = u32::try_from(random % 1001).unwrap_or_default();
+
+        delay / 2 + delay * permille / 2000
+    }
+}
+
+impl Default for Reconnect {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1), Duration::from_secs(128), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reconnect;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, time::Duration};
+
+    assert_fields!(Reconnect: initial_delay, jitter, max_attempts, max_delay);
+    assert_impl_all!(Reconnect: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_delays() {
+        let reconnect = Reconnect::default();
+        assert_eq!(Duration::from_secs(1), reconnect.initial_delay);
+        assert!(reconnect.max_attempts.is_none());
+
+        let mut delay = reconnect.initial_delay;
+
+        for _ in 0..10 {
+            assert_eq!(delay, reconnect.wait(delay));
+            delay = reconnect.next_delay(delay);
+        }
+
+        assert_eq!(Duration::from_secs(128), delay);
+    }
+
+    #[test]
+    fn test_jitter() {
+        let mut reconnect = Reconnect::new(Duration::from_secs(2), Duration::from_secs(8), 3);
+        reconnect.jitter = true;
+
+        for _ in 0..100 {
+            let wait = reconnect.wait(Duration::from_secs(2));
+            assert!(wait >= Duration::from_secs(1));
+            assert!(wait <= Duration::from_secs(2));
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use futures::stream::{Stream, StreamExt};
+use serde_json::json;
+use twilight_gateway::{Event, Intents, Shard};
+use twilight_gateway_mock::{MockGateway, MockSession};
+
+/// Wait for an event matching a predicate, skipping other events.
+async fn wait_for(
+    events: &mut (impl Stream<Item = Event> + Unpin),
+    predicate: impl Fn(&Event) -> bool,
+) -> Event {
+    while let Some(event) = events.next().await {
+        if predicate(&event) {
+            return event;
+        }
+    }
+
+    panic!("events ended before a matching event was received");
+}
+
+/// Start a shard connected to a mock gateway, returning the shard and the
+/// server's side of its session after it identified.
+async fn start(gateway: &mut MockGateway) -> (Shard, MockSession) {
+    let mut shard = Shard::builder("token", Intents::GUILD_MESSAGES)
+        .gateway_url(Some(gateway.url().to_owned()))
+        .build();
+    let (started, session) = tokio::join!(shard.start(), gateway.accept());
+    started.unwrap();
+
+    let mut session = session.unwrap();
+    session.hello(41_250).unwrap();
+
+    let identify = session.next_command().await.unwrap();
+    assert_eq!(json!(2), identify["op"]);
+    assert_eq!(json!("Bot token"), identify["d"]["token"]);
+
+    (shard, session)
+}
+
+#[tokio::test]
+async fn test_shard_mock_dispatch() {
+    let mut gateway = MockGateway::bind().await.unwrap();
+    let (shard, mut session) = start(&mut gateway).await;
+    let mut events = shard.events();
+
+    session.ready("session").unwrap();
+    session
+        .dispatch("MESSAGE_DELETE", json!({"channel_id": "1", "id": "2"}))
+        .unwrap();
+
+    wait_for(&mut events, |event| matches!(event, Event::Ready(_))).await;
+    let event = wait_for(&mut events, |event| {
+        matches!(event, Event::MessageDelete(_))
+    })
+    .await;
+    assert!(matches!(event, Event::MessageDelete(delete) if delete.id.0 == 2));
+    assert_eq!(2, shard.info().unwrap().seq());
+
+    shard.shutdown();
+}
+
+#[tokio::test]
+async fn test_shard_mock_resume() {
+    let mut gateway = MockGateway::bind().await.unwrap();
+    let (shard, mut session) = start(&mut gateway).await;
+    let mut events = shard.events();
+
+    session.ready("session").unwrap();
+    session.dispatch("TYPING_START", json!({})).unwrap();
+    wait_for(&mut events, |event| matches!(event, Event::Ready(_))).await;
+
+    // Ask the shard to reconnect, which it does by resuming on a new
+    // connection.
+    session.reconnect().unwrap();
+
+    let mut resumed = gateway.accept().await.unwrap();
+    resumed.hello(41_250).unwrap();
+
+    let resume = resumed.next_command().await.unwrap();
+    assert_eq!(json!(6), resume["op"]);
+    assert_eq!(json!("session"), resume["d"]["session_id"]);
+
+    resumed.set_sequence(2);
+    resumed.resumed().unwrap();
+    wait_for(&mut events, |event| {
+        matches!(event, Event::ShardConnected(_))
+    })
+    .await;
+
+    shard.shutdown();
+}
+
+#[tokio::test]
+async fn test_shard_mock_close_fatal() {
+    let mut gateway = MockGateway::bind().await.unwrap();
+    let (shard, session) = start(&mut gateway).await;
+    let mut events = shard.events();
+
+    session.close(4004, "Authentication failed.").unwrap();
+
+    let event = wait_for(&mut events, |event| {
+        matches!(event, Event::ShardDisconnected(_))
+    })
+    .await;
+    assert!(
+        matches!(event, Event::ShardDisconnected(disconnected) if disconnected.code == Some(4004))
+    );
+
+    // An invalid token is fatal, so the shard doesn't reconnect and its
+    // events end.
+    while events.next().await.is_some() {}
+}
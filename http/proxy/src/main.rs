@@ -0,0 +1,225 @@
+//! HTTP proxy ratelimiting requests to the Discord REST API.
+//!
+//! Bots running in multiple processes with one token must share ratelimits.
+//! The proxy accepts requests from all of them, waits for a ticket from an
+//! in-memory ratelimiter for each, and forwards them to Discord, so that the
+//! buckets are enforced in one place.
+//!
+//! Clients send their requests over HTTP to the proxy with ratelimiting
+//! disabled, such as with `twilight-http`:
+//!
+//! ```rust,ignore
+//! use twilight_http::client::{Client, Proxy};
+//!
+//! let client = Client::builder()
+//!     .proxy(Proxy::all("http://localhost:3000")?)
+//!     .proxy_http(true)
+//!     .ratelimiter(None)
+//!     .build()?;
+//! ```
+//!
+//! # Configuration
+//!
+//! The proxy is configured with environment variables:
+//!
+//! - `DISCORD_TOKEN`: token to authorize all requests with, replacing the
+//!   `Authorization` header of the clients. Clients' headers are forwarded if
+//!   it isn't set.
+//! - `HOST`: IP address to listen on, defaulting to `0.0.0.0`.
+//! - `PORT`: port to listen on, defaulting to `3000`.
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+
+use hyper::{
+    header::{HeaderValue, AUTHORIZATION, HOST, TRANSFER_ENCODING},
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use reqwest::Client;
+use std::{
+    convert::{Infallible, TryFrom},
+    env,
+    error::Error,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::Arc,
+};
+use twilight_http_ratelimiting::{InMemoryRatelimiter, Path, RatelimitHeaders, Ratelimiter};
+
+/// Base URL of the Discord API that requests are forwarded to.
+const DISCORD_URL: &str = "https://discord.com";
+
+/// Port to listen on if `PORT` isn't set.
+const DEFAULT_PORT: u16 = 3000;
+
+type GenericError = Box<dyn Error + Send + Sync>;
+
+#[derive(Debug)]
+struct State {
+    client: Client,
+    ratelimiter: InMemoryRatelimiter,
+    token: Option<HeaderValue>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), GenericError> {
+    tracing_subscriber::fmt::init();
+
+    let host = match env::var("HOST") {
+        Ok(host) => host.parse()?,
+        Err(_) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+    };
+    let port = match env::var("PORT") {
+        Ok(port) => port.parse()?,
+        Err(_) => DEFAULT_PORT,
+    };
+    let token = match env::var("DISCORD_TOKEN") {
+        Ok(token) => Some(HeaderValue::from_str(&authorization(token))?),
+        Err(_) => None,
+    };
+
+    let state = Arc::new(State {
+        client: Client::new(),
+        ratelimiter: InMemoryRatelimiter::new(),
+        token,
+    });
+
+    let service = make_service_fn(move |_| {
+        let state = Arc::clone(&state);
+
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                handle(Arc::clone(&state), request)
+            }))
+        }
+    });
+
+    let address = SocketAddr::new(host, port);
+    tracing::info!("listening on {}", address);
+
+    Server::bind(&address).serve(service).await?;
+
+    Ok(())
+}
+
+/// Prefix a token with `Bot ` if it isn't a bot or bearer token already.
+fn authorization(token: String) -> String {
+    if token.starts_with("Bot ") || token.starts_with("Bearer ") {
+        token
+    } else {
+        format!("Bot {}", token)
+    }
+}
+
+/// Ratelimit path of a request, if its URI path is a known route of the API.
+///
+/// The `/api` and version prefix of the URI path is skipped.
+fn ratelimit_path(method: &Method, path: &str) -> Option<Path> {
+    let path = path.strip_prefix("/api").unwrap_or(path);
+    let path = match path.strip_prefix("/v") {
+        Some(rest) => rest.trim_start_matches(|c: char| c.is_ascii_digit()),
+        None => path,
+    };
+
+    Path::try_from((method.clone(), path)).ok()
+}
+
+async fn handle(state: Arc<State>, request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    match forward(&state, request).await {
+        Ok(response) => Ok(response),
+        Err(source) => {
+            tracing::warn!("forwarding request failed: {}", source);
+
+            let mut response = Response::new(Body::empty());
+            *response.status_mut() = StatusCode::BAD_GATEWAY;
+
+            Ok(response)
+        }
+    }
+}
+
+async fn forward(state: &State, request: Request<Body>) -> Result<Response<Body>, GenericError> {
+    let (parts, body) = request.into_parts();
+    let path = ratelimit_path(&parts.method, parts.uri.path());
+    let url = format!(
+        "{}{}",
+        DISCORD_URL,
+        parts.uri.path_and_query().map_or("/", |path| path.as_str())
+    );
+
+    let mut headers = parts.headers;
+    headers.remove(HOST);
+
+    if let Some(token) = &state.token {
+        headers.insert(AUTHORIZATION, token.clone());
+    }
+
+    let builder = state
+        .client
+        .request(parts.method, &url)
+        .headers(headers)
+        .body(hyper::body::to_bytes(body).await?);
+
+    let tx = if let Some(path) = path {
+        Some(state.ratelimiter.ticket(path).await.await?)
+    } else {
+        tracing::debug!("no ratelimit bucket for {}, not ratelimiting", url);
+
+        None
+    };
+
+    let response = builder.send().await?;
+
+    if let Some(tx) = tx {
+        let _ = tx.send(RatelimitHeaders::try_from(response.headers()).ok());
+    }
+
+    let status = response.status();
+    let mut headers = response.headers().clone();
+    // The body is buffered, so it isn't chunked anymore.
+    headers.remove(TRANSFER_ENCODING);
+
+    let mut forwarded = Response::new(Body::from(response.bytes().await?));
+    *forwarded.status_mut() = status;
+    *forwarded.headers_mut() = headers;
+
+    Ok(forwarded)
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::Method;
+    use twilight_http_ratelimiting::Path;
+
+    #[test]
+    fn test_authorization() {
+        assert_eq!("Bot abc", super::authorization("abc".to_owned()));
+        assert_eq!("Bot abc", super::authorization("Bot abc".to_owned()));
+        assert_eq!("Bearer abc", super::authorization("Bearer abc".to_owned()));
+    }
+
+    #[test]
+    fn test_ratelimit_path() {
+        assert_eq!(
+            Some(Path::ChannelsIdMessages(1)),
+            super::ratelimit_path(&Method::POST, "/api/v8/channels/1/messages")
+        );
+        assert_eq!(
+            Some(Path::ChannelsIdMessagesId(Method::PATCH, 1)),
+            super::ratelimit_path(&Method::PATCH, "/api/channels/1/messages/2")
+        );
+        assert_eq!(
+            Some(Path::GatewayBot),
+            super::ratelimit_path(&Method::GET, "/gateway/bot")
+        );
+        assert_eq!(None, super::ratelimit_path(&Method::GET, "/api/v8/unknown"));
+    }
+}
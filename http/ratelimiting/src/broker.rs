@@ -0,0 +1,222 @@
+use crate::{headers::RatelimitHeaders, path::Path, Ratelimiter, TicketReceiver};
+use futures_channel::oneshot;
+use std::{fmt::Debug, future::Future, pin::Pin, sync::Arc, time::Duration};
+
+/// Source of truth for ratelimits that's shared by multiple processes, such as
+/// a Redis instance or a ratelimiting service.
+///
+/// Brokers are a simpler interface to implement than [`Ratelimiter`]: a
+/// request first [`acquire`]s a path, and once its response is received the
+/// ratelimit headers are given to [`update`]. Wrap a broker in a
+/// [`BrokerRatelimiter`] to use it as the ratelimiter of the HTTP client.
+///
+/// # Examples
+///
+/// A broker that doesn't ratelimit, but logs requests:
+///
+/// ```rust
+/// use std::{future::{self, Future}, pin::Pin, time::Duration};
+/// use twilight_http_ratelimiting::{Broker, BrokerRatelimiter, Path, RatelimitHeaders};
+///
+/// #[derive(Debug)]
+/// struct Logger;
+///
+/// impl Broker for Logger {
+///     fn acquire<'a>(&'a self, path: &'a Path) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+///         println!("sending request to {:?}", path);
+///
+///         Box::pin(future::ready(()))
+///     }
+///
+///     fn update<'a>(
+///         &'a self,
+///         path: &'a Path,
+///         headers: Option<RatelimitHeaders>,
+///     ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+///         println!("received headers for {:?}: {:?}", path, headers);
+///
+///         Box::pin(future::ready(()))
+///     }
+///
+///     fn time_until_available<'a>(
+///         &'a self,
+///         _: &'a Path,
+///     ) -> Pin<Box<dyn Future<Output = Option<Duration>> + Send + 'a>> {
+///         Box::pin(future::ready(None))
+///     }
+/// }
+///
+/// let ratelimiter = BrokerRatelimiter::new(Logger);
+/// ```
+///
+/// [`BrokerRatelimiter`]: struct.BrokerRatelimiter.html
+/// [`Ratelimiter`]: trait.Ratelimiter.html
+/// [`acquire`]: #tymethod.acquire
+/// [`update`]: #tymethod.update
+pub trait Broker: Debug + Send + Sync + 'static {
+    /// Wait until a request to a path can be sent.
+    ///
+    /// This should also wait for the global ratelimit. Every call is followed
+    /// by a call to [`update`] with the same path.
+    ///
+    /// [`update`]: #tymethod.update
+    fn acquire<'a>(&'a self, path: &'a Path) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+    /// Update the ratelimit of a path with the headers of the response to a
+    /// request.
+    ///
+    /// The headers are `None` if the request failed or they couldn't be
+    /// parsed, in which case the request should be released without updating
+    /// the ratelimit.
+    fn update<'a>(
+        &'a self,
+        path: &'a Path,
+        headers: Option<RatelimitHeaders>,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+    /// Estimate the time until a request to a path can be sent without being
+    /// ratelimited.
+    ///
+    /// Refer to [`Ratelimiter::time_until_available`] for more information.
+    ///
+    /// [`Ratelimiter::time_until_available`]: trait.Ratelimiter.html#tymethod.time_until_available
+    fn time_until_available<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Pin<Box<dyn Future<Output = Option<Duration>> + Send + 'a>>;
+}
+
+/// Ratelimiter over a [`Broker`].
+///
+/// A task is spawned for each request to give the headers of its response to
+/// the broker.
+///
+/// [`Broker`]: trait.Broker.html
+#[derive(Debug)]
+pub struct BrokerRatelimiter<B>(Arc<B>);
+
+impl<B: Broker> BrokerRatelimiter<B> {
+    /// Create a new ratelimiter over a broker.
+    pub fn new(broker: B) -> Self {
+        Self(Arc::new(broker))
+    }
+
+    /// Return an immutable reference to the broker.
+    pub fn broker(&self) -> &B {
+        &self.0
+    }
+}
+
+impl<B: Broker> Clone for BrokerRatelimiter<B> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<B: Broker> Ratelimiter for BrokerRatelimiter<B> {
+    fn ticket<'a>(
+        &'a self,
+        path: Path,
+    ) -> Pin<Box<dyn Future<Output = TicketReceiver> + Send + 'a>> {
+        Box::pin(ticket(Arc::clone(&self.0), path))
+    }
+
+    fn time_until_available<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Pin<Box<dyn Future<Output = Option<Duration>> + Send + 'a>> {
+        self.0.time_until_available(path)
+    }
+}
+
+async fn ticket<B: Broker>(broker: Arc<B>, path: Path) -> TicketReceiver {
+    broker.acquire(&path).await;
+
+    let (tx, rx) = oneshot::channel();
+    let (headers_tx, headers_rx) = oneshot::channel();
+    let _ = tx.send(headers_tx);
+
+    tokio::spawn(async move {
+        // The sender being dropped means that the request failed.
+        let headers = headers_rx.await.ok().flatten();
+
+        broker.update(&path, headers).await;
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Broker, BrokerRatelimiter};
+    use crate::{Path, RatelimitHeaders, Ratelimiter};
+    use futures_util::lock::Mutex;
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{
+        fmt::Debug,
+        future::{self, Future},
+        pin::Pin,
+        time::Duration,
+    };
+    use tokio::time;
+
+    assert_obj_safe!(Broker);
+
+    #[derive(Debug, Default)]
+    struct Recorder(Mutex<Vec<(Path, Option<bool>)>>);
+
+    impl Broker for Recorder {
+        fn acquire<'a>(&'a self, _: &'a Path) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+            Box::pin(future::ready(()))
+        }
+
+        fn update<'a>(
+            &'a self,
+            path: &'a Path,
+            headers: Option<RatelimitHeaders>,
+        ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+            Box::pin(async move {
+                let global = headers.as_ref().map(RatelimitHeaders::global);
+                self.0.lock().await.push((path.clone(), global));
+            })
+        }
+
+        fn time_until_available<'a>(
+            &'a self,
+            _: &'a Path,
+        ) -> Pin<Box<dyn Future<Output = Option<Duration>> + Send + 'a>> {
+            Box::pin(future::ready(Some(Duration::from_secs(1))))
+        }
+    }
+
+    assert_impl_all!(
+        BrokerRatelimiter<Recorder>: Clone,
+        Debug,
+        Ratelimiter,
+        Send,
+        Sync
+    );
+
+    #[tokio::test]
+    async fn test_ticket_updates_broker() {
+        let ratelimiter = BrokerRatelimiter::new(Recorder::default());
+        let path = Path::ChannelsIdMessages(1);
+
+        let tx = ratelimiter.ticket(path.clone()).await.await.unwrap();
+        tx.send(Some(RatelimitHeaders::None)).unwrap();
+        let tx = ratelimiter.ticket(path.clone()).await.await.unwrap();
+        drop(tx);
+
+        // Let the spawned tasks update the broker.
+        time::delay_for(Duration::from_millis(10)).await;
+
+        assert_eq!(
+            vec![(path.clone(), Some(false)), (path.clone(), None)],
+            *ratelimiter.broker().0.lock().await
+        );
+        assert_eq!(
+            Some(Duration::from_secs(1)),
+            ratelimiter.time_until_available(&path).await
+        );
+    }
+}
@@ -1,11 +1,12 @@
 use super::error::{RatelimitError, RatelimitResult};
-use reqwest::header::{HeaderMap, HeaderValue};
+use http::header::{HeaderMap, HeaderValue};
 use std::convert::TryFrom;
 
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum RatelimitHeaders {
     GlobalLimited {
+        // how long until the global ratelimit resets in ms
         reset_after: u64,
     },
     None,
@@ -71,7 +72,7 @@ impl TryFrom<&'_ HeaderMap<HeaderValue>> for RatelimitHeaders {
                     Err(why)
                 } else if map.contains_key("x-ratelimit-global") {
                     Ok(Self::GlobalLimited {
-                        reset_after: header_int(map, "x-ratelimit-reset-after")?,
+                        reset_after: global_reset_after(map)?,
                     })
                 } else {
                     Ok(Self::None)
@@ -106,6 +107,21 @@ fn parse_map(map: &HeaderMap<HeaderValue>) -> RatelimitResult<RatelimitHeaders>
     })
 }
 
+/// Parse how long until the global ratelimit resets in milliseconds.
+///
+/// Global ratelimit responses may only have a `Retry-After` header instead of
+/// the `X-RateLimit-Reset-After` header. Both are in seconds.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn global_reset_after(map: &HeaderMap<HeaderValue>) -> RatelimitResult<u64> {
+    let reset_after = match header_float(map, "x-ratelimit-reset-after") {
+        Ok(reset_after) => reset_after,
+        Err(RatelimitError::HeaderMissing { .. }) => header_float(map, "retry-after")?,
+        Err(source) => return Err(source),
+    };
+
+    Ok((reset_after * 1000.).ceil() as u64)
+}
+
 fn header_bool(map: &HeaderMap<HeaderValue>, name: &'static str) -> RatelimitResult<bool> {
     let value = map
         .get(name)
@@ -193,3 +209,75 @@ fn header_str<'a>(map: &'a HeaderMap<HeaderValue>, name: &'static str) -> Rateli
 
     Ok(text)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RatelimitHeaders;
+    use http::header::{HeaderMap, HeaderValue};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_global_retry_after() {
+        let mut map = HeaderMap::new();
+        map.insert("retry-after", HeaderValue::from_static("65"));
+        map.insert("x-ratelimit-global", HeaderValue::from_static("true"));
+
+        assert!(matches!(
+            RatelimitHeaders::try_from(&map),
+            Ok(RatelimitHeaders::GlobalLimited { reset_after: 65000 })
+        ));
+    }
+
+    #[test]
+    fn test_global_reset_after() {
+        let mut map = HeaderMap::new();
+        map.insert("retry-after", HeaderValue::from_static("2"));
+        map.insert("x-ratelimit-global", HeaderValue::from_static("true"));
+        map.insert("x-ratelimit-reset-after", HeaderValue::from_static("1.5"));
+
+        assert!(matches!(
+            RatelimitHeaders::try_from(&map),
+            Ok(RatelimitHeaders::GlobalLimited { reset_after: 1500 })
+        ));
+    }
+
+    #[test]
+    fn test_present() {
+        let mut map = HeaderMap::new();
+        map.insert("x-ratelimit-bucket", HeaderValue::from_static("abcd"));
+        map.insert("x-ratelimit-limit", HeaderValue::from_static("10"));
+        map.insert("x-ratelimit-remaining", HeaderValue::from_static("9"));
+        map.insert(
+            "x-ratelimit-reset",
+            HeaderValue::from_static("1470173023.123"),
+        );
+        map.insert("x-ratelimit-reset-after", HeaderValue::from_static("64.57"));
+
+        match RatelimitHeaders::try_from(&map) {
+            Ok(RatelimitHeaders::Present {
+                bucket,
+                global,
+                limit,
+                remaining,
+                reset,
+                reset_after,
+            }) => {
+                assert_eq!(Some("abcd"), bucket.as_deref());
+                assert!(!global);
+                assert_eq!(10, limit);
+                assert_eq!(9, remaining);
+                assert_eq!(1_470_173_023_123, reset);
+                assert_eq!(64570, reset_after);
+            }
+            other => panic!("unexpected headers: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_none() {
+        assert!(matches!(
+            RatelimitHeaders::try_from(&HeaderMap::new()),
+            Ok(RatelimitHeaders::None)
+        ));
+    }
+}
@@ -1,5 +1,5 @@
-use super::{headers::RatelimitHeaders, GlobalLockPair};
-use crate::routing::Path;
+use super::GlobalLockPair;
+use crate::{headers::RatelimitHeaders, path::Path, TicketSender};
 use futures_channel::{
     mpsc::{self, UnboundedReceiver, UnboundedSender},
     oneshot::{self, Sender},
@@ -25,7 +25,6 @@ pub enum TimeRemaining {
 #[derive(Debug)]
 pub struct Bucket {
     pub limit: AtomicU64,
-    pub path: Path,
     pub queue: BucketQueue,
     pub remaining: AtomicU64,
     pub reset_after: AtomicU64,
@@ -33,13 +32,12 @@ pub struct Bucket {
 }
 
 impl Bucket {
-    pub fn new(path: Path) -> Self {
+    pub fn new() -> Self {
         Self {
-            limit: AtomicU64::new(u64::max_value()),
-            path,
+            limit: AtomicU64::new(u64::MAX),
             queue: BucketQueue::default(),
-            remaining: AtomicU64::new(u64::max_value()),
-            reset_after: AtomicU64::new(u64::max_value()),
+            remaining: AtomicU64::new(u64::MAX),
+            reset_after: AtomicU64::new(u64::MAX),
             started_at: Mutex::new(None),
         }
     }
@@ -57,18 +55,14 @@ impl Bucket {
     }
 
     pub async fn time_remaining(&self) -> TimeRemaining {
-        let reset_after = self.reset_after();
-        let started_at = match *self.started_at.lock().await {
-            Some(v) => v,
-            None => return TimeRemaining::NotStarted,
-        };
-        let elapsed = started_at.elapsed();
+        let reset_after = Duration::from_millis(self.reset_after());
+        let started_at = *self.started_at.lock().await;
 
-        if elapsed > Duration::from_millis(reset_after) {
-            return TimeRemaining::Finished;
+        match started_at.map(|started_at| reset_after.checked_sub(started_at.elapsed())) {
+            Some(Some(remaining)) => TimeRemaining::Some(remaining),
+            Some(None) => TimeRemaining::Finished,
+            None => TimeRemaining::NotStarted,
         }
-
-        TimeRemaining::Some(Duration::from_millis(reset_after) - elapsed)
     }
 
     pub async fn try_reset(&self) -> bool {
@@ -98,7 +92,7 @@ impl Bucket {
         }
 
         if let Some((limit, remaining, reset_after)) = ratelimits {
-            if bucket_limit != limit && bucket_limit == u64::max_value() {
+            if bucket_limit != limit && bucket_limit == u64::MAX {
                 self.reset_after.store(reset_after, Ordering::SeqCst);
                 self.limit.store(limit, Ordering::SeqCst);
             }
@@ -112,28 +106,22 @@ impl Bucket {
 
 #[derive(Debug)]
 pub struct BucketQueue {
-    rx: Mutex<UnboundedReceiver<Sender<Sender<Option<RatelimitHeaders>>>>>,
-    tx: UnboundedSender<Sender<Sender<Option<RatelimitHeaders>>>>,
+    rx: Mutex<UnboundedReceiver<Sender<TicketSender>>>,
+    tx: UnboundedSender<Sender<TicketSender>>,
 }
 
 impl BucketQueue {
-    pub fn push(&self, tx: Sender<Sender<Option<RatelimitHeaders>>>) {
+    pub fn push(&self, tx: Sender<TicketSender>) {
         let _ = self.tx.unbounded_send(tx);
     }
 
-    pub async fn pop(
-        &self,
-        timeout_duration: Duration,
-    ) -> Option<Sender<Sender<Option<RatelimitHeaders>>>> {
+    pub async fn pop(&self, timeout_duration: Duration) -> Option<Sender<TicketSender>> {
         let mut rx = self.rx.lock().await;
 
-        match timeout(timeout_duration, StreamExt::next(&mut *rx))
+        timeout(timeout_duration, StreamExt::next(&mut *rx))
             .await
             .ok()
-        {
-            Some(x) => x,
-            None => None,
-        }
+            .flatten()
     }
 }
 
@@ -192,7 +180,7 @@ impl BucketQueueTask {
                 // - None was sent through the channel (request aborted)
                 // - channel was closed
                 // - timeout reached
-                Ok(Err(_)) | Err(_) | Ok(Ok(None)) => {
+                Ok(Err(_) | Ok(None)) | Err(_) => {
                     tracing::debug!(parent: &span, "receiver timed out");
                 }
             }
@@ -240,7 +228,7 @@ impl BucketQueueTask {
         drop(lock);
     }
 
-    async fn next(&self) -> Option<Sender<Sender<Option<RatelimitHeaders>>>> {
+    async fn next(&self) -> Option<Sender<TicketSender>> {
         tracing::debug!(path=?self.path, "starting to get next in queue");
 
         self.wait_if_needed().await;
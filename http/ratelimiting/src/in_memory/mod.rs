@@ -1,19 +1,13 @@
-pub mod error;
-
 mod bucket;
-mod headers;
-
-pub use self::{
-    error::{RatelimitError, RatelimitResult},
-    headers::RatelimitHeaders,
-};
 
-use crate::routing::Path;
-use bucket::{Bucket, BucketQueueTask, TimeRemaining};
-use futures_channel::oneshot::{self, Receiver, Sender};
+use self::bucket::{Bucket, BucketQueueTask, TimeRemaining};
+use crate::{path::Path, Ratelimiter, TicketReceiver, TicketSender};
+use futures_channel::oneshot::{self, Sender};
 use futures_util::lock::Mutex;
 use std::{
     collections::hash_map::{Entry, HashMap},
+    future::Future,
+    pin::Pin,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
@@ -26,7 +20,7 @@ use std::{
 /// is in place by, in turn, waiting for a guard, and then each immediately
 /// dropping it.
 #[derive(Debug, Default)]
-struct GlobalLockPair(Mutex<()>, AtomicBool);
+pub(super) struct GlobalLockPair(pub(super) Mutex<()>, AtomicBool);
 
 impl GlobalLockPair {
     pub fn lock(&self) {
@@ -42,13 +36,18 @@ impl GlobalLockPair {
     }
 }
 
+/// In-process ratelimiter keeping the buckets of paths in memory.
+///
+/// This is the default ratelimiter of the HTTP client. It only knows about
+/// the requests made through it, so processes sharing a token should send
+/// their requests through one instance of it, such as in an HTTP proxy.
 #[derive(Clone, Debug, Default)]
-pub struct Ratelimiter {
+pub struct InMemoryRatelimiter {
     buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
     global: Arc<GlobalLockPair>,
 }
 
-impl Ratelimiter {
+impl InMemoryRatelimiter {
     /// Create a new ratelimiter.
     ///
     /// Most users won't need to use this directly. If you're creating your own
@@ -57,7 +56,7 @@ impl Ratelimiter {
         Self::default()
     }
 
-    pub async fn get(&self, path: Path) -> Receiver<Sender<Option<RatelimitHeaders>>> {
+    async fn get(&self, path: Path) -> TicketReceiver {
         tracing::debug!("getting bucket for path: {:?}", path);
 
         let (tx, rx) = oneshot::channel();
@@ -78,12 +77,7 @@ impl Ratelimiter {
         rx
     }
 
-    /// Provide an estimate for the time left until a path can be used
-    /// without being ratelimited.
-    ///
-    /// This method is not guaranteed to be accurate and may return
-    /// None if either no ratelimit is known or buckets are remaining.
-    pub async fn time_until_available(&self, path: &Path) -> Option<Duration> {
+    async fn time_remaining(&self, path: &Path) -> Option<Duration> {
         let buckets = self.buckets.lock().await;
         match buckets.get(path)?.time_remaining().await {
             TimeRemaining::Finished | TimeRemaining::NotStarted => None,
@@ -91,11 +85,7 @@ impl Ratelimiter {
         }
     }
 
-    async fn entry(
-        &self,
-        path: Path,
-        tx: Sender<Sender<Option<RatelimitHeaders>>>,
-    ) -> (Arc<Bucket>, bool) {
+    async fn entry(&self, path: Path, tx: Sender<TicketSender>) -> (Arc<Bucket>, bool) {
         // nb: not realisically point of contention
         let mut buckets = self.buckets.lock().await;
 
@@ -107,11 +97,11 @@ impl Ratelimiter {
                 bucket.queue.push(tx);
                 tracing::debug!("added request into bucket queue: {:?}", path);
 
-                (Arc::clone(&bucket), false)
+                (Arc::clone(bucket), false)
             }
             Entry::Vacant(entry) => {
                 tracing::debug!("making new bucket for path: {:?}", path);
-                let bucket = Bucket::new(path.clone());
+                let bucket = Bucket::new();
                 bucket.queue.push(tx);
 
                 let bucket = Arc::new(bucket);
@@ -122,3 +112,40 @@ impl Ratelimiter {
         }
     }
 }
+
+impl Ratelimiter for InMemoryRatelimiter {
+    fn ticket<'a>(
+        &'a self,
+        path: Path,
+    ) -> Pin<Box<dyn Future<Output = TicketReceiver> + Send + 'a>> {
+        Box::pin(self.get(path))
+    }
+
+    fn time_until_available<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Pin<Box<dyn Future<Output = Option<Duration>> + Send + 'a>> {
+        Box::pin(self.time_remaining(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InMemoryRatelimiter;
+    use crate::{Path, RatelimitHeaders, Ratelimiter};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(InMemoryRatelimiter: Clone, Debug, Default, Ratelimiter, Send, Sync);
+
+    #[tokio::test]
+    async fn test_ticket() {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let path = Path::ChannelsIdMessages(1);
+
+        let tx = ratelimiter.ticket(path.clone()).await.await.unwrap();
+        tx.send(Some(RatelimitHeaders::None)).unwrap();
+
+        assert!(ratelimiter.time_until_available(&path).await.is_none());
+    }
+}
@@ -0,0 +1,102 @@
+//! Ratelimiting functionality for HTTP requests to the Discord API.
+//!
+//! Discord ratelimits requests in buckets, which are shared by requests to
+//! the same [`Path`], and globally across all requests made with a token.
+//! The HTTP client asks a [`Ratelimiter`] for a ticket before sending a
+//! request, and gives it the ratelimit headers of the response afterwards.
+//!
+//! Ratelimiters must point to the same source of truth for all of the
+//! processes using a token. If you run multiple processes, then either send
+//! their requests through an HTTP proxy that ratelimits them centrally, or
+//! implement the [`Ratelimiter`] trait over a broker.
+//!
+//! # Provided ratelimiters
+//!
+//! The [`InMemoryRatelimiter`] keeps the buckets in the memory of the current
+//! process. It's used by the HTTP client by default.
+//!
+//! The [`BrokerRatelimiter`] acquires paths from and updates them in a
+//! [`Broker`], which can be implemented over a Redis instance or a service to
+//! share the buckets between processes.
+//!
+//! [`Broker`]: trait.Broker.html
+//! [`BrokerRatelimiter`]: struct.BrokerRatelimiter.html
+//! [`InMemoryRatelimiter`]: struct.InMemoryRatelimiter.html
+//! [`Path`]: enum.Path.html
+//! [`Ratelimiter`]: trait.Ratelimiter.html
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    nonstandard_style,
+    rust_2018_idioms,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
+
+pub mod error;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod broker;
+mod headers;
+#[cfg(not(target_arch = "wasm32"))]
+mod in_memory;
+mod path;
+
+pub use self::{
+    error::{RatelimitError, RatelimitResult},
+    headers::RatelimitHeaders,
+    path::{Path, PathParseError},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::{
+    broker::{Broker, BrokerRatelimiter},
+    in_memory::InMemoryRatelimiter,
+};
+
+use futures_channel::oneshot::{Receiver, Sender};
+use std::{fmt::Debug, future::Future, pin::Pin, time::Duration};
+
+/// Sender for the ratelimit headers of the response to a request, or `None`
+/// if they couldn't be parsed.
+pub type TicketSender = Sender<Option<RatelimitHeaders>>;
+
+/// Receiver of a ticket, resolving to a [`TicketSender`] once the request may
+/// be sent.
+///
+/// [`TicketSender`]: type.TicketSender.html
+pub type TicketReceiver = Receiver<TicketSender>;
+
+/// Ratelimiter for the HTTP client to request tickets from before sending
+/// requests.
+///
+/// This will usually only need to be implemented when requests from multiple
+/// processes are ratelimited by a broker. Refer to the [crate-level]
+/// documentation for more information.
+///
+/// [crate-level]: ./index.html
+pub trait Ratelimiter: Debug + Send + Sync {
+    /// Request a ticket to send a request to a path.
+    ///
+    /// The returned receiver must resolve only when the request can be sent,
+    /// to a sender for the ratelimit headers of its response. Sending `None`
+    /// or dropping the sender releases the ticket without updating the
+    /// ratelimit.
+    fn ticket<'a>(
+        &'a self,
+        path: Path,
+    ) -> Pin<Box<dyn Future<Output = TicketReceiver> + Send + 'a>>;
+
+    /// Estimate the time until a request to a path can be sent without being
+    /// ratelimited.
+    ///
+    /// This isn't guaranteed to be accurate, and resolves to `None` if no
+    /// ratelimit of the path is known or requests to it are remaining.
+    fn time_until_available<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Pin<Box<dyn Future<Output = Option<Duration>> + Send + 'a>>;
+}
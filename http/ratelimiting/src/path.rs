@@ -0,0 +1,407 @@
+use http::Method;
+use std::{
+    convert::TryFrom,
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PathParseError {
+    /// The ID couldn't be parsed as an integer.
+    IntegerParsing {
+        /// Additional information about the parsing failure.
+        source: ParseIntError,
+    },
+    /// When parsing into a [`Path::ChannelsIdMessagesId`] variant, the method
+    /// must also be specified via its `TryFrom` impl.
+    ///
+    /// [`Path::ChannelsIdMessageId`]: enum.Path.html#variant.ChannelsIdMessagesId
+    MessageIdWithoutMethod {
+        /// The ID of the channel.
+        channel_id: u64,
+    },
+    /// A static path for the provided path string wasn't found.
+    NoMatch,
+}
+
+impl From<ParseIntError> for PathParseError {
+    fn from(source: ParseIntError) -> Self {
+        Self::IntegerParsing { source }
+    }
+}
+
+impl Display for PathParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::IntegerParsing { .. } => f.write_str("An ID in a segment was invalid"),
+            Self::MessageIdWithoutMethod { .. } => {
+                f.write_str("A message path was detected but the method wasn't given")
+            }
+            Self::NoMatch => f.write_str("There was no matched path"),
+        }
+    }
+}
+
+impl StdError for PathParseError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::IntegerParsing { source } => Some(source),
+            Self::MessageIdWithoutMethod { .. } | Self::NoMatch => None,
+        }
+    }
+}
+
+/// An enum representing a path, most useful for ratelimiting implementations.
+// If adding to this enum, be sure to add to the `TryFrom` impl.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Path {
+    /// Operating on an application's global commands.
+    ApplicationsIdCommands(u64),
+    /// Operating on an application's individual global command.
+    ApplicationsIdCommandsId(u64),
+    /// Operating on an application's commands in a guild.
+    ApplicationsIdGuildsIdCommands(u64),
+    /// Operating on an application's individual command in a guild.
+    ApplicationsIdGuildsIdCommandsId(u64),
+    /// Operating on a channel.
+    ChannelsId(u64),
+    /// Operating on a channel's invites.
+    ChannelsIdInvites(u64),
+    /// Operating on a channel's messages.
+    ChannelsIdMessages(u64),
+    /// Operating on a channel's messages by bulk deleting.
+    ChannelsIdMessagesBulkDelete(u64),
+    /// Operating on an individual channel's message.
+    ChannelsIdMessagesId(Method, u64),
+    /// Crossposting an individual channel's message.
+    ChannelsIdMessagesIdCrosspost(u64),
+    /// Operating on the threads started from an individual channel's message.
+    ChannelsIdMessagesIdThreads(u64),
+    /// Operating on an individual channel's message's reactions.
+    ChannelsIdMessagesIdReactions(u64),
+    /// Operating on an individual channel's message's reactions while
+    /// specifying the user ID and emoji type.
+    ChannelsIdMessagesIdReactionsUserIdType(u64),
+    /// Operating on a channel's permission overwrites by ID.
+    ChannelsIdPermissionsOverwriteId(u64),
+    /// Operating on a channel's pins.
+    ChannelsIdPins(u64),
+    /// Operating on a channel's individual pinned message.
+    ChannelsIdPinsMessageId(u64),
+    /// Operating on a thread's members.
+    ChannelsIdThreadMembers(u64),
+    /// Operating on a thread's individual member.
+    ChannelsIdThreadMembersId(u64),
+    /// Operating on a channel's threads.
+    ChannelsIdThreads(u64),
+    /// Operating on a channel's archived private threads.
+    ChannelsIdThreadsArchivedPrivate(u64),
+    /// Operating on a channel's archived public threads.
+    ChannelsIdThreadsArchivedPublic(u64),
+    /// Operating on a channel's typing indicator.
+    ChannelsIdTyping(u64),
+    /// Operating on a channel's webhooks.
+    ChannelsIdWebhooks(u64),
+    /// Operating on a channel's followers.
+    ChannelsIdFollowers(u64),
+    /// Operating on a channel's archived private threads that the current
+    /// user has joined.
+    ChannelsIdUsersMeThreadsArchivedPrivate(u64),
+    /// Operating with the gateway information.
+    Gateway,
+    /// Operating with the gateway information tailored to the current user.
+    GatewayBot,
+    /// Operating on the guild resource.
+    Guilds,
+    /// Operating on one of user's guilds.
+    GuildsId(u64),
+    GuildsIdBans(u64),
+    GuildsIdBansId(u64),
+    GuildsIdAuditLogs(u64),
+    /// Operating on a guild's auto moderation rules.
+    GuildsIdAutoModerationRules(u64),
+    /// Operating on an individual auto moderation rule of a guild.
+    GuildsIdAutoModerationRulesId(u64),
+    GuildsIdBansUserId(u64),
+    GuildsIdChannels(u64),
+    GuildsIdWidget(u64),
+    GuildsIdEmojis(u64),
+    GuildsIdEmojisId(u64),
+    GuildsIdIntegrations(u64),
+    GuildsIdIntegrationsId(u64),
+    GuildsIdIntegrationsIdSync(u64),
+    GuildsIdInvites(u64),
+    GuildsIdMembers(u64),
+    GuildsIdMembersId(u64),
+    GuildsIdMembersIdRolesId(u64),
+    GuildsIdMembersMeNick(u64),
+    GuildsIdPreview(u64),
+    GuildsIdPrune(u64),
+    GuildsIdRegions(u64),
+    GuildsIdRoles(u64),
+    GuildsIdRolesId(u64),
+    /// Operating on a guild's scheduled events.
+    GuildsIdScheduledEvents(u64),
+    /// Operating on a guild's individual scheduled event.
+    GuildsIdScheduledEventsId(u64),
+    /// Operating on the users subscribed to a guild's scheduled event.
+    GuildsIdScheduledEventsIdUsers(u64),
+    /// Operating on a guild's active threads.
+    /// Operating on a guild's stickers.
+    GuildsIdStickers(u64),
+    /// Operating on an individual sticker of a guild.
+    GuildsIdStickersId(u64),
+    GuildsIdThreadsActive(u64),
+    GuildsIdVanityUrl(u64),
+    GuildsIdWebhooks(u64),
+    /// Operating on an interaction's callback.
+    InteractionsIdTokenCallback(u64),
+    InvitesCode,
+    /// Operating on stage instances.
+    StageInstances,
+    /// Operating on the stage instance of a channel.
+    StageInstancesId(u64),
+    /// Operating on the packs of official stickers.
+    StickerPacks,
+    /// Operating on an individual sticker.
+    StickersId,
+    UsersId,
+    OauthApplicationsMe,
+    UsersIdConnections,
+    UsersIdChannels,
+    /// Operating on the state of a guild that the user is in.
+    UsersIdGuilds,
+    /// Operating on the state of a guild that the user is in.
+    UsersIdGuildsId,
+    /// Operating on the voice regions available to the current user.
+    VoiceRegions,
+    /// Operating on a webhook.
+    WebhooksId(u64),
+    /// Operating on a message created by a webhook, such as the followup
+    /// message of an interaction.
+    WebhooksIdTokenMessagesId(u64),
+}
+
+impl FromStr for Path {
+    type Err = PathParseError;
+
+    /// Parses a string into a path.
+    ///
+    /// The string *may* start with a slash (`/`), which will be ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_http_ratelimiting::Path;
+    /// use std::str::FromStr;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(Path::VoiceRegions, Path::from_str("/voice/regions")?);
+    /// assert_eq!(
+    ///     Path::ChannelsIdMessages(123),
+    ///     Path::from_str("channels/123/messages")?,
+    /// );
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::enum_glob_use, clippy::too_many_lines)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Path::*;
+
+        let skip = usize::from(s.starts_with('/'));
+
+        let parts = s.split('/').skip(skip).collect::<Vec<&str>>();
+
+        Ok(match parts.as_slice() {
+            ["applications", id, "commands"] => ApplicationsIdCommands(id.parse()?),
+            ["applications", id, "commands", _] => ApplicationsIdCommandsId(id.parse()?),
+            ["applications", id, "guilds", _, "commands"] => {
+                ApplicationsIdGuildsIdCommands(id.parse()?)
+            }
+            ["applications", id, "guilds", _, "commands", _] => {
+                ApplicationsIdGuildsIdCommandsId(id.parse()?)
+            }
+            ["channels", id] => ChannelsId(id.parse()?),
+            ["channels", id, "followers"] => ChannelsIdFollowers(id.parse()?),
+            ["channels", id, "invites"] => ChannelsIdInvites(id.parse()?),
+            ["channels", id, "messages"] => ChannelsIdMessages(id.parse()?),
+            ["channels", id, "messages", _] => {
+                return Err(PathParseError::MessageIdWithoutMethod {
+                    channel_id: id.parse()?,
+                });
+            }
+            ["channels", id, "messages", _, "crosspost"] => {
+                ChannelsIdMessagesIdCrosspost(id.parse()?)
+            }
+            ["channels", id, "messages", _, "threads"] => ChannelsIdMessagesIdThreads(id.parse()?),
+            ["channels", id, "messages", _, "reactions"] => {
+                ChannelsIdMessagesIdReactions(id.parse()?)
+            }
+            ["channels", id, "messages", _, "reactions", _, _] => {
+                ChannelsIdMessagesIdReactionsUserIdType(id.parse()?)
+            }
+            ["channels", id, "permissions", _] => ChannelsIdPermissionsOverwriteId(id.parse()?),
+            ["channels", id, "pins"] => ChannelsIdPins(id.parse()?),
+            ["channels", id, "pins", _] => ChannelsIdPinsMessageId(id.parse()?),
+            ["channels", id, "thread-members"] => ChannelsIdThreadMembers(id.parse()?),
+            ["channels", id, "thread-members", _] => ChannelsIdThreadMembersId(id.parse()?),
+            ["channels", id, "threads"] => ChannelsIdThreads(id.parse()?),
+            ["channels", id, "threads", "archived", "private"] => {
+                ChannelsIdThreadsArchivedPrivate(id.parse()?)
+            }
+            ["channels", id, "threads", "archived", "public"] => {
+                ChannelsIdThreadsArchivedPublic(id.parse()?)
+            }
+            ["channels", id, "typing"] => ChannelsIdTyping(id.parse()?),
+            ["channels", id, "users", "@me", "threads", "archived", "private"] => {
+                ChannelsIdUsersMeThreadsArchivedPrivate(id.parse()?)
+            }
+            ["channels", id, "webhooks"] => ChannelsIdWebhooks(id.parse()?),
+            ["gateway"] => Gateway,
+            ["gateway", "bot"] => GatewayBot,
+            ["guilds"] => Guilds,
+            ["guilds", id] => GuildsId(id.parse()?),
+            ["guilds", id, "auto-moderation", "rules"] => GuildsIdAutoModerationRules(id.parse()?),
+            ["guilds", id, "auto-moderation", "rules", _] => {
+                GuildsIdAutoModerationRulesId(id.parse()?)
+            }
+            ["guilds", id, "bans"] => GuildsIdBans(id.parse()?),
+            ["guilds", id, "bans", _] => GuildsIdBansUserId(id.parse()?),
+            ["guilds", id, "channels"] => GuildsIdChannels(id.parse()?),
+            ["guilds", id, "widget"] => GuildsIdWidget(id.parse()?),
+            ["guilds", id, "emojis"] => GuildsIdEmojis(id.parse()?),
+            ["guilds", id, "emojis", _] => GuildsIdEmojisId(id.parse()?),
+            ["guilds", id, "integrations"] => GuildsIdIntegrations(id.parse()?),
+            ["guilds", id, "integrations", _] => GuildsIdIntegrationsId(id.parse()?),
+            ["guilds", id, "integrations", _, "sync"] => GuildsIdIntegrationsIdSync(id.parse()?),
+            ["guilds", id, "invites"] => GuildsIdInvites(id.parse()?),
+            ["guilds", id, "members"] => GuildsIdMembers(id.parse()?),
+            ["guilds", id, "members", _] => GuildsIdMembersId(id.parse()?),
+            ["guilds", id, "members", _, "roles", _] => GuildsIdMembersIdRolesId(id.parse()?),
+            ["guilds", id, "members", "@me", "nick"] => GuildsIdMembersMeNick(id.parse()?),
+            ["guilds", id, "preview"] => GuildsIdPreview(id.parse()?),
+            ["guilds", id, "prune"] => GuildsIdPrune(id.parse()?),
+            ["guilds", id, "regions"] => GuildsIdRegions(id.parse()?),
+            ["guilds", id, "roles"] => GuildsIdRoles(id.parse()?),
+            ["guilds", id, "roles", _] => GuildsIdRolesId(id.parse()?),
+            ["guilds", id, "scheduled-events"] => GuildsIdScheduledEvents(id.parse()?),
+            ["guilds", id, "scheduled-events", _] => GuildsIdScheduledEventsId(id.parse()?),
+            ["guilds", id, "scheduled-events", _, "users"] => {
+                GuildsIdScheduledEventsIdUsers(id.parse()?)
+            }
+            ["guilds", id, "stickers"] => GuildsIdStickers(id.parse()?),
+            ["guilds", id, "stickers", _] => GuildsIdStickersId(id.parse()?),
+            ["guilds", id, "threads", "active"] => GuildsIdThreadsActive(id.parse()?),
+            ["guilds", id, "vanity-url"] => GuildsIdVanityUrl(id.parse()?),
+            ["guilds", id, "webhooks"] => GuildsIdWebhooks(id.parse()?),
+            ["interactions", id, _, "callback"] => InteractionsIdTokenCallback(id.parse()?),
+            ["invites", _] => InvitesCode,
+            ["oauth2", "applications", "@me"] => OauthApplicationsMe,
+            ["stage-instances"] => StageInstances,
+            ["stage-instances", id] => StageInstancesId(id.parse()?),
+            ["sticker-packs"] => StickerPacks,
+            ["stickers", _] => StickersId,
+            ["users", _] => UsersId,
+            ["users", _, "connections"] => UsersIdConnections,
+            ["users", _, "channels"] => UsersIdChannels,
+            ["users", _, "guilds"] => UsersIdGuilds,
+            ["users", _, "guilds", _] => UsersIdGuildsId,
+            ["voice", "regions"] => VoiceRegions,
+            ["webhooks", id] | ["webhooks", id, _] => WebhooksId(id.parse()?),
+            ["webhooks", id, _, "messages", _] => WebhooksIdTokenMessagesId(id.parse()?),
+            _ => return Err(PathParseError::NoMatch),
+        })
+    }
+}
+
+impl TryFrom<(Method, &str)> for Path {
+    type Error = PathParseError;
+
+    fn try_from((method, s): (Method, &str)) -> Result<Self, Self::Error> {
+        match Self::from_str(s) {
+            Ok(v) => Ok(v),
+            Err(PathParseError::MessageIdWithoutMethod { channel_id }) => {
+                Ok(Self::ChannelsIdMessagesId(method, channel_id))
+            }
+            Err(why) => Err(why),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Path, PathParseError};
+    use http::Method;
+    use std::{convert::TryFrom, error::Error, str::FromStr};
+
+    #[test]
+    fn test_path_prefix_unimportant() -> Result<(), Box<dyn Error>> {
+        assert_eq!(Path::Guilds, Path::from_str("guilds")?);
+        assert_eq!(Path::Guilds, Path::from_str("/guilds")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_path_from_str() -> Result<(), Box<dyn Error>> {
+        assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
+        assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
+        assert_eq!(
+            Path::WebhooksIdTokenMessagesId(123),
+            Path::from_str("/webhooks/123/abc/messages/456")?
+        );
+        assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(
+            Path::ApplicationsIdGuildsIdCommandsId(1),
+            Path::from_str("/applications/1/guilds/2/commands/3")?
+        );
+        assert_eq!(
+            Path::InteractionsIdTokenCallback(1),
+            Path::from_str("/interactions/1/abc/callback")?
+        );
+        assert_eq!(
+            Path::ChannelsIdThreadsArchivedPublic(1),
+            Path::from_str("/channels/1/threads/archived/public")?
+        );
+        assert_eq!(
+            Path::ChannelsIdMessagesIdThreads(1),
+            Path::from_str("/channels/1/messages/2/threads")?
+        );
+        assert_eq!(
+            Path::StageInstancesId(1),
+            Path::from_str("/stage-instances/1")?
+        );
+        assert_eq!(
+            Path::GuildsIdScheduledEventsIdUsers(1),
+            Path::from_str("/guilds/1/scheduled-events/2/users")?
+        );
+        assert_eq!(
+            Path::GuildsIdStickersId(1),
+            Path::from_str("/guilds/1/stickers/2")?
+        );
+        assert_eq!(Path::StickerPacks, Path::from_str("/sticker-packs")?);
+        assert_eq!(
+            Path::GuildsIdAutoModerationRulesId(1),
+            Path::from_str("/guilds/1/auto-moderation/rules/2")?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_path_message_id() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            PathParseError::MessageIdWithoutMethod { channel_id: 123 },
+            Path::from_str("channels/123/messages/456").unwrap_err()
+        );
+        assert_eq!(
+            Path::ChannelsIdMessagesId(Method::GET, 123),
+            Path::try_from((Method::GET, "/channels/123/messages/456"))?,
+        );
+
+        Ok(())
+    }
+}
@@ -1,14 +1,16 @@
-use super::{Client, State};
+use super::{Client, State, TracePropagator};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ratelimiting::{InMemoryRatelimiter, Ratelimiter};
 use crate::{
-    error::{Error, Result},
-    ratelimiting::Ratelimiter,
+    error::{Error, ErrorType, Result},
     request::channel::message::allowed_mentions::AllowedMentions,
 };
-use reqwest::{ClientBuilder as ReqwestClientBuilder, Proxy};
-use std::{
-    sync::{atomic::AtomicBool, Arc},
-    time::Duration,
-};
+use reqwest::ClientBuilder as ReqwestClientBuilder;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Proxy;
+use std::sync::{atomic::AtomicBool, Arc};
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
 
 #[derive(Debug)]
 /// A builder for [`Client`].
@@ -16,12 +18,16 @@ use std::{
 /// [`Client`]: struct.Client.html
 pub struct ClientBuilder {
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
+    #[cfg(not(target_arch = "wasm32"))]
     pub(crate) proxy: Option<Proxy>,
     pub(crate) proxy_http: bool,
-    pub(crate) ratelimiter: Option<Ratelimiter>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) ratelimiter: Option<Box<dyn Ratelimiter>>,
     pub(crate) reqwest_client: Option<ReqwestClientBuilder>,
+    #[cfg(not(target_arch = "wasm32"))]
     pub(crate) timeout: Duration,
     pub(crate) token: Option<String>,
+    pub(crate) trace_propagator: Option<Box<dyn TracePropagator>>,
 }
 
 impl ClientBuilder {
@@ -40,23 +46,32 @@ impl ClientBuilder {
     ///
     /// [`Client`]: struct.Client.html
     pub fn build(self) -> Result<Client> {
-        let mut builder = self
+        let builder = self
             .reqwest_client
-            .unwrap_or_else(ReqwestClientBuilder::new)
-            .timeout(self.timeout);
+            .unwrap_or_else(ReqwestClientBuilder::new);
 
-        if let Some(proxy) = self.proxy {
-            builder = builder.proxy(proxy)
-        }
+        // Requests made with the fetch API can't be proxied or time out.
+        #[cfg(not(target_arch = "wasm32"))]
+        let builder = {
+            let mut builder = builder.timeout(self.timeout);
+
+            if let Some(proxy) = self.proxy {
+                builder = builder.proxy(proxy)
+            }
+
+            builder
+        };
 
         Ok(Client {
             state: Arc::new(State {
                 http: builder
                     .build()
-                    .map_err(|source| Error::BuildingClient { source })?,
+                    .map_err(|source| Error::with_source(ErrorType::BuildingClient, source))?,
+                #[cfg(not(target_arch = "wasm32"))]
                 ratelimiter: self.ratelimiter,
                 token_invalid: AtomicBool::new(false),
                 token: self.token,
+                trace_propagator: self.trace_propagator,
                 use_http: self.proxy_http,
                 default_allowed_mentions: self.default_allowed_mentions,
             }),
@@ -74,6 +89,7 @@ impl ClientBuilder {
     /// Sets the proxy to use for all HTTP requests.
     ///
     /// This accepts a `reqwest::Proxy`.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn proxy(mut self, proxy: Proxy) -> Self {
         self.proxy.replace(proxy);
 
@@ -106,12 +122,14 @@ impl ClientBuilder {
     /// If the argument is `None` then the client's ratelimiter will be skipped
     /// before making a request.
     ///
-    /// If this method is not called at all then a default ratelimiter will be
-    /// created by `ClientBuilder::build`.
+    /// If this method is not called at all then an [`InMemoryRatelimiter`]
+    /// will be created by `ClientBuilder::build`.
     ///
     /// [`ClientBuilder::build`]: #method.build
-    pub fn ratelimiter(mut self, ratelimiter: impl Into<Option<Ratelimiter>>) -> Self {
-        self.ratelimiter = ratelimiter.into();
+    /// [`InMemoryRatelimiter`]: ../ratelimiting/struct.InMemoryRatelimiter.html
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn ratelimiter(mut self, ratelimiter: Option<Box<dyn Ratelimiter>>) -> Self {
+        self.ratelimiter = ratelimiter;
 
         self
     }
@@ -119,6 +137,7 @@ impl ClientBuilder {
     /// Set the timeout for HTTP requests.
     ///
     /// The default is 10 seconds.
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn timeout(mut self, duration: Duration) -> Self {
         self.timeout = duration;
 
@@ -142,18 +161,34 @@ impl ClientBuilder {
 
         self
     }
+
+    /// Set a propagator to inject the context of the current trace into the
+    /// headers of requests.
+    ///
+    /// Refer to [`TracePropagator`] for more information.
+    ///
+    /// [`TracePropagator`]: trait.TracePropagator.html
+    pub fn trace_propagator(mut self, trace_propagator: Box<dyn TracePropagator>) -> Self {
+        self.trace_propagator.replace(trace_propagator);
+
+        self
+    }
 }
 
 impl Default for ClientBuilder {
     fn default() -> Self {
         Self {
             default_allowed_mentions: None,
+            #[cfg(not(target_arch = "wasm32"))]
             proxy: None,
             proxy_http: false,
             reqwest_client: None,
-            ratelimiter: Some(Ratelimiter::new()),
+            #[cfg(not(target_arch = "wasm32"))]
+            ratelimiter: Some(Box::new(InMemoryRatelimiter::new())),
+            #[cfg(not(target_arch = "wasm32"))]
             timeout: Duration::from_secs(10),
             token: None,
+            trace_propagator: None,
         }
     }
 }
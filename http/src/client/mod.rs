@@ -1,12 +1,15 @@
 mod builder;
+mod propagator;
 
-pub use self::builder::ClientBuilder;
+pub use self::{builder::ClientBuilder, propagator::TracePropagator};
+#[cfg(not(target_arch = "wasm32"))]
 pub use reqwest::Proxy;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ratelimiting::{InMemoryRatelimiter, RatelimitHeaders, Ratelimiter};
 use crate::{
     api_error::{ApiError, ErrorCode},
-    error::{Error, Result, UrlError},
-    ratelimiting::{RatelimitHeaders, Ratelimiter},
+    error::{Error, ErrorType, Result, UrlError, UrlErrorType},
     request::{
         channel::message::allowed_mentions::AllowedMentions,
         guild::{create_guild::CreateGuildError, create_guild_channel::CreateGuildChannelError},
@@ -16,10 +19,14 @@ use crate::{
     API_VERSION,
 };
 use bytes::Bytes;
-use reqwest::{header::HeaderValue, Body, Client as ReqwestClient, Method, Response, StatusCode};
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Body, Client as ReqwestClient, Method, Response, StatusCode,
+};
 use serde::de::DeserializeOwned;
+#[cfg(not(target_arch = "wasm32"))]
+use std::convert::TryFrom;
 use std::{
-    convert::TryFrom,
     fmt::{Debug, Formatter, Result as FmtResult},
     result::Result as StdResult,
     sync::{
@@ -27,27 +34,44 @@ use std::{
         Arc,
     },
 };
+use tracing::Instrument;
 use twilight_model::{
-    guild::Permissions,
-    id::{ChannelId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, UserId, WebhookId},
+    application::callback::InteractionResponse,
+    channel::{thread::AutoArchiveDuration, ChannelType},
+    guild::{
+        auto_moderation::{AutoModerationEventType, AutoModerationTriggerType},
+        scheduled_event::EntityType,
+        Permissions,
+    },
+    id::{
+        ApplicationId, AutoModerationRuleId, ChannelId, CommandId, EmojiId, GuildId, IntegrationId,
+        InteractionId, MessageId, RoleId, ScheduledEventId, StickerId, UserId, WebhookId,
+    },
 };
 use url::Url;
 
 struct State {
     http: ReqwestClient,
-    ratelimiter: Option<Ratelimiter>,
+    #[cfg(not(target_arch = "wasm32"))]
+    ratelimiter: Option<Box<dyn Ratelimiter>>,
     token_invalid: AtomicBool,
     token: Option<String>,
+    trace_propagator: Option<Box<dyn TracePropagator>>,
     use_http: bool,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
 }
 
 impl Debug for State {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        f.debug_struct("State")
-            .field("http", &"Reqwest HTTP client")
-            .field("ratelimiter", &self.ratelimiter)
+        let mut debug = f.debug_struct("State");
+        debug.field("http", &"Reqwest HTTP client");
+
+        #[cfg(not(target_arch = "wasm32"))]
+        debug.field("ratelimiter", &self.ratelimiter);
+
+        debug
             .field("token", &self.token)
+            .field("trace_propagator", &self.trace_propagator)
             .field("use_http", &self.use_http)
             .finish()
     }
@@ -64,7 +88,7 @@ impl Debug for State {
 /// the configured token is invalid. This may occur when the token has been
 /// revoked or expired. When this happens, you must create a new client with the
 /// new token. The client will no longer execute requests in order to
-/// prevent API bans and will always return [`Error::Unauthorized`].
+/// prevent API bans and will always return [`ErrorType::Unauthorized`].
 ///
 /// # Examples
 ///
@@ -97,7 +121,7 @@ impl Debug for State {
 ///
 /// [here]: https://discord.com/developers/applications
 /// [`ClientBuilder`]: struct.ClientBuilder.html
-/// [`Error::Unauthorized`]: ../enum.Error.html#variant.Unauthorized
+/// [`ErrorType::Unauthorized`]: ../error/enum.ErrorType.html#variant.Unauthorized
 #[derive(Clone, Debug)]
 pub struct Client {
     state: Arc<State>,
@@ -124,9 +148,11 @@ impl Client {
         Self {
             state: Arc::new(State {
                 http: ReqwestClient::new(),
-                ratelimiter: Some(Ratelimiter::new()),
+                #[cfg(not(target_arch = "wasm32"))]
+                ratelimiter: Some(Box::new(InMemoryRatelimiter::new())),
                 token_invalid: AtomicBool::new(false),
                 token: Some(token),
+                trace_propagator: None,
                 use_http: false,
                 default_allowed_mentions: None,
             }),
@@ -163,8 +189,9 @@ impl Client {
     /// has been explicitly disabled in the [`ClientBuilder`].
     ///
     /// [`ClientBuilder`]: struct.ClientBuilder.html
-    pub fn ratelimiter(&self) -> Option<Ratelimiter> {
-        self.state.ratelimiter.clone()
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn ratelimiter(&self) -> Option<&dyn Ratelimiter> {
+        self.state.ratelimiter.as_deref()
     }
 
     /// Add a role to a member in a guild.
@@ -219,6 +246,106 @@ impl Client {
         GetAuditLog::new(self, guild_id)
     }
 
+    /// Get the auto moderation rules in a guild.
+    ///
+    /// Requires the [`MANAGE_GUILD`] permission.
+    ///
+    /// [`MANAGE_GUILD`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub fn auto_moderation_rules(&self, guild_id: GuildId) -> GetGuildAutoModerationRules<'_> {
+        GetGuildAutoModerationRules::new(self, guild_id)
+    }
+
+    /// Get an auto moderation rule in a guild.
+    ///
+    /// Requires the [`MANAGE_GUILD`] permission.
+    ///
+    /// [`MANAGE_GUILD`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub fn auto_moderation_rule(
+        &self,
+        guild_id: GuildId,
+        auto_moderation_rule_id: AutoModerationRuleId,
+    ) -> GetAutoModerationRule<'_> {
+        GetAutoModerationRule::new(self, guild_id, auto_moderation_rule_id)
+    }
+
+    /// Create an auto moderation rule in a guild.
+    ///
+    /// Requires the [`MANAGE_GUILD`] permission.
+    ///
+    /// # Examples
+    ///
+    /// Block messages containing the word "cake":
+    ///
+    /// ```rust,no_run
+    /// # use twilight_http::Client;
+    /// use twilight_model::{
+    ///     guild::auto_moderation::{
+    ///         AutoModerationAction, AutoModerationActionType, AutoModerationEventType,
+    ///         AutoModerationTriggerMetadata, AutoModerationTriggerType,
+    ///     },
+    ///     id::GuildId,
+    /// };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// # let client = Client::new("my token");
+    /// let rule = client
+    ///     .create_auto_moderation_rule(
+    ///         GuildId(1),
+    ///         "no cake",
+    ///         AutoModerationEventType::MessageSend,
+    ///         AutoModerationTriggerType::Keyword,
+    ///     )
+    ///     .actions(vec![AutoModerationAction {
+    ///         metadata: None,
+    ///         kind: AutoModerationActionType::BlockMessage,
+    ///     }])
+    ///     .enabled(true)
+    ///     .trigger_metadata(AutoModerationTriggerMetadata {
+    ///         keyword_filter: Some(vec!["cake".to_owned()]),
+    ///         ..AutoModerationTriggerMetadata::default()
+    ///     })
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`MANAGE_GUILD`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub fn create_auto_moderation_rule(
+        &self,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        event_type: AutoModerationEventType,
+        trigger_type: AutoModerationTriggerType,
+    ) -> CreateAutoModerationRule<'_> {
+        CreateAutoModerationRule::new(self, guild_id, name, event_type, trigger_type)
+    }
+
+    /// Update an auto moderation rule in a guild.
+    ///
+    /// Requires the [`MANAGE_GUILD`] permission.
+    ///
+    /// [`MANAGE_GUILD`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub fn update_auto_moderation_rule(
+        &self,
+        guild_id: GuildId,
+        auto_moderation_rule_id: AutoModerationRuleId,
+    ) -> UpdateAutoModerationRule<'_> {
+        UpdateAutoModerationRule::new(self, guild_id, auto_moderation_rule_id)
+    }
+
+    /// Delete an auto moderation rule in a guild.
+    ///
+    /// Requires the [`MANAGE_GUILD`] permission.
+    ///
+    /// [`MANAGE_GUILD`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+    pub fn delete_auto_moderation_rule(
+        &self,
+        guild_id: GuildId,
+        auto_moderation_rule_id: AutoModerationRuleId,
+    ) -> DeleteAutoModerationRule<'_> {
+        DeleteAutoModerationRule::new(self, guild_id, auto_moderation_rule_id)
+    }
+
     /// Retrieve the bans for a guild.
     ///
     /// # Examples
@@ -336,18 +463,18 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns a [`UpdateChannelError::NameInvalid`] when the length of the name is either fewer
+    /// Returns a [`UpdateChannelErrorType::NameInvalid`] when the length of the name is either fewer
     /// than 2 UTF-16 characters or more than 100 UTF-16 characters.
     ///
-    /// Returns a [`UpdateChannelError::RateLimitPerUserInvalid`] when the seconds of the rate limit
+    /// Returns a [`UpdateChannelErrorType::RateLimitPerUserInvalid`] when the seconds of the rate limit
     /// per user is more than 21600.
     ///
-    /// Returns a [`UpdateChannelError::TopicInvalid`] when the length of the topic is more than
+    /// Returns a [`UpdateChannelErrorType::TopicInvalid`] when the length of the topic is more than
     /// 1024 UTF-16 characters.
     ///
-    /// [`UpdateChannelError::NameInvalid`]: ../request/channel/update_channel/enum.UpdateChannelError.html#variant.NameInvalid
-    /// [`UpdateChannelError::RateLimitPerUserInvalid`]: ../request/channel/update_channel/enum.UpdateChannelError.html#variant.RateLimitPerUserInvalid
-    /// [`UpdateChannelError::TopicInvalid`]: ../request/channel/update_channel/enum.UpdateChannelError.html#variant.TopicInvalid
+    /// [`UpdateChannelErrorType::NameInvalid`]: ../request/channel/update_channel/enum.UpdateChannelErrorType.html#variant.NameInvalid
+    /// [`UpdateChannelErrorType::RateLimitPerUserInvalid`]: ../request/channel/update_channel/enum.UpdateChannelErrorType.html#variant.RateLimitPerUserInvalid
+    /// [`UpdateChannelErrorType::TopicInvalid`]: ../request/channel/update_channel/enum.UpdateChannelErrorType.html#variant.TopicInvalid
     pub fn update_channel(&self, channel_id: ChannelId) -> UpdateChannel<'_> {
         UpdateChannel::new(self, channel_id)
     }
@@ -404,7 +531,7 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns [`GetChannelMessagesError::LimitInvalid`] if the amount is less than 1 or greater than 100.
+    /// Returns [`GetChannelMessagesErrorType::LimitInvalid`] if the amount is less than 1 or greater than 100.
     ///
     /// [`ChannelId`]: ../../twilight_model/id/struct.ChannelId.html
     /// [`after`]: ../request/channel/message/get_channel_messages/struct.GetChannelMessages.html#method.after
@@ -412,7 +539,7 @@ impl Client {
     /// [`before`]: ../request/channel/message/get_channel_messages/struct.GetChannelMessages.html#method.before
     /// [`GetChannelMessagesConfigured`]: ../request/channel/message/get_channel_messages_configured/struct.GetChannelMessagesConfigured.html
     /// [`limit`]: ../request/channel/message/get_channel_messages/struct.GetChannelMessages.html#method.limit
-    /// [`GetChannelMessagesError::LimitInvalid`]: ../request/channel/message/get_channel_messages/enum.GetChannelMessagesError.html#variant.LimitInvalid
+    /// [`GetChannelMessagesErrorType::LimitInvalid`]: ../request/channel/message/get_channel_messages/enum.GetChannelMessagesErrorType.html#variant.LimitInvalid
     pub fn channel_messages(&self, channel_id: ChannelId) -> GetChannelMessages<'_> {
         GetChannelMessages::new(self, channel_id)
     }
@@ -512,10 +639,10 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns [`GetCurrentUserGuildsError::LimitInvalid`] if the amount is greater
+    /// Returns [`GetCurrentUserGuildsErrorType::LimitInvalid`] if the amount is greater
     /// than 100.
     ///
-    /// [`GetCurrentUserGuildsError::LimitInvalid`]: ../request/user/get_current_user_guilds/enum.GetCurrentUserGuildsError.html#variant.LimitInvalid
+    /// [`GetCurrentUserGuildsErrorType::LimitInvalid`]: ../request/user/get_current_user_guilds/enum.GetCurrentUserGuildsErrorType.html#variant.LimitInvalid
     pub fn current_user_guilds(&self) -> GetCurrentUserGuilds<'_> {
         GetCurrentUserGuilds::new(self)
     }
@@ -607,6 +734,65 @@ impl Client {
         UpdateEmoji::new(self, guild_id, emoji_id)
     }
 
+    /// Get the packs of official stickers available to Nitro subscribers.
+    pub fn nitro_sticker_packs(&self) -> GetNitroStickerPacks<'_> {
+        GetNitroStickerPacks::new(self)
+    }
+
+    /// Get a sticker by its ID.
+    pub fn sticker(&self, sticker_id: StickerId) -> GetSticker<'_> {
+        GetSticker::new(self, sticker_id)
+    }
+
+    /// Get the stickers in a guild.
+    pub fn guild_stickers(&self, guild_id: GuildId) -> GetGuildStickers<'_> {
+        GetGuildStickers::new(self, guild_id)
+    }
+
+    /// Get a sticker in a guild.
+    pub fn guild_sticker(&self, guild_id: GuildId, sticker_id: StickerId) -> GetGuildSticker<'_> {
+        GetGuildSticker::new(self, guild_id, sticker_id)
+    }
+
+    /// Create a sticker in a guild.
+    ///
+    /// The file is a PNG, APNG or Lottie JSON file of at most 500 KiB.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateGuildStickerError`] when the name, description, or tags have an invalid
+    /// length.
+    ///
+    /// [`CreateGuildStickerError`]: ../request/guild/sticker/struct.CreateGuildStickerError.html
+    pub fn create_guild_sticker(
+        &self,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        tags: impl Into<String>,
+        file: impl Into<Vec<u8>>,
+    ) -> StdResult<CreateGuildSticker<'_>, CreateGuildStickerError> {
+        CreateGuildSticker::new(self, guild_id, name, description, tags, file)
+    }
+
+    /// Update a sticker in a guild.
+    pub fn update_guild_sticker(
+        &self,
+        guild_id: GuildId,
+        sticker_id: StickerId,
+    ) -> UpdateGuildSticker<'_> {
+        UpdateGuildSticker::new(self, guild_id, sticker_id)
+    }
+
+    /// Delete a sticker in a guild.
+    pub fn delete_guild_sticker(
+        &self,
+        guild_id: GuildId,
+        sticker_id: StickerId,
+    ) -> DeleteGuildSticker<'_> {
+        DeleteGuildSticker::new(self, guild_id, sticker_id)
+    }
+
     /// Get information about the gateway, optionally with additional information detailing the
     /// number of shards to use and sessions remaining.
     ///
@@ -657,9 +843,9 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns [`CreateGuildError::NameInvalid`] if the name length is too short or too long.
+    /// Returns [`CreateGuildErrorType::NameInvalid`] if the name length is too short or too long.
     ///
-    /// [`CreateGuildError::NameInvalid`]: ../request/guild/create_guild/enum.CreateGuildError.html#variant.NameInvalid
+    /// [`CreateGuildErrorType::NameInvalid`]: ../request/guild/create_guild/enum.CreateGuildErrorType.html#variant.NameInvalid
     pub fn create_guild(
         &self,
         name: impl Into<String>,
@@ -698,19 +884,19 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns a [`CreateGuildChannelError::NameInvalid`] when the length of the name is either
+    /// Returns a [`CreateGuildChannelErrorType::NameInvalid`] when the length of the name is either
     /// fewer than 2 UTF-16 characters or more than 100 UTF-16 characters.
     ///
-    /// Returns a [`CreateGuildChannelError::RateLimitPerUserInvalid`] when the seconds of the rate
+    /// Returns a [`CreateGuildChannelErrorType::RateLimitPerUserInvalid`] when the seconds of the rate
     /// limit per user is more than 21600.
     ///
-    /// Returns a [`CreateGuildChannelError::TopicInvalid`] when the length of the topic is more
+    /// Returns a [`CreateGuildChannelErrorType::TopicInvalid`] when the length of the topic is more
     /// than
     /// 1024 UTF-16 characters.
     ///
-    /// [`CreateGuildChannelError::NameInvalid`]: ../request/guild/create_guild_channel/enum.CreateGuildChannelError.html#variant.NameInvalid
-    /// [`CreateGuildChannelError::RateLimitPerUserInvalid`]: ../request/guild/create_guild_channel/enum.CreateGuildChannelError.html#variant.RateLimitPerUserInvalid
-    /// [`CreateGuildChannelError::TopicInvalid`]: ../request/guild/create_guild_channel/enum.CreateGuildChannelError.html#variant.TopicInvalid
+    /// [`CreateGuildChannelErrorType::NameInvalid`]: ../request/guild/create_guild_channel/enum.CreateGuildChannelErrorType.html#variant.NameInvalid
+    /// [`CreateGuildChannelErrorType::RateLimitPerUserInvalid`]: ../request/guild/create_guild_channel/enum.CreateGuildChannelErrorType.html#variant.RateLimitPerUserInvalid
+    /// [`CreateGuildChannelErrorType::TopicInvalid`]: ../request/guild/create_guild_channel/enum.CreateGuildChannelErrorType.html#variant.TopicInvalid
     pub fn create_guild_channel(
         &self,
         guild_id: GuildId,
@@ -824,9 +1010,9 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns [`GetGuildMembersError::LimitInvalid`] if the limit is invalid.
+    /// Returns [`GetGuildMembersErrorType::LimitInvalid`] if the limit is invalid.
     ///
-    /// [`GetGuildMembersError::LimitInvalid`]: ../request/guild/member/get_guild_members/enum.GetGuildMembersError.html#variant.LimitInvalid
+    /// [`GetGuildMembersErrorType::LimitInvalid`]: ../request/guild/member/get_guild_members/enum.GetGuildMembersErrorType.html#variant.LimitInvalid
     pub fn guild_members(&self, guild_id: GuildId) -> GetGuildMembers<'_> {
         GetGuildMembers::new(self, guild_id)
     }
@@ -847,10 +1033,10 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns [`UpdateGuildMemberError::NicknameInvalid`] if the nickname length is too short or too
+    /// Returns [`UpdateGuildMemberErrorType::NicknameInvalid`] if the nickname length is too short or too
     /// long.
     ///
-    /// [`UpdateGuildMemberError::NicknameInvalid`]: ../request/guild/member/update_guild_member/enum.UpdateGuildMemberError.html#variant.NicknameInvalid
+    /// [`UpdateGuildMemberErrorType::NicknameInvalid`]: ../request/guild/member/update_guild_member/enum.UpdateGuildMemberErrorType.html#variant.NicknameInvalid
     ///
     /// [the discord docs]: https://discord.com/developers/docs/resources/guild#modify-guild-member
     pub fn update_guild_member(&self, guild_id: GuildId, user_id: UserId) -> UpdateGuildMember<'_> {
@@ -902,6 +1088,67 @@ impl Client {
         GetGuildVanityUrl::new(self, guild_id)
     }
 
+    /// Get the scheduled events in a guild.
+    pub fn guild_scheduled_events(&self, guild_id: GuildId) -> GetGuildScheduledEvents<'_> {
+        GetGuildScheduledEvents::new(self, guild_id)
+    }
+
+    /// Get a scheduled event in a guild.
+    pub fn guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> GetGuildScheduledEvent<'_> {
+        GetGuildScheduledEvent::new(self, guild_id, scheduled_event_id)
+    }
+
+    /// Create a scheduled event in a guild.
+    ///
+    /// The start time is an ISO 8601 timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateGuildScheduledEventErrorType::NameInvalid`] when the length of the name is
+    /// either fewer than 1 UTF-16 character or more than 100 UTF-16 characters.
+    ///
+    /// [`CreateGuildScheduledEventErrorType::NameInvalid`]: ../request/guild/scheduled_event/enum.CreateGuildScheduledEventErrorType.html#variant.NameInvalid
+    pub fn create_guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        entity_type: EntityType,
+        name: impl Into<String>,
+        scheduled_start_time: impl Into<String>,
+    ) -> StdResult<CreateGuildScheduledEvent<'_>, CreateGuildScheduledEventError> {
+        CreateGuildScheduledEvent::new(self, guild_id, entity_type, name, scheduled_start_time)
+    }
+
+    /// Update a scheduled event in a guild.
+    pub fn update_guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> UpdateGuildScheduledEvent<'_> {
+        UpdateGuildScheduledEvent::new(self, guild_id, scheduled_event_id)
+    }
+
+    /// Delete a scheduled event in a guild.
+    pub fn delete_guild_scheduled_event(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> DeleteGuildScheduledEvent<'_> {
+        DeleteGuildScheduledEvent::new(self, guild_id, scheduled_event_id)
+    }
+
+    /// Get the users subscribed to a scheduled event in a guild.
+    pub fn guild_scheduled_event_users(
+        &self,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> GetGuildScheduledEventUsers<'_> {
+        GetGuildScheduledEventUsers::new(self, guild_id, scheduled_event_id)
+    }
+
     /// Get voice region data for the guild.
     ///
     /// Can return VIP servers if the guild is VIP-enabled.
@@ -998,19 +1245,19 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// The method [`content`] returns [`CreateMessageError::ContentInvalid`] if the content is
+    /// The method [`content`] returns [`CreateMessageErrorType::ContentInvalid`] if the content is
     /// over 2000 UTF-16 characters.
     ///
-    /// The method [`embed`] returns [`CreateMessageError::EmbedTooLarge`] if the length of the
+    /// The method [`embed`] returns [`CreateMessageErrorType::EmbedTooLarge`] if the length of the
     /// embed is over 6000 characters.
     ///
     /// [`content`]:
     /// ../request/channel/message/create_message/struct.CreateMessage.html#method.content
     /// [`embed`]: ../request/channel/message/create_message/struct.CreateMessage.html#method.embed
-    /// [`CreateMessageError::ContentInvalid`]:
-    /// ../request/channel/message/create_message/enum.CreateMessageError.html#variant.ContentInvalid
-    /// [`CreateMessageError::EmbedTooLarge`]:
-    /// ../request/channel/message/create_message/enum.CreateMessageError.html#variant.EmbedTooLarge
+    /// [`CreateMessageErrorType::ContentInvalid`]:
+    /// ../request/channel/message/create_message/enum.CreateMessageErrorType.html#variant.ContentInvalid
+    /// [`CreateMessageErrorType::EmbedTooLarge`]:
+    /// ../request/channel/message/create_message/enum.CreateMessageErrorType.html#variant.EmbedTooLarge
     pub fn create_message(&self, channel_id: ChannelId) -> CreateMessage<'_> {
         CreateMessage::new(self, channel_id)
     }
@@ -1120,6 +1367,129 @@ impl Client {
         DeletePin::new(self, channel_id, message_id)
     }
 
+    /// Start a stage instance in a stage channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateStageInstanceErrorType::TopicInvalid`] when the length of the topic is either
+    /// fewer than 1 UTF-16 character or more than 120 UTF-16 characters.
+    ///
+    /// [`CreateStageInstanceErrorType::TopicInvalid`]: ../request/channel/stage/enum.CreateStageInstanceErrorType.html#variant.TopicInvalid
+    pub fn create_stage_instance(
+        &self,
+        channel_id: ChannelId,
+        topic: impl Into<String>,
+    ) -> StdResult<CreateStageInstance<'_>, CreateStageInstanceError> {
+        CreateStageInstance::new(self, channel_id, topic)
+    }
+
+    /// Get the stage instance of a stage channel.
+    pub fn stage_instance(&self, channel_id: ChannelId) -> GetStageInstance<'_> {
+        GetStageInstance::new(self, channel_id)
+    }
+
+    /// Update the stage instance of a stage channel.
+    pub fn update_stage_instance(&self, channel_id: ChannelId) -> UpdateStageInstance<'_> {
+        UpdateStageInstance::new(self, channel_id)
+    }
+
+    /// End the stage instance of a stage channel.
+    pub fn delete_stage_instance(&self, channel_id: ChannelId) -> DeleteStageInstance<'_> {
+        DeleteStageInstance::new(self, channel_id)
+    }
+
+    /// Start a thread from a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateThreadFromMessageErrorType::NameInvalid`] when the length of the name is
+    /// either fewer than 1 UTF-16 character or more than 100 UTF-16 characters.
+    ///
+    /// [`CreateThreadFromMessageErrorType::NameInvalid`]: ../request/channel/thread/enum.CreateThreadFromMessageErrorType.html#variant.NameInvalid
+    pub fn create_thread_from_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: impl Into<String>,
+        auto_archive_duration: AutoArchiveDuration,
+    ) -> StdResult<CreateThreadFromMessage<'_>, CreateThreadFromMessageError> {
+        CreateThreadFromMessage::new(self, channel_id, message_id, name, auto_archive_duration)
+    }
+
+    /// Start a thread in a channel that is not connected to a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateThreadErrorType::NameInvalid`] when the length of the name is either fewer
+    /// than 1 UTF-16 character or more than 100 UTF-16 characters.
+    ///
+    /// Returns a [`CreateThreadErrorType::TypeInvalid`] when the type of the channel is not a thread
+    /// type.
+    ///
+    /// [`CreateThreadErrorType::NameInvalid`]: ../request/channel/thread/enum.CreateThreadErrorType.html#variant.NameInvalid
+    /// [`CreateThreadErrorType::TypeInvalid`]: ../request/channel/thread/enum.CreateThreadErrorType.html#variant.TypeInvalid
+    pub fn create_thread(
+        &self,
+        channel_id: ChannelId,
+        name: impl Into<String>,
+        auto_archive_duration: AutoArchiveDuration,
+        kind: ChannelType,
+    ) -> StdResult<CreateThread<'_>, CreateThreadError> {
+        CreateThread::new(self, channel_id, name, auto_archive_duration, kind)
+    }
+
+    /// Add the current user to a thread.
+    pub fn join_thread(&self, channel_id: ChannelId) -> JoinThread<'_> {
+        JoinThread::new(self, channel_id)
+    }
+
+    /// Remove the current user from a thread.
+    pub fn leave_thread(&self, channel_id: ChannelId) -> LeaveThread<'_> {
+        LeaveThread::new(self, channel_id)
+    }
+
+    /// Add a member to a thread.
+    pub fn add_thread_member(&self, channel_id: ChannelId, user_id: UserId) -> AddThreadMember<'_> {
+        AddThreadMember::new(self, channel_id, user_id)
+    }
+
+    /// Remove a member from a thread.
+    pub fn remove_thread_member(
+        &self,
+        channel_id: ChannelId,
+        user_id: UserId,
+    ) -> RemoveThreadMember<'_> {
+        RemoveThreadMember::new(self, channel_id, user_id)
+    }
+
+    /// Get the members of a thread.
+    pub fn thread_members(&self, channel_id: ChannelId) -> GetThreadMembers<'_> {
+        GetThreadMembers::new(self, channel_id)
+    }
+
+    /// Get the active threads of a guild.
+    pub fn active_threads(&self, guild_id: GuildId) -> GetActiveThreads<'_> {
+        GetActiveThreads::new(self, guild_id)
+    }
+
+    /// Get the archived public threads of a channel.
+    pub fn public_archived_threads(&self, channel_id: ChannelId) -> GetPublicArchivedThreads<'_> {
+        GetPublicArchivedThreads::new(self, channel_id)
+    }
+
+    /// Get the archived private threads of a channel.
+    pub fn private_archived_threads(&self, channel_id: ChannelId) -> GetPrivateArchivedThreads<'_> {
+        GetPrivateArchivedThreads::new(self, channel_id)
+    }
+
+    /// Get the archived private threads of a channel that the current user has joined.
+    pub fn joined_private_archived_threads(
+        &self,
+        channel_id: ChannelId,
+    ) -> GetJoinedPrivateArchivedThreads<'_> {
+        GetJoinedPrivateArchivedThreads::new(self, channel_id)
+    }
+
     /// Get a list of users that reacted to a message with an `emoji`.
     ///
     /// This endpoint is limited to 100 users maximum, so if a message has more than 100 reactions,
@@ -1281,6 +1651,117 @@ impl Client {
         GetVoiceRegions::new(self)
     }
 
+    /// Get the global commands of an application.
+    pub fn global_commands(&self, application_id: ApplicationId) -> GetGlobalCommands<'_> {
+        GetGlobalCommands::new(self, application_id)
+    }
+
+    /// Create a global command, available in every guild the application is
+    /// in.
+    ///
+    /// Refer to [the discord docs] for the requirements of names and
+    /// descriptions.
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/slash-commands#registering-a-command
+    pub fn create_global_command(
+        &self,
+        application_id: ApplicationId,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> CreateGlobalCommand<'_> {
+        CreateGlobalCommand::new(self, application_id, name, description)
+    }
+
+    /// Delete a global command.
+    pub fn delete_global_command(
+        &self,
+        application_id: ApplicationId,
+        command_id: CommandId,
+    ) -> DeleteGlobalCommand<'_> {
+        DeleteGlobalCommand::new(self, application_id, command_id)
+    }
+
+    /// Update a global command.
+    pub fn update_global_command(
+        &self,
+        application_id: ApplicationId,
+        command_id: CommandId,
+    ) -> UpdateGlobalCommand<'_> {
+        UpdateGlobalCommand::new(self, application_id, command_id)
+    }
+
+    /// Get the commands of an application in a guild.
+    pub fn guild_commands(
+        &self,
+        application_id: ApplicationId,
+        guild_id: GuildId,
+    ) -> GetGuildCommands<'_> {
+        GetGuildCommands::new(self, application_id, guild_id)
+    }
+
+    /// Create a command that's only available in a guild.
+    ///
+    /// Unlike global commands, guild commands are available immediately, so
+    /// they're useful for testing.
+    pub fn create_guild_command(
+        &self,
+        application_id: ApplicationId,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> CreateGuildCommand<'_> {
+        CreateGuildCommand::new(self, application_id, guild_id, name, description)
+    }
+
+    /// Delete a command in a guild.
+    pub fn delete_guild_command(
+        &self,
+        application_id: ApplicationId,
+        guild_id: GuildId,
+        command_id: CommandId,
+    ) -> DeleteGuildCommand<'_> {
+        DeleteGuildCommand::new(self, application_id, guild_id, command_id)
+    }
+
+    /// Update a command in a guild.
+    pub fn update_guild_command(
+        &self,
+        application_id: ApplicationId,
+        guild_id: GuildId,
+        command_id: CommandId,
+    ) -> UpdateGuildCommand<'_> {
+        UpdateGuildCommand::new(self, application_id, guild_id, command_id)
+    }
+
+    /// Respond to an interaction by its ID and token.
+    ///
+    /// Refer to [`InteractionCallback`] for more information.
+    ///
+    /// [`InteractionCallback`]: ../request/application/struct.InteractionCallback.html
+    pub fn interaction_callback(
+        &self,
+        interaction_id: InteractionId,
+        interaction_token: impl Into<String>,
+        response: InteractionResponse,
+    ) -> InteractionCallback<'_> {
+        InteractionCallback::new(self, interaction_id, interaction_token, response)
+    }
+
+    /// Update a followup message of an interaction, such as a message sent
+    /// after a deferred response.
+    ///
+    /// Refer to [`UpdateFollowupMessage`] for more information.
+    ///
+    /// [`UpdateFollowupMessage`]: ../request/application/struct.UpdateFollowupMessage.html
+    pub fn update_followup_message(
+        &self,
+        application_id: ApplicationId,
+        interaction_token: impl Into<String>,
+        message_id: MessageId,
+    ) -> UpdateFollowupMessage<'_> {
+        UpdateFollowupMessage::new(self, application_id, interaction_token, message_id)
+    }
+
     /// Get a webhook by ID.
     pub fn webhook(&self, id: WebhookId) -> GetWebhook<'_> {
         GetWebhook::new(self, id)
@@ -1321,9 +1802,9 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns [`UrlError::SegmentMissing`] if the URL can not be parsed.
+    /// Returns [`UrlErrorType::SegmentMissing`] if the URL can not be parsed.
     ///
-    /// [`UrlError::SegmentMissing`]: ../error/enum.UrlError.html#variant.SegmentMissing
+    /// [`UrlErrorType::SegmentMissing`]: ../error/enum.UrlErrorType.html#variant.SegmentMissing
     pub fn delete_webhook_from_url(&self, url: impl AsRef<str>) -> Result<DeleteWebhook<'_>> {
         let (id, _) = parse_webhook_url(url)?;
         Ok(self.delete_webhook(id))
@@ -1338,9 +1819,9 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns [`UrlError::SegmentMissing`] if the URL can not be parsed.
+    /// Returns [`UrlErrorType::SegmentMissing`] if the URL can not be parsed.
     ///
-    /// [`UrlError::SegmentMissing`]: ../error/enum.UrlError.html#variant.SegmentMissing
+    /// [`UrlErrorType::SegmentMissing`]: ../error/enum.UrlErrorType.html#variant.SegmentMissing
     pub fn update_webhook_from_url(&self, url: impl AsRef<str>) -> Result<UpdateWebhook<'_>> {
         let (id, _) = parse_webhook_url(url)?;
         Ok(self.update_webhook(id))
@@ -1359,20 +1840,23 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns [`UrlError::SegmentMissing`] if the URL can not be parsed.
+    /// Returns [`UrlErrorType::SegmentMissing`] if the URL can not be parsed.
     ///
-    /// [`UrlError::SegmentMissing`]: ../error/enum.UrlError.html#variant.SegmentMissing
+    /// [`UrlErrorType::SegmentMissing`]: ../error/enum.UrlErrorType.html#variant.SegmentMissing
     pub fn update_webhook_with_token_from_url(
         &self,
         url: impl AsRef<str>,
     ) -> Result<UpdateWebhookWithToken<'_>> {
         let (id, token) = parse_webhook_url(url)?;
-        Ok(self.update_webhook_with_token(id, token.ok_or(UrlError::SegmentMissing)?))
+        Ok(self.update_webhook_with_token(
+            id,
+            token.ok_or_else(|| UrlError::new(UrlErrorType::SegmentMissing))?,
+        ))
     }
 
     /// Executes a webhook, sending a message to its channel.
     ///
-    /// You can only specify one of [`content`], [`embeds`], or [`file`].
+    /// You can only specify one of [`content`], [`embeds`], or [`attachment`].
     ///
     /// # Examples
     ///
@@ -1392,9 +1876,9 @@ impl Client {
     /// # Ok(()) }
     /// ```
     ///
+    /// [`attachment`]: ../request/channel/webhook/struct.ExecuteWebhook.html#method.attachment
     /// [`content`]: ../request/channel/webhook/struct.ExecuteWebhook.html#method.content
     /// [`embeds`]: ../request/channel/webhook/struct.ExecuteWebhook.html#method.embeds
-    /// [`file`]: ../request/channel/webhook/struct.ExecuteWebhook.html#method.file
     pub fn execute_webhook(
         &self,
         webhook_id: WebhookId,
@@ -1407,25 +1891,39 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns [`UrlError::SegmentMissing`] if the URL can not be parsed.
+    /// Returns [`UrlErrorType::SegmentMissing`] if the URL can not be parsed.
     ///
-    /// [`UrlError::SegmentMissing`]: ../error/enum.UrlError.html#variant.SegmentMissing
+    /// [`UrlErrorType::SegmentMissing`]: ../error/enum.UrlErrorType.html#variant.SegmentMissing
     pub fn execute_webhook_from_url(&self, url: impl AsRef<str>) -> Result<ExecuteWebhook<'_>> {
         let (id, token) = parse_webhook_url(url)?;
-        Ok(self.execute_webhook(id, token.ok_or(UrlError::SegmentMissing)?))
+        Ok(self.execute_webhook(
+            id,
+            token.ok_or_else(|| UrlError::new(UrlErrorType::SegmentMissing))?,
+        ))
     }
 
     /// Execute a request, returning the response.
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Unauthorized`] if the configured token has become
+    /// Returns [`ErrorType::Unauthorized`] if the configured token has become
     /// invalid due to expiration, revokation, etc.
     ///
-    /// [`Error::Unauthorized`]: ../enum.Error.html#variant.Unauthorized
+    /// [`ErrorType::Unauthorized`]: ../error/enum.ErrorType.html#variant.Unauthorized
     pub async fn raw(&self, request: Request) -> Result<Response> {
+        let span = tracing::debug_span!(
+            "http request",
+            method = %request.method,
+            route = ?request.path,
+            status = tracing::field::Empty,
+        );
+
+        self.send(request).instrument(span).await
+    }
+
+    async fn send(&self, request: Request) -> Result<Response> {
         if self.state.token_invalid.load(Ordering::Relaxed) {
-            return Err(Error::Unauthorized);
+            return Err(Error::new(ErrorType::Unauthorized));
         }
 
         let Request {
@@ -1444,9 +1942,13 @@ impl Client {
         let mut builder = self.state.http.request(method.clone(), &url);
 
         if let Some(ref token) = self.state.token {
-            let value = HeaderValue::from_str(&token).map_err(|source| Error::CreatingHeader {
-                name: "Authroization".to_owned(),
-                source,
+            let value = HeaderValue::from_str(&token).map_err(|source| {
+                Error::with_source(
+                    ErrorType::CreatingHeader {
+                        name: "Authroization".to_owned(),
+                    },
+                    source,
+                )
             })?;
 
             builder = builder.header("Authorization", value);
@@ -1477,25 +1979,42 @@ impl Client {
             builder = builder.headers(req_headers);
         }
 
+        if let Some(propagator) = self.state.trace_propagator.as_ref() {
+            let mut headers = HeaderMap::new();
+            propagator.inject(&mut headers);
+
+            builder = builder.headers(headers);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
         let ratelimiter = match self.state.ratelimiter.as_ref() {
             Some(ratelimiter) => ratelimiter,
             None => {
                 return builder
                     .send()
                     .await
-                    .map_err(|source| Error::RequestError { source })
+                    .map_err(|source| Error::with_source(ErrorType::RequestError, source))
             }
         };
 
-        let rx = ratelimiter.get(bucket).await;
-        let tx = rx
+        #[cfg(not(target_arch = "wasm32"))]
+        let tx = ratelimiter
+            .ticket(bucket)
+            .await
             .await
-            .map_err(|source| Error::RequestCanceled { source })?;
+            .map_err(|source| Error::with_source(ErrorType::RequestCanceled, source))?;
+
+        // There's no ratelimiter to queue requests in buckets on wasm, where
+        // each request is usually made by a short-lived worker.
+        #[cfg(target_arch = "wasm32")]
+        drop(bucket);
 
         let resp = builder
             .send()
             .await
-            .map_err(|source| Error::RequestError { source })?;
+            .map_err(|source| Error::with_source(ErrorType::RequestError, source))?;
+
+        tracing::Span::current().record("status", resp.status().as_u16());
 
         // If the API sent back an Unauthorized response, then the client's
         // configured token is permanently invalid and future requests must be
@@ -1504,6 +2023,7 @@ impl Client {
             self.state.token_invalid.store(true, Ordering::Relaxed);
         }
 
+        #[cfg(not(target_arch = "wasm32"))]
         match RatelimitHeaders::try_from(resp.headers()) {
             Ok(v) => {
                 let _ = tx.send(Some(v));
@@ -1522,25 +2042,29 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Unauthorized`] if the configured token has become
+    /// Returns [`ErrorType::Unauthorized`] if the configured token has become
     /// invalid due to expiration, revokation, etc.
     ///
-    /// [`Error::Unauthorized`]: ../enum.Error.html#variant.Unauthorized
+    /// [`ErrorType::Unauthorized`]: ../error/enum.ErrorType.html#variant.Unauthorized
     pub async fn request<T: DeserializeOwned>(&self, request: Request) -> Result<T> {
         let resp = self.make_request(request).await?;
 
         let bytes = resp
             .bytes()
             .await
-            .map_err(|source| Error::ChunkingResponse { source })?;
+            .map_err(|source| Error::with_source(ErrorType::ChunkingResponse, source))?;
 
         let mut bytes_b = bytes.as_ref().to_vec();
 
         let result = crate::json_from_slice(&mut bytes_b);
 
-        result.map_err(|source| Error::Parsing {
-            body: (*bytes).to_vec(),
-            source,
+        result.map_err(|source| {
+            Error::with_source(
+                ErrorType::Parsing {
+                    body: (*bytes).to_vec(),
+                },
+                source,
+            )
         })
     }
 
@@ -1549,7 +2073,7 @@ impl Client {
 
         resp.bytes()
             .await
-            .map_err(|source| Error::ChunkingResponse { source })
+            .map_err(|source| Error::with_source(ErrorType::ChunkingResponse, source))
     }
 
     /// Execute a request, checking only that the response was a success.
@@ -1558,10 +2082,10 @@ impl Client {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Unauthorized`] if the configured token has become
+    /// Returns [`ErrorType::Unauthorized`] if the configured token has become
     /// invalid due to expiration, revokation, etc.
     ///
-    /// [`Error::Unauthorized`]: ../enum.Error.html#variant.Unauthorized
+    /// [`ErrorType::Unauthorized`]: ../error/enum.ErrorType.html#variant.Unauthorized
     pub async fn verify(&self, request: Request) -> Result<()> {
         self.make_request(request).await?;
 
@@ -1583,7 +2107,7 @@ impl Client {
             ),
             StatusCode::TOO_MANY_REQUESTS => tracing::warn!("429 response: {:?}", resp),
             StatusCode::SERVICE_UNAVAILABLE => {
-                return Err(Error::ServiceUnavailable { response: resp })
+                return Err(Error::new(ErrorType::ServiceUnavailable { response: resp }))
             }
             _ => {}
         }
@@ -1591,15 +2115,18 @@ impl Client {
         let bytes = resp
             .bytes()
             .await
-            .map_err(|source| Error::ChunkingResponse { source })?;
+            .map_err(|source| Error::with_source(ErrorType::ChunkingResponse, source))?;
 
         let mut bytes_b = bytes.as_ref().to_vec();
 
-        let error =
-            crate::json_from_slice::<ApiError>(&mut bytes_b).map_err(|source| Error::Parsing {
-                body: bytes.to_vec(),
+        let error = crate::json_from_slice::<ApiError>(&mut bytes_b).map_err(|source| {
+            Error::with_source(
+                ErrorType::Parsing {
+                    body: bytes.to_vec(),
+                },
                 source,
-            })?;
+            )
+        })?;
 
         if let ApiError::General(ref general) = error {
             if let ErrorCode::Other(num) = general.code {
@@ -1607,11 +2134,11 @@ impl Client {
             }
         }
 
-        Err(Error::Response {
+        Err(Error::new(ErrorType::Response {
             body: bytes.as_ref().to_vec(),
             error,
             status,
-        })
+        }))
     }
 }
 
@@ -1620,9 +2147,11 @@ impl From<ReqwestClient> for Client {
         Self {
             state: Arc::new(State {
                 http: reqwest_client,
-                ratelimiter: Some(Ratelimiter::new()),
+                #[cfg(not(target_arch = "wasm32"))]
+                ratelimiter: Some(Box::new(InMemoryRatelimiter::new())),
                 token_invalid: AtomicBool::new(false),
                 token: None,
+                trace_propagator: None,
                 use_http: false,
                 default_allowed_mentions: None,
             }),
@@ -1635,17 +2164,21 @@ fn parse_webhook_url(
     url: impl AsRef<str>,
 ) -> std::result::Result<(WebhookId, Option<String>), UrlError> {
     let url = Url::parse(url.as_ref())?;
-    let mut segments = url.path_segments().ok_or(UrlError::SegmentMissing)?;
+    let mut segments = url
+        .path_segments()
+        .ok_or_else(|| UrlError::new(UrlErrorType::SegmentMissing))?;
 
     segments
         .next()
         .filter(|s| s == &"api")
-        .ok_or(UrlError::SegmentMissing)?;
+        .ok_or_else(|| UrlError::new(UrlErrorType::SegmentMissing))?;
     segments
         .next()
         .filter(|s| s == &"webhooks")
-        .ok_or(UrlError::SegmentMissing)?;
-    let id = segments.next().ok_or(UrlError::SegmentMissing)?;
+        .ok_or_else(|| UrlError::new(UrlErrorType::SegmentMissing))?;
+    let id = segments
+        .next()
+        .ok_or_else(|| UrlError::new(UrlErrorType::SegmentMissing))?;
     let token = segments.next();
 
     Ok((WebhookId(id.parse()?), token.map(String::from)))
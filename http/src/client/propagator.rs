@@ -0,0 +1,46 @@
+use http::HeaderMap;
+use std::fmt::Debug;
+
+/// Propagator of trace contexts into the headers of requests.
+///
+/// Requests are made in the `http request` span, so a propagator can take
+/// the context of the current span and inject it into the headers, such as
+/// the `traceparent` header of the W3C Trace Context format. A proxy or
+/// tracing backend between the client and Discord can then connect the
+/// requests to the traces of the code that made them.
+///
+/// This is usually implemented over a propagator of a tracing library such as
+/// OpenTelemetry. Headers are given as the [`http`] crate's [`HeaderMap`], so
+/// propagators don't depend on the HTTP client used by this crate.
+///
+/// # Examples
+///
+/// Propagate a fixed header, such as to tag the requests of a process:
+///
+/// ```rust
+/// use http::{HeaderMap, HeaderValue};
+/// use twilight_http::client::{Client, TracePropagator};
+///
+/// #[derive(Debug)]
+/// struct Process;
+///
+/// impl TracePropagator for Process {
+///     fn inject(&self, headers: &mut HeaderMap) {
+///         headers.insert("x-process", HeaderValue::from_static("worker-1"));
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::builder()
+///     .token("my token")
+///     .trace_propagator(Box::new(Process))
+///     .build()?;
+/// # Ok(()) }
+/// ```
+///
+/// [`HeaderMap`]: https://docs.rs/http/0.2/http/header/struct.HeaderMap.html
+/// [`http`]: https://crates.io/crates/http
+pub trait TracePropagator: Debug + Send + Sync {
+    /// Inject the context of the current span into the headers of a request.
+    fn inject(&self, headers: &mut HeaderMap);
+}
@@ -1,6 +1,5 @@
-use crate::{api_error::ApiError, ratelimiting::RatelimitError};
-use futures_channel::oneshot::Canceled;
-use reqwest::{header::InvalidHeaderValue, Error as ReqwestError, Response, StatusCode};
+use crate::api_error::ApiError;
+use reqwest::{Response, StatusCode};
 use std::{
     error::Error as StdError,
     fmt::{Display, Error as FmtError, Formatter, Result as FmtResult},
@@ -16,167 +15,278 @@ use simd_json::Error as JsonError;
 
 pub type Result<T, E = Error> = StdResult<T, E>;
 
+/// Parsing a webhook URL failed.
 #[derive(Debug)]
-#[non_exhaustive]
-pub enum UrlError {
-    UrlParsing { source: UrlParseError },
-    IdParsing { source: ParseIntError },
-    SegmentMissing,
+pub struct UrlError {
+    kind: UrlErrorType,
+    source: Option<Box<dyn StdError + Send + Sync>>,
+}
+
+impl UrlError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UrlErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (UrlErrorType, Option<Box<dyn StdError + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    pub(crate) const fn new(kind: UrlErrorType) -> Self {
+        Self { kind, source: None }
+    }
 }
 
 impl Display for UrlError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::UrlParsing { source, .. } => write!(f, "Url path couldn't be parsed: {}", source),
-            Self::IdParsing { source, .. } => {
-                write!(f, "Url path segment wasn't a valid ID: {}", source)
-            }
-            Self::SegmentMissing => f.write_str("Url was missing a required path segment"),
+        match &self.kind {
+            UrlErrorType::UrlParsing => f.write_str("Url path couldn't be parsed"),
+            UrlErrorType::IdParsing => f.write_str("Url path segment wasn't a valid ID"),
+            UrlErrorType::SegmentMissing => f.write_str("Url was missing a required path segment"),
         }
     }
 }
 
 impl StdError for UrlError {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
-        match self {
-            Self::UrlParsing { source, .. } => Some(source),
-            Self::IdParsing { source, .. } => Some(source),
-            Self::SegmentMissing => None,
-        }
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn StdError + 'static))
     }
 }
 
 impl From<UrlParseError> for UrlError {
     fn from(source: UrlParseError) -> Self {
-        Self::UrlParsing { source }
+        Self {
+            kind: UrlErrorType::UrlParsing,
+            source: Some(Box::new(source)),
+        }
     }
 }
 
 impl From<ParseIntError> for UrlError {
     fn from(source: ParseIntError) -> Self {
-        Self::IdParsing { source }
+        Self {
+            kind: UrlErrorType::IdParsing,
+            source: Some(Box::new(source)),
+        }
     }
 }
 
+/// Type of [`UrlError`] that occurred.
+///
+/// [`UrlError`]: struct.UrlError.html
 #[derive(Debug)]
 #[non_exhaustive]
-pub enum Error {
-    BuildingClient {
-        source: ReqwestError,
-    },
-    ChunkingResponse {
-        source: ReqwestError,
-    },
-    CreatingHeader {
-        name: String,
-        source: InvalidHeaderValue,
-    },
-    Formatting {
-        source: FmtError,
-    },
-    Json {
-        source: JsonError,
-    },
-    Parsing {
-        body: Vec<u8>,
-        source: JsonError,
-    },
-    Url {
-        source: UrlError,
-    },
-    Ratelimiting {
-        source: RatelimitError,
-    },
-    RequestCanceled {
-        source: Canceled,
-    },
-    RequestError {
-        source: ReqwestError,
-    },
-    Response {
-        body: Vec<u8>,
-        error: ApiError,
-        status: StatusCode,
-    },
-    /// API service is unavailable. Consider re-sending the request at a
-    /// later time.
-    ///
-    /// This may occur during Discord API stability incidents.
-    ServiceUnavailable {
-        response: Response,
-    },
-    /// Token in use has become revoked or is otherwise invalid.
-    ///
-    /// This can occur if a bot token is invalidated or an access token expires
-    /// or is revoked. Recreate the client to configure a new token.
-    Unauthorized,
+pub enum UrlErrorType {
+    /// URL couldn't be parsed.
+    UrlParsing,
+    /// Path segment wasn't a valid ID.
+    IdParsing,
+    /// URL was missing a required path segment.
+    SegmentMissing,
+}
+
+/// Error from the HTTP client.
+///
+/// The [`kind`] of the error can be matched on to handle categories of errors,
+/// such as retrying requests that Discord responded to with a server error.
+///
+/// [`kind`]: #method.kind
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorType,
+    source: Option<Box<dyn StdError + Send + Sync>>,
+}
+
+impl Error {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ErrorType, Option<Box<dyn StdError + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    pub(crate) fn new(kind: ErrorType) -> Self {
+        Self { kind, source: None }
+    }
+
+    pub(crate) fn with_source(
+        kind: ErrorType,
+        source: impl Into<Box<dyn StdError + Send + Sync>>,
+    ) -> Self {
+        Self {
+            kind,
+            source: Some(source.into()),
+        }
+    }
 }
 
 impl From<FmtError> for Error {
     fn from(source: FmtError) -> Self {
-        Self::Formatting { source }
+        Self::with_source(ErrorType::Formatting, source)
     }
 }
 
 impl From<JsonError> for Error {
     fn from(source: JsonError) -> Self {
-        Self::Json { source }
+        Self::with_source(ErrorType::Json, source)
     }
 }
 
 impl From<UrlError> for Error {
     fn from(source: UrlError) -> Self {
-        Self::Url { source }
+        Self::with_source(ErrorType::Url, source)
     }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::BuildingClient { .. } => {
+        match &self.kind {
+            ErrorType::BuildingClient => {
                 f.write_str("HTTP client couldn't be built due to a reqwest client error")
             }
-            Self::ChunkingResponse { .. } => f.write_str("Chunking the response failed"),
-            Self::CreatingHeader { name, .. } => {
+            ErrorType::ChunkingResponse => f.write_str("Chunking the response failed"),
+            ErrorType::CreatingHeader { name } => {
                 write!(f, "Parsing the value for header {} failed", name)
             }
-            Self::Formatting { .. } => f.write_str("Formatting a string failed"),
-            Self::Json { .. } => f.write_str("Given value couldn't be serialized"),
-            Self::Parsing { body, .. } => {
+            ErrorType::Formatting => f.write_str("Formatting a string failed"),
+            ErrorType::Json => f.write_str("Given value couldn't be serialized"),
+            ErrorType::Parsing { body } => {
                 write!(f, "Response body couldn't be deserialized: {:?}", body)
             }
-            Self::Url { source, .. } => write!(f, "{}", source),
-            Self::Ratelimiting { .. } => f.write_str("Ratelimiting failure"),
-            Self::RequestCanceled { .. } => {
+            ErrorType::Url => match &self.source {
+                Some(source) => Display::fmt(source, f),
+                None => f.write_str("Url couldn't be parsed"),
+            },
+            ErrorType::Ratelimiting => f.write_str("Ratelimiting failure"),
+            ErrorType::RequestCanceled => {
                 f.write_str("Request was canceled either before or while being sent")
             }
-            Self::RequestError { .. } => f.write_str("Parsing or sending the response failed"),
-            Self::Response { error, status, .. } => write!(
+            ErrorType::RequestError => f.write_str("Parsing or sending the response failed"),
+            ErrorType::Response { error, status, .. } => write!(
                 f,
                 "Response error: status code {}, error: {}",
                 status, error
             ),
-            Self::ServiceUnavailable { .. } => {
+            ErrorType::ServiceUnavailable { .. } => {
                 f.write_str("api may be temporarily unavailable (received a 503)")
             }
-            Self::Unauthorized => f.write_str("token in use is invalid, expired, or is revoked"),
+            ErrorType::Unauthorized => {
+                f.write_str("token in use is invalid, expired, or is revoked")
+            }
         }
     }
 }
 
 impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
-        match self {
-            Self::CreatingHeader { source, .. } => Some(source),
-            Self::Formatting { source } => Some(source),
-            Self::Json { source } | Self::Parsing { source, .. } => Some(source),
-            Self::Url { source } => Some(source),
-            Self::Ratelimiting { source } => Some(source),
-            Self::RequestCanceled { source } => Some(source),
-            Self::BuildingClient { source }
-            | Self::ChunkingResponse { source }
-            | Self::RequestError { source } => Some(source),
-            Self::Response { .. } | Self::ServiceUnavailable { .. } | Self::Unauthorized => None,
-        }
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn StdError + 'static))
+    }
+}
+
+/// Type of [`Error`] that occurred.
+///
+/// [`Error`]: struct.Error.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ErrorType {
+    /// Building the reqwest client failed.
+    BuildingClient,
+    /// Chunking the body of a response failed.
+    ChunkingResponse,
+    /// Value of a header is invalid.
+    CreatingHeader {
+        /// Name of the header.
+        name: String,
+    },
+    /// Formatting a string failed.
+    Formatting,
+    /// Serializing the body of a request failed.
+    Json,
+    /// Deserializing the body of a response failed.
+    Parsing {
+        /// Body of the response.
+        body: Vec<u8>,
+    },
+    /// Ratelimiting the request failed.
+    Ratelimiting,
+    /// Request was canceled before or while being sent.
+    RequestCanceled,
+    /// Sending the request or receiving its response failed.
+    RequestError,
+    /// API responded with an error.
+    Response {
+        /// Body of the response.
+        body: Vec<u8>,
+        /// Error returned by the API.
+        error: ApiError,
+        /// Status code of the response.
+        status: StatusCode,
+    },
+    /// API service is unavailable. Consider re-sending the request at a
+    /// later time.
+    ///
+    /// This may occur during Discord API stability incidents.
+    ServiceUnavailable {
+        /// Response of the API.
+        response: Response,
+    },
+    /// Token in use has become revoked or is otherwise invalid.
+    ///
+    /// This can occur if a bot token is invalidated or an access token expires
+    /// or is revoked. Recreate the client to configure a new token.
+    Unauthorized,
+    /// URL of a route is invalid.
+    Url,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ErrorType, UrlError, UrlErrorType};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error as StdError, fmt::Debug};
+
+    assert_fields!(ErrorType::CreatingHeader: name);
+    assert_fields!(ErrorType::Parsing: body);
+    assert_fields!(ErrorType::Response: body, error, status);
+    assert_fields!(ErrorType::ServiceUnavailable: response);
+    assert_impl_all!(Error: Debug, StdError, Send, Sync);
+    assert_impl_all!(ErrorType: Debug, Send, Sync);
+    assert_impl_all!(UrlError: Debug, StdError, Send, Sync);
+    assert_impl_all!(UrlErrorType: Debug, Send, Sync);
+
+    #[test]
+    fn test_into_parts() {
+        let error = Error::from(UrlError::new(UrlErrorType::SegmentMissing));
+        assert!(matches!(error.kind(), ErrorType::Url));
+        assert_eq!("Url was missing a required path segment", error.to_string());
+
+        let (kind, source) = error.into_parts();
+        assert!(matches!(kind, ErrorType::Url));
+        assert!(source.unwrap().downcast::<UrlError>().is_ok());
+        assert!(Error::new(ErrorType::Unauthorized).into_source().is_none());
     }
 }
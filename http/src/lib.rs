@@ -60,11 +60,39 @@
 //!
 //! This is enabled by default.
 //!
+//! ## WASM
+//!
+//! `twilight-http` can be compiled to `wasm32-unknown-unknown`, such as for
+//! interaction handlers running on edge workers. Requests are then made with
+//! the fetch API of the environment, which handles TLS, so the TLS features
+//! don't apply.
+//!
+//! There is no ratelimiter on WASM, since a worker usually doesn't live long
+//! enough to track buckets, and requests can't be proxied or time out. The
+//! `Client::ratelimiter`, `ClientBuilder::proxy`, `ClientBuilder::ratelimiter`,
+//! and `ClientBuilder::timeout` methods aren't available, and the futures of
+//! requests aren't `Send`.
+//!
+//! ## Tracing
+//!
+//! Each request is made in a `http request` span at the debug level, with
+//! the fields:
+//!
+//! - `method`: HTTP method of the request;
+//! - `route`: ratelimit path of the request, such as
+//!   `ChannelsIdMessages(123)`;
+//! - `status`: status code of the response, once it's received.
+//!
+//! The `status` field stays empty if the request fails before a response is
+//! received, such as when it times out. The context of the span can be
+//! propagated to the headers of the requests by a [`TracePropagator`].
+//!
 //! [`native-tls`]: https://crates.io/crates/native-tls
 //! [`reqwest`]: https://crates.io/crates/reqwest
 //! [`rustls`]: https://crates.io/crates/rustls
 //! [`serde_json`]: https://crates.io/crates/serde_json
 //! [`simd-json`]: https://crates.io/crates/simd-json
+//! [`TracePropagator`]: client/trait.TracePropagator.html
 //! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
 //! [discord link]: https://discord.gg/7jj8n7D
 //! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
@@ -92,12 +120,13 @@
 pub mod api_error;
 pub mod client;
 pub mod error;
-pub mod ratelimiting;
 pub mod request;
 pub mod routing;
 
+pub use twilight_http_ratelimiting as ratelimiting;
+
 /// Discord API version used by this crate.
-pub const API_VERSION: u8 = 8;
+pub const API_VERSION: u8 = 9;
 
 pub use crate::{
     client::Client,
@@ -121,5 +150,5 @@ pub(crate) use serde_json::to_vec as json_to_vec;
 #[cfg(feature = "simd-json")]
 pub(crate) use simd_json::to_vec as json_to_vec;
 
-#[cfg(not(any(feature = "native", feature = "rustls")))]
+#[cfg(not(any(feature = "native", feature = "rustls", target_arch = "wasm32")))]
 compile_error!("Either the `native` or `rustls` feature must be enabled.");
@@ -0,0 +1,99 @@
+use crate::request::prelude::*;
+use twilight_model::{
+    application::command::{Command, CommandOption},
+    id::ApplicationId,
+};
+
+#[derive(Serialize)]
+struct CreateGlobalCommandFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_permission: Option<bool>,
+    description: String,
+    name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    options: Vec<CommandOption>,
+}
+
+/// Create a global command, available in every guild the application is in
+/// and in direct messages.
+///
+/// Creating a command with the name of an existing command overwrites it.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::ApplicationId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token");
+/// let application_id = ApplicationId(123);
+///
+/// let command = client
+///     .create_global_command(application_id, "ping", "Check if the bot is alive")
+///     .await?;
+/// # Ok(()) }
+/// ```
+pub struct CreateGlobalCommand<'a> {
+    application_id: ApplicationId,
+    fields: CreateGlobalCommandFields,
+    fut: Option<Pending<'a, Command>>,
+    http: &'a Client,
+}
+
+impl<'a> CreateGlobalCommand<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        application_id: ApplicationId,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            application_id,
+            fields: CreateGlobalCommandFields {
+                default_permission: None,
+                description: description.into(),
+                name: name.into(),
+                options: Vec::new(),
+            },
+            fut: None,
+            http,
+        }
+    }
+
+    /// Whether the command is enabled by default when the application is added
+    /// to a guild.
+    pub fn default_permission(mut self, default_permission: bool) -> Self {
+        self.fields.default_permission.replace(default_permission);
+
+        self
+    }
+
+    /// Add an option to the command.
+    pub fn option(mut self, option: CommandOption) -> Self {
+        self.fields.options.push(option);
+
+        self
+    }
+
+    /// Add multiple options to the command.
+    pub fn options(mut self, options: impl IntoIterator<Item = CommandOption>) -> Self {
+        self.fields.options.extend(options);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::CreateGlobalCommand {
+                application_id: self.application_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateGlobalCommand<'_>, Command);
@@ -0,0 +1,85 @@
+use crate::request::prelude::*;
+use twilight_model::{
+    application::command::{Command, CommandOption},
+    id::{ApplicationId, GuildId},
+};
+
+#[derive(Serialize)]
+struct CreateGuildCommandFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_permission: Option<bool>,
+    description: String,
+    name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    options: Vec<CommandOption>,
+}
+
+/// Create a command that's only available in a guild.
+///
+/// Creating a command with the name of an existing command in the guild
+/// overwrites it.
+pub struct CreateGuildCommand<'a> {
+    application_id: ApplicationId,
+    fields: CreateGuildCommandFields,
+    fut: Option<Pending<'a, Command>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> CreateGuildCommand<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        application_id: ApplicationId,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            application_id,
+            fields: CreateGuildCommandFields {
+                default_permission: None,
+                description: description.into(),
+                name: name.into(),
+                options: Vec::new(),
+            },
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    /// Whether the command is enabled by default in the guild.
+    pub fn default_permission(mut self, default_permission: bool) -> Self {
+        self.fields.default_permission.replace(default_permission);
+
+        self
+    }
+
+    /// Add an option to the command.
+    pub fn option(mut self, option: CommandOption) -> Self {
+        self.fields.options.push(option);
+
+        self
+    }
+
+    /// Add multiple options to the command.
+    pub fn options(mut self, options: impl IntoIterator<Item = CommandOption>) -> Self {
+        self.fields.options.extend(options);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::CreateGuildCommand {
+                application_id: self.application_id.0,
+                guild_id: self.guild_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateGuildCommand<'_>, Command);
@@ -0,0 +1,31 @@
+use crate::request::prelude::*;
+use twilight_model::{application::command::Command, id::ApplicationId};
+
+/// Get the global commands of an application.
+pub struct GetGlobalCommands<'a> {
+    application_id: ApplicationId,
+    fut: Option<Pending<'a, Vec<Command>>>,
+    http: &'a Client,
+}
+
+impl<'a> GetGlobalCommands<'a> {
+    pub(crate) fn new(http: &'a Client, application_id: ApplicationId) -> Self {
+        Self {
+            application_id,
+            fut: None,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGlobalCommands {
+                application_id: self.application_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetGlobalCommands<'_>, Vec<Command>);
@@ -0,0 +1,39 @@
+use crate::request::prelude::*;
+use twilight_model::{
+    application::command::Command,
+    id::{ApplicationId, GuildId},
+};
+
+/// Get the commands of an application in a guild.
+///
+/// This doesn't include the global commands of the application.
+pub struct GetGuildCommands<'a> {
+    application_id: ApplicationId,
+    fut: Option<Pending<'a, Vec<Command>>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> GetGuildCommands<'a> {
+    pub(crate) fn new(http: &'a Client, application_id: ApplicationId, guild_id: GuildId) -> Self {
+        Self {
+            application_id,
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildCommands {
+                application_id: self.application_id.0,
+                guild_id: self.guild_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetGuildCommands<'_>, Vec<Command>);
@@ -0,0 +1,69 @@
+use crate::request::prelude::*;
+use twilight_model::{application::callback::InteractionResponse, id::InteractionId};
+
+/// Respond to an interaction.
+///
+/// Interactions must be responded to within 3 seconds, after which their
+/// token is invalidated. Respond with a deferred response to send a message
+/// later.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::{
+///     application::callback::{InteractionResponse, InteractionResponseData},
+///     id::InteractionId,
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token");
+/// let response = InteractionResponse::ChannelMessageWithSource(InteractionResponseData {
+///     content: Some("Pong!".to_owned()),
+///     ..InteractionResponseData::default()
+/// });
+///
+/// client
+///     .interaction_callback(InteractionId(123), "interaction token", response)
+///     .await?;
+/// # Ok(()) }
+/// ```
+pub struct InteractionCallback<'a> {
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+    interaction_id: InteractionId,
+    interaction_token: String,
+    response: InteractionResponse,
+}
+
+impl<'a> InteractionCallback<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        interaction_id: InteractionId,
+        interaction_token: impl Into<String>,
+        response: InteractionResponse,
+    ) -> Self {
+        Self {
+            fut: None,
+            http,
+            interaction_id,
+            interaction_token: interaction_token.into(),
+            response,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.verify(Request::from((
+            crate::json_to_vec(&self.response)?,
+            Route::InteractionCallback {
+                interaction_id: self.interaction_id.0,
+                interaction_token: self.interaction_token.clone(),
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(InteractionCallback<'_>, ());
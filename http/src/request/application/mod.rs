@@ -0,0 +1,25 @@
+mod create_global_command;
+mod create_guild_command;
+mod delete_global_command;
+mod delete_guild_command;
+mod get_global_commands;
+mod get_guild_commands;
+mod interaction_callback;
+mod update_followup_message;
+mod update_global_command;
+mod update_guild_command;
+
+pub use self::{
+    create_global_command::CreateGlobalCommand,
+    create_guild_command::CreateGuildCommand,
+    delete_global_command::DeleteGlobalCommand,
+    delete_guild_command::DeleteGuildCommand,
+    get_global_commands::GetGlobalCommands,
+    get_guild_commands::GetGuildCommands,
+    interaction_callback::InteractionCallback,
+    update_followup_message::{
+        UpdateFollowupMessage, UpdateFollowupMessageError, UpdateFollowupMessageErrorType,
+    },
+    update_global_command::UpdateGlobalCommand,
+    update_guild_command::UpdateGuildCommand,
+};
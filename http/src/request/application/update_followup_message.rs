@@ -0,0 +1,362 @@
+use crate::request::{channel::message::allowed_mentions::AllowedMentions, prelude::*};
+use reqwest::{
+    multipart::{Form, Part},
+    Body,
+};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    application::component::Component,
+    channel::{embed::Embed, Message},
+    id::{ApplicationId, MessageId},
+};
+
+/// The error created when a followup message can not be updated as configured.
+#[derive(Debug)]
+pub struct UpdateFollowupMessageError {
+    kind: UpdateFollowupMessageErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl UpdateFollowupMessageError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateFollowupMessageErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        UpdateFollowupMessageErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for UpdateFollowupMessageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UpdateFollowupMessageErrorType::ComponentCount { .. } => {
+                f.write_str("more than 5 action rows were provided")
+            }
+            UpdateFollowupMessageErrorType::ContentInvalid { .. } => {
+                f.write_str("the message content is invalid")
+            }
+            UpdateFollowupMessageErrorType::EmbedTooLarge { .. } => {
+                f.write_str("the embed's contents are too long")
+            }
+        }
+    }
+}
+
+impl Error for UpdateFollowupMessageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`UpdateFollowupMessageError`] that occurred.
+///
+/// [`UpdateFollowupMessageError`]: struct.UpdateFollowupMessageError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateFollowupMessageErrorType {
+    /// Returned when there are more than 5 action rows.
+    ComponentCount {
+        /// Provided components.
+        components: Vec<Component>,
+    },
+    /// Returned when the content is over 2000 UTF-16 characters.
+    ContentInvalid {
+        /// Provided content.
+        content: String,
+    },
+    /// Returned when the length of an embed is over 6000 characters.
+    EmbedTooLarge {
+        /// Provided embed.
+        embed: Box<Embed>,
+    },
+}
+
+#[derive(Default, Serialize)]
+struct UpdateFollowupMessageFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
+    // As with updating a message, a nested Option distinguishes removing the
+    // field from leaving it unchanged.
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<Option<String>>,
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embeds: Option<Option<Vec<Embed>>>,
+}
+
+/// Update a followup message of an interaction, by the ID of the application,
+/// the token of the interaction, and the ID of the message.
+///
+/// You can pass `None` to [`content`] and [`embeds`] to remove the associated
+/// field. Files can be attached with [`attachment`], in which case the request
+/// is sent as a multipart form.
+///
+/// # Examples
+///
+/// Replace the content with `"test update"`:
+///
+/// ```rust,no_run
+/// use twilight_http::Client;
+/// use twilight_model::id::{ApplicationId, MessageId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let client = Client::new("my token");
+/// client
+///     .update_followup_message(ApplicationId(1), "interaction token", MessageId(2))
+///     .content("test update".to_owned())?
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`attachment`]: #method.attachment
+/// [`content`]: #method.content
+/// [`embeds`]: #method.embeds
+pub struct UpdateFollowupMessage<'a> {
+    application_id: ApplicationId,
+    attachments: HashMap<String, Body>,
+    fields: UpdateFollowupMessageFields,
+    fut: Option<Pending<'a, Message>>,
+    http: &'a Client,
+    interaction_token: String,
+    message_id: MessageId,
+}
+
+impl<'a> UpdateFollowupMessage<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        application_id: ApplicationId,
+        interaction_token: impl Into<String>,
+        message_id: MessageId,
+    ) -> Self {
+        Self {
+            application_id,
+            attachments: HashMap::new(),
+            fields: UpdateFollowupMessageFields::default(),
+            fut: None,
+            http,
+            interaction_token: interaction_token.into(),
+            message_id,
+        }
+    }
+
+    /// Set the allowed mentions in the message.
+    ///
+    /// Use the [`build_solo`] method to get a [`AllowedMentions`] structure.
+    ///
+    /// [`build_solo`]: ../channel/message/allowed_mentions/struct.AllowedMentionsBuilder.html#method.build_solo
+    /// [`AllowedMentions`]: ../channel/message/allowed_mentions/struct.AllowedMentions.html
+    pub fn allowed_mentions(mut self, allowed: AllowedMentions) -> Self {
+        self.fields.allowed_mentions.replace(allowed);
+
+        self
+    }
+
+    /// Attach a new file to the message.
+    ///
+    /// The file is raw binary data. It can be an image, or any other kind of file.
+    pub fn attachment(mut self, name: impl Into<String>, file: impl Into<Body>) -> Self {
+        self.attachments.insert(name.into(), file.into());
+
+        self
+    }
+
+    /// Insert multiple attachments into the message.
+    pub fn attachments<N: Into<String>, F: Into<Body>>(
+        mut self,
+        attachments: impl IntoIterator<Item = (N, F)>,
+    ) -> Self {
+        for (name, file) in attachments {
+            self = self.attachment(name, file);
+        }
+
+        self
+    }
+
+    /// Set the components of the message, replacing the existing components.
+    ///
+    /// Pass an empty list to remove the components. Components must be
+    /// wrapped in up to 5 action rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateFollowupMessageErrorType::ComponentCount`] if there are
+    /// more than 5 action rows.
+    ///
+    /// [`UpdateFollowupMessageErrorType::ComponentCount`]: enum.UpdateFollowupMessageErrorType.html#variant.ComponentCount
+    pub fn components(
+        mut self,
+        components: Vec<Component>,
+    ) -> Result<Self, UpdateFollowupMessageError> {
+        if !validate::components(&components) {
+            return Err(UpdateFollowupMessageError {
+                kind: UpdateFollowupMessageErrorType::ComponentCount { components },
+                source: None,
+            });
+        }
+
+        self.fields.components.replace(components);
+
+        Ok(self)
+    }
+
+    /// Set the content of the message.
+    ///
+    /// Pass `None` if you want to remove the message content.
+    ///
+    /// The maximum length is 2000 UTF-16 characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateFollowupMessageErrorType::ContentInvalid`] if the content
+    /// length is too long.
+    ///
+    /// [`UpdateFollowupMessageErrorType::ContentInvalid`]: enum.UpdateFollowupMessageErrorType.html#variant.ContentInvalid
+    pub fn content(
+        self,
+        content: impl Into<Option<String>>,
+    ) -> Result<Self, UpdateFollowupMessageError> {
+        self._content(content.into())
+    }
+
+    fn _content(mut self, content: Option<String>) -> Result<Self, UpdateFollowupMessageError> {
+        if let Some(content_ref) = content.as_ref() {
+            if !validate::content_limit(content_ref) {
+                return Err(UpdateFollowupMessageError {
+                    kind: UpdateFollowupMessageErrorType::ContentInvalid {
+                        content: content.expect("content is known to be some"),
+                    },
+                    source: None,
+                });
+            }
+        }
+
+        self.fields.content.replace(content);
+
+        Ok(self)
+    }
+
+    /// Set the embeds of the message, replacing the existing embeds.
+    ///
+    /// Pass `None` if you want to remove the message embeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateFollowupMessageErrorType::EmbedTooLarge`] if one of
+    /// the embeds is too large.
+    ///
+    /// [`UpdateFollowupMessageErrorType::EmbedTooLarge`]: enum.UpdateFollowupMessageErrorType.html#variant.EmbedTooLarge
+    pub fn embeds(
+        self,
+        embeds: impl Into<Option<Vec<Embed>>>,
+    ) -> Result<Self, UpdateFollowupMessageError> {
+        self._embeds(embeds.into())
+    }
+
+    fn _embeds(mut self, embeds: Option<Vec<Embed>>) -> Result<Self, UpdateFollowupMessageError> {
+        if let Some(embeds) = embeds.as_ref() {
+            for embed in embeds {
+                if let Err(source) = validate::embed(embed) {
+                    return Err(UpdateFollowupMessageError {
+                        kind: UpdateFollowupMessageErrorType::EmbedTooLarge {
+                            embed: Box::new(embed.clone()),
+                        },
+                        source: Some(Box::new(source)),
+                    });
+                }
+            }
+        }
+
+        self.fields.embeds.replace(embeds);
+
+        Ok(self)
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let route = Route::UpdateFollowupMessage {
+            application_id: self.application_id.0,
+            interaction_token: self.interaction_token.clone(),
+            message_id: self.message_id.0,
+        };
+
+        let request = if self.attachments.is_empty() {
+            Request::from((crate::json_to_vec(&self.fields)?, route))
+        } else {
+            let mut form = Form::new();
+
+            for (index, (name, file)) in self.attachments.drain().enumerate() {
+                form = form.part(format!("{}", index), Part::stream(file).file_name(name));
+            }
+
+            let body = crate::json_to_vec(&self.fields)?;
+            form = form.part("payload_json", Part::bytes(body));
+
+            Request::from((form, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateFollowupMessage<'_>, Message);
+
+#[cfg(test)]
+mod tests {
+    use super::{UpdateFollowupMessage, UpdateFollowupMessageErrorType};
+    use crate::Client;
+    use twilight_model::id::{ApplicationId, MessageId};
+
+    #[test]
+    fn test_content_remove_serializes_null() {
+        let client = Client::new("token");
+        let request = UpdateFollowupMessage::new(&client, ApplicationId(1), "token", MessageId(2))
+            .content(None)
+            .unwrap();
+
+        assert_eq!(
+            br#"{"content":null}"#.to_vec(),
+            crate::json_to_vec(&request.fields).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_content_too_long() {
+        let client = Client::new("token");
+        let error = UpdateFollowupMessage::new(&client, ApplicationId(1), "token", MessageId(2))
+            .content("a".repeat(2001))
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            error.kind(),
+            UpdateFollowupMessageErrorType::ContentInvalid { .. }
+        ));
+    }
+}
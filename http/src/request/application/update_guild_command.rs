@@ -0,0 +1,90 @@
+use crate::request::prelude::*;
+use twilight_model::{
+    application::command::{Command, CommandOption},
+    id::{ApplicationId, CommandId, GuildId},
+};
+
+#[derive(Default, Serialize)]
+struct UpdateGuildCommandFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_permission: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<Vec<CommandOption>>,
+}
+
+/// Update a command in a guild.
+///
+/// Only the provided fields are updated.
+pub struct UpdateGuildCommand<'a> {
+    application_id: ApplicationId,
+    command_id: CommandId,
+    fields: UpdateGuildCommandFields,
+    fut: Option<Pending<'a, Command>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> UpdateGuildCommand<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        application_id: ApplicationId,
+        guild_id: GuildId,
+        command_id: CommandId,
+    ) -> Self {
+        Self {
+            application_id,
+            command_id,
+            fields: UpdateGuildCommandFields::default(),
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    /// Whether the command is enabled by default in the guild.
+    pub fn default_permission(mut self, default_permission: bool) -> Self {
+        self.fields.default_permission.replace(default_permission);
+
+        self
+    }
+
+    /// Set the description of the command.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.fields.description.replace(description.into());
+
+        self
+    }
+
+    /// Set the name of the command.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.fields.name.replace(name.into());
+
+        self
+    }
+
+    /// Set the options of the command, replacing the existing options.
+    pub fn options(mut self, options: impl IntoIterator<Item = CommandOption>) -> Self {
+        self.fields.options.replace(options.into_iter().collect());
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::UpdateGuildCommand {
+                application_id: self.application_id.0,
+                command_id: self.command_id.0,
+                guild_id: self.guild_id.0,
+            },
+        )))));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateGuildCommand<'_>, Command);
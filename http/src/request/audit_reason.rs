@@ -27,6 +27,9 @@ mod private {
     impl<'a> Sealed for DeleteChannelPermissionConfigured<'a> {}
     impl<'a> Sealed for DeletePin<'a> {}
     impl<'a> Sealed for UpdateChannelPermissionConfigured<'a> {}
+    impl<'a> Sealed for CreateAutoModerationRule<'a> {}
+    impl<'a> Sealed for DeleteAutoModerationRule<'a> {}
+    impl<'a> Sealed for UpdateAutoModerationRule<'a> {}
     impl<'a> Sealed for CreateBan<'a> {}
     impl<'a> Sealed for DeleteBan<'a> {}
     impl<'a> Sealed for CreateGuildChannel<'a> {}
@@ -34,6 +37,9 @@ mod private {
     impl<'a> Sealed for CreateEmoji<'a> {}
     impl<'a> Sealed for DeleteEmoji<'a> {}
     impl<'a> Sealed for UpdateEmoji<'a> {}
+    impl<'a> Sealed for CreateGuildSticker<'a> {}
+    impl<'a> Sealed for DeleteGuildSticker<'a> {}
+    impl<'a> Sealed for UpdateGuildSticker<'a> {}
     impl<'a> Sealed for CreateGuildIntegration<'a> {}
     impl<'a> Sealed for DeleteGuildIntegration<'a> {}
     impl<'a> Sealed for UpdateGuildIntegration<'a> {}
@@ -55,32 +61,76 @@ impl AuditLogReasonError {
         if reason.chars().count() <= Self::AUDIT_REASON_LENGTH {
             Ok(reason)
         } else {
-            Err(AuditLogReasonError::TooLarge { reason })
+            Err(AuditLogReasonError {
+                kind: AuditLogReasonErrorType::TooLarge { reason },
+                source: None,
+            })
         }
     }
 }
 
 /// The error created when a reason can not be used as configured.
-#[derive(Clone, Debug)]
-pub enum AuditLogReasonError {
-    /// Returned when the reason is over 512 UTF-16 characters.
-    TooLarge { reason: String },
+#[derive(Debug)]
+pub struct AuditLogReasonError {
+    kind: AuditLogReasonErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl AuditLogReasonError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &AuditLogReasonErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        AuditLogReasonErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for AuditLogReasonError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::TooLarge { reason } => write!(
+        match &self.kind {
+            AuditLogReasonErrorType::TooLarge { reason } => write!(
                 f,
                 "the audit log reason is {} characters long, but the max is {}",
                 reason.chars().count(),
-                Self::AUDIT_REASON_LENGTH
+                AuditLogReasonError::AUDIT_REASON_LENGTH
             ),
         }
     }
 }
 
-impl Error for AuditLogReasonError {}
+impl Error for AuditLogReasonError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`AuditLogReasonError`] that occurred.
+///
+/// [`AuditLogReasonError`]: struct.AuditLogReasonError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum AuditLogReasonErrorType {
+    /// Returned when the reason is over 512 UTF-16 characters.
+    TooLarge { reason: String },
+}
 
 #[cfg(test)]
 mod test {
@@ -10,14 +10,76 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
+    application::component::Component,
     channel::{embed::Embed, Message},
-    id::ChannelId,
+    id::{ChannelId, StickerId},
 };
 
 /// The error created when a messsage can not be created as configured.
+#[derive(Debug)]
+pub struct CreateMessageError {
+    kind: CreateMessageErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateMessageError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateMessageErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CreateMessageErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateMessageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateMessageErrorType::ComponentCount { .. } => {
+                f.write_str("more than 5 action rows were provided")
+            }
+            CreateMessageErrorType::ContentInvalid { .. } => {
+                f.write_str("the message content is invalid")
+            }
+            CreateMessageErrorType::EmbedTooLarge { .. } => {
+                f.write_str("the embed's contents are too long")
+            }
+            CreateMessageErrorType::StickerCount { .. } => {
+                f.write_str("more than 3 stickers were provided")
+            }
+        }
+    }
+}
+
+impl Error for CreateMessageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateMessageError`] that occurred.
+///
+/// [`CreateMessageError`]: struct.CreateMessageError.html
 #[derive(Clone, Debug)]
 #[non_exhaustive]
-pub enum CreateMessageError {
+pub enum CreateMessageErrorType {
+    /// Returned when there are more than 5 action rows.
+    ComponentCount {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Returned when the content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -27,31 +89,18 @@ pub enum CreateMessageError {
     EmbedTooLarge {
         /// Provided embed.
         embed: Box<Embed>,
-        /// The source of the error.
-        source: EmbedValidationError,
     },
-}
-
-impl Display for CreateMessageError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::ContentInvalid { .. } => f.write_str("the message content is invalid"),
-            Self::EmbedTooLarge { .. } => f.write_str("the embed's contents are too long"),
-        }
-    }
-}
-
-impl Error for CreateMessageError {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Self::ContentInvalid { .. } => None,
-            Self::EmbedTooLarge { source, .. } => Some(source),
-        }
-    }
+    /// Returned when more than 3 stickers are provided.
+    StickerCount {
+        /// Provided sticker IDs.
+        sticker_ids: Vec<StickerId>,
+    },
 }
 
 #[derive(Default, Serialize)]
 pub(crate) struct CreateMessageFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -61,6 +110,8 @@ pub(crate) struct CreateMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     payload_json: Option<Vec<u8>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    sticker_ids: Option<Vec<StickerId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     tts: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) allowed_mentions: Option<AllowedMentions>,
@@ -108,23 +159,49 @@ impl<'a> CreateMessage<'a> {
         }
     }
 
+    /// Set the components of the message.
+    ///
+    /// Components must be wrapped in up to 5 action rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateMessageErrorType::ComponentCount`] if there are more than 5
+    /// action rows.
+    ///
+    /// [`CreateMessageErrorType::ComponentCount`]: enum.CreateMessageErrorType.html#variant.ComponentCount
+    pub fn components(mut self, components: Vec<Component>) -> Result<Self, CreateMessageError> {
+        if !validate::components(&components) {
+            return Err(CreateMessageError {
+                kind: CreateMessageErrorType::ComponentCount { components },
+                source: None,
+            });
+        }
+
+        self.fields.components.replace(components);
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// The maximum length is 2000 UTF-16 characters.
     ///
     /// # Errors
     ///
-    /// Returns [`CreateMessageError::ContentInvalid`] if the content length is
+    /// Returns [`CreateMessageErrorType::ContentInvalid`] if the content length is
     /// too long.
     ///
-    /// [`CreateMessageError::ContentInvalid`]: enum.CreateMessageError.html#variant.ContentInvalid
+    /// [`CreateMessageErrorType::ContentInvalid`]: enum.CreateMessageErrorType.html#variant.ContentInvalid
     pub fn content(self, content: impl Into<String>) -> Result<Self, CreateMessageError> {
         self._content(content.into())
     }
 
     fn _content(mut self, content: String) -> Result<Self, CreateMessageError> {
         if !validate::content_limit(&content) {
-            return Err(CreateMessageError::ContentInvalid { content });
+            return Err(CreateMessageError {
+                kind: CreateMessageErrorType::ContentInvalid { content },
+                source: None,
+            });
         }
 
         self.fields.content.replace(content);
@@ -143,16 +220,18 @@ impl<'a> CreateMessage<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`CreateMessageError::EmbedTooLarge`] if the embed is too large.
+    /// Returns [`CreateMessageErrorType::EmbedTooLarge`] if the embed is too large.
     ///
     /// [the discord docs]: https://discord.com/developers/docs/resources/channel#embed-limits
     /// [`EmbedBuilder`]: ../../../../../twilight_embed_builder/builder/struct.EmbedBuilder.html
-    /// [`CreateMessageError::EmbedTooLarge`]: enum.CreateMessageError.html#variant.EmbedTooLarge
+    /// [`CreateMessageErrorType::EmbedTooLarge`]: enum.CreateMessageErrorType.html#variant.EmbedTooLarge
     pub fn embed(mut self, embed: Embed) -> Result<Self, CreateMessageError> {
         if let Err(source) = validate::embed(&embed) {
-            return Err(CreateMessageError::EmbedTooLarge {
-                embed: Box::new(embed),
-                source,
+            return Err(CreateMessageError {
+                kind: CreateMessageErrorType::EmbedTooLarge {
+                    embed: Box::new(embed),
+                },
+                source: Some(Box::new(source)),
             });
         }
 
@@ -207,6 +286,30 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Set the stickers to send with the message.
+    ///
+    /// A message can have up to 3 stickers, which must be either from a guild
+    /// the current user is in or official stickers.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateMessageErrorType::StickerCount`] if more than 3 stickers
+    /// are provided.
+    ///
+    /// [`CreateMessageErrorType::StickerCount`]: enum.CreateMessageErrorType.html#variant.StickerCount
+    pub fn sticker_ids(mut self, sticker_ids: Vec<StickerId>) -> Result<Self, CreateMessageError> {
+        if !validate::sticker_ids(&sticker_ids) {
+            return Err(CreateMessageError {
+                kind: CreateMessageErrorType::StickerCount { sticker_ids },
+                source: None,
+            });
+        }
+
+        self.fields.sticker_ids.replace(sticker_ids);
+
+        Ok(self)
+    }
+
     /// Specify true if the message is TTS.
     pub fn tts(mut self, tts: bool) -> Self {
         self.fields.tts.replace(tts);
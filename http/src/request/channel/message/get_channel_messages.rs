@@ -10,25 +10,65 @@ use twilight_model::{
 };
 
 /// The error returned if the request can not be created as configured.
-#[derive(Clone, Debug)]
-#[non_exhaustive]
-pub enum GetChannelMessagesError {
-    /// The maximum number of messages to retrieve is either 0 or more than 100.
-    LimitInvalid {
-        /// Provided maximum number of messages to retrieve.
-        limit: u64,
-    },
+#[derive(Debug)]
+pub struct GetChannelMessagesError {
+    kind: GetChannelMessagesErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl GetChannelMessagesError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GetChannelMessagesErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        GetChannelMessagesErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for GetChannelMessagesError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+        match &self.kind {
+            GetChannelMessagesErrorType::LimitInvalid { .. } => f.write_str("the limit is invalid"),
         }
     }
 }
 
-impl Error for GetChannelMessagesError {}
+impl Error for GetChannelMessagesError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`GetChannelMessagesError`] that occurred.
+///
+/// [`GetChannelMessagesError`]: struct.GetChannelMessagesError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum GetChannelMessagesErrorType {
+    /// The maximum number of messages to retrieve is either 0 or more than 100.
+    LimitInvalid {
+        /// Provided maximum number of messages to retrieve.
+        limit: u64,
+    },
+}
 
 #[derive(Default)]
 struct GetChannelMessagesFields {
@@ -65,7 +105,7 @@ struct GetChannelMessagesFields {
 ///
 /// # Errors
 ///
-/// Returns [`GetChannelMessagesError::LimitInvalid`] if the amount is less than 1 or greater than 100.
+/// Returns [`GetChannelMessagesErrorType::LimitInvalid`] if the amount is less than 1 or greater than 100.
 ///
 /// [`ChannelId`]: ../../../../../twilight_model/id/struct.ChannelId.html
 /// [`after`]: #method.after
@@ -74,7 +114,7 @@ struct GetChannelMessagesFields {
 /// [`GetChannelMessagesConfigured`]:
 /// ../get_channel_messages_configured/struct.GetChannelMessagesConfigured.html
 /// [`limit`]: #method.limit
-/// [`GetChannelMessagesError::LimitInvalid`]: enum.GetChannelMessagesError.html#variant.LimitInvalid
+/// [`GetChannelMessagesErrorType::LimitInvalid`]: enum.GetChannelMessagesErrorType.html#variant.LimitInvalid
 pub struct GetChannelMessages<'a> {
     channel_id: ChannelId,
     fields: GetChannelMessagesFields,
@@ -131,13 +171,16 @@ impl<'a> GetChannelMessages<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`GetChannelMessagesError::LimitInvalid`] if the amount is less than 1 or greater than
+    /// Returns [`GetChannelMessagesErrorType::LimitInvalid`] if the amount is less than 1 or greater than
     /// 100.
     ///
-    /// [`GetChannelMessagesError::LimitInvalid`]: enum.GetChannelMessagesError.html#variant.LimitInvalid
+    /// [`GetChannelMessagesErrorType::LimitInvalid`]: enum.GetChannelMessagesErrorType.html#variant.LimitInvalid
     pub fn limit(mut self, limit: u64) -> Result<Self, GetChannelMessagesError> {
         if !validate::get_channel_messages_limit(limit) {
-            return Err(GetChannelMessagesError::LimitInvalid { limit });
+            return Err(GetChannelMessagesError {
+                kind: GetChannelMessagesErrorType::LimitInvalid { limit },
+                source: None,
+            });
         }
 
         self.fields.limit.replace(limit);
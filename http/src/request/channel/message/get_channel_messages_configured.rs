@@ -9,25 +9,67 @@ use twilight_model::{
 };
 
 /// The error returned if the request can not be created as configured.
-#[derive(Clone, Debug)]
-#[non_exhaustive]
-pub enum GetChannelMessagesConfiguredError {
-    /// The maximum number of messages to retrieve is either 0 or more than 100.
-    LimitInvalid {
-        /// Provided maximum number of messages to retrieve.
-        limit: u64,
-    },
+#[derive(Debug)]
+pub struct GetChannelMessagesConfiguredError {
+    kind: GetChannelMessagesConfiguredErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl GetChannelMessagesConfiguredError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GetChannelMessagesConfiguredErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        GetChannelMessagesConfiguredErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for GetChannelMessagesConfiguredError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+        match &self.kind {
+            GetChannelMessagesConfiguredErrorType::LimitInvalid { .. } => {
+                f.write_str("the limit is invalid")
+            }
         }
     }
 }
 
-impl Error for GetChannelMessagesConfiguredError {}
+impl Error for GetChannelMessagesConfiguredError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`GetChannelMessagesConfiguredError`] that occurred.
+///
+/// [`GetChannelMessagesConfiguredError`]: struct.GetChannelMessagesConfiguredError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum GetChannelMessagesConfiguredErrorType {
+    /// The maximum number of messages to retrieve is either 0 or more than 100.
+    LimitInvalid {
+        /// Provided maximum number of messages to retrieve.
+        limit: u64,
+    },
+}
 
 struct GetChannelMessagesConfiguredFields {
     limit: Option<u64>,
@@ -76,13 +118,16 @@ impl<'a> GetChannelMessagesConfigured<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`GetChannelMessagesConfiguredError::LimitInvalid`] if the
+    /// Returns [`GetChannelMessagesConfiguredErrorType::LimitInvalid`] if the
     /// amount is greater than 21600.
     ///
-    /// [`GetChannelMessagesConfiguredError::LimitInvalid`]: enum.GetChannelMessagesConfiguredError.html#variant.LimitInvalid
+    /// [`GetChannelMessagesConfiguredErrorType::LimitInvalid`]: enum.GetChannelMessagesConfiguredErrorType.html#variant.LimitInvalid
     pub fn limit(mut self, limit: u64) -> Result<Self, GetChannelMessagesConfiguredError> {
         if !validate::get_channel_messages_limit(limit) {
-            return Err(GetChannelMessagesConfiguredError::LimitInvalid { limit });
+            return Err(GetChannelMessagesConfiguredError {
+                kind: GetChannelMessagesConfiguredErrorType::LimitInvalid { limit },
+                source: None,
+            });
         }
 
         self.fields.limit.replace(limit);
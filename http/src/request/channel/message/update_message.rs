@@ -4,14 +4,73 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
+    application::component::Component,
     channel::{embed::Embed, message::MessageFlags, Message},
     id::{ChannelId, MessageId},
 };
 
 /// The error created when a message can not be updated as configured.
+#[derive(Debug)]
+pub struct UpdateMessageError {
+    kind: UpdateMessageErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl UpdateMessageError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateMessageErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (UpdateMessageErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for UpdateMessageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UpdateMessageErrorType::ComponentCount { .. } => {
+                f.write_str("more than 5 action rows were provided")
+            }
+            UpdateMessageErrorType::ContentInvalid { .. } => {
+                f.write_str("the message content is invalid")
+            }
+            UpdateMessageErrorType::EmbedTooLarge { .. } => {
+                f.write_str("the embed's contents are too long")
+            }
+        }
+    }
+}
+
+impl Error for UpdateMessageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`UpdateMessageError`] that occurred.
+///
+/// [`UpdateMessageError`]: struct.UpdateMessageError.html
 #[derive(Clone, Debug)]
 #[non_exhaustive]
-pub enum UpdateMessageError {
+pub enum UpdateMessageErrorType {
+    /// Returned when there are more than 5 action rows.
+    ComponentCount {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Returned when the content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -21,33 +80,15 @@ pub enum UpdateMessageError {
     EmbedTooLarge {
         /// Provided embed.
         embed: Box<Embed>,
-        /// The source of the error.
-        source: EmbedValidationError,
     },
 }
 
-impl Display for UpdateMessageError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::ContentInvalid { .. } => f.write_str("the message content is invalid"),
-            Self::EmbedTooLarge { .. } => f.write_str("the embed's contents are too long"),
-        }
-    }
-}
-
-impl Error for UpdateMessageError {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Self::ContentInvalid { .. } => None,
-            Self::EmbedTooLarge { source, .. } => Some(source),
-        }
-    }
-}
-
 #[derive(Default, Serialize)]
 struct UpdateMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<Vec<Component>>,
     // We don't serialize if this is Option::None, to avoid overwriting the
     // field without meaning to.
     //
@@ -126,6 +167,30 @@ impl<'a> UpdateMessage<'a> {
         }
     }
 
+    /// Set the components of the message, replacing the existing components.
+    ///
+    /// Pass an empty list to remove the components. Components must be
+    /// wrapped in up to 5 action rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateMessageErrorType::ComponentCount`] if there are more than 5
+    /// action rows.
+    ///
+    /// [`UpdateMessageErrorType::ComponentCount`]: enum.UpdateMessageErrorType.html#variant.ComponentCount
+    pub fn components(mut self, components: Vec<Component>) -> Result<Self, UpdateMessageError> {
+        if !validate::components(&components) {
+            return Err(UpdateMessageError {
+                kind: UpdateMessageErrorType::ComponentCount { components },
+                source: None,
+            });
+        }
+
+        self.fields.components.replace(components);
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -137,10 +202,10 @@ impl<'a> UpdateMessage<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`UpdateMessageError::ContentInvalid`] if the content length is
+    /// Returns [`UpdateMessageErrorType::ContentInvalid`] if the content length is
     /// too long.
     ///
-    /// [`UpdateMessageError::ContentInvalid`]: enum.UpdateMessageError.html#variant.ContentInvalid
+    /// [`UpdateMessageErrorType::ContentInvalid`]: enum.UpdateMessageErrorType.html#variant.ContentInvalid
     pub fn content(self, content: impl Into<Option<String>>) -> Result<Self, UpdateMessageError> {
         self._content(content.into())
     }
@@ -148,8 +213,11 @@ impl<'a> UpdateMessage<'a> {
     fn _content(mut self, content: Option<String>) -> Result<Self, UpdateMessageError> {
         if let Some(content_ref) = content.as_ref() {
             if !validate::content_limit(content_ref) {
-                return Err(UpdateMessageError::ContentInvalid {
-                    content: content.expect("content is known to be some"),
+                return Err(UpdateMessageError {
+                    kind: UpdateMessageErrorType::ContentInvalid {
+                        content: content.expect("content is known to be some"),
+                    },
+                    source: None,
                 });
             }
         }
@@ -172,9 +240,11 @@ impl<'a> UpdateMessage<'a> {
     fn _embed(mut self, embed: Option<Embed>) -> Result<Self, UpdateMessageError> {
         if let Some(embed_ref) = embed.as_ref() {
             if let Err(source) = validate::embed(&embed_ref) {
-                return Err(UpdateMessageError::EmbedTooLarge {
-                    embed: Box::new(embed.expect("embed is known to be some")),
-                    source,
+                return Err(UpdateMessageError {
+                    kind: UpdateMessageErrorType::EmbedTooLarge {
+                        embed: Box::new(embed.expect("embed is known to be some")),
+                    },
+                    source: Some(Box::new(source)),
                 });
             }
         }
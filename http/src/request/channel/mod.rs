@@ -1,6 +1,8 @@
 pub mod invite;
 pub mod message;
 pub mod reaction;
+pub mod stage;
+pub mod thread;
 pub mod update_channel;
 pub mod webhook;
 
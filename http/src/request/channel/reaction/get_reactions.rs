@@ -9,25 +9,60 @@ use twilight_model::{
 };
 
 /// The error created if the reactions can not be retrieved as configured.
-#[derive(Clone, Debug)]
-#[non_exhaustive]
-pub enum GetReactionsError {
-    /// The number of reactions to retrieve must be between 1 and 100, inclusive.
-    LimitInvalid {
-        /// The provided maximum number of reactions to get.
-        limit: u64,
-    },
+#[derive(Debug)]
+pub struct GetReactionsError {
+    kind: GetReactionsErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl GetReactionsError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GetReactionsErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (GetReactionsErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for GetReactionsError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+        match &self.kind {
+            GetReactionsErrorType::LimitInvalid { .. } => f.write_str("the limit is invalid"),
         }
     }
 }
 
-impl Error for GetReactionsError {}
+impl Error for GetReactionsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`GetReactionsError`] that occurred.
+///
+/// [`GetReactionsError`]: struct.GetReactionsError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum GetReactionsErrorType {
+    /// The number of reactions to retrieve must be between 1 and 100, inclusive.
+    LimitInvalid {
+        /// The provided maximum number of reactions to get.
+        limit: u64,
+    },
+}
 
 #[derive(Default)]
 struct GetReactionsFields {
@@ -87,12 +122,15 @@ impl<'a> GetReactions<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`GetReactionsError::LimitInvalid`] if the amount is greater than 100.
+    /// Returns [`GetReactionsErrorType::LimitInvalid`] if the amount is greater than 100.
     ///
-    /// [`GetReactionsError::LimitInvalid`]: enum.GetReactionsError.html#variant.LimitInvalid
+    /// [`GetReactionsErrorType::LimitInvalid`]: enum.GetReactionsErrorType.html#variant.LimitInvalid
     pub fn limit(mut self, limit: u64) -> Result<Self, GetReactionsError> {
         if !validate::get_reactions_limit(limit) {
-            return Err(GetReactionsError::LimitInvalid { limit });
+            return Err(GetReactionsError {
+                kind: GetReactionsErrorType::LimitInvalid { limit },
+                source: None,
+            });
         }
 
         self.fields.limit.replace(limit);
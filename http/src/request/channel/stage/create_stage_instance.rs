@@ -0,0 +1,155 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::stage_instance::{PrivacyLevel, StageInstance},
+    id::ChannelId,
+};
+
+/// Returned when the stage instance can not be created as configured.
+#[derive(Debug)]
+pub struct CreateStageInstanceError {
+    kind: CreateStageInstanceErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateStageInstanceError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateStageInstanceErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CreateStageInstanceErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateStageInstanceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateStageInstanceErrorType::TopicInvalid { .. } => {
+                f.write_str("the length of the topic is invalid")
+            }
+        }
+    }
+}
+
+impl Error for CreateStageInstanceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateStageInstanceError`] that occurred.
+///
+/// [`CreateStageInstanceError`]: struct.CreateStageInstanceError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateStageInstanceErrorType {
+    /// The length of the topic is either fewer than 1 UTF-16 character or
+    /// more than 120 UTF-16 characters.
+    TopicInvalid {
+        /// Provided topic.
+        topic: String,
+    },
+}
+
+#[derive(Serialize)]
+struct CreateStageInstanceFields {
+    channel_id: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    privacy_level: Option<PrivacyLevel>,
+    topic: String,
+}
+
+/// Start a stage instance in a stage channel.
+///
+/// Requires the user to be a moderator of the stage channel.
+///
+/// # Errors
+///
+/// Returns a [`CreateStageInstanceErrorType::TopicInvalid`] when the length of the
+/// topic is either fewer than 1 UTF-16 character or more than 120 UTF-16
+/// characters.
+///
+/// [`CreateStageInstanceErrorType::TopicInvalid`]: enum.CreateStageInstanceErrorType.html#variant.TopicInvalid
+pub struct CreateStageInstance<'a> {
+    fields: CreateStageInstanceFields,
+    fut: Option<Pending<'a, StageInstance>>,
+    http: &'a Client,
+}
+
+impl<'a> CreateStageInstance<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        topic: impl Into<String>,
+    ) -> Result<Self, CreateStageInstanceError> {
+        Self::_new(http, channel_id, topic.into())
+    }
+
+    fn _new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        topic: String,
+    ) -> Result<Self, CreateStageInstanceError> {
+        if !validate::stage_topic(&topic) {
+            return Err(CreateStageInstanceError {
+                kind: CreateStageInstanceErrorType::TopicInvalid { topic },
+                source: None,
+            });
+        }
+
+        Ok(Self {
+            fields: CreateStageInstanceFields {
+                channel_id,
+                privacy_level: None,
+                topic,
+            },
+            fut: None,
+            http,
+        })
+    }
+
+    /// Set who can see the stage instance.
+    ///
+    /// Defaults to [`PrivacyLevel::GuildOnly`].
+    ///
+    /// [`PrivacyLevel::GuildOnly`]: ../../../../twilight_model/channel/stage_instance/enum.PrivacyLevel.html#variant.GuildOnly
+    pub fn privacy_level(mut self, privacy_level: PrivacyLevel) -> Self {
+        self.fields.privacy_level.replace(privacy_level);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::CreateStageInstance,
+        ));
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateStageInstance<'_>, StageInstance);
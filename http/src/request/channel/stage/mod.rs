@@ -0,0 +1,15 @@
+mod create_stage_instance;
+mod delete_stage_instance;
+mod get_stage_instance;
+mod update_stage_instance;
+
+pub use self::{
+    create_stage_instance::{
+        CreateStageInstance, CreateStageInstanceError, CreateStageInstanceErrorType,
+    },
+    delete_stage_instance::DeleteStageInstance,
+    get_stage_instance::GetStageInstance,
+    update_stage_instance::{
+        UpdateStageInstance, UpdateStageInstanceError, UpdateStageInstanceErrorType,
+    },
+};
@@ -0,0 +1,150 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::stage_instance::{PrivacyLevel, StageInstance},
+    id::ChannelId,
+};
+
+/// Returned when the stage instance can not be updated as configured.
+#[derive(Debug)]
+pub struct UpdateStageInstanceError {
+    kind: UpdateStageInstanceErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl UpdateStageInstanceError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateStageInstanceErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        UpdateStageInstanceErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for UpdateStageInstanceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UpdateStageInstanceErrorType::TopicInvalid { .. } => {
+                f.write_str("the length of the topic is invalid")
+            }
+        }
+    }
+}
+
+impl Error for UpdateStageInstanceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`UpdateStageInstanceError`] that occurred.
+///
+/// [`UpdateStageInstanceError`]: struct.UpdateStageInstanceError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateStageInstanceErrorType {
+    /// The length of the topic is either fewer than 1 UTF-16 character or
+    /// more than 120 UTF-16 characters.
+    TopicInvalid {
+        /// Provided topic.
+        topic: String,
+    },
+}
+
+#[derive(Default, Serialize)]
+struct UpdateStageInstanceFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    privacy_level: Option<PrivacyLevel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    topic: Option<String>,
+}
+
+/// Update the stage instance of a stage channel.
+///
+/// Requires the user to be a moderator of the stage channel.
+pub struct UpdateStageInstance<'a> {
+    channel_id: ChannelId,
+    fields: UpdateStageInstanceFields,
+    fut: Option<Pending<'a, StageInstance>>,
+    http: &'a Client,
+}
+
+impl<'a> UpdateStageInstance<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fields: UpdateStageInstanceFields::default(),
+            fut: None,
+            http,
+        }
+    }
+
+    /// Set who can see the stage instance.
+    pub fn privacy_level(mut self, privacy_level: PrivacyLevel) -> Self {
+        self.fields.privacy_level.replace(privacy_level);
+
+        self
+    }
+
+    /// Set the topic of the stage instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateStageInstanceErrorType::TopicInvalid`] when the length of
+    /// the topic is either fewer than 1 UTF-16 character or more than 120
+    /// UTF-16 characters.
+    ///
+    /// [`UpdateStageInstanceErrorType::TopicInvalid`]: enum.UpdateStageInstanceErrorType.html#variant.TopicInvalid
+    pub fn topic(self, topic: impl Into<String>) -> Result<Self, UpdateStageInstanceError> {
+        self._topic(topic.into())
+    }
+
+    fn _topic(mut self, topic: String) -> Result<Self, UpdateStageInstanceError> {
+        if !validate::stage_topic(&topic) {
+            return Err(UpdateStageInstanceError {
+                kind: UpdateStageInstanceErrorType::TopicInvalid { topic },
+                source: None,
+            });
+        }
+
+        self.fields.topic.replace(topic);
+
+        Ok(self)
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::UpdateStageInstance {
+                channel_id: self.channel_id.0,
+            },
+        ));
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateStageInstance<'_>, StageInstance);
@@ -0,0 +1,187 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::{thread::AutoArchiveDuration, ChannelType, GuildChannel},
+    id::ChannelId,
+};
+
+/// Returned when the thread can not be created as configured.
+#[derive(Debug)]
+pub struct CreateThreadError {
+    kind: CreateThreadErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateThreadError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateThreadErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CreateThreadErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateThreadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateThreadErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+            CreateThreadErrorType::TypeInvalid { .. } => {
+                f.write_str("the type of the channel is not a thread")
+            }
+        }
+    }
+}
+
+impl Error for CreateThreadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateThreadError`] that occurred.
+///
+/// [`CreateThreadError`]: struct.CreateThreadError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateThreadErrorType {
+    /// The length of the name is either fewer than 1 UTF-16 character or
+    /// more than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+    /// The type of the channel is not a thread type.
+    TypeInvalid {
+        /// Provided type.
+        kind: ChannelType,
+    },
+}
+
+#[derive(Serialize)]
+struct CreateThreadFields {
+    auto_archive_duration: AutoArchiveDuration,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitable: Option<bool>,
+    #[serde(rename = "type")]
+    kind: ChannelType,
+    name: String,
+}
+
+/// Start a thread in a channel that is not connected to a message.
+///
+/// The minimum length of the name is 1 UTF-16 character and the maximum is
+/// 100 UTF-16 characters.
+///
+/// # Errors
+///
+/// Returns a [`CreateThreadErrorType::NameInvalid`] when the length of the name is
+/// either fewer than 1 UTF-16 character or more than 100 UTF-16 characters.
+///
+/// Returns a [`CreateThreadErrorType::TypeInvalid`] when the type of the channel
+/// is not [`GuildNewsThread`], [`GuildPrivateThread`], or
+/// [`GuildPublicThread`].
+///
+/// [`CreateThreadErrorType::NameInvalid`]: enum.CreateThreadErrorType.html#variant.NameInvalid
+/// [`CreateThreadErrorType::TypeInvalid`]: enum.CreateThreadErrorType.html#variant.TypeInvalid
+/// [`GuildNewsThread`]: ../../../../twilight_model/channel/enum.ChannelType.html#variant.GuildNewsThread
+/// [`GuildPrivateThread`]: ../../../../twilight_model/channel/enum.ChannelType.html#variant.GuildPrivateThread
+/// [`GuildPublicThread`]: ../../../../twilight_model/channel/enum.ChannelType.html#variant.GuildPublicThread
+pub struct CreateThread<'a> {
+    channel_id: ChannelId,
+    fields: CreateThreadFields,
+    fut: Option<Pending<'a, GuildChannel>>,
+    http: &'a Client,
+}
+
+impl<'a> CreateThread<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        name: impl Into<String>,
+        auto_archive_duration: AutoArchiveDuration,
+        kind: ChannelType,
+    ) -> Result<Self, CreateThreadError> {
+        Self::_new(http, channel_id, name.into(), auto_archive_duration, kind)
+    }
+
+    fn _new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        name: String,
+        auto_archive_duration: AutoArchiveDuration,
+        kind: ChannelType,
+    ) -> Result<Self, CreateThreadError> {
+        if !validate::thread_name(&name) {
+            return Err(CreateThreadError {
+                kind: CreateThreadErrorType::NameInvalid { name },
+                source: None,
+            });
+        }
+
+        if !matches!(
+            kind,
+            ChannelType::GuildNewsThread
+                | ChannelType::GuildPrivateThread
+                | ChannelType::GuildPublicThread
+        ) {
+            return Err(CreateThreadError {
+                kind: CreateThreadErrorType::TypeInvalid { kind },
+                source: None,
+            });
+        }
+
+        Ok(Self {
+            channel_id,
+            fields: CreateThreadFields {
+                auto_archive_duration,
+                invitable: None,
+                kind,
+                name,
+            },
+            fut: None,
+            http,
+        })
+    }
+
+    /// Whether non-moderators can add other non-moderators to the thread.
+    ///
+    /// Only applies to private threads.
+    pub fn invitable(mut self, invitable: bool) -> Self {
+        self.fields.invitable.replace(invitable);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::CreateThread {
+                channel_id: self.channel_id.0,
+            },
+        ));
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateThread<'_>, GuildChannel);
@@ -0,0 +1,160 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::{thread::AutoArchiveDuration, GuildChannel},
+    id::{ChannelId, MessageId},
+};
+
+/// Returned when the thread can not be created as configured.
+#[derive(Debug)]
+pub struct CreateThreadFromMessageError {
+    kind: CreateThreadFromMessageErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateThreadFromMessageError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateThreadFromMessageErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CreateThreadFromMessageErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateThreadFromMessageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateThreadFromMessageErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+        }
+    }
+}
+
+impl Error for CreateThreadFromMessageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateThreadFromMessageError`] that occurred.
+///
+/// [`CreateThreadFromMessageError`]: struct.CreateThreadFromMessageError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateThreadFromMessageErrorType {
+    /// The length of the name is either fewer than 1 UTF-16 character or
+    /// more than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+}
+
+#[derive(Serialize)]
+struct CreateThreadFromMessageFields {
+    auto_archive_duration: AutoArchiveDuration,
+    name: String,
+}
+
+/// Start a thread from a message.
+///
+/// The thread is a public thread when started in a text channel, and a news
+/// thread when started in a news channel. Its ID is the same as the ID of the
+/// message.
+///
+/// # Errors
+///
+/// Returns a [`CreateThreadFromMessageErrorType::NameInvalid`] when the length of
+/// the name is either fewer than 1 UTF-16 character or more than 100 UTF-16
+/// characters.
+///
+/// [`CreateThreadFromMessageErrorType::NameInvalid`]: enum.CreateThreadFromMessageErrorType.html#variant.NameInvalid
+pub struct CreateThreadFromMessage<'a> {
+    channel_id: ChannelId,
+    fields: CreateThreadFromMessageFields,
+    fut: Option<Pending<'a, GuildChannel>>,
+    http: &'a Client,
+    message_id: MessageId,
+}
+
+impl<'a> CreateThreadFromMessage<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: impl Into<String>,
+        auto_archive_duration: AutoArchiveDuration,
+    ) -> Result<Self, CreateThreadFromMessageError> {
+        Self::_new(
+            http,
+            channel_id,
+            message_id,
+            name.into(),
+            auto_archive_duration,
+        )
+    }
+
+    fn _new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: String,
+        auto_archive_duration: AutoArchiveDuration,
+    ) -> Result<Self, CreateThreadFromMessageError> {
+        if !validate::thread_name(&name) {
+            return Err(CreateThreadFromMessageError {
+                kind: CreateThreadFromMessageErrorType::NameInvalid { name },
+                source: None,
+            });
+        }
+
+        Ok(Self {
+            channel_id,
+            fields: CreateThreadFromMessageFields {
+                auto_archive_duration,
+                name,
+            },
+            fut: None,
+            http,
+            message_id,
+        })
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::CreateThreadFromMessage {
+                channel_id: self.channel_id.0,
+                message_id: self.message_id.0,
+            },
+        ));
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateThreadFromMessage<'_>, GuildChannel);
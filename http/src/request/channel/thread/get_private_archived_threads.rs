@@ -0,0 +1,65 @@
+use crate::request::prelude::*;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use twilight_model::{channel::thread::ThreadsListing, id::ChannelId};
+
+#[derive(Default)]
+struct GetPrivateArchivedThreadsFields {
+    before: Option<String>,
+    limit: Option<u64>,
+}
+
+/// Get the archived private threads of a channel, most recently archived first.
+///
+/// Requires the [`MANAGE_THREADS`] permission.
+///
+/// [`MANAGE_THREADS`]: ../../../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_THREADS
+pub struct GetPrivateArchivedThreads<'a> {
+    channel_id: ChannelId,
+    fields: GetPrivateArchivedThreadsFields,
+    fut: Option<Pending<'a, ThreadsListing>>,
+    http: &'a Client,
+}
+
+impl<'a> GetPrivateArchivedThreads<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fields: GetPrivateArchivedThreadsFields::default(),
+            fut: None,
+            http,
+        }
+    }
+
+    /// Get threads archived before this ISO 8601 timestamp.
+    pub fn before(mut self, timestamp: impl Into<String>) -> Self {
+        self.fields.before.replace(timestamp.into());
+
+        self
+    }
+
+    /// Set the maximum number of threads to retrieve.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.fields.limit.replace(limit);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(
+            self.http
+                .request(Request::from(Route::GetPrivateArchivedThreads {
+                    before: self
+                        .fields
+                        .before
+                        .as_ref()
+                        .map(|before| utf8_percent_encode(before, NON_ALPHANUMERIC).to_string()),
+                    channel_id: self.channel_id.0,
+                    limit: self.fields.limit,
+                })),
+        ));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetPrivateArchivedThreads<'_>, ThreadsListing);
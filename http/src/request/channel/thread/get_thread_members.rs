@@ -0,0 +1,35 @@
+use crate::request::prelude::*;
+use twilight_model::{channel::thread::ThreadMember, id::ChannelId};
+
+/// Get the members of a thread.
+///
+/// Requires the [`GUILD_MEMBERS`] intent.
+///
+/// [`GUILD_MEMBERS`]: ../../../../twilight_model/gateway/struct.Intents.html#associatedconstant.GUILD_MEMBERS
+pub struct GetThreadMembers<'a> {
+    channel_id: ChannelId,
+    fut: Option<Pending<'a, Vec<ThreadMember>>>,
+    http: &'a Client,
+}
+
+impl<'a> GetThreadMembers<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fut: None,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetThreadMembers {
+                channel_id: self.channel_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetThreadMembers<'_>, Vec<ThreadMember>);
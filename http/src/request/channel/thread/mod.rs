@@ -0,0 +1,27 @@
+mod add_thread_member;
+mod create_thread;
+mod create_thread_from_message;
+mod get_active_threads;
+mod get_joined_private_archived_threads;
+mod get_private_archived_threads;
+mod get_public_archived_threads;
+mod get_thread_members;
+mod join_thread;
+mod leave_thread;
+mod remove_thread_member;
+
+pub use self::{
+    add_thread_member::AddThreadMember,
+    create_thread::{CreateThread, CreateThreadError, CreateThreadErrorType},
+    create_thread_from_message::{
+        CreateThreadFromMessage, CreateThreadFromMessageError, CreateThreadFromMessageErrorType,
+    },
+    get_active_threads::GetActiveThreads,
+    get_joined_private_archived_threads::GetJoinedPrivateArchivedThreads,
+    get_private_archived_threads::GetPrivateArchivedThreads,
+    get_public_archived_threads::GetPublicArchivedThreads,
+    get_thread_members::GetThreadMembers,
+    join_thread::JoinThread,
+    leave_thread::LeaveThread,
+    remove_thread_member::RemoveThreadMember,
+};
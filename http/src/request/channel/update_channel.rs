@@ -9,9 +9,60 @@ use twilight_model::{
 };
 
 /// Returned when the channel can not be updated as configured.
+#[derive(Debug)]
+pub struct UpdateChannelError {
+    kind: UpdateChannelErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl UpdateChannelError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateChannelErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (UpdateChannelErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for UpdateChannelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UpdateChannelErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+            UpdateChannelErrorType::RateLimitPerUserInvalid { .. } => {
+                f.write_str("the rate limit per user is invalid")
+            }
+            UpdateChannelErrorType::TopicInvalid { .. } => f.write_str("the topic is invalid"),
+        }
+    }
+}
+
+impl Error for UpdateChannelError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`UpdateChannelError`] that occurred.
+///
+/// [`UpdateChannelError`]: struct.UpdateChannelError.html
 #[derive(Clone, Debug)]
 #[non_exhaustive]
-pub enum UpdateChannelError {
+pub enum UpdateChannelErrorType {
     /// The length of the name is either fewer than 2 UTF-16 characters or
     /// more than 100 UTF-16 characters.
     NameInvalid {
@@ -30,20 +81,6 @@ pub enum UpdateChannelError {
     },
 }
 
-impl Display for UpdateChannelError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::NameInvalid { .. } => f.write_str("the length of the name is invalid"),
-            Self::RateLimitPerUserInvalid { .. } => {
-                f.write_str("the rate limit per user is invalid")
-            }
-            Self::TopicInvalid { .. } => f.write_str("the topic is invalid"),
-        }
-    }
-}
-
-impl Error for UpdateChannelError {}
-
 // The Discord API doesn't require the `name` and `kind` fields to be present,
 // but it does require them to be non-null.
 #[derive(Default, Serialize)]
@@ -79,18 +116,18 @@ struct UpdateChannelFields {
 ///
 /// # Errors
 ///
-/// Returns a [`UpdateChannelError::NameInvalid`] when the length of the name is either fewer than
+/// Returns a [`UpdateChannelErrorType::NameInvalid`] when the length of the name is either fewer than
 /// 2 UTF-16 characters or more than 100 UTF-16 characters.
 ///
-/// Returns a [`UpdateChannelError::RateLimitPerUserInvalid`] when the seconds of the rate limit per
+/// Returns a [`UpdateChannelErrorType::RateLimitPerUserInvalid`] when the seconds of the rate limit per
 /// user is more than 21600.
 ///
-/// Returns a [`UpdateChannelError::TopicInvalid`] when the length of the topic is more than
+/// Returns a [`UpdateChannelErrorType::TopicInvalid`] when the length of the topic is more than
 /// 1024 UTF-16 characters.
 ///
-/// [`UpdateChannelError::NameInvalid`]: enum.UpdateChannelError.html#variant.NameInvalid
-/// [`UpdateChannelError::RateLimitPerUserInvalid`]: enum.UpdateChannelError.html#variant.RateLimitPerUserInvalid
-/// [`UpdateChannelError::TopicInvalid`]: enum.UpdateChannelError.html#variant.TopicInvalid
+/// [`UpdateChannelErrorType::NameInvalid`]: enum.UpdateChannelErrorType.html#variant.NameInvalid
+/// [`UpdateChannelErrorType::RateLimitPerUserInvalid`]: enum.UpdateChannelErrorType.html#variant.RateLimitPerUserInvalid
+/// [`UpdateChannelErrorType::TopicInvalid`]: enum.UpdateChannelErrorType.html#variant.TopicInvalid
 pub struct UpdateChannel<'a> {
     channel_id: ChannelId,
     fields: UpdateChannelFields,
@@ -124,17 +161,20 @@ impl<'a> UpdateChannel<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`UpdateChannelError::NameInvalid`] if the name length is
+    /// Returns [`UpdateChannelErrorType::NameInvalid`] if the name length is
     /// too short or too long.
     ///
-    /// [`UpdateChannelError::NameInvalid`]: enum.UpdateChannelError.html#variant.NameInvalid
+    /// [`UpdateChannelErrorType::NameInvalid`]: enum.UpdateChannelErrorType.html#variant.NameInvalid
     pub fn name(self, name: impl Into<String>) -> Result<Self, UpdateChannelError> {
         self._name(name.into())
     }
 
     fn _name(mut self, name: String) -> Result<Self, UpdateChannelError> {
         if !validate::channel_name(&name) {
-            return Err(UpdateChannelError::NameInvalid { name });
+            return Err(UpdateChannelError {
+                kind: UpdateChannelErrorType::NameInvalid { name },
+                source: None,
+            });
         }
 
         self.fields.name.replace(name);
@@ -188,18 +228,21 @@ impl<'a> UpdateChannel<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`UpdateChannelError::RateLimitPerUserInvalid`] if the amount is greater than
+    /// Returns [`UpdateChannelErrorType::RateLimitPerUserInvalid`] if the amount is greater than
     /// 21600.
     ///
-    /// [`UpdateChannelError::RateLimitPerUserInvalid`]: enum.UpdateChannelError.html#variant.RateLimitPerUserInvalid
+    /// [`UpdateChannelErrorType::RateLimitPerUserInvalid`]: enum.UpdateChannelErrorType.html#variant.RateLimitPerUserInvalid
     /// [the discord docs]: https://discordapp.com/developers/docs/resources/channel#channel-object-channel-structure>
     pub fn rate_limit_per_user(
         mut self,
         rate_limit_per_user: u64,
     ) -> Result<Self, UpdateChannelError> {
         if rate_limit_per_user > 21600 {
-            return Err(UpdateChannelError::RateLimitPerUserInvalid {
-                rate_limit_per_user,
+            return Err(UpdateChannelError {
+                kind: UpdateChannelErrorType::RateLimitPerUserInvalid {
+                    rate_limit_per_user,
+                },
+                source: None,
             });
         }
 
@@ -214,18 +257,21 @@ impl<'a> UpdateChannel<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`UpdateChannelError::TopicInvalid`] if the topic length is
+    /// Returns [`UpdateChannelErrorType::TopicInvalid`] if the topic length is
     /// too long.
     ///
     /// [the discord docs]: https://discordapp.com/developers/docs/resources/channel#channel-object-channel-structure
-    /// [`UpdateChannelError::TopicInvalid`]: enum.UpdateChannelError.html#variant.TopicInvalid
+    /// [`UpdateChannelErrorType::TopicInvalid`]: enum.UpdateChannelErrorType.html#variant.TopicInvalid
     pub fn topic(self, topic: impl Into<String>) -> Result<Self, UpdateChannelError> {
         self._topic(topic.into())
     }
 
     fn _topic(mut self, topic: String) -> Result<Self, UpdateChannelError> {
         if topic.chars().count() > 1024 {
-            return Err(UpdateChannelError::TopicInvalid { topic });
+            return Err(UpdateChannelError {
+                kind: UpdateChannelErrorType::TopicInvalid { topic },
+                source: None,
+            });
         }
 
         self.fields.topic.replace(topic);
@@ -1,5 +1,10 @@
-use crate::request::prelude::*;
+use crate::request::{channel::message::allowed_mentions::AllowedMentions, prelude::*};
 use futures_util::future::TryFutureExt;
+use reqwest::{
+    multipart::{Form, Part},
+    Body,
+};
+use std::collections::HashMap;
 use twilight_model::{
     channel::{embed::Embed, Message},
     id::WebhookId,
@@ -7,6 +12,8 @@ use twilight_model::{
 
 #[derive(Default, Serialize)]
 struct ExecuteWebhookFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_mentions: Option<AllowedMentions>,
     #[serde(skip_serializing_if = "Option::is_none")]
     avatar_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -14,8 +21,6 @@ struct ExecuteWebhookFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<Vec<Embed>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    file: Option<Vec<u8>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     payload_json: Option<Vec<u8>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tts: Option<bool>,
@@ -27,7 +32,7 @@ struct ExecuteWebhookFields {
 
 /// Executes a webhook, sending a message to its channel.
 ///
-/// You can only specify one of [`content`], [`embeds`], or [`file`].
+/// You can only specify one of [`content`], [`embeds`], or [`attachment`].
 ///
 /// # Examples
 ///
@@ -47,10 +52,11 @@ struct ExecuteWebhookFields {
 /// # Ok(()) }
 /// ```
 ///
+/// [`attachment`]: #method.attachment
 /// [`content`]: #method.content
 /// [`embeds`]: #method.embeds
-/// [`file`]: #method.file
 pub struct ExecuteWebhook<'a> {
+    attachments: HashMap<String, Body>,
     fields: ExecuteWebhookFields,
     fut: Option<Pending<'a, Option<Message>>>,
     http: &'a Client,
@@ -61,7 +67,11 @@ pub struct ExecuteWebhook<'a> {
 impl<'a> ExecuteWebhook<'a> {
     pub(crate) fn new(http: &'a Client, webhook_id: WebhookId, token: impl Into<String>) -> Self {
         Self {
-            fields: ExecuteWebhookFields::default(),
+            attachments: HashMap::new(),
+            fields: ExecuteWebhookFields {
+                allowed_mentions: http.default_allowed_mentions(),
+                ..ExecuteWebhookFields::default()
+            },
             fut: None,
             http,
             token: token.into(),
@@ -69,6 +79,19 @@ impl<'a> ExecuteWebhook<'a> {
         }
     }
 
+    /// Set the allowed mentions in the webhook's message.
+    ///
+    /// Defaults to the default allowed mentions of the client, if any. Use
+    /// the [`build_solo`] method to get a [`AllowedMentions`] structure.
+    ///
+    /// [`build_solo`]: ../message/allowed_mentions/struct.AllowedMentionsBuilder.html#method.build_solo
+    /// [`AllowedMentions`]: ../message/allowed_mentions/struct.AllowedMentions.html
+    pub fn allowed_mentions(mut self, allowed: AllowedMentions) -> Self {
+        self.fields.allowed_mentions.replace(allowed);
+
+        self
+    }
+
     /// The URL of the avatar of the webhook.
     pub fn avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
         self.fields.avatar_url.replace(avatar_url.into());
@@ -92,9 +115,23 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
-    /// Attach a file to the webhook.
-    pub fn file(mut self, file: impl Into<Vec<u8>>) -> Self {
-        self.fields.file.replace(file.into());
+    /// Attach a new file to the webhook's message.
+    ///
+    /// The file is raw binary data. It can be an image, or any other kind of file.
+    pub fn attachment(mut self, name: impl Into<String>, file: impl Into<Body>) -> Self {
+        self.attachments.insert(name.into(), file.into());
+
+        self
+    }
+
+    /// Insert multiple attachments into the webhook's message.
+    pub fn attachments<N: Into<String>, F: Into<Body>>(
+        mut self,
+        attachments: impl IntoIterator<Item = (N, F)>,
+    ) -> Self {
+        for (name, file) in attachments {
+            self = self.attachment(name, file);
+        }
 
         self
     }
@@ -133,14 +170,26 @@ impl<'a> ExecuteWebhook<'a> {
     }
 
     fn start(&mut self) -> Result<()> {
-        let request = Request::from((
-            crate::json_to_vec(&self.fields)?,
-            Route::ExecuteWebhook {
-                token: self.token.to_owned(),
-                wait: self.fields.wait,
-                webhook_id: self.webhook_id.0,
-            },
-        ));
+        let route = Route::ExecuteWebhook {
+            token: self.token.to_owned(),
+            wait: self.fields.wait,
+            webhook_id: self.webhook_id.0,
+        };
+
+        let request = if self.attachments.is_empty() {
+            Request::from((crate::json_to_vec(&self.fields)?, route))
+        } else {
+            let mut form = Form::new();
+
+            for (index, (name, file)) in self.attachments.drain().enumerate() {
+                form = form.part(format!("{}", index), Part::stream(file).file_name(name));
+            }
+
+            let body = crate::json_to_vec(&self.fields)?;
+            form = form.part("payload_json", Part::bytes(body));
+
+            Request::from((form, route))
+        };
 
         match self.fields.wait {
             Some(true) => {
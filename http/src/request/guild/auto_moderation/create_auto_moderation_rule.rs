@@ -0,0 +1,240 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::auto_moderation::{
+        AutoModerationAction, AutoModerationEventType, AutoModerationRule,
+        AutoModerationTriggerMetadata, AutoModerationTriggerType,
+    },
+    id::{ChannelId, GuildId, RoleId},
+};
+
+/// Returned when the auto moderation rule can not be created as configured.
+#[derive(Debug)]
+pub struct CreateAutoModerationRuleError {
+    kind: CreateAutoModerationRuleErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateAutoModerationRuleError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateAutoModerationRuleErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CreateAutoModerationRuleErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateAutoModerationRuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateAutoModerationRuleErrorType::ExemptChannelsInvalid { .. } => {
+                f.write_str("more than 50 channels were exempted")
+            }
+            CreateAutoModerationRuleErrorType::ExemptRolesInvalid { .. } => {
+                f.write_str("more than 20 roles were exempted")
+            }
+        }
+    }
+}
+
+impl Error for CreateAutoModerationRuleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateAutoModerationRuleError`] that occurred.
+///
+/// [`CreateAutoModerationRuleError`]: struct.CreateAutoModerationRuleError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateAutoModerationRuleErrorType {
+    /// More than 50 channels were exempted from the rule.
+    ExemptChannelsInvalid {
+        /// Provided channels.
+        channels: Vec<ChannelId>,
+    },
+    /// More than 20 roles were exempted from the rule.
+    ExemptRolesInvalid {
+        /// Provided roles.
+        roles: Vec<RoleId>,
+    },
+}
+
+#[derive(Serialize)]
+struct CreateAutoModerationRuleFields {
+    actions: Vec<AutoModerationAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    event_type: AutoModerationEventType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exempt_channels: Option<Vec<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exempt_roles: Option<Vec<RoleId>>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trigger_metadata: Option<AutoModerationTriggerMetadata>,
+    trigger_type: AutoModerationTriggerType,
+}
+
+/// Create an auto moderation rule in a guild.
+///
+/// Requires the [`MANAGE_GUILD`] permission.
+///
+/// [`MANAGE_GUILD`]: ../../../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+pub struct CreateAutoModerationRule<'a> {
+    fields: CreateAutoModerationRuleFields,
+    fut: Option<Pending<'a, AutoModerationRule>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+}
+
+impl<'a> CreateAutoModerationRule<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        event_type: AutoModerationEventType,
+        trigger_type: AutoModerationTriggerType,
+    ) -> Self {
+        Self {
+            fields: CreateAutoModerationRuleFields {
+                actions: Vec::new(),
+                enabled: None,
+                event_type,
+                exempt_channels: None,
+                exempt_roles: None,
+                name: name.into(),
+                trigger_metadata: None,
+                trigger_type,
+            },
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+        }
+    }
+
+    /// Set the actions taken when the rule is triggered.
+    pub fn actions(mut self, actions: Vec<AutoModerationAction>) -> Self {
+        self.fields.actions = actions;
+
+        self
+    }
+
+    /// Set whether the rule is enabled.
+    ///
+    /// Rules are disabled by default.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.fields.enabled.replace(enabled);
+
+        self
+    }
+
+    /// Set the channels the rule doesn't apply to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateAutoModerationRuleErrorType::ExemptChannelsInvalid`] when
+    /// more than 50 channels are provided.
+    ///
+    /// [`CreateAutoModerationRuleErrorType::ExemptChannelsInvalid`]: enum.CreateAutoModerationRuleErrorType.html#variant.ExemptChannelsInvalid
+    pub fn exempt_channels(
+        mut self,
+        channels: Vec<ChannelId>,
+    ) -> Result<Self, CreateAutoModerationRuleError> {
+        if !validate::auto_moderation_exempt_channels(&channels) {
+            return Err(CreateAutoModerationRuleError {
+                kind: CreateAutoModerationRuleErrorType::ExemptChannelsInvalid { channels },
+                source: None,
+            });
+        }
+
+        self.fields.exempt_channels.replace(channels);
+
+        Ok(self)
+    }
+
+    /// Set the roles the rule doesn't apply to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateAutoModerationRuleErrorType::ExemptRolesInvalid`] when more
+    /// than 20 roles are provided.
+    ///
+    /// [`CreateAutoModerationRuleErrorType::ExemptRolesInvalid`]: enum.CreateAutoModerationRuleErrorType.html#variant.ExemptRolesInvalid
+    pub fn exempt_roles(
+        mut self,
+        roles: Vec<RoleId>,
+    ) -> Result<Self, CreateAutoModerationRuleError> {
+        if !validate::auto_moderation_exempt_roles(&roles) {
+            return Err(CreateAutoModerationRuleError {
+                kind: CreateAutoModerationRuleErrorType::ExemptRolesInvalid { roles },
+                source: None,
+            });
+        }
+
+        self.fields.exempt_roles.replace(roles);
+
+        Ok(self)
+    }
+
+    /// Set the metadata used to determine whether the rule is triggered.
+    ///
+    /// Which fields are used depends on the trigger type of the rule.
+    pub fn trigger_metadata(mut self, trigger_metadata: AutoModerationTriggerMetadata) -> Self {
+        self.fields.trigger_metadata.replace(trigger_metadata);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let route = Route::CreateAutoModerationRule {
+            guild_id: self.guild_id.0,
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            let headers = audit_header(&reason)?;
+            Request::from((crate::json_to_vec(&self.fields)?, headers, route))
+        } else {
+            Request::from((crate::json_to_vec(&self.fields)?, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for CreateAutoModerationRule<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(CreateAutoModerationRule<'_>, AutoModerationRule);
@@ -0,0 +1,60 @@
+use crate::request::prelude::*;
+use twilight_model::id::{AutoModerationRuleId, GuildId};
+
+/// Delete an auto moderation rule in a guild.
+///
+/// Requires the [`MANAGE_GUILD`] permission.
+///
+/// [`MANAGE_GUILD`]: ../../../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+pub struct DeleteAutoModerationRule<'a> {
+    auto_moderation_rule_id: AutoModerationRuleId,
+    fut: Option<Pending<'a, ()>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+}
+
+impl<'a> DeleteAutoModerationRule<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        auto_moderation_rule_id: AutoModerationRuleId,
+    ) -> Self {
+        Self {
+            auto_moderation_rule_id,
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let route = Route::DeleteAutoModerationRule {
+            auto_moderation_rule_id: self.auto_moderation_rule_id.0,
+            guild_id: self.guild_id.0,
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            let headers = audit_header(&reason)?;
+            Request::from((headers, route))
+        } else {
+            Request::from(route)
+        };
+
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for DeleteAutoModerationRule<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(DeleteAutoModerationRule<'_>, ());
@@ -0,0 +1,244 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::auto_moderation::{
+        AutoModerationAction, AutoModerationEventType, AutoModerationRule,
+        AutoModerationTriggerMetadata,
+    },
+    id::{AutoModerationRuleId, ChannelId, GuildId, RoleId},
+};
+
+/// Returned when the auto moderation rule can not be updated as configured.
+#[derive(Debug)]
+pub struct UpdateAutoModerationRuleError {
+    kind: UpdateAutoModerationRuleErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl UpdateAutoModerationRuleError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateAutoModerationRuleErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        UpdateAutoModerationRuleErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for UpdateAutoModerationRuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UpdateAutoModerationRuleErrorType::ExemptChannelsInvalid { .. } => {
+                f.write_str("more than 50 channels were exempted")
+            }
+            UpdateAutoModerationRuleErrorType::ExemptRolesInvalid { .. } => {
+                f.write_str("more than 20 roles were exempted")
+            }
+        }
+    }
+}
+
+impl Error for UpdateAutoModerationRuleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`UpdateAutoModerationRuleError`] that occurred.
+///
+/// [`UpdateAutoModerationRuleError`]: struct.UpdateAutoModerationRuleError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateAutoModerationRuleErrorType {
+    /// More than 50 channels were exempted from the rule.
+    ExemptChannelsInvalid {
+        /// Provided channels.
+        channels: Vec<ChannelId>,
+    },
+    /// More than 20 roles were exempted from the rule.
+    ExemptRolesInvalid {
+        /// Provided roles.
+        roles: Vec<RoleId>,
+    },
+}
+
+#[derive(Default, Serialize)]
+struct UpdateAutoModerationRuleFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actions: Option<Vec<AutoModerationAction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_type: Option<AutoModerationEventType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exempt_channels: Option<Vec<ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exempt_roles: Option<Vec<RoleId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trigger_metadata: Option<AutoModerationTriggerMetadata>,
+}
+
+/// Update an auto moderation rule in a guild.
+///
+/// Requires the [`MANAGE_GUILD`] permission.
+///
+/// [`MANAGE_GUILD`]: ../../../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+pub struct UpdateAutoModerationRule<'a> {
+    auto_moderation_rule_id: AutoModerationRuleId,
+    fields: UpdateAutoModerationRuleFields,
+    fut: Option<Pending<'a, AutoModerationRule>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+}
+
+impl<'a> UpdateAutoModerationRule<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        auto_moderation_rule_id: AutoModerationRuleId,
+    ) -> Self {
+        Self {
+            auto_moderation_rule_id,
+            fields: UpdateAutoModerationRuleFields::default(),
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+        }
+    }
+
+    /// Set the actions taken when the rule is triggered.
+    pub fn actions(mut self, actions: Vec<AutoModerationAction>) -> Self {
+        self.fields.actions.replace(actions);
+
+        self
+    }
+
+    /// Set whether the rule is enabled.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.fields.enabled.replace(enabled);
+
+        self
+    }
+
+    /// Set the event type that the rule checks.
+    pub fn event_type(mut self, event_type: AutoModerationEventType) -> Self {
+        self.fields.event_type.replace(event_type);
+
+        self
+    }
+
+    /// Set the channels the rule doesn't apply to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateAutoModerationRuleErrorType::ExemptChannelsInvalid`] when
+    /// more than 50 channels are provided.
+    ///
+    /// [`UpdateAutoModerationRuleErrorType::ExemptChannelsInvalid`]: enum.UpdateAutoModerationRuleErrorType.html#variant.ExemptChannelsInvalid
+    pub fn exempt_channels(
+        mut self,
+        channels: Vec<ChannelId>,
+    ) -> Result<Self, UpdateAutoModerationRuleError> {
+        if !validate::auto_moderation_exempt_channels(&channels) {
+            return Err(UpdateAutoModerationRuleError {
+                kind: UpdateAutoModerationRuleErrorType::ExemptChannelsInvalid { channels },
+                source: None,
+            });
+        }
+
+        self.fields.exempt_channels.replace(channels);
+
+        Ok(self)
+    }
+
+    /// Set the roles the rule doesn't apply to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateAutoModerationRuleErrorType::ExemptRolesInvalid`] when more
+    /// than 20 roles are provided.
+    ///
+    /// [`UpdateAutoModerationRuleErrorType::ExemptRolesInvalid`]: enum.UpdateAutoModerationRuleErrorType.html#variant.ExemptRolesInvalid
+    pub fn exempt_roles(
+        mut self,
+        roles: Vec<RoleId>,
+    ) -> Result<Self, UpdateAutoModerationRuleError> {
+        if !validate::auto_moderation_exempt_roles(&roles) {
+            return Err(UpdateAutoModerationRuleError {
+                kind: UpdateAutoModerationRuleErrorType::ExemptRolesInvalid { roles },
+                source: None,
+            });
+        }
+
+        self.fields.exempt_roles.replace(roles);
+
+        Ok(self)
+    }
+
+    /// Set the name of the rule.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.fields.name.replace(name.into());
+
+        self
+    }
+
+    /// Set the metadata used to determine whether the rule is triggered.
+    pub fn trigger_metadata(mut self, trigger_metadata: AutoModerationTriggerMetadata) -> Self {
+        self.fields.trigger_metadata.replace(trigger_metadata);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let route = Route::UpdateAutoModerationRule {
+            auto_moderation_rule_id: self.auto_moderation_rule_id.0,
+            guild_id: self.guild_id.0,
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            let headers = audit_header(&reason)?;
+            Request::from((crate::json_to_vec(&self.fields)?, headers, route))
+        } else {
+            Request::from((crate::json_to_vec(&self.fields)?, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for UpdateAutoModerationRule<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(UpdateAutoModerationRule<'_>, AutoModerationRule);
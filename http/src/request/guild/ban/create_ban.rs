@@ -6,27 +6,62 @@ use std::{
 use twilight_model::id::{GuildId, UserId};
 
 /// The error created when the ban can not be created as configured.
-#[derive(Clone, Debug)]
-#[non_exhaustive]
-pub enum CreateBanError {
-    /// The number of days' worth of messages to delete is greater than 7.
-    DeleteMessageDaysInvalid {
-        /// Provided number of days' worth of messages to delete.
-        days: u64,
-    },
+#[derive(Debug)]
+pub struct CreateBanError {
+    kind: CreateBanErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateBanError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateBanErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CreateBanErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for CreateBanError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::DeleteMessageDaysInvalid { .. } => {
+        match &self.kind {
+            CreateBanErrorType::DeleteMessageDaysInvalid { .. } => {
                 f.write_str("the number of days' worth of messages to delete is invalid")
             }
         }
     }
 }
 
-impl Error for CreateBanError {}
+impl Error for CreateBanError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateBanError`] that occurred.
+///
+/// [`CreateBanError`]: struct.CreateBanError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateBanErrorType {
+    /// The number of days' worth of messages to delete is greater than 7.
+    DeleteMessageDaysInvalid {
+        /// Provided number of days' worth of messages to delete.
+        days: u64,
+    },
+}
 
 #[derive(Default)]
 struct CreateBanFields {
@@ -83,13 +118,16 @@ impl<'a> CreateBan<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`CreateBanError::DeleteMessageDaysInvalid`] if the number of days
+    /// Returns [`CreateBanErrorType::DeleteMessageDaysInvalid`] if the number of days
     /// is greater than 7.
     ///
-    /// [`CreateBanError::DeleteMessageDaysInvalid`]: enum.CreateBanError.html#variant.DeleteMessageDaysInvalid
+    /// [`CreateBanErrorType::DeleteMessageDaysInvalid`]: enum.CreateBanErrorType.html#variant.DeleteMessageDaysInvalid
     pub fn delete_message_days(mut self, days: u64) -> Result<Self, CreateBanError> {
         if !validate::ban_delete_message_days(days) {
-            return Err(CreateBanError::DeleteMessageDaysInvalid { days });
+            return Err(CreateBanError {
+                kind: CreateBanErrorType::DeleteMessageDaysInvalid { days },
+                source: None,
+            });
         }
 
         self.fields.delete_message_days.replace(days);
@@ -10,30 +10,67 @@ use twilight_model::{
 };
 
 /// Error building role fields.
-#[derive(Clone, Debug, Eq, PartialEq)]
-#[non_exhaustive]
-pub enum RoleFieldsError {
-    /// Color was larger than a valid RGB hexadecimal value.
-    ColorNotRgb {
-        /// Provided color hex value.
-        color: u32,
-    },
-    /// Invalid id for builders.
-    IdInvalid,
+#[derive(Debug)]
+pub struct RoleFieldsError {
+    kind: RoleFieldsErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl RoleFieldsError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &RoleFieldsErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (RoleFieldsErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for RoleFieldsError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::ColorNotRgb { color } => {
+        match &self.kind {
+            RoleFieldsErrorType::ColorNotRgb { color } => {
                 f.write_fmt(format_args!("the color {} is invalid", color))
             }
-            Self::IdInvalid => f.write_str("the given id value is 1, which is not acceptable"),
+            RoleFieldsErrorType::IdInvalid => {
+                f.write_str("the given id value is 1, which is not acceptable")
+            }
         }
     }
 }
 
-impl Error for RoleFieldsError {}
+impl Error for RoleFieldsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`RoleFieldsError`] that occurred.
+///
+/// [`RoleFieldsError`]: struct.RoleFieldsError.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RoleFieldsErrorType {
+    /// Color was larger than a valid RGB hexadecimal value.
+    ColorNotRgb {
+        /// Provided color hex value.
+        color: u32,
+    },
+    /// Invalid id for builders.
+    IdInvalid,
+}
 
 /// A builder for role fields.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -74,12 +111,15 @@ impl RoleFieldsBuilder {
     ///
     /// # Errors
     ///
-    /// Returns [`RoleFieldsError::ColorNotRgb`] if the color is not valid RGB.
+    /// Returns [`RoleFieldsErrorType::ColorNotRgb`] if the color is not valid RGB.
     ///
-    /// [`RoleFieldsError::ColorNotRgb`]: enum.RoleFieldsError.html#variant.ColorNotRgb
+    /// [`RoleFieldsErrorType::ColorNotRgb`]: enum.RoleFieldsErrorType.html#variant.ColorNotRgb
     pub fn color(mut self, color: u32) -> Result<Self, RoleFieldsError> {
         if color > Self::COLOR_MAXIMUM {
-            return Err(RoleFieldsError::ColorNotRgb { color });
+            return Err(RoleFieldsError {
+                kind: RoleFieldsErrorType::ColorNotRgb { color },
+                source: None,
+            });
         }
 
         self.0.color.replace(color);
@@ -98,12 +138,15 @@ impl RoleFieldsBuilder {
     ///
     /// # Errors
     ///
-    /// Returns [`RoleFieldsError::IdInvalid`] if the id is set to 1.
+    /// Returns [`RoleFieldsErrorType::IdInvalid`] if the id is set to 1.
     ///
-    /// [`RoleFieldsError::IdInvalid`]: enum.RoleFieldsError.html#variant.IdInvalid
+    /// [`RoleFieldsErrorType::IdInvalid`]: enum.RoleFieldsErrorType.html#variant.IdInvalid
     pub fn id(mut self, id: RoleId) -> Result<Self, RoleFieldsError> {
         if id == Self::ROLE_ID {
-            return Err(RoleFieldsError::IdInvalid);
+            return Err(RoleFieldsError {
+                kind: RoleFieldsErrorType::IdInvalid,
+                source: None,
+            });
         }
 
         self.0.id = id;
@@ -134,9 +177,65 @@ impl RoleFieldsBuilder {
 }
 
 /// Error building text fields.
+#[derive(Debug)]
+pub struct TextFieldsError {
+    kind: TextFieldsErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl TextFieldsError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &TextFieldsErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (TextFieldsErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for TextFieldsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            TextFieldsErrorType::NameTooShort { name } => {
+                f.write_fmt(format_args!("the name is too short: {}", name.len()))
+            }
+            TextFieldsErrorType::NameTooLong { name } => {
+                f.write_fmt(format_args!("the name is too long: {}", name.len()))
+            }
+            TextFieldsErrorType::RateLimitInvalid { limit } => {
+                f.write_fmt(format_args!("the rate limit {} is invalid", limit))
+            }
+            TextFieldsErrorType::TopicTooLong { topic } => {
+                f.write_fmt(format_args!("the topic is too long: {}", topic.len()))
+            }
+        }
+    }
+}
+
+impl Error for TextFieldsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`TextFieldsError`] that occurred.
+///
+/// [`TextFieldsError`]: struct.TextFieldsError.html
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
-pub enum TextFieldsError {
+pub enum TextFieldsErrorType {
     /// The name is too short.
     NameTooShort {
         /// The invalid name.
@@ -159,27 +258,6 @@ pub enum TextFieldsError {
     },
 }
 
-impl Display for TextFieldsError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::NameTooShort { name } => {
-                f.write_fmt(format_args!("the name is too short: {}", name.len()))
-            }
-            Self::NameTooLong { name } => {
-                f.write_fmt(format_args!("the name is too long: {}", name.len()))
-            }
-            Self::RateLimitInvalid { limit } => {
-                f.write_fmt(format_args!("the rate limit {} is invalid", limit))
-            }
-            Self::TopicTooLong { topic } => {
-                f.write_fmt(format_args!("the topic is too long: {}", topic.len()))
-            }
-        }
-    }
-}
-
-impl Error for TextFieldsError {}
-
 /// A builder for text fields.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[must_use = "must be built into a text channel"]
@@ -218,23 +296,29 @@ impl TextFieldsBuilder {
     ///
     /// # Errors
     ///
-    /// Returns [`TextFieldsError::NameTooShort`] if the name is too short.
+    /// Returns [`TextFieldsErrorType::NameTooShort`] if the name is too short.
     ///
-    /// Returns [`TextFieldsError::NameTooLong`] if the name is too long.
+    /// Returns [`TextFieldsErrorType::NameTooLong`] if the name is too long.
     ///
-    /// [`TextFieldsError::NameTooShort`]: enum.TextFieldsError.html#variant.NameTooShort
-    /// [`TextFieldsError::NameTooLong`]: enum.TextFieldsError.html#variant.NameTooLong
+    /// [`TextFieldsErrorType::NameTooShort`]: enum.TextFieldsErrorType.html#variant.NameTooShort
+    /// [`TextFieldsErrorType::NameTooLong`]: enum.TextFieldsErrorType.html#variant.NameTooLong
     pub fn new(name: impl Into<String>) -> Result<Self, TextFieldsError> {
         Self::_new(name.into())
     }
 
     fn _new(name: String) -> Result<Self, TextFieldsError> {
         if name.len() < Self::MIN_NAME_LENGTH {
-            return Err(TextFieldsError::NameTooShort { name });
+            return Err(TextFieldsError {
+                kind: TextFieldsErrorType::NameTooShort { name },
+                source: None,
+            });
         }
 
         if name.len() > Self::MAX_NAME_LENGTH {
-            return Err(TextFieldsError::NameTooLong { name });
+            return Err(TextFieldsError {
+                kind: TextFieldsErrorType::NameTooLong { name },
+                source: None,
+            });
         }
 
         Ok(Self(TextFields {
@@ -272,12 +356,15 @@ impl TextFieldsBuilder {
     ///
     /// # Errors
     ///
-    /// Returns [`TextFieldsError::RateLimitInvalid`] if the rate limit is invalid.
+    /// Returns [`TextFieldsErrorType::RateLimitInvalid`] if the rate limit is invalid.
     ///
-    /// [`TextFieldsError::RateLimitInvalid`]: enum.TextFieldsError.html#variant.RateLimitInvalid
+    /// [`TextFieldsErrorType::RateLimitInvalid`]: enum.TextFieldsErrorType.html#variant.RateLimitInvalid
     pub fn rate_limit_per_user(mut self, limit: u64) -> Result<Self, TextFieldsError> {
         if limit > Self::MAX_RATE_LIMIT {
-            return Err(TextFieldsError::RateLimitInvalid { limit });
+            return Err(TextFieldsError {
+                kind: TextFieldsErrorType::RateLimitInvalid { limit },
+                source: None,
+            });
         }
 
         self.0.rate_limit_per_user.replace(limit);
@@ -289,16 +376,19 @@ impl TextFieldsBuilder {
     ///
     /// # Errors
     ///
-    /// Returns [`TextFieldsError::TopicTooLong`] if the topic is too long.
+    /// Returns [`TextFieldsErrorType::TopicTooLong`] if the topic is too long.
     ///
-    /// [`TextFieldsError::TopicTooLong`]: enum.TextFieldsError.html#variant.TopicTooLong
+    /// [`TextFieldsErrorType::TopicTooLong`]: enum.TextFieldsErrorType.html#variant.TopicTooLong
     pub fn topic(self, topic: impl Into<String>) -> Result<Self, TextFieldsError> {
         self._topic(topic.into())
     }
 
     fn _topic(mut self, topic: String) -> Result<Self, TextFieldsError> {
         if topic.len() > Self::MAX_TOPIC_LENGTH {
-            return Err(TextFieldsError::TopicTooLong { topic });
+            return Err(TextFieldsError {
+                kind: TextFieldsErrorType::TopicTooLong { topic },
+                source: None,
+            });
         }
 
         self.0.topic.replace(topic);
@@ -308,9 +398,59 @@ impl TextFieldsBuilder {
 }
 
 /// Error building voice fields.
+#[derive(Debug)]
+pub struct VoiceFieldsError {
+    kind: VoiceFieldsErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl VoiceFieldsError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &VoiceFieldsErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (VoiceFieldsErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for VoiceFieldsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            VoiceFieldsErrorType::NameTooShort { name } => {
+                f.write_fmt(format_args!("the name is too short: {}", name.len()))
+            }
+            VoiceFieldsErrorType::NameTooLong { name } => {
+                f.write_fmt(format_args!("the name is too long: {}", name.len()))
+            }
+        }
+    }
+}
+
+impl Error for VoiceFieldsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`VoiceFieldsError`] that occurred.
+///
+/// [`VoiceFieldsError`]: struct.VoiceFieldsError.html
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
-pub enum VoiceFieldsError {
+pub enum VoiceFieldsErrorType {
     /// The name is too short.
     NameTooShort {
         /// The invalid name.
@@ -323,21 +463,6 @@ pub enum VoiceFieldsError {
     },
 }
 
-impl Display for VoiceFieldsError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::NameTooShort { name } => {
-                f.write_fmt(format_args!("the name is too short: {}", name.len()))
-            }
-            Self::NameTooLong { name } => {
-                f.write_fmt(format_args!("the name is too long: {}", name.len()))
-            }
-        }
-    }
-}
-
-impl Error for VoiceFieldsError {}
-
 /// A builder for voice fields.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[must_use = "must be built into a voice channel"]
@@ -362,23 +487,29 @@ impl VoiceFieldsBuilder {
     ///
     /// # Errors
     ///
-    /// Returns [`VoiceFieldsError::NameTooShort`] if the name is too short.
+    /// Returns [`VoiceFieldsErrorType::NameTooShort`] if the name is too short.
     ///
-    /// Returns [`VoiceFieldsError::NameTooLong`] if the name is too long.
+    /// Returns [`VoiceFieldsErrorType::NameTooLong`] if the name is too long.
     ///
-    /// [`VoiceFieldsError::NameTooShort`]: enum.VoiceFieldsError.html#variant.NameTooShort
-    /// [`VoiceFieldsError::NameTooLong`]: enum.VoiceFieldsError.html#variant.NameTooLong
+    /// [`VoiceFieldsErrorType::NameTooShort`]: enum.VoiceFieldsErrorType.html#variant.NameTooShort
+    /// [`VoiceFieldsErrorType::NameTooLong`]: enum.VoiceFieldsErrorType.html#variant.NameTooLong
     pub fn new(name: impl Into<String>) -> Result<Self, VoiceFieldsError> {
         Self::_new(name.into())
     }
 
     fn _new(name: String) -> Result<Self, VoiceFieldsError> {
         if name.len() < Self::MIN_NAME_LENGTH {
-            return Err(VoiceFieldsError::NameTooShort { name });
+            return Err(VoiceFieldsError {
+                kind: VoiceFieldsErrorType::NameTooShort { name },
+                source: None,
+            });
         }
 
         if name.len() > Self::MAX_NAME_LENGTH {
-            return Err(VoiceFieldsError::NameTooLong { name });
+            return Err(VoiceFieldsError {
+                kind: VoiceFieldsErrorType::NameTooLong { name },
+                source: None,
+            });
         }
 
         Ok(Self(VoiceFields {
@@ -420,9 +551,64 @@ impl VoiceFieldsBuilder {
 }
 
 /// Error creating category fields.
+#[derive(Debug)]
+pub struct CategoryFieldsError {
+    kind: CategoryFieldsErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CategoryFieldsError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CategoryFieldsErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CategoryFieldsErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CategoryFieldsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CategoryFieldsErrorType::NameTooShort { name } => {
+                f.write_fmt(format_args!("the name is too short: {}", name.len()))
+            }
+            CategoryFieldsErrorType::NameTooLong { name } => {
+                f.write_fmt(format_args!("the name is too long: {}", name.len()))
+            }
+        }
+    }
+}
+
+impl Error for CategoryFieldsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CategoryFieldsError`] that occurred.
+///
+/// [`CategoryFieldsError`]: struct.CategoryFieldsError.html
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
-pub enum CategoryFieldsError {
+pub enum CategoryFieldsErrorType {
     /// The name is too short.
     NameTooShort {
         /// The invalid name.
@@ -435,21 +621,6 @@ pub enum CategoryFieldsError {
     },
 }
 
-impl Display for CategoryFieldsError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::NameTooShort { name } => {
-                f.write_fmt(format_args!("the name is too short: {}", name.len()))
-            }
-            Self::NameTooLong { name } => {
-                f.write_fmt(format_args!("the name is too long: {}", name.len()))
-            }
-        }
-    }
-}
-
-impl Error for CategoryFieldsError {}
-
 /// A builder for a category channel, and its children.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[must_use = "must be built into a category channel"]
@@ -477,23 +648,29 @@ impl CategoryFieldsBuilder {
     ///
     /// # Errors
     ///
-    /// Returns [`CategoryFieldsError::NameTooShort`] if the name is too short.
+    /// Returns [`CategoryFieldsErrorType::NameTooShort`] if the name is too short.
     ///
-    /// Returns [`CategoryFieldsError::NameTooLong`] if the name is too long.
+    /// Returns [`CategoryFieldsErrorType::NameTooLong`] if the name is too long.
     ///
-    /// [`CategoryFieldsError::NameTooShort`]: enum.CategoryFieldsError.html#variant.NameTooShort
-    /// [`CategoryFieldsError::NameTooLong`]: enum.CategoryFieldsError.html#variant.NameTooLong
+    /// [`CategoryFieldsErrorType::NameTooShort`]: enum.CategoryFieldsErrorType.html#variant.NameTooShort
+    /// [`CategoryFieldsErrorType::NameTooLong`]: enum.CategoryFieldsErrorType.html#variant.NameTooLong
     pub fn new(name: impl Into<String>) -> Result<Self, CategoryFieldsError> {
         Self::_new(name.into())
     }
 
     fn _new(name: String) -> Result<Self, CategoryFieldsError> {
         if name.len() < Self::MIN_NAME_LENGTH {
-            return Err(CategoryFieldsError::NameTooShort { name });
+            return Err(CategoryFieldsError {
+                kind: CategoryFieldsErrorType::NameTooShort { name },
+                source: None,
+            });
         }
 
         if name.len() > Self::MAX_NAME_LENGTH {
-            return Err(CategoryFieldsError::NameTooLong { name });
+            return Err(CategoryFieldsError {
+                kind: CategoryFieldsErrorType::NameTooLong { name },
+                source: None,
+            });
         }
 
         Ok(Self {
@@ -591,8 +768,9 @@ impl GuildChannelFieldsBuilder {
 mod tests {
     use super::{
         super::{CategoryFields, GuildChannelFields, RoleFields, TextFields, VoiceFields},
-        CategoryFieldsBuilder, CategoryFieldsError, GuildChannelFieldsBuilder, RoleFieldsBuilder,
-        RoleFieldsError, TextFieldsBuilder, TextFieldsError, VoiceFieldsBuilder, VoiceFieldsError,
+        CategoryFieldsBuilder, CategoryFieldsErrorType, GuildChannelFieldsBuilder,
+        RoleFieldsBuilder, RoleFieldsErrorType, TextFieldsBuilder, TextFieldsErrorType,
+        VoiceFieldsBuilder, VoiceFieldsErrorType,
     };
     use twilight_model::{
         channel::{
@@ -626,10 +804,11 @@ mod tests {
     #[test]
     fn test_role_fields() {
         assert_eq!(
-            RoleFieldsError::ColorNotRgb { color: 123_123_123 },
+            &RoleFieldsErrorType::ColorNotRgb { color: 123_123_123 },
             RoleFieldsBuilder::new("role")
                 .color(123_123_123)
                 .unwrap_err()
+                .kind()
         );
 
         let fields = RoleFieldsBuilder::new("rolename")
@@ -659,10 +838,10 @@ mod tests {
     #[test]
     fn test_voice_fields() {
         assert_eq!(
-            VoiceFieldsError::NameTooShort {
+            &VoiceFieldsErrorType::NameTooShort {
                 name: String::from("c")
             },
-            VoiceFieldsBuilder::new("c").unwrap_err()
+            VoiceFieldsBuilder::new("c").unwrap_err().kind()
         );
 
         let fields = voice();
@@ -699,10 +878,10 @@ mod tests {
     #[test]
     fn test_text_fields() {
         assert_eq!(
-            TextFieldsError::NameTooShort {
+            &TextFieldsErrorType::NameTooShort {
                 name: String::from("b")
             },
-            TextFieldsBuilder::new("b").unwrap_err()
+            TextFieldsBuilder::new("b").unwrap_err().kind()
         );
 
         let fields = text();
@@ -736,10 +915,10 @@ mod tests {
     #[test]
     fn test_category_fields() {
         assert_eq!(
-            CategoryFieldsError::NameTooShort {
+            &CategoryFieldsErrorType::NameTooShort {
                 name: String::from("a")
             },
-            CategoryFieldsBuilder::new("a").unwrap_err()
+            CategoryFieldsBuilder::new("a").unwrap_err().kind()
         );
 
         let fields = category();
@@ -17,9 +17,60 @@ mod builder;
 pub use self::builder::*;
 
 /// The error returned when the guild can not be created as configured.
+#[derive(Debug)]
+pub struct CreateGuildError {
+    kind: CreateGuildErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateGuildError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateGuildErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CreateGuildErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateGuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateGuildErrorType::NameInvalid { .. } => f.write_str("the guild name is invalid"),
+            CreateGuildErrorType::TooManyChannels { .. } => {
+                f.write_str("too many channels were provided")
+            }
+            CreateGuildErrorType::TooManyRoles { .. } => {
+                f.write_str("too many roles were provided")
+            }
+        }
+    }
+}
+
+impl Error for CreateGuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateGuildError`] that occurred.
+///
+/// [`CreateGuildError`]: struct.CreateGuildError.html
 #[derive(Clone, Debug)]
 #[non_exhaustive]
-pub enum CreateGuildError {
+pub enum CreateGuildErrorType {
     /// The name of the guild is either fewer than 2 UTF-16 characters or more than 100 UTF-16
     /// characters.
     NameInvalid {
@@ -42,18 +93,6 @@ pub enum CreateGuildError {
     },
 }
 
-impl Display for CreateGuildError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::NameInvalid { .. } => f.write_str("the guild name is invalid"),
-            Self::TooManyChannels { .. } => f.write_str("too many channels were provided"),
-            Self::TooManyRoles { .. } => f.write_str("too many roles were provided"),
-        }
-    }
-}
-
-impl Error for CreateGuildError {}
-
 #[derive(Serialize)]
 struct CreateGuildFields {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -209,9 +248,9 @@ impl From<VoiceFieldsBuilder> for VoiceFields {
 ///
 /// # Errors
 ///
-/// Returns [`CreateGuildError::NameInvalid`] if the name length is too short or too long.
+/// Returns [`CreateGuildErrorType::NameInvalid`] if the name length is too short or too long.
 ///
-/// [`CreateGuildError::NameInvalid`]: enum.CreateGuildError.html#variant.NameInvalid
+/// [`CreateGuildErrorType::NameInvalid`]: enum.CreateGuildErrorType.html#variant.NameInvalid
 pub struct CreateGuild<'a> {
     fields: CreateGuildFields,
     fut: Option<Pending<'a, PartialGuild>>,
@@ -225,7 +264,10 @@ impl<'a> CreateGuild<'a> {
 
     fn _new(http: &'a Client, name: String) -> Result<Self, CreateGuildError> {
         if !validate::guild_name(&name) {
-            return Err(CreateGuildError::NameInvalid { name });
+            return Err(CreateGuildError {
+                kind: CreateGuildErrorType::NameInvalid { name },
+                source: None,
+            });
         }
 
         Ok(Self {
@@ -295,14 +337,17 @@ impl<'a> CreateGuild<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`CreateGuildError::TooManyChannels`] if the number of channels is over 500.
+    /// Returns [`CreateGuildErrorType::TooManyChannels`] if the number of channels is over 500.
     ///
-    /// [`CreateGuildError::TooManyChannels`]: enum.CreateGuildError.html#variant.TooManyChannels
+    /// [`CreateGuildErrorType::TooManyChannels`]: enum.CreateGuildErrorType.html#variant.TooManyChannels
     pub fn channels(mut self, channels: Vec<GuildChannelFields>) -> Result<Self, CreateGuildError> {
         // Error 30013
         // <https://discordapp.com/developers/docs/topics/opcodes-and-status-codes#json>
         if channels.len() > 500 {
-            return Err(CreateGuildError::TooManyChannels { channels });
+            return Err(CreateGuildError {
+                kind: CreateGuildErrorType::TooManyChannels { channels },
+                source: None,
+            });
         }
 
         self.fields.channels.replace(channels);
@@ -396,13 +441,16 @@ impl<'a> CreateGuild<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`CreateGuildError::TooManyRoles`] if the number of roles is
+    /// Returns [`CreateGuildErrorType::TooManyRoles`] if the number of roles is
     /// over 250.
     ///
-    /// [`CreateGuildError::TooManyRoles`]: enum.CreateGuildError.html#variant.TooManyRoles
+    /// [`CreateGuildErrorType::TooManyRoles`]: enum.CreateGuildErrorType.html#variant.TooManyRoles
     pub fn roles(mut self, mut roles: Vec<RoleFields>) -> Result<Self, CreateGuildError> {
         if roles.len() > 250 {
-            return Err(CreateGuildError::TooManyRoles { roles });
+            return Err(CreateGuildError {
+                kind: CreateGuildErrorType::TooManyRoles { roles },
+                source: None,
+            });
         }
 
         if let Some(prev_roles) = self.fields.roles.as_mut() {
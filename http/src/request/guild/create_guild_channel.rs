@@ -9,9 +9,65 @@ use twilight_model::{
 };
 
 /// Returned when the channel can not be created as configured.
+#[derive(Debug)]
+pub struct CreateGuildChannelError {
+    kind: CreateGuildChannelErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateGuildChannelError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateGuildChannelErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CreateGuildChannelErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateGuildChannelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateGuildChannelErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+            CreateGuildChannelErrorType::RateLimitPerUserInvalid { .. } => {
+                f.write_str("the rate limit per user is invalid")
+            }
+            CreateGuildChannelErrorType::TopicInvalid { .. } => f.write_str("the topic is invalid"),
+        }
+    }
+}
+
+impl Error for CreateGuildChannelError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateGuildChannelError`] that occurred.
+///
+/// [`CreateGuildChannelError`]: struct.CreateGuildChannelError.html
 #[derive(Clone, Debug)]
 #[non_exhaustive]
-pub enum CreateGuildChannelError {
+pub enum CreateGuildChannelErrorType {
     /// The length of the name is either fewer than 2 UTF-16 characters or
     /// more than 100 UTF-16 characters.
     NameInvalid {
@@ -30,20 +86,6 @@ pub enum CreateGuildChannelError {
     },
 }
 
-impl Display for CreateGuildChannelError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::NameInvalid { .. } => f.write_str("the length of the name is invalid"),
-            Self::RateLimitPerUserInvalid { .. } => {
-                f.write_str("the rate limit per user is invalid")
-            }
-            Self::TopicInvalid { .. } => f.write_str("the topic is invalid"),
-        }
-    }
-}
-
-impl Error for CreateGuildChannelError {}
-
 #[derive(Serialize)]
 struct CreateGuildChannelFields {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -74,18 +116,18 @@ struct CreateGuildChannelFields {
 ///
 /// # Errors
 ///
-/// Returns a [`CreateGuildChannelError::NameInvalid`] when the length of the name is either fewer
+/// Returns a [`CreateGuildChannelErrorType::NameInvalid`] when the length of the name is either fewer
 /// than 2 UTF-16 characters or more than 100 UTF-16 characters.
 ///
-/// Returns a [`CreateGuildChannelError::RateLimitPerUserInvalid`] when the seconds of the rate
+/// Returns a [`CreateGuildChannelErrorType::RateLimitPerUserInvalid`] when the seconds of the rate
 /// limit per user is more than 21600.
 ///
-/// Returns a [`CreateGuildChannelError::TopicInvalid`] when the length of the topic is more than
+/// Returns a [`CreateGuildChannelErrorType::TopicInvalid`] when the length of the topic is more than
 /// 1024 UTF-16 characters.
 ///
-/// [`CreateGuildChannelError::NameInvalid`]: enum.CreateGuildChannelError.html#variant.NameInvalid
-/// [`CreateGuildChannelError::RateLimitPerUserInvalid`]: enum.CreateGuildChannelError.html#variant.RateLimitPerUserInvalid
-/// [`CreateGuildChannelError::TopicInvalid`]: enum.CreateGuildChannelError.html#variant.TopicInvalid
+/// [`CreateGuildChannelErrorType::NameInvalid`]: enum.CreateGuildChannelErrorType.html#variant.NameInvalid
+/// [`CreateGuildChannelErrorType::RateLimitPerUserInvalid`]: enum.CreateGuildChannelErrorType.html#variant.RateLimitPerUserInvalid
+/// [`CreateGuildChannelErrorType::TopicInvalid`]: enum.CreateGuildChannelErrorType.html#variant.TopicInvalid
 pub struct CreateGuildChannel<'a> {
     fields: CreateGuildChannelFields,
     fut: Option<Pending<'a, GuildChannel>>,
@@ -109,7 +151,10 @@ impl<'a> CreateGuildChannel<'a> {
         name: String,
     ) -> Result<Self, CreateGuildChannelError> {
         if !validate::channel_name(&name) {
-            return Err(CreateGuildChannelError::NameInvalid { name });
+            return Err(CreateGuildChannelError {
+                kind: CreateGuildChannelErrorType::NameInvalid { name },
+                source: None,
+            });
         }
 
         Ok(Self {
@@ -191,18 +236,21 @@ impl<'a> CreateGuildChannel<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`CreateGuildChannelError::RateLimitPerUserInvalid`] if the amount is greater than
+    /// Returns [`CreateGuildChannelErrorType::RateLimitPerUserInvalid`] if the amount is greater than
     /// 21600.
     ///
     /// [the discord docs]: https://discordapp.com/developers/docs/resources/channel#channel-object-channel-structure
-    /// [`CreateGuildChannelError::RateLimitPerUserInvalid`]: enum.CreateGuildChannelError.html#variant.RateLimitPerUserInvalid
+    /// [`CreateGuildChannelErrorType::RateLimitPerUserInvalid`]: enum.CreateGuildChannelErrorType.html#variant.RateLimitPerUserInvalid
     pub fn rate_limit_per_user(
         mut self,
         rate_limit_per_user: u64,
     ) -> Result<Self, CreateGuildChannelError> {
         if rate_limit_per_user > 21600 {
-            return Err(CreateGuildChannelError::RateLimitPerUserInvalid {
-                rate_limit_per_user,
+            return Err(CreateGuildChannelError {
+                kind: CreateGuildChannelErrorType::RateLimitPerUserInvalid {
+                    rate_limit_per_user,
+                },
+                source: None,
             });
         }
 
@@ -217,18 +265,21 @@ impl<'a> CreateGuildChannel<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`CreateGuildChannelError::TopicInvalid`] if the topic length is
+    /// Returns [`CreateGuildChannelErrorType::TopicInvalid`] if the topic length is
     /// too long.
     ///
     /// [the discord docs]: https://discordapp.com/developers/docs/resources/channel#channel-object-channel-structure
-    /// [`CreateGuildChannelError::TopicInvalid`]: enum.CreateGuildChannelError.html#variant.TopicInvalid
+    /// [`CreateGuildChannelErrorType::TopicInvalid`]: enum.CreateGuildChannelErrorType.html#variant.TopicInvalid
     pub fn topic(self, topic: impl Into<String>) -> Result<Self, CreateGuildChannelError> {
         self._topic(topic.into())
     }
 
     fn _topic(mut self, topic: String) -> Result<Self, CreateGuildChannelError> {
         if topic.chars().count() > 1024 {
-            return Err(CreateGuildChannelError::TopicInvalid { topic });
+            return Err(CreateGuildChannelError {
+                kind: CreateGuildChannelErrorType::TopicInvalid { topic },
+                source: None,
+            });
         }
 
         self.fields.topic.replace(topic);
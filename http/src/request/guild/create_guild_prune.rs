@@ -9,22 +9,64 @@ use twilight_model::{
 };
 
 /// The error created when the guild prune can not be created as configured.
-#[derive(Clone, Debug)]
-#[non_exhaustive]
-pub enum CreateGuildPruneError {
-    /// The number of days is 0.
-    DaysInvalid,
+#[derive(Debug)]
+pub struct CreateGuildPruneError {
+    kind: CreateGuildPruneErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateGuildPruneError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateGuildPruneErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CreateGuildPruneErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for CreateGuildPruneError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::DaysInvalid { .. } => f.write_str("the number of days is invalid"),
+        match &self.kind {
+            CreateGuildPruneErrorType::DaysInvalid { .. } => {
+                f.write_str("the number of days is invalid")
+            }
         }
     }
 }
 
-impl Error for CreateGuildPruneError {}
+impl Error for CreateGuildPruneError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateGuildPruneError`] that occurred.
+///
+/// [`CreateGuildPruneError`]: struct.CreateGuildPruneError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateGuildPruneErrorType {
+    /// The number of days is 0.
+    DaysInvalid,
+}
 
 #[derive(Default)]
 struct CreateGuildPruneFields {
@@ -79,12 +121,15 @@ impl<'a> CreateGuildPrune<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`CreateGuildPruneError::DaysInvalid`] if the number of days is 0.
+    /// Returns [`CreateGuildPruneErrorType::DaysInvalid`] if the number of days is 0.
     ///
-    /// [`CreateGuildPruneError::DaysInvalid`]: enum.CreateGuildPruneError.html#variant.DaysInvalid
+    /// [`CreateGuildPruneErrorType::DaysInvalid`]: enum.CreateGuildPruneErrorType.html#variant.DaysInvalid
     pub fn days(mut self, days: u64) -> Result<Self, CreateGuildPruneError> {
         if !validate::guild_prune_days(days) {
-            return Err(CreateGuildPruneError::DaysInvalid);
+            return Err(CreateGuildPruneError {
+                kind: CreateGuildPruneErrorType::DaysInvalid,
+                source: None,
+            });
         }
 
         self.fields.days.replace(days);
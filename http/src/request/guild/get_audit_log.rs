@@ -1,4 +1,5 @@
 use crate::request::prelude::*;
+use futures_util::stream::{self, Stream};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -9,9 +10,54 @@ use twilight_model::{
 };
 
 /// The error returned when the audit log can not be requested as configured.
+#[derive(Debug)]
+pub struct GetAuditLogError {
+    kind: GetAuditLogErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl GetAuditLogError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GetAuditLogErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (GetAuditLogErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for GetAuditLogError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            GetAuditLogErrorType::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+        }
+    }
+}
+
+impl Error for GetAuditLogError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`GetAuditLogError`] that occurred.
+///
+/// [`GetAuditLogError`]: struct.GetAuditLogError.html
 #[derive(Clone, Debug)]
 #[non_exhaustive]
-pub enum GetAuditLogError {
+pub enum GetAuditLogErrorType {
     /// The limit is either 0 or more than 100.
     LimitInvalid {
         /// Provided maximum number of audit logs to get.
@@ -19,17 +65,10 @@ pub enum GetAuditLogError {
     },
 }
 
-impl Display for GetAuditLogError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::LimitInvalid { .. } => f.write_str("the limit is invalid"),
-        }
-    }
-}
-
-impl Error for GetAuditLogError {}
+/// Number of entries the API returns if no limit is set.
+const DEFAULT_LIMIT: u64 = 50;
 
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 struct GetAuditLogFields {
     action_type: Option<AuditLogEvent>,
     before: Option<u64>,
@@ -51,11 +90,41 @@ struct GetAuditLogFields {
 ///
 /// let guild_id = GuildId(101);
 /// let audit_log = client
-/// // not done
 ///     .audit_log(guild_id)
 ///     .await?;
 /// # Ok(()) }
 /// ```
+///
+/// Walk backwards through the audit log in pages of up to 100 entries with
+/// [`pages`]:
+///
+/// ```rust,no_run
+/// use futures_util::stream::StreamExt;
+/// use twilight_http::Client;
+/// use twilight_model::{guild::audit_log::AuditLogEvent, id::GuildId};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("token");
+///
+/// let mut pages = client
+///     .audit_log(GuildId(101))
+///     .action_type(AuditLogEvent::MemberBanAdd)
+///     .limit(100)?
+///     .pages()
+///     .boxed();
+///
+/// while let Some(page) = pages.next().await {
+///     for entry in page?.entries() {
+///         if let Some(user) = entry.user {
+///             println!("{} banned a member", user.name);
+///         }
+///     }
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`pages`]: #method.pages
 pub struct GetAuditLog<'a> {
     fields: GetAuditLogFields,
     fut: Option<Pending<'a, Option<AuditLog>>>,
@@ -93,13 +162,16 @@ impl<'a> GetAuditLog<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`GetAuditLogError::LimitInvalid`] if the `limit` is 0 or
+    /// Returns [`GetAuditLogErrorType::LimitInvalid`] if the `limit` is 0 or
     /// greater than 100.
     ///
-    /// [`GetAuditLogError::LimitInvalid`]: enum.GetAuditLogError.html#variant.LimitInvalid
+    /// [`GetAuditLogErrorType::LimitInvalid`]: enum.GetAuditLogErrorType.html#variant.LimitInvalid
     pub fn limit(mut self, limit: u64) -> Result<Self, GetAuditLogError> {
         if !validate::get_audit_log_limit(limit) {
-            return Err(GetAuditLogError::LimitInvalid { limit });
+            return Err(GetAuditLogError {
+                kind: GetAuditLogErrorType::LimitInvalid { limit },
+                source: None,
+            });
         }
 
         self.fields.limit.replace(limit);
@@ -116,6 +188,81 @@ impl<'a> GetAuditLog<'a> {
         self
     }
 
+    /// Stream the audit log in pages, walking backwards from the newest entry
+    /// or the entry set with [`before`].
+    ///
+    /// Each page is requested with the configured filters and limit, and
+    /// before the oldest entry of the previous page. The stream ends once a
+    /// page has fewer entries than the limit, which is 50 by default, or if
+    /// the audit log can't be found. An empty page is yielded before the
+    /// stream ends. It also ends after the first error.
+    ///
+    /// [`before`]: #method.before
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pages(self) -> impl Stream<Item = Result<AuditLog>> + Send + 'a {
+        self.paginate()
+    }
+
+    /// Stream the audit log in pages, walking backwards from the newest entry
+    /// or the entry set with [`before`].
+    ///
+    /// Each page is requested with the configured filters and limit, and
+    /// before the oldest entry of the previous page. The stream ends once a
+    /// page has fewer entries than the limit, which is 50 by default, or if
+    /// the audit log can't be found. An empty page is yielded before the
+    /// stream ends. It also ends after the first error.
+    ///
+    /// [`before`]: #method.before
+    #[cfg(target_arch = "wasm32")]
+    pub fn pages(self) -> impl Stream<Item = Result<AuditLog>> + 'a {
+        self.paginate()
+    }
+
+    // The stream is only `Send` if the request futures are, which they aren't
+    // on wasm.
+    fn paginate(self) -> impl Stream<Item = Result<AuditLog>> + 'a {
+        stream::unfold(Some(self), |request| async move {
+            let request = request?;
+            let GetAuditLog {
+                fields,
+                guild_id,
+                http,
+                ..
+            } = request;
+
+            let audit_log = match request.await {
+                Ok(Some(audit_log)) => audit_log,
+                Ok(None) => return None,
+                Err(source) => return Some((Err(source), None)),
+            };
+
+            let limit = fields.limit.unwrap_or(DEFAULT_LIMIT);
+            let oldest = audit_log
+                .audit_log_entries
+                .iter()
+                .map(|entry| entry.id.0)
+                .min();
+
+            // An empty page is still yielded, but there's nothing before it.
+            let next = match oldest {
+                Some(oldest) if audit_log.audit_log_entries.len() as u64 >= limit => {
+                    Some(GetAuditLog {
+                        fields: GetAuditLogFields {
+                            before: Some(oldest),
+                            ..fields
+                        },
+                        fut: None,
+                        guild_id,
+                        http,
+                    })
+                }
+                _ => None,
+            };
+
+            Some((Ok(audit_log), next))
+        })
+    }
+
     fn start(&mut self) -> Result<()> {
         self.fut.replace(Box::pin(self.http.request(Request::from(
             Route::GetAuditLogs {
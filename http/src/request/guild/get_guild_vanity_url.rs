@@ -1,4 +1,4 @@
-use crate::{request::prelude::*, Error};
+use crate::{error::ErrorType, request::prelude::*, Error};
 use serde::Deserialize;
 use std::{
     future::Future,
@@ -48,8 +48,12 @@ impl Future for GetGuildVanityUrl<'_> {
             if let Some(fut) = self.as_mut().fut.as_mut() {
                 let bytes = match fut.as_mut().poll(cx) {
                     Poll::Ready(Ok(bytes)) => bytes,
-                    Poll::Ready(Err(crate::Error::Response { status, .. }))
-                        if status == reqwest::StatusCode::NOT_FOUND =>
+                    Poll::Ready(Err(why))
+                        if matches!(
+                            why.kind(),
+                            ErrorType::Response { status, .. }
+                                if *status == reqwest::StatusCode::NOT_FOUND
+                        ) =>
                     {
                         return Poll::Ready(Ok(None));
                     }
@@ -60,10 +64,12 @@ impl Future for GetGuildVanityUrl<'_> {
                 let mut bytes = bytes.as_ref().to_vec();
                 let vanity_url =
                     crate::json_from_slice::<VanityUrl>(&mut bytes).map_err(|source| {
-                        Error::Parsing {
-                            body: bytes.to_vec(),
+                        Error::with_source(
+                            ErrorType::Parsing {
+                                body: bytes.to_vec(),
+                            },
                             source,
-                        }
+                        )
                     })?;
 
                 return Poll::Ready(Ok(Some(vanity_url.code)));
@@ -19,25 +19,65 @@ use serde_json::Value;
 use simd_json::value::OwnedValue as Value;
 
 /// The error created when the members can not be fetched as configured.
-#[derive(Clone, Debug)]
-#[non_exhaustive]
-pub enum GetGuildMembersError {
-    /// The limit is either 0 or more than 1000.
-    LimitInvalid {
-        /// Provided limit.
-        limit: u64,
-    },
+#[derive(Debug)]
+pub struct GetGuildMembersError {
+    kind: GetGuildMembersErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl GetGuildMembersError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GetGuildMembersErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        GetGuildMembersErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for GetGuildMembersError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+        match &self.kind {
+            GetGuildMembersErrorType::LimitInvalid { .. } => f.write_str("the limit is invalid"),
         }
     }
 }
 
-impl Error for GetGuildMembersError {}
+impl Error for GetGuildMembersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`GetGuildMembersError`] that occurred.
+///
+/// [`GetGuildMembersError`]: struct.GetGuildMembersError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum GetGuildMembersErrorType {
+    /// The limit is either 0 or more than 1000.
+    LimitInvalid {
+        /// Provided limit.
+        limit: u64,
+    },
+}
 
 #[derive(Default)]
 struct GetGuildMembersFields {
@@ -71,9 +111,9 @@ struct GetGuildMembersFields {
 ///
 /// # Errors
 ///
-/// Returns [`GetGuildMembersError::LimitInvalid`] if the limit is invalid.
+/// Returns [`GetGuildMembersErrorType::LimitInvalid`] if the limit is invalid.
 ///
-/// [`GetGuildMembersError::LimitInvalid`]: enum.GetGuildMembersError.html#variant.LimitInvalid
+/// [`GetGuildMembersErrorType::LimitInvalid`]: enum.GetGuildMembersErrorType.html#variant.LimitInvalid
 pub struct GetGuildMembers<'a> {
     fields: GetGuildMembersFields,
     fut: Option<Pending<'a, Bytes>>,
@@ -104,13 +144,16 @@ impl<'a> GetGuildMembers<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`GetGuildMembersError::LimitInvalid`] if the limit is 0 or
+    /// Returns [`GetGuildMembersErrorType::LimitInvalid`] if the limit is 0 or
     /// greater than 1000.
     ///
-    /// [`GetGuildMembersError::LimitInvalid`]: enum.GetGuildMembersError.html#variant.LimitInvalid
+    /// [`GetGuildMembersErrorType::LimitInvalid`]: enum.GetGuildMembersErrorType.html#variant.LimitInvalid
     pub fn limit(mut self, limit: u64) -> Result<Self, GetGuildMembersError> {
         if !validate::get_guild_members_limit(limit) {
-            return Err(GetGuildMembersError::LimitInvalid { limit });
+            return Err(GetGuildMembersError {
+                kind: GetGuildMembersErrorType::LimitInvalid { limit },
+                source: None,
+            });
         }
 
         self.fields.limit.replace(limit);
@@ -1,4 +1,4 @@
-use crate::request::prelude::*;
+use crate::{error::ErrorType, request::prelude::*};
 use serde::de::DeserializeSeed;
 use std::{
     future::Future,
@@ -54,8 +54,12 @@ impl Future for GetMember<'_> {
             if let Some(fut) = self.as_mut().fut.as_mut() {
                 let bytes = match fut.as_mut().poll(cx) {
                     Poll::Ready(Ok(bytes)) => bytes,
-                    Poll::Ready(Err(crate::Error::Response { status, .. }))
-                        if status == reqwest::StatusCode::NOT_FOUND =>
+                    Poll::Ready(Err(why))
+                        if matches!(
+                            why.kind(),
+                            ErrorType::Response { status, .. }
+                                if *status == reqwest::StatusCode::NOT_FOUND
+                        ) =>
                     {
                         return Poll::Ready(Ok(None));
                     }
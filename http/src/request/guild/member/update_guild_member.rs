@@ -6,22 +6,64 @@ use std::{
 use twilight_model::id::{ChannelId, GuildId, RoleId, UserId};
 
 /// The error created when the member can not be updated as configured.
-#[derive(Clone, Debug)]
-#[non_exhaustive]
-pub enum UpdateGuildMemberError {
-    /// The nickname is either empty or the length is more than 32 UTF-16 characters.
-    NicknameInvalid { nickname: String },
+#[derive(Debug)]
+pub struct UpdateGuildMemberError {
+    kind: UpdateGuildMemberErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl UpdateGuildMemberError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateGuildMemberErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        UpdateGuildMemberErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for UpdateGuildMemberError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::NicknameInvalid { .. } => f.write_str("the nickname length is invalid"),
+        match &self.kind {
+            UpdateGuildMemberErrorType::NicknameInvalid { .. } => {
+                f.write_str("the nickname length is invalid")
+            }
         }
     }
 }
 
-impl Error for UpdateGuildMemberError {}
+impl Error for UpdateGuildMemberError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`UpdateGuildMemberError`] that occurred.
+///
+/// [`UpdateGuildMemberError`]: struct.UpdateGuildMemberError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateGuildMemberErrorType {
+    /// The nickname is either empty or the length is more than 32 UTF-16 characters.
+    NicknameInvalid { nickname: String },
+}
 
 #[derive(Default, Serialize)]
 struct UpdateGuildMemberFields {
@@ -45,10 +87,10 @@ struct UpdateGuildMemberFields {
 ///
 /// # Errors
 ///
-/// Returns [`UpdateGuildMemberError::NicknameInvalid`] if the nickname length is too short or too
+/// Returns [`UpdateGuildMemberErrorType::NicknameInvalid`] if the nickname length is too short or too
 /// long.
 ///
-/// [`UpdateGuildMemberError::NicknameInvalid`]: enum.UpdateGuildMemberError.html#variant.NicknameInvalid
+/// [`UpdateGuildMemberErrorType::NicknameInvalid`]: enum.UpdateGuildMemberErrorType.html#variant.NicknameInvalid
 ///
 /// [the discord docs]: https://discord.com/developers/docs/resources/guild#modify-guild-member
 pub struct UpdateGuildMember<'a> {
@@ -99,10 +141,10 @@ impl<'a> UpdateGuildMember<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`UpdateGuildMemberError::NicknameInvalid`] if the nickname length is too short or
+    /// Returns [`UpdateGuildMemberErrorType::NicknameInvalid`] if the nickname length is too short or
     /// too long.
     ///
-    /// [`UpdateGuildMemberError::NicknameInvalid`]: enum.UpdateGuildMemberError.html#variant.NicknameInvalid
+    /// [`UpdateGuildMemberErrorType::NicknameInvalid`]: enum.UpdateGuildMemberErrorType.html#variant.NicknameInvalid
     pub fn nick(self, nick: impl Into<Option<String>>) -> Result<Self, UpdateGuildMemberError> {
         self._nick(nick.into())
     }
@@ -110,8 +152,11 @@ impl<'a> UpdateGuildMember<'a> {
     fn _nick(mut self, nick: Option<String>) -> Result<Self, UpdateGuildMemberError> {
         if let Some(nick) = nick.as_ref() {
             if !validate::nickname(&nick) {
-                return Err(UpdateGuildMemberError::NicknameInvalid {
-                    nickname: nick.to_owned(),
+                return Err(UpdateGuildMemberError {
+                    kind: UpdateGuildMemberErrorType::NicknameInvalid {
+                        nickname: nick.to_owned(),
+                    },
+                    source: None,
                 });
             }
         }
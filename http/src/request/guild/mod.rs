@@ -1,3 +1,4 @@
+pub mod auto_moderation;
 pub mod ban;
 pub mod create_guild;
 pub mod create_guild_channel;
@@ -8,6 +9,8 @@ pub mod get_guild_prune_count;
 pub mod integration;
 pub mod member;
 pub mod role;
+pub mod scheduled_event;
+pub mod sticker;
 pub mod update_guild;
 
 mod delete_guild;
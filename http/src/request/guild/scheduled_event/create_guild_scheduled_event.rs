@@ -0,0 +1,266 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::scheduled_event::{
+        EntityMetadata, EntityType, GuildScheduledEvent, PrivacyLevel, RecurrenceRule,
+    },
+    id::{ChannelId, GuildId},
+};
+
+/// Returned when the scheduled event can not be created as configured.
+#[derive(Debug)]
+pub struct CreateGuildScheduledEventError {
+    kind: CreateGuildScheduledEventErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateGuildScheduledEventError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateGuildScheduledEventErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CreateGuildScheduledEventErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateGuildScheduledEventError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateGuildScheduledEventErrorType::DescriptionInvalid { .. } => {
+                f.write_str("the length of the description is invalid")
+            }
+            CreateGuildScheduledEventErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+        }
+    }
+}
+
+impl Error for CreateGuildScheduledEventError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateGuildScheduledEventError`] that occurred.
+///
+/// [`CreateGuildScheduledEventError`]: struct.CreateGuildScheduledEventError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateGuildScheduledEventErrorType {
+    /// The length of the description is either fewer than 1 UTF-16 character
+    /// or more than 1000 UTF-16 characters.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+    },
+    /// The length of the name is either fewer than 1 UTF-16 character or
+    /// more than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+}
+
+#[derive(Serialize)]
+struct CreateGuildScheduledEventFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_metadata: Option<EntityMetadata>,
+    entity_type: EntityType,
+    name: String,
+    privacy_level: PrivacyLevel,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<RecurrenceRule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_end_time: Option<String>,
+    scheduled_start_time: String,
+}
+
+/// Create a scheduled event in a guild.
+///
+/// Events taking place in a stage or voice channel need a [`channel_id`],
+/// while events with an external entity need a [`location`] and a
+/// [`scheduled_end_time`].
+///
+/// Requires the [`MANAGE_EVENTS`] permission.
+///
+/// # Errors
+///
+/// Returns a [`CreateGuildScheduledEventErrorType::NameInvalid`] when the length
+/// of the name is either fewer than 1 UTF-16 character or more than 100
+/// UTF-16 characters.
+///
+/// [`CreateGuildScheduledEventErrorType::NameInvalid`]: enum.CreateGuildScheduledEventErrorType.html#variant.NameInvalid
+/// [`MANAGE_EVENTS`]: ../../../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_EVENTS
+/// [`channel_id`]: #method.channel_id
+/// [`location`]: #method.location
+/// [`scheduled_end_time`]: #method.scheduled_end_time
+pub struct CreateGuildScheduledEvent<'a> {
+    fields: CreateGuildScheduledEventFields,
+    fut: Option<Pending<'a, GuildScheduledEvent>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> CreateGuildScheduledEvent<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        entity_type: EntityType,
+        name: impl Into<String>,
+        scheduled_start_time: impl Into<String>,
+    ) -> Result<Self, CreateGuildScheduledEventError> {
+        Self::_new(
+            http,
+            guild_id,
+            entity_type,
+            name.into(),
+            scheduled_start_time.into(),
+        )
+    }
+
+    fn _new(
+        http: &'a Client,
+        guild_id: GuildId,
+        entity_type: EntityType,
+        name: String,
+        scheduled_start_time: String,
+    ) -> Result<Self, CreateGuildScheduledEventError> {
+        if !validate::scheduled_event_name(&name) {
+            return Err(CreateGuildScheduledEventError {
+                kind: CreateGuildScheduledEventErrorType::NameInvalid { name },
+                source: None,
+            });
+        }
+
+        Ok(Self {
+            fields: CreateGuildScheduledEventFields {
+                channel_id: None,
+                description: None,
+                entity_metadata: None,
+                entity_type,
+                name,
+                privacy_level: PrivacyLevel::GuildOnly,
+                recurrence_rule: None,
+                scheduled_end_time: None,
+                scheduled_start_time,
+            },
+            fut: None,
+            guild_id,
+            http,
+        })
+    }
+
+    /// Set the stage or voice channel the event takes place in.
+    pub fn channel_id(mut self, channel_id: ChannelId) -> Self {
+        self.fields.channel_id.replace(channel_id);
+
+        self
+    }
+
+    /// Set the description of the event.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateGuildScheduledEventErrorType::DescriptionInvalid`] when the
+    /// length of the description is either fewer than 1 UTF-16 character or
+    /// more than 1000 UTF-16 characters.
+    ///
+    /// [`CreateGuildScheduledEventErrorType::DescriptionInvalid`]: enum.CreateGuildScheduledEventErrorType.html#variant.DescriptionInvalid
+    pub fn description(
+        self,
+        description: impl Into<String>,
+    ) -> Result<Self, CreateGuildScheduledEventError> {
+        self._description(description.into())
+    }
+
+    fn _description(mut self, description: String) -> Result<Self, CreateGuildScheduledEventError> {
+        if !validate::scheduled_event_description(&description) {
+            return Err(CreateGuildScheduledEventError {
+                kind: CreateGuildScheduledEventErrorType::DescriptionInvalid { description },
+                source: None,
+            });
+        }
+
+        self.fields.description.replace(description);
+
+        Ok(self)
+    }
+
+    /// Set the location of an event with an external entity.
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.fields.entity_metadata.replace(EntityMetadata {
+            location: Some(location.into()),
+        });
+
+        self
+    }
+
+    /// Set who can see the event.
+    ///
+    /// Defaults to [`PrivacyLevel::GuildOnly`], which is currently the only
+    /// privacy level.
+    ///
+    /// [`PrivacyLevel::GuildOnly`]: ../../../../twilight_model/guild/scheduled_event/enum.PrivacyLevel.html#variant.GuildOnly
+    pub fn privacy_level(mut self, privacy_level: PrivacyLevel) -> Self {
+        self.fields.privacy_level = privacy_level;
+
+        self
+    }
+
+    /// Set the rule the event recurs by.
+    pub fn recurrence_rule(mut self, recurrence_rule: RecurrenceRule) -> Self {
+        self.fields.recurrence_rule.replace(recurrence_rule);
+
+        self
+    }
+
+    /// Set the ISO 8601 timestamp of when the event ends.
+    pub fn scheduled_end_time(mut self, scheduled_end_time: impl Into<String>) -> Self {
+        self.fields
+            .scheduled_end_time
+            .replace(scheduled_end_time.into());
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::CreateGuildScheduledEvent {
+                guild_id: self.guild_id.0,
+            },
+        ));
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateGuildScheduledEvent<'_>, GuildScheduledEvent);
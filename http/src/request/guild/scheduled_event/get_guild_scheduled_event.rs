@@ -0,0 +1,52 @@
+use crate::request::prelude::*;
+use twilight_model::{
+    guild::scheduled_event::GuildScheduledEvent,
+    id::{GuildId, ScheduledEventId},
+};
+
+/// Get a scheduled event in a guild.
+pub struct GetGuildScheduledEvent<'a> {
+    fut: Option<PendingOption<'a>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    scheduled_event_id: ScheduledEventId,
+    with_user_count: bool,
+}
+
+impl<'a> GetGuildScheduledEvent<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+            scheduled_event_id,
+            with_user_count: false,
+        }
+    }
+
+    /// Set whether to include the number of users subscribed to the event.
+    pub fn with_user_count(mut self, with_user_count: bool) -> Self {
+        self.with_user_count = with_user_count;
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut
+            .replace(Box::pin(self.http.request_bytes(Request::from(
+                Route::GetGuildScheduledEvent {
+                    guild_id: self.guild_id.0,
+                    scheduled_event_id: self.scheduled_event_id.0,
+                    with_user_count: self.with_user_count,
+                },
+            ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(opt, GetGuildScheduledEvent<'_>, GuildScheduledEvent);
@@ -0,0 +1,178 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::scheduled_event::GuildScheduledEventUser,
+    id::{GuildId, ScheduledEventId, UserId},
+};
+
+/// Returned when the users of a scheduled event can not be retrieved as
+/// configured.
+#[derive(Debug)]
+pub struct GetGuildScheduledEventUsersError {
+    kind: GetGuildScheduledEventUsersErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl GetGuildScheduledEventUsersError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GetGuildScheduledEventUsersErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        GetGuildScheduledEventUsersErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for GetGuildScheduledEventUsersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            GetGuildScheduledEventUsersErrorType::LimitInvalid { .. } => {
+                f.write_str("the limit is invalid")
+            }
+        }
+    }
+}
+
+impl Error for GetGuildScheduledEventUsersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`GetGuildScheduledEventUsersError`] that occurred.
+///
+/// [`GetGuildScheduledEventUsersError`]: struct.GetGuildScheduledEventUsersError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum GetGuildScheduledEventUsersErrorType {
+    /// The number of users to retrieve must be between 1 and 100, inclusive.
+    LimitInvalid {
+        /// The provided maximum number of users to get.
+        limit: u64,
+    },
+}
+
+#[derive(Default)]
+struct GetGuildScheduledEventUsersFields {
+    after: Option<UserId>,
+    before: Option<UserId>,
+    limit: Option<u64>,
+    with_member: bool,
+}
+
+/// Get the users subscribed to a scheduled event in a guild.
+///
+/// This endpoint is limited to 100 users maximum, so if an event has more
+/// than 100 subscribers, requests must be chained with [`after`] or
+/// [`before`] until all users are retrieved.
+///
+/// [`after`]: #method.after
+/// [`before`]: #method.before
+pub struct GetGuildScheduledEventUsers<'a> {
+    fields: GetGuildScheduledEventUsersFields,
+    fut: Option<Pending<'a, Vec<GuildScheduledEventUser>>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    scheduled_event_id: ScheduledEventId,
+}
+
+impl<'a> GetGuildScheduledEventUsers<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            fields: GetGuildScheduledEventUsersFields::default(),
+            fut: None,
+            guild_id,
+            http,
+            scheduled_event_id,
+        }
+    }
+
+    /// Get users after this ID.
+    pub fn after(mut self, after: UserId) -> Self {
+        self.fields.after.replace(after);
+
+        self
+    }
+
+    /// Get users before this ID.
+    pub fn before(mut self, before: UserId) -> Self {
+        self.fields.before.replace(before);
+
+        self
+    }
+
+    /// Set the maximum number of users to retrieve.
+    ///
+    /// The minimum is 1 and the maximum is 100. If no limit is specified,
+    /// Discord sets the default to 100.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GetGuildScheduledEventUsersErrorType::LimitInvalid`] if the
+    /// amount is 0 or greater than 100.
+    ///
+    /// [`GetGuildScheduledEventUsersErrorType::LimitInvalid`]: enum.GetGuildScheduledEventUsersErrorType.html#variant.LimitInvalid
+    pub fn limit(mut self, limit: u64) -> Result<Self, GetGuildScheduledEventUsersError> {
+        if !validate::scheduled_event_get_users(limit) {
+            return Err(GetGuildScheduledEventUsersError {
+                kind: GetGuildScheduledEventUsersErrorType::LimitInvalid { limit },
+                source: None,
+            });
+        }
+
+        self.fields.limit.replace(limit);
+
+        Ok(self)
+    }
+
+    /// Set whether to include the guild member of each user.
+    pub fn with_member(mut self, with_member: bool) -> Self {
+        self.fields.with_member = with_member;
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildScheduledEventUsers {
+                after: self.fields.after.map(|x| x.0),
+                before: self.fields.before.map(|x| x.0),
+                guild_id: self.guild_id.0,
+                limit: self.fields.limit,
+                scheduled_event_id: self.scheduled_event_id.0,
+                with_member: self.fields.with_member,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(
+    GetGuildScheduledEventUsers<'_>,
+    Vec<GuildScheduledEventUser>
+);
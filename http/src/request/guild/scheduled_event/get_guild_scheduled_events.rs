@@ -0,0 +1,41 @@
+use crate::request::prelude::*;
+use twilight_model::{guild::scheduled_event::GuildScheduledEvent, id::GuildId};
+
+/// Get the scheduled events in a guild.
+pub struct GetGuildScheduledEvents<'a> {
+    fut: Option<Pending<'a, Vec<GuildScheduledEvent>>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    with_user_count: bool,
+}
+
+impl<'a> GetGuildScheduledEvents<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+            with_user_count: false,
+        }
+    }
+
+    /// Set whether to include the number of users subscribed to each event.
+    pub fn with_user_count(mut self, with_user_count: bool) -> Self {
+        self.with_user_count = with_user_count;
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildScheduledEvents {
+                guild_id: self.guild_id.0,
+                with_user_count: self.with_user_count,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetGuildScheduledEvents<'_>, Vec<GuildScheduledEvent>);
@@ -0,0 +1,24 @@
+mod create_guild_scheduled_event;
+mod delete_guild_scheduled_event;
+mod get_guild_scheduled_event;
+mod get_guild_scheduled_event_users;
+mod get_guild_scheduled_events;
+mod update_guild_scheduled_event;
+
+pub use self::{
+    create_guild_scheduled_event::{
+        CreateGuildScheduledEvent, CreateGuildScheduledEventError,
+        CreateGuildScheduledEventErrorType,
+    },
+    delete_guild_scheduled_event::DeleteGuildScheduledEvent,
+    get_guild_scheduled_event::GetGuildScheduledEvent,
+    get_guild_scheduled_event_users::{
+        GetGuildScheduledEventUsers, GetGuildScheduledEventUsersError,
+        GetGuildScheduledEventUsersErrorType,
+    },
+    get_guild_scheduled_events::GetGuildScheduledEvents,
+    update_guild_scheduled_event::{
+        UpdateGuildScheduledEvent, UpdateGuildScheduledEventError,
+        UpdateGuildScheduledEventErrorType,
+    },
+};
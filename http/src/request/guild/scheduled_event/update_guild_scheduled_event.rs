@@ -0,0 +1,286 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::scheduled_event::{
+        EntityMetadata, EntityType, GuildScheduledEvent, PrivacyLevel, RecurrenceRule, Status,
+    },
+    id::{ChannelId, GuildId, ScheduledEventId},
+};
+
+/// Returned when the scheduled event can not be updated as configured.
+#[derive(Debug)]
+pub struct UpdateGuildScheduledEventError {
+    kind: UpdateGuildScheduledEventErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl UpdateGuildScheduledEventError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateGuildScheduledEventErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        UpdateGuildScheduledEventErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for UpdateGuildScheduledEventError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UpdateGuildScheduledEventErrorType::DescriptionInvalid { .. } => {
+                f.write_str("the length of the description is invalid")
+            }
+            UpdateGuildScheduledEventErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+        }
+    }
+}
+
+impl Error for UpdateGuildScheduledEventError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`UpdateGuildScheduledEventError`] that occurred.
+///
+/// [`UpdateGuildScheduledEventError`]: struct.UpdateGuildScheduledEventError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateGuildScheduledEventErrorType {
+    /// The length of the description is either fewer than 1 UTF-16 character
+    /// or more than 1000 UTF-16 characters.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+    },
+    /// The length of the name is either fewer than 1 UTF-16 character or
+    /// more than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+}
+
+#[derive(Default, Serialize)]
+struct UpdateGuildScheduledEventFields {
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_id: Option<Option<ChannelId>>,
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_metadata: Option<EntityMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entity_type: Option<EntityType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    privacy_level: Option<PrivacyLevel>,
+    #[allow(clippy::option_option)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recurrence_rule: Option<Option<RecurrenceRule>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_end_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_start_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<Status>,
+}
+
+/// Update a scheduled event in a guild.
+///
+/// Changing the entity type of an event to [`EntityType::External`] requires
+/// removing its channel with [`channel_id`], and setting a [`location`] and a
+/// [`scheduled_end_time`].
+///
+/// [`EntityType::External`]: ../../../../twilight_model/guild/scheduled_event/enum.EntityType.html#variant.External
+/// [`channel_id`]: #method.channel_id
+/// [`location`]: #method.location
+/// [`scheduled_end_time`]: #method.scheduled_end_time
+pub struct UpdateGuildScheduledEvent<'a> {
+    fields: UpdateGuildScheduledEventFields,
+    fut: Option<Pending<'a, GuildScheduledEvent>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    scheduled_event_id: ScheduledEventId,
+}
+
+impl<'a> UpdateGuildScheduledEvent<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        scheduled_event_id: ScheduledEventId,
+    ) -> Self {
+        Self {
+            fields: UpdateGuildScheduledEventFields::default(),
+            fut: None,
+            guild_id,
+            http,
+            scheduled_event_id,
+        }
+    }
+
+    /// Set the stage or voice channel the event takes place in, or `None` to
+    /// remove it.
+    pub fn channel_id(mut self, channel_id: impl Into<Option<ChannelId>>) -> Self {
+        self.fields.channel_id.replace(channel_id.into());
+
+        self
+    }
+
+    /// Set the description of the event, or `None` to remove it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildScheduledEventErrorType::DescriptionInvalid`] when the
+    /// length of the description is either fewer than 1 UTF-16 character or
+    /// more than 1000 UTF-16 characters.
+    ///
+    /// [`UpdateGuildScheduledEventErrorType::DescriptionInvalid`]: enum.UpdateGuildScheduledEventErrorType.html#variant.DescriptionInvalid
+    pub fn description(
+        self,
+        description: impl Into<Option<String>>,
+    ) -> Result<Self, UpdateGuildScheduledEventError> {
+        self._description(description.into())
+    }
+
+    fn _description(
+        mut self,
+        description: Option<String>,
+    ) -> Result<Self, UpdateGuildScheduledEventError> {
+        if let Some(description) = description.as_ref() {
+            if !validate::scheduled_event_description(description) {
+                return Err(UpdateGuildScheduledEventError {
+                    kind: UpdateGuildScheduledEventErrorType::DescriptionInvalid {
+                        description: description.to_owned(),
+                    },
+                    source: None,
+                });
+            }
+        }
+
+        self.fields.description.replace(description);
+
+        Ok(self)
+    }
+
+    /// Set the type of entity the event takes place in.
+    pub fn entity_type(mut self, entity_type: EntityType) -> Self {
+        self.fields.entity_type.replace(entity_type);
+
+        self
+    }
+
+    /// Set the location of an event with an external entity.
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.fields.entity_metadata.replace(EntityMetadata {
+            location: Some(location.into()),
+        });
+
+        self
+    }
+
+    /// Set the name of the event.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildScheduledEventErrorType::NameInvalid`] when the length
+    /// of the name is either fewer than 1 UTF-16 character or more than 100
+    /// UTF-16 characters.
+    ///
+    /// [`UpdateGuildScheduledEventErrorType::NameInvalid`]: enum.UpdateGuildScheduledEventErrorType.html#variant.NameInvalid
+    pub fn name(self, name: impl Into<String>) -> Result<Self, UpdateGuildScheduledEventError> {
+        self._name(name.into())
+    }
+
+    fn _name(mut self, name: String) -> Result<Self, UpdateGuildScheduledEventError> {
+        if !validate::scheduled_event_name(&name) {
+            return Err(UpdateGuildScheduledEventError {
+                kind: UpdateGuildScheduledEventErrorType::NameInvalid { name },
+                source: None,
+            });
+        }
+
+        self.fields.name.replace(name);
+
+        Ok(self)
+    }
+
+    /// Set who can see the event.
+    pub fn privacy_level(mut self, privacy_level: PrivacyLevel) -> Self {
+        self.fields.privacy_level.replace(privacy_level);
+
+        self
+    }
+
+    /// Set the rule the event recurs by, or `None` to stop it from recurring.
+    pub fn recurrence_rule(mut self, recurrence_rule: impl Into<Option<RecurrenceRule>>) -> Self {
+        self.fields.recurrence_rule.replace(recurrence_rule.into());
+
+        self
+    }
+
+    /// Set the ISO 8601 timestamp of when the event ends.
+    pub fn scheduled_end_time(mut self, scheduled_end_time: impl Into<String>) -> Self {
+        self.fields
+            .scheduled_end_time
+            .replace(scheduled_end_time.into());
+
+        self
+    }
+
+    /// Set the ISO 8601 timestamp of when the event starts.
+    pub fn scheduled_start_time(mut self, scheduled_start_time: impl Into<String>) -> Self {
+        self.fields
+            .scheduled_start_time
+            .replace(scheduled_start_time.into());
+
+        self
+    }
+
+    /// Set the status of the event, such as to start or cancel it.
+    pub fn status(mut self, status: Status) -> Self {
+        self.fields.status.replace(status);
+
+        self
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = Request::from((
+            crate::json_to_vec(&self.fields)?,
+            Route::UpdateGuildScheduledEvent {
+                guild_id: self.guild_id.0,
+                scheduled_event_id: self.scheduled_event_id.0,
+            },
+        ));
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateGuildScheduledEvent<'_>, GuildScheduledEvent);
@@ -0,0 +1,230 @@
+use crate::request::prelude::*;
+use reqwest::multipart::{Form, Part};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{channel::message::sticker::Sticker, id::GuildId};
+
+/// Returned when the sticker can not be created as configured.
+#[derive(Debug)]
+pub struct CreateGuildStickerError {
+    kind: CreateGuildStickerErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateGuildStickerError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateGuildStickerErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CreateGuildStickerErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateGuildStickerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateGuildStickerErrorType::DescriptionInvalid { .. } => {
+                f.write_str("the length of the description is invalid")
+            }
+            CreateGuildStickerErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+            CreateGuildStickerErrorType::TagsInvalid { .. } => {
+                f.write_str("the length of the tags is invalid")
+            }
+        }
+    }
+}
+
+impl Error for CreateGuildStickerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateGuildStickerError`] that occurred.
+///
+/// [`CreateGuildStickerError`]: struct.CreateGuildStickerError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum CreateGuildStickerErrorType {
+    /// The length of the description is either 1 UTF-16 character or more
+    /// than 100 UTF-16 characters.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+    },
+    /// The length of the name is either fewer than 2 UTF-16 characters or
+    /// more than 30 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+    /// The length of the tags is either fewer than 1 UTF-16 character or
+    /// more than 200 UTF-16 characters.
+    TagsInvalid {
+        /// Provided tags.
+        tags: String,
+    },
+}
+
+struct CreateGuildStickerFields {
+    description: String,
+    file: Vec<u8>,
+    name: String,
+    tags: String,
+}
+
+/// Create a sticker in a guild.
+///
+/// The file is a PNG, APNG or Lottie JSON file of at most 500 KiB. The tags
+/// are the name of the unicode emoji the sticker is suggested for, or a
+/// comma separated list of keywords.
+///
+/// Requires the [`MANAGE_EMOJIS`] permission.
+///
+/// # Errors
+///
+/// Returns a [`CreateGuildStickerErrorType::DescriptionInvalid`] when the length
+/// of the description is either 1 UTF-16 character or more than 100 UTF-16
+/// characters.
+///
+/// Returns a [`CreateGuildStickerErrorType::NameInvalid`] when the length of the
+/// name is either fewer than 2 UTF-16 characters or more than 30 UTF-16
+/// characters.
+///
+/// Returns a [`CreateGuildStickerErrorType::TagsInvalid`] when the length of the
+/// tags is either fewer than 1 UTF-16 character or more than 200 UTF-16
+/// characters.
+///
+/// [`CreateGuildStickerErrorType::DescriptionInvalid`]: enum.CreateGuildStickerErrorType.html#variant.DescriptionInvalid
+/// [`CreateGuildStickerErrorType::NameInvalid`]: enum.CreateGuildStickerErrorType.html#variant.NameInvalid
+/// [`CreateGuildStickerErrorType::TagsInvalid`]: enum.CreateGuildStickerErrorType.html#variant.TagsInvalid
+/// [`MANAGE_EMOJIS`]: ../../../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+pub struct CreateGuildSticker<'a> {
+    fields: CreateGuildStickerFields,
+    fut: Option<Pending<'a, Sticker>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+}
+
+impl<'a> CreateGuildSticker<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        tags: impl Into<String>,
+        file: impl Into<Vec<u8>>,
+    ) -> Result<Self, CreateGuildStickerError> {
+        Self::_new(
+            http,
+            guild_id,
+            name.into(),
+            description.into(),
+            tags.into(),
+            file.into(),
+        )
+    }
+
+    fn _new(
+        http: &'a Client,
+        guild_id: GuildId,
+        name: String,
+        description: String,
+        tags: String,
+        file: Vec<u8>,
+    ) -> Result<Self, CreateGuildStickerError> {
+        if !validate::sticker_name(&name) {
+            return Err(CreateGuildStickerError {
+                kind: CreateGuildStickerErrorType::NameInvalid { name },
+                source: None,
+            });
+        }
+
+        if !validate::sticker_description(&description) {
+            return Err(CreateGuildStickerError {
+                kind: CreateGuildStickerErrorType::DescriptionInvalid { description },
+                source: None,
+            });
+        }
+
+        if !validate::sticker_tags(&tags) {
+            return Err(CreateGuildStickerError {
+                kind: CreateGuildStickerErrorType::TagsInvalid { tags },
+                source: None,
+            });
+        }
+
+        Ok(Self {
+            fields: CreateGuildStickerFields {
+                description,
+                file,
+                name,
+                tags,
+            },
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+        })
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let route = Route::CreateGuildSticker {
+            guild_id: self.guild_id.0,
+        };
+
+        let form = Form::new()
+            .text("name", self.fields.name.clone())
+            .text("description", self.fields.description.clone())
+            .text("tags", self.fields.tags.clone())
+            .part(
+                "file",
+                Part::bytes(self.fields.file.clone()).file_name("sticker"),
+            );
+
+        let request = if let Some(reason) = &self.reason {
+            let headers = audit_header(&reason)?;
+            Request::from((form, headers, route))
+        } else {
+            Request::from((form, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for CreateGuildSticker<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(CreateGuildSticker<'_>, Sticker);
@@ -0,0 +1,56 @@
+use crate::request::prelude::*;
+use twilight_model::id::{GuildId, StickerId};
+
+/// Delete a sticker in a guild.
+pub struct DeleteGuildSticker<'a> {
+    fut: Option<Pending<'a, ()>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+    sticker_id: StickerId,
+}
+
+impl<'a> DeleteGuildSticker<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId, sticker_id: StickerId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+            sticker_id,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let request = if let Some(reason) = &self.reason {
+            let headers = audit_header(&reason)?;
+            Request::from((
+                headers,
+                Route::DeleteGuildSticker {
+                    guild_id: self.guild_id.0,
+                    sticker_id: self.sticker_id.0,
+                },
+            ))
+        } else {
+            Request::from(Route::DeleteGuildSticker {
+                guild_id: self.guild_id.0,
+                sticker_id: self.sticker_id.0,
+            })
+        };
+
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for DeleteGuildSticker<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(DeleteGuildSticker<'_>, ());
@@ -0,0 +1,31 @@
+use crate::request::prelude::*;
+use twilight_model::{channel::message::sticker::Sticker, id::GuildId};
+
+/// Get the stickers in a guild.
+pub struct GetGuildStickers<'a> {
+    fut: Option<Pending<'a, Vec<Sticker>>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> GetGuildStickers<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(self.http.request(Request::from(
+            Route::GetGuildStickers {
+                guild_id: self.guild_id.0,
+            },
+        ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetGuildStickers<'_>, Vec<Sticker>);
@@ -0,0 +1,238 @@
+use crate::request::prelude::*;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::message::sticker::Sticker,
+    id::{GuildId, StickerId},
+};
+
+/// Returned when the sticker can not be updated as configured.
+#[derive(Debug)]
+pub struct UpdateGuildStickerError {
+    kind: UpdateGuildStickerErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl UpdateGuildStickerError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateGuildStickerErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        UpdateGuildStickerErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for UpdateGuildStickerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UpdateGuildStickerErrorType::DescriptionInvalid { .. } => {
+                f.write_str("the length of the description is invalid")
+            }
+            UpdateGuildStickerErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+            UpdateGuildStickerErrorType::TagsInvalid { .. } => {
+                f.write_str("the length of the tags is invalid")
+            }
+        }
+    }
+}
+
+impl Error for UpdateGuildStickerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`UpdateGuildStickerError`] that occurred.
+///
+/// [`UpdateGuildStickerError`]: struct.UpdateGuildStickerError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum UpdateGuildStickerErrorType {
+    /// The length of the description is either 1 UTF-16 character or more
+    /// than 100 UTF-16 characters.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+    },
+    /// The length of the name is either fewer than 2 UTF-16 characters or
+    /// more than 30 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+    /// The length of the tags is either fewer than 1 UTF-16 character or
+    /// more than 200 UTF-16 characters.
+    TagsInvalid {
+        /// Provided tags.
+        tags: String,
+    },
+}
+
+#[derive(Default, Serialize)]
+struct UpdateGuildStickerFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<String>,
+}
+
+/// Update a sticker in a guild.
+///
+/// Requires the [`MANAGE_EMOJIS`] permission.
+///
+/// [`MANAGE_EMOJIS`]: ../../../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+pub struct UpdateGuildSticker<'a> {
+    fields: UpdateGuildStickerFields,
+    fut: Option<Pending<'a, Sticker>>,
+    guild_id: GuildId,
+    http: &'a Client,
+    reason: Option<String>,
+    sticker_id: StickerId,
+}
+
+impl<'a> UpdateGuildSticker<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId, sticker_id: StickerId) -> Self {
+        Self {
+            fields: UpdateGuildStickerFields::default(),
+            fut: None,
+            guild_id,
+            http,
+            reason: None,
+            sticker_id,
+        }
+    }
+
+    /// Set the description of the sticker.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildStickerErrorType::DescriptionInvalid`] when the length
+    /// of the description is either 1 UTF-16 character or more than 100
+    /// UTF-16 characters.
+    ///
+    /// [`UpdateGuildStickerErrorType::DescriptionInvalid`]: enum.UpdateGuildStickerErrorType.html#variant.DescriptionInvalid
+    pub fn description(
+        self,
+        description: impl Into<String>,
+    ) -> Result<Self, UpdateGuildStickerError> {
+        self._description(description.into())
+    }
+
+    fn _description(mut self, description: String) -> Result<Self, UpdateGuildStickerError> {
+        if !validate::sticker_description(&description) {
+            return Err(UpdateGuildStickerError {
+                kind: UpdateGuildStickerErrorType::DescriptionInvalid { description },
+                source: None,
+            });
+        }
+
+        self.fields.description.replace(description);
+
+        Ok(self)
+    }
+
+    /// Set the name of the sticker.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildStickerErrorType::NameInvalid`] when the length of the
+    /// name is either fewer than 2 UTF-16 characters or more than 30 UTF-16
+    /// characters.
+    ///
+    /// [`UpdateGuildStickerErrorType::NameInvalid`]: enum.UpdateGuildStickerErrorType.html#variant.NameInvalid
+    pub fn name(self, name: impl Into<String>) -> Result<Self, UpdateGuildStickerError> {
+        self._name(name.into())
+    }
+
+    fn _name(mut self, name: String) -> Result<Self, UpdateGuildStickerError> {
+        if !validate::sticker_name(&name) {
+            return Err(UpdateGuildStickerError {
+                kind: UpdateGuildStickerErrorType::NameInvalid { name },
+                source: None,
+            });
+        }
+
+        self.fields.name.replace(name);
+
+        Ok(self)
+    }
+
+    /// Set the autocomplete tags of the sticker.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UpdateGuildStickerErrorType::TagsInvalid`] when the length of the
+    /// tags is either fewer than 1 UTF-16 character or more than 200 UTF-16
+    /// characters.
+    ///
+    /// [`UpdateGuildStickerErrorType::TagsInvalid`]: enum.UpdateGuildStickerErrorType.html#variant.TagsInvalid
+    pub fn tags(self, tags: impl Into<String>) -> Result<Self, UpdateGuildStickerError> {
+        self._tags(tags.into())
+    }
+
+    fn _tags(mut self, tags: String) -> Result<Self, UpdateGuildStickerError> {
+        if !validate::sticker_tags(&tags) {
+            return Err(UpdateGuildStickerError {
+                kind: UpdateGuildStickerErrorType::TagsInvalid { tags },
+                source: None,
+            });
+        }
+
+        self.fields.tags.replace(tags);
+
+        Ok(self)
+    }
+
+    fn start(&mut self) -> Result<()> {
+        let route = Route::UpdateGuildSticker {
+            guild_id: self.guild_id.0,
+            sticker_id: self.sticker_id.0,
+        };
+
+        let request = if let Some(reason) = &self.reason {
+            let headers = audit_header(&reason)?;
+            Request::from((crate::json_to_vec(&self.fields)?, headers, route))
+        } else {
+            Request::from((crate::json_to_vec(&self.fields)?, route))
+        };
+
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+impl<'a> AuditLogReason for UpdateGuildSticker<'a> {
+    fn reason(mut self, reason: impl Into<String>) -> Result<Self, AuditLogReasonError> {
+        self.reason
+            .replace(AuditLogReasonError::validate(reason.into())?);
+
+        Ok(self)
+    }
+}
+
+poll_req!(UpdateGuildSticker<'_>, Sticker);
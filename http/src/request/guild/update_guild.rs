@@ -11,9 +11,54 @@ use twilight_model::{
 };
 
 /// The error returned when the guild can not be updated as configured.
+#[derive(Debug)]
+pub struct UpdateGuildError {
+    kind: UpdateGuildErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl UpdateGuildError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateGuildErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (UpdateGuildErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for UpdateGuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UpdateGuildErrorType::NameInvalid { .. } => f.write_str("the name's length is invalid"),
+        }
+    }
+}
+
+impl Error for UpdateGuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`UpdateGuildError`] that occurred.
+///
+/// [`UpdateGuildError`]: struct.UpdateGuildError.html
 #[derive(Clone, Debug)]
 #[non_exhaustive]
-pub enum UpdateGuildError {
+pub enum UpdateGuildErrorType {
     /// The name length is either fewer than 2 UTF-16 characters or more than 100 UTF-16
     /// characters.
     NameInvalid {
@@ -22,16 +67,6 @@ pub enum UpdateGuildError {
     },
 }
 
-impl Display for UpdateGuildError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::NameInvalid { .. } => f.write_str("the name's length is invalid"),
-        }
-    }
-}
-
-impl Error for UpdateGuildError {}
-
 #[derive(Default, Serialize)]
 struct UpdateGuildFields {
     #[allow(clippy::option_option)]
@@ -175,17 +210,20 @@ impl<'a> UpdateGuild<'a> {
     ///
     /// # Erroors
     ///
-    /// Returns [`UpdateGuildError::NameInvalid`] if the name length is too
+    /// Returns [`UpdateGuildErrorType::NameInvalid`] if the name length is too
     /// short or too long.
     ///
-    /// [`UpdateGuildError::NameInvalid`]: enum.UpdateGuildError.html#variant.NameInvalid
+    /// [`UpdateGuildErrorType::NameInvalid`]: enum.UpdateGuildErrorType.html#variant.NameInvalid
     pub fn name(self, name: impl Into<String>) -> Result<Self, UpdateGuildError> {
         self._name(name.into())
     }
 
     fn _name(mut self, name: String) -> Result<Self, UpdateGuildError> {
         if !validate::guild_name(&name) {
-            return Err(UpdateGuildError::NameInvalid { name });
+            return Err(UpdateGuildError {
+                kind: UpdateGuildErrorType::NameInvalid { name },
+                source: None,
+            });
         }
 
         self.fields.name.replace(name);
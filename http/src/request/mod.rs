@@ -35,8 +35,12 @@ macro_rules! poll_req {
                     if let Some(fut) = self.as_mut().fut.as_mut() {
                         let bytes = match fut.as_mut().poll(cx) {
                             Poll::Ready(Ok(bytes)) => bytes,
-                            Poll::Ready(Err(crate::Error::Response { status, .. }))
-                                if status == reqwest::StatusCode::NOT_FOUND =>
+                            Poll::Ready(Err(why))
+                                if matches!(
+                                    why.kind(),
+                                    crate::error::ErrorType::Response { status, .. }
+                                        if *status == reqwest::StatusCode::NOT_FOUND
+                                ) =>
                             {
                                 return Poll::Ready(Ok(None));
                             }
@@ -46,9 +50,13 @@ macro_rules! poll_req {
 
                         let mut bytes = bytes.as_ref().to_vec();
                         return Poll::Ready(json_from_slice(&mut bytes).map(Some).map_err(
-                            |source| crate::Error::Parsing {
-                                body: bytes.to_vec(),
-                                source,
+                            |source| {
+                                crate::Error::with_source(
+                                    crate::error::ErrorType::Parsing {
+                                        body: bytes.to_vec(),
+                                    },
+                                    source,
+                                )
                             },
                         ));
                     }
@@ -62,9 +70,11 @@ macro_rules! poll_req {
     };
 }
 
+pub mod application;
 pub mod channel;
 pub mod guild;
 pub mod prelude;
+pub mod sticker;
 pub mod user;
 
 mod audit_reason;
@@ -75,7 +85,7 @@ mod get_voice_regions;
 mod validate;
 
 pub use self::{
-    audit_reason::{AuditLogReason, AuditLogReasonError},
+    audit_reason::{AuditLogReason, AuditLogReasonError, AuditLogReasonErrorType},
     get_gateway::GetGateway,
     get_gateway_authed::GetGatewayAuthed,
     get_user_application::GetUserApplicationInfo,
@@ -83,7 +93,7 @@ pub use self::{
 };
 
 use crate::{
-    error::{Error, Result},
+    error::{Error, ErrorType, Result},
     routing::{Path, Route},
 };
 use bytes::Bytes;
@@ -96,9 +106,18 @@ use reqwest::{
 
 use std::{borrow::Cow, future::Future, pin::Pin};
 
+#[cfg(not(target_arch = "wasm32"))]
 type Pending<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+#[cfg(not(target_arch = "wasm32"))]
 type PendingOption<'a> = Pin<Box<dyn Future<Output = Result<Bytes>> + Send + 'a>>;
 
+// Futures of the fetch API aren't `Send`, which is fine on the single
+// thread of a browser or worker.
+#[cfg(target_arch = "wasm32")]
+type Pending<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + 'a>>;
+#[cfg(target_arch = "wasm32")]
+type PendingOption<'a> = Pin<Box<dyn Future<Output = Result<Bytes>> + 'a>>;
+
 #[derive(Debug)]
 pub struct Request {
     /// The body of the request, if any.
@@ -119,11 +138,14 @@ pub(crate) fn audit_header(reason: &str) -> Result<HeaderMap<HeaderValue>> {
     let header_name = HeaderName::from_static("x-audit-log-reason");
     let mut headers = HeaderMap::new();
     let encoded_reason = utf8_percent_encode(reason, NON_ALPHANUMERIC).to_string();
-    let header_value =
-        HeaderValue::from_str(&encoded_reason).map_err(|e| Error::CreatingHeader {
-            name: encoded_reason.clone(),
-            source: e,
-        })?;
+    let header_value = HeaderValue::from_str(&encoded_reason).map_err(|source| {
+        Error::with_source(
+            ErrorType::CreatingHeader {
+                name: encoded_reason.clone(),
+            },
+            source,
+        )
+    })?;
 
     headers.insert(header_name, header_value);
 
@@ -213,6 +235,21 @@ impl From<(Vec<u8>, Form, Route)> for Request {
     }
 }
 
+impl From<(Form, HeaderMap<HeaderValue>, Route)> for Request {
+    fn from((form, headers, route): (Form, HeaderMap<HeaderValue>, Route)) -> Self {
+        let (method, path, path_str) = route.into_parts();
+
+        Self {
+            body: None,
+            form: Some(form),
+            headers: Some(headers),
+            method,
+            path,
+            path_str,
+        }
+    }
+}
+
 impl From<(HeaderMap<HeaderValue>, Route)> for Request {
     fn from((headers, route): (HeaderMap<HeaderValue>, Route)) -> Self {
         let (method, path, path_str) = route.into_parts();
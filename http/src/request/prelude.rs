@@ -1,12 +1,17 @@
-pub(super) use super::{audit_header, validate, Pending, PendingOption, Request};
 pub use super::{
+    application::*,
     audit_reason::{AuditLogReason, AuditLogReasonError},
-    channel::{invite::*, message::*, reaction::*, webhook::*, *},
+    channel::{invite::*, message::*, reaction::*, stage::*, thread::*, webhook::*, *},
     get_gateway::GetGateway,
     get_gateway_authed::GetGatewayAuthed,
     get_voice_regions::GetVoiceRegions,
-    guild::{ban::*, emoji::*, integration::*, member::*, role::*, *},
+    guild::{
+        auto_moderation::*, ban::*, emoji::*, integration::*, member::*, role::*,
+        scheduled_event::*, sticker::*, *,
+    },
+    sticker::*,
     user::*,
 };
+pub(super) use super::{audit_header, validate, Pending, PendingOption, Request};
 pub(super) use crate::{client::Client, error::Result, routing::Route};
 pub(super) use serde::Serialize;
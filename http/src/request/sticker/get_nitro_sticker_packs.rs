@@ -0,0 +1,33 @@
+use crate::request::prelude::*;
+use serde::Deserialize;
+use twilight_model::channel::message::sticker::StickerPack;
+
+/// Packs of official stickers available to Nitro subscribers.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct StickerPackListing {
+    /// List of sticker packs.
+    pub sticker_packs: Vec<StickerPack>,
+}
+
+/// Get the packs of official stickers available to Nitro subscribers.
+pub struct GetNitroStickerPacks<'a> {
+    fut: Option<Pending<'a, StickerPackListing>>,
+    http: &'a Client,
+}
+
+impl<'a> GetNitroStickerPacks<'a> {
+    pub(crate) fn new(http: &'a Client) -> Self {
+        Self { fut: None, http }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut.replace(Box::pin(
+            self.http
+                .request(Request::from(Route::GetNitroStickerPacks)),
+        ));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetNitroStickerPacks<'_>, StickerPackListing);
@@ -0,0 +1,32 @@
+use crate::request::prelude::*;
+use twilight_model::{channel::message::sticker::Sticker, id::StickerId};
+
+/// Get a sticker by its ID.
+pub struct GetSticker<'a> {
+    fut: Option<PendingOption<'a>>,
+    http: &'a Client,
+    sticker_id: StickerId,
+}
+
+impl<'a> GetSticker<'a> {
+    pub(crate) fn new(http: &'a Client, sticker_id: StickerId) -> Self {
+        Self {
+            fut: None,
+            http,
+            sticker_id,
+        }
+    }
+
+    fn start(&mut self) -> Result<()> {
+        self.fut
+            .replace(Box::pin(self.http.request_bytes(Request::from(
+                Route::GetSticker {
+                    sticker_id: self.sticker_id.0,
+                },
+            ))));
+
+        Ok(())
+    }
+}
+
+poll_req!(opt, GetSticker<'_>, Sticker);
@@ -7,25 +7,67 @@ use std::{
 use twilight_model::{guild::Permissions, id::GuildId};
 
 /// The error created when the current guilds can not be retrieved as configured.
-#[derive(Clone, Debug)]
-#[non_exhaustive]
-pub enum GetCurrentUserGuildsError {
-    /// The maximum number of guilds to retrieve is 0 or more than 100.
-    LimitInvalid {
-        /// Provided maximum number of guilds to retrieve.
-        limit: u64,
-    },
+#[derive(Debug)]
+pub struct GetCurrentUserGuildsError {
+    kind: GetCurrentUserGuildsErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl GetCurrentUserGuildsError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GetCurrentUserGuildsErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        GetCurrentUserGuildsErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for GetCurrentUserGuildsError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+        match &self.kind {
+            GetCurrentUserGuildsErrorType::LimitInvalid { .. } => {
+                f.write_str("the limit is invalid")
+            }
         }
     }
 }
 
-impl Error for GetCurrentUserGuildsError {}
+impl Error for GetCurrentUserGuildsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`GetCurrentUserGuildsError`] that occurred.
+///
+/// [`GetCurrentUserGuildsError`]: struct.GetCurrentUserGuildsError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum GetCurrentUserGuildsErrorType {
+    /// The maximum number of guilds to retrieve is 0 or more than 100.
+    LimitInvalid {
+        /// Provided maximum number of guilds to retrieve.
+        limit: u64,
+    },
+}
 
 struct GetCurrentUserGuildsFields {
     after: Option<GuildId>,
@@ -121,14 +163,17 @@ impl<'a> GetCurrentUserGuilds<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`GetCurrentUserGuildsError::LimitInvalid`] if the amount is greater
+    /// Returns [`GetCurrentUserGuildsErrorType::LimitInvalid`] if the amount is greater
     /// than 100.
     ///
-    /// [`GetCurrentUserGuildsError::LimitInvalid`]: enum.GetCurrentUserGuildsError.html#variant.LimitInvalid
+    /// [`GetCurrentUserGuildsErrorType::LimitInvalid`]: enum.GetCurrentUserGuildsErrorType.html#variant.LimitInvalid
     /// [the discord docs]: https://discordapp.com/developers/docs/resources/user#get-current-user-guilds-query-string-params
     pub fn limit(mut self, limit: u64) -> Result<Self, GetCurrentUserGuildsError> {
         if !validate::get_current_user_guilds_limit(limit) {
-            return Err(GetCurrentUserGuildsError::LimitInvalid { limit });
+            return Err(GetCurrentUserGuildsError {
+                kind: GetCurrentUserGuildsErrorType::LimitInvalid { limit },
+                source: None,
+            });
         }
 
         self.fields.limit.replace(limit);
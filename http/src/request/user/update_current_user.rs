@@ -6,9 +6,61 @@ use std::{
 use twilight_model::user::User;
 
 /// The error created when the user can not be updated as configured.
+#[derive(Debug)]
+pub struct UpdateCurrentUserError {
+    kind: UpdateCurrentUserErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl UpdateCurrentUserError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateCurrentUserErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        UpdateCurrentUserErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for UpdateCurrentUserError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UpdateCurrentUserErrorType::UsernameInvalid { .. } => {
+                f.write_str("the username length is invalid")
+            }
+        }
+    }
+}
+
+impl Error for UpdateCurrentUserError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`UpdateCurrentUserError`] that occurred.
+///
+/// [`UpdateCurrentUserError`]: struct.UpdateCurrentUserError.html
 #[derive(Clone, Debug)]
 #[non_exhaustive]
-pub enum UpdateCurrentUserError {
+pub enum UpdateCurrentUserErrorType {
     /// The length of the username is either fewer than 2 UTF-16 characters or more than 32 UTF-16
     /// characters.
     UsernameInvalid {
@@ -17,16 +69,6 @@ pub enum UpdateCurrentUserError {
     },
 }
 
-impl Display for UpdateCurrentUserError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::UsernameInvalid { .. } => f.write_str("the username length is invalid"),
-        }
-    }
-}
-
-impl Error for UpdateCurrentUserError {}
-
 #[derive(Default, Serialize)]
 struct UpdateCurrentUserFields {
     #[allow(clippy::option_option)]
@@ -74,17 +116,20 @@ impl<'a> UpdateCurrentUser<'a> {
     ///
     /// # Errors
     ///
-    /// Returns [`UpdateCurrentUserError::UsernameInvalid`] if the username length is too short or
+    /// Returns [`UpdateCurrentUserErrorType::UsernameInvalid`] if the username length is too short or
     /// too long.
     ///
-    /// [`UpdateCurrentUserError::UsernameInvalid`]: enum.UpdateCurrentUserError.html#variant.UsernameInvalid
+    /// [`UpdateCurrentUserErrorType::UsernameInvalid`]: enum.UpdateCurrentUserErrorType.html#variant.UsernameInvalid
     pub fn username(self, username: impl Into<String>) -> Result<Self, UpdateCurrentUserError> {
         self._username(username.into())
     }
 
     fn _username(mut self, username: String) -> Result<Self, UpdateCurrentUserError> {
         if !validate::username(&username) {
-            return Err(UpdateCurrentUserError::UsernameInvalid { username });
+            return Err(UpdateCurrentUserError {
+                kind: UpdateCurrentUserErrorType::UsernameInvalid { username },
+                source: None,
+            });
         }
 
         self.fields.username.replace(username);
@@ -7,77 +7,46 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::embed::Embed;
+use twilight_model::{
+    application::component::Component,
+    channel::embed::Embed,
+    id::{ChannelId, RoleId, StickerId},
+};
 
 /// An embed is not valid.
 ///
 /// Referenced values are used from [the Discord docs][docs].
 ///
 /// [docs]: https://discord.com/developers/docs/resources/channel#embed-limits
-#[derive(Clone, Debug)]
-#[non_exhaustive]
-pub enum EmbedValidationError {
-    /// The embed author's name is larger than
-    /// [the maximum][`AUTHOR_NAME_LENGTH`].
-    ///
-    /// [`AUTHOR_NAME_LENGTH`]: #const.AUTHOR_NAME_LENGTH
-    AuthorNameTooLarge {
-        /// The number of codepoints that were provided.
-        chars: usize,
-    },
-    /// The embed description is larger than
-    /// [the maximum][`DESCRIPTION_LENGTH`].
-    ///
-    /// [`DESCRIPTION_LENGTH`]: #const.DESCRIPTION_LENGTH
-    DescriptionTooLarge {
-        /// The number of codepoints that were provided.
-        chars: usize,
-    },
-    /// The combined content of all embed fields - author name, description,
-    /// footer, field names and values, and title - is larger than
-    /// [the maximum][`EMBED_TOTAL_LENGTH`].
-    ///
-    /// [`EMBED_TOTAL_LENGTH`]: #const.EMBED_TOTAL_LENGTH
-    EmbedTooLarge {
-        /// The number of codepoints that were provided.
-        chars: usize,
-    },
-    /// A field's name is larger than [the maximum][`FIELD_NAME_LENGTH`].
-    ///
-    /// [`FIELD_NAME_LENGTH`]: #const.FIELD_NAME_LENGTH
-    FieldNameTooLarge {
-        /// The number of codepoints that were provided.
-        chars: usize,
-    },
-    /// A field's value is larger than [the maximum][`FIELD_VALUE_LENGTH`].
-    ///
-    /// [`FIELD_VALUE_LENGTH`]: #const.FIELD_VALUE_LENGTH
-    FieldValueTooLarge {
-        /// The number of codepoints that were provided.
-        chars: usize,
-    },
-    /// The footer text is larger than [the maximum][`FOOTER_TEXT_LENGTH`].
-    ///
-    /// [`FOOTER_TEXT_LENGTH`]: #const.FOOTER_TEXT_LENGTH
-    FooterTextTooLarge {
-        /// The number of codepoints that were provided.
-        chars: usize,
-    },
-    /// The title is larger than [the maximum][`TITLE_LENGTH`].
-    ///
-    /// [`TITLE_LENGTH`]: #const.TITLE_LENGTH
-    TitleTooLarge {
-        /// The number of codepoints that were provided.
-        chars: usize,
-    },
-    /// There are more than [the maximum][`FIELD_COUNT`] number of fields in the
-    /// embed.
-    ///
-    /// [`FIELD_COUNT`]: #const.FIELD_COUNT
-    TooManyFields {
-        /// The number of fields that were provided.
-        amount: usize,
-    },
+#[derive(Debug)]
+pub struct EmbedValidationError {
+    kind: EmbedValidationErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl EmbedValidationError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &EmbedValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        EmbedValidationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
 }
 
 impl EmbedValidationError {
@@ -85,7 +54,7 @@ impl EmbedValidationError {
     pub const AUTHOR_NAME_LENGTH: usize = 256;
 
     /// The maximum embed description length in codepoints.
-    pub const DESCRIPTION_LENGTH: usize = 2048;
+    pub const DESCRIPTION_LENGTH: usize = 4096;
 
     /// The maximum combined embed length in codepoints.
     pub const EMBED_TOTAL_LENGTH: usize = 6000;
@@ -108,60 +77,145 @@ impl EmbedValidationError {
 
 impl Display for EmbedValidationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::AuthorNameTooLarge { chars } => write!(
+        match &self.kind {
+            EmbedValidationErrorType::AuthorNameTooLarge { chars } => write!(
                 f,
                 "the author name is {} characters long, but the max is {}",
                 chars,
-                Self::AUTHOR_NAME_LENGTH
+                EmbedValidationError::AUTHOR_NAME_LENGTH
             ),
-            Self::DescriptionTooLarge { chars } => write!(
+            EmbedValidationErrorType::DescriptionTooLarge { chars } => write!(
                 f,
                 "the description is {} characters long, but the max is {}",
                 chars,
-                Self::DESCRIPTION_LENGTH
+                EmbedValidationError::DESCRIPTION_LENGTH
             ),
-            Self::EmbedTooLarge { chars } => write!(
+            EmbedValidationErrorType::EmbedTooLarge { chars } => write!(
                 f,
                 "the combined total length of the embed is {} characters long, but the max is {}",
                 chars,
-                Self::EMBED_TOTAL_LENGTH
+                EmbedValidationError::EMBED_TOTAL_LENGTH
             ),
-            Self::FieldNameTooLarge { chars } => write!(
+            EmbedValidationErrorType::FieldNameTooLarge { chars } => write!(
                 f,
                 "a field name is {} characters long, but the max is {}",
                 chars,
-                Self::FIELD_NAME_LENGTH
+                EmbedValidationError::FIELD_NAME_LENGTH
             ),
-            Self::FieldValueTooLarge { chars } => write!(
+            EmbedValidationErrorType::FieldValueTooLarge { chars } => write!(
                 f,
                 "a field value is {} characters long, but the max is {}",
                 chars,
-                Self::FIELD_VALUE_LENGTH
+                EmbedValidationError::FIELD_VALUE_LENGTH
             ),
-            Self::FooterTextTooLarge { chars } => write!(
+            EmbedValidationErrorType::FooterTextTooLarge { chars } => write!(
                 f,
                 "the footer's text is {} characters long, but the max is {}",
                 chars,
-                Self::FOOTER_TEXT_LENGTH
+                EmbedValidationError::FOOTER_TEXT_LENGTH
             ),
-            Self::TitleTooLarge { chars } => write!(
+            EmbedValidationErrorType::TitleTooLarge { chars } => write!(
                 f,
                 "the title's length is {} characters long, but the max is {}",
                 chars,
-                Self::TITLE_LENGTH
+                EmbedValidationError::TITLE_LENGTH
             ),
-            Self::TooManyFields { amount } => write!(
+            EmbedValidationErrorType::TooManyFields { amount } => write!(
                 f,
                 "there are {} fields, but the maximum amount is {}",
                 amount,
-                Self::FIELD_COUNT
+                EmbedValidationError::FIELD_COUNT
             ),
         }
     }
 }
 
-impl Error for EmbedValidationError {}
+impl Error for EmbedValidationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`EmbedValidationError`] that occurred.
+///
+/// [`EmbedValidationError`]: struct.EmbedValidationError.html
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum EmbedValidationErrorType {
+    /// The embed author's name is larger than
+    /// [the maximum][`AUTHOR_NAME_LENGTH`].
+    ///
+    /// [`AUTHOR_NAME_LENGTH`]: struct.EmbedValidationError.html#associatedconstant.AUTHOR_NAME_LENGTH
+    AuthorNameTooLarge {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// The embed description is larger than
+    /// [the maximum][`DESCRIPTION_LENGTH`].
+    ///
+    /// [`DESCRIPTION_LENGTH`]: struct.EmbedValidationError.html#associatedconstant.DESCRIPTION_LENGTH
+    DescriptionTooLarge {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// The combined content of all embed fields - author name, description,
+    /// footer, field names and values, and title - is larger than
+    /// [the maximum][`EMBED_TOTAL_LENGTH`].
+    ///
+    /// [`EMBED_TOTAL_LENGTH`]: struct.EmbedValidationError.html#associatedconstant.EMBED_TOTAL_LENGTH
+    EmbedTooLarge {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A field's name is larger than [the maximum][`FIELD_NAME_LENGTH`].
+    ///
+    /// [`FIELD_NAME_LENGTH`]: struct.EmbedValidationError.html#associatedconstant.FIELD_NAME_LENGTH
+    FieldNameTooLarge {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A field's value is larger than [the maximum][`FIELD_VALUE_LENGTH`].
+    ///
+    /// [`FIELD_VALUE_LENGTH`]: struct.EmbedValidationError.html#associatedconstant.FIELD_VALUE_LENGTH
+    FieldValueTooLarge {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// The footer text is larger than [the maximum][`FOOTER_TEXT_LENGTH`].
+    ///
+    /// [`FOOTER_TEXT_LENGTH`]: struct.EmbedValidationError.html#associatedconstant.FOOTER_TEXT_LENGTH
+    FooterTextTooLarge {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// The title is larger than [the maximum][`TITLE_LENGTH`].
+    ///
+    /// [`TITLE_LENGTH`]: struct.EmbedValidationError.html#associatedconstant.TITLE_LENGTH
+    TitleTooLarge {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// There are more than [the maximum][`FIELD_COUNT`] number of fields in the
+    /// embed.
+    ///
+    /// [`FIELD_COUNT`]: struct.EmbedValidationError.html#associatedconstant.FIELD_COUNT
+    TooManyFields {
+        /// The number of fields that were provided.
+        amount: usize,
+    },
+}
+
+pub fn auto_moderation_exempt_channels(value: &[ChannelId]) -> bool {
+    // <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-auto-moderation-rule-structure>
+    value.len() <= 50
+}
+
+pub fn auto_moderation_exempt_roles(value: &[RoleId]) -> bool {
+    // <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-auto-moderation-rule-structure>
+    value.len() <= 20
+}
 
 pub fn ban_delete_message_days(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/guild#create-guild-ban-query-string-params>
@@ -179,6 +233,11 @@ fn _channel_name(value: &str) -> bool {
     len >= 2 && len <= 100
 }
 
+pub fn components(value: &[Component]) -> bool {
+    // <https://discord.com/developers/docs/interactions/message-components#action-rows>
+    value.len() <= 5
+}
+
 pub fn content_limit(value: impl AsRef<str>) -> bool {
     _content_limit(value.as_ref())
 }
@@ -192,8 +251,11 @@ pub fn embed(embed: &Embed) -> Result<(), EmbedValidationError> {
     let mut total = 0;
 
     if embed.fields.len() > EmbedValidationError::FIELD_COUNT {
-        return Err(EmbedValidationError::TooManyFields {
-            amount: embed.fields.len(),
+        return Err(EmbedValidationError {
+            kind: EmbedValidationErrorType::TooManyFields {
+                amount: embed.fields.len(),
+            },
+            source: None,
         });
     }
 
@@ -205,7 +267,10 @@ pub fn embed(embed: &Embed) -> Result<(), EmbedValidationError> {
         let chars = name.chars().count();
 
         if chars > EmbedValidationError::AUTHOR_NAME_LENGTH {
-            return Err(EmbedValidationError::AuthorNameTooLarge { chars });
+            return Err(EmbedValidationError {
+                kind: EmbedValidationErrorType::AuthorNameTooLarge { chars },
+                source: None,
+            });
         }
 
         total += chars;
@@ -215,7 +280,10 @@ pub fn embed(embed: &Embed) -> Result<(), EmbedValidationError> {
         let chars = description.chars().count();
 
         if chars > EmbedValidationError::DESCRIPTION_LENGTH {
-            return Err(EmbedValidationError::DescriptionTooLarge { chars });
+            return Err(EmbedValidationError {
+                kind: EmbedValidationErrorType::DescriptionTooLarge { chars },
+                source: None,
+            });
         }
 
         total += chars;
@@ -225,7 +293,10 @@ pub fn embed(embed: &Embed) -> Result<(), EmbedValidationError> {
         let chars = footer.text.chars().count();
 
         if chars > EmbedValidationError::FOOTER_TEXT_LENGTH {
-            return Err(EmbedValidationError::FooterTextTooLarge { chars });
+            return Err(EmbedValidationError {
+                kind: EmbedValidationErrorType::FooterTextTooLarge { chars },
+                source: None,
+            });
         }
 
         total += chars;
@@ -235,13 +306,19 @@ pub fn embed(embed: &Embed) -> Result<(), EmbedValidationError> {
         let name_chars = field.name.chars().count();
 
         if name_chars > EmbedValidationError::FIELD_NAME_LENGTH {
-            return Err(EmbedValidationError::FieldNameTooLarge { chars: name_chars });
+            return Err(EmbedValidationError {
+                kind: EmbedValidationErrorType::FieldNameTooLarge { chars: name_chars },
+                source: None,
+            });
         }
 
         let value_chars = field.value.chars().count();
 
         if value_chars > EmbedValidationError::FIELD_VALUE_LENGTH {
-            return Err(EmbedValidationError::FieldValueTooLarge { chars: value_chars });
+            return Err(EmbedValidationError {
+                kind: EmbedValidationErrorType::FieldValueTooLarge { chars: value_chars },
+                source: None,
+            });
         }
 
         total += name_chars + value_chars;
@@ -251,14 +328,20 @@ pub fn embed(embed: &Embed) -> Result<(), EmbedValidationError> {
         let chars = title.chars().count();
 
         if chars > EmbedValidationError::TITLE_LENGTH {
-            return Err(EmbedValidationError::TitleTooLarge { chars });
+            return Err(EmbedValidationError {
+                kind: EmbedValidationErrorType::TitleTooLarge { chars },
+                source: None,
+            });
         }
 
         total += chars;
     }
 
     if total > EmbedValidationError::EMBED_TOTAL_LENGTH {
-        return Err(EmbedValidationError::EmbedTooLarge { chars: total });
+        return Err(EmbedValidationError {
+            kind: EmbedValidationErrorType::EmbedTooLarge { chars: total },
+            source: None,
+        });
     }
 
     Ok(())
@@ -316,6 +399,93 @@ fn _nickname(value: &str) -> bool {
     len > 0 && len <= 32
 }
 
+pub fn scheduled_event_description(value: impl AsRef<str>) -> bool {
+    _scheduled_event_description(value.as_ref())
+}
+
+fn _scheduled_event_description(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event-json-params>
+    len > 0 && len <= 1000
+}
+
+pub fn scheduled_event_get_users(value: u64) -> bool {
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#get-guild-scheduled-event-users-query-string-params>
+    value > 0 && value <= 100
+}
+
+pub fn scheduled_event_name(value: impl AsRef<str>) -> bool {
+    _scheduled_event_name(value.as_ref())
+}
+
+fn _scheduled_event_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event-json-params>
+    len > 0 && len <= 100
+}
+
+pub fn stage_topic(value: impl AsRef<str>) -> bool {
+    _stage_topic(value.as_ref())
+}
+
+fn _stage_topic(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/stage-instance#stage-instance-object>
+    len > 0 && len <= 120
+}
+
+pub fn sticker_description(value: impl AsRef<str>) -> bool {
+    _sticker_description(value.as_ref())
+}
+
+fn _sticker_description(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/sticker#create-guild-sticker-form-params>
+    len == 0 || (len >= 2 && len <= 100)
+}
+
+pub fn sticker_ids(value: &[StickerId]) -> bool {
+    // <https://discord.com/developers/docs/resources/channel#create-message-jsonform-params>
+    value.len() <= 3
+}
+
+pub fn sticker_name(value: impl AsRef<str>) -> bool {
+    _sticker_name(value.as_ref())
+}
+
+fn _sticker_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/sticker#create-guild-sticker-form-params>
+    len >= 2 && len <= 30
+}
+
+pub fn sticker_tags(value: impl AsRef<str>) -> bool {
+    _sticker_tags(value.as_ref())
+}
+
+fn _sticker_tags(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/sticker#create-guild-sticker-form-params>
+    len > 0 && len <= 200
+}
+
+pub fn thread_name(value: impl AsRef<str>) -> bool {
+    _thread_name(value.as_ref())
+}
+
+fn _thread_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // <https://discord.com/developers/docs/resources/channel#start-thread-without-message-json-params>
+    len > 0 && len <= 100
+}
+
 pub fn username(value: impl AsRef<str>) -> bool {
     // <https://discordapp.com/developers/docs/resources/user#usernames-and-nicknames>
     _username(value.as_ref())
@@ -330,7 +500,10 @@ fn _username(value: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use twilight_model::channel::embed::{EmbedAuthor, EmbedField, EmbedFooter};
+    use twilight_model::{
+        application::component::{ActionRow, ComponentType},
+        channel::embed::{EmbedAuthor, EmbedField, EmbedFooter},
+    };
 
     fn base_embed() -> Embed {
         Embed {
@@ -350,6 +523,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_auto_moderation_exempt_channels() {
+        assert!(auto_moderation_exempt_channels(&[]));
+        assert!(auto_moderation_exempt_channels(&[ChannelId(1); 50]));
+
+        assert!(!auto_moderation_exempt_channels(&[ChannelId(1); 51]));
+    }
+
+    #[test]
+    fn test_auto_moderation_exempt_roles() {
+        assert!(auto_moderation_exempt_roles(&[]));
+        assert!(auto_moderation_exempt_roles(&[RoleId(1); 20]));
+
+        assert!(!auto_moderation_exempt_roles(&[RoleId(1); 21]));
+    }
+
     #[test]
     fn test_ban_delete_message_days() {
         assert!(ban_delete_message_days(0));
@@ -369,6 +558,19 @@ mod tests {
         assert!(!channel_name("a".repeat(101)));
     }
 
+    #[test]
+    fn test_components() {
+        let row = Component::ActionRow(ActionRow {
+            components: Vec::new(),
+            kind: ComponentType::ActionRow,
+        });
+
+        assert!(components(&[]));
+        assert!(components(&vec![row.clone(); 5]));
+
+        assert!(!components(&vec![row; 6]));
+    }
+
     #[test]
     fn test_content_limit() {
         assert!(content_limit(""));
@@ -423,21 +625,21 @@ mod tests {
             url: None,
         });
         assert!(matches!(
-            super::embed(&embed),
-            Err(EmbedValidationError::AuthorNameTooLarge { chars: 257 })
+            super::embed(&embed).unwrap_err().kind(),
+            EmbedValidationErrorType::AuthorNameTooLarge { chars: 257 }
         ));
     }
 
     #[test]
     fn test_embed_description_limit() {
         let mut embed = base_embed();
-        embed.description.replace(str::repeat("a", 2048));
+        embed.description.replace(str::repeat("a", 4096));
         assert!(super::embed(&embed).is_ok());
 
-        embed.description.replace(str::repeat("a", 2049));
+        embed.description.replace(str::repeat("a", 4097));
         assert!(matches!(
-            super::embed(&embed),
-            Err(EmbedValidationError::DescriptionTooLarge { chars: 2049 })
+            super::embed(&embed).unwrap_err().kind(),
+            EmbedValidationErrorType::DescriptionTooLarge { chars: 4097 }
         ));
     }
 
@@ -454,8 +656,8 @@ mod tests {
         }
 
         assert!(matches!(
-            super::embed(&embed),
-            Err(EmbedValidationError::TooManyFields { amount: 26 })
+            super::embed(&embed).unwrap_err().kind(),
+            EmbedValidationErrorType::TooManyFields { amount: 26 }
         ));
     }
 
@@ -475,8 +677,8 @@ mod tests {
             value: "a".to_owned(),
         });
         assert!(matches!(
-            super::embed(&embed),
-            Err(EmbedValidationError::FieldNameTooLarge { chars: 257 })
+            super::embed(&embed).unwrap_err().kind(),
+            EmbedValidationErrorType::FieldNameTooLarge { chars: 257 }
         ));
     }
 
@@ -496,8 +698,8 @@ mod tests {
             value: str::repeat("a", 1025),
         });
         assert!(matches!(
-            super::embed(&embed),
-            Err(EmbedValidationError::FieldValueTooLarge { chars: 1025 })
+            super::embed(&embed).unwrap_err().kind(),
+            EmbedValidationErrorType::FieldValueTooLarge { chars: 1025 }
         ));
     }
 
@@ -517,8 +719,8 @@ mod tests {
             text: str::repeat("a", 2049),
         });
         assert!(matches!(
-            super::embed(&embed),
-            Err(EmbedValidationError::FooterTextTooLarge { chars: 2049 })
+            super::embed(&embed).unwrap_err().kind(),
+            EmbedValidationErrorType::FooterTextTooLarge { chars: 2049 }
         ));
     }
 
@@ -530,8 +732,8 @@ mod tests {
 
         embed.title.replace(str::repeat("a", 257));
         assert!(matches!(
-            super::embed(&embed),
-            Err(EmbedValidationError::TitleTooLarge { chars: 257 })
+            super::embed(&embed).unwrap_err().kind(),
+            EmbedValidationErrorType::TitleTooLarge { chars: 257 }
         ));
     }
 
@@ -559,8 +761,8 @@ mod tests {
         });
 
         assert!(matches!(
-            super::embed(&embed),
-            Err(EmbedValidationError::EmbedTooLarge { chars: 6304 })
+            super::embed(&embed).unwrap_err().kind(),
+            EmbedValidationErrorType::EmbedTooLarge { chars: 6304 }
         ));
     }
 
@@ -635,6 +837,87 @@ mod tests {
         assert!(!nickname("a".repeat(33)));
     }
 
+    #[test]
+    fn test_scheduled_event_description() {
+        assert!(scheduled_event_description("a"));
+        assert!(scheduled_event_description("a".repeat(1000)));
+
+        assert!(!scheduled_event_description(""));
+        assert!(!scheduled_event_description("a".repeat(1001)));
+    }
+
+    #[test]
+    fn test_scheduled_event_get_users() {
+        assert!(scheduled_event_get_users(1));
+        assert!(scheduled_event_get_users(100));
+
+        assert!(!scheduled_event_get_users(0));
+        assert!(!scheduled_event_get_users(101));
+    }
+
+    #[test]
+    fn test_scheduled_event_name() {
+        assert!(scheduled_event_name("a"));
+        assert!(scheduled_event_name("a".repeat(100)));
+
+        assert!(!scheduled_event_name(""));
+        assert!(!scheduled_event_name("a".repeat(101)));
+    }
+
+    #[test]
+    fn test_stage_topic() {
+        assert!(stage_topic("a"));
+        assert!(stage_topic("a".repeat(120)));
+
+        assert!(!stage_topic(""));
+        assert!(!stage_topic("a".repeat(121)));
+    }
+
+    #[test]
+    fn test_sticker_description() {
+        assert!(sticker_description(""));
+        assert!(sticker_description("aa"));
+        assert!(sticker_description("a".repeat(100)));
+
+        assert!(!sticker_description("a"));
+        assert!(!sticker_description("a".repeat(101)));
+    }
+
+    #[test]
+    fn test_sticker_ids() {
+        assert!(sticker_ids(&[]));
+        assert!(sticker_ids(&[StickerId(1); 3]));
+
+        assert!(!sticker_ids(&[StickerId(1); 4]));
+    }
+
+    #[test]
+    fn test_sticker_name() {
+        assert!(sticker_name("aa"));
+        assert!(sticker_name("a".repeat(30)));
+
+        assert!(!sticker_name("a"));
+        assert!(!sticker_name("a".repeat(31)));
+    }
+
+    #[test]
+    fn test_sticker_tags() {
+        assert!(sticker_tags("a"));
+        assert!(sticker_tags("a".repeat(200)));
+
+        assert!(!sticker_tags(""));
+        assert!(!sticker_tags("a".repeat(201)));
+    }
+
+    #[test]
+    fn test_thread_name() {
+        assert!(thread_name("a"));
+        assert!(thread_name("a".repeat(100)));
+
+        assert!(!thread_name(""));
+        assert!(!thread_name("a".repeat(101)));
+    }
+
     #[test]
     fn test_username() {
         assert!(username("aa"));
@@ -1,245 +1,7 @@
-use reqwest::Method;
-use std::{
-    borrow::Cow,
-    convert::TryFrom,
-    error::Error as StdError,
-    fmt::{Display, Formatter, Result as FmtResult, Write},
-    num::ParseIntError,
-    str::FromStr,
-};
-
-#[derive(Clone, Debug, Eq, PartialEq)]
-#[non_exhaustive]
-pub enum PathParseError {
-    /// The ID couldn't be parsed as an integer.
-    IntegerParsing {
-        /// Additional information about the parsing failure.
-        source: ParseIntError,
-    },
-    /// When parsing into a [`Path::ChannelsIdMessagesId`] variant, the method
-    /// must also be specified via its `TryFrom` impl.
-    ///
-    /// [`Path::ChannelsIdMessageId`]: enum.Path.html#variant.ChannelsIdMessagesId
-    MessageIdWithoutMethod {
-        /// The ID of the channel.
-        channel_id: u64,
-    },
-    /// A static path for the provided path string wasn't found.
-    NoMatch,
-}
-
-impl From<ParseIntError> for PathParseError {
-    fn from(source: ParseIntError) -> Self {
-        Self::IntegerParsing { source }
-    }
-}
-
-impl Display for PathParseError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::IntegerParsing { .. } => f.write_str("An ID in a segment was invalid"),
-            Self::MessageIdWithoutMethod { .. } => {
-                f.write_str("A message path was detected but the method wasn't given")
-            }
-            Self::NoMatch => f.write_str("There was no matched path"),
-        }
-    }
-}
-
-impl StdError for PathParseError {
-    fn source(&self) -> Option<&(dyn StdError + 'static)> {
-        match self {
-            Self::IntegerParsing { source } => Some(source),
-            Self::MessageIdWithoutMethod { .. } | Self::NoMatch => None,
-        }
-    }
-}
-
-/// An enum representing a path, most useful for ratelimiting implementations.
-// If adding to this enum, be sure to add to the `TryFrom` impl.
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-#[non_exhaustive]
-pub enum Path {
-    /// Operating on a channel.
-    ChannelsId(u64),
-    /// Operating on a channel's invites.
-    ChannelsIdInvites(u64),
-    /// Operating on a channel's messages.
-    ChannelsIdMessages(u64),
-    /// Operating on a channel's messages by bulk deleting.
-    ChannelsIdMessagesBulkDelete(u64),
-    /// Operating on an individual channel's message.
-    ChannelsIdMessagesId(Method, u64),
-    /// Crossposting an individual channel's message.
-    ChannelsIdMessagesIdCrosspost(u64),
-    /// Operating on an individual channel's message's reactions.
-    ChannelsIdMessagesIdReactions(u64),
-    /// Operating on an individual channel's message's reactions while
-    /// specifying the user ID and emoji type.
-    ChannelsIdMessagesIdReactionsUserIdType(u64),
-    /// Operating on a channel's permission overwrites by ID.
-    ChannelsIdPermissionsOverwriteId(u64),
-    /// Operating on a channel's pins.
-    ChannelsIdPins(u64),
-    /// Operating on a channel's individual pinned message.
-    ChannelsIdPinsMessageId(u64),
-    /// Operating on a channel's typing indicator.
-    ChannelsIdTyping(u64),
-    /// Operating on a channel's webhooks.
-    ChannelsIdWebhooks(u64),
-    /// Operating on a channel's followers.
-    ChannelsIdFollowers(u64),
-    /// Operating with the gateway information.
-    Gateway,
-    /// Operating with the gateway information tailored to the current user.
-    GatewayBot,
-    /// Operating on the guild resource.
-    Guilds,
-    /// Operating on one of user's guilds.
-    GuildsId(u64),
-    GuildsIdBans(u64),
-    GuildsIdBansId(u64),
-    GuildsIdAuditLogs(u64),
-    GuildsIdBansUserId(u64),
-    GuildsIdChannels(u64),
-    GuildsIdWidget(u64),
-    GuildsIdEmojis(u64),
-    GuildsIdEmojisId(u64),
-    GuildsIdIntegrations(u64),
-    GuildsIdIntegrationsId(u64),
-    GuildsIdIntegrationsIdSync(u64),
-    GuildsIdInvites(u64),
-    GuildsIdMembers(u64),
-    GuildsIdMembersId(u64),
-    GuildsIdMembersIdRolesId(u64),
-    GuildsIdMembersMeNick(u64),
-    GuildsIdPreview(u64),
-    GuildsIdPrune(u64),
-    GuildsIdRegions(u64),
-    GuildsIdRoles(u64),
-    GuildsIdRolesId(u64),
-    GuildsIdVanityUrl(u64),
-    GuildsIdWebhooks(u64),
-    InvitesCode,
-    UsersId,
-    OauthApplicationsMe,
-    UsersIdConnections,
-    UsersIdChannels,
-    /// Operating on the state of a guild that the user is in.
-    UsersIdGuilds,
-    /// Operating on the state of a guild that the user is in.
-    UsersIdGuildsId,
-    /// Operating on the voice regions available to the current user.
-    VoiceRegions,
-    /// Operating on a webhook.
-    WebhooksId(u64),
-}
-
-impl FromStr for Path {
-    type Err = PathParseError;
+pub use twilight_http_ratelimiting::{Path, PathParseError};
 
-    /// Parses a string into a path.
-    ///
-    /// The string *may* start with a slash (`/`), which will be ignored.
-    ///
-    /// # Examples
-    ///
-    /// ```rust
-    /// use twilight_http::routing::Path;
-    /// use std::str::FromStr;
-    ///
-    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// assert_eq!(Path::VoiceRegions, Path::from_str("/voice/regions")?);
-    /// assert_eq!(
-    ///     Path::ChannelsIdMessages(123),
-    ///     Path::from_str("channels/123/messages")?,
-    /// );
-    /// # Ok(()) }
-    /// ```
-    #[allow(clippy::enum_glob_use)]
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use Path::*;
-
-        let skip = usize::from(s.starts_with('/'));
-
-        let parts = s.split('/').skip(skip).collect::<Vec<&str>>();
-
-        Ok(match parts.as_slice() {
-            ["channels", id] => ChannelsId(id.parse()?),
-            ["channels", id, "followers"] => ChannelsIdFollowers(id.parse()?),
-            ["channels", id, "invites"] => ChannelsIdInvites(id.parse()?),
-            ["channels", id, "messages"] => ChannelsIdMessages(id.parse()?),
-            ["channels", id, "messages", _] => {
-                return Err(PathParseError::MessageIdWithoutMethod {
-                    channel_id: id.parse()?,
-                });
-            }
-            ["channels", id, "messages", _, "crosspost"] => {
-                ChannelsIdMessagesIdCrosspost(id.parse()?)
-            }
-            ["channels", id, "messages", _, "reactions"] => {
-                ChannelsIdMessagesIdReactions(id.parse()?)
-            }
-            ["channels", id, "messages", _, "reactions", _, _] => {
-                ChannelsIdMessagesIdReactionsUserIdType(id.parse()?)
-            }
-            ["channels", id, "permissions", _] => ChannelsIdPermissionsOverwriteId(id.parse()?),
-            ["channels", id, "pins"] => ChannelsIdPins(id.parse()?),
-            ["channels", id, "pins", _] => ChannelsIdPinsMessageId(id.parse()?),
-            ["channels", id, "typing"] => ChannelsIdTyping(id.parse()?),
-            ["channels", id, "webhooks"] => ChannelsIdWebhooks(id.parse()?),
-            ["gateway"] => Gateway,
-            ["gateway", "bot"] => GatewayBot,
-            ["guilds"] => Guilds,
-            ["guilds", id] => GuildsId(id.parse()?),
-            ["guilds", id, "bans"] => GuildsIdBans(id.parse()?),
-            ["guilds", id, "bans", _] => GuildsIdBansUserId(id.parse()?),
-            ["guilds", id, "channels"] => GuildsIdChannels(id.parse()?),
-            ["guilds", id, "widget"] => GuildsIdWidget(id.parse()?),
-            ["guilds", id, "emojis"] => GuildsIdEmojis(id.parse()?),
-            ["guilds", id, "emojis", _] => GuildsIdEmojisId(id.parse()?),
-            ["guilds", id, "integrations"] => GuildsIdIntegrations(id.parse()?),
-            ["guilds", id, "integrations", _] => GuildsIdIntegrationsId(id.parse()?),
-            ["guilds", id, "integrations", _, "sync"] => GuildsIdIntegrationsIdSync(id.parse()?),
-            ["guilds", id, "invites"] => GuildsIdInvites(id.parse()?),
-            ["guilds", id, "members"] => GuildsIdMembers(id.parse()?),
-            ["guilds", id, "members", _] => GuildsIdMembersId(id.parse()?),
-            ["guilds", id, "members", _, "roles", _] => GuildsIdMembersIdRolesId(id.parse()?),
-            ["guilds", id, "members", "@me", "nick"] => GuildsIdMembersMeNick(id.parse()?),
-            ["guilds", id, "preview"] => GuildsIdPreview(id.parse()?),
-            ["guilds", id, "prune"] => GuildsIdPrune(id.parse()?),
-            ["guilds", id, "regions"] => GuildsIdRegions(id.parse()?),
-            ["guilds", id, "roles"] => GuildsIdRoles(id.parse()?),
-            ["guilds", id, "roles", _] => GuildsIdRolesId(id.parse()?),
-            ["guilds", id, "vanity-url"] => GuildsIdVanityUrl(id.parse()?),
-            ["guilds", id, "webhooks"] => GuildsIdWebhooks(id.parse()?),
-            ["invites", _] => InvitesCode,
-            ["oauth2", "applications", "@me"] => OauthApplicationsMe,
-            ["users", _] => UsersId,
-            ["users", _, "connections"] => UsersIdConnections,
-            ["users", _, "channels"] => UsersIdChannels,
-            ["users", _, "guilds"] => UsersIdGuilds,
-            ["users", _, "guilds", _] => UsersIdGuildsId,
-            ["voice", "regions"] => VoiceRegions,
-            ["webhooks", id] | ["webhooks", id, _] => WebhooksId(id.parse()?),
-            _ => return Err(PathParseError::NoMatch),
-        })
-    }
-}
-
-impl TryFrom<(Method, &str)> for Path {
-    type Error = PathParseError;
-
-    fn try_from((method, s): (Method, &str)) -> Result<Self, Self::Error> {
-        match Self::from_str(s) {
-            Ok(v) => Ok(v),
-            Err(PathParseError::MessageIdWithoutMethod { channel_id }) => {
-                Ok(Self::ChannelsIdMessagesId(method, channel_id))
-            }
-            Err(why) => Err(why),
-        }
-    }
-}
+use reqwest::Method;
+use std::{borrow::Cow, fmt::Write};
 
 #[derive(Clone, Debug)]
 #[non_exhaustive]
@@ -253,6 +15,18 @@ pub enum Route {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to add a member to a thread.
+    AddThreadMember {
+        /// The ID of the thread.
+        channel_id: u64,
+        /// The ID of the user.
+        user_id: u64,
+    },
+    /// Route information to create an auto moderation rule in a guild.
+    CreateAutoModerationRule {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to create a ban on a user in a guild.
     CreateBan {
         /// The number of days' worth of the user's messages to delete in the
@@ -275,8 +49,20 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to create a global command.
+    CreateGlobalCommand {
+        /// The ID of the application.
+        application_id: u64,
+    },
     /// Route information to create a guild.
     CreateGuild,
+    /// Route information to create a command in a guild.
+    CreateGuildCommand {
+        /// The ID of the application.
+        application_id: u64,
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to create a guild's integration.
     CreateGuildIntegration {
         /// The ID of the guild.
@@ -297,6 +83,16 @@ pub enum Route {
         /// pruned.
         include_roles: Vec<u64>,
     },
+    /// Route information to create a scheduled event in a guild.
+    CreateGuildScheduledEvent {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
+    /// Route information to create a sticker in a guild.
+    CreateGuildSticker {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to create an invite to a channel.
     CreateInvite {
         /// The ID of the channel.
@@ -323,6 +119,20 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to create a stage instance.
+    CreateStageInstance,
+    /// Route information to create a thread in a channel.
+    CreateThread {
+        /// The ID of the channel.
+        channel_id: u64,
+    },
+    /// Route information to create a thread from a message.
+    CreateThreadFromMessage {
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The ID of the message.
+        message_id: u64,
+    },
     /// Route information to create a typing trigger in a channel.
     CreateTypingTrigger {
         /// The ID of the channel.
@@ -340,6 +150,13 @@ pub enum Route {
         /// The ID of the message.
         message_id: u64,
     },
+    /// Route information to delete an auto moderation rule in a guild.
+    DeleteAutoModerationRule {
+        /// The ID of the auto moderation rule.
+        auto_moderation_rule_id: u64,
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to delete a ban on a user in a guild.
     DeleteBan {
         /// The ID of the guild.
@@ -359,11 +176,27 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to delete a global command.
+    DeleteGlobalCommand {
+        /// The ID of the application.
+        application_id: u64,
+        /// The ID of the command.
+        command_id: u64,
+    },
     /// Route information to delete a guild.
     DeleteGuild {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to delete a command in a guild.
+    DeleteGuildCommand {
+        /// The ID of the application.
+        application_id: u64,
+        /// The ID of the command.
+        command_id: u64,
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to delete a guild integration.
     DeleteGuildIntegration {
         /// The ID of the guild.
@@ -371,6 +204,20 @@ pub enum Route {
         /// The ID of the integration.
         integration_id: u64,
     },
+    /// Route information to delete a scheduled event in a guild.
+    DeleteGuildScheduledEvent {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The ID of the scheduled event.
+        scheduled_event_id: u64,
+    },
+    /// Route information to delete a sticker in a guild.
+    DeleteGuildSticker {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The ID of the sticker.
+        sticker_id: u64,
+    },
     /// Route information to delete an invite.
     DeleteInvite {
         /// The unique invite code.
@@ -431,6 +278,11 @@ pub enum Route {
         /// The ID of the role.
         role_id: u64,
     },
+    /// Route information to delete the stage instance of a channel.
+    DeleteStageInstance {
+        /// The ID of the stage channel.
+        channel_id: u64,
+    },
     /// Route information to delete a webhook.
     DeleteWebhook {
         /// The token of the webhook.
@@ -452,6 +304,11 @@ pub enum Route {
         /// The ID of the channel to follow.
         channel_id: u64,
     },
+    /// Route information to get the active threads of a guild.
+    GetActiveThreads {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a paginated list of audit logs in a guild.
     GetAuditLogs {
         /// The type of action to get audit logs for.
@@ -465,6 +322,13 @@ pub enum Route {
         /// The ID of the user, if specified.
         user_id: Option<u64>,
     },
+    /// Route information to get an auto moderation rule in a guild.
+    GetAutoModerationRule {
+        /// The ID of the auto moderation rule.
+        auto_moderation_rule_id: u64,
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get information about a single ban in a guild.
     GetBan {
         /// The ID of the guild.
@@ -516,6 +380,11 @@ pub enum Route {
     /// Route information to get gateway information tailored to the current
     /// user.
     GetGatewayBot,
+    /// Route information to get the global commands of an application.
+    GetGlobalCommands {
+        /// The ID of the application.
+        application_id: u64,
+    },
     /// Route information to get a guild.
     GetGuild {
         /// The ID of the guild.
@@ -524,6 +393,18 @@ pub enum Route {
         /// guild.
         with_counts: bool,
     },
+    /// Route information to get the auto moderation rules in a guild.
+    GetGuildAutoModerationRules {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
+    /// Route information to get the commands of an application in a guild.
+    GetGuildCommands {
+        /// The ID of the application.
+        application_id: u64,
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a guild's widget.
     GetGuildWidget {
         /// The ID of the guild.
@@ -574,6 +455,49 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get a scheduled event in a guild.
+    GetGuildScheduledEvent {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The ID of the scheduled event.
+        scheduled_event_id: u64,
+        /// Whether to include the number of subscribed users.
+        with_user_count: bool,
+    },
+    /// Route information to get the users subscribed to a scheduled event.
+    GetGuildScheduledEventUsers {
+        /// The minimum ID of users to get.
+        after: Option<u64>,
+        /// The maximum ID of users to get.
+        before: Option<u64>,
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The maximum number of users to get.
+        limit: Option<u64>,
+        /// The ID of the scheduled event.
+        scheduled_event_id: u64,
+        /// Whether to include the guild member of each user.
+        with_member: bool,
+    },
+    /// Route information to get the scheduled events in a guild.
+    GetGuildScheduledEvents {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// Whether to include the number of subscribed users.
+        with_user_count: bool,
+    },
+    /// Route information to get a sticker in a guild.
+    GetGuildSticker {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The ID of the sticker.
+        sticker_id: u64,
+    },
+    /// Route information to get the stickers in a guild.
+    GetGuildStickers {
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a guild's vanity URL.
     GetGuildVanityUrl {
         /// The ID of the guild.
@@ -605,6 +529,16 @@ pub enum Route {
         /// Whether to retrieve statistics about the invite.
         with_counts: bool,
     },
+    /// Route information to get the archived private threads of a channel
+    /// that the current user has joined.
+    GetJoinedPrivateArchivedThreads {
+        /// The maximum ID of threads to get.
+        before: Option<u64>,
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The maximum number of threads to get.
+        limit: Option<u64>,
+    },
     /// Route information to get a member.
     GetMember {
         /// The ID of the guild.
@@ -632,11 +566,34 @@ pub enum Route {
         /// The maximum number of messages to get.
         limit: Option<u64>,
     },
+    /// Route information to get the packs of official stickers available to
+    /// Nitro subscribers.
+    GetNitroStickerPacks,
     /// Route information to get a channel's pins.
     GetPins {
         /// The ID of the channel.
         channel_id: u64,
     },
+    /// Route information to get the archived private threads of a channel.
+    GetPrivateArchivedThreads {
+        /// The URI encoded timestamp that threads must have been archived
+        /// before.
+        before: Option<String>,
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The maximum number of threads to get.
+        limit: Option<u64>,
+    },
+    /// Route information to get the archived public threads of a channel.
+    GetPublicArchivedThreads {
+        /// The URI encoded timestamp that threads must have been archived
+        /// before.
+        before: Option<String>,
+        /// The ID of the channel.
+        channel_id: u64,
+        /// The maximum number of threads to get.
+        limit: Option<u64>,
+    },
     /// Route information to get the users who reacted to a message with a
     /// specified emoji.
     GetReactionUsers {
@@ -653,6 +610,21 @@ pub enum Route {
         /// The ID of the message.
         message_id: u64,
     },
+    /// Route information to get the stage instance of a channel.
+    GetStageInstance {
+        /// The ID of the stage channel.
+        channel_id: u64,
+    },
+    /// Route information to get a sticker.
+    GetSticker {
+        /// The ID of the sticker.
+        sticker_id: u64,
+    },
+    /// Route information to get the members of a thread.
+    GetThreadMembers {
+        /// The ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to get the current user.
     GetUser {
         /// The ID of the target user. This can be `@me` to specify the current
@@ -673,11 +645,28 @@ pub enum Route {
         /// The ID of the webhook.
         webhook_id: u64,
     },
+    /// Route information to respond to an interaction.
+    InteractionCallback {
+        /// The ID of the interaction.
+        interaction_id: u64,
+        /// The token of the interaction.
+        interaction_token: String,
+    },
+    /// Route information to add the current user to a thread.
+    JoinThread {
+        /// The ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to leave the guild.
     LeaveGuild {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to remove the current user from a thread.
+    LeaveThread {
+        /// The ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to pin a message to a channel.
     PinMessage {
         /// The ID of the channel.
@@ -701,6 +690,13 @@ pub enum Route {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to remove a member from a thread.
+    RemoveThreadMember {
+        /// The ID of the thread.
+        channel_id: u64,
+        /// The ID of the user.
+        user_id: u64,
+    },
     /// Route information to sync a guild's integration.
     SyncGuildIntegration {
         /// The ID of the guild.
@@ -715,6 +711,13 @@ pub enum Route {
         /// The ID of the message.
         message_id: u64,
     },
+    /// Route information to update an auto moderation rule in a guild.
+    UpdateAutoModerationRule {
+        /// The ID of the auto moderation rule.
+        auto_moderation_rule_id: u64,
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to update a channel, such as a guild channel or group.
     UpdateChannel {
         /// The ID of the channel.
@@ -729,11 +732,36 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to update a global command.
+    UpdateGlobalCommand {
+        /// The ID of the application.
+        application_id: u64,
+        /// The ID of the command.
+        command_id: u64,
+    },
+    /// Route information to update a followup message of an interaction.
+    UpdateFollowupMessage {
+        /// The ID of the application.
+        application_id: u64,
+        /// The token of the interaction.
+        interaction_token: String,
+        /// The ID of the message.
+        message_id: u64,
+    },
     /// Route information to update a guild.
     UpdateGuild {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to update a command in a guild.
+    UpdateGuildCommand {
+        /// The ID of the application.
+        application_id: u64,
+        /// The ID of the command.
+        command_id: u64,
+        /// The ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to update a guild channel.
     UpdateGuildChannels {
         /// The ID of the guild.
@@ -751,6 +779,20 @@ pub enum Route {
         /// The ID of the integration.
         integration_id: u64,
     },
+    /// Route information to update a scheduled event in a guild.
+    UpdateGuildScheduledEvent {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The ID of the scheduled event.
+        scheduled_event_id: u64,
+    },
+    /// Route information to update a sticker in a guild.
+    UpdateGuildSticker {
+        /// The ID of the guild.
+        guild_id: u64,
+        /// The ID of the sticker.
+        sticker_id: u64,
+    },
     /// Route information to update a member.
     UpdateMember {
         /// The ID of the guild.
@@ -790,6 +832,11 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to update the stage instance of a channel.
+    UpdateStageInstance {
+        /// The ID of the stage channel.
+        channel_id: u64,
+    },
     /// Route information to update a webhook.
     UpdateWebhook {
         /// The token of the webhook.
@@ -819,6 +866,19 @@ impl Route {
                 Path::GuildsIdMembersIdRolesId(guild_id),
                 format!("guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id).into(),
             ),
+            Self::AddThreadMember {
+                channel_id,
+                user_id,
+            } => (
+                Method::PUT,
+                Path::ChannelsIdThreadMembersId(channel_id),
+                format!("channels/{}/thread-members/{}", channel_id, user_id).into(),
+            ),
+            Self::CreateAutoModerationRule { guild_id } => (
+                Method::POST,
+                Path::GuildsIdAutoModerationRules(guild_id),
+                format!("guilds/{}/auto-moderation/rules", guild_id).into(),
+            ),
             Self::CreateBan {
                 guild_id,
                 delete_message_days,
@@ -850,7 +910,24 @@ impl Route {
                 Path::GuildsIdEmojis(guild_id),
                 format!("guilds/{}/emojis", guild_id).into(),
             ),
+            Self::CreateGlobalCommand { application_id } => (
+                Method::POST,
+                Path::ApplicationsIdCommands(application_id),
+                format!("applications/{}/commands", application_id).into(),
+            ),
             Self::CreateGuild => (Method::POST, Path::Guilds, "guilds".into()),
+            Self::CreateGuildCommand {
+                application_id,
+                guild_id,
+            } => (
+                Method::POST,
+                Path::ApplicationsIdGuildsIdCommands(application_id),
+                format!(
+                    "applications/{}/guilds/{}/commands",
+                    application_id, guild_id
+                )
+                .into(),
+            ),
             Self::CreateGuildIntegration { guild_id } => (
                 Method::POST,
                 Path::GuildsIdIntegrationsId(guild_id),
@@ -886,6 +963,16 @@ impl Route {
 
                 (Method::POST, Path::GuildsIdPrune(guild_id), path.into())
             }
+            Self::CreateGuildScheduledEvent { guild_id } => (
+                Method::POST,
+                Path::GuildsIdScheduledEvents(guild_id),
+                format!("guilds/{}/scheduled-events", guild_id).into(),
+            ),
+            Self::CreateGuildSticker { guild_id } => (
+                Method::POST,
+                Path::GuildsIdStickers(guild_id),
+                format!("guilds/{}/stickers", guild_id).into(),
+            ),
             Self::CreateInvite { channel_id } => (
                 Method::POST,
                 Path::ChannelsIdInvites(channel_id),
@@ -919,6 +1006,22 @@ impl Route {
                 Path::GuildsIdRoles(guild_id),
                 format!("guilds/{}/roles", guild_id).into(),
             ),
+            Self::CreateStageInstance => {
+                (Method::POST, Path::StageInstances, "stage-instances".into())
+            }
+            Self::CreateThread { channel_id } => (
+                Method::POST,
+                Path::ChannelsIdThreads(channel_id),
+                format!("channels/{}/threads", channel_id).into(),
+            ),
+            Self::CreateThreadFromMessage {
+                channel_id,
+                message_id,
+            } => (
+                Method::POST,
+                Path::ChannelsIdMessagesIdThreads(channel_id),
+                format!("channels/{}/messages/{}/threads", channel_id, message_id).into(),
+            ),
             Self::CreateTypingTrigger { channel_id } => (
                 Method::POST,
                 Path::ChannelsIdTyping(channel_id),
@@ -937,6 +1040,18 @@ impl Route {
                 Path::ChannelsIdMessagesIdCrosspost(channel_id),
                 format!("channels/{}/messages/{}/crosspost", channel_id, message_id).into(),
             ),
+            Self::DeleteAutoModerationRule {
+                auto_moderation_rule_id,
+                guild_id,
+            } => (
+                Method::DELETE,
+                Path::GuildsIdAutoModerationRulesId(guild_id),
+                format!(
+                    "guilds/{}/auto-moderation/rules/{}",
+                    guild_id, auto_moderation_rule_id
+                )
+                .into(),
+            ),
             Self::DeleteBan { guild_id, user_id } => (
                 Method::DELETE,
                 Path::GuildsIdBansUserId(guild_id),
@@ -952,11 +1067,32 @@ impl Route {
                 Path::GuildsIdEmojisId(guild_id),
                 format!("guilds/{}/emojis/{}", guild_id, emoji_id).into(),
             ),
+            Self::DeleteGlobalCommand {
+                application_id,
+                command_id,
+            } => (
+                Method::DELETE,
+                Path::ApplicationsIdCommandsId(application_id),
+                format!("applications/{}/commands/{}", application_id, command_id).into(),
+            ),
             Self::DeleteGuild { guild_id } => (
                 Method::DELETE,
                 Path::GuildsId(guild_id),
                 format!("guilds/{}", guild_id).into(),
             ),
+            Self::DeleteGuildCommand {
+                application_id,
+                command_id,
+                guild_id,
+            } => (
+                Method::DELETE,
+                Path::ApplicationsIdGuildsIdCommandsId(application_id),
+                format!(
+                    "applications/{}/guilds/{}/commands/{}",
+                    application_id, guild_id, command_id
+                )
+                .into(),
+            ),
             Self::DeleteGuildIntegration {
                 guild_id,
                 integration_id,
@@ -965,6 +1101,26 @@ impl Route {
                 Path::GuildsIdIntegrationsId(guild_id),
                 format!("guilds/{}/integrations/{}", guild_id, integration_id).into(),
             ),
+            Self::DeleteGuildScheduledEvent {
+                guild_id,
+                scheduled_event_id,
+            } => (
+                Method::DELETE,
+                Path::GuildsIdScheduledEventsId(guild_id),
+                format!(
+                    "guilds/{}/scheduled-events/{}",
+                    guild_id, scheduled_event_id
+                )
+                .into(),
+            ),
+            Self::DeleteGuildSticker {
+                guild_id,
+                sticker_id,
+            } => (
+                Method::DELETE,
+                Path::GuildsIdStickersId(guild_id),
+                format!("guilds/{}/stickers/{}", guild_id, sticker_id).into(),
+            ),
             Self::DeleteInvite { code } => (
                 Method::DELETE,
                 Path::InvitesCode,
@@ -1031,6 +1187,11 @@ impl Route {
                 Path::GuildsIdRolesId(guild_id),
                 format!("guilds/{}/roles/{}", guild_id, role_id).into(),
             ),
+            Self::DeleteStageInstance { channel_id } => (
+                Method::DELETE,
+                Path::StageInstancesId(channel_id),
+                format!("stage-instances/{}", channel_id).into(),
+            ),
             Self::DeleteWebhook { token, webhook_id } => {
                 let mut path = format!("webhooks/{}", webhook_id);
 
@@ -1059,6 +1220,11 @@ impl Route {
                 Path::ChannelsIdFollowers(channel_id),
                 format!("channels/{}/followers", channel_id).into(),
             ),
+            Self::GetActiveThreads { guild_id } => (
+                Method::GET,
+                Path::GuildsIdThreadsActive(guild_id),
+                format!("guilds/{}/threads/active", guild_id).into(),
+            ),
             Self::GetAuditLogs {
                 action_type,
                 before,
@@ -1086,6 +1252,18 @@ impl Route {
 
                 (Method::GET, Path::GuildsIdAuditLogs(guild_id), path.into())
             }
+            Self::GetAutoModerationRule {
+                auto_moderation_rule_id,
+                guild_id,
+            } => (
+                Method::GET,
+                Path::GuildsIdAutoModerationRulesId(guild_id),
+                format!(
+                    "guilds/{}/auto-moderation/rules/{}",
+                    guild_id, auto_moderation_rule_id
+                )
+                .into(),
+            ),
             Self::GetBan { guild_id, user_id } => (
                 Method::GET,
                 Path::GuildsIdBansId(guild_id),
@@ -1133,6 +1311,11 @@ impl Route {
                 format!("guilds/{}/emojis", guild_id).into(),
             ),
             Self::GetGateway => (Method::GET, Path::Gateway, "gateway".into()),
+            Self::GetGlobalCommands { application_id } => (
+                Method::GET,
+                Path::ApplicationsIdCommands(application_id),
+                format!("applications/{}/commands", application_id).into(),
+            ),
             Self::GetGuild {
                 guild_id,
                 with_counts,
@@ -1143,6 +1326,23 @@ impl Route {
                 }
                 (Method::GET, Path::GuildsId(guild_id), path.into())
             }
+            Self::GetGuildAutoModerationRules { guild_id } => (
+                Method::GET,
+                Path::GuildsIdAutoModerationRules(guild_id),
+                format!("guilds/{}/auto-moderation/rules", guild_id).into(),
+            ),
+            Self::GetGuildCommands {
+                application_id,
+                guild_id,
+            } => (
+                Method::GET,
+                Path::ApplicationsIdGuildsIdCommands(application_id),
+                format!(
+                    "applications/{}/guilds/{}/commands",
+                    application_id, guild_id
+                )
+                .into(),
+            ),
             Self::GetGuildWidget { guild_id } => (
                 Method::GET,
                 Path::GuildsIdWidget(guild_id),
@@ -1215,6 +1415,75 @@ impl Route {
                 Path::GuildsIdRoles(guild_id),
                 format!("guilds/{}/roles", guild_id).into(),
             ),
+            Self::GetGuildScheduledEvent {
+                guild_id,
+                scheduled_event_id,
+                with_user_count,
+            } => (
+                Method::GET,
+                Path::GuildsIdScheduledEventsId(guild_id),
+                format!(
+                    "guilds/{}/scheduled-events/{}?with_user_count={}",
+                    guild_id, scheduled_event_id, with_user_count
+                )
+                .into(),
+            ),
+            Self::GetGuildScheduledEventUsers {
+                after,
+                before,
+                guild_id,
+                limit,
+                scheduled_event_id,
+                with_member,
+            } => {
+                let mut path = format!(
+                    "guilds/{}/scheduled-events/{}/users?with_member={}",
+                    guild_id, scheduled_event_id, with_member
+                );
+
+                if let Some(after) = after {
+                    let _ = write!(path, "&after={}", after);
+                }
+
+                if let Some(before) = before {
+                    let _ = write!(path, "&before={}", before);
+                }
+
+                if let Some(limit) = limit {
+                    let _ = write!(path, "&limit={}", limit);
+                }
+
+                (
+                    Method::GET,
+                    Path::GuildsIdScheduledEventsIdUsers(guild_id),
+                    path.into(),
+                )
+            }
+            Self::GetGuildScheduledEvents {
+                guild_id,
+                with_user_count,
+            } => (
+                Method::GET,
+                Path::GuildsIdScheduledEvents(guild_id),
+                format!(
+                    "guilds/{}/scheduled-events?with_user_count={}",
+                    guild_id, with_user_count
+                )
+                .into(),
+            ),
+            Self::GetGuildSticker {
+                guild_id,
+                sticker_id,
+            } => (
+                Method::GET,
+                Path::GuildsIdStickersId(guild_id),
+                format!("guilds/{}/stickers/{}", guild_id, sticker_id).into(),
+            ),
+            Self::GetGuildStickers { guild_id } => (
+                Method::GET,
+                Path::GuildsIdStickers(guild_id),
+                format!("guilds/{}/stickers", guild_id).into(),
+            ),
             Self::GetGuildVanityUrl { guild_id } => (
                 Method::GET,
                 Path::GuildsIdVanityUrl(guild_id),
@@ -1256,6 +1525,30 @@ impl Route {
                 Path::InvitesCode,
                 format!("invites/{}?with-counts={}", code, with_counts).into(),
             ),
+            Self::GetJoinedPrivateArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            } => {
+                let mut path = format!(
+                    "channels/{}/users/@me/threads/archived/private?",
+                    channel_id
+                );
+
+                if let Some(before) = before {
+                    let _ = write!(path, "before={}", before);
+                }
+
+                if let Some(limit) = limit {
+                    let _ = write!(path, "&limit={}", limit);
+                }
+
+                (
+                    Method::GET,
+                    Path::ChannelsIdUsersMeThreadsArchivedPrivate(channel_id),
+                    path.into(),
+                )
+            }
             Self::GetMember { guild_id, user_id } => (
                 Method::GET,
                 Path::GuildsIdMembersId(guild_id),
@@ -1300,11 +1593,54 @@ impl Route {
                     path.into(),
                 )
             }
+            Self::GetNitroStickerPacks => (Method::GET, Path::StickerPacks, "sticker-packs".into()),
             Self::GetPins { channel_id } => (
                 Method::GET,
                 Path::ChannelsIdPins(channel_id),
                 format!("channels/{}/pins", channel_id).into(),
             ),
+            Self::GetPrivateArchivedThreads {
+                ref before,
+                channel_id,
+                limit,
+            } => {
+                let mut path = format!("channels/{}/threads/archived/private?", channel_id);
+
+                if let Some(before) = before {
+                    let _ = write!(path, "before={}", before);
+                }
+
+                if let Some(limit) = limit {
+                    let _ = write!(path, "&limit={}", limit);
+                }
+
+                (
+                    Method::GET,
+                    Path::ChannelsIdThreadsArchivedPrivate(channel_id),
+                    path.into(),
+                )
+            }
+            Self::GetPublicArchivedThreads {
+                ref before,
+                channel_id,
+                limit,
+            } => {
+                let mut path = format!("channels/{}/threads/archived/public?", channel_id);
+
+                if let Some(before) = before {
+                    let _ = write!(path, "before={}", before);
+                }
+
+                if let Some(limit) = limit {
+                    let _ = write!(path, "&limit={}", limit);
+                }
+
+                (
+                    Method::GET,
+                    Path::ChannelsIdThreadsArchivedPublic(channel_id),
+                    path.into(),
+                )
+            }
             Self::GetReactionUsers {
                 after,
                 before,
@@ -1346,6 +1682,21 @@ impl Route {
                 Path::UsersIdChannels,
                 "users/@me/channels".into(),
             ),
+            Self::GetStageInstance { channel_id } => (
+                Method::GET,
+                Path::StageInstancesId(channel_id),
+                format!("stage-instances/{}", channel_id).into(),
+            ),
+            Self::GetSticker { sticker_id } => (
+                Method::GET,
+                Path::StickersId,
+                format!("stickers/{}", sticker_id).into(),
+            ),
+            Self::GetThreadMembers { channel_id } => (
+                Method::GET,
+                Path::ChannelsIdThreadMembers(channel_id),
+                format!("channels/{}/thread-members", channel_id).into(),
+            ),
             Self::GetUser { target_user } => (
                 Method::GET,
                 Path::UsersId,
@@ -1362,11 +1713,33 @@ impl Route {
 
                 (Method::GET, Path::WebhooksId(webhook_id), path.into())
             }
+            Self::InteractionCallback {
+                interaction_id,
+                interaction_token,
+            } => (
+                Method::POST,
+                Path::InteractionsIdTokenCallback(interaction_id),
+                format!(
+                    "interactions/{}/{}/callback",
+                    interaction_id, interaction_token
+                )
+                .into(),
+            ),
+            Self::JoinThread { channel_id } => (
+                Method::PUT,
+                Path::ChannelsIdThreadMembersId(channel_id),
+                format!("channels/{}/thread-members/@me", channel_id).into(),
+            ),
             Self::LeaveGuild { guild_id } => (
                 Method::DELETE,
                 Path::UsersIdGuildsId,
                 format!("users/@me/guilds/{}", guild_id).into(),
             ),
+            Self::LeaveThread { channel_id } => (
+                Method::DELETE,
+                Path::ChannelsIdThreadMembersId(channel_id),
+                format!("channels/{}/thread-members/@me", channel_id).into(),
+            ),
             Self::PinMessage {
                 channel_id,
                 message_id,
@@ -1389,6 +1762,14 @@ impl Route {
                 Path::GuildsIdMembersIdRolesId(guild_id),
                 format!("guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id).into(),
             ),
+            Self::RemoveThreadMember {
+                channel_id,
+                user_id,
+            } => (
+                Method::DELETE,
+                Path::ChannelsIdThreadMembersId(channel_id),
+                format!("channels/{}/thread-members/{}", channel_id, user_id).into(),
+            ),
             Self::SyncGuildIntegration {
                 guild_id,
                 integration_id,
@@ -1405,6 +1786,18 @@ impl Route {
                 Path::ChannelsIdPinsMessageId(channel_id),
                 format!("channels/{}/pins/{}", channel_id, message_id).into(),
             ),
+            Self::UpdateAutoModerationRule {
+                auto_moderation_rule_id,
+                guild_id,
+            } => (
+                Method::PATCH,
+                Path::GuildsIdAutoModerationRulesId(guild_id),
+                format!(
+                    "guilds/{}/auto-moderation/rules/{}",
+                    guild_id, auto_moderation_rule_id
+                )
+                .into(),
+            ),
             Self::UpdateChannel { channel_id } => (
                 Method::PATCH,
                 Path::ChannelsId(channel_id),
@@ -1416,11 +1809,45 @@ impl Route {
                 Path::GuildsIdEmojisId(guild_id),
                 format!("guilds/{}/emojis/{}", guild_id, emoji_id).into(),
             ),
+            Self::UpdateGlobalCommand {
+                application_id,
+                command_id,
+            } => (
+                Method::PATCH,
+                Path::ApplicationsIdCommandsId(application_id),
+                format!("applications/{}/commands/{}", application_id, command_id).into(),
+            ),
+            Self::UpdateFollowupMessage {
+                application_id,
+                interaction_token,
+                message_id,
+            } => (
+                Method::PATCH,
+                Path::WebhooksIdTokenMessagesId(application_id),
+                format!(
+                    "webhooks/{}/{}/messages/{}",
+                    application_id, interaction_token, message_id
+                )
+                .into(),
+            ),
             Self::UpdateGuild { guild_id } => (
                 Method::PATCH,
                 Path::GuildsId(guild_id),
                 format!("guilds/{}", guild_id).into(),
             ),
+            Self::UpdateGuildCommand {
+                application_id,
+                command_id,
+                guild_id,
+            } => (
+                Method::PATCH,
+                Path::ApplicationsIdGuildsIdCommandsId(application_id),
+                format!(
+                    "applications/{}/guilds/{}/commands/{}",
+                    application_id, guild_id, command_id
+                )
+                .into(),
+            ),
             Self::UpdateGuildChannels { guild_id } => (
                 Method::PATCH,
                 Path::GuildsIdChannels(guild_id),
@@ -1439,6 +1866,26 @@ impl Route {
                 Path::GuildsIdIntegrationsId(guild_id),
                 format!("guilds/{}/integrations/{}", guild_id, integration_id,).into(),
             ),
+            Self::UpdateGuildScheduledEvent {
+                guild_id,
+                scheduled_event_id,
+            } => (
+                Method::PATCH,
+                Path::GuildsIdScheduledEventsId(guild_id),
+                format!(
+                    "guilds/{}/scheduled-events/{}",
+                    guild_id, scheduled_event_id
+                )
+                .into(),
+            ),
+            Self::UpdateGuildSticker {
+                guild_id,
+                sticker_id,
+            } => (
+                Method::PATCH,
+                Path::GuildsIdStickersId(guild_id),
+                format!("guilds/{}/stickers/{}", guild_id, sticker_id).into(),
+            ),
             Self::UpdateMember { guild_id, user_id } => (
                 Method::PATCH,
                 Path::GuildsIdMembersId(guild_id),
@@ -1475,6 +1922,11 @@ impl Route {
                 Path::GuildsIdRolesId(guild_id),
                 format!("guilds/{}/roles", guild_id).into(),
             ),
+            Self::UpdateStageInstance { channel_id } => (
+                Method::PATCH,
+                Path::StageInstancesId(channel_id),
+                format!("stage-instances/{}", channel_id).into(),
+            ),
             Self::UpdateWebhook { token, webhook_id } => {
                 let mut path = format!("webhooks/{}", webhook_id);
 
@@ -1488,41 +1940,3 @@ impl Route {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::{Path, PathParseError};
-    use reqwest::Method;
-    use std::{convert::TryFrom, error::Error, str::FromStr};
-
-    #[test]
-    fn test_path_prefix_unimportant() -> Result<(), Box<dyn Error>> {
-        assert_eq!(Path::Guilds, Path::from_str("guilds")?);
-        assert_eq!(Path::Guilds, Path::from_str("/guilds")?);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_path_from_str() -> Result<(), Box<dyn Error>> {
-        assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
-        assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
-        assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_path_message_id() -> Result<(), Box<dyn Error>> {
-        assert_eq!(
-            PathParseError::MessageIdWithoutMethod { channel_id: 123 },
-            Path::from_str("channels/123/messages/456").unwrap_err()
-        );
-        assert_eq!(
-            Path::ChannelsIdMessagesId(Method::GET, 123),
-            Path::try_from((Method::GET, "/channels/123/messages/456"))?,
-        );
-
-        Ok(())
-    }
-}
@@ -0,0 +1,383 @@
+//! # twilight-interactions-derive
+//!
+//! Derive macros for the traits of [`twilight-interactions`], which should be
+//! used through that crate rather than directly.
+//!
+//! [`twilight-interactions`]: https://docs.rs/twilight-interactions
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    ext::IdentExt, parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, GenericArgument,
+    Ident, Lit, LitStr, Meta, NestedMeta, PathArguments, Result, Type,
+};
+
+/// Maximum length of the name of a command or option.
+const NAME_LENGTH_MAX: usize = 32;
+
+/// Maximum length of the description of a command or option.
+const DESCRIPTION_LENGTH_MAX: usize = 100;
+
+/// Derive `CommandModel`, parsing the options of an invoked command into the
+/// fields of a struct.
+#[proc_macro_derive(CommandModel, attributes(command))]
+pub fn command_model(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_command_model(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive `CreateCommand`, creating the definition of a command with an
+/// option for each field of a struct.
+#[proc_macro_derive(CreateCommand, attributes(command))]
+pub fn create_command(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_create_command(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Values of a `#[command(...)]` attribute.
+#[derive(Default)]
+struct CommandAttribute {
+    desc: Option<LitStr>,
+    name: Option<LitStr>,
+    rename: Option<LitStr>,
+}
+
+impl CommandAttribute {
+    /// Parse the `#[command(...)]` attributes of an item, accepting only the
+    /// provided keys.
+    fn parse(attrs: &[Attribute], keys: &[&str]) -> Result<Self> {
+        let mut parsed = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("command")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                other => return Err(Error::new_spanned(other, "expected `#[command(...)]`")),
+            };
+
+            for nested in list.nested {
+                let pair = match nested {
+                    NestedMeta::Meta(Meta::NameValue(pair)) => pair,
+                    other => return Err(Error::new_spanned(other, "expected `key = \"value\"`")),
+                };
+
+                let key = pair
+                    .path
+                    .get_ident()
+                    .map(ToString::to_string)
+                    .filter(|key| keys.contains(&key.as_str()))
+                    .ok_or_else(|| {
+                        Error::new_spanned(
+                            &pair.path,
+                            format!("unknown key, expected one of: {}", keys.join(", ")),
+                        )
+                    })?;
+
+                let value = match pair.lit {
+                    Lit::Str(value) => value,
+                    other => return Err(Error::new_spanned(other, "expected a string")),
+                };
+
+                let slot = match key.as_str() {
+                    "desc" => &mut parsed.desc,
+                    "name" => &mut parsed.name,
+                    _ => &mut parsed.rename,
+                };
+
+                if slot.is_some() {
+                    return Err(Error::new_spanned(&pair.path, "duplicate key"));
+                }
+
+                *slot = Some(value);
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Field of a command struct, which is an option of the command.
+struct CommandField<'a> {
+    desc: Option<LitStr>,
+    ident: &'a Ident,
+    name: LitStr,
+    /// Type of the option's value, which is the inner type of optional
+    /// options.
+    kind: &'a Type,
+    required: bool,
+}
+
+/// Parse the fields of a command struct.
+fn parse_fields(input: &DeriveInput) -> Result<Vec<CommandField<'_>>> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "commands can't have generics",
+        ));
+    }
+
+    let named = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "commands must be structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "commands must be structs with named fields",
+            ))
+        }
+    };
+
+    let mut fields = Vec::with_capacity(named.named.len());
+    let mut optional = false;
+
+    for field in &named.named {
+        let attribute = CommandAttribute::parse(&field.attrs, &["desc", "rename"])?;
+        let ident = field
+            .ident
+            .as_ref()
+            .ok_or_else(|| Error::new_spanned(field, "expected a named field"))?;
+        let name = attribute
+            .rename
+            .unwrap_or_else(|| LitStr::new(&ident.unraw().to_string(), ident.span()));
+
+        validate_name(&name)?;
+
+        if let Some(desc) = &attribute.desc {
+            validate_description(desc)?;
+        }
+
+        let (kind, required) = match option_inner(&field.ty) {
+            Some(inner) => (inner, false),
+            None => (&field.ty, true),
+        };
+
+        if required && optional {
+            return Err(Error::new_spanned(
+                field,
+                "required options must come before optional options",
+            ));
+        }
+
+        optional |= !required;
+
+        fields.push(CommandField {
+            desc: attribute.desc,
+            ident,
+            name,
+            kind,
+            required,
+        });
+    }
+
+    Ok(fields)
+}
+
+/// Inner type of an `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None,
+    };
+
+    let segment = path.segments.last()?;
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first()? {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Validate that a name is 1 to 32 lowercase letters, digits, dashes, or
+/// underscores, as Discord requires.
+fn validate_name(name: &LitStr) -> Result<()> {
+    let value = name.value();
+    let valid = (1..=NAME_LENGTH_MAX).contains(&value.chars().count())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::new_spanned(
+            name,
+            "names must be 1 to 32 lowercase letters, digits, dashes, or underscores",
+        ))
+    }
+}
+
+/// Validate that a description is 1 to 100 characters.
+fn validate_description(desc: &LitStr) -> Result<()> {
+    if (1..=DESCRIPTION_LENGTH_MAX).contains(&desc.value().chars().count()) {
+        Ok(())
+    } else {
+        Err(Error::new_spanned(
+            desc,
+            "descriptions must be 1 to 100 characters",
+        ))
+    }
+}
+
+fn expand_command_model(input: &DeriveInput) -> Result<TokenStream2> {
+    let fields = parse_fields(input)?;
+    let ident = &input.ident;
+
+    let vars = (0..fields.len())
+        .map(|index| Ident::new(&format!("option_{}", index), Span::call_site()))
+        .collect::<Vec<_>>();
+    let declarations = fields.iter().zip(&vars).map(|(field, var)| {
+        let kind = field.kind;
+
+        quote!(let mut #var: ::std::option::Option<#kind> = ::std::option::Option::None;)
+    });
+    let arms = fields.iter().zip(&vars).map(|(field, var)| {
+        let name = &field.name;
+
+        quote! {
+            #name => {
+                #var = ::std::option::Option::Some(
+                    ::twilight_interactions::command::parse_option(option)?,
+                );
+            }
+        }
+    });
+    let assignments = fields.iter().zip(&vars).map(|(field, var)| {
+        let field_ident = field.ident;
+        let name = &field.name;
+
+        if field.required {
+            quote! {
+                #field_ident: #var.ok_or_else(|| {
+                    ::twilight_interactions::command::ParseError::OptionMissing {
+                        name: ::std::borrow::ToOwned::to_owned(#name),
+                    }
+                })?,
+            }
+        } else {
+            quote!(#field_ident: #var,)
+        }
+    });
+
+    Ok(quote! {
+        impl ::twilight_interactions::command::CommandModel for #ident {
+            fn from_options(
+                options: &[::twilight_interactions::__model::application::interaction::CommandDataOption],
+            ) -> ::std::result::Result<Self, ::twilight_interactions::command::ParseError> {
+                #(#declarations)*
+
+                for option in options {
+                    match option.name.as_str() {
+                        #(#arms)*
+                        other => {
+                            return ::std::result::Result::Err(
+                                ::twilight_interactions::command::ParseError::OptionUnknown {
+                                    name: ::std::borrow::ToOwned::to_owned(other),
+                                },
+                            );
+                        }
+                    }
+                }
+
+                ::std::result::Result::Ok(Self {
+                    #(#assignments)*
+                })
+            }
+        }
+    })
+}
+
+fn expand_create_command(input: &DeriveInput) -> Result<TokenStream2> {
+    let attribute = CommandAttribute::parse(&input.attrs, &["desc", "name"])?;
+    let fields = parse_fields(input)?;
+    let ident = &input.ident;
+
+    let name = attribute.name.ok_or_else(|| {
+        Error::new_spanned(
+            ident,
+            "expected a `#[command(name = \"...\", desc = \"...\")]` attribute",
+        )
+    })?;
+    let desc = attribute.desc.ok_or_else(|| {
+        Error::new_spanned(
+            ident,
+            "expected a `#[command(name = \"...\", desc = \"...\")]` attribute",
+        )
+    })?;
+
+    validate_name(&name)?;
+    validate_description(&desc)?;
+
+    let options = fields
+        .iter()
+        .map(|field| {
+            let desc = field.desc.as_ref().ok_or_else(|| {
+                Error::new_spanned(
+                    field.ident,
+                    "expected a `#[command(desc = \"...\")]` attribute",
+                )
+            })?;
+            let kind = field.kind;
+            let name = &field.name;
+            let required = field.required;
+
+            Ok(quote! {
+                ::twilight_interactions::__model::application::command::CommandOption {
+                    choices: ::std::vec::Vec::new(),
+                    description: ::std::borrow::ToOwned::to_owned(#desc),
+                    kind: <#kind as ::twilight_interactions::command::ParseOption>::KIND,
+                    name: ::std::borrow::ToOwned::to_owned(#name),
+                    options: ::std::vec::Vec::new(),
+                    required: #required,
+                }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl ::twilight_interactions::command::CreateCommand for #ident {
+            fn create_command() -> ::twilight_interactions::__model::application::command::Command {
+                ::twilight_interactions::__model::application::command::Command {
+                    application_id: ::std::option::Option::None,
+                    default_permission: ::std::option::Option::None,
+                    description: ::std::borrow::ToOwned::to_owned(#desc),
+                    guild_id: ::std::option::Option::None,
+                    id: ::std::option::Option::None,
+                    name: ::std::borrow::ToOwned::to_owned(#name),
+                    options: ::std::vec![#(#options),*],
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,382 @@
+//! Traits for defining application commands as Rust types and parsing the
+//! data of invoked commands into them.
+//!
+//! The traits are usually implemented via the derive macros of the same
+//! name, which are enabled by the `derive` feature.
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use twilight_interactions_derive::{CommandModel, CreateCommand};
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    application::{
+        command::{Command, CommandOptionType},
+        interaction::{CommandData, CommandDataOption, CommandOptionValue},
+    },
+    id::{ChannelId, RoleId, UserId},
+};
+
+/// Parsing the data of an invoked command failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// A required option wasn't provided.
+    OptionMissing {
+        /// Name of the option.
+        name: String,
+    },
+    /// The value of an option doesn't have the type of its field.
+    OptionTypeInvalid {
+        /// Type of the field.
+        expected: CommandOptionType,
+        /// Name of the option.
+        name: String,
+    },
+    /// An option that the type doesn't have a field for was provided.
+    OptionUnknown {
+        /// Name of the option.
+        name: String,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::OptionMissing { name } => {
+                f.write_fmt(format_args!("required option `{}` is missing", name))
+            }
+            Self::OptionTypeInvalid { expected, name } => f.write_fmt(format_args!(
+                "option `{}` is not of type {}",
+                name,
+                expected.name(),
+            )),
+            Self::OptionUnknown { name } => {
+                f.write_fmt(format_args!("option `{}` is unknown", name))
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Create the definition of a command to register with Discord.
+///
+/// This can be derived for structs with named fields, where each field is an
+/// option whose type implements [`ParseOption`]. Fields of the type
+/// `Option<T>` are optional options, and required options must come before
+/// them.
+///
+/// The struct must have a `#[command(name = "...", desc = "...")]`
+/// attribute, and each field a `#[command(desc = "...")]` attribute. Fields
+/// can be renamed with `rename = "..."`.
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_interactions::command::{CommandModel, CreateCommand};
+/// use twilight_model::id::UserId;
+///
+/// #[derive(CommandModel, CreateCommand)]
+/// #[command(name = "ban", desc = "Ban a user")]
+/// struct Ban {
+///     #[command(desc = "User to ban")]
+///     user: UserId,
+///     #[command(rename = "delete-days", desc = "Days of messages to delete")]
+///     delete_days: Option<i64>,
+/// }
+///
+/// let command = Ban::create_command();
+///
+/// assert_eq!("ban", command.name);
+/// assert_eq!("delete-days", command.options[1].name);
+/// assert!(!command.options[1].required);
+/// ```
+///
+/// [`ParseOption`]: trait.ParseOption.html
+pub trait CreateCommand: Sized {
+    /// Create the definition of the command.
+    fn create_command() -> Command;
+}
+
+/// Parse the data of an invoked command into a type.
+///
+/// This can be derived along with [`CreateCommand`], so that the data of the
+/// command it defines is parsed into the same type.
+///
+/// [`CreateCommand`]: trait.CreateCommand.html
+pub trait CommandModel: Sized {
+    /// Parse the options of an invoked command.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::OptionMissing`] if a required option wasn't
+    /// provided.
+    ///
+    /// Returns [`ParseError::OptionTypeInvalid`] if the value of an option
+    /// doesn't have the type of its field.
+    ///
+    /// Returns [`ParseError::OptionUnknown`] if an unknown option was
+    /// provided.
+    ///
+    /// [`ParseError::OptionMissing`]: enum.ParseError.html#variant.OptionMissing
+    /// [`ParseError::OptionTypeInvalid`]: enum.ParseError.html#variant.OptionTypeInvalid
+    /// [`ParseError::OptionUnknown`]: enum.ParseError.html#variant.OptionUnknown
+    fn from_options(options: &[CommandDataOption]) -> Result<Self, ParseError>;
+
+    /// Parse the data of an invoked command.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_interactions::command::CommandModel;
+    /// use twilight_model::{
+    ///     application::interaction::{CommandData, CommandDataOption, CommandOptionValue},
+    ///     id::CommandId,
+    /// };
+    ///
+    /// #[derive(CommandModel)]
+    /// struct Echo {
+    ///     #[command(desc = "Text to echo")]
+    ///     text: String,
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let data = CommandData {
+    ///     id: CommandId(1),
+    ///     name: "echo".to_owned(),
+    ///     options: vec![CommandDataOption {
+    ///         name: "text".to_owned(),
+    ///         options: Vec::new(),
+    ///         value: Some(CommandOptionValue::String("hello".to_owned())),
+    ///     }],
+    /// };
+    ///
+    /// assert_eq!("hello", Echo::from_interaction_data(&data)?.text);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the options can't be parsed, as with
+    /// [`from_options`].
+    ///
+    /// [`ParseError`]: enum.ParseError.html
+    /// [`from_options`]: #tymethod.from_options
+    fn from_interaction_data(data: &CommandData) -> Result<Self, ParseError> {
+        Self::from_options(&data.options)
+    }
+}
+
+/// Type that can be the value of a command option.
+pub trait ParseOption: Sized {
+    /// Type of the option in the definition of a command.
+    const KIND: CommandOptionType;
+
+    /// Parse the value of an option, returning `None` if it doesn't have the
+    /// type.
+    fn parse_option(value: &CommandOptionValue) -> Option<Self>;
+}
+
+impl ParseOption for bool {
+    const KIND: CommandOptionType = CommandOptionType::Boolean;
+
+    fn parse_option(value: &CommandOptionValue) -> Option<Self> {
+        match value {
+            CommandOptionValue::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl ParseOption for ChannelId {
+    const KIND: CommandOptionType = CommandOptionType::Channel;
+
+    fn parse_option(value: &CommandOptionValue) -> Option<Self> {
+        parse_id(value).map(Self)
+    }
+}
+
+impl ParseOption for i64 {
+    const KIND: CommandOptionType = CommandOptionType::Integer;
+
+    fn parse_option(value: &CommandOptionValue) -> Option<Self> {
+        match value {
+            CommandOptionValue::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl ParseOption for RoleId {
+    const KIND: CommandOptionType = CommandOptionType::Role;
+
+    fn parse_option(value: &CommandOptionValue) -> Option<Self> {
+        parse_id(value).map(Self)
+    }
+}
+
+impl ParseOption for String {
+    const KIND: CommandOptionType = CommandOptionType::String;
+
+    fn parse_option(value: &CommandOptionValue) -> Option<Self> {
+        match value {
+            CommandOptionValue::String(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl ParseOption for UserId {
+    const KIND: CommandOptionType = CommandOptionType::User;
+
+    fn parse_option(value: &CommandOptionValue) -> Option<Self> {
+        parse_id(value).map(Self)
+    }
+}
+
+/// Parse the value of a provided option into a type.
+///
+/// This is used by the derived implementations of [`CommandModel`].
+///
+/// # Errors
+///
+/// Returns [`ParseError::OptionTypeInvalid`] if the option doesn't have a
+/// value of the type.
+///
+/// [`CommandModel`]: trait.CommandModel.html
+/// [`ParseError::OptionTypeInvalid`]: enum.ParseError.html#variant.OptionTypeInvalid
+pub fn parse_option<T: ParseOption>(option: &CommandDataOption) -> Result<T, ParseError> {
+    option
+        .value
+        .as_ref()
+        .and_then(T::parse_option)
+        .ok_or_else(|| ParseError::OptionTypeInvalid {
+            expected: T::KIND,
+            name: option.name.clone(),
+        })
+}
+
+/// Parse an ID, which is provided as a string.
+fn parse_id(value: &CommandOptionValue) -> Option<u64> {
+    match value {
+        CommandOptionValue::String(value) => value.parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandModel, CreateCommand, ParseError, ParseOption};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        application::{
+            command::CommandOptionType,
+            interaction::{CommandDataOption, CommandOptionValue},
+        },
+        id::{ChannelId, RoleId, UserId},
+    };
+
+    assert_fields!(ParseError::OptionMissing: name);
+    assert_fields!(ParseError::OptionTypeInvalid: expected, name);
+    assert_fields!(ParseError::OptionUnknown: name);
+    assert_impl_all!(ParseError: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(bool: ParseOption);
+    assert_impl_all!(ChannelId: ParseOption);
+    assert_impl_all!(i64: ParseOption);
+    assert_impl_all!(RoleId: ParseOption);
+    assert_impl_all!(String: ParseOption);
+    assert_impl_all!(UserId: ParseOption);
+
+    #[derive(CommandModel, CreateCommand, Debug, Eq, PartialEq)]
+    #[command(name = "kick", desc = "Kick a member")]
+    struct Kick {
+        #[command(desc = "Member to kick")]
+        member: UserId,
+        #[command(desc = "Channel to log to")]
+        log: Option<ChannelId>,
+        #[command(rename = "type", desc = "Whether to notify the member")]
+        notify: Option<bool>,
+    }
+
+    fn option(name: &str, value: CommandOptionValue) -> CommandDataOption {
+        CommandDataOption {
+            name: name.to_owned(),
+            options: Vec::new(),
+            value: Some(value),
+        }
+    }
+
+    #[test]
+    fn test_create_command() {
+        let command = Kick::create_command();
+
+        assert_eq!("kick", command.name);
+        assert_eq!("Kick a member", command.description);
+        assert!(command.id.is_none());
+
+        let options = command
+            .options
+            .iter()
+            .map(|option| (option.name.as_str(), option.kind, option.required))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                ("member", CommandOptionType::User, true),
+                ("log", CommandOptionType::Channel, false),
+                ("type", CommandOptionType::Boolean, false),
+            ],
+            options
+        );
+        assert_eq!("Member to kick", command.options[0].description);
+    }
+
+    #[test]
+    fn test_from_options() {
+        let options = &[
+            option("type", CommandOptionValue::Boolean(true)),
+            option("member", CommandOptionValue::String("1".to_owned())),
+        ];
+
+        assert_eq!(
+            Kick {
+                member: UserId(1),
+                log: None,
+                notify: Some(true),
+            },
+            Kick::from_options(options).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_from_options_invalid() {
+        assert_eq!(
+            ParseError::OptionMissing {
+                name: "member".to_owned(),
+            },
+            Kick::from_options(&[]).unwrap_err()
+        );
+        assert_eq!(
+            ParseError::OptionTypeInvalid {
+                expected: CommandOptionType::User,
+                name: "member".to_owned(),
+            },
+            Kick::from_options(&[option("member", CommandOptionValue::Integer(1))]).unwrap_err()
+        );
+        assert_eq!(
+            ParseError::OptionUnknown {
+                name: "reason".to_owned(),
+            },
+            Kick::from_options(&[option(
+                "reason",
+                CommandOptionValue::String("spam".to_owned())
+            )])
+            .unwrap_err()
+        );
+    }
+}
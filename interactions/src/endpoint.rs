@@ -0,0 +1,400 @@
+//! Receive interactions over HTTP, without a gateway connection.
+//!
+//! Discord sends interactions to the interactions endpoint URL of an
+//! application as `POST` requests, signed with the key whose public half is
+//! shown on the application's page of the developer portal. Requests must be
+//! verified before they're handled, and Discord regularly sends requests
+//! with invalid signatures to check that endpoints reject them with a
+//! `401 Unauthorized` response.
+//!
+//! Verified requests are parsed into [`Interaction`]s, and are answered by
+//! responding to the request with a JSON [`Response`]. [`Ping`]s, which
+//! Discord sends when the URL is configured, must be answered with
+//! [`Response::Pong`].
+//!
+//! This is independent of any HTTP server, so it only needs the values of the
+//! [`SIGNATURE_HEADER`] and [`TIMESTAMP_HEADER`] headers and the body of the
+//! request.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use twilight_interactions::endpoint::{self, EndpointError, PublicKey, Response};
+//! use twilight_model::application::{
+//!     callback::InteractionResponseData, interaction::Interaction,
+//! };
+//!
+//! // Returns the status code and body to respond with.
+//! fn handle(key: &PublicKey, signature: &str, timestamp: &str, body: &[u8]) -> (u16, Vec<u8>) {
+//!     let interaction = match endpoint::parse(key, signature, timestamp, body) {
+//!         Ok(interaction) => interaction,
+//!         Err(EndpointError::Deserializing { .. }) => return (400, Vec::new()),
+//!         Err(_) => return (401, Vec::new()),
+//!     };
+//!
+//!     let response = match interaction {
+//!         Interaction::Ping(_) => Response::Pong,
+//!         _ => Response::ChannelMessageWithSource(InteractionResponseData {
+//!             content: Some("Hello!".to_owned()),
+//!             ..InteractionResponseData::default()
+//!         }),
+//!     };
+//!
+//!     (200, serde_json::to_vec(&response).unwrap())
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let key = "<public key>".parse::<PublicKey>()?;
+//! # Ok(()) }
+//! ```
+//!
+//! [`Interaction`]: ../../twilight_model/application/interaction/enum.Interaction.html
+//! [`Ping`]: ../../twilight_model/application/interaction/struct.Ping.html
+//! [`Response`]: type.Response.html
+//! [`Response::Pong`]: ../../twilight_model/application/callback/enum.InteractionResponse.html#variant.Pong
+//! [`SIGNATURE_HEADER`]: constant.SIGNATURE_HEADER.html
+//! [`TIMESTAMP_HEADER`]: constant.TIMESTAMP_HEADER.html
+
+use ring::signature::{UnparsedPublicKey, ED25519};
+use serde_json::Error as JsonError;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+use twilight_model::application::{callback::InteractionResponse, interaction::Interaction};
+
+/// Name of the header containing the hex encoded signature of a request.
+pub const SIGNATURE_HEADER: &str = "x-signature-ed25519";
+
+/// Name of the header containing the timestamp a request was signed at.
+pub const TIMESTAMP_HEADER: &str = "x-signature-timestamp";
+
+/// Length of an Ed25519 public key in bytes.
+const PUBLIC_KEY_LENGTH: usize = 32;
+
+/// Length of an Ed25519 signature in bytes.
+const SIGNATURE_LENGTH: usize = 64;
+
+/// Parsing a public key failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PublicKeyError {
+    /// String isn't a key of 32 bytes encoded as hex.
+    HexInvalid {
+        /// Provided string.
+        hex: String,
+    },
+}
+
+impl Display for PublicKeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::HexInvalid { hex } => f.write_fmt(format_args!(
+                "`{}` is not a key of {} bytes encoded as hex",
+                hex, PUBLIC_KEY_LENGTH
+            )),
+        }
+    }
+}
+
+impl Error for PublicKeyError {}
+
+/// Verifying or parsing a request to the interactions endpoint failed.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum EndpointError {
+    /// The body of the request isn't a valid interaction.
+    Deserializing {
+        /// Reason for the error.
+        source: JsonError,
+    },
+    /// The signature header isn't a signature of 64 bytes encoded as hex.
+    SignatureInvalid,
+    /// The signature doesn't match the timestamp and body of the request, so
+    /// the request must be rejected.
+    SignatureMismatch,
+}
+
+impl Display for EndpointError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Deserializing { .. } => f.write_str("the body is not a valid interaction"),
+            Self::SignatureInvalid => f.write_fmt(format_args!(
+                "the signature is not {} bytes encoded as hex",
+                SIGNATURE_LENGTH
+            )),
+            Self::SignatureMismatch => {
+                f.write_str("the signature does not match the timestamp and body")
+            }
+        }
+    }
+}
+
+impl Error for EndpointError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Deserializing { source } => Some(source),
+            Self::SignatureInvalid | Self::SignatureMismatch => None,
+        }
+    }
+}
+
+/// Ed25519 public key of an application, used to verify the requests sent
+/// to its interactions endpoint.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PublicKey([u8; PUBLIC_KEY_LENGTH]);
+
+impl PublicKey {
+    /// Parse a public key from the hex string shown in the developer portal.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PublicKeyError::HexInvalid`] if the string isn't a key of 32
+    /// bytes encoded as hex.
+    ///
+    /// [`PublicKeyError::HexInvalid`]: enum.PublicKeyError.html#variant.HexInvalid
+    pub fn parse_hex(hex: &str) -> Result<Self, PublicKeyError> {
+        let mut key = [0; PUBLIC_KEY_LENGTH];
+
+        if decode_hex(hex, &mut key) {
+            Ok(Self(key))
+        } else {
+            Err(PublicKeyError::HexInvalid {
+                hex: hex.to_owned(),
+            })
+        }
+    }
+
+    /// Bytes of the key.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; PUBLIC_KEY_LENGTH] {
+        &self.0
+    }
+
+    /// Verify the signature of a request to the interactions endpoint.
+    ///
+    /// The signature and timestamp are the values of the
+    /// [`SIGNATURE_HEADER`] and [`TIMESTAMP_HEADER`] headers.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EndpointError::SignatureInvalid`] if the signature isn't 64
+    /// bytes encoded as hex.
+    ///
+    /// Returns [`EndpointError::SignatureMismatch`] if the signature doesn't
+    /// match the timestamp and body.
+    ///
+    /// [`EndpointError::SignatureInvalid`]: enum.EndpointError.html#variant.SignatureInvalid
+    /// [`EndpointError::SignatureMismatch`]: enum.EndpointError.html#variant.SignatureMismatch
+    /// [`SIGNATURE_HEADER`]: constant.SIGNATURE_HEADER.html
+    /// [`TIMESTAMP_HEADER`]: constant.TIMESTAMP_HEADER.html
+    pub fn verify(
+        &self,
+        signature: &str,
+        timestamp: &str,
+        body: &[u8],
+    ) -> Result<(), EndpointError> {
+        let mut decoded = [0; SIGNATURE_LENGTH];
+
+        if !decode_hex(signature, &mut decoded) {
+            return Err(EndpointError::SignatureInvalid);
+        }
+
+        let mut message = Vec::with_capacity(timestamp.len() + body.len());
+        message.extend_from_slice(timestamp.as_bytes());
+        message.extend_from_slice(body);
+
+        UnparsedPublicKey::new(&ED25519, &self.0)
+            .verify(&message, &decoded)
+            .map_err(|_| EndpointError::SignatureMismatch)
+    }
+}
+
+impl FromStr for PublicKey {
+    type Err = PublicKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_hex(s)
+    }
+}
+
+/// Response to an interaction, sent as the JSON body of the response to the
+/// request.
+///
+/// This is the same type that's sent to the interaction callback route when
+/// responding over HTTP instead.
+pub type Response = InteractionResponse;
+
+/// Verify a request to the interactions endpoint and parse its body into an
+/// interaction.
+///
+/// # Errors
+///
+/// Returns [`EndpointError::SignatureInvalid`] or
+/// [`EndpointError::SignatureMismatch`] if the request couldn't be verified,
+/// as with [`PublicKey::verify`].
+///
+/// Returns [`EndpointError::Deserializing`] if the body isn't a valid
+/// interaction.
+///
+/// [`EndpointError::Deserializing`]: enum.EndpointError.html#variant.Deserializing
+/// [`EndpointError::SignatureInvalid`]: enum.EndpointError.html#variant.SignatureInvalid
+/// [`EndpointError::SignatureMismatch`]: enum.EndpointError.html#variant.SignatureMismatch
+/// [`PublicKey::verify`]: struct.PublicKey.html#method.verify
+pub fn parse(
+    key: &PublicKey,
+    signature: &str,
+    timestamp: &str,
+    body: &[u8],
+) -> Result<Interaction, EndpointError> {
+    key.verify(signature, timestamp, body)?;
+
+    serde_json::from_slice(body).map_err(|source| EndpointError::Deserializing { source })
+}
+
+/// Decode a hex string into a buffer, returning whether it's valid and
+/// exactly fills the buffer.
+fn decode_hex(hex: &str, buf: &mut [u8]) -> bool {
+    let hex = hex.as_bytes();
+
+    if hex.len() != buf.len() * 2 {
+        return false;
+    }
+
+    for (byte, pair) in buf.iter_mut().zip(hex.chunks_exact(2)) {
+        match (hex_value(pair[0]), hex_value(pair[1])) {
+            (Some(high), Some(low)) => *byte = high << 4 | low,
+            _ => return false,
+        }
+    }
+
+    true
+}
+
+/// Value of a hex digit.
+const fn hex_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EndpointError, PublicKey, PublicKeyError, Response};
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{
+        error::Error,
+        fmt::{Debug, Write},
+        hash::Hash,
+        str::FromStr,
+    };
+    use twilight_model::{application::interaction::Interaction, id::InteractionId};
+
+    assert_fields!(EndpointError::Deserializing: source);
+    assert_fields!(PublicKeyError::HexInvalid: hex);
+    assert_impl_all!(EndpointError: Debug, Error, Send, Sync);
+    assert_impl_all!(PublicKey: Clone, Debug, Eq, FromStr, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(PublicKeyError: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(Response: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    const BODY: &[u8] = br#"{"application_id":"1","id":"2","token":"abc","type":1,"version":1}"#;
+    const TIMESTAMP: &str = "1600000000";
+
+    fn key_pair() -> Ed25519KeyPair {
+        Ed25519KeyPair::from_seed_unchecked(&[7; 32]).unwrap()
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+
+            hex
+        })
+    }
+
+    fn sign(timestamp: &str, body: &[u8]) -> String {
+        let mut message = timestamp.as_bytes().to_vec();
+        message.extend_from_slice(body);
+
+        to_hex(key_pair().sign(&message).as_ref())
+    }
+
+    fn public_key() -> PublicKey {
+        to_hex(key_pair().public_key().as_ref()).parse().unwrap()
+    }
+
+    #[test]
+    fn test_public_key() {
+        let hex = to_hex(key_pair().public_key().as_ref());
+
+        assert_eq!(
+            key_pair().public_key().as_ref(),
+            &PublicKey::parse_hex(&hex).unwrap().as_bytes()[..]
+        );
+        assert_eq!(
+            public_key(),
+            PublicKey::parse_hex(&hex.to_uppercase()).unwrap()
+        );
+
+        for hex in &[
+            "",
+            &hex[1..],
+            &hex[2..],
+            &format!("{}00", hex),
+            &hex.replace('a', "g"),
+        ] {
+            assert_eq!(
+                PublicKeyError::HexInvalid {
+                    hex: (*hex).to_owned(),
+                },
+                PublicKey::parse_hex(hex).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let key = public_key();
+        let signature = sign(TIMESTAMP, BODY);
+
+        assert!(key.verify(&signature, TIMESTAMP, BODY).is_ok());
+        assert!(matches!(
+            key.verify(&signature, "1600000001", BODY),
+            Err(EndpointError::SignatureMismatch)
+        ));
+        assert!(matches!(
+            key.verify(&signature, TIMESTAMP, b"{}"),
+            Err(EndpointError::SignatureMismatch)
+        ));
+        assert!(matches!(
+            key.verify(&signature[2..], TIMESTAMP, BODY),
+            Err(EndpointError::SignatureInvalid)
+        ));
+        assert!(matches!(
+            key.verify("not hex", TIMESTAMP, BODY),
+            Err(EndpointError::SignatureInvalid)
+        ));
+    }
+
+    #[test]
+    fn test_parse() {
+        let key = public_key();
+        let interaction = super::parse(&key, &sign(TIMESTAMP, BODY), TIMESTAMP, BODY).unwrap();
+
+        assert!(matches!(
+            &interaction,
+            Interaction::Ping(ping) if ping.id == InteractionId(2) && ping.token == "abc"
+        ));
+
+        let body = b"{}";
+        assert!(matches!(
+            super::parse(&key, &sign(TIMESTAMP, body), TIMESTAMP, body),
+            Err(EndpointError::Deserializing { .. })
+        ));
+    }
+}
@@ -0,0 +1,100 @@
+//! # twilight-interactions
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-interactions` is a crate for the [`twilight-rs`] ecosystem to
+//! define application commands as Rust types.
+//!
+//! Deriving [`CreateCommand`] on a struct generates the definition of a
+//! command to register with Discord, and deriving [`CommandModel`] generates
+//! the parsing of the options of an invoked command into the struct, so the
+//! definition and the parsing can't get out of sync.
+//!
+//! With the `endpoint` feature, the [`endpoint`] module verifies and parses
+//! interactions sent to an HTTP interactions endpoint, so that bots can
+//! receive them without a gateway connection.
+//!
+//! ## Features
+//!
+//! ### `derive`
+//!
+//! Enables the derive macros for [`CommandModel`] and [`CreateCommand`].
+//! This is enabled by default.
+//!
+//! ### `endpoint`
+//!
+//! Enables the [`endpoint`] module, which verifies the signatures of
+//! requests to an interactions endpoint with [`ring`], and enables the
+//! `serde-support` feature of `twilight-model` to parse them.
+//!
+//! ## Examples
+//!
+//! Define a command to echo text, register it, and parse the options when
+//! it's invoked:
+//!
+//! ```rust
+//! use twilight_interactions::command::{CommandModel, CreateCommand};
+//! use twilight_model::application::interaction::ApplicationCommand;
+//!
+//! #[derive(CommandModel, CreateCommand)]
+//! #[command(name = "echo", desc = "Echo text back")]
+//! struct Echo {
+//!     #[command(desc = "Text to echo")]
+//!     text: String,
+//!     #[command(desc = "Number of times to echo the text")]
+//!     times: Option<i64>,
+//! }
+//!
+//! // Send the command's definition when registering commands.
+//! let command = Echo::create_command();
+//! assert_eq!("echo", command.name);
+//!
+//! fn handle(interaction: &ApplicationCommand) -> Option<String> {
+//!     let echo = Echo::from_interaction_data(&interaction.data).ok()?;
+//!     let times = echo.times.unwrap_or(1);
+//!
+//!     Some(echo.text.repeat(times as usize))
+//! }
+//! ```
+//!
+//! [`CommandModel`]: command/trait.CommandModel.html
+//! [`CreateCommand`]: command/trait.CreateCommand.html
+//! [`endpoint`]: endpoint/index.html
+//! [`ring`]: https://crates.io/crates/ring
+//! [`twilight-rs`]: https://github.com/twilight-rs/twilight
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-stable-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    clippy::pedantic,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unsafe_code,
+    unused,
+    warnings
+)]
+#![allow(clippy::module_name_repetitions)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+// Allows the derive macros, which refer to this crate by name, to be used in
+// its own tests.
+#[cfg(test)]
+extern crate self as twilight_interactions;
+
+pub mod command;
+#[cfg(feature = "endpoint")]
+#[cfg_attr(docsrs, doc(cfg(feature = "endpoint")))]
+pub mod endpoint;
+
+/// Re-export of the model crate, used by the code generated by the derive
+/// macros.
+#[doc(hidden)]
+pub use twilight_model as __model;
@@ -179,12 +179,8 @@ async fn play(msg: Message, state: State) -> Result<(), Box<dyn Error + Send + S
     let guild_id = msg.guild_id.unwrap();
 
     let player = state.lavalink.player(guild_id).await.unwrap();
-    let req = twilight_lavalink::http::load_track(
-        player.node().config().address,
-        &msg.content,
-        &player.node().config().authorization,
-    )?
-    .try_into()?;
+    let req =
+        twilight_lavalink::http::load_track(player.node().config(), &msg.content)?.try_into()?;
     let res = state.reqwest.execute(req).await?;
     let loaded = res.json::<LoadedTracks>().await?;
 
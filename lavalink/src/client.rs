@@ -1,12 +1,13 @@
 //! Client to manage nodes and players.
 
 use crate::{
-    model::{IncomingEvent, OutgoingEvent, VoiceUpdate},
-    node::{Node, NodeConfig, NodeError, Resume},
+    model::{IncomingEvent, SlimVoiceServerUpdate, VoiceUpdate},
+    node::{Node, NodeConfig, NodeError, Protocol, Reconnect, Resume},
     player::{Player, PlayerManager},
 };
 use dashmap::{mapref::one::Ref, DashMap};
-use futures_channel::mpsc::{TrySendError, UnboundedReceiver};
+use futures_channel::mpsc::UnboundedReceiver;
+use futures_util::stream::{Stream, StreamExt};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -22,37 +23,64 @@ use twilight_model::{
 };
 
 /// An error that can occur while interacting with the client.
-#[derive(Clone, Debug, PartialEq)]
-#[non_exhaustive]
-pub enum ClientError {
-    /// A node isn't configured, so the operation isn't possible to fulfill.
-    NodesUnconfigured,
-    /// Sending a voice update event to the node failed because the node's
-    /// connection was shutdown.
-    SendingVoiceUpdate {
-        /// The source of the error.
-        source: TrySendError<OutgoingEvent>,
-    },
+#[derive(Debug)]
+pub struct ClientError {
+    kind: ClientErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ClientError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ClientErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ClientErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for ClientError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::NodesUnconfigured => f.write_str("no node has been configured"),
-            Self::SendingVoiceUpdate { .. } => f.write_str("couldn't send voice update to node"),
+        match self.kind {
+            ClientErrorType::NodesUnconfigured => f.write_str("no node has been configured"),
+            ClientErrorType::SendingVoiceUpdate => {
+                f.write_str("couldn't send voice update to node")
+            }
         }
     }
 }
 
 impl Error for ClientError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Self::NodesUnconfigured => None,
-            Self::SendingVoiceUpdate { source } => Some(source),
-        }
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
     }
 }
 
+/// Type of [`ClientError`] that occurred.
+///
+/// [`ClientError`]: struct.ClientError.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ClientErrorType {
+    /// A node isn't configured, so the operation isn't possible to fulfill.
+    NodesUnconfigured,
+    /// Sending a voice update event to the node failed because the node's
+    /// connection was shutdown.
+    SendingVoiceUpdate,
+}
+
 #[derive(Debug)]
 enum VoiceStateHalf {
     Server(VoiceServerUpdate),
@@ -61,7 +89,6 @@ enum VoiceStateHalf {
 
 #[derive(Debug, Default)]
 struct LavalinkRef {
-    guilds: DashMap<GuildId, SocketAddr>,
     nodes: DashMap<SocketAddr, Node>,
     players: PlayerManager,
     resume: Option<Resume>,
@@ -121,7 +148,6 @@ impl Lavalink {
 
     fn _new_with_resume(user_id: UserId, shard_count: u64, resume: Option<Resume>) -> Self {
         Self(Arc::new(LavalinkRef {
-            guilds: DashMap::new(),
             nodes: DashMap::new(),
             players: PlayerManager::new(),
             resume,
@@ -145,12 +171,19 @@ impl Lavalink {
     /// stalled voice states that never received their voice server update half
     /// or vice versa. It is recommended that you process Ready events.
     ///
+    /// A voice server update or voice state update that changes the voice
+    /// connection of a player is forwarded on its own, such as when Discord
+    /// moves the voice connection to another voice server mid-playback.
+    ///
+    /// Refer to [`forward`] for processing a stream of events.
+    ///
     /// # Errors
     ///
-    /// Returns [`ClientError::NodesUnconfigured`] if no nodes have been added
+    /// Returns [`ClientErrorType::NodesUnconfigured`] if no nodes have been added
     /// to the client when attempting to retrieve a guild's player.
     ///
-    /// [`ClientError::NodesUnconfigured`]: enum.ClientError.html#variant.NodesUnconfigured
+    /// [`ClientErrorType::NodesUnconfigured`]: enum.ClientErrorType.html#variant.NodesUnconfigured
+    /// [`forward`]: #method.forward
     /// [crate documentation]: ../index.html#examples
     pub async fn process(&self, event: &Event) -> Result<(), ClientError> {
         tracing::trace!("processing event: {:?}", event);
@@ -191,6 +224,14 @@ impl Lavalink {
             }
         };
 
+        if !self.0.waiting.contains_key(&guild_id) {
+            if let Some(update) = self.voice_update_change(guild_id, &half) {
+                tracing::debug!("voice connection of guild {} changed", guild_id);
+
+                return self.send_voice_update(guild_id, update).await;
+            }
+        }
+
         let update = {
             let existing_half = match self.0.waiting.get(&guild_id) {
                 Some(existing_half) => existing_half,
@@ -252,12 +293,101 @@ impl Lavalink {
         tracing::debug!("removing guild {} from waiting list", guild_id);
         self.0.waiting.remove(&guild_id);
 
+        self.send_voice_update(guild_id, update).await
+    }
+
+    /// Process a stream of events from the gateway into the Lavalink client,
+    /// returning a stream of the same events.
+    ///
+    /// Each event is [processed] before it's returned, so the voice credentials
+    /// of players are forwarded to their nodes without needing to call
+    /// [`process`] in the event loop. Errors from processing events are
+    /// logged.
+    ///
+    /// # Examples
+    ///
+    /// Forward the voice events of a shard:
+    ///
+    /// ```no_run
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_gateway::{Intents, Shard};
+    /// use twilight_lavalink::Lavalink;
+    /// # use twilight_model::id::UserId;
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let user_id = UserId(1);
+    ///
+    /// let lavalink = Lavalink::new(user_id, 1);
+    /// let mut shard = Shard::new("token", Intents::GUILD_VOICE_STATES);
+    /// shard.start().await?;
+    ///
+    /// let mut events = Box::pin(lavalink.forward(shard.events()));
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     println!("got event: {:?}", event.kind());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`process`]: #method.process
+    /// [processed]: #method.process
+    pub fn forward<S: Stream<Item = Event>>(&self, events: S) -> impl Stream<Item = Event> {
+        let lavalink = self.clone();
+
+        events.then(move |event| {
+            let lavalink = lavalink.clone();
+
+            async move {
+                if let Err(source) = lavalink.process(&event).await {
+                    tracing::warn!("failed to process event: {}", source);
+                }
+
+                event
+            }
+        })
+    }
+
+    /// Return the voice update for a half that changes the existing voice
+    /// connection of a player, if it does.
+    fn voice_update_change(&self, guild_id: GuildId, half: &VoiceStateHalf) -> Option<VoiceUpdate> {
+        let current = self.players().get(&guild_id)?.voice_update()?;
+
+        match half {
+            VoiceStateHalf::Server(server) => {
+                let event = SlimVoiceServerUpdate::from(server.clone());
+
+                if event == current.event {
+                    return None;
+                }
+
+                Some(VoiceUpdate::new(guild_id, current.session_id, event))
+            }
+            VoiceStateHalf::State(state) => {
+                if state.0.session_id == current.session_id {
+                    return None;
+                }
+
+                Some(VoiceUpdate::new(
+                    guild_id,
+                    &state.0.session_id,
+                    current.event,
+                ))
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "debug", fields(guild_id = %guild_id), skip(self, guild_id))]
+    async fn send_voice_update(
+        &self,
+        guild_id: GuildId,
+        update: VoiceUpdate,
+    ) -> Result<(), ClientError> {
         tracing::debug!("getting player for guild {}", guild_id);
         let player = self.player(guild_id).await?;
         tracing::debug!("sending voice update for guild {}: {:?}", guild_id, update);
-        player
-            .send(update)
-            .map_err(|source| ClientError::SendingVoiceUpdate { source })?;
+        player.send(update).map_err(|source| ClientError {
+            kind: ClientErrorType::SendingVoiceUpdate,
+            source: Some(Box::new(source)),
+        })?;
         tracing::debug!("sent voice update for guild {}", guild_id);
 
         Ok(())
@@ -267,25 +397,177 @@ impl Lavalink {
     ///
     /// If a node already exists with the provided address, then it will be
     /// replaced.
+    ///
+    /// The node is expected to use version 3 of the Lavalink protocol. Use
+    /// [`add_with_protocol`] for nodes using another version.
+    ///
+    /// [`add_with_protocol`]: #method.add_with_protocol
     pub async fn add(
         &self,
         address: SocketAddr,
         authorization: impl Into<String>,
     ) -> Result<(Node, UnboundedReceiver<IncomingEvent>), NodeError> {
-        let config = NodeConfig {
+        self.add_with_protocol(address, authorization, Protocol::V3)
+            .await
+    }
+
+    /// Like [`add`], but allows you to specify the version of the Lavalink
+    /// protocol that the node uses.
+    ///
+    /// [`add`]: #method.add
+    pub async fn add_with_protocol(
+        &self,
+        address: SocketAddr,
+        authorization: impl Into<String>,
+        protocol: Protocol,
+    ) -> Result<(Node, UnboundedReceiver<IncomingEvent>), NodeError> {
+        let mut config = self.node_config(address, authorization);
+        config.protocol = protocol;
+
+        self.add_with_config(config).await
+    }
+
+    /// Like [`add`], but allows you to specify how the node reconnects when
+    /// its connection drops.
+    ///
+    /// If [`Reconnect::migrate_players`] is enabled, then the node's players
+    /// are [migrated] to the other nodes once it gives up reconnecting.
+    ///
+    /// [`Reconnect::migrate_players`]: ../node/struct.Reconnect.html#structfield.migrate_players
+    /// [`add`]: #method.add
+    /// [migrated]: #method.migrate
+    pub async fn add_with_reconnect(
+        &self,
+        address: SocketAddr,
+        authorization: impl Into<String>,
+        protocol: Protocol,
+        reconnect: Reconnect,
+    ) -> Result<(Node, UnboundedReceiver<IncomingEvent>), NodeError> {
+        let mut config = self.node_config(address, authorization);
+        config.protocol = protocol;
+        config.reconnect = reconnect;
+
+        self.add_with_config(config).await
+    }
+
+    /// Create the configuration of a node with the client's user ID, shard
+    /// count, and resume capability, to be added via [`add_with_config`].
+    ///
+    /// [`add_with_config`]: #method.add_with_config
+    pub fn node_config(&self, address: SocketAddr, authorization: impl Into<String>) -> NodeConfig {
+        NodeConfig::new(
+            self.0.user_id,
+            self.0.shard_count,
             address,
-            authorization: authorization.into(),
-            resume: self.0.resume.clone(),
-            shard_count: self.0.shard_count,
-            user_id: self.0.user_id,
-        };
+            authorization,
+            self.0.resume.clone(),
+        )
+    }
 
+    /// Like [`add`], but with the full configuration of the node, such as to
+    /// connect to it over TLS.
+    ///
+    /// Use [`node_config`] to create a configuration with the client's
+    /// details.
+    ///
+    /// # Examples
+    ///
+    /// Add a node of a hosted provider over TLS:
+    ///
+    /// ```no_run
+    /// use std::net::SocketAddr;
+    /// use twilight_lavalink::{node::Tls, Lavalink};
+    /// # use twilight_model::id::UserId;
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let user_id = UserId(1);
+    ///
+    /// let lavalink = Lavalink::new(user_id, 1);
+    ///
+    /// let address = SocketAddr::from(([203, 0, 113, 1], 443));
+    /// let mut config = lavalink.node_config(address, "password");
+    /// config.tls.replace(Tls::new("lavalink.example.com"));
+    ///
+    /// lavalink.add_with_config(config).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`add`]: #method.add
+    /// [`node_config`]: #method.node_config
+    pub async fn add_with_config(
+        &self,
+        config: NodeConfig,
+    ) -> Result<(Node, UnboundedReceiver<IncomingEvent>), NodeError> {
+        let address = config.address;
         let (node, rx) = Node::connect(config, self.0.players.clone()).await?;
         self.0.nodes.insert(address, node.clone());
 
+        if node.config().reconnect.migrate_players {
+            // Only hold a weak reference so that the client can be dropped
+            // while the node is still up.
+            let closed = node.closed();
+            let lavalink = Arc::downgrade(&self.0);
+
+            tokio::spawn(async move {
+                closed.await;
+
+                let lavalink = match lavalink.upgrade() {
+                    Some(lavalink) => Self(lavalink),
+                    None => return,
+                };
+
+                // The node may have been replaced by a new one at the same
+                // address in the meantime.
+                if matches!(lavalink.node(address), Some(node) if node.is_connected()) {
+                    return;
+                }
+
+                if let Err(source) = lavalink.migrate(address).await {
+                    tracing::warn!("failed to migrate players of node {}: {}", address, source);
+                }
+            });
+        }
+
         Ok((node, rx))
     }
 
+    /// Retrieve a node managed by the Lavalink client by its address.
+    ///
+    /// This can be used to read the node's [stats].
+    ///
+    /// [stats]: ../node/struct.Node.html#method.stats
+    pub fn node(&self, address: SocketAddr) -> Option<Node> {
+        self.0.nodes.get(&address).map(|node| node.value().clone())
+    }
+
+    /// Retrieve all of the nodes managed by the Lavalink client.
+    ///
+    /// # Examples
+    ///
+    /// Print the number of playing players of each node:
+    ///
+    /// ```no_run
+    /// use twilight_lavalink::Lavalink;
+    /// # use twilight_model::id::UserId;
+    /// # #[tokio::main] async fn main() {
+    /// # let user_id = UserId(1);
+    ///
+    /// let lavalink = Lavalink::new(user_id, 10);
+    ///
+    /// for node in lavalink.nodes() {
+    ///     let stats = node.stats().await;
+    ///
+    ///     println!("{}: {} playing", node.config().address, stats.playing_players);
+    /// }
+    /// # }
+    /// ```
+    pub fn nodes(&self) -> Vec<Node> {
+        self.0
+            .nodes
+            .iter()
+            .map(|node| node.value().clone())
+            .collect()
+    }
+
     /// Remove a node from the list of nodes being managed by the Lavalink
     /// client.
     ///
@@ -294,23 +576,97 @@ impl Lavalink {
         self.0.nodes.remove(&address)
     }
 
+    /// Move the players of a node to the best of the other nodes, removing the
+    /// node from the client.
+    ///
+    /// Each player's voice connection and play state, such as its track,
+    /// position, and volume, are sent to its new node so that it continues
+    /// playing where it left off.
+    ///
+    /// This is done automatically for nodes that give up reconnecting if they
+    /// were added with [`Reconnect::migrate_players`] enabled. It can also be
+    /// used to drain a node before shutting it down.
+    ///
+    /// Returns the number of players that were migrated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ClientErrorType::NodesUnconfigured`] if the node has players and
+    /// there are no other connected nodes to migrate them to.
+    ///
+    /// [`ClientErrorType::NodesUnconfigured`]: enum.ClientErrorType.html#variant.NodesUnconfigured
+    /// [`Reconnect::migrate_players`]: ../node/struct.Reconnect.html#structfield.migrate_players
+    pub async fn migrate(&self, address: SocketAddr) -> Result<usize, ClientError> {
+        self.0.nodes.remove(&address);
+
+        let guild_ids = self
+            .players()
+            .players
+            .iter()
+            .filter(|player| player.node().config().address == address)
+            .map(|player| *player.key())
+            .collect::<Vec<_>>();
+
+        if guild_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let node = self.best().await?;
+        let mut migrated = 0;
+
+        for guild_id in guild_ids {
+            let mut player = match self.players().get_mut(&guild_id) {
+                Some(player) => player,
+                None => continue,
+            };
+
+            player.set_node(node.clone());
+
+            for event in player.replay_events() {
+                if let Err(source) = node.send(event) {
+                    tracing::warn!(
+                        "failed to migrate player for guild {}: {:?}",
+                        guild_id,
+                        source
+                    );
+                }
+            }
+
+            migrated += 1;
+        }
+
+        tracing::debug!(
+            "migrated {} players from node {} to node {}",
+            migrated,
+            address,
+            node.config().address,
+        );
+
+        Ok(migrated)
+    }
+
     /// Determine the "best" node for new players according to available nodes'
     /// penalty scores.
     ///
-    /// Refer to [`Node::penalty`] for how this is calculated.
+    /// Nodes that are reconnecting are skipped. Refer to [`Node::penalty`] for
+    /// how this is calculated.
     ///
     /// # Errors
     ///
-    /// Returns [`ClientError::NodesUnconfigured`] if there are no configured
+    /// Returns [`ClientErrorType::NodesUnconfigured`] if there are no configured
     /// nodes available in the client.
     ///
-    /// [`ClientError::NodesUnconfigured`]: enum.ClientError.html#variant.NodesUnconfigured
+    /// [`ClientErrorType::NodesUnconfigured`]: enum.ClientErrorType.html#variant.NodesUnconfigured
     /// [`Node::penalty`]: ../node/struct.Node.html#method.penalty
     pub async fn best(&self) -> Result<Node, ClientError> {
         let mut lowest = i32::MAX;
         let mut best = None;
 
         for node in self.0.nodes.iter() {
+            if !node.value().is_connected() {
+                continue;
+            }
+
             let penalty = node.value().penalty().await;
 
             if penalty < lowest {
@@ -319,7 +675,10 @@ impl Lavalink {
             }
         }
 
-        best.ok_or(ClientError::NodesUnconfigured)
+        best.ok_or(ClientError {
+            kind: ClientErrorType::NodesUnconfigured,
+            source: None,
+        })
     }
 
     /// Retrieve an immutable reference to the player manager.
@@ -335,10 +694,10 @@ impl Lavalink {
     ///
     /// # Errors
     ///
-    /// Returns [`ClientError::NodesUnconfigured`] if no node has been
+    /// Returns [`ClientErrorType::NodesUnconfigured`] if no node has been
     /// configured via [`add`].
     ///
-    /// [`ClientError::NodesUnconfigured`]: enum.ClientError.html#variant.NodesUnconfigured
+    /// [`ClientErrorType::NodesUnconfigured`]: enum.ClientErrorType.html#variant.NodesUnconfigured
     /// [`PlayerManager::get`]: ../player/struct.PlayerManager.html#method.get
     /// [`add`]: #method.add
     pub async fn player(&self, guild_id: GuildId) -> Result<Ref<'_, GuildId, Player>, ClientError> {
@@ -374,12 +733,12 @@ impl Lavalink {
 
 #[cfg(test)]
 mod tests {
-    use super::{ClientError, Lavalink, VoiceStateHalf};
-    use static_assertions::{assert_fields, assert_impl_all};
+    use super::{ClientError, ClientErrorType, Lavalink, VoiceStateHalf};
+    use static_assertions::assert_impl_all;
     use std::{error::Error, fmt::Debug};
 
-    assert_fields!(ClientError::SendingVoiceUpdate: source);
-    assert_impl_all!(ClientError: Clone, Debug, Error, PartialEq, Send, Sync);
+    assert_impl_all!(ClientErrorType: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ClientError: Debug, Error, Send, Sync);
     assert_impl_all!(Lavalink: Clone, Debug, Send, Sync);
     assert_impl_all!(VoiceStateHalf: Debug, Send, Sync);
 }
@@ -1,13 +1,14 @@
 //! Models to deserialize responses into and functions to create `http` crate
 //! requests.
 
+use crate::node::{self, NodeConfig};
 use http::{
-    header::{HeaderValue, AUTHORIZATION},
+    header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE},
     Error as HttpError, Request,
 };
 use percent_encoding::NON_ALPHANUMERIC;
 use serde::{Deserialize, Serialize};
-use std::net::{IpAddr, SocketAddr};
+use std::net::IpAddr;
 
 /// The type of search result given.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -74,10 +75,20 @@ pub struct PlaylistInfo {
 }
 
 /// Possible track results for a query.
+///
+/// This is the raw response body; [`LoadResult`] is the typed version of it.
+///
+/// [`LoadResult`]: enum.LoadResult.html
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
 #[serde(rename_all = "camelCase")]
 pub struct LoadedTracks {
+    /// The reason that loading the tracks failed, if the load type is
+    /// [`LoadType::LoadFailed`].
+    ///
+    /// [`LoadType::LoadFailed`]: enum.LoadType.html#variant.LoadFailed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exception: Option<LoadException>,
     /// The type of search result, such as a list of tracks or a playlist.
     pub load_type: LoadType,
     /// Information about the playlist, if provided.
@@ -86,6 +97,141 @@ pub struct LoadedTracks {
     pub tracks: Vec<Track>,
 }
 
+/// The reason that loading tracks failed.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct LoadException {
+    /// The message of the exception, if any.
+    pub message: Option<String>,
+    /// How severe the exception is.
+    pub severity: Severity,
+}
+
+/// How severe an exception is.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Severity {
+    /// The cause is known and expected, such as a track being unavailable.
+    Common,
+    /// The cause might not be exactly known, but is possibly caused by
+    /// outside factors.
+    Suspicious,
+    /// The cause is unknown, possibly being a bug in Lavalink.
+    Fault,
+}
+
+/// The typed result of loading tracks.
+///
+/// This can be deserialized from the response body of [`load_track`], and is
+/// converted from and into [`LoadedTracks`].
+///
+/// # Examples
+///
+/// ```
+/// use twilight_lavalink::http::LoadResult;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let body = br#"{"loadType":"NO_MATCHES","playlistInfo":{},"tracks":[]}"#;
+/// let result = serde_json::from_slice::<LoadResult>(body)?;
+///
+/// assert_eq!(LoadResult::NoMatches, result);
+/// # Ok(()) }
+/// ```
+///
+/// [`LoadedTracks`]: struct.LoadedTracks.html
+/// [`load_track`]: fn.load_track.html
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(from = "LoadedTracks", into = "LoadedTracks")]
+pub enum LoadResult {
+    /// Loading the tracks failed.
+    LoadFailed(LoadException),
+    /// There were no matches.
+    NoMatches,
+    /// A playlist was found.
+    PlaylistLoaded {
+        /// The name of the playlist, if available.
+        name: Option<String>,
+        /// The index of the selected track within the tracks, if one was
+        /// selected.
+        selected_track: Option<u64>,
+        /// The tracks of the playlist.
+        tracks: Vec<Track>,
+    },
+    /// Search results were found.
+    SearchResult(Vec<Track>),
+    /// A single track was found.
+    TrackLoaded(Track),
+}
+
+impl From<LoadedTracks> for LoadResult {
+    fn from(loaded: LoadedTracks) -> Self {
+        match loaded.load_type {
+            LoadType::LoadFailed => Self::LoadFailed(loaded.exception.unwrap_or(LoadException {
+                message: None,
+                severity: Severity::Fault,
+            })),
+            LoadType::NoMatches => Self::NoMatches,
+            LoadType::PlaylistLoaded => Self::PlaylistLoaded {
+                name: loaded.playlist_info.name,
+                selected_track: loaded.playlist_info.selected_track,
+                tracks: loaded.tracks,
+            },
+            LoadType::SearchResult => Self::SearchResult(loaded.tracks),
+            LoadType::TrackLoaded => match loaded.tracks.into_iter().next() {
+                Some(track) => Self::TrackLoaded(track),
+                None => Self::NoMatches,
+            },
+        }
+    }
+}
+
+impl From<LoadResult> for LoadedTracks {
+    fn from(result: LoadResult) -> Self {
+        let mut loaded = Self {
+            exception: None,
+            load_type: LoadType::NoMatches,
+            playlist_info: PlaylistInfo {
+                name: None,
+                selected_track: None,
+            },
+            tracks: Vec::new(),
+        };
+
+        match result {
+            LoadResult::LoadFailed(exception) => {
+                loaded.exception.replace(exception);
+                loaded.load_type = LoadType::LoadFailed;
+            }
+            LoadResult::NoMatches => {}
+            LoadResult::PlaylistLoaded {
+                name,
+                selected_track,
+                tracks,
+            } => {
+                loaded.load_type = LoadType::PlaylistLoaded;
+                loaded.playlist_info = PlaylistInfo {
+                    name,
+                    selected_track,
+                };
+                loaded.tracks = tracks;
+            }
+            LoadResult::SearchResult(tracks) => {
+                loaded.load_type = LoadType::SearchResult;
+                loaded.tracks = tracks;
+            }
+            LoadResult::TrackLoaded(track) => {
+                loaded.load_type = LoadType::TrackLoaded;
+                loaded.tracks.push(track);
+            }
+        }
+
+        loaded
+    }
+}
+
 /// A failing IP address within the planner.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[non_exhaustive]
@@ -240,39 +386,93 @@ pub struct RotatingNanoIpDetails {
 /// Get a list of tracks that match an identifier.
 ///
 /// The response will include a body which can be deserialized into a
-/// [`LoadedTracks`].
+/// [`LoadResult`], or a [`LoadedTracks`] for the raw response.
 ///
+/// [`LoadResult`]: enum.LoadResult.html
 /// [`LoadedTracks`]: struct.LoadedTracks.html
 pub fn load_track(
-    address: SocketAddr,
+    node: &NodeConfig,
     identifier: impl AsRef<str>,
-    authorization: impl AsRef<str>,
 ) -> Result<Request<&'static [u8]>, HttpError> {
     let identifier =
         percent_encoding::percent_encode(identifier.as_ref().as_bytes(), NON_ALPHANUMERIC);
-    let url = format!("http://{}/loadtracks?identifier={}", address, identifier);
+    let url = format!(
+        "{}/loadtracks?identifier={}",
+        node::base_url(node, false),
+        identifier,
+    );
 
     let mut req = Request::get(url);
 
-    let auth_value = HeaderValue::from_str(authorization.as_ref())?;
+    let auth_value = HeaderValue::from_str(&node.authorization)?;
     req = req.header(AUTHORIZATION, auth_value);
 
     req.body(b"")
 }
 
-/// Get the configured route planner for a node by address.
+/// Decode a base64 track string into the details of the track.
 ///
 /// The response will include a body which can be deserialized into a
-/// [`RoutePlanner`].
+/// [`TrackInfo`].
 ///
-/// [`RoutePlanner`]: enum.RoutePlanner.html
-pub fn get_route_planner(
-    address: SocketAddr,
-    authorization: impl AsRef<str>,
+/// [`TrackInfo`]: struct.TrackInfo.html
+pub fn decode_track(
+    node: &NodeConfig,
+    track: impl AsRef<str>,
 ) -> Result<Request<&'static [u8]>, HttpError> {
-    let mut req = Request::get(format!("{}/routeplanner/status", address));
+    let track = percent_encoding::percent_encode(track.as_ref().as_bytes(), NON_ALPHANUMERIC);
+    let url = format!(
+        "{}/decodetrack?track={}",
+        node::base_url(node, false),
+        track
+    );
+
+    let mut req = Request::get(url);
+
+    let auth_value = HeaderValue::from_str(&node.authorization)?;
+    req = req.header(AUTHORIZATION, auth_value);
+
+    req.body(b"")
+}
+
+/// Decode multiple base64 track strings into tracks with their details.
+///
+/// The response will include a body which can be deserialized into a list of
+/// [`Track`]s, in the same order as the track strings.
+///
+/// [`Track`]: struct.Track.html
+pub fn decode_tracks<T: AsRef<str>>(
+    node: &NodeConfig,
+    tracks: impl IntoIterator<Item = T>,
+) -> Result<Request<Vec<u8>>, HttpError> {
+    let tracks = tracks
+        .into_iter()
+        .map(|track| track.as_ref().to_owned())
+        .collect::<Vec<_>>();
+
+    let mut req = Request::post(format!("{}/decodetracks", node::base_url(node, false)));
+
+    let auth_value = HeaderValue::from_str(&node.authorization)?;
+    req = req.header(AUTHORIZATION, auth_value);
+    req = req.header(CONTENT_TYPE, "application/json");
+
+    req.body(serde_json::to_vec(&tracks).unwrap())
+}
+
+/// Get the configured route planner for a node.
+///
+/// The response will include a body which can be deserialized into a
+/// [`RoutePlanner`]. If the node doesn't have a route planner configured,
+/// then the response has a status code of 204 and no body.
+///
+/// [`RoutePlanner`]: enum.RoutePlanner.html
+pub fn get_route_planner(node: &NodeConfig) -> Result<Request<&'static [u8]>, HttpError> {
+    let mut req = Request::get(format!(
+        "{}/routeplanner/status",
+        node::base_url(node, false),
+    ));
 
-    let auth_value = HeaderValue::from_str(authorization.as_ref())?;
+    let auth_value = HeaderValue::from_str(&node.authorization)?;
     req = req.header(AUTHORIZATION, auth_value);
 
     req.body(b"")
@@ -282,14 +482,17 @@ pub fn get_route_planner(
 ///
 /// The response will not include a body on success.
 pub fn unmark_failed_address(
-    node_address: impl Into<SocketAddr>,
-    authorization: impl AsRef<str>,
+    node: &NodeConfig,
     route_address: impl Into<IpAddr>,
 ) -> Result<Request<Vec<u8>>, HttpError> {
-    let mut req = Request::post(format!("{}/routeplanner/status", node_address.into()));
+    let mut req = Request::post(format!(
+        "{}/routeplanner/free/address",
+        node::base_url(node, false),
+    ));
 
-    let auth_value = HeaderValue::from_str(authorization.as_ref())?;
+    let auth_value = HeaderValue::from_str(&node.authorization)?;
     req = req.header(AUTHORIZATION, auth_value);
+    req = req.header(CONTENT_TYPE, "application/json");
 
     req.body(
         serde_json::to_vec(&serde_json::json!({
@@ -299,17 +502,39 @@ pub fn unmark_failed_address(
     )
 }
 
+/// Unmark all of the failed IP addresses of the route planner, meaning that
+/// they can all be used again.
+///
+/// The response will not include a body on success.
+pub fn unmark_all_failed_addresses(node: &NodeConfig) -> Result<Request<&'static [u8]>, HttpError> {
+    let mut req = Request::post(format!(
+        "{}/routeplanner/free/all",
+        node::base_url(node, false),
+    ));
+
+    let auth_value = HeaderValue::from_str(&node.authorization)?;
+    req = req.header(AUTHORIZATION, auth_value);
+
+    req.body(b"")
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        FailingAddress, IpBlock, IpBlockType, LoadType, LoadedTracks, NanoIpDetails,
-        NanoIpRoutePlanner, PlaylistInfo, RotatingIpDetails, RotatingIpRoutePlanner,
-        RotatingNanoIpDetails, RotatingNanoIpRoutePlanner, RoutePlanner, RoutePlannerType, Track,
-        TrackInfo,
+        FailingAddress, IpBlock, IpBlockType, LoadException, LoadResult, LoadType, LoadedTracks,
+        NanoIpDetails, NanoIpRoutePlanner, PlaylistInfo, RotatingIpDetails, RotatingIpRoutePlanner,
+        RotatingNanoIpDetails, RotatingNanoIpRoutePlanner, RoutePlanner, RoutePlannerType,
+        Severity, Track, TrackInfo,
     };
+    use crate::node::{NodeConfig, Tls};
+    use http::Method;
     use serde::{Deserialize, Serialize};
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::fmt::Debug;
+    use std::{
+        fmt::Debug,
+        net::{IpAddr, SocketAddr},
+    };
+    use twilight_model::id::UserId;
 
     assert_fields!(FailingAddress: address, failing_timestamp, failing_time);
     assert_impl_all!(
@@ -343,6 +568,28 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(LoadException: message, severity);
+    assert_impl_all!(
+        LoadException: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_impl_all!(
+        LoadResult: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        From<LoadedTracks>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_impl_all!(
         LoadType: Clone,
         Debug,
@@ -353,7 +600,7 @@ mod tests {
         Serialize,
         Sync,
     );
-    assert_fields!(LoadedTracks: load_type, playlist_info, tracks);
+    assert_fields!(LoadedTracks: exception, load_type, playlist_info, tracks);
     assert_impl_all!(
         LoadedTracks: Clone,
         Debug,
@@ -456,6 +703,17 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_impl_all!(
+        Severity: Clone,
+        Copy,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_impl_all!(
         RoutePlannerType: Clone,
         Debug,
@@ -507,4 +765,155 @@ mod tests {
         Serialize,
         Sync
     );
+
+    fn track(name: &str) -> Track {
+        Track {
+            info: TrackInfo {
+                author: None,
+                identifier: name.to_owned(),
+                is_seekable: true,
+                is_stream: false,
+                length: 1000,
+                position: 0,
+                title: None,
+                uri: name.to_owned(),
+            },
+            track: name.to_owned(),
+        }
+    }
+
+    fn track_json(name: &str) -> serde_json::Value {
+        serde_json::json!({
+            "info": {
+                "author": null,
+                "identifier": name,
+                "isSeekable": true,
+                "isStream": false,
+                "length": 1000,
+                "position": 0,
+                "title": null,
+                "uri": name,
+            },
+            "track": name,
+        })
+    }
+
+    #[test]
+    fn test_load_result_track_loaded() {
+        let body = serde_json::json!({
+            "loadType": "TRACK_LOADED",
+            "playlistInfo": {},
+            "tracks": [track_json("a")],
+        });
+        let result = serde_json::from_value::<LoadResult>(body).unwrap();
+
+        assert_eq!(LoadResult::TrackLoaded(track("a")), result);
+    }
+
+    #[test]
+    fn test_load_result_playlist_loaded() {
+        let body = serde_json::json!({
+            "loadType": "PLAYLIST_LOADED",
+            "playlistInfo": { "name": "list", "selectedTrack": 1 },
+            "tracks": [track_json("a"), track_json("b")],
+        });
+        let result = serde_json::from_value::<LoadResult>(body).unwrap();
+
+        assert_eq!(
+            LoadResult::PlaylistLoaded {
+                name: Some("list".to_owned()),
+                selected_track: Some(1),
+                tracks: vec![track("a"), track("b")],
+            },
+            result,
+        );
+    }
+
+    #[test]
+    fn test_load_result_load_failed() {
+        let body = serde_json::json!({
+            "loadType": "LOAD_FAILED",
+            "playlistInfo": {},
+            "tracks": [],
+            "exception": { "message": "unavailable", "severity": "COMMON" },
+        });
+        let result = serde_json::from_value::<LoadResult>(body).unwrap();
+
+        assert_eq!(
+            LoadResult::LoadFailed(LoadException {
+                message: Some("unavailable".to_owned()),
+                severity: Severity::Common,
+            }),
+            result,
+        );
+    }
+
+    #[test]
+    fn test_load_result_round_trip() {
+        let result = LoadResult::SearchResult(vec![track("a")]);
+        let value = serde_json::to_value(&result).unwrap();
+
+        assert_eq!("SEARCH_RESULT", value["loadType"]);
+        assert_eq!(result, serde_json::from_value::<LoadResult>(value).unwrap());
+    }
+
+    fn node() -> NodeConfig {
+        let address = SocketAddr::from(([127, 0, 0, 1], 2333));
+
+        NodeConfig::new(UserId(1), 1, address, "pass", None)
+    }
+
+    #[test]
+    fn test_decode_requests() {
+        let node = node();
+
+        let req = super::decode_track(&node, "QAAA/w==").unwrap();
+        assert_eq!(Method::GET, req.method());
+        assert_eq!(
+            "http://127.0.0.1:2333/decodetrack?track=QAAA%2Fw%3D%3D",
+            req.uri()
+        );
+        assert_eq!("pass", req.headers()["Authorization"]);
+
+        let req = super::decode_tracks(&node, vec!["a", "b"]).unwrap();
+        assert_eq!(Method::POST, req.method());
+        assert_eq!("http://127.0.0.1:2333/decodetracks", req.uri());
+        assert_eq!(br#"["a","b"]"#, req.body().as_slice());
+    }
+
+    #[test]
+    fn test_route_planner_requests() {
+        let node = node();
+
+        let req = super::get_route_planner(&node).unwrap();
+        assert_eq!("http://127.0.0.1:2333/routeplanner/status", req.uri());
+
+        let req = super::unmark_failed_address(&node, IpAddr::from([10, 0, 0, 1])).unwrap();
+        assert_eq!(Method::POST, req.method());
+        assert_eq!("http://127.0.0.1:2333/routeplanner/free/address", req.uri());
+        assert_eq!(br#"{"address":"10.0.0.1"}"#, req.body().as_slice());
+
+        let req = super::unmark_all_failed_addresses(&node).unwrap();
+        assert_eq!(Method::POST, req.method());
+        assert_eq!("http://127.0.0.1:2333/routeplanner/free/all", req.uri());
+    }
+
+    #[test]
+    fn test_requests_tls() {
+        let mut node = node();
+        node.tls.replace(Tls::new("lavalink.example.com"));
+
+        let req = super::load_track(&node, "ytsearch:song").unwrap();
+        assert_eq!(
+            "https://lavalink.example.com:2333/loadtracks?identifier=ytsearch%3Asong",
+            req.uri()
+        );
+        assert_eq!("pass", req.headers()["Authorization"]);
+
+        let req = super::unmark_all_failed_addresses(&node).unwrap();
+        assert_eq!(
+            "https://lavalink.example.com:2333/routeplanner/free/all",
+            req.uri()
+        );
+    }
 }
@@ -45,6 +45,17 @@
 //!
 //! This is enabled by default.
 //!
+//! ## Tracing
+//!
+//! The crate enters these spans:
+//!
+//! - `lavalink node` at the info level, around the connection to a node, with
+//!   the `node` field containing its address;
+//! - `player send` at the debug level, around sending an event to a player,
+//!   with the `guild_id` field;
+//! - `send_voice_update` at the debug level, around forwarding a voice update
+//!   to a player, with the `guild_id` and `update` fields.
+//!
 //! ## Examples
 //!
 //! Create a [client], add a [node], and give events to the client to [process]
@@ -125,8 +136,15 @@ pub mod client;
 pub mod model;
 pub mod node;
 pub mod player;
+pub mod queue;
+pub mod v4;
 
 #[cfg(feature = "http")]
 pub mod http;
 
-pub use self::{client::Lavalink, node::Node, player::PlayerManager};
+pub use self::{
+    client::Lavalink,
+    node::{Node, Protocol},
+    player::PlayerManager,
+    queue::QueueManager,
+};
@@ -10,10 +10,10 @@ use serde::{Deserialize, Serialize};
 pub enum Opcode {
     /// Destroy a player from a node.
     Destroy,
-    /// Equalize a player.
-    Equalizer,
     /// Meta information about a track starting or ending.
     Event,
+    /// Apply audio filters to a player.
+    Filters,
     /// Pause a player.
     Pause,
     /// Play a track.
@@ -46,8 +46,8 @@ pub mod outgoing {
     pub enum OutgoingEvent {
         /// Destroy a player for a guild.
         Destroy(Destroy),
-        /// Equalize a player.
-        Equalizer(Equalizer),
+        /// Apply audio filters to a player.
+        Filters(Box<Filters>),
         /// Pause or unpause a player.
         Pause(Pause),
         /// Play a track.
@@ -62,15 +62,31 @@ pub mod outgoing {
         Volume(Volume),
     }
 
+    impl OutgoingEvent {
+        /// Return the guild ID of the player that the event is for.
+        pub fn guild_id(&self) -> GuildId {
+            match self {
+                Self::Destroy(event) => event.guild_id,
+                Self::Filters(event) => event.guild_id,
+                Self::Pause(event) => event.guild_id,
+                Self::Play(event) => event.guild_id,
+                Self::Seek(event) => event.guild_id,
+                Self::Stop(event) => event.guild_id,
+                Self::VoiceUpdate(event) => event.guild_id,
+                Self::Volume(event) => event.guild_id,
+            }
+        }
+    }
+
     impl From<Destroy> for OutgoingEvent {
         fn from(event: Destroy) -> OutgoingEvent {
             Self::Destroy(event)
         }
     }
 
-    impl From<Equalizer> for OutgoingEvent {
-        fn from(event: Equalizer) -> OutgoingEvent {
-            Self::Equalizer(event)
+    impl From<Filters> for OutgoingEvent {
+        fn from(event: Filters) -> OutgoingEvent {
+            Self::Filters(Box::new(event))
         }
     }
 
@@ -140,57 +156,309 @@ pub mod outgoing {
         }
     }
 
-    /// Equalize a player.
+    /// A band of the equalizer filter.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct EqualizerBand {
+        /// The band.
+        pub band: i64,
+        /// The gain.
+        pub gain: f64,
+    }
+
+    impl EqualizerBand {
+        /// Create a new equalizer band.
+        pub fn new(band: i64, gain: f64) -> Self {
+            Self::from((band, gain))
+        }
+    }
+
+    impl From<(i64, f64)> for EqualizerBand {
+        fn from((band, gain): (i64, f64)) -> Self {
+            Self { band, gain }
+        }
+    }
+
+    /// Apply audio filters to a player.
+    ///
+    /// Each filter that is set replaces any filter of the same type that was
+    /// previously applied, and any filter that isn't set is disabled. Sending
+    /// an event without any filters set disables all of them.
+    ///
+    /// Filters other than the equalizer require Lavalink 3.4 or later.
     #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
     #[non_exhaustive]
     #[serde(rename_all = "camelCase")]
-    pub struct Equalizer {
-        /// The bands to use as part of the equalizer.
-        pub bands: Vec<EqualizerBand>,
+    pub struct Filters {
+        /// Mix the left and right channels.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub channel_mix: Option<ChannelMix>,
+        /// Distort the audio.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub distortion: Option<Distortion>,
+        /// The bands of the equalizer.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub equalizer: Option<Vec<EqualizerBand>>,
         /// The guild ID of the player.
         pub guild_id: GuildId,
+        /// Eliminate part of a band, usually targeting vocals.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub karaoke: Option<Karaoke>,
+        /// Suppress higher frequencies.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub low_pass: Option<LowPass>,
         /// The opcode of the event.
         pub op: Opcode,
+        /// Rotate the audio around the stereo channels.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rotation: Option<Rotation>,
+        /// Change the speed, pitch, and rate of the audio.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub timescale: Option<Timescale>,
+        /// Oscillate the volume of the audio.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tremolo: Option<Tremolo>,
+        /// Oscillate the pitch of the audio.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub vibrato: Option<Vibrato>,
     }
 
-    impl Equalizer {
-        /// Create a new equalizer event.
-        pub fn new(guild_id: GuildId, bands: Vec<EqualizerBand>) -> Self {
-            Self::from((guild_id, bands))
+    impl Filters {
+        /// Create a new filters event without any filters set.
+        ///
+        /// Sending this event as-is disables all filters of the player.
+        pub fn new(guild_id: GuildId) -> Self {
+            Self::from(guild_id)
         }
     }
 
-    impl From<(GuildId, Vec<EqualizerBand>)> for Equalizer {
-        fn from((guild_id, bands): (GuildId, Vec<EqualizerBand>)) -> Self {
+    impl From<GuildId> for Filters {
+        fn from(guild_id: GuildId) -> Self {
             Self {
-                bands,
+                channel_mix: None,
+                distortion: None,
+                equalizer: None,
                 guild_id,
-                op: Opcode::Equalizer,
+                karaoke: None,
+                low_pass: None,
+                op: Opcode::Filters,
+                rotation: None,
+                timescale: None,
+                tremolo: None,
+                vibrato: None,
             }
         }
     }
 
-    /// A band of the equalizer event.
+    impl From<(GuildId, Vec<EqualizerBand>)> for Filters {
+        fn from((guild_id, bands): (GuildId, Vec<EqualizerBand>)) -> Self {
+            let mut filters = Self::from(guild_id);
+            filters.equalizer.replace(bands);
+
+            filters
+        }
+    }
+
+    /// Mix the left and right channels of the audio.
+    ///
+    /// Each factor is from 0 to 1. Setting all factors to 0.5 makes both
+    /// channels get the same audio.
     #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
     #[non_exhaustive]
     #[serde(rename_all = "camelCase")]
-    pub struct EqualizerBand {
-        /// The band.
-        pub band: i64,
-        /// The gain.
-        pub gain: f64,
+    pub struct ChannelMix {
+        /// The factor of the left channel mixed into the left channel.
+        pub left_to_left: f64,
+        /// The factor of the left channel mixed into the right channel.
+        pub left_to_right: f64,
+        /// The factor of the right channel mixed into the left channel.
+        pub right_to_left: f64,
+        /// The factor of the right channel mixed into the right channel.
+        pub right_to_right: f64,
     }
 
-    impl EqualizerBand {
-        /// Create a new equalizer band.
-        pub fn new(band: i64, gain: f64) -> Self {
-            Self::from((band, gain))
+    impl ChannelMix {
+        /// Create a new channel mix filter.
+        pub fn new(
+            left_to_left: f64,
+            left_to_right: f64,
+            right_to_left: f64,
+            right_to_right: f64,
+        ) -> Self {
+            Self {
+                left_to_left,
+                left_to_right,
+                right_to_left,
+                right_to_right,
+            }
         }
     }
 
-    impl From<(i64, f64)> for EqualizerBand {
-        fn from((band, gain): (i64, f64)) -> Self {
-            Self { band, gain }
+    /// Distort the audio using sine, cosine, and tangent functions.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Distortion {
+        /// The offset of the cosine function.
+        pub cos_offset: f64,
+        /// The scale of the cosine function.
+        pub cos_scale: f64,
+        /// The offset applied to the distorted audio.
+        pub offset: f64,
+        /// The scale applied to the distorted audio.
+        pub scale: f64,
+        /// The offset of the sine function.
+        pub sin_offset: f64,
+        /// The scale of the sine function.
+        pub sin_scale: f64,
+        /// The offset of the tangent function.
+        pub tan_offset: f64,
+        /// The scale of the tangent function.
+        pub tan_scale: f64,
+    }
+
+    impl Default for Distortion {
+        fn default() -> Self {
+            Self {
+                cos_offset: 0.0,
+                cos_scale: 1.0,
+                offset: 0.0,
+                scale: 1.0,
+                sin_offset: 0.0,
+                sin_scale: 1.0,
+                tan_offset: 0.0,
+                tan_scale: 1.0,
+            }
+        }
+    }
+
+    /// Eliminate part of a band of the audio, usually targeting vocals.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Karaoke {
+        /// The frequency of the band to filter, in hertz.
+        pub filter_band: f64,
+        /// The width of the band to filter.
+        pub filter_width: f64,
+        /// The level of the effect, from 0 to 1.
+        pub level: f64,
+        /// The level of the effect on mono audio, from 0 to 1.
+        pub mono_level: f64,
+    }
+
+    impl Default for Karaoke {
+        fn default() -> Self {
+            Self {
+                filter_band: 220.0,
+                filter_width: 100.0,
+                level: 1.0,
+                mono_level: 1.0,
+            }
+        }
+    }
+
+    /// Suppress frequencies higher than a cutoff, letting through only lower
+    /// frequencies.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct LowPass {
+        /// How strongly higher frequencies are suppressed.
+        ///
+        /// Values of 1 or less disable the filter.
+        pub smoothing: f64,
+    }
+
+    impl LowPass {
+        /// Create a new low pass filter.
+        pub fn new(smoothing: f64) -> Self {
+            Self { smoothing }
+        }
+    }
+
+    /// Rotate the audio around the stereo channels, also known as audio
+    /// panning.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Rotation {
+        /// The frequency of the rotation in hertz.
+        ///
+        /// For example, 0.2 is similar to the "8D audio" effect.
+        pub rotation_hz: f64,
+    }
+
+    impl Rotation {
+        /// Create a new rotation filter.
+        pub fn new(rotation_hz: f64) -> Self {
+            Self { rotation_hz }
+        }
+    }
+
+    /// Change the speed, pitch, and rate of the audio.
+    ///
+    /// Each value is a multiplier where 1 is the default.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Timescale {
+        /// The pitch multiplier.
+        pub pitch: f64,
+        /// The rate multiplier, changing both the speed and the pitch.
+        pub rate: f64,
+        /// The speed multiplier.
+        pub speed: f64,
+    }
+
+    impl Timescale {
+        /// Create a new timescale filter.
+        pub fn new(speed: f64, pitch: f64, rate: f64) -> Self {
+            Self { pitch, rate, speed }
+        }
+    }
+
+    impl Default for Timescale {
+        fn default() -> Self {
+            Self::new(1.0, 1.0, 1.0)
+        }
+    }
+
+    /// Oscillate the volume of the audio.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Tremolo {
+        /// The depth of the oscillation, from more than 0 up to 1.
+        pub depth: f64,
+        /// The frequency of the oscillation in hertz, greater than 0.
+        pub frequency: f64,
+    }
+
+    impl Tremolo {
+        /// Create a new tremolo filter.
+        pub fn new(frequency: f64, depth: f64) -> Self {
+            Self { depth, frequency }
+        }
+    }
+
+    /// Oscillate the pitch of the audio.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct Vibrato {
+        /// The depth of the oscillation, from more than 0 up to 1.
+        pub depth: f64,
+        /// The frequency of the oscillation in hertz, from more than 0 up to
+        /// 14.
+        pub frequency: f64,
+    }
+
+    impl Vibrato {
+        /// Create a new vibrato filter.
+        pub fn new(frequency: f64, depth: f64) -> Self {
+            Self { depth, frequency }
         }
     }
 
@@ -463,6 +731,8 @@ pub mod incoming {
 
     use super::Opcode;
     use serde::{Deserialize, Serialize};
+    use serde_json::Value;
+    use std::convert::TryFrom;
     use twilight_model::id::GuildId;
 
     /// An incoming event from a Lavalink node.
@@ -476,8 +746,84 @@ pub mod incoming {
         Stats(Stats),
         /// A track ended.
         TrackEnd(TrackEnd),
+        /// An exception occurred while playing a track.
+        TrackException(TrackException),
         /// A track started.
         TrackStart(TrackStart),
+        /// The voice websocket connection of a player closed.
+        WebSocketClosed(WebSocketClosed),
+        // This must be the last variant, since it matches every message.
+        /// A message that isn't otherwise known, such as an event from a
+        /// plugin of the node.
+        PluginEvent(PluginEvent),
+    }
+
+    impl IncomingEvent {
+        /// Return the guild ID of the player that the event is about, if it's
+        /// about a player.
+        ///
+        /// Statistics aren't about any player.
+        pub fn guild_id(&self) -> Option<GuildId> {
+            match self {
+                Self::PlayerUpdate(event) => Some(event.guild_id),
+                Self::Stats(_) => None,
+                Self::TrackEnd(event) => Some(event.guild_id),
+                Self::TrackException(event) => Some(event.guild_id),
+                Self::TrackStart(event) => Some(event.guild_id),
+                Self::WebSocketClosed(event) => Some(event.guild_id),
+                Self::PluginEvent(event) => event.guild_id(),
+            }
+        }
+    }
+
+    /// A message from a node that isn't otherwise known, such as one sent by a
+    /// plugin.
+    ///
+    /// Plugins, such as LavaSrc or SponsorBlock, can send their own ops and
+    /// event types. The full message is kept so that it can be deserialized
+    /// into the plugin's own types.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(into = "Value", try_from = "Value")]
+    pub struct PluginEvent {
+        /// The full message as it was received.
+        pub data: Value,
+        /// The op of the message, such as `"event"` for events of types that
+        /// aren't known.
+        pub op: String,
+    }
+
+    impl PluginEvent {
+        /// Return the guild ID of the player that the message is about, if it
+        /// has one.
+        pub fn guild_id(&self) -> Option<GuildId> {
+            self.data
+                .get("guildId")?
+                .as_str()?
+                .parse()
+                .ok()
+                .map(GuildId)
+        }
+    }
+
+    impl From<PluginEvent> for Value {
+        fn from(event: PluginEvent) -> Self {
+            event.data
+        }
+    }
+
+    impl TryFrom<Value> for PluginEvent {
+        type Error = &'static str;
+
+        fn try_from(data: Value) -> Result<Self, Self::Error> {
+            let op = data
+                .get("op")
+                .and_then(Value::as_str)
+                .ok_or("message has no op")?
+                .to_owned();
+
+            Ok(Self { data, op })
+        }
     }
 
     impl From<PlayerUpdate> for IncomingEvent {
@@ -552,16 +898,16 @@ pub mod incoming {
         pub system_load: f64,
     }
 
-    /// CPU information about a node and its host.
+    /// Statistics about the audio frames sent by a node.
     #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
     #[non_exhaustive]
     #[serde(rename_all = "camelCase")]
     pub struct StatsFrames {
-        /// The number of CPU cores.
+        /// The average number of frames sent per minute.
         pub sent: u64,
-        /// The load of the Lavalink server.
+        /// The average number of frames per minute that were empty.
         pub nulled: u64,
-        /// The load of the system as a whole.
+        /// The average number of frames per minute that weren't sent in time.
         pub deficit: u64,
     }
 
@@ -587,9 +933,15 @@ pub mod incoming {
         /// A track for a player ended.
         #[serde(rename = "TrackEndEvent")]
         End,
+        /// An exception occurred while playing a track for a player.
+        #[serde(rename = "TrackExceptionEvent")]
+        Exception,
         /// A track for a player started.
         #[serde(rename = "TrackStartEvent")]
         Start,
+        /// The voice websocket connection of a player closed.
+        #[serde(rename = "WebSocketClosedEvent")]
+        WebSocketClosed,
     }
 
     /// A track ended.
@@ -612,6 +964,24 @@ pub mod incoming {
         pub track: String,
     }
 
+    /// An exception occurred while playing a track.
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct TrackException {
+        /// The message of the exception.
+        pub error: String,
+        /// The guild ID of the player.
+        pub guild_id: GuildId,
+        /// The type of track event.
+        #[serde(rename = "type")]
+        pub kind: TrackEventType,
+        /// The opcode of the event.
+        pub op: Opcode,
+        /// The base64 track that was affected.
+        pub track: String,
+    }
+
     /// A track started.
     #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
     #[non_exhaustive]
@@ -627,16 +997,42 @@ pub mod incoming {
         /// The base64 track that was affected.
         pub track: String,
     }
+
+    /// The voice websocket connection of a player closed.
+    ///
+    /// Refer to Discord's [voice close event codes] for the meaning of the
+    /// code.
+    ///
+    /// [voice close event codes]: https://discord.com/developers/docs/topics/opcodes-and-status-codes#voice-voice-close-event-codes
+    #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[non_exhaustive]
+    #[serde(rename_all = "camelCase")]
+    pub struct WebSocketClosed {
+        /// Whether Discord closed the connection rather than Lavalink.
+        pub by_remote: bool,
+        /// The close code of the connection.
+        pub code: u64,
+        /// The guild ID of the player.
+        pub guild_id: GuildId,
+        /// The type of track event.
+        #[serde(rename = "type")]
+        pub kind: TrackEventType,
+        /// The opcode of the event.
+        pub op: Opcode,
+        /// The reason that the connection was closed.
+        pub reason: String,
+    }
 }
 
 pub use self::{
     incoming::{
-        IncomingEvent, PlayerUpdate, PlayerUpdateState, Stats, StatsCpu, StatsFrames, StatsMemory,
-        TrackEnd, TrackEventType, TrackStart,
+        IncomingEvent, PlayerUpdate, PlayerUpdateState, PluginEvent, Stats, StatsCpu, StatsFrames,
+        StatsMemory, TrackEnd, TrackEventType, TrackException, TrackStart, WebSocketClosed,
     },
     outgoing::{
-        Destroy, Equalizer, EqualizerBand, OutgoingEvent, Pause, Play, Seek, SlimVoiceServerUpdate,
-        Stop, VoiceUpdate, Volume,
+        ChannelMix, Destroy, Distortion, EqualizerBand, Filters, Karaoke, LowPass, OutgoingEvent,
+        Pause, Play, Rotation, Seek, SlimVoiceServerUpdate, Stop, Timescale, Tremolo, Vibrato,
+        VoiceUpdate, Volume,
     },
 };
 
@@ -644,12 +1040,14 @@ pub use self::{
 mod tests {
     use super::{
         incoming::{
-            IncomingEvent, PlayerUpdate, PlayerUpdateState, Stats, StatsCpu, StatsFrames,
-            StatsMemory, TrackEnd, TrackEventType, TrackStart,
+            IncomingEvent, PlayerUpdate, PlayerUpdateState, PluginEvent, Stats, StatsCpu,
+            StatsFrames, StatsMemory, TrackEnd, TrackEventType, TrackException, TrackStart,
+            WebSocketClosed,
         },
         outgoing::{
-            Destroy, Equalizer, EqualizerBand, OutgoingEvent, Pause, Play, Seek,
-            SlimVoiceServerUpdate, Stop, VoiceUpdate, Volume,
+            ChannelMix, Destroy, Distortion, EqualizerBand, Filters, Karaoke, LowPass,
+            OutgoingEvent, Pause, Play, Rotation, Seek, SlimVoiceServerUpdate, Stop, Timescale,
+            Tremolo, Vibrato, VoiceUpdate, Volume,
         },
         Opcode,
     };
@@ -682,17 +1080,87 @@ mod tests {
         Serialize,
         Sync,
     );
-    assert_fields!(Equalizer: bands, guild_id, op);
+    assert_fields!(
+        Filters: channel_mix,
+        distortion,
+        equalizer,
+        guild_id,
+        karaoke,
+        low_pass,
+        op,
+        rotation,
+        timescale,
+        tremolo,
+        vibrato
+    );
     assert_impl_all!(
-        Equalizer: Clone,
+        Filters: Clone,
         Debug,
         Deserialize<'static>,
+        From<GuildId>,
         From<(GuildId, Vec<EqualizerBand>)>,
         PartialEq,
         Send,
         Serialize,
         Sync,
     );
+    assert_fields!(
+        ChannelMix: left_to_left,
+        left_to_right,
+        right_to_left,
+        right_to_right
+    );
+    assert_impl_all!(ChannelMix: Clone, Debug, Deserialize<'static>, PartialEq, Send, Serialize, Sync);
+    assert_fields!(
+        Distortion: cos_offset,
+        cos_scale,
+        offset,
+        scale,
+        sin_offset,
+        sin_scale,
+        tan_offset,
+        tan_scale
+    );
+    assert_impl_all!(
+        Distortion: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Karaoke: filter_band, filter_width, level, mono_level);
+    assert_impl_all!(
+        Karaoke: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(LowPass: smoothing);
+    assert_impl_all!(LowPass: Clone, Debug, Deserialize<'static>, PartialEq, Send, Serialize, Sync);
+    assert_fields!(Rotation: rotation_hz);
+    assert_impl_all!(Rotation: Clone, Debug, Deserialize<'static>, PartialEq, Send, Serialize, Sync);
+    assert_fields!(Timescale: pitch, rate, speed);
+    assert_impl_all!(
+        Timescale: Clone,
+        Debug,
+        Default,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Tremolo: depth, frequency);
+    assert_impl_all!(Tremolo: Clone, Debug, Deserialize<'static>, PartialEq, Send, Serialize, Sync);
+    assert_fields!(Vibrato: depth, frequency);
+    assert_impl_all!(Vibrato: Clone, Debug, Deserialize<'static>, PartialEq, Send, Serialize, Sync);
     assert_impl_all!(
         IncomingEvent: Clone,
         Debug,
@@ -709,7 +1177,7 @@ mod tests {
         Debug,
         Deserialize<'static>,
         From<Destroy>,
-        From<Equalizer>,
+        From<Filters>,
         From<Pause>,
         From<Play>,
         From<Seek>,
@@ -880,6 +1348,16 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(TrackException: error, guild_id, kind, op, track);
+    assert_impl_all!(
+        TrackException: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(TrackStart: guild_id, kind, op, track);
     assert_impl_all!(
         TrackStart: Clone,
@@ -890,6 +1368,16 @@ mod tests {
         Serialize,
         Sync,
     );
+    assert_fields!(WebSocketClosed: by_remote, code, guild_id, kind, op, reason);
+    assert_impl_all!(
+        WebSocketClosed: Clone,
+        Debug,
+        Deserialize<'static>,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
     assert_fields!(VoiceUpdate: event, guild_id, op, session_id);
     assert_impl_all!(
         VoiceUpdate: Clone,
@@ -983,4 +1471,107 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn filters_only_set() {
+        let mut filters = Filters::new(GuildId(1));
+        filters.timescale.replace(Timescale::new(1.5, 1.0, 1.0));
+
+        serde_test::assert_ser_tokens(
+            &filters,
+            &[
+                Token::Struct {
+                    name: "Filters",
+                    len: 3,
+                },
+                Token::Str("guildId"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("op"),
+                Token::UnitVariant {
+                    name: "Opcode",
+                    variant: "filters",
+                },
+                Token::Str("timescale"),
+                Token::Some,
+                Token::Struct {
+                    name: "Timescale",
+                    len: 3,
+                },
+                Token::Str("pitch"),
+                Token::F64(1.0),
+                Token::Str("rate"),
+                Token::F64(1.0),
+                Token::Str("speed"),
+                Token::F64(1.5),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn incoming_track_events() {
+        let start = r#"{"op":"event","type":"TrackStartEvent","guildId":"1","track":"a"}"#;
+        assert!(matches!(
+            serde_json::from_str::<IncomingEvent>(start).unwrap(),
+            IncomingEvent::TrackStart(_),
+        ));
+
+        let exception = r#"{"op":"event","type":"TrackExceptionEvent","guildId":"1","track":"a","error":"broken"}"#;
+        assert!(matches!(
+            serde_json::from_str::<IncomingEvent>(exception).unwrap(),
+            IncomingEvent::TrackException(TrackException { error, .. }) if error == "broken",
+        ));
+
+        let closed = r#"{"op":"event","type":"WebSocketClosedEvent","guildId":"1","code":4006,"reason":"","byRemote":true}"#;
+        assert!(matches!(
+            serde_json::from_str::<IncomingEvent>(closed).unwrap(),
+            IncomingEvent::WebSocketClosed(WebSocketClosed { code: 4006, .. }),
+        ));
+    }
+
+    #[test]
+    fn incoming_plugin_events() {
+        let segments = r#"{"op":"event","type":"SegmentsLoaded","guildId":"1","segments":[]}"#;
+        let event = serde_json::from_str::<IncomingEvent>(segments).unwrap();
+        assert_eq!(Some(GuildId(1)), event.guild_id());
+
+        let event = match event {
+            IncomingEvent::PluginEvent(event) => event,
+            other => panic!("not a plugin event: {:?}", other),
+        };
+        assert_eq!("event", event.op);
+        assert_eq!("SegmentsLoaded", event.data["type"]);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(segments).unwrap(),
+            serde_json::to_value(IncomingEvent::PluginEvent(event)).unwrap(),
+        );
+
+        let custom = r#"{"op":"lyrics","lines":[]}"#;
+        assert!(matches!(
+            serde_json::from_str::<IncomingEvent>(custom).unwrap(),
+            IncomingEvent::PluginEvent(PluginEvent { op, .. }) if op == "lyrics",
+        ));
+        assert!(serde_json::from_str::<IncomingEvent>(r#"{"lines":[]}"#).is_err());
+    }
+
+    #[test]
+    fn stats_frames_provided() {
+        let stats = serde_json::from_str::<Stats>(
+            r#"{"op":"stats","players":2,"playingPlayers":1,"uptime":100,"memory":{"free":1,"used":2,"allocated":3,"reservable":4},"cpu":{"cores":2,"systemLoad":0.5,"lavalinkLoad":0.25},"frameStats":{"sent":6000,"nulled":10,"deficit":20}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Some(StatsFrames {
+                deficit: 20,
+                nulled: 10,
+                sent: 6000,
+            }),
+            stats.frames,
+        );
+        assert_eq!(1, stats.playing_players);
+        assert_eq!(2, stats.cpu.cores);
+    }
 }
@@ -21,73 +21,79 @@
 use crate::{
     model::{IncomingEvent, Opcode, OutgoingEvent, PlayerUpdate, Stats, StatsCpu, StatsMemory},
     player::PlayerManager,
+    v4::{self, ErrorResponse, UpdateSession},
 };
 use async_tungstenite::{
     tokio::ConnectStream,
     tungstenite::{Error as TungsteniteError, Message},
     WebSocketStream,
 };
-use futures_channel::mpsc::{self, TrySendError, UnboundedReceiver, UnboundedSender};
+use futures_channel::{
+    mpsc::{self, TrySendError, UnboundedReceiver, UnboundedSender},
+    oneshot::{self, Receiver},
+};
 use futures_util::{
-    future::{self, Either},
+    future::{self, Either, FutureExt, Shared},
     lock::BiLock,
     sink::SinkExt,
     stream::StreamExt,
 };
-use http::{header::HeaderName, Error as HttpError, Request, Response, StatusCode};
-use serde_json::Error as JsonError;
+use http::{header::HeaderName, Request, Response, StatusCode};
+use hyper::{client::HttpConnector, Body, Client};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
     net::SocketAddr,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     time::Duration,
 };
 use tokio::time as tokio_time;
+use tracing::Instrument;
 use twilight_model::id::UserId;
 
 /// An error occurred while either initializing a connection or while running
 /// its event loop.
 #[derive(Debug)]
-#[non_exhaustive]
-pub enum NodeError {
-    /// Building the HTTP request to initialize a connection failed.
-    BuildingConnectionRequest {
-        /// The source of the error from the `http` crate.
-        source: HttpError,
-    },
-    /// Connecting to the Lavalink server failed after several backoff attempts.
-    Connecting {
-        /// The source of the error from the `tungstenite` crate.
-        source: TungsteniteError,
-    },
-    /// Serializing a JSON message to be sent to a Lavalink node failed.
-    SerializingMessage {
-        /// The message that couldn't be serialized.
-        message: OutgoingEvent,
-        /// The source of the error from the `serde_json` crate.
-        source: JsonError,
-    },
-    /// The given authorization for the node is incorrect.
-    Unauthorized {
-        /// The address of the node that failed to authorize.
-        address: SocketAddr,
-        /// The authorization used to connect to the node.
-        authorization: String,
-    },
+pub struct NodeError {
+    kind: NodeErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl NodeError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &NodeErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (NodeErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
 }
 
 impl Display for NodeError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        match self {
-            Self::BuildingConnectionRequest { .. } => {
+        match &self.kind {
+            NodeErrorType::BuildingConnectionRequest => {
                 f.write_str("failed to build connection request")
             }
-            Self::Connecting { .. } => f.write_str("Failed to connect to the node"),
-            Self::SerializingMessage { .. } => {
+            NodeErrorType::Connecting => f.write_str("Failed to connect to the node"),
+            NodeErrorType::SerializingMessage { .. } => {
                 f.write_str("failed to serialize outgoing message as json")
             }
-            Self::Unauthorized { address, .. } => write!(
+            NodeErrorType::Unauthorized { address, .. } => write!(
                 f,
                 "the authorization used to connect to node {} is invalid",
                 address
@@ -98,12 +104,57 @@ impl Display for NodeError {
 
 impl Error for NodeError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Self::BuildingConnectionRequest { source } => Some(source),
-            Self::Connecting { source } => Some(source),
-            Self::SerializingMessage { source, .. } => Some(source),
-            Self::Unauthorized { .. } => None,
-        }
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`NodeError`] that occurred.
+///
+/// [`NodeError`]: struct.NodeError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum NodeErrorType {
+    /// Building the HTTP request to initialize a connection failed.
+    BuildingConnectionRequest,
+    /// Connecting to the Lavalink server failed after the configured number of
+    /// [`Reconnect`] attempts.
+    ///
+    /// [`Reconnect`]: struct.Reconnect.html
+    Connecting,
+    /// Serializing a JSON message to be sent to a Lavalink node failed.
+    SerializingMessage {
+        /// The message that couldn't be serialized.
+        message: OutgoingEvent,
+    },
+    /// The given authorization for the node is incorrect.
+    Unauthorized {
+        /// The address of the node that failed to authorize.
+        address: SocketAddr,
+        /// The authorization used to connect to the node.
+        authorization: String,
+    },
+}
+
+/// The version of the Lavalink protocol that a node uses.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Protocol {
+    /// Version 3, where players are controlled through websocket messages.
+    V3,
+    /// Version 4, where players are controlled through the REST API and the
+    /// websocket only receives events.
+    ///
+    /// Refer to the [`v4`] module for more information.
+    ///
+    /// [`v4`]: ../v4/index.html
+    V4,
+}
+
+impl Default for Protocol {
+    fn default() -> Self {
+        Self::V3
     }
 }
 
@@ -116,21 +167,141 @@ pub struct NodeConfig {
     /// The address of the node.
     pub address: SocketAddr,
     /// The password to use when authenticating.
+    ///
+    /// Each node has its own password.
     pub authorization: String,
+    /// The version of the Lavalink protocol that the server uses.
+    ///
+    /// The default is [`Protocol::V3`].
+    ///
+    /// [`Protocol::V3`]: enum.Protocol.html#variant.V3
+    pub protocol: Protocol,
+    /// How the node reconnects when its connection drops.
+    ///
+    /// Refer to [`Reconnect`] for the defaults.
+    ///
+    /// [`Reconnect`]: struct.Reconnect.html
+    pub reconnect: Reconnect,
     /// The details for resuming a Lavalink session, if any.
     ///
     /// Set this to `None` to disable resume capability.
     pub resume: Option<Resume>,
     /// The number of shards in use by the bot.
     pub shard_count: u64,
+    /// The TLS configuration of the node, if it's connected to over TLS.
+    ///
+    /// The default is `None`, connecting over plaintext.
+    pub tls: Option<Tls>,
     /// The user ID of the bot.
     pub user_id: UserId,
 }
 
+/// Configuration for connecting to a node over TLS, using `wss://` for its
+/// websocket and `https://` for its REST API.
+///
+/// This is needed by most hosted Lavalink providers. The TLS backend is
+/// selected by enabling either the `rustls` or `native` feature.
+///
+/// REST requests built by the [`http`] and [`v4`] modules for a node with this
+/// configuration are also sent over TLS.
+///
+/// [`http`]: ../http/index.html
+/// [`v4`]: ../v4/index.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Tls {
+    /// The domain name of the node, used to verify its certificate.
+    ///
+    /// The node is connected to by its domain name and the port of its
+    /// address.
+    pub domain: String,
+}
+
+impl Tls {
+    /// Configure connecting over TLS to a node by its domain name.
+    pub fn new(domain: impl Into<String>) -> Self {
+        Self {
+            domain: domain.into(),
+        }
+    }
+}
+
+/// Configuration for how a node reconnects when its connection drops.
+///
+/// The delay before the next attempt starts at the [`initial_delay`] and
+/// doubles after each failed attempt, up to the [`max_delay`]. By default a
+/// node waits 1 second at first, at most 64 seconds, and gives up after 8
+/// attempts.
+///
+/// [`initial_delay`]: #structfield.initial_delay
+/// [`max_delay`]: #structfield.max_delay
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Reconnect {
+    /// The delay before the second attempt to connect.
+    pub initial_delay: Duration,
+    /// The number of attempts to connect before giving up, or `None` to never
+    /// give up.
+    pub max_attempts: Option<u32>,
+    /// The maximum delay between attempts to connect.
+    pub max_delay: Duration,
+    /// Whether the players of the node are migrated to other nodes once the
+    /// node gives up reconnecting.
+    ///
+    /// This only applies to nodes managed by the [`Lavalink`] client. Refer to
+    /// [`Lavalink::migrate`] for more information.
+    ///
+    /// The default is `false`.
+    ///
+    /// [`Lavalink`]: ../client/struct.Lavalink.html
+    /// [`Lavalink::migrate`]: ../client/struct.Lavalink.html#method.migrate
+    pub migrate_players: bool,
+}
+
+impl Reconnect {
+    /// Configure the delays between attempts to connect and the number of
+    /// attempts, if limited.
+    pub fn new(
+        initial_delay: Duration,
+        max_delay: Duration,
+        max_attempts: impl Into<Option<u32>>,
+    ) -> Self {
+        Self {
+            initial_delay,
+            max_attempts: max_attempts.into(),
+            max_delay,
+            migrate_players: false,
+        }
+    }
+}
+
+impl Default for Reconnect {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1), Duration::from_secs(64), 8)
+    }
+}
+
 /// Configuration for a session which can be resumed.
+///
+/// When the connection to a node drops, the node keeps its players running
+/// for the [`timeout`] and queues events for when the session is resumed. If
+/// the session couldn't be resumed, such as because the timeout elapsed or the
+/// node restarted, then the state of the node's players is replayed to it:
+/// their voice connections, tracks and positions, pause states, volumes, and
+/// filters.
+///
+/// [`timeout`]: #structfield.timeout
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub struct Resume {
+    /// The key used to identify the session to resume.
+    ///
+    /// If this is `None`, then the address of the node is used. Set a key if
+    /// multiple bots connect to the same node.
+    ///
+    /// This is only used by version 3 nodes; version 4 nodes resume by the ID
+    /// of the previous session instead.
+    pub key: Option<String>,
     /// The number of seconds that the Lavalink server will allow the session to
     /// be resumed for after a disconnect.
     ///
@@ -142,13 +313,27 @@ impl Resume {
     /// Configure resume capability, providing the number of seconds that the
     /// Lavalink server should queue events for when the connection is resumed.
     pub fn new(seconds: u64) -> Self {
-        Self { timeout: seconds }
+        Self {
+            key: None,
+            timeout: seconds,
+        }
+    }
+
+    /// Like [`new`], but using a key to identify the session instead of the
+    /// address of the node.
+    ///
+    /// [`new`]: #method.new
+    pub fn new_with_key(key: impl Into<String>, seconds: u64) -> Self {
+        Self {
+            key: Some(key.into()),
+            timeout: seconds,
+        }
     }
 }
 
 impl Default for Resume {
     fn default() -> Self {
-        Self { timeout: 60 }
+        Self::new(60)
     }
 }
 
@@ -187,8 +372,11 @@ impl NodeConfig {
         Self {
             address,
             authorization,
+            protocol: Protocol::default(),
+            reconnect: Reconnect::default(),
             resume,
             shard_count,
+            tls: None,
             user_id,
         }
     }
@@ -196,7 +384,9 @@ impl NodeConfig {
 
 #[derive(Debug)]
 struct NodeRef {
+    closed: Shared<Receiver<()>>,
     config: NodeConfig,
+    connected: Arc<AtomicBool>,
     lavalink_tx: UnboundedSender<OutgoingEvent>,
     players: PlayerManager,
     stats: BiLock<Stats>,
@@ -243,16 +433,38 @@ impl Node {
             op: Opcode::Stats,
             uptime: 0,
         });
-        tracing::debug!("starting connection to {}", config.address);
-        let (conn_loop, lavalink_tx, lavalink_rx) =
-            Connection::connect(config.clone(), players.clone(), bilock_right).await?;
+        let connected = Arc::new(AtomicBool::new(false));
+        let span = tracing::info_span!("lavalink node", node = %config.address);
+        tracing::debug!(parent: &span, "starting connection to {}", config.address);
+        let (conn_loop, lavalink_tx, lavalink_rx) = Connection::connect(
+            config.clone(),
+            players.clone(),
+            bilock_right,
+            Arc::clone(&connected),
+        )
+        .instrument(span.clone())
+        .await?;
         tracing::debug!("started connection to {}", config.address);
 
-        tokio::spawn(conn_loop.run());
+        let (closed_tx, closed_rx) = oneshot::channel();
+        let address = config.address;
+
+        tokio::spawn(
+            async move {
+                if let Err(source) = conn_loop.run().await {
+                    tracing::warn!("connection to node {} ended: {:?}", address, source);
+                }
+
+                let _ = closed_tx.send(());
+            }
+            .instrument(span),
+        );
 
         Ok((
             Self(Arc::new(NodeRef {
+                closed: closed_rx.shared(),
                 config,
+                connected,
                 lavalink_tx,
                 players,
                 stats: bilock_left,
@@ -266,6 +478,20 @@ impl Node {
         &self.0.config
     }
 
+    /// Whether the node is currently connected.
+    ///
+    /// This is `false` while the node is reconnecting and after it gave up
+    /// reconnecting.
+    pub fn is_connected(&self) -> bool {
+        self.0.connected.load(Ordering::Relaxed)
+    }
+
+    /// Wait for the connection to the node to end for good, either because the
+    /// node gave up reconnecting or because it was dropped.
+    pub fn closed(&self) -> impl Future<Output = ()> {
+        self.0.closed.clone().map(|_| ())
+    }
+
     /// Retrieve an immutable reference to the player manager used by the node.
     pub async fn players(&self) -> &PlayerManager {
         &self.0.players
@@ -288,6 +514,13 @@ impl Node {
     }
 
     /// Retrieve a copy of the node's stats.
+    ///
+    /// Nodes send their stats periodically, about once a minute, and each
+    /// update is also received as an [`IncomingEvent::Stats`] event on the
+    /// node's stream of events. Until the first update is received all of the
+    /// stats are zero.
+    ///
+    /// [`IncomingEvent::Stats`]: ../model/incoming/enum.IncomingEvent.html#variant.Stats
     pub async fn stats(&self) -> Stats {
         (*self.0.stats.lock().await).clone()
     }
@@ -296,32 +529,50 @@ impl Node {
     ///
     /// This score can be used to calculate how loaded the server is. A higher
     /// number means it is more heavily loaded.
+    ///
+    /// The score is the standard Lavalink penalty: the number of playing
+    /// players, plus penalties that grow exponentially with the system's CPU
+    /// load and with the number of audio frames per minute that were late
+    /// (deficit) or missing (nulled). A node that hasn't sent frame stats yet
+    /// has no frame penalties.
     pub async fn penalty(&self) -> i32 {
-        let stats = self.0.stats.lock().await;
-        let cpu = 1.05f64.powf(100f64 * stats.cpu.system_load) * 10f64 - 10f64;
-
-        let (deficit_frame, null_frame) = (
-            1.03f64
-                .powf(500f64 * (stats.frames.as_ref().map_or(0, |f| f.deficit) as f64 / 3000f64))
-                * 300f64
-                - 300f64,
-            (1.03f64
-                .powf(500f64 * (stats.frames.as_ref().map_or(0, |f| f.nulled) as f64 / 3000f64))
-                * 300f64
-                - 300f64)
-                * 2f64,
-        );
-
-        stats.playing_players as i32 + cpu as i32 + deficit_frame as i32 + null_frame as i32
+        penalty(&*self.0.stats.lock().await)
     }
 }
 
+/// Calculate the standard Lavalink penalty score of a node's stats.
+fn penalty(stats: &Stats) -> i32 {
+    let cpu = 1.05f64.powf(100f64 * stats.cpu.system_load) * 10f64 - 10f64;
+
+    let (deficit_frame, null_frame) = match stats.frames.as_ref() {
+        Some(frames) => (
+            1.03f64.powf(500f64 * (frames.deficit as f64 / 3000f64)) * 600f64 - 600f64,
+            (1.03f64.powf(500f64 * (frames.nulled as f64 / 3000f64)) * 300f64 - 300f64) * 2f64,
+        ),
+        None => (0f64, 0f64),
+    };
+
+    stats.playing_players as i32 + cpu as i32 + deficit_frame as i32 + null_frame as i32
+}
+
+/// The session of a connection to a node.
+#[derive(Debug, Default)]
+struct Session {
+    /// The ID of the session, only provided by version 4 nodes.
+    id: Option<String>,
+    /// Whether the previous session was resumed.
+    resumed: bool,
+}
+
 struct Connection {
     config: NodeConfig,
+    connected: Arc<AtomicBool>,
     connection: WebSocketStream<ConnectStream>,
+    http: HttpClient,
     node_from: UnboundedReceiver<OutgoingEvent>,
     node_to: UnboundedSender<IncomingEvent>,
     players: PlayerManager,
+    session_id: Option<String>,
     stats: BiLock<Stats>,
 }
 
@@ -330,6 +581,7 @@ impl Connection {
         config: NodeConfig,
         players: PlayerManager,
         stats: BiLock<Stats>,
+        connected: Arc<AtomicBool>,
     ) -> Result<
         (
             Self,
@@ -338,7 +590,9 @@ impl Connection {
         ),
         NodeError,
     > {
-        let connection = reconnect(&config).await?;
+        let http = http_client();
+        let (connection, session) = reconnect(&config, &http, None).await?;
+        connected.store(true, Ordering::Relaxed);
 
         let (to_node, from_lavalink) = mpsc::unbounded();
         let (to_lavalink, from_node) = mpsc::unbounded();
@@ -346,10 +600,13 @@ impl Connection {
         Ok((
             Self {
                 config,
+                connected,
                 connection,
+                http,
                 node_from: from_node,
                 node_to: to_node,
                 players,
+                session_id: session.id,
                 stats,
             },
             to_lavalink,
@@ -368,7 +625,19 @@ impl Connection {
                 }
                 Either::Left((_, _)) => {
                     tracing::debug!("connection to {} closed, reconnecting", self.config.address);
-                    self.connection = reconnect(&self.config).await?;
+                    self.connected.store(false, Ordering::Relaxed);
+                    let (connection, session) =
+                        reconnect(&self.config, &self.http, self.session_id.as_deref()).await?;
+                    self.connection = connection;
+                    self.connected.store(true, Ordering::Relaxed);
+
+                    if session.id.is_some() {
+                        self.session_id = session.id;
+                    }
+
+                    if !session.resumed {
+                        self.replay().await?;
+                    }
                 }
                 Either::Right((Some(outgoing), _)) => {
                     tracing::debug!(
@@ -377,14 +646,7 @@ impl Connection {
                         outgoing
                     );
 
-                    let payload = serde_json::to_string(&outgoing).map_err(|source| {
-                        NodeError::SerializingMessage {
-                            message: outgoing,
-                            source,
-                        }
-                    })?;
-                    let msg = Message::Text(payload);
-                    self.connection.send(msg).await.unwrap();
+                    self.send(outgoing).await?;
                 }
                 Either::Right((_, _)) => {
                     tracing::debug!("node {} closed, ending connection", self.config.address);
@@ -428,9 +690,22 @@ impl Connection {
             }
         };
 
-        let event = match serde_json::from_str(&text) {
-            Ok(event) => event,
-            Err(_) => {
+        let event = match self.config.protocol {
+            Protocol::V3 => serde_json::from_str(&text).ok(),
+            Protocol::V4 => match v4::parse_message(&text) {
+                Some(v4::Message::Event(event)) => Some(event),
+                Some(v4::Message::Ready { session_id, .. }) => {
+                    self.session_id.replace(session_id);
+
+                    return Ok(true);
+                }
+                None => None,
+            },
+        };
+
+        let event = match event {
+            Some(event) => event,
+            None => {
                 tracing::warn!("unknown message from lavalink node: {}", text);
 
                 return Ok(true);
@@ -440,9 +715,29 @@ impl Connection {
         match event {
             IncomingEvent::PlayerUpdate(ref update) => self.player_update(update).await?,
             IncomingEvent::Stats(ref stats) => self.stats(stats).await?,
+            IncomingEvent::TrackEnd(ref end) => {
+                if let Some(player) = self.players.get(&end.guild_id) {
+                    player.track_ended(&end.track);
+                }
+            }
             _ => {}
         }
 
+        if let IncomingEvent::TrackEnd(_)
+        | IncomingEvent::TrackException(_)
+        | IncomingEvent::TrackStart(_)
+        | IncomingEvent::WebSocketClosed(_)
+        | IncomingEvent::PluginEvent(_) = event
+        {
+            let player = event
+                .guild_id()
+                .and_then(|guild_id| self.players.get(&guild_id));
+
+            if let Some(player) = player {
+                player.dispatch(&event);
+            }
+        }
+
         // It's fine if the rx end dropped, often users don't need to care about
         // these events.
         if !self.node_to.is_closed() {
@@ -466,8 +761,9 @@ impl Connection {
             }
         };
 
-        *player.value_mut().position_mut() = update.state.position;
-        *player.value_mut().time_mut() = update.state.time;
+        player
+            .value_mut()
+            .update_position(update.state.position, update.state.time);
 
         Ok(())
     }
@@ -477,87 +773,382 @@ impl Connection {
 
         Ok(())
     }
+
+    /// Replay the state of the node's players after connecting to a new
+    /// session, so that they continue playing where they left off.
+    async fn replay(&mut self) -> Result<(), NodeError> {
+        let address = self.config.address;
+        let events = self
+            .players
+            .players
+            .iter()
+            .filter(|player| player.node().config().address == address)
+            .flat_map(|player| player.replay_events())
+            .collect::<Vec<_>>();
+
+        tracing::debug!("replaying {} events to node {}", events.len(), address);
+
+        for event in events {
+            self.send(event).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Send an event to the node, over the websocket for version 3 nodes or
+    /// as a REST request for version 4 nodes.
+    async fn send(&mut self, event: OutgoingEvent) -> Result<(), NodeError> {
+        if self.config.protocol == Protocol::V4 {
+            self.send_request(&event).await;
+
+            return Ok(());
+        }
+
+        let payload = match serde_json::to_string(&event) {
+            Ok(payload) => payload,
+            Err(source) => {
+                return Err(NodeError {
+                    kind: NodeErrorType::SerializingMessage { message: event },
+                    source: Some(Box::new(source)),
+                })
+            }
+        };
+
+        if let Err(source) = self.connection.send(Message::Text(payload)).await {
+            tracing::warn!(
+                "failed to send event to node {}: {:?}",
+                self.config.address,
+                source
+            );
+
+            return Ok(());
+        }
+
+        if let Some(player) = self.players.get(&event.guild_id()) {
+            player.event_sent(&event);
+        }
+
+        Ok(())
+    }
+
+    async fn send_request(&self, event: &OutgoingEvent) {
+        let success = self.request(event).await;
+
+        if let Some(player) = self.players.get(&event.guild_id()) {
+            player.event_acknowledged(event, success);
+        }
+    }
+
+    /// Send the request of an event, returning whether the node accepted it.
+    async fn request(&self, event: &OutgoingEvent) -> bool {
+        let address = self.config.address;
+
+        let session_id = match self.session_id.as_deref() {
+            Some(session_id) => session_id,
+            None => {
+                tracing::warn!("no session with node {} to send {:?}", address, event);
+
+                return false;
+            }
+        };
+
+        match v4::event_request(&self.config, session_id, event) {
+            Some(Ok(request)) => rest(&self.http, &self.config, request).await,
+            Some(Err(source)) => {
+                tracing::warn!("failed to build request for node {}: {:?}", address, source);
+
+                false
+            }
+            None => {
+                tracing::debug!("event has no request for node {}: {:?}", address, event);
+
+                true
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rustls")]
+type HttpClient = Client<hyper_rustls::HttpsConnector<HttpConnector>>;
+
+#[cfg(all(feature = "native", not(feature = "rustls")))]
+type HttpClient = Client<hyper_tls::HttpsConnector<HttpConnector>>;
+
+#[cfg(not(any(feature = "native", feature = "rustls")))]
+type HttpClient = Client<HttpConnector>;
+
+/// Create the client used to send REST requests to nodes, which supports
+/// HTTPS if a TLS backend is enabled.
+fn http_client() -> HttpClient {
+    #[cfg(feature = "rustls")]
+    {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+
+        let mut config = tokio_rustls::rustls::ClientConfig::new();
+        config
+            .root_store
+            .add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS);
+
+        Client::builder().build(hyper_rustls::HttpsConnector::from((http, config)))
+    }
+
+    #[cfg(all(feature = "native", not(feature = "rustls")))]
+    {
+        Client::builder().build(hyper_tls::HttpsConnector::new())
+    }
+
+    #[cfg(not(any(feature = "native", feature = "rustls")))]
+    {
+        Client::new()
+    }
 }
 
-fn connect_request(state: &NodeConfig) -> Result<Request<()>, NodeError> {
-    let mut builder = Request::get(format!("ws://{}", state.address));
+/// Return the base URL of a node's websocket or REST API.
+pub(crate) fn base_url(config: &NodeConfig, websocket: bool) -> String {
+    match (config.tls.as_ref(), websocket) {
+        (Some(tls), true) => format!("wss://{}:{}", tls.domain, config.address.port()),
+        (Some(tls), false) => format!("https://{}:{}", tls.domain, config.address.port()),
+        (None, true) => format!("ws://{}", config.address),
+        (None, false) => format!("http://{}", config.address),
+    }
+}
+
+/// Send a REST request to a node, logging the typed error body if it fails.
+///
+/// Returns whether the request succeeded.
+async fn rest(http: &HttpClient, config: &NodeConfig, request: Request<Vec<u8>>) -> bool {
+    let address = config.address;
+
+    let (parts, body) = request.into_parts();
+
+    let response = match http
+        .request(Request::from_parts(parts, Body::from(body)))
+        .await
+    {
+        Ok(response) => response,
+        Err(source) => {
+            tracing::warn!("failed to send request to node {}: {:?}", address, source);
+
+            return false;
+        }
+    };
+
+    let status = response.status();
+
+    if status.is_success() {
+        return true;
+    }
+
+    let body = hyper::body::to_bytes(response.into_body()).await;
+
+    match body.map(|body| serde_json::from_slice::<ErrorResponse>(&body)) {
+        Ok(Ok(error)) => tracing::warn!("request to node {} failed: {}", address, error),
+        _ => tracing::warn!("request to node {} failed with status {}", address, status),
+    }
+
+    false
+}
+
+/// Return the key identifying the resumable session of a node, if resuming is
+/// enabled.
+fn resume_key(config: &NodeConfig) -> Option<String> {
+    let resume = config.resume.as_ref()?;
+
+    Some(
+        resume
+            .key
+            .clone()
+            .unwrap_or_else(|| config.address.to_string()),
+    )
+}
+
+fn connect_request(state: &NodeConfig, session_id: Option<&str>) -> Result<Request<()>, NodeError> {
+    let base = base_url(state, true);
+    let mut builder = match state.protocol {
+        Protocol::V3 => Request::get(base),
+        Protocol::V4 => Request::get(format!("{}/v4/websocket", base)),
+    };
     builder = builder.header("Authorization", &state.authorization);
-    builder = builder.header("Num-Shards", state.shard_count);
     builder = builder.header("User-Id", state.user_id.0);
 
-    if state.resume.is_some() {
-        builder = builder.header("Resume-Key", state.address.to_string());
+    match state.protocol {
+        Protocol::V3 => {
+            builder = builder.header("Num-Shards", state.shard_count);
+
+            if let Some(key) = resume_key(state) {
+                builder = builder.header("Resume-Key", key);
+            }
+        }
+        Protocol::V4 => {
+            builder = builder.header(
+                "Client-Name",
+                concat!("twilight-lavalink/", env!("CARGO_PKG_VERSION")),
+            );
+
+            if let (Some(_), Some(session_id)) = (state.resume.as_ref(), session_id) {
+                builder = builder.header("Session-Id", session_id);
+            }
+        }
     }
 
-    builder
-        .body(())
-        .map_err(|source| NodeError::BuildingConnectionRequest { source })
+    builder.body(()).map_err(|source| NodeError {
+        kind: NodeErrorType::BuildingConnectionRequest,
+        source: Some(Box::new(source)),
+    })
 }
 
-async fn reconnect(config: &NodeConfig) -> Result<WebSocketStream<ConnectStream>, NodeError> {
-    let (mut stream, res) = backoff(config).await?;
+/// Connect to a node, returning the stream and its session.
+///
+/// A version 4 node is resumed by the ID of its previous session, if any.
+///
+/// If the session wasn't resumed and resuming is enabled, then the new session
+/// is configured to be resumable.
+async fn reconnect(
+    config: &NodeConfig,
+    http: &HttpClient,
+    session_id: Option<&str>,
+) -> Result<(WebSocketStream<ConnectStream>, Session), NodeError> {
+    let (mut stream, res) = backoff(config, session_id).await?;
+
+    let session = match config.protocol {
+        Protocol::V3 => {
+            let header = HeaderName::from_static("session-resumed");
+
+            Session {
+                id: None,
+                resumed: matches!(res.headers().get(header), Some(value) if value.as_bytes() == b"true"),
+            }
+        }
+        Protocol::V4 => ready(&mut stream, config).await,
+    };
 
-    let headers = res.headers();
+    if session.resumed {
+        tracing::debug!("session to {} resumed", config.address);
 
-    if let Some(resume) = config.resume.as_ref() {
-        let header = HeaderName::from_static("session-resumed");
+        return Ok((stream, session));
+    }
 
-        if let Some(value) = headers.get(header) {
-            if value.as_bytes() == b"false" {
-                tracing::debug!("session to node {} didn't resume", config.address);
+    tracing::debug!("session to node {} didn't resume", config.address);
 
-                let payload = serde_json::json!({
-                    "op": "configureResuming",
-                    "key": config.address,
-                    "timeout": resume.timeout,
-                });
-                let msg = Message::Text(serde_json::to_string(&payload).unwrap());
+    if let (Protocol::V4, Some(resume), Some(id)) = (
+        config.protocol,
+        config.resume.as_ref(),
+        session.id.as_deref(),
+    ) {
+        let mut update = UpdateSession::default();
+        update.resuming.replace(true);
+        update.timeout.replace(resume.timeout);
 
-                stream.send(msg).await.unwrap();
-            } else {
-                tracing::debug!("session to {} resumed", config.address);
+        match v4::update_session(config, id, &update) {
+            Ok(request) => {
+                rest(http, config, request).await;
             }
+            Err(source) => tracing::warn!(
+                "failed to build request for node {}: {:?}",
+                config.address,
+                source,
+            ),
+        }
+    } else if let (Protocol::V3, Some(resume), Some(key)) =
+        (config.protocol, config.resume.as_ref(), resume_key(config))
+    {
+        let payload = serde_json::json!({
+            "op": "configureResuming",
+            "key": key,
+            "timeout": resume.timeout,
+        });
+        let msg = Message::Text(serde_json::to_string(&payload).unwrap());
+
+        if let Err(source) = stream.send(msg).await {
+            tracing::warn!(
+                "failed to configure resuming for node {}: {:?}",
+                config.address,
+                source,
+            );
         }
     }
 
-    Ok(stream)
+    Ok((stream, session))
+}
+
+/// Wait for a version 4 node to send the details of the new session.
+async fn ready(stream: &mut WebSocketStream<ConnectStream>, config: &NodeConfig) -> Session {
+    let wait = async {
+        while let Some(Ok(message)) = stream.next().await {
+            if let Message::Text(text) = message {
+                if let Some(v4::Message::Ready {
+                    resumed,
+                    session_id,
+                }) = v4::parse_message(&text)
+                {
+                    return Some(Session {
+                        id: Some(session_id),
+                        resumed,
+                    });
+                }
+            }
+        }
+
+        None
+    };
+
+    match tokio_time::timeout(Duration::from_secs(10), wait).await {
+        Ok(Some(session)) => session,
+        _ => {
+            tracing::warn!("node {} didn't send the session details", config.address);
+
+            Session::default()
+        }
+    }
 }
 
 async fn backoff(
     config: &NodeConfig,
+    session_id: Option<&str>,
 ) -> Result<(WebSocketStream<ConnectStream>, Response<()>), NodeError> {
-    let mut seconds = 1;
+    let reconnect = &config.reconnect;
+    let mut attempts = 0;
+    let mut delay = reconnect.initial_delay;
 
     loop {
-        let req = connect_request(config)?;
+        let req = connect_request(config, session_id)?;
 
         match async_tungstenite::tokio::connect_async(req).await {
             Ok((stream, res)) => return Ok((stream, res)),
             Err(source) => {
-                tracing::warn!("failed to connect to node {}: {:?}", source, config.address);
+                tracing::warn!("failed to connect to node {}: {:?}", config.address, source);
 
                 if matches!(source, TungsteniteError::Http(status) if status == StatusCode::UNAUTHORIZED)
                 {
-                    return Err(NodeError::Unauthorized {
-                        address: config.address,
-                        authorization: config.authorization.to_owned(),
+                    return Err(NodeError {
+                        kind: NodeErrorType::Unauthorized {
+                            address: config.address,
+                            authorization: config.authorization.to_owned(),
+                        },
+                        source: None,
                     });
                 }
 
-                if seconds > 64 {
+                attempts += 1;
+
+                if matches!(reconnect.max_attempts, Some(max) if attempts >= max) {
                     tracing::debug!("no longer trying to connect to node {}", config.address);
 
-                    return Err(NodeError::Connecting { source });
+                    return Err(NodeError {
+                        kind: NodeErrorType::Connecting,
+                        source: Some(Box::new(source)),
+                    });
                 }
 
                 tracing::debug!(
-                    "waiting {} sceonds before attempting to connect to node {} again",
-                    seconds,
+                    "waiting {:?} before attempting to connect to node {} again",
+                    delay,
                     config.address,
                 );
-                tokio_time::delay_for(Duration::from_secs(seconds)).await;
+                tokio_time::delay_for(delay).await;
 
-                seconds *= 2;
+                delay = (delay * 2).min(reconnect.max_delay);
 
                 continue;
             }
@@ -567,24 +1158,132 @@ async fn backoff(
 
 #[cfg(test)]
 mod tests {
-    use super::{Node, NodeConfig, NodeError, Resume};
+    use super::{
+        base_url, connect_request, Node, NodeConfig, NodeError, NodeErrorType, Protocol, Reconnect,
+        Resume, Tls,
+    };
+    use crate::model::{Opcode, Stats, StatsCpu, StatsFrames, StatsMemory};
     use static_assertions::{assert_fields, assert_impl_all};
-    use std::{error::Error, fmt::Debug};
+    use std::{error::Error, fmt::Debug, net::SocketAddr};
+    use twilight_model::id::UserId;
 
     assert_fields!(
         NodeConfig: address,
         authorization,
+        protocol,
+        reconnect,
         resume,
         shard_count,
+        tls,
         user_id
     );
     assert_impl_all!(NodeConfig: Clone, Debug, Send, Sync);
-    assert_fields!(NodeError::BuildingConnectionRequest: source);
-    assert_fields!(NodeError::Connecting: source);
-    assert_fields!(NodeError::SerializingMessage: message, source);
-    assert_fields!(NodeError::Unauthorized: address, authorization);
+    assert_fields!(NodeErrorType::SerializingMessage: message);
+    assert_fields!(NodeErrorType::Unauthorized: address, authorization);
+    assert_impl_all!(NodeErrorType: Debug, Send, Sync);
     assert_impl_all!(NodeError: Debug, Error, Send, Sync);
     assert_impl_all!(Node: Clone, Debug, Send, Sync);
-    assert_fields!(Resume: timeout);
+    assert_impl_all!(Protocol: Clone, Copy, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_fields!(
+        Reconnect: initial_delay,
+        max_attempts,
+        max_delay,
+        migrate_players
+    );
+    assert_impl_all!(Reconnect: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_fields!(Resume: key, timeout);
+    assert_fields!(Tls: domain);
+    assert_impl_all!(Tls: Clone, Debug, Eq, PartialEq, Send, Sync);
     assert_impl_all!(Resume: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    fn stats(playing_players: u64, system_load: f64, frames: Option<StatsFrames>) -> Stats {
+        Stats {
+            cpu: StatsCpu {
+                cores: 4,
+                lavalink_load: 0f64,
+                system_load,
+            },
+            frames,
+            memory: StatsMemory {
+                allocated: 0,
+                free: 0,
+                used: 0,
+                reservable: 0,
+            },
+            players: playing_players,
+            playing_players,
+            op: Opcode::Stats,
+            uptime: 0,
+        }
+    }
+
+    #[test]
+    fn test_penalty() {
+        assert_eq!(0, super::penalty(&stats(0, 0f64, None)));
+        assert_eq!(5, super::penalty(&stats(5, 0f64, None)));
+        // 1.05^10 * 10 - 10
+        assert_eq!(6, super::penalty(&stats(0, 0.1, None)));
+
+        let frames = StatsFrames {
+            deficit: 300,
+            nulled: 0,
+            sent: 3000,
+        };
+        // 1.03^50 * 600 - 600
+        assert_eq!(2030, super::penalty(&stats(0, 0f64, Some(frames))));
+
+        let frames = StatsFrames {
+            deficit: 0,
+            nulled: 300,
+            sent: 3000,
+        };
+        // (1.03^50 * 300 - 300) * 2
+        assert_eq!(2030, super::penalty(&stats(0, 0f64, Some(frames))));
+    }
+
+    #[test]
+    fn test_connect_request_resume_key() {
+        let address = SocketAddr::from(([127, 0, 0, 1], 2333));
+        let config = |resume| NodeConfig::new(UserId(1), 1, address, "pass", resume);
+
+        let request = connect_request(&config(None), None).unwrap();
+        assert!(request.headers().get("Resume-Key").is_none());
+
+        let request = connect_request(&config(Some(Resume::default())), None).unwrap();
+        assert_eq!("127.0.0.1:2333", request.headers()["Resume-Key"]);
+
+        let request =
+            connect_request(&config(Some(Resume::new_with_key("bot", 60))), None).unwrap();
+        assert_eq!("bot", request.headers()["Resume-Key"]);
+    }
+
+    #[test]
+    fn test_connect_request_v4() {
+        let address = SocketAddr::from(([127, 0, 0, 1], 2333));
+        let mut config = NodeConfig::new(UserId(1), 1, address, "pass", Resume::default());
+        config.protocol = Protocol::V4;
+
+        let request = connect_request(&config, None).unwrap();
+        assert_eq!("ws://127.0.0.1:2333/v4/websocket", request.uri());
+        assert!(request.headers().get("Resume-Key").is_none());
+        assert!(request.headers().get("Session-Id").is_none());
+
+        let request = connect_request(&config, Some("abc")).unwrap();
+        assert_eq!("abc", request.headers()["Session-Id"]);
+    }
+
+    #[test]
+    fn test_connect_request_tls() {
+        let address = SocketAddr::from(([127, 0, 0, 1], 443));
+        let mut config = NodeConfig::new(UserId(1), 1, address, "pass", None);
+        config.tls.replace(Tls::new("lavalink.example.com"));
+
+        let request = connect_request(&config, None).unwrap();
+        assert_eq!("wss://lavalink.example.com:443/", request.uri());
+        assert_eq!("https://lavalink.example.com:443", base_url(&config, false));
+
+        config.protocol = Protocol::V4;
+        let request = connect_request(&config, None).unwrap();
+        assert_eq!("wss://lavalink.example.com:443/v4/websocket", request.uri());
+    }
 }
@@ -15,16 +15,138 @@ use dashmap::{
     mapref::one::{Ref, RefMut},
     DashMap,
 };
-use futures_channel::mpsc::TrySendError;
+use futures_channel::{
+    mpsc::{self, TrySendError, UnboundedReceiver, UnboundedSender},
+    oneshot::{self, Receiver, Sender},
+};
 use std::{
-    fmt::Debug,
+    convert::TryFrom,
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
     sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc,
+        atomic::{AtomicBool, AtomicU16, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
     },
+    task::{Context, Poll},
+    time::Instant,
 };
 use twilight_model::id::{ChannelId, GuildId};
 
+/// Changing the state of a player failed.
+#[derive(Debug)]
+pub struct PlayerError {
+    kind: PlayerErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl PlayerError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &PlayerErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (PlayerErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for PlayerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            PlayerErrorType::PositionInvalid { position } => {
+                write!(f, "the position {} can't be negative", position)
+            }
+            PlayerErrorType::Sending => f.write_str("failed to send the event to the node"),
+            PlayerErrorType::Unconfirmed => f.write_str("the node didn't confirm the change"),
+            PlayerErrorType::VolumeInvalid { volume } => {
+                write!(f, "the volume {} isn't between 0 and 1000", volume)
+            }
+        }
+    }
+}
+
+impl Error for PlayerError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`PlayerError`] that occurred.
+///
+/// [`PlayerError`]: struct.PlayerError.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PlayerErrorType {
+    /// The position to seek to is negative.
+    PositionInvalid {
+        /// The provided position.
+        position: i64,
+    },
+    /// Sending the event failed because the node's connection was shutdown.
+    Sending,
+    /// The node didn't confirm the change, such as because it rejected the
+    /// request or its connection closed.
+    Unconfirmed,
+    /// The volume isn't in the range of 0 to 1000.
+    VolumeInvalid {
+        /// The provided volume.
+        volume: i64,
+    },
+}
+
+/// A future that resolves once the node confirms a change to a player.
+///
+/// Version 3 nodes confirm a change with the next player update that they send
+/// after receiving it, while version 4 nodes confirm it by acknowledging the
+/// REST request. Player updates are usually sent every few seconds.
+///
+/// The confirmation doesn't need to be awaited; dropping it doesn't affect the
+/// change.
+///
+/// # Errors
+///
+/// Resolves to [`PlayerErrorType::Unconfirmed`] if the node rejected the change or
+/// the connection closed before confirming it.
+///
+/// [`PlayerErrorType::Unconfirmed`]: enum.PlayerErrorType.html#variant.Unconfirmed
+#[derive(Debug)]
+#[must_use = "confirmations do nothing unless awaited"]
+pub struct Confirmation(Receiver<()>);
+
+impl Future for Confirmation {
+    type Output = Result<(), PlayerError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx).map(|result| {
+            result.map_err(|source| PlayerError {
+                kind: PlayerErrorType::Unconfirmed,
+                source: Some(Box::new(source)),
+            })
+        })
+    }
+}
+
+/// An event waiting for the node to confirm it.
+#[derive(Debug)]
+struct Pending {
+    event: OutgoingEvent,
+    sent: bool,
+    tx: Sender<()>,
+}
+
 /// Retrieve and create players for guilds.
 ///
 /// The player manager contains all of the players for all guilds over all
@@ -66,26 +188,34 @@ impl PlayerManager {
 #[derive(Debug)]
 pub struct Player {
     channel_id: Option<ChannelId>,
+    events: Mutex<Vec<UnboundedSender<IncomingEvent>>>,
+    filters: Mutex<Option<Filters>>,
     guild_id: GuildId,
     node: Node,
     paused: AtomicBool,
-    playing: Option<()>,
-    position: i64,
+    pending: Mutex<Vec<Pending>>,
+    position: Mutex<Position>,
     time: i64,
-    volume: u16,
+    track: Mutex<Option<String>>,
+    voice_update: Mutex<Option<VoiceUpdate>>,
+    volume: AtomicU16,
 }
 
 impl Player {
     pub(crate) fn new(guild_id: GuildId, node: Node) -> Self {
         Self {
             channel_id: None,
+            events: Mutex::new(Vec::new()),
+            filters: Mutex::new(None),
             guild_id,
             node,
             paused: AtomicBool::new(false),
-            playing: None,
-            position: 0,
+            pending: Mutex::new(Vec::new()),
+            position: Mutex::new(Position::new(0)),
             time: 0,
-            volume: 0,
+            track: Mutex::new(None),
+            voice_update: Mutex::new(None),
+            volume: AtomicU16::new(100),
         }
     }
 
@@ -121,29 +251,315 @@ impl Player {
     }
 
     fn _send(&self, event: OutgoingEvent) -> Result<(), TrySendError<OutgoingEvent>> {
+        let span = tracing::debug_span!("player send", guild_id = %self.guild_id);
+        let _span_enter = span.enter();
+
         tracing::debug!(
             "sending event on guild player {}: {:?}",
             self.guild_id,
             event
         );
 
-        if let OutgoingEvent::Pause(ref event) = event {
-            self.paused.store(event.pause, Ordering::Release);
+        match event {
+            OutgoingEvent::Destroy(_) => {
+                lock(&self.track).take();
+                lock(&self.voice_update).take();
+            }
+            OutgoingEvent::Filters(ref event) => {
+                lock(&self.filters).replace(Filters::clone(event));
+            }
+            OutgoingEvent::Pause(ref event) => {
+                // Stop or start counting from where the track is now.
+                self.set_position(self.position());
+                self.paused.store(event.pause, Ordering::Release);
+            }
+            OutgoingEvent::Play(ref event) => {
+                let mut track = lock(&self.track);
+
+                // Lavalink ignores the track if it shouldn't replace the
+                // current one.
+                if !event.no_replace || track.is_none() {
+                    track.replace(event.track.clone());
+                    drop(track);
+
+                    let start = event.start_time.and_then(|time| i64::try_from(time).ok());
+                    self.set_position(start.unwrap_or(0));
+                }
+            }
+            OutgoingEvent::Seek(ref event) => {
+                self.set_position(event.position);
+            }
+            OutgoingEvent::Stop(_) => {
+                lock(&self.track).take();
+                self.set_position(0);
+            }
+            OutgoingEvent::VoiceUpdate(ref event) => {
+                lock(&self.voice_update).replace(event.clone());
+            }
+            OutgoingEvent::Volume(ref event) => {
+                let volume = u16::try_from(event.volume.clamp(0, 1000)).unwrap_or_default();
+                self.volume.store(volume, Ordering::Release);
+            }
         }
 
         self.node.send(event)
     }
 
+    /// Return a stream of the events about the player's tracks and voice
+    /// connection.
+    ///
+    /// The stream receives the [`TrackStart`], [`TrackEnd`],
+    /// [`TrackException`], and [`WebSocketClosed`] events of the player's
+    /// guild from its node, as well as [`PluginEvent`]s about the player, in
+    /// addition to the node-wide stream returned when
+    /// adding the node. Each call returns a new stream, and a stream that's
+    /// dropped is removed the next time an event is received.
+    ///
+    /// # Examples
+    ///
+    /// Print the tracks that end in a guild:
+    ///
+    /// ```no_run
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_lavalink::{model::IncomingEvent, Lavalink};
+    /// # use twilight_model::id::{GuildId, UserId};
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let (guild_id, user_id) = (GuildId(1), UserId(2));
+    ///
+    /// let lavalink = Lavalink::new(user_id, 10);
+    /// let mut events = lavalink.player(guild_id).await?.events();
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     if let IncomingEvent::TrackEnd(end) = event {
+    ///         println!("track ended: {}", end.reason);
+    ///     }
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`PluginEvent`]: ../model/incoming/struct.PluginEvent.html
+    /// [`TrackEnd`]: ../model/incoming/struct.TrackEnd.html
+    /// [`TrackException`]: ../model/incoming/struct.TrackException.html
+    /// [`TrackStart`]: ../model/incoming/struct.TrackStart.html
+    /// [`WebSocketClosed`]: ../model/incoming/struct.WebSocketClosed.html
+    pub fn events(&self) -> UnboundedReceiver<IncomingEvent> {
+        let (tx, rx) = mpsc::unbounded();
+        lock(&self.events).push(tx);
+
+        rx
+    }
+
+    /// Send an event to the player's event streams, removing the streams
+    /// that have been dropped.
+    pub(crate) fn dispatch(&self, event: &IncomingEvent) {
+        lock(&self.events).retain(|tx| tx.unbounded_send(event.clone()).is_ok());
+    }
+
     /// Return an immutable reference to the node linked to the player.
     pub fn node(&self) -> &Node {
         &self.node
     }
 
+    /// Link the player to another node, such as when migrating it away from
+    /// a node that went down.
+    pub(crate) fn set_node(&mut self, node: Node) {
+        self.node = node;
+    }
+
     /// Return a copy of the player's channel ID.
     pub fn channel_id(&self) -> Option<ChannelId> {
         self.channel_id.as_ref().copied()
     }
 
+    /// Return a copy of the filters last sent to the player, if any.
+    ///
+    /// Refer to [`Filters`] for how sending filters replaces the previous
+    /// ones.
+    ///
+    /// [`Filters`]: ../model/outgoing/struct.Filters.html
+    pub fn filters(&self) -> Option<Filters> {
+        lock(&self.filters).clone()
+    }
+
+    /// Apply audio filters to the player, replacing the previous filters.
+    ///
+    /// This is a shorthand for [sending] a [`Filters`] event.
+    ///
+    /// Returns a `futures_channel` `TrySendError` if the node has been removed.
+    ///
+    /// # Examples
+    ///
+    /// Speed up the current track:
+    ///
+    /// ```
+    /// use twilight_lavalink::{model::{Filters, Timescale}, Lavalink};
+    /// # use twilight_model::id::{GuildId, UserId};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let (guild_id, user_id) = (GuildId(1), UserId(2));
+    ///
+    /// let lavalink = Lavalink::new(user_id, 10);
+    ///
+    /// if let Some(player) = lavalink.players().get(&guild_id) {
+    ///     let mut filters = Filters::new(guild_id);
+    ///     filters.timescale.replace(Timescale::new(1.25, 1.0, 1.0));
+    ///     player.set_filters(filters)?;
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Filters`]: ../model/outgoing/struct.Filters.html
+    /// [sending]: #method.send
+    pub fn set_filters(&self, filters: Filters) -> Result<(), TrySendError<OutgoingEvent>> {
+        self.send(filters)
+    }
+
+    /// Disable all audio filters of the player.
+    ///
+    /// Returns a `futures_channel` `TrySendError` if the node has been removed.
+    pub fn clear_filters(&self) -> Result<(), TrySendError<OutgoingEvent>> {
+        self.set_filters(Filters::new(self.guild_id))
+    }
+
+    /// Pause or unpause the player, returning a [`Confirmation`] of the change.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlayerErrorType::Sending`] if the node has been removed.
+    ///
+    /// [`Confirmation`]: struct.Confirmation.html
+    /// [`PlayerErrorType::Sending`]: enum.PlayerErrorType.html#variant.Sending
+    pub fn pause(&self, pause: bool) -> Result<Confirmation, PlayerError> {
+        self.send_confirmed(Pause::new(self.guild_id, pause).into())
+    }
+
+    /// Seek the player's track to a position in milliseconds, returning a
+    /// [`Confirmation`] of the change.
+    ///
+    /// # Examples
+    ///
+    /// Seek to the start of the track and wait for the node to confirm it:
+    ///
+    /// ```no_run
+    /// use twilight_lavalink::Lavalink;
+    /// # use twilight_model::id::{GuildId, UserId};
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let (guild_id, user_id) = (GuildId(1), UserId(2));
+    ///
+    /// let lavalink = Lavalink::new(user_id, 10);
+    /// let confirmation = lavalink.player(guild_id).await?.seek(0)?;
+    /// confirmation.await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlayerErrorType::PositionInvalid`] if the position is negative.
+    ///
+    /// Returns [`PlayerErrorType::Sending`] if the node has been removed.
+    ///
+    /// [`Confirmation`]: struct.Confirmation.html
+    /// [`PlayerErrorType::PositionInvalid`]: enum.PlayerErrorType.html#variant.PositionInvalid
+    /// [`PlayerErrorType::Sending`]: enum.PlayerErrorType.html#variant.Sending
+    pub fn seek(&self, position: i64) -> Result<Confirmation, PlayerError> {
+        if position < 0 {
+            return Err(PlayerError {
+                kind: PlayerErrorType::PositionInvalid { position },
+                source: None,
+            });
+        }
+
+        self.send_confirmed(Seek::new(self.guild_id, position).into())
+    }
+
+    /// Set the volume of the player, returning a [`Confirmation`] of the
+    /// change.
+    ///
+    /// The volume is a percentage from 0 to 1000, where 100 is the default.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlayerErrorType::VolumeInvalid`] if the volume isn't in the range.
+    ///
+    /// Returns [`PlayerErrorType::Sending`] if the node has been removed.
+    ///
+    /// [`Confirmation`]: struct.Confirmation.html
+    /// [`PlayerErrorType::Sending`]: enum.PlayerErrorType.html#variant.Sending
+    /// [`PlayerErrorType::VolumeInvalid`]: enum.PlayerErrorType.html#variant.VolumeInvalid
+    pub fn set_volume(&self, volume: i64) -> Result<Confirmation, PlayerError> {
+        if !(0..=1000).contains(&volume) {
+            return Err(PlayerError {
+                kind: PlayerErrorType::VolumeInvalid { volume },
+                source: None,
+            });
+        }
+
+        self.send_confirmed(Volume::new(self.guild_id, volume).into())
+    }
+
+    fn send_confirmed(&self, event: OutgoingEvent) -> Result<Confirmation, PlayerError> {
+        let (tx, rx) = oneshot::channel();
+
+        // Wait for the confirmation before sending the event, since the node
+        // may send it right away.
+        let mut pending = lock(&self.pending);
+        pending.retain(|pending| !pending.tx.is_canceled());
+        pending.push(Pending {
+            event: event.clone(),
+            sent: false,
+            tx,
+        });
+        drop(pending);
+
+        // If sending fails, then the receiver is dropped and the pending
+        // event is removed later.
+        self._send(event).map_err(|source| PlayerError {
+            kind: PlayerErrorType::Sending,
+            source: Some(Box::new(source)),
+        })?;
+
+        Ok(Confirmation(rx))
+    }
+
+    /// Mark an event as sent over the websocket of a version 3 node, so that
+    /// the next player update confirms it.
+    pub(crate) fn event_sent(&self, event: &OutgoingEvent) {
+        let mut pending = lock(&self.pending);
+
+        if let Some(pending) = pending
+            .iter_mut()
+            .find(|pending| !pending.sent && pending.event == *event)
+        {
+            pending.sent = true;
+        }
+    }
+
+    /// Confirm or reject an event once a version 4 node responded to its
+    /// request.
+    pub(crate) fn event_acknowledged(&self, event: &OutgoingEvent, success: bool) {
+        let mut pending = lock(&self.pending);
+
+        if let Some(index) = pending.iter().position(|pending| pending.event == *event) {
+            let pending = pending.remove(index);
+
+            if success {
+                let _ = pending.tx.send(());
+            }
+        }
+    }
+
+    /// Confirm the events that were sent before a player update was received.
+    fn confirm_sent(&self) {
+        let mut pending = lock(&self.pending);
+        let (sent, waiting) = pending
+            .drain(..)
+            .partition::<Vec<_>, _>(|pending| pending.sent);
+        *pending = waiting;
+
+        for pending in sent {
+            let _ = pending.tx.send(());
+        }
+    }
+
     /// Return an copy of the player's guild ID.
     pub fn guild_id(&self) -> GuildId {
         self.guild_id
@@ -154,14 +570,30 @@ impl Player {
         self.paused.load(Ordering::Acquire)
     }
 
-    /// Return a copy of the player's position.
+    /// Return an estimate of the position of the player's track in
+    /// milliseconds.
+    ///
+    /// Nodes only send the position in player updates every few seconds, so
+    /// the time elapsed since the last update is added to it while a track is
+    /// playing and the player isn't paused. Playing, seeking, pausing, and
+    /// stopping through the player also update the position.
     pub fn position(&self) -> i64 {
-        self.position
+        let playing = !self.paused() && self.track().is_some();
+
+        lock(&self.position).estimate(Instant::now(), playing)
     }
 
-    /// Return a mmutable reference to the player's channel ID.
-    pub(crate) fn position_mut(&mut self) -> &mut i64 {
-        &mut self.position
+    fn set_position(&self, position: i64) {
+        *lock(&self.position) = Position::new(position);
+    }
+
+    /// Update the position and time from a player update of the node.
+    ///
+    /// This also confirms the events that were sent before the update.
+    pub(crate) fn update_position(&mut self, position: i64, time: i64) {
+        self.set_position(position);
+        self.time = time;
+        self.confirm_sent();
     }
 
     /// Return a copy of the player's time.
@@ -169,23 +601,123 @@ impl Player {
         self.time
     }
 
-    /// Return a mutable reference to the player's channel ID.
-    pub(crate) fn time_mut(&mut self) -> &mut i64 {
-        &mut self.time
+    /// Return a copy of the track that the player was last told to play, if
+    /// it hasn't ended or been stopped since.
+    pub fn track(&self) -> Option<String> {
+        lock(&self.track).clone()
+    }
+
+    /// Return a copy of the voice update last sent to the player, if any.
+    pub(crate) fn voice_update(&self) -> Option<VoiceUpdate> {
+        lock(&self.voice_update).clone()
     }
 
     /// Return a copy of the player's volume.
     pub fn volume_ref(&self) -> u16 {
-        self.volume
+        self.volume.load(Ordering::Acquire)
+    }
+
+    /// Clear the player's track if it's the track that ended.
+    pub(crate) fn track_ended(&self, track: &str) {
+        let mut current = lock(&self.track);
+
+        if current.as_deref() == Some(track) {
+            current.take();
+        }
+    }
+
+    /// Return the events needed to restore the player's state on a new
+    /// session of its node.
+    ///
+    /// Nothing is replayed if the player never had a voice connection.
+    pub(crate) fn replay_events(&self) -> Vec<OutgoingEvent> {
+        let voice_update = match lock(&self.voice_update).clone() {
+            Some(voice_update) => voice_update,
+            None => return Vec::new(),
+        };
+
+        let mut events = vec![OutgoingEvent::from(voice_update)];
+        let paused = self.paused();
+
+        if let Some(track) = self.track() {
+            let position = u64::try_from(self.position()).ok();
+
+            events.push(Play::new(self.guild_id, track, position, None, false).into());
+
+            if paused {
+                events.push(Pause::new(self.guild_id, true).into());
+            }
+        }
+
+        let volume = self.volume_ref();
+
+        if volume != 100 {
+            events.push(Volume::new(self.guild_id, i64::from(volume)).into());
+        }
+
+        if let Some(filters) = self.filters() {
+            events.push(filters.into());
+        }
+
+        events
+    }
+}
+
+/// Lock a mutex, recovering it if it was poisoned.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A position of a player's track and when it was known.
+#[derive(Debug)]
+struct Position {
+    at: Instant,
+    position: i64,
+}
+
+impl Position {
+    fn new(position: i64) -> Self {
+        Self {
+            at: Instant::now(),
+            position,
+        }
+    }
+
+    /// Estimate the position at a point in time, counting the time elapsed
+    /// since the position was known if the track was playing.
+    fn estimate(&self, now: Instant, playing: bool) -> i64 {
+        if !playing {
+            return self.position;
+        }
+
+        let elapsed = now.saturating_duration_since(self.at).as_millis();
+
+        self.position
+            .saturating_add(i64::try_from(elapsed).unwrap_or(i64::MAX))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Player, PlayerManager};
-    use static_assertions::assert_impl_all;
-    use std::fmt::Debug;
+    use super::{Confirmation, Player, PlayerError, PlayerErrorType, PlayerManager, Position};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug, time::Duration};
 
+    assert_impl_all!(Confirmation: Debug, Send, Sync);
+    assert_fields!(PlayerErrorType::PositionInvalid: position);
+    assert_fields!(PlayerErrorType::VolumeInvalid: volume);
+    assert_impl_all!(PlayerErrorType: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(PlayerError: Debug, Error, Send, Sync);
     assert_impl_all!(PlayerManager: Clone, Debug, Default, Send, Sync);
     assert_impl_all!(Player: Debug, Send, Sync);
+
+    #[test]
+    fn test_position_estimate() {
+        let position = Position::new(5000);
+        let later = position.at + Duration::from_millis(1500);
+
+        assert_eq!(6500, position.estimate(later, true));
+        assert_eq!(5000, position.estimate(later, false));
+        assert_eq!(5000, position.estimate(position.at, true));
+    }
 }
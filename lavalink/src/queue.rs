@@ -0,0 +1,636 @@
+//! Per-guild queues of tracks layered on top of [players].
+//!
+//! The [`QueueManager`] holds a [`Queue`] of tracks for each guild and plays
+//! them in order on the guild's player. Tracks are added with
+//! [`QueueManager::enqueue`], and the next track is played automatically when
+//! the current one finishes, as long as the incoming events of nodes are
+//! passed to [`QueueManager::process`].
+//!
+//! Using queues is optional: players can still be controlled directly, but
+//! events sent directly to a player aren't reflected in its queue.
+//!
+//! [players]: ../player/struct.Player.html
+//! [`Queue`]: struct.Queue.html
+//! [`QueueManager`]: struct.QueueManager.html
+//! [`QueueManager::enqueue`]: struct.QueueManager.html#method.enqueue
+//! [`QueueManager::process`]: struct.QueueManager.html#method.process
+
+use crate::{
+    model::{IncomingEvent, OutgoingEvent, Play, Stop, TrackEnd},
+    player::PlayerManager,
+};
+use dashmap::{mapref::one::Ref, DashMap};
+use std::{
+    collections::{hash_map::RandomState, VecDeque},
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::{BuildHasher, Hasher},
+    sync::Arc,
+};
+use twilight_model::id::GuildId;
+
+/// An error that can occur while managing a queue.
+#[derive(Debug)]
+pub struct QueueError {
+    kind: QueueErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl QueueError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &QueueErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (QueueErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for QueueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            QueueErrorType::PlayerMissing { guild_id } => {
+                f.write_fmt(format_args!("guild {} has no player", guild_id))
+            }
+            QueueErrorType::Sending => f.write_str("couldn't send event to node"),
+        }
+    }
+}
+
+impl Error for QueueError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`QueueError`] that occurred.
+///
+/// [`QueueError`]: struct.QueueError.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum QueueErrorType {
+    /// The guild doesn't have a player to play the queue's tracks on.
+    PlayerMissing {
+        /// The ID of the guild.
+        guild_id: GuildId,
+    },
+    /// Sending an event to the player's node failed because the node's
+    /// connection was shutdown.
+    Sending,
+}
+
+/// How a queue continues after its current track ends.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LoopMode {
+    /// Play each track once, stopping when the queue is empty.
+    None,
+    /// Repeat the current track when it finishes.
+    ///
+    /// Skipping still advances to the next track.
+    Track,
+    /// Move each track to the back of the queue when it ends, repeating the
+    /// whole queue.
+    Queue,
+}
+
+impl Default for LoopMode {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// A queue of tracks for a guild.
+///
+/// Tracks are the base64 encoded tracks returned when [loading tracks].
+///
+/// [loading tracks]: ../http/fn.load_track.html
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Queue {
+    current: Option<String>,
+    loop_mode: LoopMode,
+    tracks: VecDeque<String>,
+}
+
+impl Queue {
+    /// Create a new, empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the track that is currently playing, if any.
+    pub fn current(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    /// Return the loop mode of the queue.
+    pub fn loop_mode(&self) -> LoopMode {
+        self.loop_mode
+    }
+
+    /// Return the upcoming tracks, in the order they'll be played.
+    ///
+    /// This doesn't include the current track.
+    pub fn tracks(&self) -> &VecDeque<String> {
+        &self.tracks
+    }
+
+    /// Add a track to the back of the queue.
+    ///
+    /// Returns whether the next track became the current track because nothing
+    /// was playing, meaning that it needs to be played.
+    fn enqueue(&mut self, track: String) -> bool {
+        self.tracks.push_back(track);
+
+        if self.current.is_none() {
+            self.current = self.tracks.pop_front();
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Advance the queue to the next track according to its loop mode,
+    /// returning the new current track.
+    ///
+    /// If the current track finished on its own then it's repeated in the
+    /// [`LoopMode::Track`] mode; otherwise, such as when skipping, the queue
+    /// always moves on.
+    ///
+    /// [`LoopMode::Track`]: enum.LoopMode.html#variant.Track
+    fn advance(&mut self, finished: bool) -> Option<&str> {
+        match (self.loop_mode, self.current.take()) {
+            (LoopMode::Track, Some(current)) if finished => {
+                self.current.replace(current);
+            }
+            (LoopMode::Queue, Some(current)) => {
+                self.tracks.push_back(current);
+                self.current = self.tracks.pop_front();
+            }
+            _ => {
+                self.current = self.tracks.pop_front();
+            }
+        }
+
+        self.current()
+    }
+
+    /// Drop the current track without looping it, returning the next track.
+    ///
+    /// This is used for tracks that failed to load, which would fail again if
+    /// they were repeated.
+    fn discard(&mut self) -> Option<&str> {
+        self.current = self.tracks.pop_front();
+
+        self.current()
+    }
+
+    /// Move the current track back to the front of the upcoming tracks.
+    ///
+    /// This is used when playing the current track failed, so that it's played
+    /// next instead of being left as a current track that never ends.
+    fn requeue(&mut self) {
+        if let Some(current) = self.current.take() {
+            self.tracks.push_front(current);
+        }
+    }
+
+    /// Shuffle the upcoming tracks.
+    fn shuffle(&mut self) {
+        // Seed a xorshift generator from the randomly keyed standard hasher
+        // to avoid depending on a random number generator.
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_usize(self.tracks.len());
+        let mut state = hasher.finish() | 1;
+
+        // Fisher-Yates shuffle.
+        for idx in (1..self.tracks.len()).rev() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            #[allow(clippy::cast_possible_truncation)]
+            let other = (state % (idx as u64 + 1)) as usize;
+            self.tracks.swap(idx, other);
+        }
+    }
+}
+
+/// Manage the queues of tracks of guilds, playing them on guilds' players.
+///
+/// Refer to the [module] documentation for more information.
+///
+/// # Examples
+///
+/// Queue two tracks, starting the first:
+///
+/// ```no_run
+/// use twilight_lavalink::{queue::QueueManager, Lavalink};
+/// # use twilight_model::id::{GuildId, UserId};
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let (guild_id, user_id) = (GuildId(1), UserId(2));
+/// # let (track_a, track_b) = (String::new(), String::new());
+///
+/// let lavalink = Lavalink::new(user_id, 10);
+/// let queues = QueueManager::new(lavalink.players().clone());
+///
+/// queues.enqueue(guild_id, track_a)?;
+/// queues.enqueue(guild_id, track_b)?;
+/// # Ok(()) }
+/// ```
+///
+/// [module]: index.html
+#[derive(Clone, Debug)]
+pub struct QueueManager {
+    players: PlayerManager,
+    queues: Arc<DashMap<GuildId, Queue>>,
+}
+
+impl QueueManager {
+    /// Create a new queue manager playing tracks on the players of a player
+    /// manager.
+    pub fn new(players: PlayerManager) -> Self {
+        Self {
+            players,
+            queues: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Return an immutable reference to the queue of a guild.
+    pub fn get(&self, guild_id: &GuildId) -> Option<Ref<'_, GuildId, Queue>> {
+        self.queues.get(guild_id)
+    }
+
+    /// Add a track to the back of a guild's queue.
+    ///
+    /// If nothing is playing in the guild's queue then the next track is played
+    /// immediately, which is this track unless an earlier track failed to play.
+    /// If playing it fails then the track isn't added.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueErrorType::PlayerMissing`] if the track needs to be played
+    /// but the guild doesn't have a player.
+    ///
+    /// Returns [`QueueErrorType::Sending`] if sending the event to play the track
+    /// failed.
+    ///
+    /// [`QueueErrorType::PlayerMissing`]: enum.QueueErrorType.html#variant.PlayerMissing
+    /// [`QueueErrorType::Sending`]: enum.QueueErrorType.html#variant.Sending
+    pub fn enqueue(&self, guild_id: GuildId, track: impl Into<String>) -> Result<(), QueueError> {
+        let mut queue = self.queues.entry(guild_id).or_default();
+
+        if queue.enqueue(track.into()) {
+            // Don't leave the track as the current track if it couldn't be
+            // played, or later tracks would only be queued behind it.
+            if let Err(source) = self.play(guild_id, queue.current()) {
+                queue.requeue();
+                queue.tracks.pop_back();
+
+                return Err(source);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Skip the current track of a guild's queue, playing the next track.
+    ///
+    /// If there is no next track then the player is stopped. Returns the new
+    /// current track, if any. If playing the next track or stopping the player
+    /// fails then the queue is left as it was.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueErrorType::PlayerMissing`] if the guild doesn't have a
+    /// player.
+    ///
+    /// Returns [`QueueErrorType::Sending`] if sending the event to play the next
+    /// track or stop the player failed.
+    ///
+    /// [`QueueErrorType::PlayerMissing`]: enum.QueueErrorType.html#variant.PlayerMissing
+    /// [`QueueErrorType::Sending`]: enum.QueueErrorType.html#variant.Sending
+    pub fn skip(&self, guild_id: GuildId) -> Result<Option<String>, QueueError> {
+        let mut queue = self.queues.entry(guild_id).or_default();
+        let previous = queue.clone();
+        let next = queue.advance(false).map(ToOwned::to_owned);
+
+        // The current track is still playing if the next one couldn't be, so
+        // keep it current to advance the queue when it ends.
+        if let Err(source) = self.play(guild_id, next.as_deref()) {
+            *queue = previous;
+
+            return Err(source);
+        }
+
+        Ok(next)
+    }
+
+    /// Shuffle the upcoming tracks of a guild's queue.
+    pub fn shuffle(&self, guild_id: GuildId) {
+        if let Some(mut queue) = self.queues.get_mut(&guild_id) {
+            queue.shuffle();
+        }
+    }
+
+    /// Set the loop mode of a guild's queue.
+    pub fn set_loop_mode(&self, guild_id: GuildId, loop_mode: LoopMode) {
+        self.queues.entry(guild_id).or_default().loop_mode = loop_mode;
+    }
+
+    /// Remove a guild's queue, returning it if it existed.
+    ///
+    /// This doesn't stop the current track.
+    pub fn remove(&self, guild_id: &GuildId) -> Option<Queue> {
+        self.queues.remove(guild_id).map(|(_, queue)| queue)
+    }
+
+    /// Process an incoming event from a node, advancing the queue of a guild
+    /// when its current track finishes.
+    ///
+    /// Tracks stopped or replaced by another track don't advance the queue,
+    /// but tracks that fail to load do, and are removed from the queue
+    /// regardless of its loop mode.
+    ///
+    /// If playing the next track fails then it's moved back to the front of the
+    /// queue, and is played by the next call to [`enqueue`] or [`skip`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueueErrorType::PlayerMissing`] if the guild doesn't have a
+    /// player.
+    ///
+    /// Returns [`QueueErrorType::Sending`] if sending the event to play the next
+    /// track failed.
+    ///
+    /// [`QueueErrorType::PlayerMissing`]: enum.QueueErrorType.html#variant.PlayerMissing
+    /// [`QueueErrorType::Sending`]: enum.QueueErrorType.html#variant.Sending
+    /// [`enqueue`]: #method.enqueue
+    /// [`skip`]: #method.skip
+    pub fn process(&self, event: &IncomingEvent) -> Result<(), QueueError> {
+        let event = match event {
+            IncomingEvent::TrackEnd(event) if may_start_next(event) => event,
+            _ => return Ok(()),
+        };
+
+        let mut queue = match self.queues.get_mut(&event.guild_id) {
+            Some(queue) if queue.current() == Some(event.track.as_str()) => queue,
+            _ => return Ok(()),
+        };
+
+        let next = if event.reason == "LOAD_FAILED" {
+            queue.discard()
+        } else {
+            queue.advance(true)
+        };
+
+        if next.is_some() {
+            if let Err(source) = self.play(event.guild_id, next) {
+                queue.requeue();
+
+                return Err(source);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Play a track on a guild's player, or stop it if there's no track.
+    fn play(&self, guild_id: GuildId, track: Option<&str>) -> Result<(), QueueError> {
+        let player = self.players.get(&guild_id).ok_or(QueueError {
+            kind: QueueErrorType::PlayerMissing { guild_id },
+            source: None,
+        })?;
+
+        let event = match track {
+            Some(track) => OutgoingEvent::from(Play::from((guild_id, track))),
+            None => OutgoingEvent::from(Stop::from(guild_id)),
+        };
+
+        player.send(event).map_err(|source| QueueError {
+            kind: QueueErrorType::Sending,
+            source: Some(Box::new(source)),
+        })
+    }
+}
+
+/// Whether a track ended in a way that the next track should be played.
+fn may_start_next(event: &TrackEnd) -> bool {
+    matches!(event.reason.as_str(), "FINISHED" | "LOAD_FAILED")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LoopMode, Queue, QueueError, QueueErrorType, QueueManager};
+    use crate::{
+        model::{IncomingEvent, Opcode, TrackEnd, TrackEventType},
+        player::PlayerManager,
+    };
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{collections::HashSet, error::Error, fmt::Debug};
+    use twilight_model::id::GuildId;
+
+    assert_fields!(QueueErrorType::PlayerMissing: guild_id);
+    assert_impl_all!(QueueErrorType: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(QueueError: Debug, Error, Send, Sync);
+    assert_impl_all!(LoopMode: Clone, Copy, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(Queue: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(QueueManager: Clone, Debug, Send, Sync);
+
+    fn queue(tracks: &[&str]) -> Queue {
+        let mut queue = Queue::new();
+
+        for track in tracks {
+            queue.enqueue((*track).to_owned());
+        }
+
+        queue
+    }
+
+    #[test]
+    fn test_enqueue() {
+        let mut queue = Queue::new();
+        assert!(queue.enqueue("a".to_owned()));
+        assert!(!queue.enqueue("b".to_owned()));
+        assert_eq!(Some("a"), queue.current());
+        assert_eq!(vec!["b"], Vec::from(queue.tracks().clone()));
+    }
+
+    #[test]
+    fn test_enqueue_player_missing() {
+        let manager = QueueManager::new(PlayerManager::new());
+        let error = manager.enqueue(GuildId(1), "a").unwrap_err();
+
+        assert_eq!(
+            &QueueErrorType::PlayerMissing {
+                guild_id: GuildId(1)
+            },
+            error.kind()
+        );
+        assert!(error.into_source().is_none());
+
+        let queue = manager.get(&GuildId(1)).unwrap();
+        assert!(queue.current().is_none());
+        assert!(queue.tracks().is_empty());
+    }
+
+    #[test]
+    fn test_enqueue_player_missing_keeps_order() {
+        let manager = QueueManager::new(PlayerManager::new());
+        let mut queue = queue(&["a"]);
+        queue.requeue();
+        manager.queues.insert(GuildId(1), queue);
+
+        assert!(manager.enqueue(GuildId(1), "b").is_err());
+
+        let queue = manager.get(&GuildId(1)).unwrap();
+        assert!(queue.current().is_none());
+        assert_eq!(vec!["a"], Vec::from(queue.tracks().clone()));
+    }
+
+    #[test]
+    fn test_requeue() {
+        let mut queue = queue(&["a", "b"]);
+        queue.requeue();
+        assert!(queue.current().is_none());
+        assert_eq!(vec!["a", "b"], Vec::from(queue.tracks().clone()));
+
+        // The requeued track is played before newly added ones.
+        assert!(queue.enqueue("c".to_owned()));
+        assert_eq!(Some("a"), queue.current());
+        assert_eq!(vec!["b", "c"], Vec::from(queue.tracks().clone()));
+    }
+
+    #[test]
+    fn test_skip_player_missing() {
+        let manager = QueueManager::new(PlayerManager::new());
+        let mut queue = queue(&["a", "b"]);
+        queue.loop_mode = LoopMode::Queue;
+        manager.queues.insert(GuildId(1), queue);
+
+        let error = manager.skip(GuildId(1)).unwrap_err();
+        assert_eq!(
+            &QueueErrorType::PlayerMissing {
+                guild_id: GuildId(1)
+            },
+            error.kind()
+        );
+
+        let queue = manager.get(&GuildId(1)).unwrap();
+        assert_eq!(Some("a"), queue.current());
+        assert_eq!(vec!["b"], Vec::from(queue.tracks().clone()));
+    }
+
+    #[test]
+    fn test_advance() {
+        let mut queue = queue(&["a", "b"]);
+        assert_eq!(Some("b"), queue.advance(true));
+        assert_eq!(None, queue.advance(true));
+        assert_eq!(None, queue.advance(true));
+    }
+
+    #[test]
+    fn test_advance_loop_track() {
+        let mut queue = queue(&["a", "b"]);
+        queue.loop_mode = LoopMode::Track;
+        assert_eq!(Some("a"), queue.advance(true));
+        // Skipping moves on even when looping the track.
+        assert_eq!(Some("b"), queue.advance(false));
+        assert_eq!(Some("b"), queue.advance(true));
+    }
+
+    #[test]
+    fn test_advance_loop_queue() {
+        let mut queue = queue(&["a", "b"]);
+        queue.loop_mode = LoopMode::Queue;
+        assert_eq!(Some("b"), queue.advance(true));
+        assert_eq!(Some("a"), queue.advance(false));
+        assert_eq!(Some("b"), queue.advance(true));
+    }
+
+    #[test]
+    fn test_discard() {
+        let mut queue = queue(&["a", "b"]);
+        queue.loop_mode = LoopMode::Queue;
+        assert_eq!(Some("b"), queue.discard());
+        assert_eq!(None, queue.discard());
+        assert!(queue.tracks().is_empty());
+    }
+
+    #[test]
+    fn test_process_load_failed() {
+        let manager = QueueManager::new(PlayerManager::new());
+        let mut queue = queue(&["a"]);
+        queue.loop_mode = LoopMode::Track;
+        manager.queues.insert(GuildId(1), queue);
+
+        let event = IncomingEvent::TrackEnd(TrackEnd {
+            guild_id: GuildId(1),
+            kind: TrackEventType::End,
+            op: Opcode::Event,
+            reason: "LOAD_FAILED".to_owned(),
+            track: "a".to_owned(),
+        });
+        assert!(manager.process(&event).is_ok());
+
+        let queue = manager.get(&GuildId(1)).unwrap();
+        assert!(queue.current().is_none());
+        assert!(queue.tracks().is_empty());
+    }
+
+    #[test]
+    fn test_process_player_missing() {
+        let manager = QueueManager::new(PlayerManager::new());
+        manager.queues.insert(GuildId(1), queue(&["a", "b", "c"]));
+
+        let event = IncomingEvent::TrackEnd(TrackEnd {
+            guild_id: GuildId(1),
+            kind: TrackEventType::End,
+            op: Opcode::Event,
+            reason: "FINISHED".to_owned(),
+            track: "a".to_owned(),
+        });
+        let error = manager.process(&event).unwrap_err();
+        assert_eq!(
+            &QueueErrorType::PlayerMissing {
+                guild_id: GuildId(1)
+            },
+            error.kind()
+        );
+
+        let queue = manager.get(&GuildId(1)).unwrap();
+        assert!(queue.current().is_none());
+        assert_eq!(vec!["b", "c"], Vec::from(queue.tracks().clone()));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let tracks = (0..50).map(|n| n.to_string()).collect::<Vec<_>>();
+        let mut queue = Queue::new();
+
+        for track in &tracks {
+            queue.enqueue(track.clone());
+        }
+
+        queue.shuffle();
+        assert_eq!(Some("0"), queue.current());
+
+        let shuffled = queue.tracks().iter().cloned().collect::<HashSet<_>>();
+        assert_eq!(
+            tracks[1..].iter().cloned().collect::<HashSet<_>>(),
+            shuffled
+        );
+    }
+}
@@ -0,0 +1,830 @@
+//! Models and requests for version 4 of the Lavalink API.
+//!
+//! In version 4 players are controlled through the REST API of a session
+//! instead of websocket messages, and the websocket only receives events.
+//! Nodes configured to use [`Protocol::V4`] translate [outgoing events] into
+//! these requests and version 4 events into [incoming events] automatically,
+//! so the rest of the crate works the same with either version.
+//!
+//! The models and functions here are for making requests to a node directly,
+//! such as loading tracks, which has a different response body than in
+//! version 3.
+//!
+//! [`Protocol::V4`]: ../node/enum.Protocol.html#variant.V4
+//! [incoming events]: ../model/incoming/enum.IncomingEvent.html
+//! [outgoing events]: ../model/outgoing/enum.OutgoingEvent.html
+
+use crate::{
+    model::{
+        ChannelMix, Distortion, EqualizerBand, Filters, IncomingEvent, Karaoke, LowPass, Opcode,
+        OutgoingEvent, Rotation, Timescale, TrackEnd, TrackEventType, TrackException, TrackStart,
+        Tremolo, Vibrato, WebSocketClosed,
+    },
+    node::{self, NodeConfig},
+};
+use http::{
+    header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE},
+    Error as HttpError, Method, Request,
+};
+#[cfg(feature = "http-support")]
+use percent_encoding::NON_ALPHANUMERIC;
+use serde::{Deserialize, Serialize, Serializer};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::id::GuildId;
+
+/// The body of an error response from the REST API.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorResponse {
+    /// The reason phrase of the status code, such as `"Not Found"`.
+    pub error: String,
+    /// A description of the error.
+    pub message: String,
+    /// The path of the request.
+    pub path: String,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The time that the error occurred in Unix milliseconds.
+    pub timestamp: u64,
+    /// The stack trace of the error, only included if the request had the
+    /// `trace` query parameter set.
+    #[serde(default)]
+    pub trace: Option<String>,
+}
+
+impl Display for ErrorResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_fmt(format_args!("{} {}: ", self.status, self.error))?;
+
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for ErrorResponse {}
+
+/// The result of loading tracks, as returned by [`load_track`].
+///
+/// [`load_track`]: fn.load_track.html
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(content = "data", rename_all = "camelCase", tag = "loadType")]
+pub enum LoadResult {
+    /// There were no matches.
+    Empty {},
+    /// Loading the tracks failed.
+    Error(Exception),
+    /// A playlist was found.
+    Playlist(Playlist),
+    /// Search results were found.
+    Search(Vec<Track>),
+    /// A single track was found.
+    Track(Track),
+}
+
+/// An exception that occurred while loading or playing a track.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct Exception {
+    /// The cause of the exception.
+    pub cause: String,
+    /// The message of the exception, if any.
+    pub message: Option<String>,
+    /// How severe the exception is.
+    pub severity: Severity,
+}
+
+/// How severe an exception is.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+    /// The cause is known and expected, such as a track being unavailable.
+    Common,
+    /// The cause might not be exactly known, but is possibly caused by
+    /// outside factors.
+    Suspicious,
+    /// The cause is unknown, possibly being a bug in Lavalink.
+    Fault,
+}
+
+/// A loaded playlist.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct Playlist {
+    /// Information about the playlist.
+    pub info: PlaylistInfo,
+    /// The tracks of the playlist.
+    pub tracks: Vec<Track>,
+}
+
+/// Information about a playlist.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistInfo {
+    /// The name of the playlist.
+    pub name: String,
+    /// The index of the selected track, or -1 if no track is selected.
+    pub selected_track: i64,
+}
+
+/// A loaded track.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct Track {
+    /// The base64 encoded track to use in the [`Play`] event.
+    ///
+    /// [`Play`]: ../model/outgoing/struct.Play.html
+    pub encoded: String,
+    /// Details about the track, such as the author and title.
+    pub info: TrackInfo,
+}
+
+/// Details about a track, such as the author and title.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct TrackInfo {
+    /// The URL of the track's artwork, if any.
+    #[serde(default)]
+    pub artwork_url: Option<String>,
+    /// The name of the author.
+    pub author: String,
+    /// The identifier of the track within its source.
+    pub identifier: String,
+    /// Whether the track can be seeked.
+    pub is_seekable: bool,
+    /// Whether the track is a stream.
+    pub is_stream: bool,
+    /// The International Standard Recording Code of the track, if any.
+    #[serde(default)]
+    pub isrc: Option<String>,
+    /// The length of the track in milliseconds.
+    pub length: u64,
+    /// The position of the track in milliseconds.
+    pub position: u64,
+    /// The name of the source of the track, such as `"youtube"`.
+    pub source_name: String,
+    /// The title of the track.
+    pub title: String,
+    /// The URI of the track, if any.
+    pub uri: Option<String>,
+}
+
+/// The state of a session, as returned when updating it.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct Session {
+    /// Whether the session can be resumed.
+    pub resuming: bool,
+    /// The number of seconds that the session can be resumed for after a
+    /// disconnect.
+    pub timeout: u64,
+}
+
+/// Update the resuming configuration of a session.
+///
+/// Properties that aren't set are left unchanged.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSession {
+    /// Whether the session can be resumed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resuming: Option<bool>,
+    /// The number of seconds that the session can be resumed for after a
+    /// disconnect.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+}
+
+/// Update a player, creating it if it doesn't exist.
+///
+/// Properties that aren't set are left unchanged.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePlayer {
+    /// The position in milliseconds to end the track.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<u64>,
+    /// The filters to apply, replacing all previously applied filters.
+    ///
+    /// Only the filters of the event are used; its guild ID is ignored.
+    #[serde(
+        serialize_with = "serialize_filters",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub filters: Option<Filters>,
+    /// Whether the player is paused.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paused: Option<bool>,
+    /// The position in milliseconds to seek the track to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u64>,
+    /// The track to play, or stop playing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track: Option<UpdatePlayerTrack>,
+    /// The voice connection of the player.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice: Option<VoiceState>,
+    /// The volume of the player from 0 to 1000.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume: Option<i64>,
+}
+
+/// The track of a player update.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePlayerTrack {
+    /// The base64 encoded track to play, or `None` to stop the player.
+    pub encoded: Option<String>,
+}
+
+impl UpdatePlayerTrack {
+    /// Create a new track update, playing the track if one is provided and
+    /// otherwise stopping the player.
+    pub fn new(encoded: impl Into<Option<String>>) -> Self {
+        Self {
+            encoded: encoded.into(),
+        }
+    }
+}
+
+/// The voice connection of a player.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct VoiceState {
+    /// The endpoint of the Discord voice server.
+    pub endpoint: String,
+    /// The session ID of the voice connection.
+    pub session_id: String,
+    /// The authentication token of the Discord voice server.
+    pub token: String,
+}
+
+impl VoiceState {
+    /// Create a new voice state.
+    pub fn new(
+        endpoint: impl Into<String>,
+        session_id: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            session_id: session_id.into(),
+            token: token.into(),
+        }
+    }
+}
+
+/// The filters of a [`Filters`] event without its guild ID and opcode.
+///
+/// [`Filters`]: ../model/outgoing/struct.Filters.html
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlayerFilters<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_mix: Option<&'a ChannelMix>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    distortion: Option<&'a Distortion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    equalizer: Option<&'a Vec<EqualizerBand>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    karaoke: Option<&'a Karaoke>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    low_pass: Option<&'a LowPass>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rotation: Option<&'a Rotation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timescale: Option<&'a Timescale>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tremolo: Option<&'a Tremolo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vibrato: Option<&'a Vibrato>,
+}
+
+// Serde requires the reference to the option.
+#[allow(clippy::ref_option)]
+fn serialize_filters<S: Serializer>(
+    filters: &Option<Filters>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    filters
+        .as_ref()
+        .map(|filters| PlayerFilters {
+            channel_mix: filters.channel_mix.as_ref(),
+            distortion: filters.distortion.as_ref(),
+            equalizer: filters.equalizer.as_ref(),
+            karaoke: filters.karaoke.as_ref(),
+            low_pass: filters.low_pass.as_ref(),
+            rotation: filters.rotation.as_ref(),
+            timescale: filters.timescale.as_ref(),
+            tremolo: filters.tremolo.as_ref(),
+            vibrato: filters.vibrato.as_ref(),
+        })
+        .serialize(serializer)
+}
+
+/// Get a list of tracks that match an identifier.
+///
+/// The response will include a body which can be deserialized into a
+/// [`LoadResult`].
+///
+/// [`LoadResult`]: enum.LoadResult.html
+#[cfg(feature = "http-support")]
+pub fn load_track(
+    node: &NodeConfig,
+    identifier: impl AsRef<str>,
+) -> Result<Request<&'static [u8]>, HttpError> {
+    let identifier =
+        percent_encoding::percent_encode(identifier.as_ref().as_bytes(), NON_ALPHANUMERIC);
+    let url = format!(
+        "{}/v4/loadtracks?identifier={}",
+        node::base_url(node, false),
+        identifier,
+    );
+
+    let mut req = Request::get(url);
+
+    let auth_value = HeaderValue::from_str(&node.authorization)?;
+    req = req.header(AUTHORIZATION, auth_value);
+
+    req.body(b"")
+}
+
+/// Update the player of a guild within a session, creating it if it doesn't
+/// exist.
+///
+/// Set `no_replace` to not replace the current track if the update includes
+/// a track to play.
+///
+/// The response will include a body with the updated player.
+pub fn update_player(
+    node: &NodeConfig,
+    session_id: impl AsRef<str>,
+    guild_id: GuildId,
+    no_replace: bool,
+    update: &UpdatePlayer,
+) -> Result<Request<Vec<u8>>, HttpError> {
+    let url = format!(
+        "{}/v4/sessions/{}/players/{}?noReplace={}",
+        node::base_url(node, false),
+        session_id.as_ref(),
+        guild_id,
+        no_replace,
+    );
+
+    json_request(Method::PATCH, url, &node.authorization, update)
+}
+
+/// Destroy the player of a guild within a session.
+///
+/// The response will not include a body on success.
+pub fn destroy_player(
+    node: &NodeConfig,
+    session_id: impl AsRef<str>,
+    guild_id: GuildId,
+) -> Result<Request<Vec<u8>>, HttpError> {
+    let url = format!(
+        "{}/v4/sessions/{}/players/{}",
+        node::base_url(node, false),
+        session_id.as_ref(),
+        guild_id,
+    );
+
+    let mut req = Request::delete(url);
+
+    let auth_value = HeaderValue::from_str(&node.authorization)?;
+    req = req.header(AUTHORIZATION, auth_value);
+
+    req.body(Vec::new())
+}
+
+/// Update the resuming configuration of a session.
+///
+/// The response will include a body which can be deserialized into a
+/// [`Session`].
+///
+/// [`Session`]: struct.Session.html
+pub fn update_session(
+    node: &NodeConfig,
+    session_id: impl AsRef<str>,
+    update: &UpdateSession,
+) -> Result<Request<Vec<u8>>, HttpError> {
+    let url = format!(
+        "{}/v4/sessions/{}",
+        node::base_url(node, false),
+        session_id.as_ref(),
+    );
+
+    json_request(Method::PATCH, url, &node.authorization, update)
+}
+
+fn json_request(
+    method: Method,
+    url: String,
+    authorization: &str,
+    body: &impl Serialize,
+) -> Result<Request<Vec<u8>>, HttpError> {
+    let mut req = Request::builder().method(method).uri(url);
+
+    let auth_value = HeaderValue::from_str(authorization)?;
+    req = req.header(AUTHORIZATION, auth_value);
+    req = req.header(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    // Serializing these models can't fail.
+    req.body(serde_json::to_vec(body).unwrap())
+}
+
+/// Create the request for an outgoing event sent to a session.
+///
+/// Returns `None` if the event has no equivalent in version 4, such as voice
+/// updates without an endpoint.
+pub(crate) fn event_request(
+    node: &NodeConfig,
+    session_id: &str,
+    event: &OutgoingEvent,
+) -> Option<Result<Request<Vec<u8>>, HttpError>> {
+    let mut update = UpdatePlayer::default();
+    let mut no_replace = false;
+
+    let guild_id = match event {
+        OutgoingEvent::Destroy(event) => {
+            return Some(destroy_player(node, session_id, event.guild_id));
+        }
+        OutgoingEvent::Filters(event) => {
+            update.filters.replace(Filters::clone(event));
+
+            event.guild_id
+        }
+        OutgoingEvent::Pause(event) => {
+            update.paused.replace(event.pause);
+
+            event.guild_id
+        }
+        OutgoingEvent::Play(event) => {
+            update.end_time = event.end_time;
+            update.position = event.start_time;
+            update
+                .track
+                .replace(UpdatePlayerTrack::new(event.track.clone()));
+            no_replace = event.no_replace;
+
+            event.guild_id
+        }
+        OutgoingEvent::Seek(event) => {
+            update.position = u64::try_from(event.position).ok();
+
+            event.guild_id
+        }
+        OutgoingEvent::Stop(event) => {
+            update.track.replace(UpdatePlayerTrack::new(None));
+
+            event.guild_id
+        }
+        OutgoingEvent::VoiceUpdate(event) => {
+            let endpoint = event.event.endpoint.clone()?;
+            update.voice.replace(VoiceState::new(
+                endpoint,
+                event.session_id.clone(),
+                event.event.token.clone(),
+            ));
+
+            event.guild_id
+        }
+        OutgoingEvent::Volume(event) => {
+            update.volume.replace(event.volume);
+
+            event.guild_id
+        }
+    };
+
+    Some(update_player(
+        node, session_id, guild_id, no_replace, &update,
+    ))
+}
+
+/// A message received over the websocket of a version 4 node.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Message {
+    /// An event, translated to the version 3 model.
+    Event(IncomingEvent),
+    /// The session is ready to be used.
+    Ready {
+        /// Whether a previous session was resumed.
+        resumed: bool,
+        /// The ID of the session, used in REST requests.
+        session_id: String,
+    },
+}
+
+#[derive(Deserialize)]
+struct MessageOp<'a> {
+    op: &'a str,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Ready {
+    resumed: bool,
+    session_id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrackEvent {
+    by_remote: Option<bool>,
+    code: Option<u64>,
+    exception: Option<Exception>,
+    guild_id: GuildId,
+    reason: Option<String>,
+    track: Option<EncodedTrack>,
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+#[derive(Deserialize)]
+struct EncodedTrack {
+    encoded: String,
+}
+
+/// Parse a message received over the websocket of a version 4 node.
+///
+/// Returns `None` if the message is unknown or has no version 3 equivalent.
+pub(crate) fn parse_message(text: &str) -> Option<Message> {
+    let op = serde_json::from_str::<MessageOp<'_>>(text).ok()?.op;
+
+    match op {
+        "ready" => {
+            let ready = serde_json::from_str::<Ready>(text).ok()?;
+
+            Some(Message::Ready {
+                resumed: ready.resumed,
+                session_id: ready.session_id,
+            })
+        }
+        "event" => {
+            let event = serde_json::from_str::<TrackEvent>(text).ok()?;
+
+            let event = match event.kind.as_str() {
+                "TrackEndEvent" => IncomingEvent::TrackEnd(TrackEnd {
+                    guild_id: event.guild_id,
+                    kind: TrackEventType::End,
+                    op: Opcode::Event,
+                    reason: screaming_snake_case(&event.reason.unwrap_or_default()),
+                    track: event.track?.encoded,
+                }),
+                "TrackExceptionEvent" => {
+                    let exception = event.exception?;
+
+                    IncomingEvent::TrackException(TrackException {
+                        error: exception.message.unwrap_or(exception.cause),
+                        guild_id: event.guild_id,
+                        kind: TrackEventType::Exception,
+                        op: Opcode::Event,
+                        track: event.track?.encoded,
+                    })
+                }
+                "TrackStartEvent" => IncomingEvent::TrackStart(TrackStart {
+                    guild_id: event.guild_id,
+                    kind: TrackEventType::Start,
+                    op: Opcode::Event,
+                    track: event.track?.encoded,
+                }),
+                "WebSocketClosedEvent" => IncomingEvent::WebSocketClosed(WebSocketClosed {
+                    by_remote: event.by_remote?,
+                    code: event.code?,
+                    guild_id: event.guild_id,
+                    kind: TrackEventType::WebSocketClosed,
+                    op: Opcode::Event,
+                    reason: event.reason.unwrap_or_default(),
+                }),
+                _ => {
+                    return serde_json::from_str(text)
+                        .ok()
+                        .map(IncomingEvent::PluginEvent)
+                        .map(Message::Event)
+                }
+            };
+
+            Some(Message::Event(event))
+        }
+        _ => serde_json::from_str(text).ok().map(Message::Event),
+    }
+}
+
+/// Convert a camel case end reason, such as `"loadFailed"`, to the screaming
+/// snake case used in version 3, such as `"LOAD_FAILED"`.
+fn screaming_snake_case(reason: &str) -> String {
+    let mut converted = String::with_capacity(reason.len() + 2);
+
+    for ch in reason.chars() {
+        if ch.is_uppercase() {
+            converted.push('_');
+        }
+
+        converted.extend(ch.to_uppercase());
+    }
+
+    converted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        event_request, parse_message, ErrorResponse, Exception, LoadResult, Message, Playlist,
+        PlaylistInfo, Session, Severity, Track, TrackInfo, UpdatePlayer, UpdatePlayerTrack,
+        UpdateSession, VoiceState,
+    };
+    use crate::{
+        model::{
+            Filters, IncomingEvent, Opcode, OutgoingEvent, Play, Timescale, TrackEnd,
+            TrackEventType,
+        },
+        node::NodeConfig,
+    };
+    use http::Method;
+    use serde::{Deserialize, Serialize};
+    use serde_json::Value;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{
+        error::Error,
+        fmt::{Debug, Display},
+        net::SocketAddr,
+    };
+    use twilight_model::id::{GuildId, UserId};
+
+    assert_fields!(ErrorResponse: error, message, path, status, timestamp, trace);
+    assert_impl_all!(
+        ErrorResponse: Clone,
+        Debug,
+        Deserialize<'static>,
+        Display,
+        Eq,
+        Error,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Exception: cause, message, severity);
+    assert_impl_all!(
+        LoadResult: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync,
+    );
+    assert_fields!(Playlist: info, tracks);
+    assert_fields!(PlaylistInfo: name, selected_track);
+    assert_fields!(Session: resuming, timeout);
+    assert_impl_all!(Severity: Clone, Copy, Debug, Deserialize<'static>, Eq, Send, Sync);
+    assert_fields!(Track: encoded, info);
+    assert_fields!(
+        TrackInfo: artwork_url,
+        author,
+        identifier,
+        is_seekable,
+        is_stream,
+        isrc,
+        length,
+        position,
+        source_name,
+        title,
+        uri
+    );
+    assert_fields!(
+        UpdatePlayer: end_time,
+        filters,
+        paused,
+        position,
+        track,
+        voice,
+        volume
+    );
+    assert_impl_all!(UpdatePlayer: Clone, Debug, Default, PartialEq, Send, Serialize, Sync);
+    assert_fields!(UpdatePlayerTrack: encoded);
+    assert_fields!(UpdateSession: resuming, timeout);
+    assert_impl_all!(UpdateSession: Clone, Debug, Default, Eq, Send, Serialize, Sync);
+    assert_fields!(VoiceState: endpoint, session_id, token);
+
+    fn node() -> NodeConfig {
+        let address = SocketAddr::from(([127, 0, 0, 1], 2333));
+
+        NodeConfig::new(UserId(1), 1, address, "pass", None)
+    }
+
+    #[test]
+    fn test_load_result_empty() {
+        let result = serde_json::from_str::<LoadResult>(r#"{"loadType":"empty","data":{}}"#);
+        assert_eq!(LoadResult::Empty {}, result.unwrap());
+    }
+
+    #[test]
+    fn test_load_result_error() {
+        let result = serde_json::from_str::<LoadResult>(
+            r#"{"loadType":"error","data":{"message":"gone","severity":"common","cause":"x"}}"#,
+        );
+
+        assert_eq!(
+            LoadResult::Error(Exception {
+                cause: "x".to_owned(),
+                message: Some("gone".to_owned()),
+                severity: Severity::Common,
+            }),
+            result.unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_event_request_play() {
+        let event = OutgoingEvent::from(Play::new(GuildId(1), "track", 500, None, true));
+        let request = event_request(&node(), "session", &event).unwrap().unwrap();
+
+        assert_eq!(&Method::PATCH, request.method());
+        assert_eq!(
+            "http://127.0.0.1:2333/v4/sessions/session/players/1?noReplace=true",
+            request.uri(),
+        );
+        assert_eq!("pass", request.headers()["Authorization"]);
+
+        let body = serde_json::from_slice::<Value>(request.body()).unwrap();
+        assert_eq!(
+            serde_json::json!({ "position": 500, "track": { "encoded": "track" } }),
+            body,
+        );
+    }
+
+    #[test]
+    fn test_event_request_filters() {
+        let mut filters = Filters::new(GuildId(1));
+        filters.timescale.replace(Timescale::new(2.0, 1.0, 1.0));
+        let event = OutgoingEvent::from(filters);
+        let request = event_request(&node(), "session", &event).unwrap().unwrap();
+
+        let body = serde_json::from_slice::<Value>(request.body()).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "filters": { "timescale": { "pitch": 1.0, "rate": 1.0, "speed": 2.0 } },
+            }),
+            body,
+        );
+    }
+
+    #[test]
+    fn test_parse_message_ready() {
+        let message = parse_message(r#"{"op":"ready","resumed":false,"sessionId":"abc"}"#);
+
+        assert_eq!(
+            Some(Message::Ready {
+                resumed: false,
+                session_id: "abc".to_owned(),
+            }),
+            message,
+        );
+    }
+
+    #[test]
+    fn test_parse_message_track_end() {
+        let message = parse_message(
+            r#"{"op":"event","type":"TrackEndEvent","guildId":"1","reason":"loadFailed","track":{"encoded":"abc","info":{}}}"#,
+        );
+
+        assert_eq!(
+            Some(Message::Event(IncomingEvent::TrackEnd(TrackEnd {
+                guild_id: GuildId(1),
+                kind: TrackEventType::End,
+                op: Opcode::Event,
+                reason: "LOAD_FAILED".to_owned(),
+                track: "abc".to_owned(),
+            }))),
+            message,
+        );
+    }
+
+    #[test]
+    fn test_parse_message_unknown() {
+        assert!(matches!(
+            parse_message(
+                r#"{"op":"event","type":"TrackStuckEvent","guildId":"1","thresholdMs":1000}"#
+            ),
+            Some(Message::Event(IncomingEvent::PluginEvent(_))),
+        ));
+        assert!(parse_message("not json").is_none());
+    }
+}
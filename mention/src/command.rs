@@ -0,0 +1,73 @@
+//! Mentions of application commands, which the Discord client displays as
+//! a link that fills in the command.
+//!
+//! Commands are mentioned via [`fmt::command`] or [`Mention`].
+//!
+//! [`fmt::command`]: ../fmt/fn.command.html
+//! [`Mention`]: ../fmt/trait.Mention.html
+
+use twilight_model::id::CommandId;
+
+/// Command with its name, including the names of any subcommand group and
+/// subcommand.
+///
+/// # Examples
+///
+/// Mention a subcommand:
+///
+/// ```
+/// use twilight_mention::{command::CommandMention, Mention};
+/// use twilight_model::id::CommandId;
+///
+/// let command = CommandMention::new(CommandId(123), "permissions edit");
+/// assert_eq!("</permissions edit:123>", command.mention().to_string());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CommandMention {
+    id: CommandId,
+    name: String,
+}
+
+impl CommandMention {
+    /// Create a new command mention from the ID of a command and its name.
+    ///
+    /// To mention a subcommand, the names of the command, the subcommand
+    /// group if any, and the subcommand are separated by spaces.
+    pub fn new(id: CommandId, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+        }
+    }
+
+    /// ID of the command.
+    #[must_use]
+    pub const fn id(&self) -> CommandId {
+        self.id
+    }
+
+    /// Name of the command, including the names of any subcommand group and
+    /// subcommand.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommandMention;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::id::CommandId;
+
+    assert_impl_all!(CommandMention: Clone, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_getters() {
+        let command = CommandMention::new(CommandId(1), "ping");
+
+        assert_eq!(CommandId(1), command.id());
+        assert_eq!("ping", command.name());
+    }
+}
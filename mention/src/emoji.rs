@@ -0,0 +1,86 @@
+//! Custom emojis with their names, which are rendered as the emoji by the
+//! Discord client.
+//!
+//! Mentioning an [`EmojiId`] uses a placeholder name and can't render
+//! animated emojis, while a [`CustomEmoji`] is mentioned with its name and
+//! whether it's animated.
+//!
+//! [`CustomEmoji`]: struct.CustomEmoji.html
+//! [`EmojiId`]: ../../twilight_model/id/struct.EmojiId.html
+
+use twilight_model::{guild::Emoji, id::EmojiId};
+
+/// Custom emoji with its name and whether it's animated.
+///
+/// # Examples
+///
+/// Mention an animated emoji:
+///
+/// ```
+/// use twilight_mention::{emoji::CustomEmoji, Mention};
+/// use twilight_model::id::EmojiId;
+///
+/// let emoji = CustomEmoji::new(EmojiId(123), "dance", true);
+/// assert_eq!("<a:dance:123>", emoji.mention().to_string());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CustomEmoji {
+    animated: bool,
+    id: EmojiId,
+    name: String,
+}
+
+impl CustomEmoji {
+    /// Create a new custom emoji from its ID, name, and whether it's
+    /// animated.
+    pub fn new(id: EmojiId, name: impl Into<String>, animated: bool) -> Self {
+        Self {
+            animated,
+            id,
+            name: name.into(),
+        }
+    }
+
+    /// Whether the emoji is animated.
+    #[must_use]
+    pub const fn animated(&self) -> bool {
+        self.animated
+    }
+
+    /// ID of the emoji.
+    #[must_use]
+    pub const fn id(&self) -> EmojiId {
+        self.id
+    }
+
+    /// Name of the emoji.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl From<&Emoji> for CustomEmoji {
+    fn from(emoji: &Emoji) -> Self {
+        Self::new(emoji.id, emoji.name.clone(), emoji.animated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CustomEmoji;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+    use twilight_model::{guild::Emoji, id::EmojiId};
+
+    assert_impl_all!(CustomEmoji: Clone, Debug, Eq, From<&'static Emoji>, Hash, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_getters() {
+        let emoji = CustomEmoji::new(EmojiId(1), "dance", true);
+
+        assert!(emoji.animated());
+        assert_eq!(EmojiId(1), emoji.id());
+        assert_eq!("dance", emoji.name());
+    }
+}
@@ -1,12 +1,18 @@
 //! Formatters for creating mentions.
 
+use crate::{
+    command::CommandMention,
+    emoji::CustomEmoji,
+    navigation::GuildNavigation,
+    timestamp::{Timestamp, TimestampStyle},
+};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use twilight_model::{
     channel::{
         CategoryChannel, Channel, Group, GuildChannel, PrivateChannel, TextChannel, VoiceChannel,
     },
     guild::{Emoji, Member, Role},
-    id::{ChannelId, EmojiId, RoleId, UserId},
+    id::{ChannelId, CommandId, EmojiId, RoleId, UserId},
     user::{CurrentUser, User},
 };
 
@@ -32,6 +38,28 @@ impl Display for MentionFormat<ChannelId> {
     }
 }
 
+/// Mention a command. This will format as `</NAME:ID>`.
+impl Display for MentionFormat<CommandMention> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_fmt(format_args!("</{}:{}>", self.0.name(), self.0.id()))
+    }
+}
+
+/// Mention a custom emoji. This will format as `<:NAME:ID>`, or
+/// `<a:NAME:ID>` if it's animated.
+impl Display for MentionFormat<CustomEmoji> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let prefix = if self.0.animated() { "a" } else { "" };
+
+        f.write_fmt(format_args!(
+            "<{}:{}:{}>",
+            prefix,
+            self.0.name(),
+            self.0.id()
+        ))
+    }
+}
+
 /// Mention an emoji. This will format as `<:emoji:ID>`.
 impl Display for MentionFormat<EmojiId> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -39,6 +67,14 @@ impl Display for MentionFormat<EmojiId> {
     }
 }
 
+/// Mention a navigation section of a guild. This will format as
+/// `<id:SECTION>`.
+impl Display for MentionFormat<GuildNavigation> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_fmt(format_args!("<id:{}>", self.0))
+    }
+}
+
 /// Mention a role. This will format as `<@&ID>`.
 impl Display for MentionFormat<RoleId> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -46,6 +82,20 @@ impl Display for MentionFormat<RoleId> {
     }
 }
 
+/// Mention a timestamp. This will format as `<t:UNIX>` or `<t:UNIX:STYLE>`
+/// if a style is present.
+impl Display for MentionFormat<Timestamp> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_fmt(format_args!("<t:{}", self.0.unix()))?;
+
+        if let Some(style) = self.0.style() {
+            f.write_fmt(format_args!(":{}", style))?;
+        }
+
+        f.write_str(">")
+    }
+}
+
 /// Mention a user. This will format as `<@ID>`.
 impl Display for MentionFormat<UserId> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -53,6 +103,66 @@ impl Display for MentionFormat<UserId> {
     }
 }
 
+/// Mention a command by its ID and name.
+///
+/// This is a shorthand for mentioning a [`CommandMention`].
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_mention::fmt;
+/// use twilight_model::id::CommandId;
+///
+/// assert_eq!("</ping:123>", fmt::command(CommandId(123), "ping").to_string());
+/// ```
+///
+/// [`CommandMention`]: ../command/struct.CommandMention.html
+pub fn command(id: CommandId, name: impl Into<String>) -> MentionFormat<CommandMention> {
+    MentionFormat(CommandMention::new(id, name))
+}
+
+/// Mention a custom emoji by its ID, name, and whether it's animated.
+///
+/// This is a shorthand for mentioning a [`CustomEmoji`].
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_mention::fmt;
+/// use twilight_model::id::EmojiId;
+///
+/// let mention = fmt::custom_emoji(EmojiId(123), "rainbow", false);
+/// assert_eq!("<:rainbow:123>", mention.to_string());
+/// ```
+///
+/// [`CustomEmoji`]: ../emoji/struct.CustomEmoji.html
+pub fn custom_emoji(
+    id: EmojiId,
+    name: impl Into<String>,
+    animated: bool,
+) -> MentionFormat<CustomEmoji> {
+    MentionFormat(CustomEmoji::new(id, name, animated))
+}
+
+/// Mention a Unix timestamp in seconds with a display style.
+///
+/// This is a shorthand for mentioning a [`Timestamp`].
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_mention::{timestamp::TimestampStyle, fmt};
+///
+/// let mention = fmt::timestamp(1_624_047_064, TimestampStyle::ShortDate);
+/// assert_eq!("<t:1624047064:d>", mention.to_string());
+/// ```
+///
+/// [`Timestamp`]: ../timestamp/struct.Timestamp.html
+#[must_use]
+pub const fn timestamp(unix: u64, style: TimestampStyle) -> MentionFormat<Timestamp> {
+    MentionFormat(Timestamp::new(unix, Some(style)))
+}
+
 /// Mention a resource, such as an emoji or user.
 ///
 /// This will create a mention that will link to a user if it exists.
@@ -88,6 +198,20 @@ impl Mention<ChannelId> for &'_ ChannelId {
     }
 }
 
+/// Mention a command. This will format as `</NAME:ID>`.
+impl Mention<CommandMention> for CommandMention {
+    fn mention(&self) -> MentionFormat<CommandMention> {
+        MentionFormat(self.clone())
+    }
+}
+
+/// Mention a command. This will format as `</NAME:ID>`.
+impl Mention<CommandMention> for &'_ CommandMention {
+    fn mention(&self) -> MentionFormat<CommandMention> {
+        (*self).mention()
+    }
+}
+
 /// Mention a guild category channel. This will format as `<#ID>`.
 impl Mention<ChannelId> for CategoryChannel {
     fn mention(&self) -> MentionFormat<ChannelId> {
@@ -130,6 +254,22 @@ impl Mention<UserId> for &'_ CurrentUser {
     }
 }
 
+/// Mention a custom emoji. This will format as `<:NAME:ID>`, or
+/// `<a:NAME:ID>` if it's animated.
+impl Mention<CustomEmoji> for CustomEmoji {
+    fn mention(&self) -> MentionFormat<CustomEmoji> {
+        MentionFormat(self.clone())
+    }
+}
+
+/// Mention a custom emoji. This will format as `<:NAME:ID>`, or
+/// `<a:NAME:ID>` if it's animated.
+impl Mention<CustomEmoji> for &'_ CustomEmoji {
+    fn mention(&self) -> MentionFormat<CustomEmoji> {
+        (*self).mention()
+    }
+}
+
 /// Mention an emoji. This will format as `<:emoji:ID>`.
 impl Mention<EmojiId> for EmojiId {
     fn mention(&self) -> MentionFormat<EmojiId> {
@@ -186,6 +326,22 @@ impl Mention<ChannelId> for &'_ GuildChannel {
     }
 }
 
+/// Mention a navigation section of a guild. This will format as
+/// `<id:SECTION>`.
+impl Mention<GuildNavigation> for GuildNavigation {
+    fn mention(&self) -> MentionFormat<GuildNavigation> {
+        MentionFormat(*self)
+    }
+}
+
+/// Mention a navigation section of a guild. This will format as
+/// `<id:SECTION>`.
+impl Mention<GuildNavigation> for &'_ GuildNavigation {
+    fn mention(&self) -> MentionFormat<GuildNavigation> {
+        (*self).mention()
+    }
+}
+
 /// Mention a member's user. This will format as `<@ID>`.
 impl Mention<UserId> for Member {
     fn mention(&self) -> MentionFormat<UserId> {
@@ -256,6 +412,20 @@ impl Mention<ChannelId> for &'_ TextChannel {
     }
 }
 
+/// Mention a timestamp. This will format as `<t:UNIX>` or `<t:UNIX:STYLE>`.
+impl Mention<Timestamp> for Timestamp {
+    fn mention(&self) -> MentionFormat<Timestamp> {
+        MentionFormat(*self)
+    }
+}
+
+/// Mention a timestamp. This will format as `<t:UNIX>` or `<t:UNIX:STYLE>`.
+impl Mention<Timestamp> for &'_ Timestamp {
+    fn mention(&self) -> MentionFormat<Timestamp> {
+        (*self).mention()
+    }
+}
+
 /// Mention a user ID. This will format as `<&ID>`.
 impl Mention<UserId> for UserId {
     fn mention(&self) -> MentionFormat<UserId> {
@@ -301,6 +471,12 @@ impl Mention<ChannelId> for &'_ VoiceChannel {
 #[cfg(test)]
 mod tests {
     use super::{Mention, MentionFormat};
+    use crate::{
+        command::CommandMention,
+        emoji::CustomEmoji,
+        navigation::GuildNavigation,
+        timestamp::{Timestamp, TimestampStyle},
+    };
     use static_assertions::assert_impl_all;
     use std::fmt::{Debug, Display};
     use twilight_model::{
@@ -309,14 +485,18 @@ mod tests {
             VoiceChannel,
         },
         guild::{Emoji, Member, Role},
-        id::{ChannelId, EmojiId, RoleId, UserId},
+        id::{ChannelId, CommandId, EmojiId, RoleId, UserId},
         user::{CurrentUser, User},
     };
 
     assert_impl_all!(MentionFormat<()>: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<ChannelId>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MentionFormat<CommandMention>: Clone, Debug, Display, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MentionFormat<CustomEmoji>: Clone, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<EmojiId>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MentionFormat<GuildNavigation>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<RoleId>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(MentionFormat<Timestamp>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(MentionFormat<UserId>: Clone, Copy, Debug, Display, Eq, PartialEq, Send, Sync);
     assert_impl_all!(ChannelId: Mention<ChannelId>);
     assert_impl_all!(&'static ChannelId: Mention<ChannelId>);
@@ -324,8 +504,12 @@ mod tests {
     assert_impl_all!(&'static CategoryChannel: Mention<ChannelId>);
     assert_impl_all!(Channel: Mention<ChannelId>);
     assert_impl_all!(&'static Channel: Mention<ChannelId>);
+    assert_impl_all!(CommandMention: Mention<CommandMention>);
+    assert_impl_all!(&'static CommandMention: Mention<CommandMention>);
     assert_impl_all!(CurrentUser: Mention<UserId>);
     assert_impl_all!(&'static CurrentUser: Mention<UserId>);
+    assert_impl_all!(CustomEmoji: Mention<CustomEmoji>);
+    assert_impl_all!(&'static CustomEmoji: Mention<CustomEmoji>);
     assert_impl_all!(EmojiId: Mention<EmojiId>);
     assert_impl_all!(&'static EmojiId: Mention<EmojiId>);
     assert_impl_all!(Emoji: Mention<EmojiId>);
@@ -334,6 +518,8 @@ mod tests {
     assert_impl_all!(&'static Group: Mention<ChannelId>);
     assert_impl_all!(GuildChannel: Mention<ChannelId>);
     assert_impl_all!(&'static GuildChannel: Mention<ChannelId>);
+    assert_impl_all!(GuildNavigation: Mention<GuildNavigation>);
+    assert_impl_all!(&'static GuildNavigation: Mention<GuildNavigation>);
     assert_impl_all!(Member: Mention<UserId>);
     assert_impl_all!(&'static Member: Mention<UserId>);
     assert_impl_all!(PrivateChannel: Mention<ChannelId>);
@@ -343,6 +529,8 @@ mod tests {
     assert_impl_all!(Role: Mention<RoleId>);
     assert_impl_all!(&'static Role: Mention<RoleId>);
     assert_impl_all!(TextChannel: Mention<ChannelId>);
+    assert_impl_all!(Timestamp: Mention<Timestamp>);
+    assert_impl_all!(&'static Timestamp: Mention<Timestamp>);
     assert_impl_all!(&'static TextChannel: Mention<ChannelId>);
     assert_impl_all!(UserId: Mention<UserId>);
     assert_impl_all!(&'static UserId: Mention<UserId>);
@@ -356,16 +544,69 @@ mod tests {
         assert_eq!("<#123>", ChannelId(123).mention().to_string());
     }
 
+    #[test]
+    fn test_mention_format_command() {
+        assert_eq!(
+            "</ping:123>",
+            CommandMention::new(CommandId(123), "ping")
+                .mention()
+                .to_string()
+        );
+        assert_eq!(
+            "</role add:123>",
+            super::command(CommandId(123), "role add").to_string()
+        );
+    }
+
+    #[test]
+    fn test_mention_format_custom_emoji() {
+        assert_eq!(
+            "<:rainbow:123>",
+            CustomEmoji::new(EmojiId(123), "rainbow", false)
+                .mention()
+                .to_string()
+        );
+        assert_eq!(
+            "<a:dance:123>",
+            super::custom_emoji(EmojiId(123), "dance", true).to_string()
+        );
+    }
+
     #[test]
     fn test_mention_format_emoji_id() {
         assert_eq!("<:emoji:123>", EmojiId(123).mention().to_string());
     }
 
+    #[test]
+    fn test_mention_format_guild_navigation() {
+        assert_eq!("<id:browse>", GuildNavigation::Browse.mention().to_string());
+        assert_eq!(
+            "<id:customize>",
+            GuildNavigation::Customize.mention().to_string()
+        );
+        assert_eq!("<id:guide>", GuildNavigation::Guide.mention().to_string());
+    }
+
     #[test]
     fn test_mention_format_role_id() {
         assert_eq!("<@&123>", RoleId(123).mention().to_string());
     }
 
+    #[test]
+    fn test_mention_format_timestamp() {
+        assert_eq!("<t:123>", Timestamp::new(123, None).mention().to_string());
+        assert_eq!(
+            "<t:123:R>",
+            Timestamp::new(123, Some(TimestampStyle::RelativeTime))
+                .mention()
+                .to_string()
+        );
+        assert_eq!(
+            "<t:123:F>",
+            super::timestamp(123, TimestampStyle::LongDateTime).to_string()
+        );
+    }
+
     #[test]
     fn test_mention_format_user_id() {
         assert_eq!("<@123>", UserId(123).mention().to_string());
@@ -6,7 +6,7 @@
 //! ecosystem to mention its model types and parse those mentions.
 //!
 //! With this library, you can create mentions for various types, such as users,
-//! emojis, roles, members, or channels.
+//! emojis, roles, members, channels, commands, guild sections, or timestamps.
 //!
 //! ## Examples
 //!
@@ -42,12 +42,20 @@
 )]
 #![allow(clippy::module_name_repetitions)]
 
+pub mod command;
+
+pub mod emoji;
+
 pub mod fmt;
 
+pub mod navigation;
+
 pub mod parse;
 
+pub mod timestamp;
+
 #[doc(no_inline)]
-pub use fmt::{Mention, MentionFormat};
+pub use fmt::{command, custom_emoji, timestamp, Mention, MentionFormat};
 
 #[doc(no_inline)]
 pub use parse::ParseMention;
@@ -0,0 +1,93 @@
+//! Mentions of the navigation sections of a guild, which the Discord client
+//! displays as a link to the section.
+//!
+//! Sections are mentioned via [`Mention`].
+//!
+//! [`Mention`]: ../fmt/trait.Mention.html
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Navigation section of a guild.
+///
+/// # Examples
+///
+/// Mention the channels and roles section:
+///
+/// ```
+/// use twilight_mention::{navigation::GuildNavigation, Mention};
+///
+/// assert_eq!("<id:customize>", GuildNavigation::Customize.mention().to_string());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum GuildNavigation {
+    /// Browse channels section.
+    ///
+    /// Displayed as `browse`.
+    Browse,
+    /// Channels and roles section, where members pick their roles and which
+    /// channels they see.
+    ///
+    /// Displayed as `customize`.
+    Customize,
+    /// Server guide section.
+    ///
+    /// Displayed as `guide`.
+    Guide,
+}
+
+impl GuildNavigation {
+    /// Retrieve the name of the section used in its mention.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_mention::navigation::GuildNavigation;
+    ///
+    /// assert_eq!("guide", GuildNavigation::Guide.name());
+    /// ```
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Browse => "browse",
+            Self::Customize => "customize",
+            Self::Guide => "guide",
+        }
+    }
+}
+
+impl Display for GuildNavigation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GuildNavigation;
+    use static_assertions::assert_impl_all;
+    use std::{
+        fmt::{Debug, Display},
+        hash::Hash,
+    };
+
+    assert_impl_all!(
+        GuildNavigation: Clone,
+        Copy,
+        Debug,
+        Display,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_names() {
+        assert_eq!("browse", GuildNavigation::Browse.name());
+        assert_eq!("customize", GuildNavigation::Customize.name());
+        assert_eq!("guide", GuildNavigation::Guide.name());
+        assert_eq!("guide", GuildNavigation::Guide.to_string());
+    }
+}
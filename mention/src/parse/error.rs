@@ -39,6 +39,13 @@ pub enum ParseMentionError<'a> {
         /// Character that was instead found where the sigil should be.
         found: Option<char>,
     },
+    /// Style of a timestamp mention is not a known [`TimestampStyle`].
+    ///
+    /// [`TimestampStyle`]: ../timestamp/enum.TimestampStyle.html
+    TimestampStyleInvalid {
+        /// String that could not be parsed into a timestamp style.
+        found: &'a str,
+    },
     /// Trailing arrow (`>`) is not present.
     TrailingArrow {
         /// Character that was instead found where the trailing arrow should be.
@@ -86,6 +93,10 @@ impl Display for ParseMentionError<'_> {
                     f.write_str("nothing")
                 }
             }
+            Self::TimestampStyleInvalid { found } => f.write_fmt(format_args!(
+                "style portion ('{}') of timestamp mention is not a timestamp style",
+                found
+            )),
             Self::TrailingArrow { found } => {
                 f.write_str("expected to find a trailing arrow ('>') but instead ")?;
 
@@ -106,6 +117,7 @@ impl Error for ParseMentionError<'_> {
             Self::LeadingArrow { .. }
             | Self::PartMissing { .. }
             | Self::Sigil { .. }
+            | Self::TimestampStyleInvalid { .. }
             | Self::TrailingArrow { .. } => None,
         }
     }
@@ -120,6 +132,7 @@ mod tests {
     assert_fields!(ParseMentionError::IdNotU64: found, source);
     assert_fields!(ParseMentionError::LeadingArrow: found);
     assert_fields!(ParseMentionError::Sigil: expected, found);
+    assert_fields!(ParseMentionError::TimestampStyleInvalid: found);
     assert_fields!(ParseMentionError::TrailingArrow: found);
     assert_impl_all!(ParseMentionError<'_>: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
 
@@ -186,6 +199,12 @@ mod tests {
             .to_string(),
         );
 
+        expected = "style portion ('x') of timestamp mention is not a timestamp style";
+        assert_eq!(
+            expected,
+            ParseMentionError::TimestampStyleInvalid { found: "x" }.to_string(),
+        );
+
         expected = "expected to find a trailing arrow ('>') but instead found 'a'";
         assert_eq!(
             expected,
@@ -1,5 +1,6 @@
 use super::{MentionIter, MentionType, ParseMentionError};
-use std::str::Chars;
+use crate::timestamp::{Timestamp, TimestampStyle};
+use std::{convert::TryFrom, str::Chars};
 use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 
 /// Parse mentions out of buffers.
@@ -81,7 +82,10 @@ impl ParseMention for ChannelId {
 }
 
 impl ParseMention for EmojiId {
-    const SIGILS: &'static [&'static str] = &[":"];
+    /// Sigils for Emoji ID mentions.
+    ///
+    /// Animated emojis have the sigil `a:` while static emojis have `:`.
+    const SIGILS: &'static [&'static str] = &["a:", ":"];
 
     fn parse(buf: &str) -> Result<Self, ParseMentionError<'_>>
     where
@@ -95,12 +99,16 @@ impl ParseMention for MentionType {
     /// Sigils for any type of mention.
     ///
     /// Contains all of the sigils of every other type of mention.
-    const SIGILS: &'static [&'static str] = &["#", ":", "@&", "@!", "@"];
+    const SIGILS: &'static [&'static str] = &["#", "a:", ":", "@&", "@!", "@", "t:"];
 
     fn parse(buf: &str) -> Result<Self, ParseMentionError<'_>>
     where
         Self: Sized,
     {
+        if buf.starts_with('<') && buf[1..].starts_with(Timestamp::SIGILS[0]) {
+            return Timestamp::parse(buf).map(MentionType::Timestamp);
+        }
+
         let (id, found) = parse_id(buf, Self::SIGILS)?;
 
         for sigil in ChannelId::SIGILS {
@@ -142,6 +150,17 @@ impl ParseMention for RoleId {
     }
 }
 
+impl ParseMention for Timestamp {
+    const SIGILS: &'static [&'static str] = &["t:"];
+
+    fn parse(buf: &str) -> Result<Self, ParseMentionError<'_>>
+    where
+        Self: Sized,
+    {
+        parse_timestamp(buf)
+    }
+}
+
 impl ParseMention for UserId {
     /// Sigils for User ID mentions.
     ///
@@ -203,7 +222,7 @@ fn parse_id<'a>(
         });
     };
 
-    if sigil == ":" && !emoji_sigil_present(&mut chars) {
+    if EmojiId::SIGILS.contains(&sigil) && !emoji_sigil_present(&mut chars) {
         return Err(ParseMentionError::PartMissing {
             found: 1,
             expected: 2,
@@ -225,6 +244,58 @@ fn parse_id<'a>(
         })
 }
 
+/// # Errors
+///
+/// Returns the same errors as [`parse_id`], as well as
+/// [`ParseMentionError::TimestampStyleInvalid`] if the style is present but
+/// isn't a [`TimestampStyle`].
+///
+/// [`parse_id`]: fn.parse_id.html
+/// [`ParseMentionError::TimestampStyleInvalid`]: enum.ParseMentionError.html#variant.TimestampStyleInvalid
+/// [`TimestampStyle`]: ../../timestamp/enum.TimestampStyle.html
+fn parse_timestamp(buf: &str) -> Result<Timestamp, ParseMentionError<'_>> {
+    let mut chars = buf.chars();
+
+    let c = chars.next();
+
+    if c != Some('<') {
+        return Err(ParseMentionError::LeadingArrow { found: c });
+    }
+
+    let rest = chars.as_str();
+
+    let remaining =
+        rest.strip_prefix(Timestamp::SIGILS[0])
+            .ok_or_else(|| ParseMentionError::Sigil {
+                expected: Timestamp::SIGILS,
+                found: rest.chars().next(),
+            })?;
+
+    let inner = remaining
+        .find('>')
+        .and_then(|idx| remaining.get(..idx))
+        .ok_or(ParseMentionError::TrailingArrow { found: None })?;
+
+    let (unix, style) = match inner.find(':') {
+        Some(idx) => (&inner[..idx], Some(&inner[idx + 1..])),
+        None => (inner, None),
+    };
+
+    let unix = unix.parse().map_err(|source| ParseMentionError::IdNotU64 {
+        found: unix,
+        source,
+    })?;
+
+    let style = style
+        .map(|style| {
+            TimestampStyle::try_from(style)
+                .map_err(|_| ParseMentionError::TimestampStyleInvalid { found: style })
+        })
+        .transpose()?;
+
+    Ok(Timestamp::new(unix, style))
+}
+
 // Don't use `Iterator::skip_while` so we can mutate `chars` in-place;
 // `skip_while` is consuming.
 fn emoji_sigil_present(chars: &mut Chars<'_>) -> bool {
@@ -246,6 +317,7 @@ fn emoji_sigil_present(chars: &mut Chars<'_>) -> bool {
 /// <https://rust-lang.github.io/api-guidelines/future-proofing.html>
 mod private {
     use super::super::MentionType;
+    use crate::timestamp::Timestamp;
     use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 
     pub trait Sealed {}
@@ -254,6 +326,7 @@ mod private {
     impl Sealed for EmojiId {}
     impl Sealed for MentionType {}
     impl Sealed for RoleId {}
+    impl Sealed for Timestamp {}
     impl Sealed for UserId {}
 }
 
@@ -264,6 +337,7 @@ mod tests {
         private::Sealed,
         ParseMention,
     };
+    use crate::timestamp::{Timestamp, TimestampStyle};
     use static_assertions::assert_impl_all;
     use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 
@@ -271,14 +345,19 @@ mod tests {
     assert_impl_all!(EmojiId: ParseMention, Sealed);
     assert_impl_all!(MentionType: ParseMention, Sealed);
     assert_impl_all!(RoleId: ParseMention, Sealed);
+    assert_impl_all!(Timestamp: ParseMention, Sealed);
     assert_impl_all!(UserId: ParseMention, Sealed);
 
     #[test]
     fn test_sigils() {
         assert_eq!(&["#"], ChannelId::SIGILS);
-        assert_eq!(&[":"], EmojiId::SIGILS);
-        assert_eq!(&["#", ":", "@&", "@!", "@"], MentionType::SIGILS);
+        assert_eq!(&["a:", ":"], EmojiId::SIGILS);
+        assert_eq!(
+            &["#", "a:", ":", "@&", "@!", "@", "t:"],
+            MentionType::SIGILS
+        );
         assert_eq!(&["@&"], RoleId::SIGILS);
+        assert_eq!(&["t:"], Timestamp::SIGILS);
         assert_eq!(&["@!", "@"], UserId::SIGILS);
     }
 
@@ -297,9 +376,17 @@ mod tests {
     #[test]
     fn test_parse_emoji_id() {
         assert_eq!(EmojiId(123), EmojiId::parse("<:name:123>").unwrap());
+        assert_eq!(EmojiId(123), EmojiId::parse("<a:name:123>").unwrap());
+        assert_eq!(
+            ParseMentionError::PartMissing {
+                expected: 2,
+                found: 1,
+            },
+            EmojiId::parse("<a:123>").unwrap_err(),
+        );
         assert_eq!(
             ParseMentionError::Sigil {
-                expected: &[":"],
+                expected: &["a:", ":"],
                 found: Some('@'),
             },
             EmojiId::parse("<@123>").unwrap_err(),
@@ -316,6 +403,10 @@ mod tests {
             MentionType::Emoji(EmojiId(123)),
             MentionType::parse("<:name:123>").unwrap()
         );
+        assert_eq!(
+            MentionType::Emoji(EmojiId(123)),
+            MentionType::parse("<a:name:123>").unwrap()
+        );
         assert_eq!(
             MentionType::Role(RoleId(123)),
             MentionType::parse("<@&123>").unwrap()
@@ -324,9 +415,13 @@ mod tests {
             MentionType::User(UserId(123)),
             MentionType::parse("<@123>").unwrap()
         );
+        assert_eq!(
+            MentionType::Timestamp(Timestamp::new(123, Some(TimestampStyle::RelativeTime))),
+            MentionType::parse("<t:123:R>").unwrap()
+        );
         assert_eq!(
             ParseMentionError::Sigil {
-                expected: &["#", ":", "@&", "@!", "@"],
+                expected: &["#", "a:", ":", "@&", "@!", "@", "t:"],
                 found: Some(';'),
             },
             MentionType::parse("<;123>").unwrap_err(),
@@ -345,6 +440,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(
+            Timestamp::new(123, None),
+            Timestamp::parse("<t:123>").unwrap()
+        );
+        assert_eq!(
+            Timestamp::new(123, Some(TimestampStyle::LongDate)),
+            Timestamp::parse("<t:123:D>").unwrap()
+        );
+        assert_eq!(
+            ParseMentionError::TimestampStyleInvalid { found: "x" },
+            Timestamp::parse("<t:123:x>").unwrap_err(),
+        );
+        assert_eq!(
+            ParseMentionError::IdNotU64 {
+                found: "abc",
+                source: "abc".parse::<u64>().unwrap_err(),
+            },
+            Timestamp::parse("<t:abc:R>").unwrap_err(),
+        );
+        assert_eq!(
+            ParseMentionError::Sigil {
+                expected: &["t:"],
+                found: Some('@'),
+            },
+            Timestamp::parse("<@123>").unwrap_err(),
+        );
+        assert_eq!(
+            ParseMentionError::TrailingArrow { found: None },
+            Timestamp::parse("<t:123").unwrap_err(),
+        );
+    }
+
     #[test]
     fn test_parse_user_id() {
         assert_eq!(UserId(123), UserId::parse("<@123>").unwrap());
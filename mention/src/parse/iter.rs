@@ -77,6 +77,8 @@ impl<'a, T: ParseMention + std::fmt::Debug> Iterator for MentionIter<'a, T> {
                     for _ in 0..sigil.chars().count() {
                         self.chars.next();
                     }
+
+                    break;
                 }
             }
 
@@ -103,6 +105,7 @@ mod tests {
         super::{MentionType, ParseMention},
         MentionIter,
     };
+    use crate::timestamp::{Timestamp, TimestampStyle};
     use static_assertions::{assert_impl_all, assert_obj_safe};
     use std::fmt::Debug;
     use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
@@ -111,12 +114,14 @@ mod tests {
     assert_impl_all!(MentionIter<'_, EmojiId>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(MentionIter<'_, MentionType>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(MentionIter<'_, RoleId>: Clone, Debug, Iterator, Send, Sync);
+    assert_impl_all!(MentionIter<'_, Timestamp>: Clone, Debug, Iterator, Send, Sync);
     assert_impl_all!(MentionIter<'_, UserId>: Clone, Debug, Iterator, Send, Sync);
     assert_obj_safe!(
         MentionIter<'_, ChannelId>,
         MentionIter<'_, EmojiId>,
         MentionIter<'_, MentionType>,
         MentionIter<'_, RoleId>,
+        MentionIter<'_, Timestamp>,
         MentionIter<'_, UserId>,
     );
 
@@ -141,17 +146,22 @@ mod tests {
 
     #[test]
     fn test_iter_emoji_ids() {
-        let mut iter = EmojiId::iter("some <:name:123> emojis <:emoji:456>");
+        let mut iter = EmojiId::iter("some <:name:123> emojis <:emoji:456> <a:animated:789>");
         assert_eq!(EmojiId(123), iter.next().unwrap().0);
         assert_eq!(EmojiId(456), iter.next().unwrap().0);
+        let (mention, start, end) = iter.next().unwrap();
+        assert_eq!(EmojiId(789), mention);
+        assert_eq!(37, start);
+        assert_eq!(52, end);
         assert!(iter.next().is_none());
     }
 
     #[test]
     fn test_iter_mention_type() {
-        let mut iter = MentionType::iter("<#12><:name:34><@&56><@!78><@90>");
+        let mut iter = MentionType::iter("<#12><:name:34><a:name:35><@&56><@!78><@90>");
         assert_eq!(MentionType::Channel(ChannelId(12)), iter.next().unwrap().0);
         assert_eq!(MentionType::Emoji(EmojiId(34)), iter.next().unwrap().0);
+        assert_eq!(MentionType::Emoji(EmojiId(35)), iter.next().unwrap().0);
         assert_eq!(MentionType::Role(RoleId(56)), iter.next().unwrap().0);
         assert_eq!(MentionType::User(UserId(78)), iter.next().unwrap().0);
         assert_eq!(MentionType::User(UserId(90)), iter.next().unwrap().0);
@@ -166,6 +176,17 @@ mod tests {
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    fn test_iter_timestamps() {
+        let mut iter = Timestamp::iter("starts <t:123:R>, ends <t:456> <t:789:x>");
+        assert_eq!(
+            Timestamp::new(123, Some(TimestampStyle::RelativeTime)),
+            iter.next().unwrap().0
+        );
+        assert_eq!(Timestamp::new(456, None), iter.next().unwrap().0);
+        assert!(iter.next().is_none());
+    }
+
     #[test]
     fn test_iter_user_ids() {
         let mut iter = UserId::iter("some <@123>users<@456>");
@@ -6,6 +6,9 @@
 //! There is also the [`MentionType`]: it's an enum wrapping all possible types
 //! of mentions and works just like the individual IDs.
 //!
+//! Timestamp mentions, such as `<t:1624047064:R>`, are parsed into a
+//! [`Timestamp`].
+//!
 //! While the syntax of mentions will be validated and the IDs within them
 //! parsed, they won't be validated as being proper snowflakes or as real IDs in
 //! use.
@@ -36,6 +39,8 @@
 //! assert!(matches!(iter.next(), Some((UserId(789), _, _))));
 //! assert!(iter.next().is_none());
 //! ```
+//!
+//! [`Timestamp`]: ../timestamp/struct.Timestamp.html
 
 mod error;
 mod r#impl;
@@ -43,6 +48,7 @@ mod iter;
 
 pub use self::{error::ParseMentionError, iter::MentionIter, r#impl::ParseMention};
 
+use crate::timestamp::Timestamp;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 
@@ -72,13 +78,15 @@ use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 /// use twilight_mention::parse::{MentionType, ParseMention};
 /// use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 ///
-/// let buf = "channel <#12> emoji <:name:34> role <@&56> user <@78>";
+/// let buf = "channel <#12> emoji <:name:34> <a:name:35> role <@&56> user <@78> at <t:90>";
 ///
 /// let mut iter = MentionType::iter(buf);
 /// assert!(matches!(iter.next(), Some((MentionType::Channel(ChannelId(12)), _, _))));
 /// assert!(matches!(iter.next(), Some((MentionType::Emoji(EmojiId(34)), _, _))));
+/// assert!(matches!(iter.next(), Some((MentionType::Emoji(EmojiId(35)), _, _))));
 /// assert!(matches!(iter.next(), Some((MentionType::Role(RoleId(56)), _, _))));
 /// assert!(matches!(iter.next(), Some((MentionType::User(UserId(78)), _, _))));
+/// assert!(matches!(iter.next(), Some((MentionType::Timestamp(_), _, _))));
 /// assert!(iter.next().is_none());
 /// ```
 ///
@@ -88,10 +96,12 @@ use twilight_model::id::{ChannelId, EmojiId, RoleId, UserId};
 pub enum MentionType {
     /// Channel mention.
     Channel(ChannelId),
-    /// Emoji mention.
+    /// Emoji mention, static or animated.
     Emoji(EmojiId),
     /// Role mention.
     Role(RoleId),
+    /// Timestamp mention.
+    Timestamp(Timestamp),
     /// User mention.
     User(UserId),
 }
@@ -102,6 +112,7 @@ impl Display for MentionType {
             Self::Channel(id) => Display::fmt(id, f),
             Self::Emoji(id) => Display::fmt(id, f),
             Self::Role(id) => Display::fmt(id, f),
+            Self::Timestamp(timestamp) => Display::fmt(&timestamp.unix(), f),
             Self::User(id) => Display::fmt(id, f),
         }
     }
@@ -0,0 +1,232 @@
+//! Timestamps that are formatted in each user's locale by the Discord client.
+//!
+//! Timestamps are mentioned via [`fmt::timestamp`] or [`Mention`], and can be
+//! parsed via [`ParseMention`].
+//!
+//! [`fmt::timestamp`]: ../fmt/fn.timestamp.html
+//! [`Mention`]: ../fmt/trait.Mention.html
+//! [`ParseMention`]: ../parse/trait.ParseMention.html
+
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Timestamp with an optional display style.
+///
+/// # Examples
+///
+/// Mention a timestamp as relative to the current time:
+///
+/// ```
+/// use twilight_mention::{
+///     timestamp::{Timestamp, TimestampStyle},
+///     Mention,
+/// };
+///
+/// let timestamp = Timestamp::new(1_624_047_064, Some(TimestampStyle::RelativeTime));
+/// assert_eq!("<t:1624047064:R>", timestamp.mention().to_string());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Timestamp {
+    style: Option<TimestampStyle>,
+    unix: u64,
+}
+
+impl Timestamp {
+    /// Create a new timestamp from a Unix timestamp in seconds and an
+    /// optional display style.
+    ///
+    /// If no style is provided then the Discord client uses
+    /// [`TimestampStyle::ShortDateTime`].
+    ///
+    /// [`TimestampStyle::ShortDateTime`]: enum.TimestampStyle.html#variant.ShortDateTime
+    #[must_use]
+    pub const fn new(unix: u64, style: Option<TimestampStyle>) -> Self {
+        Self { style, unix }
+    }
+
+    /// Display style of the timestamp, if any.
+    #[must_use]
+    pub const fn style(&self) -> Option<TimestampStyle> {
+        self.style
+    }
+
+    /// Unix timestamp in seconds.
+    #[must_use]
+    pub const fn unix(&self) -> u64 {
+        self.unix
+    }
+}
+
+/// Style of a timestamp, determining how the Discord client displays it.
+///
+/// The examples in the variants' documentation are for the `en-US` locale.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum TimestampStyle {
+    /// Long date, such as "20 April 2021".
+    ///
+    /// Displayed as `D`.
+    LongDate,
+    /// Long date and time, such as "Tuesday, 20 April 2021 16:20".
+    ///
+    /// Displayed as `F`.
+    LongDateTime,
+    /// Long time, such as "16:20:30".
+    ///
+    /// Displayed as `T`.
+    LongTime,
+    /// Time relative to now, such as "2 months ago".
+    ///
+    /// Displayed as `R`.
+    RelativeTime,
+    /// Short date, such as "20/04/2021".
+    ///
+    /// Displayed as `d`.
+    ShortDate,
+    /// Short date and time, such as "20 April 2021 16:20".
+    ///
+    /// This is the style the Discord client uses if none is provided.
+    ///
+    /// Displayed as `f`.
+    ShortDateTime,
+    /// Short time, such as "16:20".
+    ///
+    /// Displayed as `t`.
+    ShortTime,
+}
+
+impl TimestampStyle {
+    /// Retrieve the display character of the style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_mention::timestamp::TimestampStyle;
+    ///
+    /// assert_eq!("R", TimestampStyle::RelativeTime.name());
+    /// ```
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::LongDate => "D",
+            Self::LongDateTime => "F",
+            Self::LongTime => "T",
+            Self::RelativeTime => "R",
+            Self::ShortDate => "d",
+            Self::ShortDateTime => "f",
+            Self::ShortTime => "t",
+        }
+    }
+}
+
+impl Display for TimestampStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.name())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for TimestampStyle {
+    type Error = TimestampStyleConversionError<'a>;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Ok(match value {
+            "D" => Self::LongDate,
+            "F" => Self::LongDateTime,
+            "T" => Self::LongTime,
+            "R" => Self::RelativeTime,
+            "d" => Self::ShortDate,
+            "f" => Self::ShortDateTime,
+            "t" => Self::ShortTime,
+            _ => return Err(TimestampStyleConversionError { value }),
+        })
+    }
+}
+
+/// Converting a string into a [`TimestampStyle`] failed because it isn't a
+/// known style.
+///
+/// [`TimestampStyle`]: enum.TimestampStyle.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimestampStyleConversionError<'a> {
+    value: &'a str,
+}
+
+impl<'a> TimestampStyleConversionError<'a> {
+    /// Value that couldn't be converted.
+    #[must_use]
+    pub const fn value(&self) -> &'a str {
+        self.value
+    }
+}
+
+impl Display for TimestampStyleConversionError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_fmt(format_args!("'{}' is not a timestamp style", self.value))
+    }
+}
+
+impl Error for TimestampStyleConversionError<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Timestamp, TimestampStyle, TimestampStyleConversionError};
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::TryFrom,
+        error::Error,
+        fmt::{Debug, Display},
+        hash::Hash,
+    };
+
+    assert_impl_all!(Timestamp: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(
+        TimestampStyle: Clone,
+        Copy,
+        Debug,
+        Display,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync,
+        TryFrom<&'static str>
+    );
+    assert_impl_all!(TimestampStyleConversionError<'_>: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+
+    const STYLES: &[(TimestampStyle, &str)] = &[
+        (TimestampStyle::LongDate, "D"),
+        (TimestampStyle::LongDateTime, "F"),
+        (TimestampStyle::LongTime, "T"),
+        (TimestampStyle::RelativeTime, "R"),
+        (TimestampStyle::ShortDate, "d"),
+        (TimestampStyle::ShortDateTime, "f"),
+        (TimestampStyle::ShortTime, "t"),
+    ];
+
+    #[test]
+    fn test_timestamp() {
+        let timestamp = Timestamp::new(123, Some(TimestampStyle::ShortTime));
+        assert_eq!(123, timestamp.unix());
+        assert_eq!(Some(TimestampStyle::ShortTime), timestamp.style());
+        assert!(Timestamp::new(123, None).style().is_none());
+    }
+
+    #[test]
+    fn test_style_names() {
+        for (style, name) in STYLES {
+            assert_eq!(*name, style.name());
+            assert_eq!(*name, style.to_string());
+            assert_eq!(Ok(*style), TimestampStyle::try_from(*name));
+        }
+    }
+
+    #[test]
+    fn test_style_invalid() {
+        let error = TimestampStyle::try_from("x").unwrap_err();
+        assert_eq!("x", error.value());
+        assert_eq!("'x' is not a timestamp style", error.to_string());
+    }
+}
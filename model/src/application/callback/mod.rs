@@ -0,0 +1,9 @@
+//! Types for responding to interactions.
+
+mod modal;
+mod response;
+mod response_data;
+
+pub use self::{
+    modal::Modal, response::InteractionResponse, response_data::InteractionResponseData,
+};
@@ -0,0 +1,65 @@
+use crate::application::component::Component;
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Popup with text inputs shown to the user who invoked an interaction.
+///
+/// Its components must be action rows containing one text input each.
+/// Submitting the modal creates a modal submit interaction.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct Modal {
+    pub components: Vec<Component>,
+    pub custom_id: String,
+    pub title: String,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::Modal;
+    use crate::application::component::{
+        ActionRow, Component, ComponentType, TextInput, TextInputStyle,
+    };
+
+    #[test]
+    fn test_modal() {
+        let value = Modal {
+            components: vec![Component::ActionRow(ActionRow {
+                components: vec![Component::TextInput(TextInput {
+                    custom_id: "reason".to_owned(),
+                    kind: ComponentType::TextInput,
+                    label: "Reason".to_owned(),
+                    max_length: None,
+                    min_length: None,
+                    placeholder: None,
+                    required: None,
+                    style: TextInputStyle::Short,
+                    value: None,
+                })],
+                kind: ComponentType::ActionRow,
+            })],
+            custom_id: "report".to_owned(),
+            title: "Report".to_owned(),
+        };
+        let json = serde_json::json!({
+            "components": [
+                {
+                    "components": [
+                        {
+                            "custom_id": "reason",
+                            "label": "Reason",
+                            "style": 1,
+                            "type": 4,
+                        },
+                    ],
+                    "type": 1,
+                },
+            ],
+            "custom_id": "report",
+            "title": "Report",
+        });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+}
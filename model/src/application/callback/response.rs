@@ -0,0 +1,194 @@
+use super::{InteractionResponseData, Modal};
+#[cfg(feature = "serde-support")]
+use serde::{
+    de::{DeserializeOwned, Deserializer, Error as DeError},
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Serialize,
+};
+#[cfg(feature = "serde-support")]
+use serde_value::Value;
+
+/// Response to an interaction, either over the interaction callback route or
+/// as the body of the response to a request to an interactions endpoint.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum InteractionResponse {
+    /// Acknowledge a ping.
+    Pong,
+    /// Respond with a message.
+    ChannelMessageWithSource(InteractionResponseData),
+    /// Acknowledge the interaction and show a loading state, to send a
+    /// followup message later.
+    DeferredChannelMessageWithSource(InteractionResponseData),
+    /// Acknowledge a component interaction, to update its message later.
+    DeferredUpdateMessage,
+    /// Update the message a component is attached to.
+    UpdateMessage(InteractionResponseData),
+    /// Show a modal to the user.
+    Modal(Modal),
+}
+
+impl InteractionResponse {
+    /// Type of the response, as sent to Discord.
+    #[must_use]
+    pub const fn kind(&self) -> u8 {
+        match self {
+            Self::Pong => 1,
+            Self::ChannelMessageWithSource(_) => 4,
+            Self::DeferredChannelMessageWithSource(_) => 5,
+            Self::DeferredUpdateMessage => 6,
+            Self::UpdateMessage(_) => 7,
+            Self::Modal(_) => 9,
+        }
+    }
+}
+
+#[cfg(feature = "serde-support")]
+impl Serialize for InteractionResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("InteractionResponse", 2)?;
+
+        match self {
+            Self::Pong | Self::DeferredUpdateMessage => state.skip_field("data")?,
+            Self::ChannelMessageWithSource(data)
+            | Self::DeferredChannelMessageWithSource(data)
+            | Self::UpdateMessage(data) => state.serialize_field("data", data)?,
+            Self::Modal(modal) => state.serialize_field("data", modal)?,
+        }
+
+        state.serialize_field("type", &self.kind())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde-support")]
+#[derive(Deserialize)]
+struct InteractionResponseIntermediary {
+    data: Option<Value>,
+    #[serde(rename = "type")]
+    kind: u8,
+}
+
+#[cfg(feature = "serde-support")]
+impl<'de> Deserialize<'de> for InteractionResponse {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let response = InteractionResponseIntermediary::deserialize(deserializer)?;
+
+        Ok(match response.kind {
+            1 => Self::Pong,
+            4 => Self::ChannelMessageWithSource(deserialize_data(response.data)?),
+            5 => Self::DeferredChannelMessageWithSource(deserialize_data(response.data)?),
+            6 => Self::DeferredUpdateMessage,
+            7 => Self::UpdateMessage(deserialize_data(response.data)?),
+            9 => Self::Modal(deserialize_data(response.data)?),
+            other => {
+                return Err(DeError::custom(format_args!(
+                    "unknown interaction response type {}",
+                    other
+                )))
+            }
+        })
+    }
+}
+
+#[cfg(feature = "serde-support")]
+/// Deserialize the data of a response now that its type is known.
+fn deserialize_data<T: DeserializeOwned, E: DeError>(data: Option<Value>) -> Result<T, E> {
+    data.ok_or_else(|| DeError::missing_field("data"))?
+        .deserialize_into()
+        .map_err(DeError::custom)
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{InteractionResponse, InteractionResponseData, Modal};
+    use crate::application::component::{
+        ActionRow, Component, ComponentType, TextInput, TextInputStyle,
+    };
+
+    #[test]
+    fn test_pong() {
+        let value = InteractionResponse::Pong;
+        let json = serde_json::json!({ "type": 1 });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+
+    #[test]
+    fn test_deferred_update_message() {
+        let value = InteractionResponse::DeferredUpdateMessage;
+        let json = serde_json::json!({ "type": 6 });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+
+    #[test]
+    fn test_channel_message_with_source() {
+        let value = InteractionResponse::ChannelMessageWithSource(InteractionResponseData {
+            content: Some("hello".to_owned()),
+            ..InteractionResponseData::default()
+        });
+        let json = serde_json::json!({ "data": { "content": "hello" }, "type": 4 });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+
+    #[test]
+    fn test_modal() {
+        let value = InteractionResponse::Modal(Modal {
+            components: vec![Component::ActionRow(ActionRow {
+                components: vec![Component::TextInput(TextInput {
+                    custom_id: "reason".to_owned(),
+                    kind: ComponentType::TextInput,
+                    label: "Reason".to_owned(),
+                    max_length: Some(100),
+                    min_length: None,
+                    placeholder: None,
+                    required: Some(true),
+                    style: TextInputStyle::Paragraph,
+                    value: None,
+                })],
+                kind: ComponentType::ActionRow,
+            })],
+            custom_id: "report".to_owned(),
+            title: "Report".to_owned(),
+        });
+        let json = serde_json::json!({
+            "data": {
+                "components": [
+                    {
+                        "components": [
+                            {
+                                "custom_id": "reason",
+                                "label": "Reason",
+                                "max_length": 100,
+                                "required": true,
+                                "style": 2,
+                                "type": 4,
+                            },
+                        ],
+                        "type": 1,
+                    },
+                ],
+                "custom_id": "report",
+                "title": "Report",
+            },
+            "type": 9,
+        });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+
+    #[test]
+    fn test_missing_data() {
+        assert!(
+            serde_json::from_value::<InteractionResponse>(serde_json::json!({ "type": 4 }))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    application::component::Component,
+    channel::{
+        embed::Embed,
+        message::{AllowedMentions, MessageFlags},
+        Attachment,
+    },
+};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Message sent in response to an interaction, or used to update the message
+/// a component is attached to.
+///
+/// Only the provided fields are sent, so when updating a message fields that
+/// aren't provided are left unchanged.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct InteractionResponseData {
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub allowed_mentions: Option<AllowedMentions>,
+    /// Attachments to keep when updating a message.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub attachments: Option<Vec<Attachment>>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub components: Option<Vec<Component>>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub content: Option<String>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub embeds: Option<Vec<Embed>>,
+    /// Only [`EPHEMERAL`] and [`SUPPRESS_EMBEDS`] may be set.
+    ///
+    /// [`EPHEMERAL`]: ../../channel/message/struct.MessageFlags.html#associatedconstant.EPHEMERAL
+    /// [`SUPPRESS_EMBEDS`]: ../../channel/message/struct.MessageFlags.html#associatedconstant.SUPPRESS_EMBEDS
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub flags: Option<MessageFlags>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub tts: Option<bool>,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::InteractionResponseData;
+    use crate::channel::message::{AllowedMentions, MessageFlags};
+
+    #[test]
+    fn test_minimal() {
+        let value = InteractionResponseData::default();
+        let json = serde_json::json!({});
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+
+    #[test]
+    fn test_ephemeral() {
+        let value = InteractionResponseData {
+            allowed_mentions: Some(AllowedMentions::default()),
+            content: Some("only you can see this".to_owned()),
+            flags: Some(MessageFlags::EPHEMERAL),
+            ..InteractionResponseData::default()
+        };
+        let json = serde_json::json!({
+            "allowed_mentions": {
+                "parse": [],
+                "replied_user": false,
+            },
+            "content": "only you can see this",
+            "flags": 64,
+        });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+}
@@ -0,0 +1,140 @@
+mod option;
+mod option_type;
+
+pub use self::{
+    option::{CommandOption, CommandOptionChoice, CommandOptionChoiceValue},
+    option_type::CommandOptionType,
+};
+
+use crate::id::{ApplicationId, CommandId, GuildId};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Application command, such as a slash command.
+///
+/// When creating a command the IDs are omitted, and they're present when the
+/// command is returned by Discord. Commands with a guild ID are only
+/// available in that guild.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct Command {
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub application_id: Option<ApplicationId>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub default_permission: Option<bool>,
+    pub description: String,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub guild_id: Option<GuildId>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<CommandId>,
+    pub name: String,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub options: Vec<CommandOption>,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{
+        Command, CommandOption, CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType,
+    };
+    use crate::id::{ApplicationId, CommandId};
+
+    #[test]
+    fn test_command() {
+        let value = Command {
+            application_id: Some(ApplicationId(1)),
+            default_permission: None,
+            description: "Ban a user".to_owned(),
+            guild_id: None,
+            id: Some(CommandId(2)),
+            name: "ban".to_owned(),
+            options: vec![
+                CommandOption {
+                    choices: Vec::new(),
+                    description: "User to ban".to_owned(),
+                    kind: CommandOptionType::User,
+                    name: "user".to_owned(),
+                    options: Vec::new(),
+                    required: true,
+                },
+                CommandOption {
+                    choices: vec![
+                        CommandOptionChoice {
+                            name: "None".to_owned(),
+                            value: CommandOptionChoiceValue::Integer(0),
+                        },
+                        CommandOptionChoice {
+                            name: "Week".to_owned(),
+                            value: CommandOptionChoiceValue::Integer(7),
+                        },
+                    ],
+                    description: "Days of messages to delete".to_owned(),
+                    kind: CommandOptionType::Integer,
+                    name: "days".to_owned(),
+                    options: Vec::new(),
+                    required: false,
+                },
+            ],
+        };
+        let json = serde_json::json!({
+            "application_id": "1",
+            "description": "Ban a user",
+            "id": "2",
+            "name": "ban",
+            "options": [
+                {
+                    "description": "User to ban",
+                    "name": "user",
+                    "required": true,
+                    "type": 6,
+                },
+                {
+                    "choices": [
+                        {
+                            "name": "None",
+                            "value": 0,
+                        },
+                        {
+                            "name": "Week",
+                            "value": 7,
+                        },
+                    ],
+                    "description": "Days of messages to delete",
+                    "name": "days",
+                    "required": false,
+                    "type": 4,
+                },
+            ],
+        });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+
+    #[test]
+    fn test_choice_value() {
+        assert_eq!(
+            CommandOptionChoiceValue::Integer(1),
+            CommandOptionChoiceValue::from(1)
+        );
+        assert_eq!(
+            CommandOptionChoiceValue::String("a".to_owned()),
+            serde_json::from_value(serde_json::json!("a")).unwrap()
+        );
+    }
+}
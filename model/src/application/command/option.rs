@@ -0,0 +1,69 @@
+use super::CommandOptionType;
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Option of a [`Command`], such as an argument or a subcommand.
+///
+/// Subcommands and subcommand groups have nested options, while only string
+/// and integer options may have choices.
+///
+/// [`Command`]: struct.Command.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct CommandOption {
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub choices: Vec<CommandOptionChoice>,
+    pub description: String,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: CommandOptionType,
+    pub name: String,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub options: Vec<CommandOption>,
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    pub required: bool,
+}
+
+/// Predetermined value that a user can pick for a [`CommandOption`].
+///
+/// [`CommandOption`]: struct.CommandOption.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct CommandOptionChoice {
+    pub name: String,
+    pub value: CommandOptionChoiceValue,
+}
+
+/// Value of a [`CommandOptionChoice`].
+///
+/// [`CommandOptionChoice`]: struct.CommandOptionChoice.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde-support", serde(untagged))]
+pub enum CommandOptionChoiceValue {
+    Integer(i64),
+    String(String),
+}
+
+impl From<i64> for CommandOptionChoiceValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<String> for CommandOptionChoiceValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for CommandOptionChoiceValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
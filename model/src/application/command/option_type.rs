@@ -0,0 +1,52 @@
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of a [`CommandOption`].
+///
+/// [`CommandOption`]: struct.CommandOption.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum CommandOptionType {
+    SubCommand = 1,
+    SubCommandGroup = 2,
+    String = 3,
+    Integer = 4,
+    Boolean = 5,
+    User = 6,
+    Channel = 7,
+    Role = 8,
+}
+
+impl CommandOptionType {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Boolean => "Boolean",
+            Self::Channel => "Channel",
+            Self::Integer => "Integer",
+            Self::Role => "Role",
+            Self::String => "String",
+            Self::SubCommand => "SubCommand",
+            Self::SubCommandGroup => "SubCommandGroup",
+            Self::User => "User",
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::CommandOptionType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&CommandOptionType::SubCommand, &[Token::U8(1)]);
+        serde_test::assert_tokens(&CommandOptionType::SubCommandGroup, &[Token::U8(2)]);
+        serde_test::assert_tokens(&CommandOptionType::String, &[Token::U8(3)]);
+        serde_test::assert_tokens(&CommandOptionType::Integer, &[Token::U8(4)]);
+        serde_test::assert_tokens(&CommandOptionType::Boolean, &[Token::U8(5)]);
+        serde_test::assert_tokens(&CommandOptionType::User, &[Token::U8(6)]);
+        serde_test::assert_tokens(&CommandOptionType::Channel, &[Token::U8(7)]);
+        serde_test::assert_tokens(&CommandOptionType::Role, &[Token::U8(8)]);
+    }
+}
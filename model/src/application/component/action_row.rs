@@ -0,0 +1,14 @@
+use super::{Component, ComponentType};
+#[cfg(feature = "serde-support")]
+use serde::Serialize;
+
+/// Row of components on a message.
+///
+/// Action rows can't contain other action rows.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+pub struct ActionRow {
+    pub components: Vec<Component>,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: ComponentType,
+}
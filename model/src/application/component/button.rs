@@ -0,0 +1,67 @@
+use super::ComponentType;
+use crate::channel::ReactionType;
+#[cfg(feature = "serde-support")]
+use serde::Serialize;
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Clickable button on a message.
+///
+/// Buttons with the [`ButtonStyle::Link`] style have a URL rather than a
+/// custom ID, and don't create an interaction when clicked.
+///
+/// [`ButtonStyle::Link`]: enum.ButtonStyle.html#variant.Link
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+pub struct Button {
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub custom_id: Option<String>,
+    pub disabled: bool,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub emoji: Option<ReactionType>,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: ComponentType,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub label: Option<String>,
+    pub style: ButtonStyle,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum ButtonStyle {
+    Primary = 1,
+    Secondary = 2,
+    Success = 3,
+    Danger = 4,
+    Link = 5,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::ButtonStyle;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&ButtonStyle::Primary, &[Token::U8(1)]);
+        serde_test::assert_tokens(&ButtonStyle::Secondary, &[Token::U8(2)]);
+        serde_test::assert_tokens(&ButtonStyle::Success, &[Token::U8(3)]);
+        serde_test::assert_tokens(&ButtonStyle::Danger, &[Token::U8(4)]);
+        serde_test::assert_tokens(&ButtonStyle::Link, &[Token::U8(5)]);
+    }
+}
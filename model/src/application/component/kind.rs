@@ -0,0 +1,40 @@
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of a [`Component`].
+///
+/// [`Component`]: enum.Component.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum ComponentType {
+    ActionRow = 1,
+    Button = 2,
+    SelectMenu = 3,
+    TextInput = 4,
+}
+
+impl ComponentType {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ActionRow => "ActionRow",
+            Self::Button => "Button",
+            Self::SelectMenu => "SelectMenu",
+            Self::TextInput => "TextInput",
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::ComponentType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&ComponentType::ActionRow, &[Token::U8(1)]);
+        serde_test::assert_tokens(&ComponentType::Button, &[Token::U8(2)]);
+        serde_test::assert_tokens(&ComponentType::SelectMenu, &[Token::U8(3)]);
+        serde_test::assert_tokens(&ComponentType::TextInput, &[Token::U8(4)]);
+    }
+}
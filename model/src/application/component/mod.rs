@@ -0,0 +1,310 @@
+mod action_row;
+mod button;
+mod kind;
+mod select_menu;
+mod text_input;
+
+pub use self::{
+    action_row::ActionRow,
+    button::{Button, ButtonStyle},
+    kind::ComponentType,
+    select_menu::{SelectMenu, SelectMenuOption},
+    text_input::{TextInput, TextInputStyle},
+};
+
+#[cfg(feature = "serde-support")]
+use crate::channel::ReactionType;
+#[cfg(feature = "serde-support")]
+use serde::{
+    de::{DeserializeOwned, Deserializer, Error as DeError},
+    Deserialize, Serialize,
+};
+#[cfg(feature = "serde-support")]
+use serde_value::Value;
+
+/// Interactive element of a message or modal.
+///
+/// Components are sent in action rows. Buttons and select menus create
+/// interactions when used, which can be awaited with `twilight-standby` or
+/// handled as `InteractionCreate` events. Text inputs are only used in
+/// modals.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+#[cfg_attr(feature = "serde-support", serde(untagged))]
+pub enum Component {
+    ActionRow(ActionRow),
+    Button(Button),
+    SelectMenu(SelectMenu),
+    TextInput(TextInput),
+}
+
+impl Component {
+    /// Return the type of the inner component.
+    pub fn kind(&self) -> ComponentType {
+        match self {
+            Self::ActionRow(row) => row.kind,
+            Self::Button(button) => button.kind,
+            Self::SelectMenu(menu) => menu.kind,
+            Self::TextInput(input) => input.kind,
+        }
+    }
+
+    /// Return the custom ID of the inner component, if it has one.
+    ///
+    /// Action rows and link buttons don't have custom IDs.
+    pub fn custom_id(&self) -> Option<&str> {
+        match self {
+            Self::ActionRow(_) => None,
+            Self::Button(button) => button.custom_id.as_deref(),
+            Self::SelectMenu(menu) => Some(&menu.custom_id),
+            Self::TextInput(input) => Some(&input.custom_id),
+        }
+    }
+}
+
+impl From<ActionRow> for Component {
+    fn from(row: ActionRow) -> Self {
+        Self::ActionRow(row)
+    }
+}
+
+impl From<Button> for Component {
+    fn from(button: Button) -> Self {
+        Self::Button(button)
+    }
+}
+
+impl From<SelectMenu> for Component {
+    fn from(menu: SelectMenu) -> Self {
+        Self::SelectMenu(menu)
+    }
+}
+
+impl From<TextInput> for Component {
+    fn from(input: TextInput) -> Self {
+        Self::TextInput(input)
+    }
+}
+
+#[cfg(feature = "serde-support")]
+/// Union of the fields of every component type, used to determine what
+/// variant to deserialize into.
+#[cfg_attr(feature = "serde-support", derive(Deserialize))]
+struct ComponentIntermediary {
+    components: Option<Vec<Component>>,
+    custom_id: Option<String>,
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    disabled: bool,
+    emoji: Option<ReactionType>,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    kind: ComponentType,
+    label: Option<String>,
+    max_length: Option<u16>,
+    max_values: Option<u8>,
+    min_length: Option<u16>,
+    min_values: Option<u8>,
+    options: Option<Vec<SelectMenuOption>>,
+    placeholder: Option<String>,
+    required: Option<bool>,
+    /// Style of a button or text input, which are different types.
+    style: Option<Value>,
+    url: Option<String>,
+    value: Option<String>,
+}
+
+#[cfg(feature = "serde-support")]
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let component = ComponentIntermediary::deserialize(deserializer)?;
+        let kind = component.kind;
+
+        tracing::trace!(?kind, "deserializing component");
+
+        Ok(match kind {
+            ComponentType::ActionRow => Self::ActionRow(ActionRow {
+                components: component
+                    .components
+                    .ok_or_else(|| DeError::missing_field("components"))?,
+                kind,
+            }),
+            ComponentType::Button => Self::Button(Button {
+                custom_id: component.custom_id,
+                disabled: component.disabled,
+                emoji: component.emoji,
+                kind,
+                label: component.label,
+                style: deserialize_style(component.style)?,
+                url: component.url,
+            }),
+            ComponentType::SelectMenu => Self::SelectMenu(SelectMenu {
+                custom_id: component
+                    .custom_id
+                    .ok_or_else(|| DeError::missing_field("custom_id"))?,
+                disabled: component.disabled,
+                kind,
+                max_values: component.max_values,
+                min_values: component.min_values,
+                options: component
+                    .options
+                    .ok_or_else(|| DeError::missing_field("options"))?,
+                placeholder: component.placeholder,
+            }),
+            ComponentType::TextInput => Self::TextInput(TextInput {
+                custom_id: component
+                    .custom_id
+                    .ok_or_else(|| DeError::missing_field("custom_id"))?,
+                kind,
+                label: component
+                    .label
+                    .ok_or_else(|| DeError::missing_field("label"))?,
+                max_length: component.max_length,
+                min_length: component.min_length,
+                placeholder: component.placeholder,
+                required: component.required,
+                style: deserialize_style(component.style)?,
+                value: component.value,
+            }),
+        })
+    }
+}
+
+#[cfg(feature = "serde-support")]
+/// Deserialize the style of a component now that its type is known.
+fn deserialize_style<T: DeserializeOwned, E: DeError>(style: Option<Value>) -> Result<T, E> {
+    style
+        .ok_or_else(|| DeError::missing_field("style"))?
+        .deserialize_into()
+        .map_err(DeError::custom)
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{
+        ActionRow, Button, ButtonStyle, Component, ComponentType, SelectMenu, SelectMenuOption,
+        TextInput, TextInputStyle,
+    };
+    use crate::{channel::ReactionType, id::EmojiId};
+
+    #[test]
+    fn test_action_row() {
+        let value = Component::ActionRow(ActionRow {
+            components: vec![
+                Component::Button(Button {
+                    custom_id: Some("next".to_owned()),
+                    disabled: false,
+                    emoji: Some(ReactionType::Custom {
+                        animated: false,
+                        id: EmojiId(1),
+                        name: Some("arrow".to_owned()),
+                    }),
+                    kind: ComponentType::Button,
+                    label: Some("Next".to_owned()),
+                    style: ButtonStyle::Primary,
+                    url: None,
+                }),
+                Component::Button(Button {
+                    custom_id: None,
+                    disabled: false,
+                    emoji: None,
+                    kind: ComponentType::Button,
+                    label: Some("Docs".to_owned()),
+                    style: ButtonStyle::Link,
+                    url: Some("https://twilight.rs".to_owned()),
+                }),
+            ],
+            kind: ComponentType::ActionRow,
+        });
+        let json = serde_json::json!({
+            "components": [
+                {
+                    "custom_id": "next",
+                    "disabled": false,
+                    "emoji": {
+                        "animated": false,
+                        "id": "1",
+                        "name": "arrow",
+                    },
+                    "label": "Next",
+                    "style": 1,
+                    "type": 2,
+                },
+                {
+                    "disabled": false,
+                    "label": "Docs",
+                    "style": 5,
+                    "type": 2,
+                    "url": "https://twilight.rs",
+                },
+            ],
+            "type": 1,
+        });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+        assert_eq!(ComponentType::ActionRow, value.kind());
+        assert!(value.custom_id().is_none());
+    }
+
+    #[test]
+    fn test_select_menu() {
+        let value = Component::SelectMenu(SelectMenu {
+            custom_id: "color".to_owned(),
+            disabled: false,
+            kind: ComponentType::SelectMenu,
+            max_values: Some(2),
+            min_values: None,
+            options: vec![SelectMenuOption {
+                default: true,
+                description: None,
+                emoji: None,
+                label: "Red".to_owned(),
+                value: "red".to_owned(),
+            }],
+            placeholder: Some("Pick a color".to_owned()),
+        });
+
+        let deserialized = serde_json::from_value(serde_json::json!({
+            "custom_id": "color",
+            "max_values": 2,
+            "options": [
+                {
+                    "default": true,
+                    "label": "Red",
+                    "value": "red",
+                },
+            ],
+            "placeholder": "Pick a color",
+            "type": 3,
+        }))
+        .unwrap();
+
+        assert_eq!(value, deserialized);
+        assert_eq!(Some("color"), value.custom_id());
+    }
+
+    #[test]
+    fn test_text_input() {
+        let value = Component::TextInput(TextInput {
+            custom_id: "reason".to_owned(),
+            kind: ComponentType::TextInput,
+            label: "Reason".to_owned(),
+            max_length: Some(512),
+            min_length: None,
+            placeholder: None,
+            required: Some(true),
+            style: TextInputStyle::Paragraph,
+            value: None,
+        });
+        let json = serde_json::json!({
+            "custom_id": "reason",
+            "label": "Reason",
+            "max_length": 512,
+            "required": true,
+            "style": 2,
+            "type": 4,
+        });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+}
@@ -0,0 +1,52 @@
+use super::ComponentType;
+use crate::channel::ReactionType;
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Dropdown menu of options on a message.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+pub struct SelectMenu {
+    pub custom_id: String,
+    pub disabled: bool,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: ComponentType,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub max_values: Option<u8>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub min_values: Option<u8>,
+    pub options: Vec<SelectMenuOption>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub placeholder: Option<String>,
+}
+
+/// Option that can be chosen in a [`SelectMenu`].
+///
+/// [`SelectMenu`]: struct.SelectMenu.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct SelectMenuOption {
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    pub default: bool,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<String>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub emoji: Option<ReactionType>,
+    pub label: String,
+    pub value: String,
+}
@@ -0,0 +1,66 @@
+use super::ComponentType;
+#[cfg(feature = "serde-support")]
+use serde::Serialize;
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Text field in a modal.
+///
+/// Text inputs can only be sent in modals, not on messages.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+pub struct TextInput {
+    pub custom_id: String,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: ComponentType,
+    pub label: String,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub max_length: Option<u16>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub min_length: Option<u16>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub placeholder: Option<String>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub required: Option<bool>,
+    pub style: TextInputStyle,
+    /// Text pre-filled in the field.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum TextInputStyle {
+    /// Single line of text.
+    Short = 1,
+    /// Multiple lines of text.
+    Paragraph = 2,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::TextInputStyle;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&TextInputStyle::Short, &[Token::U8(1)]);
+        serde_test::assert_tokens(&TextInputStyle::Paragraph, &[Token::U8(2)]);
+    }
+}
@@ -0,0 +1,88 @@
+use super::{InteractionMember, InteractionType};
+use crate::{
+    id::{ApplicationId, ChannelId, CommandId, GuildId, InteractionId, UserId},
+    user::User,
+};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Application command, such as a slash command, invoked by a user.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+pub struct ApplicationCommand {
+    pub application_id: ApplicationId,
+    pub channel_id: ChannelId,
+    pub data: CommandData,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub guild_id: Option<GuildId>,
+    pub id: InteractionId,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: InteractionType,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub member: Option<InteractionMember>,
+    pub token: String,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub user: Option<User>,
+}
+
+impl ApplicationCommand {
+    /// Return the ID of the user that invoked the command.
+    pub fn author_id(&self) -> Option<UserId> {
+        super::author_id(self.member.as_ref(), self.user.as_ref())
+    }
+}
+
+/// Data of the command that was invoked.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct CommandData {
+    pub id: CommandId,
+    pub name: String,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub options: Vec<CommandDataOption>,
+}
+
+/// Option provided by the user when invoking a command.
+///
+/// Subcommands and subcommand groups have nested options rather than a value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct CommandDataOption {
+    pub name: String,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub options: Vec<CommandDataOption>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub value: Option<CommandOptionValue>,
+}
+
+/// Value of a [`CommandDataOption`].
+///
+/// Users, channels, and roles are provided as the string form of their ID.
+///
+/// [`CommandDataOption`]: struct.CommandDataOption.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde-support", serde(untagged))]
+pub enum CommandOptionValue {
+    Boolean(bool),
+    Integer(i64),
+    String(String),
+}
@@ -0,0 +1,40 @@
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of an [`Interaction`].
+///
+/// [`Interaction`]: enum.Interaction.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum InteractionType {
+    Ping = 1,
+    ApplicationCommand = 2,
+    MessageComponent = 3,
+    ModalSubmit = 5,
+}
+
+impl InteractionType {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::ApplicationCommand => "ApplicationCommand",
+            Self::MessageComponent => "MessageComponent",
+            Self::ModalSubmit => "ModalSubmit",
+            Self::Ping => "Ping",
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::InteractionType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&InteractionType::Ping, &[Token::U8(1)]);
+        serde_test::assert_tokens(&InteractionType::ApplicationCommand, &[Token::U8(2)]);
+        serde_test::assert_tokens(&InteractionType::MessageComponent, &[Token::U8(3)]);
+        serde_test::assert_tokens(&InteractionType::ModalSubmit, &[Token::U8(5)]);
+    }
+}
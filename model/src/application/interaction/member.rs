@@ -0,0 +1,20 @@
+use crate::{guild::Permissions, id::RoleId, user::User};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Member that invoked an interaction in a guild.
+///
+/// Unlike other members, this includes the member's permissions in the channel
+/// the interaction was invoked in.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct InteractionMember {
+    pub deaf: bool,
+    pub joined_at: Option<String>,
+    pub mute: bool,
+    pub nick: Option<String>,
+    pub permissions: Option<Permissions>,
+    pub premium_since: Option<String>,
+    pub roles: Vec<RoleId>,
+    pub user: User,
+}
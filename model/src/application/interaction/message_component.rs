@@ -0,0 +1,60 @@
+use super::{InteractionMember, InteractionType};
+use crate::{
+    application::component::ComponentType,
+    channel::Message,
+    id::{ApplicationId, ChannelId, GuildId, InteractionId, UserId},
+    user::User,
+};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Message component, such as a button, used by a user.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+pub struct MessageComponentInteraction {
+    pub application_id: ApplicationId,
+    pub channel_id: ChannelId,
+    pub data: MessageComponentInteractionData,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub guild_id: Option<GuildId>,
+    pub id: InteractionId,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: InteractionType,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub member: Option<InteractionMember>,
+    /// Message the component is attached to.
+    pub message: Message,
+    pub token: String,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub user: Option<User>,
+}
+
+impl MessageComponentInteraction {
+    /// Return the ID of the user that used the component.
+    pub fn author_id(&self) -> Option<UserId> {
+        super::author_id(self.member.as_ref(), self.user.as_ref())
+    }
+}
+
+/// Data of the component that was used.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct MessageComponentInteractionData {
+    pub component_type: ComponentType,
+    pub custom_id: String,
+    /// Values chosen by the user in a select menu.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub values: Vec<String>,
+}
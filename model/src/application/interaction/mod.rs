@@ -0,0 +1,434 @@
+mod application_command;
+mod kind;
+mod member;
+mod message_component;
+mod modal_submit;
+mod ping;
+
+pub use self::{
+    application_command::{ApplicationCommand, CommandData, CommandDataOption, CommandOptionValue},
+    kind::InteractionType,
+    member::InteractionMember,
+    message_component::{MessageComponentInteraction, MessageComponentInteractionData},
+    modal_submit::{
+        ModalInteractionData, ModalInteractionDataActionRow, ModalInteractionDataComponent,
+        ModalSubmitInteraction,
+    },
+    ping::Ping,
+};
+
+#[cfg(feature = "serde-support")]
+use crate::{channel::Message, id::ApplicationId};
+use crate::{
+    id::{ChannelId, GuildId, InteractionId, UserId},
+    user::User,
+};
+#[cfg(feature = "serde-support")]
+use serde::{
+    de::{DeserializeOwned, Deserializer, Error as DeError},
+    Deserialize, Serialize,
+};
+#[cfg(feature = "serde-support")]
+use serde_value::Value;
+
+/// Interaction created by a user, such as by invoking a command, clicking a
+/// button, or submitting a modal.
+///
+/// Interactions are received over the gateway as `InteractionCreate` events or
+/// over an interactions endpoint.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+#[cfg_attr(feature = "serde-support", serde(untagged))]
+pub enum Interaction {
+    ApplicationCommand(Box<ApplicationCommand>),
+    MessageComponent(Box<MessageComponentInteraction>),
+    ModalSubmit(Box<ModalSubmitInteraction>),
+    Ping(Box<Ping>),
+}
+
+impl Interaction {
+    /// Return the ID of the inner interaction.
+    pub fn id(&self) -> InteractionId {
+        match self {
+            Self::ApplicationCommand(command) => command.id,
+            Self::MessageComponent(component) => component.id,
+            Self::ModalSubmit(modal) => modal.id,
+            Self::Ping(ping) => ping.id,
+        }
+    }
+
+    /// Return the type of the inner interaction.
+    pub fn kind(&self) -> InteractionType {
+        match self {
+            Self::ApplicationCommand(command) => command.kind,
+            Self::MessageComponent(component) => component.kind,
+            Self::ModalSubmit(modal) => modal.kind,
+            Self::Ping(ping) => ping.kind,
+        }
+    }
+
+    /// Return the ID of the channel the interaction was invoked in.
+    ///
+    /// Pings aren't invoked in a channel.
+    pub fn channel_id(&self) -> Option<ChannelId> {
+        match self {
+            Self::ApplicationCommand(command) => Some(command.channel_id),
+            Self::MessageComponent(component) => Some(component.channel_id),
+            Self::ModalSubmit(modal) => Some(modal.channel_id),
+            Self::Ping(_) => None,
+        }
+    }
+
+    /// Return the ID of the guild the interaction was invoked in.
+    ///
+    /// This is `None` for interactions invoked in private channels and pings.
+    pub fn guild_id(&self) -> Option<GuildId> {
+        match self {
+            Self::ApplicationCommand(command) => command.guild_id,
+            Self::MessageComponent(component) => component.guild_id,
+            Self::ModalSubmit(modal) => modal.guild_id,
+            Self::Ping(_) => None,
+        }
+    }
+
+    /// Return the ID of the user that invoked the interaction.
+    ///
+    /// In guilds this is the ID of the member's user, and in private channels
+    /// it's the ID of the user. Pings aren't invoked by a user.
+    pub fn author_id(&self) -> Option<UserId> {
+        match self {
+            Self::ApplicationCommand(command) => command.author_id(),
+            Self::MessageComponent(component) => component.author_id(),
+            Self::ModalSubmit(modal) => modal.author_id(),
+            Self::Ping(_) => None,
+        }
+    }
+
+    /// Return the token of the inner interaction, used to respond to it.
+    pub fn token(&self) -> &str {
+        match self {
+            Self::ApplicationCommand(command) => &command.token,
+            Self::MessageComponent(component) => &component.token,
+            Self::ModalSubmit(modal) => &modal.token,
+            Self::Ping(ping) => &ping.token,
+        }
+    }
+}
+
+#[cfg(feature = "serde-support")]
+/// Union of the fields of every interaction type.
+///
+/// The shape of the data depends on the type of the interaction, so it's
+/// deserialized once the type is known.
+#[cfg_attr(feature = "serde-support", derive(Deserialize))]
+struct InteractionIntermediary {
+    application_id: ApplicationId,
+    channel_id: Option<ChannelId>,
+    data: Option<Value>,
+    guild_id: Option<GuildId>,
+    id: InteractionId,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    kind: InteractionType,
+    member: Option<InteractionMember>,
+    message: Option<Message>,
+    token: String,
+    user: Option<User>,
+}
+
+#[cfg(feature = "serde-support")]
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let interaction = InteractionIntermediary::deserialize(deserializer)?;
+        let id = interaction.id;
+        let kind = interaction.kind;
+
+        tracing::trace!(%id, ?kind, "deserializing interaction");
+
+        Ok(match kind {
+            InteractionType::ApplicationCommand => {
+                Self::ApplicationCommand(Box::new(ApplicationCommand {
+                    application_id: interaction.application_id,
+                    channel_id: interaction
+                        .channel_id
+                        .ok_or_else(|| DeError::missing_field("channel_id"))?,
+                    data: deserialize_data(interaction.data)?,
+                    guild_id: interaction.guild_id,
+                    id,
+                    kind,
+                    member: interaction.member,
+                    token: interaction.token,
+                    user: interaction.user,
+                }))
+            }
+            InteractionType::MessageComponent => {
+                Self::MessageComponent(Box::new(MessageComponentInteraction {
+                    application_id: interaction.application_id,
+                    channel_id: interaction
+                        .channel_id
+                        .ok_or_else(|| DeError::missing_field("channel_id"))?,
+                    data: deserialize_data(interaction.data)?,
+                    guild_id: interaction.guild_id,
+                    id,
+                    kind,
+                    member: interaction.member,
+                    message: interaction
+                        .message
+                        .ok_or_else(|| DeError::missing_field("message"))?,
+                    token: interaction.token,
+                    user: interaction.user,
+                }))
+            }
+            InteractionType::ModalSubmit => Self::ModalSubmit(Box::new(ModalSubmitInteraction {
+                application_id: interaction.application_id,
+                channel_id: interaction
+                    .channel_id
+                    .ok_or_else(|| DeError::missing_field("channel_id"))?,
+                data: deserialize_data(interaction.data)?,
+                guild_id: interaction.guild_id,
+                id,
+                kind,
+                member: interaction.member,
+                message: interaction.message,
+                token: interaction.token,
+                user: interaction.user,
+            })),
+            InteractionType::Ping => Self::Ping(Box::new(Ping {
+                application_id: interaction.application_id,
+                id,
+                kind,
+                token: interaction.token,
+            })),
+        })
+    }
+}
+
+/// Return the ID of the user that invoked an interaction, whether it was
+/// invoked by a member in a guild or a user in a private channel.
+fn author_id(member: Option<&InteractionMember>, user: Option<&User>) -> Option<UserId> {
+    member
+        .map(|member| member.user.id)
+        .or_else(|| user.map(|user| user.id))
+}
+
+#[cfg(feature = "serde-support")]
+/// Deserialize the data of an interaction now that its type is known.
+fn deserialize_data<T: DeserializeOwned, E: DeError>(data: Option<Value>) -> Result<T, E> {
+    data.ok_or_else(|| DeError::missing_field("data"))?
+        .deserialize_into()
+        .map_err(DeError::custom)
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{
+        CommandData, CommandDataOption, CommandOptionValue, Interaction, InteractionType,
+        MessageComponentInteractionData,
+    };
+    use crate::{
+        application::component::ComponentType,
+        id::{ApplicationId, ChannelId, CommandId, GuildId, InteractionId, UserId},
+    };
+    use serde_json::{json, Value};
+
+    fn user() -> Value {
+        json!({
+            "avatar": null,
+            "discriminator": "0001",
+            "id": "5",
+            "username": "twilight",
+        })
+    }
+
+    #[test]
+    fn test_ping() {
+        let value = serde_json::from_value::<Interaction>(json!({
+            "application_id": "1",
+            "id": "2",
+            "token": "token",
+            "type": 1,
+        }))
+        .unwrap();
+
+        assert_eq!(InteractionId(2), value.id());
+        assert_eq!(InteractionType::Ping, value.kind());
+        assert!(value.author_id().is_none());
+        assert_eq!("token", value.token());
+    }
+
+    #[test]
+    fn test_application_command() {
+        let value = serde_json::from_value::<Interaction>(json!({
+            "application_id": "1",
+            "channel_id": "3",
+            "data": {
+                "id": "6",
+                "name": "ban",
+                "options": [
+                    {
+                        "name": "user",
+                        "value": "5",
+                    },
+                    {
+                        "name": "days",
+                        "value": 7,
+                    },
+                ],
+            },
+            "guild_id": "4",
+            "id": "2",
+            "member": {
+                "deaf": false,
+                "joined_at": "2021-01-01T00:00:00.000000+00:00",
+                "mute": false,
+                "nick": null,
+                "permissions": "8",
+                "premium_since": null,
+                "roles": [],
+                "user": user(),
+            },
+            "token": "token",
+            "type": 2,
+            "version": 1,
+        }))
+        .unwrap();
+
+        assert_eq!(Some(ChannelId(3)), value.channel_id());
+        assert_eq!(Some(GuildId(4)), value.guild_id());
+        assert_eq!(Some(UserId(5)), value.author_id());
+
+        let command = match value {
+            Interaction::ApplicationCommand(command) => command,
+            other => panic!("expected application command, got {:?}", other),
+        };
+
+        assert_eq!(ApplicationId(1), command.application_id);
+        assert_eq!(
+            CommandData {
+                id: CommandId(6),
+                name: "ban".to_owned(),
+                options: vec![
+                    CommandDataOption {
+                        name: "user".to_owned(),
+                        options: Vec::new(),
+                        value: Some(CommandOptionValue::String("5".to_owned())),
+                    },
+                    CommandDataOption {
+                        name: "days".to_owned(),
+                        options: Vec::new(),
+                        value: Some(CommandOptionValue::Integer(7)),
+                    },
+                ],
+            },
+            command.data
+        );
+    }
+
+    #[test]
+    fn test_message_component() {
+        let value = serde_json::from_value::<Interaction>(json!({
+            "application_id": "1",
+            "channel_id": "3",
+            "data": {
+                "component_type": 3,
+                "custom_id": "color",
+                "values": ["red"],
+            },
+            "id": "2",
+            "message": {
+                "attachments": [],
+                "author": user(),
+                "channel_id": "3",
+                "content": "pick a color",
+                "edited_timestamp": null,
+                "embeds": [],
+                "id": "7",
+                "mention_everyone": false,
+                "mention_roles": [],
+                "mentions": [],
+                "pinned": false,
+                "timestamp": "2021-01-01T00:00:00.000000+00:00",
+                "tts": false,
+                "type": 0,
+            },
+            "token": "token",
+            "type": 3,
+            "user": user(),
+        }))
+        .unwrap();
+
+        assert!(value.guild_id().is_none());
+        assert_eq!(Some(UserId(5)), value.author_id());
+
+        let component = match value {
+            Interaction::MessageComponent(component) => component,
+            other => panic!("expected message component, got {:?}", other),
+        };
+
+        assert_eq!(
+            MessageComponentInteractionData {
+                component_type: ComponentType::SelectMenu,
+                custom_id: "color".to_owned(),
+                values: vec!["red".to_owned()],
+            },
+            component.data
+        );
+        assert_eq!("pick a color", &*component.message.content);
+    }
+
+    #[test]
+    fn test_missing_data() {
+        let result = serde_json::from_value::<Interaction>(json!({
+            "application_id": "1",
+            "channel_id": "3",
+            "id": "2",
+            "token": "token",
+            "type": 2,
+        }));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_modal_submit() {
+        let value = serde_json::from_value::<Interaction>(json!({
+            "application_id": "1",
+            "channel_id": "3",
+            "data": {
+                "components": [
+                    {
+                        "components": [
+                            {
+                                "custom_id": "reason",
+                                "type": 4,
+                                "value": "spam",
+                            },
+                        ],
+                        "type": 1,
+                    },
+                ],
+                "custom_id": "report",
+            },
+            "id": "2",
+            "token": "token",
+            "type": 5,
+            "user": user(),
+        }))
+        .unwrap();
+
+        assert_eq!(Some(UserId(5)), value.author_id());
+
+        let modal = match value {
+            Interaction::ModalSubmit(modal) => modal,
+            other => panic!("expected modal submit, got {:?}", other),
+        };
+
+        assert_eq!("report", modal.data.custom_id);
+        assert_eq!(
+            ComponentType::TextInput,
+            modal.data.components[0].components[0].kind
+        );
+        assert_eq!(Some("spam"), modal.data.value("reason"));
+        assert!(modal.data.value("details").is_none());
+        assert!(modal.message.is_none());
+    }
+}
@@ -0,0 +1,93 @@
+use super::{InteractionMember, InteractionType};
+use crate::{
+    application::component::ComponentType,
+    channel::Message,
+    id::{ApplicationId, ChannelId, GuildId, InteractionId, UserId},
+    user::User,
+};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Modal submitted by a user.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+pub struct ModalSubmitInteraction {
+    pub application_id: ApplicationId,
+    pub channel_id: ChannelId,
+    pub data: ModalInteractionData,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub guild_id: Option<GuildId>,
+    pub id: InteractionId,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: InteractionType,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub member: Option<InteractionMember>,
+    /// Message the modal was opened from, if it was opened from a component.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message>,
+    pub token: String,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub user: Option<User>,
+}
+
+impl ModalSubmitInteraction {
+    /// Return the ID of the user that submitted the modal.
+    pub fn author_id(&self) -> Option<UserId> {
+        super::author_id(self.member.as_ref(), self.user.as_ref())
+    }
+}
+
+/// Data of the modal that was submitted.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct ModalInteractionData {
+    pub components: Vec<ModalInteractionDataActionRow>,
+    pub custom_id: String,
+}
+
+impl ModalInteractionData {
+    /// Return the value submitted for the text input with a custom ID.
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        self.values()
+            .find(|(id, _)| *id == custom_id)
+            .map(|(_, value)| value)
+    }
+
+    /// Return an iterator over the custom IDs and submitted values of the
+    /// text inputs in the modal.
+    pub fn values(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.components
+            .iter()
+            .flat_map(|row| row.components.iter())
+            .map(|component| (component.custom_id.as_str(), component.value.as_str()))
+    }
+}
+
+/// Row of submitted components in a modal.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct ModalInteractionDataActionRow {
+    pub components: Vec<ModalInteractionDataComponent>,
+}
+
+/// Component submitted in a modal, along with its value.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct ModalInteractionDataComponent {
+    pub custom_id: String,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: ComponentType,
+    pub value: String,
+}
@@ -0,0 +1,15 @@
+use super::InteractionType;
+use crate::id::{ApplicationId, InteractionId};
+#[cfg(feature = "serde-support")]
+use serde::Serialize;
+
+/// Ping sent by Discord to check that an interactions endpoint is reachable.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+pub struct Ping {
+    pub application_id: ApplicationId,
+    pub id: InteractionId,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: InteractionType,
+    pub token: String,
+}
@@ -0,0 +1,7 @@
+//! Types for application commands, interactions, and the message components
+//! that create them.
+
+pub mod callback;
+pub mod command;
+pub mod component;
+pub mod interaction;
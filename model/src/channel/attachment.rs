@@ -1,18 +1,20 @@
 use crate::id::AttachmentId;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Attachment {
-    pub filename: String,
+    pub filename: Box<str>,
     pub height: Option<u64>,
     pub id: AttachmentId,
-    pub proxy_url: String,
+    pub proxy_url: Box<str>,
     pub size: u64,
-    pub url: String,
+    pub url: Box<str>,
     pub width: Option<u64>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::Attachment;
     use crate::id::AttachmentId;
@@ -21,12 +23,12 @@ mod tests {
     #[test]
     fn test_attachment() {
         let value = Attachment {
-            filename: "a.png".to_owned(),
+            filename: "a.png".into(),
             height: Some(184),
             id: AttachmentId(700_000_000_000_000_000),
-            proxy_url: "https://cdn.example.com/1.png".to_owned(),
+            proxy_url: "https://cdn.example.com/1.png".into(),
             size: 13_593,
-            url: "https://example.com/1.png".to_owned(),
+            url: "https://example.com/1.png".into(),
             width: Some(184),
         };
 
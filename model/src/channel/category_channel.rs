@@ -2,20 +2,22 @@ use crate::{
     channel::{permission_overwrite::PermissionOverwrite, ChannelType},
     id::{ChannelId, GuildId},
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct CategoryChannel {
     pub guild_id: Option<GuildId>,
     pub id: ChannelId,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: ChannelType,
     pub name: String,
     pub permission_overwrites: Vec<PermissionOverwrite>,
     pub position: i64,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{CategoryChannel, ChannelId, ChannelType, GuildId};
     use serde_test::Token;
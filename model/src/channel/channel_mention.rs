@@ -2,18 +2,20 @@ use crate::{
     channel::ChannelType,
     id::{ChannelId, GuildId},
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct ChannelMention {
     pub guild_id: GuildId,
     pub id: ChannelId,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: ChannelType,
     pub name: String,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ChannelId, ChannelMention, ChannelType, GuildId};
     use serde_test::Token;
@@ -1,6 +1,8 @@
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct EmbedAuthor {
     pub icon_url: Option<String>,
     pub name: Option<String>,
@@ -8,7 +10,7 @@ pub struct EmbedAuthor {
     pub url: Option<String>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::EmbedAuthor;
     use serde_test::Token;
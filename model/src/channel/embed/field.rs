@@ -1,14 +1,16 @@
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct EmbedField {
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub inline: bool,
     pub name: String,
     pub value: String,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::EmbedField;
     use serde_test::Token;
@@ -1,6 +1,8 @@
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct EmbedImage {
     pub height: Option<u64>,
     pub proxy_url: Option<String>,
@@ -8,7 +10,7 @@ pub struct EmbedImage {
     pub width: Option<u64>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::EmbedImage;
     use serde_test::Token;
@@ -11,19 +11,21 @@ pub use self::{
     provider::EmbedProvider, thumbnail::EmbedThumbnail, video::EmbedVideo,
 };
 
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Embed {
     pub author: Option<EmbedAuthor>,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub color: Option<u32>,
     pub description: Option<String>,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub fields: Vec<EmbedField>,
     pub footer: Option<EmbedFooter>,
     pub image: Option<EmbedImage>,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: String,
     pub provider: Option<EmbedProvider>,
     pub thumbnail: Option<EmbedThumbnail>,
@@ -33,7 +35,7 @@ pub struct Embed {
     pub video: Option<EmbedVideo>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::Embed;
     use serde_test::Token;
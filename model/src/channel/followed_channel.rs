@@ -1,4 +1,5 @@
 use crate::id::{ChannelId, WebhookId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
 /// An object indicating that following a news channel
@@ -10,13 +11,14 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`ChannelId`]: ../id/struct.ChannelId.html
 /// [`WebhookId`]: ../id/struct.WebhookId.html
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct FollowedChannel {
     pub channel_id: ChannelId,
     pub webhook_id: WebhookId,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ChannelId, FollowedChannel, WebhookId};
     use serde_test::Token;
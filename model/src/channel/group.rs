@@ -3,14 +3,16 @@ use crate::{
     id::{ApplicationId, ChannelId, MessageId, UserId},
     user::User,
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Group {
     pub application_id: Option<ApplicationId>,
     pub icon: Option<String>,
     pub id: ChannelId,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: ChannelType,
     pub last_message_id: Option<MessageId>,
     pub last_pin_timestamp: Option<String>,
@@ -19,7 +21,7 @@ pub struct Group {
     pub recipients: Vec<User>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ApplicationId, ChannelId, ChannelType, Group, MessageId, UserId};
     use serde_test::Token;
@@ -1,8 +1,8 @@
+#[cfg(feature = "serde-support")]
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
-)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
 #[repr(u8)]
 pub enum MessageActivityType {
     Join = 1,
@@ -11,7 +11,7 @@ pub enum MessageActivityType {
     JoinRequest = 5,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::MessageActivityType;
     use serde_test::Token;
@@ -0,0 +1,76 @@
+use crate::id::{RoleId, UserId};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Which mentions in the content of a message may ping anyone.
+///
+/// Mentions of users and roles may be allowed either by type via `parse` or
+/// explicitly via `users` and `roles`, but not both.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct AllowedMentions {
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    pub parse: Vec<MentionType>,
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    pub replied_user: bool,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub roles: Vec<RoleId>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub users: Vec<UserId>,
+}
+
+/// Type of mention that is parsed from the content of a message.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde-support", serde(rename_all = "snake_case"))]
+pub enum MentionType {
+    /// `@everyone` and `@here` mentions.
+    Everyone,
+    /// Role mentions.
+    Roles,
+    /// User mentions.
+    Users,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{AllowedMentions, MentionType};
+    use crate::id::{RoleId, UserId};
+
+    #[test]
+    fn test_minimal() {
+        let value = AllowedMentions::default();
+        let json = serde_json::json!({
+            "parse": [],
+            "replied_user": false,
+        });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+
+    #[test]
+    fn test_complete() {
+        let value = AllowedMentions {
+            parse: vec![MentionType::Everyone],
+            replied_user: true,
+            roles: vec![RoleId(1)],
+            users: vec![UserId(2)],
+        };
+        let json = serde_json::json!({
+            "parse": ["everyone"],
+            "replied_user": true,
+            "roles": ["1"],
+            "users": ["2"],
+        });
+
+        assert_eq!(json, serde_json::to_value(&value).unwrap());
+        assert_eq!(value, serde_json::from_value(json).unwrap());
+    }
+}
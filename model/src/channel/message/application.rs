@@ -1,7 +1,9 @@
 use crate::id::ApplicationId;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct MessageApplication {
     pub cover_image: Option<String>,
     pub description: String,
@@ -10,7 +12,7 @@ pub struct MessageApplication {
     pub name: String,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ApplicationId, MessageApplication};
     use serde_test::Token;
@@ -1,10 +1,10 @@
 use crate::channel::ConversionError;
+#[cfg(feature = "serde-support")]
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::convert::TryFrom;
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
-)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
 #[repr(u8)]
 pub enum MessageType {
     Regular = 0,
@@ -51,7 +51,7 @@ impl TryFrom<u8> for MessageType {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ConversionError, MessageType};
     use serde_test::Token;
@@ -1,58 +1,76 @@
 mod activity;
 mod activity_type;
+mod allowed_mentions;
 mod application;
 mod flags;
 mod kind;
 mod reaction;
 mod reference;
 
+pub mod sticker;
+
 pub use self::{
-    activity::MessageActivity, activity_type::MessageActivityType, application::MessageApplication,
-    flags::MessageFlags, kind::MessageType, reaction::MessageReaction, reference::MessageReference,
+    activity::MessageActivity,
+    activity_type::MessageActivityType,
+    allowed_mentions::{AllowedMentions, MentionType},
+    application::MessageApplication,
+    flags::MessageFlags,
+    kind::MessageType,
+    reaction::MessageReaction,
+    reference::MessageReference,
+    sticker::MessageSticker,
 };
 
 use crate::{
+    application::component::Component,
     channel::{embed::Embed, Attachment, ChannelMention},
     guild::PartialMember,
     id::{ChannelId, GuildId, MessageId, RoleId, UserId, WebhookId},
     user::User,
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Message {
     pub activity: Option<MessageActivity>,
     pub application: Option<MessageApplication>,
     pub attachments: Vec<Attachment>,
     pub author: User,
     pub channel_id: ChannelId,
-    pub content: String,
-    pub edited_timestamp: Option<String>,
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    pub components: Vec<Component>,
+    pub content: Box<str>,
+    pub edited_timestamp: Option<Box<str>>,
     pub embeds: Vec<Embed>,
     pub flags: Option<MessageFlags>,
     pub guild_id: Option<GuildId>,
     pub id: MessageId,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: MessageType,
     pub member: Option<PartialMember>,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub mention_channels: Vec<ChannelMention>,
     pub mention_everyone: bool,
     pub mention_roles: Vec<RoleId>,
-    #[serde(with = "serde_mappable_seq")]
+    #[cfg_attr(feature = "serde-support", serde(with = "serde_mappable_seq"))]
     pub mentions: HashMap<UserId, User>,
     pub pinned: bool,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub reactions: Vec<MessageReaction>,
-    #[serde(rename = "message_reference")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "message_reference"))]
     pub reference: Option<MessageReference>,
-    pub timestamp: String,
+    /// Stickers sent with the message.
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    pub sticker_items: Vec<MessageSticker>,
+    pub timestamp: Box<str>,
     pub tts: bool,
     pub webhook_id: Option<WebhookId>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{Message, MessageFlags, MessageType};
     use crate::{
@@ -86,7 +104,8 @@ mod tests {
                 verified: None,
             },
             channel_id: ChannelId(2),
-            content: "ping".to_owned(),
+            components: Vec::new(),
+            content: "ping".into(),
             edited_timestamp: None,
             embeds: Vec::new(),
             flags: Some(MessageFlags::empty()),
@@ -107,7 +126,8 @@ mod tests {
             pinned: false,
             reactions: Vec::new(),
             reference: None,
-            timestamp: "2020-02-02T02:02:02.020000+00:00".to_owned(),
+            sticker_items: Vec::new(),
+            timestamp: "2020-02-02T02:02:02.020000+00:00".into(),
             tts: false,
             webhook_id: None,
         };
@@ -117,7 +137,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Message",
-                    len: 23,
+                    len: 25,
                 },
                 Token::Str("activity"),
                 Token::None,
@@ -163,6 +183,9 @@ mod tests {
                 Token::Str("channel_id"),
                 Token::NewtypeStruct { name: "ChannelId" },
                 Token::Str("2"),
+                Token::Str("components"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("content"),
                 Token::Str("ping"),
                 Token::Str("edited_timestamp"),
@@ -220,6 +243,9 @@ mod tests {
                 Token::SeqEnd,
                 Token::Str("message_reference"),
                 Token::None,
+                Token::Str("sticker_items"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("timestamp"),
                 Token::Str("2020-02-02T02:02:02.020000+00:00"),
                 Token::Str("tts"),
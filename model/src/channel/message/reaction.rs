@@ -1,14 +1,16 @@
 use crate::channel::ReactionType;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct MessageReaction {
     pub count: u64,
     pub emoji: ReactionType,
     pub me: bool,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{MessageReaction, ReactionType};
     use serde_test::Token;
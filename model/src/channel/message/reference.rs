@@ -1,14 +1,16 @@
 use crate::id::{ChannelId, GuildId, MessageId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct MessageReference {
     pub channel_id: ChannelId,
     pub guild_id: Option<GuildId>,
     pub message_id: Option<MessageId>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ChannelId, GuildId, MessageId, MessageReference};
     use serde_test::Token;
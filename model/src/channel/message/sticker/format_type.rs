@@ -0,0 +1,31 @@
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Format of a sticker's image.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum StickerFormatType {
+    /// PNG image.
+    Png = 1,
+    /// Animated PNG image.
+    Apng = 2,
+    /// Lottie animation.
+    Lottie = 3,
+    /// GIF image.
+    Gif = 4,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::StickerFormatType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&StickerFormatType::Png, &[Token::U8(1)]);
+        serde_test::assert_tokens(&StickerFormatType::Apng, &[Token::U8(2)]);
+        serde_test::assert_tokens(&StickerFormatType::Lottie, &[Token::U8(3)]);
+        serde_test::assert_tokens(&StickerFormatType::Gif, &[Token::U8(4)]);
+    }
+}
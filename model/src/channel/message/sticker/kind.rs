@@ -0,0 +1,25 @@
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Where a sticker comes from.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum StickerType {
+    /// Official sticker in a pack.
+    Standard = 1,
+    /// Sticker uploaded to a guild.
+    Guild = 2,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::StickerType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&StickerType::Standard, &[Token::U8(1)]);
+        serde_test::assert_tokens(&StickerType::Guild, &[Token::U8(2)]);
+    }
+}
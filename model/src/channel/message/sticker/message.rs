@@ -0,0 +1,46 @@
+use super::StickerFormatType;
+use crate::id::StickerId;
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Minimal information about a sticker sent in a message.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct MessageSticker {
+    pub format_type: StickerFormatType,
+    pub id: StickerId,
+    pub name: String,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{MessageSticker, StickerFormatType, StickerId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_message_sticker() {
+        let value = MessageSticker {
+            format_type: StickerFormatType::Png,
+            id: StickerId(1),
+            name: "sticker".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "MessageSticker",
+                    len: 3,
+                },
+                Token::Str("format_type"),
+                Token::U8(1),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "StickerId" },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("sticker"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Types for stickers, which can be sent in messages.
+
+mod format_type;
+mod kind;
+mod message;
+mod pack;
+
+pub use self::{
+    format_type::StickerFormatType, kind::StickerType, message::MessageSticker, pack::StickerPack,
+};
+
+use crate::{
+    id::{GuildId, StickerId, StickerPackId},
+    user::User,
+};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Sticker, either from an official pack or uploaded to a guild.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct Sticker {
+    /// Whether the sticker can be used, which may be false for guild stickers
+    /// when the guild loses boosts.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub available: Option<bool>,
+    pub description: Option<String>,
+    pub format_type: StickerFormatType,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub guild_id: Option<GuildId>,
+    pub id: StickerId,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: StickerType,
+    pub name: String,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub pack_id: Option<StickerPackId>,
+    /// Sort order of the sticker within its pack.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub sort_value: Option<u64>,
+    /// Comma separated keywords used for autocompletion.
+    pub tags: String,
+    /// User that uploaded the guild sticker.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub user: Option<User>,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{GuildId, Sticker, StickerFormatType, StickerId, StickerType};
+    use serde_test::Token;
+
+    #[test]
+    fn test_guild_sticker() {
+        let value = Sticker {
+            available: Some(true),
+            description: Some("a sticker".to_owned()),
+            format_type: StickerFormatType::Png,
+            guild_id: Some(GuildId(1)),
+            id: StickerId(2),
+            kind: StickerType::Guild,
+            name: "sticker".to_owned(),
+            pack_id: None,
+            sort_value: None,
+            tags: "sticker,test".to_owned(),
+            user: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Sticker",
+                    len: 8,
+                },
+                Token::Str("available"),
+                Token::Some,
+                Token::Bool(true),
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("a sticker"),
+                Token::Str("format_type"),
+                Token::U8(1),
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "StickerId" },
+                Token::Str("2"),
+                Token::Str("type"),
+                Token::U8(2),
+                Token::Str("name"),
+                Token::Str("sticker"),
+                Token::Str("tags"),
+                Token::Str("sticker,test"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use super::Sticker;
+use crate::id::{GenericId, StickerId, StickerPackId};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Pack of official stickers available to Nitro subscribers.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct StickerPack {
+    /// ID of the banner image of the pack.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub banner_asset_id: Option<GenericId>,
+    /// ID of the sticker shown as the icon of the pack.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub cover_sticker_id: Option<StickerId>,
+    pub description: String,
+    pub id: StickerPackId,
+    pub name: String,
+    /// ID of the SKU the pack is sold as.
+    pub sku_id: GenericId,
+    pub stickers: Vec<Sticker>,
+}
@@ -1,6 +1,8 @@
 pub mod embed;
 pub mod message;
 pub mod permission_overwrite;
+pub mod stage_instance;
+pub mod thread;
 
 mod attachment;
 mod category_channel;
@@ -24,18 +26,27 @@ pub use self::{
     webhook_type::WebhookType,
 };
 
-use crate::id::{ChannelId, GuildId, MessageId};
+#[cfg(feature = "serde-support")]
+use crate::id::MessageId;
+use crate::{
+    channel::{
+        permission_overwrite::PermissionOverwrite,
+        thread::{NewsThread, PrivateThread, PublicThread},
+    },
+    id::{ChannelId, GuildId},
+};
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{
         DeserializeSeed, Deserializer, Error as DeError, IgnoredAny, MapAccess, SeqAccess, Visitor,
     },
     Deserialize, Serialize,
 };
+#[cfg(feature = "serde-support")]
 use serde_mappable_seq::Key;
-use std::{
-    collections::HashMap,
-    fmt::{self, Formatter, Result as FmtResult},
-};
+#[cfg(feature = "serde-support")]
+use std::collections::HashMap;
+use std::fmt::{self, Formatter, Result as FmtResult};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum ConversionError {
@@ -52,8 +63,9 @@ impl fmt::Display for ConversionError {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde-support", serde(untagged))]
 pub enum Channel {
     Group(Group),
     Guild(GuildChannel),
@@ -84,10 +96,15 @@ impl Channel {
     }
 }
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
+#[cfg_attr(feature = "serde-support", serde(untagged))]
 pub enum GuildChannel {
     Category(CategoryChannel),
+    NewsThread(NewsThread),
+    PrivateThread(PrivateThread),
+    PublicThread(PublicThread),
+    Stage(VoiceChannel),
     Text(TextChannel),
     Voice(VoiceChannel),
 }
@@ -97,6 +114,10 @@ impl GuildChannel {
     pub fn guild_id(&self) -> Option<GuildId> {
         match self {
             Self::Category(category) => category.guild_id,
+            Self::NewsThread(thread) => thread.guild_id,
+            Self::PrivateThread(thread) => thread.guild_id,
+            Self::PublicThread(thread) => thread.guild_id,
+            Self::Stage(stage) => stage.guild_id,
             Self::Text(text) => text.guild_id,
             Self::Voice(voice) => voice.guild_id,
         }
@@ -106,6 +127,10 @@ impl GuildChannel {
     pub fn id(&self) -> ChannelId {
         match self {
             Self::Category(category) => category.id,
+            Self::NewsThread(thread) => thread.id,
+            Self::PrivateThread(thread) => thread.id,
+            Self::PublicThread(thread) => thread.id,
+            Self::Stage(stage) => stage.id,
             Self::Text(text) => text.id,
             Self::Voice(voice) => voice.id,
         }
@@ -115,43 +140,79 @@ impl GuildChannel {
     pub fn name(&self) -> &str {
         match self {
             Self::Category(category) => category.name.as_ref(),
+            Self::NewsThread(thread) => thread.name.as_ref(),
+            Self::PrivateThread(thread) => thread.name.as_ref(),
+            Self::PublicThread(thread) => thread.name.as_ref(),
+            Self::Stage(stage) => stage.name.as_ref(),
             Self::Text(text) => text.name.as_ref(),
             Self::Voice(voice) => voice.name.as_ref(),
         }
     }
+
+    /// Return an immutable reference to the permission overwrites of the inner
+    /// guild channel.
+    ///
+    /// Threads don't have permission overwrites, they use those of their
+    /// parent channel.
+    pub fn permission_overwrites(&self) -> &[PermissionOverwrite] {
+        match self {
+            Self::Category(category) => &category.permission_overwrites,
+            Self::NewsThread(_) | Self::PrivateThread(_) | Self::PublicThread(_) => &[],
+            Self::Stage(stage) => &stage.permission_overwrites,
+            Self::Text(text) => &text.permission_overwrites,
+            Self::Voice(voice) => &voice.permission_overwrites,
+        }
+    }
+
+    /// Return whether the inner guild channel is a thread.
+    pub fn is_thread(&self) -> bool {
+        matches!(
+            self,
+            Self::NewsThread(_) | Self::PrivateThread(_) | Self::PublicThread(_)
+        )
+    }
 }
 
+#[cfg(feature = "serde-support")]
 impl Key<'_, ChannelId> for GuildChannel {
     fn key(&self) -> ChannelId {
-        match self {
-            Self::Category(c) => c.id,
-            Self::Text(c) => c.id,
-            Self::Voice(c) => c.id,
-        }
+        self.id()
     }
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(field_identifier, rename_all = "snake_case")]
+#[cfg(feature = "serde-support")]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize))]
+#[cfg_attr(
+    feature = "serde-support",
+    serde(field_identifier, rename_all = "snake_case")
+)]
 enum GuildChannelField {
     Bitrate,
     GuildId,
     Id,
     LastMessageId,
     LastPinTimestamp,
+    Member,
+    MemberCount,
+    MessageCount,
     Name,
     Nsfw,
+    OwnerId,
     ParentId,
     PermissionOverwrites,
     Position,
     RateLimitPerUser,
+    ThreadMetadata,
     Topic,
     Type,
     UserLimit,
 }
 
+#[cfg(feature = "serde-support")]
 struct GuildChannelVisitor;
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for GuildChannelVisitor {
     type Value = GuildChannel;
 
@@ -164,6 +225,10 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         const VARIANTS: &[&str] = &[
             "GuildCategory",
             "GuildNews",
+            "GuildNewsThread",
+            "GuildPrivateThread",
+            "GuildPublicThread",
+            "GuildStageVoice",
             "GuildStore",
             "GuildText",
             "GuildVoice",
@@ -175,12 +240,17 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         let mut kind = None;
         let mut last_message_id: Option<Option<MessageId>> = None;
         let mut last_pin_timestamp: Option<Option<String>> = None;
+        let mut member = None;
+        let mut member_count = None;
+        let mut message_count = None;
         let mut name = None;
         let mut nsfw = None;
+        let mut owner_id = None;
         let mut parent_id: Option<Option<ChannelId>> = None;
         let mut permission_overwrites = None;
         let mut position = None;
         let mut rate_limit_per_user = None;
+        let mut thread_metadata = None;
         let mut topic: Option<Option<String>> = None;
         let mut user_limit = None;
 
@@ -251,6 +321,27 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     last_pin_timestamp = Some(map.next_value()?);
                 }
+                GuildChannelField::Member => {
+                    if member.is_some() {
+                        return Err(DeError::duplicate_field("member"));
+                    }
+
+                    member = Some(map.next_value()?);
+                }
+                GuildChannelField::MemberCount => {
+                    if member_count.is_some() {
+                        return Err(DeError::duplicate_field("member_count"));
+                    }
+
+                    member_count = Some(map.next_value()?);
+                }
+                GuildChannelField::MessageCount => {
+                    if message_count.is_some() {
+                        return Err(DeError::duplicate_field("message_count"));
+                    }
+
+                    message_count = Some(map.next_value()?);
+                }
                 GuildChannelField::Name => {
                     if name.is_some() {
                         return Err(DeError::duplicate_field("name"));
@@ -265,6 +356,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     nsfw = Some(map.next_value()?);
                 }
+                GuildChannelField::OwnerId => {
+                    if owner_id.is_some() {
+                        return Err(DeError::duplicate_field("owner_id"));
+                    }
+
+                    owner_id = Some(map.next_value()?);
+                }
                 GuildChannelField::ParentId => {
                     if parent_id.is_some() {
                         return Err(DeError::duplicate_field("parent_id"));
@@ -293,6 +391,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     rate_limit_per_user = Some(map.next_value()?);
                 }
+                GuildChannelField::ThreadMetadata => {
+                    if thread_metadata.is_some() {
+                        return Err(DeError::duplicate_field("thread_metadata"));
+                    }
+
+                    thread_metadata = Some(map.next_value()?);
+                }
                 GuildChannelField::Topic => {
                     if topic.is_some() {
                         return Err(DeError::duplicate_field("topic"));
@@ -315,9 +420,6 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         let id = id.ok_or_else(|| DeError::missing_field("id"))?;
         let kind = kind.ok_or_else(|| DeError::missing_field("type"))?;
         let name = name.ok_or_else(|| DeError::missing_field("name"))?;
-        let permission_overwrites =
-            permission_overwrites.ok_or_else(|| DeError::missing_field("permission_overwrites"))?;
-        let position = position.ok_or_else(|| DeError::missing_field("position"))?;
 
         let nsfw = nsfw.unwrap_or_default();
         let parent_id = parent_id.unwrap_or_default();
@@ -328,9 +430,87 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
             %name,
             %nsfw,
             ?parent_id,
+            "common fields of all variants exist"
+        );
+
+        if let ChannelType::GuildNewsThread
+        | ChannelType::GuildPrivateThread
+        | ChannelType::GuildPublicThread = kind
+        {
+            let last_message_id = last_message_id.unwrap_or_default();
+            let member_count =
+                member_count.ok_or_else(|| DeError::missing_field("member_count"))?;
+            let message_count =
+                message_count.ok_or_else(|| DeError::missing_field("message_count"))?;
+            let owner_id = owner_id.unwrap_or_default();
+            let thread_metadata =
+                thread_metadata.ok_or_else(|| DeError::missing_field("thread_metadata"))?;
+
+            tracing::trace!(
+                ?last_message_id,
+                %member_count,
+                %message_count,
+                ?owner_id,
+                ?thread_metadata,
+                "handling thread"
+            );
+
+            return Ok(match kind {
+                ChannelType::GuildNewsThread => GuildChannel::NewsThread(NewsThread {
+                    guild_id,
+                    id,
+                    kind,
+                    last_message_id,
+                    member,
+                    member_count,
+                    message_count,
+                    name,
+                    owner_id,
+                    parent_id,
+                    rate_limit_per_user,
+                    thread_metadata,
+                }),
+                ChannelType::GuildPrivateThread => GuildChannel::PrivateThread(PrivateThread {
+                    guild_id,
+                    id,
+                    kind,
+                    last_message_id,
+                    member,
+                    member_count,
+                    message_count,
+                    name,
+                    owner_id,
+                    parent_id,
+                    rate_limit_per_user,
+                    thread_metadata,
+                }),
+                _ => GuildChannel::PublicThread(PublicThread {
+                    guild_id,
+                    id,
+                    kind,
+                    last_message_id,
+                    member,
+                    member_count,
+                    message_count,
+                    name,
+                    owner_id,
+                    parent_id,
+                    rate_limit_per_user,
+                    thread_metadata,
+                }),
+            });
+        }
+
+        // Threads use the permissions of their parent channel, but every other
+        // guild channel has its own.
+        let permission_overwrites =
+            permission_overwrites.ok_or_else(|| DeError::missing_field("permission_overwrites"))?;
+        let position = position.ok_or_else(|| DeError::missing_field("position"))?;
+
+        tracing::trace!(
             ?permission_overwrites,
             %position,
-            "common fields of all variants exist"
+            "permission fields of non-thread variants exist"
         );
 
         Ok(match kind {
@@ -346,13 +526,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
                     position,
                 })
             }
-            ChannelType::GuildVoice => {
+            ChannelType::GuildStageVoice | ChannelType::GuildVoice => {
                 let bitrate = bitrate.ok_or_else(|| DeError::missing_field("bitrate"))?;
                 let user_limit = user_limit.ok_or_else(|| DeError::missing_field("user_limit"))?;
 
-                tracing::trace!(%bitrate, ?user_limit, "handling voice channel");
+                tracing::trace!(%bitrate, ?user_limit, "handling stage or voice channel");
 
-                GuildChannel::Voice(VoiceChannel {
+                let channel = VoiceChannel {
                     id,
                     bitrate,
                     guild_id,
@@ -362,7 +542,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
                     parent_id,
                     position,
                     user_limit,
-                })
+                };
+
+                if kind == ChannelType::GuildStageVoice {
+                    GuildChannel::Stage(channel)
+                } else {
+                    GuildChannel::Voice(channel)
+                }
             }
             ChannelType::GuildNews | ChannelType::GuildStore | ChannelType::GuildText => {
                 let last_message_id = last_message_id.unwrap_or_default();
@@ -396,17 +582,21 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> Deserialize<'de> for GuildChannel {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         deserializer.deserialize_map(GuildChannelVisitor)
     }
 }
 
+#[cfg(feature = "serde-support")]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct GuildChannelMapDeserializer;
 
+#[cfg(feature = "serde-support")]
 struct GuildChannelMapVisitor;
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for GuildChannelMapVisitor {
     type Value = HashMap<ChannelId, GuildChannel>;
 
@@ -433,6 +623,7 @@ impl<'de> Visitor<'de> for GuildChannelMapVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> DeserializeSeed<'de> for GuildChannelMapDeserializer {
     type Value = HashMap<ChannelId, GuildChannel>;
 
@@ -441,14 +632,17 @@ impl<'de> DeserializeSeed<'de> for GuildChannelMapDeserializer {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{
         CategoryChannel, Channel, ChannelType, Group, GuildChannel, PrivateChannel, TextChannel,
         VoiceChannel,
     };
     use crate::{
-        channel::permission_overwrite::PermissionOverwrite,
+        channel::{
+            permission_overwrite::PermissionOverwrite,
+            thread::{AutoArchiveDuration, PrivateThread, ThreadMember, ThreadMetadata},
+        },
         id::{ChannelId, GuildId, MessageId, UserId},
     };
 
@@ -736,4 +930,96 @@ mod tests {
             .unwrap()
         );
     }
+
+    #[test]
+    fn test_guild_stage_channel_deserialization() {
+        let value = GuildChannel::Stage(VoiceChannel {
+            id: ChannelId(1),
+            bitrate: 64_000,
+            guild_id: Some(GuildId(2)),
+            kind: ChannelType::GuildStageVoice,
+            name: "stage".to_owned(),
+            permission_overwrites: Vec::new(),
+            parent_id: None,
+            position: 3,
+            user_limit: Some(0),
+        });
+        let permission_overwrites: Vec<PermissionOverwrite> = Vec::new();
+
+        assert_eq!(
+            value,
+            serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "bitrate": 64_000,
+                "guild_id": "2",
+                "name": "stage",
+                "permission_overwrites": permission_overwrites,
+                "position": 3,
+                "type": ChannelType::GuildStageVoice,
+                "user_limit": 0,
+            }))
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_guild_private_thread_deserialization() {
+        let value = GuildChannel::PrivateThread(PrivateThread {
+            guild_id: Some(GuildId(2)),
+            id: ChannelId(1),
+            kind: ChannelType::GuildPrivateThread,
+            last_message_id: Some(MessageId(3)),
+            member: Some(ThreadMember {
+                flags: 0,
+                id: Some(ChannelId(1)),
+                join_timestamp: "2021-01-01T00:00:00.000000+00:00".to_owned(),
+                user_id: Some(UserId(4)),
+            }),
+            member_count: 2,
+            message_count: 5,
+            name: "private".to_owned(),
+            owner_id: Some(UserId(4)),
+            parent_id: Some(ChannelId(6)),
+            rate_limit_per_user: Some(10),
+            thread_metadata: ThreadMetadata {
+                archive_timestamp: "2021-01-01T00:00:00.000000+00:00".to_owned(),
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Week,
+                invitable: Some(true),
+                locked: false,
+            },
+        });
+
+        assert!(value.is_thread());
+        assert!(value.permission_overwrites().is_empty());
+        assert_eq!(
+            value,
+            serde_json::from_value(serde_json::json!({
+                "guild_id": "2",
+                "id": "1",
+                "last_message_id": "3",
+                "member": {
+                    "flags": 0,
+                    "id": "1",
+                    "join_timestamp": "2021-01-01T00:00:00.000000+00:00",
+                    "user_id": "4",
+                },
+                "member_count": 2,
+                "message_count": 5,
+                "name": "private",
+                "owner_id": "4",
+                "parent_id": "6",
+                "rate_limit_per_user": 10,
+                "thread_metadata": {
+                    "archive_timestamp": "2021-01-01T00:00:00.000000+00:00",
+                    "archived": false,
+                    "auto_archive_duration": 10080,
+                    "invitable": true,
+                    "locked": false,
+                },
+                "type": ChannelType::GuildPrivateThread,
+            }))
+            .unwrap()
+        );
+    }
 }
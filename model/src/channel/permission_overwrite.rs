@@ -2,11 +2,13 @@ use crate::{
     guild::Permissions,
     id::{RoleId, UserId},
 };
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{Deserializer, Error as DeError},
     ser::SerializeStruct,
     Deserialize, Serialize, Serializer,
 };
+#[cfg(feature = "serde-support")]
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -22,23 +24,27 @@ pub enum PermissionOverwriteType {
     Role(RoleId),
 }
 
-#[derive(Deserialize)]
+#[cfg(feature = "serde-support")]
+#[cfg_attr(feature = "serde-support", derive(Deserialize))]
 struct PermissionOverwriteData {
     allow: Permissions,
     deny: Permissions,
     id: String,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     kind: PermissionOverwriteTypeName,
 }
 
-#[derive(Debug, Deserialize_repr, Serialize_repr)]
+#[cfg(feature = "serde-support")]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
 #[repr(u8)]
-#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "serde-support", serde(rename_all = "snake_case"))]
 enum PermissionOverwriteTypeName {
     Member = 1,
     Role = 0,
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> Deserialize<'de> for PermissionOverwrite {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let data = PermissionOverwriteData::deserialize(deserializer)?;
@@ -69,6 +75,7 @@ impl<'de> Deserialize<'de> for PermissionOverwrite {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl Serialize for PermissionOverwrite {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let mut state = serializer.serialize_struct("PermissionOverwrite", 4)?;
@@ -91,7 +98,7 @@ impl Serialize for PermissionOverwrite {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{PermissionOverwrite, PermissionOverwriteType, Permissions};
     use crate::id::UserId;
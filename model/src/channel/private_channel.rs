@@ -3,19 +3,21 @@ use crate::{
     id::{ChannelId, MessageId},
     user::User,
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct PrivateChannel {
     pub id: ChannelId,
     pub last_message_id: Option<MessageId>,
     pub last_pin_timestamp: Option<String>,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: ChannelType,
     pub recipients: Vec<User>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ChannelId, ChannelType, MessageId, PrivateChannel};
     use serde_test::Token;
@@ -1,15 +1,20 @@
+#[cfg(feature = "serde-support")]
+use crate::guild::member::OptionalMemberDeserializer;
 use crate::{
     channel::ReactionType,
-    guild::member::{Member, OptionalMemberDeserializer},
+    guild::member::Member,
     id::{ChannelId, GuildId, MessageId, UserId},
 };
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     Deserialize, Serialize,
 };
+#[cfg(feature = "serde-support")]
 use std::fmt::{Formatter, Result as FmtResult};
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
 pub struct Reaction {
     pub channel_id: ChannelId,
     pub emoji: ReactionType,
@@ -19,8 +24,13 @@ pub struct Reaction {
     pub user_id: UserId,
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(field_identifier, rename_all = "snake_case")]
+#[cfg(feature = "serde-support")]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize))]
+#[cfg_attr(
+    feature = "serde-support",
+    serde(field_identifier, rename_all = "snake_case")
+)]
 enum Field {
     ChannelId,
     Emoji,
@@ -30,8 +40,10 @@ enum Field {
     UserId,
 }
 
+#[cfg(feature = "serde-support")]
 struct ReactionVisitor;
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for ReactionVisitor {
     type Value = Reaction;
 
@@ -143,6 +155,7 @@ impl<'de> Visitor<'de> for ReactionVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> Deserialize<'de> for Reaction {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         const FIELDS: &[&str] = &[
@@ -158,7 +171,7 @@ impl<'de> Deserialize<'de> for Reaction {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::super::{Reaction, ReactionType};
     use crate::{
@@ -1,11 +1,13 @@
 use crate::id::EmojiId;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde-support", serde(untagged))]
 pub enum ReactionType {
     Custom {
-        #[serde(default)]
+        #[cfg_attr(feature = "serde-support", serde(default))]
         animated: bool,
         // Even though it says that the id can be nil in the docs,
         // it is a bit misleading as that should only happen when
@@ -21,7 +23,7 @@ pub enum ReactionType {
     },
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::ReactionType;
     use crate::id::EmojiId;
@@ -0,0 +1,65 @@
+mod privacy_level;
+
+pub use self::privacy_level::PrivacyLevel;
+
+use crate::id::{ChannelId, GuildId, StageId};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Live stage in a stage channel.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct StageInstance {
+    pub channel_id: ChannelId,
+    /// Whether stage discovery is disabled for the stage.
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    pub discoverable_disabled: bool,
+    pub guild_id: GuildId,
+    pub id: StageId,
+    pub privacy_level: PrivacyLevel,
+    pub topic: String,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{ChannelId, GuildId, PrivacyLevel, StageId, StageInstance};
+    use serde_test::Token;
+
+    #[test]
+    fn test_stage_instance() {
+        let value = StageInstance {
+            channel_id: ChannelId(1),
+            discoverable_disabled: false,
+            guild_id: GuildId(2),
+            id: StageId(3),
+            privacy_level: PrivacyLevel::GuildOnly,
+            topic: "a topic".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "StageInstance",
+                    len: 6,
+                },
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("1"),
+                Token::Str("discoverable_disabled"),
+                Token::Bool(false),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("2"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "StageId" },
+                Token::Str("3"),
+                Token::Str("privacy_level"),
+                Token::U8(2),
+                Token::Str("topic"),
+                Token::Str("a topic"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
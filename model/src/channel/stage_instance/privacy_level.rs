@@ -0,0 +1,25 @@
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Who can see a stage.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum PrivacyLevel {
+    /// Stage is visible to everyone through stage discovery.
+    Public = 1,
+    /// Stage is only visible to members of the guild.
+    GuildOnly = 2,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::PrivacyLevel;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&PrivacyLevel::Public, &[Token::U8(1)]);
+        serde_test::assert_tokens(&PrivacyLevel::GuildOnly, &[Token::U8(2)]);
+    }
+}
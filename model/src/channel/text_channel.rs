@@ -2,18 +2,20 @@ use crate::{
     channel::{permission_overwrite::PermissionOverwrite, ChannelType},
     id::{ChannelId, GuildId, MessageId},
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct TextChannel {
     pub guild_id: Option<GuildId>,
     pub id: ChannelId,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: ChannelType,
     pub last_message_id: Option<MessageId>,
     pub last_pin_timestamp: Option<String>,
     pub name: String,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub nsfw: bool,
     pub permission_overwrites: Vec<PermissionOverwrite>,
     pub parent_id: Option<ChannelId>,
@@ -22,7 +24,7 @@ pub struct TextChannel {
     pub topic: Option<String>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ChannelId, ChannelType, GuildId, TextChannel};
     use serde_test::Token;
@@ -0,0 +1,16 @@
+use super::ThreadMember;
+use crate::channel::GuildChannel;
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// List of threads, and the current user's thread members for the threads it
+/// has joined.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct ThreadsListing {
+    /// Whether there are more threads to request, if the list is paginated.
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    pub has_more: bool,
+    pub members: Vec<ThreadMember>,
+    pub threads: Vec<GuildChannel>,
+}
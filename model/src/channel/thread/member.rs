@@ -0,0 +1,65 @@
+use crate::id::{ChannelId, UserId};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Member of a thread.
+///
+/// The IDs are omitted when the member is included in a [`GUILD_CREATE`]
+/// event or a thread channel.
+///
+/// [`GUILD_CREATE`]: ../../gateway/payload/struct.GuildCreate.html
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct ThreadMember {
+    pub flags: u64,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<ChannelId>,
+    pub join_timestamp: String,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub user_id: Option<UserId>,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{ChannelId, ThreadMember, UserId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_thread_member() {
+        let value = ThreadMember {
+            flags: 0,
+            id: Some(ChannelId(1)),
+            join_timestamp: "2021-01-01T00:00:00.000000+00:00".to_owned(),
+            user_id: Some(UserId(2)),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ThreadMember",
+                    len: 4,
+                },
+                Token::Str("flags"),
+                Token::U64(0),
+                Token::Str("id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("1"),
+                Token::Str("join_timestamp"),
+                Token::Str("2021-01-01T00:00:00.000000+00:00"),
+                Token::Str("user_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("2"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use super::AutoArchiveDuration;
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Archival state of a thread.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct ThreadMetadata {
+    pub archive_timestamp: String,
+    pub archived: bool,
+    pub auto_archive_duration: AutoArchiveDuration,
+    /// Whether non-moderators can add other non-moderators to a private
+    /// thread.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub invitable: Option<bool>,
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    pub locked: bool,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{AutoArchiveDuration, ThreadMetadata};
+    use serde_test::Token;
+
+    #[test]
+    fn test_thread_metadata() {
+        let value = ThreadMetadata {
+            archive_timestamp: "2021-01-01T00:00:00.000000+00:00".to_owned(),
+            archived: false,
+            auto_archive_duration: AutoArchiveDuration::Day,
+            invitable: None,
+            locked: false,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ThreadMetadata",
+                    len: 4,
+                },
+                Token::Str("archive_timestamp"),
+                Token::Str("2021-01-01T00:00:00.000000+00:00"),
+                Token::Str("archived"),
+                Token::Bool(false),
+                Token::Str("auto_archive_duration"),
+                Token::U16(1440),
+                Token::Str("locked"),
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
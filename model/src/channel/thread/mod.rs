@@ -0,0 +1,15 @@
+//! Types for threads, which are temporary sub-channels of text and news
+//! channels.
+
+mod auto_archive_duration;
+mod listing;
+mod member;
+mod metadata;
+mod news;
+mod private;
+mod public;
+
+pub use self::{
+    auto_archive_duration::AutoArchiveDuration, listing::ThreadsListing, member::ThreadMember,
+    metadata::ThreadMetadata, news::NewsThread, private::PrivateThread, public::PublicThread,
+};
@@ -0,0 +1,34 @@
+use super::{ThreadMember, ThreadMetadata};
+use crate::{
+    channel::ChannelType,
+    id::{ChannelId, GuildId, MessageId, UserId},
+};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Thread in a text channel that's only visible to its members and
+/// moderators.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct PrivateThread {
+    pub guild_id: Option<GuildId>,
+    pub id: ChannelId,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: ChannelType,
+    pub last_message_id: Option<MessageId>,
+    /// Thread member of the current user, if it has joined the thread.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub member: Option<ThreadMember>,
+    /// Approximate number of members, which stops counting at 50.
+    pub member_count: u8,
+    /// Approximate number of messages, which stops counting at 50.
+    pub message_count: u8,
+    pub name: String,
+    pub owner_id: Option<UserId>,
+    pub parent_id: Option<ChannelId>,
+    pub rate_limit_per_user: Option<u64>,
+    pub thread_metadata: ThreadMetadata,
+}
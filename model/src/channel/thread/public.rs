@@ -0,0 +1,117 @@
+use super::{ThreadMember, ThreadMetadata};
+use crate::{
+    channel::ChannelType,
+    id::{ChannelId, GuildId, MessageId, UserId},
+};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Thread in a text channel that's visible to everyone who can view the
+/// channel.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct PublicThread {
+    pub guild_id: Option<GuildId>,
+    pub id: ChannelId,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: ChannelType,
+    pub last_message_id: Option<MessageId>,
+    /// Thread member of the current user, if it has joined the thread.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub member: Option<ThreadMember>,
+    /// Approximate number of members, which stops counting at 50.
+    pub member_count: u8,
+    /// Approximate number of messages, which stops counting at 50.
+    pub message_count: u8,
+    pub name: String,
+    pub owner_id: Option<UserId>,
+    pub parent_id: Option<ChannelId>,
+    pub rate_limit_per_user: Option<u64>,
+    pub thread_metadata: ThreadMetadata,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{ChannelId, ChannelType, GuildId, PublicThread, ThreadMetadata, UserId};
+    use crate::channel::thread::AutoArchiveDuration;
+    use serde_test::Token;
+
+    #[test]
+    fn test_public_thread() {
+        let value = PublicThread {
+            guild_id: Some(GuildId(1)),
+            id: ChannelId(2),
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            member: None,
+            member_count: 3,
+            message_count: 4,
+            name: "thread".to_owned(),
+            owner_id: Some(UserId(5)),
+            parent_id: Some(ChannelId(6)),
+            rate_limit_per_user: None,
+            thread_metadata: ThreadMetadata {
+                archive_timestamp: "2021-01-01T00:00:00.000000+00:00".to_owned(),
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Hour,
+                invitable: None,
+                locked: false,
+            },
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "PublicThread",
+                    len: 11,
+                },
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::Str("type"),
+                Token::U8(11),
+                Token::Str("last_message_id"),
+                Token::None,
+                Token::Str("member_count"),
+                Token::U8(3),
+                Token::Str("message_count"),
+                Token::U8(4),
+                Token::Str("name"),
+                Token::Str("thread"),
+                Token::Str("owner_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("5"),
+                Token::Str("parent_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("6"),
+                Token::Str("rate_limit_per_user"),
+                Token::None,
+                Token::Str("thread_metadata"),
+                Token::Struct {
+                    name: "ThreadMetadata",
+                    len: 4,
+                },
+                Token::Str("archive_timestamp"),
+                Token::Str("2021-01-01T00:00:00.000000+00:00"),
+                Token::Str("archived"),
+                Token::Bool(false),
+                Token::Str("auto_archive_duration"),
+                Token::U16(60),
+                Token::Str("locked"),
+                Token::Bool(false),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -3,22 +3,27 @@ use crate::{
     id::{ChannelId, GuildId, WebhookId},
     user::User,
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Webhook {
     pub avatar: Option<String>,
     pub channel_id: ChannelId,
     pub guild_id: Option<GuildId>,
     pub id: WebhookId,
-    #[serde(default = "WebhookType::default", rename = "type")]
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(default = "WebhookType::default", rename = "type")
+    )]
     pub kind: WebhookType,
     pub name: Option<String>,
     pub token: Option<String>,
     pub user: Option<User>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ChannelId, GuildId, Webhook, WebhookId, WebhookType};
     use serde_test::Token;
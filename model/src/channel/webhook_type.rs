@@ -1,8 +1,8 @@
+#[cfg(feature = "serde-support")]
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
 #[repr(u8)]
 pub enum WebhookType {
     Incoming = 1,
@@ -15,7 +15,7 @@ impl Default for WebhookType {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::WebhookType;
     use serde_test::Token;
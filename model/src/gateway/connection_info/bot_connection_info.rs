@@ -1,9 +1,11 @@
 use crate::gateway::SessionStartLimit;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
 /// Gateway information containing the recommended shard count and session
 /// availability.
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct BotConnectionInfo {
     /// Current session availability and session connection concurrency limits.
     pub session_start_limit: SessionStartLimit,
@@ -13,7 +15,7 @@ pub struct BotConnectionInfo {
     pub url: String,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{BotConnectionInfo, SessionStartLimit};
     use serde_test::Token;
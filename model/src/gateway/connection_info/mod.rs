@@ -2,16 +2,18 @@ mod bot_connection_info;
 
 pub use self::bot_connection_info::BotConnectionInfo;
 
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
 /// Gateway information containing the URL to connect to.
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct ConnectionInfo {
     /// URL to the gateway.
     pub url: String,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::ConnectionInfo;
     use serde_test::Token;
@@ -1,6 +1,7 @@
-use super::{
-    super::OpCode, DispatchEvent, DispatchEventWithTypeDeserializer, Event, EventConversionError,
-};
+#[cfg(feature = "serde-support")]
+use super::{super::OpCode, DispatchEventWithTypeDeserializer};
+use super::{DispatchEvent, Event, EventConversionError};
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{
         value::U8Deserializer, DeserializeSeed, Deserializer, Error as DeError, IgnoredAny,
@@ -9,6 +10,7 @@ use serde::{
     ser::{SerializeStruct, Serializer},
     Deserialize, Serialize,
 };
+#[cfg(feature = "serde-support")]
 use std::fmt::{Formatter, Result as FmtResult};
 use std::{convert::TryFrom, str::FromStr};
 
@@ -40,8 +42,13 @@ impl TryFrom<Event> for GatewayEvent {
     }
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
-#[serde(field_identifier, rename_all = "lowercase")]
+#[cfg(feature = "serde-support")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize))]
+#[cfg_attr(
+    feature = "serde-support",
+    serde(field_identifier, rename_all = "lowercase")
+)]
 enum Field {
     D,
     Op,
@@ -49,7 +56,9 @@ enum Field {
     T,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[cfg(feature = "serde-support")]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 struct Hello {
     heartbeat_interval: u64,
 }
@@ -242,8 +251,10 @@ impl<'a> GatewayEventDeserializer<'a> {
     }
 }
 
+#[cfg(feature = "serde-support")]
 struct GatewayEventVisitor<'a>(u8, Option<u64>, Option<&'a str>);
 
+#[cfg(feature = "serde-support")]
 impl GatewayEventVisitor<'_> {
     fn field<'de, T: Deserialize<'de>, V: MapAccess<'de>>(
         map: &mut V,
@@ -288,6 +299,7 @@ impl GatewayEventVisitor<'_> {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for GatewayEventVisitor<'_> {
     type Value = GatewayEvent;
 
@@ -438,6 +450,7 @@ impl<'de> Visitor<'de> for GatewayEventVisitor<'_> {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> DeserializeSeed<'de> for GatewayEventDeserializer<'_> {
     type Value = GatewayEvent;
 
@@ -452,6 +465,7 @@ impl<'de> DeserializeSeed<'de> for GatewayEventDeserializer<'_> {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> DeserializeSeed<'de> for GatewayEventDeserializerOwned {
     type Value = GatewayEvent;
 
@@ -466,6 +480,7 @@ impl<'de> DeserializeSeed<'de> for GatewayEventDeserializerOwned {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl Serialize for GatewayEvent {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         fn opcode(gateway_event: &GatewayEvent) -> OpCode {
@@ -519,7 +534,7 @@ impl Serialize for GatewayEvent {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{DispatchEvent, GatewayEvent, GatewayEventDeserializer, OpCode};
     use crate::{
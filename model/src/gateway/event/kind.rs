@@ -1,13 +1,19 @@
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
 /// The type of an event.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde-support", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum EventType {
-    #[serde(rename = "GUILD_BAN_ADD")]
+    AutoModerationActionExecution,
+    AutoModerationRuleCreate,
+    AutoModerationRuleDelete,
+    AutoModerationRuleUpdate,
+    #[cfg_attr(feature = "serde-support", serde(rename = "GUILD_BAN_ADD"))]
     BanAdd,
-    #[serde(rename = "GUILD_BAN_REMOVE")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "GUILD_BAN_REMOVE"))]
     BanRemove,
     ChannelCreate,
     ChannelDelete,
@@ -23,16 +29,22 @@ pub enum EventType {
     GuildDelete,
     GuildEmojisUpdate,
     GuildIntegrationsUpdate,
+    GuildScheduledEventCreate,
+    GuildScheduledEventDelete,
+    GuildScheduledEventUpdate,
+    GuildScheduledEventUserAdd,
+    GuildScheduledEventUserRemove,
     GuildUpdate,
+    InteractionCreate,
     InviteCreate,
     InviteDelete,
-    #[serde(rename = "GUILD_MEMBER_ADD")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "GUILD_MEMBER_ADD"))]
     MemberAdd,
-    #[serde(rename = "GUILD_MEMBER_REMOVE")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "GUILD_MEMBER_REMOVE"))]
     MemberRemove,
-    #[serde(rename = "GUILD_MEMBER_UPDATE")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "GUILD_MEMBER_UPDATE"))]
     MemberUpdate,
-    #[serde(rename = "GUILD_MEMBERS_CHUNK")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "GUILD_MEMBERS_CHUNK"))]
     MemberChunk,
     MessageCreate,
     MessageDelete,
@@ -40,21 +52,27 @@ pub enum EventType {
     MessageUpdate,
     PresenceUpdate,
     PresencesReplace,
-    #[serde(rename = "MESSAGE_REACTION_ADD")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "MESSAGE_REACTION_ADD"))]
     ReactionAdd,
-    #[serde(rename = "MESSAGE_REACTION_REMOVE")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "MESSAGE_REACTION_REMOVE"))]
     ReactionRemove,
-    #[serde(rename = "MESSAGE_REACTION_REMOVE_ALL")]
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(rename = "MESSAGE_REACTION_REMOVE_ALL")
+    )]
     ReactionRemoveAll,
-    #[serde(rename = "MESSAGE_REACTION_REMOVE_EMOJI")]
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(rename = "MESSAGE_REACTION_REMOVE_EMOJI")
+    )]
     ReactionRemoveEmoji,
     Ready,
     Resumed,
-    #[serde(rename = "GUILD_ROLE_CREATE")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "GUILD_ROLE_CREATE"))]
     RoleCreate,
-    #[serde(rename = "GUILD_ROLE_DELETE")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "GUILD_ROLE_DELETE"))]
     RoleDelete,
-    #[serde(rename = "GUILD_ROLE_UPDATE")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "GUILD_ROLE_UPDATE"))]
     RoleUpdate,
     ShardConnected,
     ShardConnecting,
@@ -63,6 +81,15 @@ pub enum EventType {
     ShardReconnecting,
     ShardPayload,
     ShardResuming,
+    StageInstanceCreate,
+    StageInstanceDelete,
+    StageInstanceUpdate,
+    ThreadCreate,
+    ThreadDelete,
+    ThreadListSync,
+    ThreadMemberUpdate,
+    ThreadMembersUpdate,
+    ThreadUpdate,
     TypingStart,
     UnavailableGuild,
     UserUpdate,
@@ -74,6 +101,10 @@ pub enum EventType {
 impl EventType {
     pub fn name(self) -> Option<&'static str> {
         match self {
+            Self::AutoModerationActionExecution => Some("AUTO_MODERATION_ACTION_EXECUTION"),
+            Self::AutoModerationRuleCreate => Some("AUTO_MODERATION_RULE_CREATE"),
+            Self::AutoModerationRuleDelete => Some("AUTO_MODERATION_RULE_DELETE"),
+            Self::AutoModerationRuleUpdate => Some("AUTO_MODERATION_RULE_UPDATE"),
             Self::BanAdd => Some("GUILD_BAN_ADD"),
             Self::BanRemove => Some("GUILD_BAN_REMOVE"),
             Self::ChannelCreate => Some("CHANNEL_CREATE"),
@@ -85,7 +116,13 @@ impl EventType {
             Self::GuildDelete => Some("GUILD_DELETE"),
             Self::GuildEmojisUpdate => Some("GUILD_EMOJIS_UPDATE"),
             Self::GuildIntegrationsUpdate => Some("GUILD_INTEGRATIONS_UPDATE"),
+            Self::GuildScheduledEventCreate => Some("GUILD_SCHEDULED_EVENT_CREATE"),
+            Self::GuildScheduledEventDelete => Some("GUILD_SCHEDULED_EVENT_DELETE"),
+            Self::GuildScheduledEventUpdate => Some("GUILD_SCHEDULED_EVENT_UPDATE"),
+            Self::GuildScheduledEventUserAdd => Some("GUILD_SCHEDULED_EVENT_USER_ADD"),
+            Self::GuildScheduledEventUserRemove => Some("GUILD_SCHEDULED_EVENT_USER_REMOVE"),
             Self::GuildUpdate => Some("GUILD_UPDATE"),
+            Self::InteractionCreate => Some("INTERACTION_CREATE"),
             Self::InviteCreate => Some("INVITE_CREATE"),
             Self::InviteDelete => Some("INVITE_DELETE"),
             Self::MemberAdd => Some("GUILD_MEMBER_ADD"),
@@ -107,6 +144,15 @@ impl EventType {
             Self::RoleCreate => Some("GUILD_ROLE_CREATE"),
             Self::RoleDelete => Some("GUILD_ROLE_DELETE"),
             Self::RoleUpdate => Some("GUILD_ROLE_UPDATE"),
+            Self::StageInstanceCreate => Some("STAGE_INSTANCE_CREATE"),
+            Self::StageInstanceDelete => Some("STAGE_INSTANCE_DELETE"),
+            Self::StageInstanceUpdate => Some("STAGE_INSTANCE_UPDATE"),
+            Self::ThreadCreate => Some("THREAD_CREATE"),
+            Self::ThreadDelete => Some("THREAD_DELETE"),
+            Self::ThreadListSync => Some("THREAD_LIST_SYNC"),
+            Self::ThreadMemberUpdate => Some("THREAD_MEMBER_UPDATE"),
+            Self::ThreadMembersUpdate => Some("THREAD_MEMBERS_UPDATE"),
+            Self::ThreadUpdate => Some("THREAD_UPDATE"),
             Self::TypingStart => Some("TYPING_START"),
             Self::UnavailableGuild => Some("UNAVAILABLE_GUILD"),
             Self::UserUpdate => Some("USER_UPDATE"),
@@ -134,6 +180,10 @@ impl<'a> TryFrom<&'a str> for EventType {
 
     fn try_from(event_type: &'a str) -> Result<Self, Self::Error> {
         match event_type {
+            "AUTO_MODERATION_ACTION_EXECUTION" => Ok(Self::AutoModerationActionExecution),
+            "AUTO_MODERATION_RULE_CREATE" => Ok(Self::AutoModerationRuleCreate),
+            "AUTO_MODERATION_RULE_DELETE" => Ok(Self::AutoModerationRuleDelete),
+            "AUTO_MODERATION_RULE_UPDATE" => Ok(Self::AutoModerationRuleUpdate),
             "GUILD_BAN_ADD" => Ok(Self::BanAdd),
             "GUILD_BAN_REMOVE" => Ok(Self::BanRemove),
             "CHANNEL_CREATE" => Ok(Self::ChannelCreate),
@@ -145,7 +195,13 @@ impl<'a> TryFrom<&'a str> for EventType {
             "GUILD_DELETE" => Ok(Self::GuildDelete),
             "GUILD_EMOJIS_UPDATE" => Ok(Self::GuildEmojisUpdate),
             "GUILD_INTEGRATIONS_UPDATE" => Ok(Self::GuildIntegrationsUpdate),
+            "GUILD_SCHEDULED_EVENT_CREATE" => Ok(Self::GuildScheduledEventCreate),
+            "GUILD_SCHEDULED_EVENT_DELETE" => Ok(Self::GuildScheduledEventDelete),
+            "GUILD_SCHEDULED_EVENT_UPDATE" => Ok(Self::GuildScheduledEventUpdate),
+            "GUILD_SCHEDULED_EVENT_USER_ADD" => Ok(Self::GuildScheduledEventUserAdd),
+            "GUILD_SCHEDULED_EVENT_USER_REMOVE" => Ok(Self::GuildScheduledEventUserRemove),
             "GUILD_UPDATE" => Ok(Self::GuildUpdate),
+            "INTERACTION_CREATE" => Ok(Self::InteractionCreate),
             "INVITE_CREATE" => Ok(Self::InviteCreate),
             "INVITE_DELETE" => Ok(Self::InviteDelete),
             "GUILD_MEMBER_ADD" => Ok(Self::MemberAdd),
@@ -167,6 +223,15 @@ impl<'a> TryFrom<&'a str> for EventType {
             "GUILD_ROLE_CREATE" => Ok(Self::RoleCreate),
             "GUILD_ROLE_DELETE" => Ok(Self::RoleDelete),
             "GUILD_ROLE_UPDATE" => Ok(Self::RoleUpdate),
+            "STAGE_INSTANCE_CREATE" => Ok(Self::StageInstanceCreate),
+            "STAGE_INSTANCE_DELETE" => Ok(Self::StageInstanceDelete),
+            "STAGE_INSTANCE_UPDATE" => Ok(Self::StageInstanceUpdate),
+            "THREAD_CREATE" => Ok(Self::ThreadCreate),
+            "THREAD_DELETE" => Ok(Self::ThreadDelete),
+            "THREAD_LIST_SYNC" => Ok(Self::ThreadListSync),
+            "THREAD_MEMBER_UPDATE" => Ok(Self::ThreadMemberUpdate),
+            "THREAD_MEMBERS_UPDATE" => Ok(Self::ThreadMembersUpdate),
+            "THREAD_UPDATE" => Ok(Self::ThreadUpdate),
             "TYPING_START" => Ok(Self::TypingStart),
             "UNAVAILABLE_GUILD" => Ok(Self::UnavailableGuild),
             "USER_UPDATE" => Ok(Self::UserUpdate),
@@ -178,7 +243,7 @@ impl<'a> TryFrom<&'a str> for EventType {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::EventType;
     use serde_test::Token;
@@ -193,8 +258,25 @@ mod tests {
         );
     }
 
+    #[allow(clippy::too_many_lines)]
     #[test]
     fn test_variants() {
+        assert_variant(
+            EventType::AutoModerationActionExecution,
+            "AUTO_MODERATION_ACTION_EXECUTION",
+        );
+        assert_variant(
+            EventType::AutoModerationRuleCreate,
+            "AUTO_MODERATION_RULE_CREATE",
+        );
+        assert_variant(
+            EventType::AutoModerationRuleDelete,
+            "AUTO_MODERATION_RULE_DELETE",
+        );
+        assert_variant(
+            EventType::AutoModerationRuleUpdate,
+            "AUTO_MODERATION_RULE_UPDATE",
+        );
         assert_variant(EventType::BanAdd, "GUILD_BAN_ADD");
         assert_variant(EventType::BanRemove, "GUILD_BAN_REMOVE");
         assert_variant(EventType::ChannelCreate, "CHANNEL_CREATE");
@@ -217,7 +299,28 @@ mod tests {
             EventType::GuildIntegrationsUpdate,
             "GUILD_INTEGRATIONS_UPDATE",
         );
+        assert_variant(
+            EventType::GuildScheduledEventCreate,
+            "GUILD_SCHEDULED_EVENT_CREATE",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventDelete,
+            "GUILD_SCHEDULED_EVENT_DELETE",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventUpdate,
+            "GUILD_SCHEDULED_EVENT_UPDATE",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventUserAdd,
+            "GUILD_SCHEDULED_EVENT_USER_ADD",
+        );
+        assert_variant(
+            EventType::GuildScheduledEventUserRemove,
+            "GUILD_SCHEDULED_EVENT_USER_REMOVE",
+        );
         assert_variant(EventType::GuildUpdate, "GUILD_UPDATE");
+        assert_variant(EventType::InteractionCreate, "INTERACTION_CREATE");
         assert_variant(EventType::InviteCreate, "INVITE_CREATE");
         assert_variant(EventType::InviteDelete, "INVITE_DELETE");
         assert_variant(EventType::MemberAdd, "GUILD_MEMBER_ADD");
@@ -249,6 +352,15 @@ mod tests {
         assert_variant(EventType::ShardPayload, "SHARD_PAYLOAD");
         assert_variant(EventType::ShardReconnecting, "SHARD_RECONNECTING");
         assert_variant(EventType::ShardResuming, "SHARD_RESUMING");
+        assert_variant(EventType::StageInstanceCreate, "STAGE_INSTANCE_CREATE");
+        assert_variant(EventType::StageInstanceDelete, "STAGE_INSTANCE_DELETE");
+        assert_variant(EventType::StageInstanceUpdate, "STAGE_INSTANCE_UPDATE");
+        assert_variant(EventType::ThreadCreate, "THREAD_CREATE");
+        assert_variant(EventType::ThreadDelete, "THREAD_DELETE");
+        assert_variant(EventType::ThreadListSync, "THREAD_LIST_SYNC");
+        assert_variant(EventType::ThreadMemberUpdate, "THREAD_MEMBER_UPDATE");
+        assert_variant(EventType::ThreadMembersUpdate, "THREAD_MEMBERS_UPDATE");
+        assert_variant(EventType::ThreadUpdate, "THREAD_UPDATE");
         assert_variant(EventType::TypingStart, "TYPING_START");
         assert_variant(EventType::UnavailableGuild, "UNAVAILABLE_GUILD");
         assert_variant(EventType::UserUpdate, "USER_UPDATE");
@@ -27,6 +27,14 @@ use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 /// [`ShardEvent`]: shard/enum.ShardEvent.html
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Event {
+    /// An auto moderation rule was triggered and one of its actions executed.
+    AutoModerationActionExecution(Box<AutoModerationActionExecution>),
+    /// An auto moderation rule was created.
+    AutoModerationRuleCreate(Box<AutoModerationRuleCreate>),
+    /// An auto moderation rule was deleted.
+    AutoModerationRuleDelete(Box<AutoModerationRuleDelete>),
+    /// An auto moderation rule was updated.
+    AutoModerationRuleUpdate(Box<AutoModerationRuleUpdate>),
     /// A user was banned from a guild.
     BanAdd(BanAdd),
     /// A user's ban from a guild was removed.
@@ -61,8 +69,20 @@ pub enum Event {
     GuildEmojisUpdate(GuildEmojisUpdate),
     /// A guild's integrations were updated.
     GuildIntegrationsUpdate(GuildIntegrationsUpdate),
+    /// A scheduled event was created in a guild.
+    GuildScheduledEventCreate(Box<GuildScheduledEventCreate>),
+    /// A scheduled event was deleted from a guild.
+    GuildScheduledEventDelete(Box<GuildScheduledEventDelete>),
+    /// A scheduled event in a guild was updated.
+    GuildScheduledEventUpdate(Box<GuildScheduledEventUpdate>),
+    /// A user subscribed to a scheduled event.
+    GuildScheduledEventUserAdd(GuildScheduledEventUserAdd),
+    /// A user unsubscribed from a scheduled event.
+    GuildScheduledEventUserRemove(GuildScheduledEventUserRemove),
     /// A guild was updated.
     GuildUpdate(Box<GuildUpdate>),
+    /// An interaction was invoked by a user.
+    InteractionCreate(Box<InteractionCreate>),
     /// A invite was made.
     InviteCreate(Box<InviteCreate>),
     /// A invite was deleted.
@@ -125,6 +145,24 @@ pub enum Event {
     ShardPayload(Payload),
     /// A shard is now in a Resuming stage after a disconnect.
     ShardResuming(Resuming),
+    /// A stage instance was created.
+    StageInstanceCreate(StageInstanceCreate),
+    /// A stage instance was deleted.
+    StageInstanceDelete(StageInstanceDelete),
+    /// A stage instance was updated.
+    StageInstanceUpdate(StageInstanceUpdate),
+    /// A thread was created or the current user was added to a private thread.
+    ThreadCreate(Box<ThreadCreate>),
+    /// A thread was deleted.
+    ThreadDelete(ThreadDelete),
+    /// The current user gained access to a channel and its threads were synced.
+    ThreadListSync(Box<ThreadListSync>),
+    /// The thread member of the current user was updated.
+    ThreadMemberUpdate(ThreadMemberUpdate),
+    /// Members were added to or removed from a thread.
+    ThreadMembersUpdate(ThreadMembersUpdate),
+    /// A thread was updated.
+    ThreadUpdate(Box<ThreadUpdate>),
     /// A user started typing in a channel.
     TypingStart(Box<TypingStart>),
     /// A guild is now unavailable.
@@ -142,6 +180,10 @@ pub enum Event {
 impl Event {
     pub fn kind(&self) -> EventType {
         match self {
+            Self::AutoModerationActionExecution(_) => EventType::AutoModerationActionExecution,
+            Self::AutoModerationRuleCreate(_) => EventType::AutoModerationRuleCreate,
+            Self::AutoModerationRuleDelete(_) => EventType::AutoModerationRuleDelete,
+            Self::AutoModerationRuleUpdate(_) => EventType::AutoModerationRuleUpdate,
             Self::BanAdd(_) => EventType::BanAdd,
             Self::BanRemove(_) => EventType::BanRemove,
             Self::ChannelCreate(_) => EventType::ChannelCreate,
@@ -158,7 +200,13 @@ impl Event {
             Self::GuildDelete(_) => EventType::GuildDelete,
             Self::GuildEmojisUpdate(_) => EventType::GuildEmojisUpdate,
             Self::GuildIntegrationsUpdate(_) => EventType::GuildIntegrationsUpdate,
+            Self::GuildScheduledEventCreate(_) => EventType::GuildScheduledEventCreate,
+            Self::GuildScheduledEventDelete(_) => EventType::GuildScheduledEventDelete,
+            Self::GuildScheduledEventUpdate(_) => EventType::GuildScheduledEventUpdate,
+            Self::GuildScheduledEventUserAdd(_) => EventType::GuildScheduledEventUserAdd,
+            Self::GuildScheduledEventUserRemove(_) => EventType::GuildScheduledEventUserRemove,
             Self::GuildUpdate(_) => EventType::GuildUpdate,
+            Self::InteractionCreate(_) => EventType::InteractionCreate,
             Self::InviteCreate(_) => EventType::InviteCreate,
             Self::InviteDelete(_) => EventType::InviteDelete,
             Self::MemberAdd(_) => EventType::MemberAdd,
@@ -187,6 +235,15 @@ impl Event {
             Self::ShardReconnecting(_) => EventType::ShardReconnecting,
             Self::ShardPayload(_) => EventType::ShardPayload,
             Self::ShardResuming(_) => EventType::ShardResuming,
+            Self::StageInstanceCreate(_) => EventType::StageInstanceCreate,
+            Self::StageInstanceDelete(_) => EventType::StageInstanceDelete,
+            Self::StageInstanceUpdate(_) => EventType::StageInstanceUpdate,
+            Self::ThreadCreate(_) => EventType::ThreadCreate,
+            Self::ThreadDelete(_) => EventType::ThreadDelete,
+            Self::ThreadListSync(_) => EventType::ThreadListSync,
+            Self::ThreadMemberUpdate(_) => EventType::ThreadMemberUpdate,
+            Self::ThreadMembersUpdate(_) => EventType::ThreadMembersUpdate,
+            Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
             Self::UserUpdate(_) => EventType::UserUpdate,
@@ -200,6 +257,12 @@ impl Event {
 impl From<Box<DispatchEvent>> for Event {
     fn from(event: Box<DispatchEvent>) -> Self {
         match *event {
+            DispatchEvent::AutoModerationActionExecution(v) => {
+                Self::AutoModerationActionExecution(v)
+            }
+            DispatchEvent::AutoModerationRuleCreate(v) => Self::AutoModerationRuleCreate(v),
+            DispatchEvent::AutoModerationRuleDelete(v) => Self::AutoModerationRuleDelete(v),
+            DispatchEvent::AutoModerationRuleUpdate(v) => Self::AutoModerationRuleUpdate(v),
             DispatchEvent::BanAdd(v) => Self::BanAdd(v),
             DispatchEvent::BanRemove(v) => Self::BanRemove(v),
             DispatchEvent::ChannelCreate(v) => Self::ChannelCreate(v),
@@ -220,7 +283,15 @@ impl From<Box<DispatchEvent>> for Event {
             DispatchEvent::RoleCreate(v) => Self::RoleCreate(v),
             DispatchEvent::RoleDelete(v) => Self::RoleDelete(v),
             DispatchEvent::RoleUpdate(v) => Self::RoleUpdate(v),
+            DispatchEvent::GuildScheduledEventCreate(v) => Self::GuildScheduledEventCreate(v),
+            DispatchEvent::GuildScheduledEventDelete(v) => Self::GuildScheduledEventDelete(v),
+            DispatchEvent::GuildScheduledEventUpdate(v) => Self::GuildScheduledEventUpdate(v),
+            DispatchEvent::GuildScheduledEventUserAdd(v) => Self::GuildScheduledEventUserAdd(v),
+            DispatchEvent::GuildScheduledEventUserRemove(v) => {
+                Self::GuildScheduledEventUserRemove(v)
+            }
             DispatchEvent::GuildUpdate(v) => Self::GuildUpdate(v),
+            DispatchEvent::InteractionCreate(v) => Self::InteractionCreate(v),
             DispatchEvent::MessageCreate(v) => Self::MessageCreate(v),
             DispatchEvent::MessageDelete(v) => Self::MessageDelete(v),
             DispatchEvent::MessageDeleteBulk(v) => Self::MessageDeleteBulk(v),
@@ -233,6 +304,15 @@ impl From<Box<DispatchEvent>> for Event {
             DispatchEvent::ReactionRemoveEmoji(v) => Self::ReactionRemoveEmoji(v),
             DispatchEvent::Ready(v) => Self::Ready(v),
             DispatchEvent::Resumed => Self::Resumed,
+            DispatchEvent::StageInstanceCreate(v) => Self::StageInstanceCreate(v),
+            DispatchEvent::StageInstanceDelete(v) => Self::StageInstanceDelete(v),
+            DispatchEvent::StageInstanceUpdate(v) => Self::StageInstanceUpdate(v),
+            DispatchEvent::ThreadCreate(v) => Self::ThreadCreate(v),
+            DispatchEvent::ThreadDelete(v) => Self::ThreadDelete(v),
+            DispatchEvent::ThreadListSync(v) => Self::ThreadListSync(v),
+            DispatchEvent::ThreadMemberUpdate(v) => Self::ThreadMemberUpdate(v),
+            DispatchEvent::ThreadMembersUpdate(v) => Self::ThreadMembersUpdate(v),
+            DispatchEvent::ThreadUpdate(v) => Self::ThreadUpdate(v),
             DispatchEvent::TypingStart(v) => Self::TypingStart(v),
             DispatchEvent::UnavailableGuild(v) => Self::UnavailableGuild(v),
             DispatchEvent::UserUpdate(v) => Self::UserUpdate(v),
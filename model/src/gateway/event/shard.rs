@@ -1,9 +1,12 @@
 use super::{Event, EventConversionError};
+use bytes::Bytes;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
 /// Indicator that a shard is now fully connected.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Connected {
     /// The interval that heartbeats are being sent to the gateway.
     pub heartbeat_interval: u64,
@@ -12,7 +15,8 @@ pub struct Connected {
 }
 
 /// Indicator that a shard is now connecting.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Connecting {
     /// The URL used to connect to the gateway.
     pub gateway: String,
@@ -22,7 +26,8 @@ pub struct Connecting {
 
 /// Indicator that a shard is now disconnected and may soon be reconnecting if
 /// not explicitly shutdown.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Disconnected {
     /// The code for the disconnect if not initiated by the host, if any.
     pub code: Option<u16>,
@@ -34,7 +39,8 @@ pub struct Disconnected {
 
 /// Indicator that a shard is now identifying with the gateway to create a new
 /// session.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Identifying {
     /// The ID of the shard that identified with the gateway.
     pub shard_id: u64,
@@ -43,21 +49,37 @@ pub struct Identifying {
 }
 
 /// A payload of bytes came in through the gateway.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+///
+/// The opcode, sequence, and event type of the payload are read from it
+/// without deserializing the rest of it, so the payload can be forwarded or
+/// handled even if its event type isn't modelled.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Payload {
     /// The bytes that came in.
-    pub bytes: Vec<u8>,
+    ///
+    /// Cloning the bytes is cheap, so a payload can be sent to many
+    /// listeners without copying it.
+    pub bytes: Bytes,
+    /// The dispatch event type of the payload, if it's a dispatch.
+    pub event_type: Option<String>,
+    /// The opcode of the payload.
+    pub op: u8,
+    /// The sequence of the payload, if it's a dispatch.
+    pub sequence: Option<u64>,
 }
 
 /// Indicator that a shard is now reconnecting.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Reconnecting {
     /// The ID of the shard that began reconnecting.
     pub shard_id: u64,
 }
 
 /// Indicator that a shard is now resuming a session after a disconnect.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Resuming {
     /// The event sequence sent when resuming was initiated.
     pub seq: u64,
@@ -66,8 +88,9 @@ pub struct Resuming {
 }
 
 /// "Meta" events about a shard's status, not from the gateway.
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde-support", serde(untagged))]
 pub enum ShardEvent {
     /// A shard is now in a Connected stage after being fully connected to the
     /// gateway.
@@ -106,12 +129,13 @@ impl TryFrom<Event> for ShardEvent {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{
         Connected, Connecting, Disconnected, Event, Identifying, Payload, Reconnecting, Resuming,
         ShardEvent,
     };
+    use bytes::Bytes;
     use serde_test::Token;
     use std::convert::TryInto;
 
@@ -214,20 +238,30 @@ mod tests {
 
     #[test]
     fn test_payload() {
-        let value = Payload { bytes: vec![1, 2] };
+        let value = Payload {
+            bytes: Bytes::from_static(&[1, 2]),
+            event_type: Some("TYPING_START".to_owned()),
+            op: 0,
+            sequence: Some(3),
+        };
 
         serde_test::assert_tokens(
             &value,
             &[
                 Token::Struct {
                     name: "Payload",
-                    len: 1,
+                    len: 4,
                 },
                 Token::Str("bytes"),
-                Token::Seq { len: Some(2) },
-                Token::U8(1),
-                Token::U8(2),
-                Token::SeqEnd,
+                Token::Bytes(&[1, 2]),
+                Token::Str("event_type"),
+                Token::Some,
+                Token::Str("TYPING_START"),
+                Token::Str("op"),
+                Token::U8(0),
+                Token::Str("sequence"),
+                Token::Some,
+                Token::U64(3),
                 Token::StructEnd,
             ],
         );
@@ -313,7 +347,12 @@ mod tests {
             ShardEvent::Identifying(_)
         ));
 
-        let payload = Event::ShardPayload(Payload { bytes: vec![1, 2] });
+        let payload = Event::ShardPayload(Payload {
+            bytes: Bytes::from_static(&[1, 2]),
+            event_type: None,
+            op: 11,
+            sequence: None,
+        });
         assert!(matches!(
             payload.try_into().unwrap(),
             ShardEvent::Payload(_)
@@ -1,4 +1,5 @@
 use bitflags::bitflags;
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{Deserialize, Deserializer},
     ser::{Serialize, Serializer},
@@ -189,15 +190,35 @@ bitflags! {
         /// [`TYPING_START`]: ./event/enum.Event.html#variant.TypingStart
         /// [`GUILD_MESSAGE_TYPING`]: #associatedconstant.GUILD_MESSAGE_TYPING
         const DIRECT_MESSAGE_TYPING = 1 << 14;
+        /// Auto moderation configuration intent.
+        ///
+        /// Event(s) received:
+        ///  - [`AUTO_MODERATION_RULE_CREATE`]
+        ///  - [`AUTO_MODERATION_RULE_UPDATE`]
+        ///  - [`AUTO_MODERATION_RULE_DELETE`]
+        ///
+        /// [`AUTO_MODERATION_RULE_CREATE`]: ./event/enum.Event.html#variant.AutoModerationRuleCreate
+        /// [`AUTO_MODERATION_RULE_UPDATE`]: ./event/enum.Event.html#variant.AutoModerationRuleUpdate
+        /// [`AUTO_MODERATION_RULE_DELETE`]: ./event/enum.Event.html#variant.AutoModerationRuleDelete
+        const AUTO_MODERATION_CONFIGURATION = 1 << 20;
+        /// Auto moderation execution intent.
+        ///
+        /// Event(s) received:
+        ///  - [`AUTO_MODERATION_ACTION_EXECUTION`]
+        ///
+        /// [`AUTO_MODERATION_ACTION_EXECUTION`]: ./event/enum.Event.html#variant.AutoModerationActionExecution
+        const AUTO_MODERATION_EXECUTION = 1 << 21;
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> Deserialize<'de> for Intents {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         Ok(Self::from_bits_truncate(u64::deserialize(deserializer)?))
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl Serialize for Intents {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -207,7 +228,7 @@ impl Serialize for Intents {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::Intents;
     use serde_test::Token;
@@ -229,5 +250,10 @@ mod tests {
         serde_test::assert_tokens(&Intents::DIRECT_MESSAGES, &[Token::U64(1 << 12)]);
         serde_test::assert_tokens(&Intents::DIRECT_MESSAGE_REACTIONS, &[Token::U64(1 << 13)]);
         serde_test::assert_tokens(&Intents::DIRECT_MESSAGE_TYPING, &[Token::U64(1 << 14)]);
+        serde_test::assert_tokens(
+            &Intents::AUTO_MODERATION_CONFIGURATION,
+            &[Token::U64(1 << 20)],
+        );
+        serde_test::assert_tokens(&Intents::AUTO_MODERATION_EXECUTION, &[Token::U64(1 << 21)]);
     }
 }
@@ -0,0 +1,41 @@
+use crate::{
+    guild::auto_moderation::{AutoModerationAction, AutoModerationTriggerType},
+    id::{AutoModerationRuleId, ChannelId, GuildId, MessageId, UserId},
+};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Action of an auto moderation rule has been executed.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct AutoModerationActionExecution {
+    pub action: AutoModerationAction,
+    /// ID of the alert message sent by the action, if any.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub alert_system_message_id: Option<MessageId>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub channel_id: Option<ChannelId>,
+    /// Content of the message that triggered the rule.
+    pub content: String,
+    pub guild_id: GuildId,
+    /// Substring of the content that triggered the rule.
+    pub matched_content: Option<String>,
+    /// Keyword or pattern of the rule that was matched.
+    pub matched_keyword: Option<String>,
+    /// ID of the message that triggered the rule, if it wasn't blocked.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub message_id: Option<MessageId>,
+    pub rule_id: AutoModerationRuleId,
+    pub rule_trigger_type: AutoModerationTriggerType,
+    /// User that sent the content.
+    pub user_id: UserId,
+}
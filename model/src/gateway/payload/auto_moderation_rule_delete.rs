@@ -0,0 +1,22 @@
+use crate::guild::auto_moderation::AutoModerationRule;
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct AutoModerationRuleDelete(pub AutoModerationRule);
+
+impl Deref for AutoModerationRuleDelete {
+    type Target = AutoModerationRule;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AutoModerationRuleDelete {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -1,8 +1,10 @@
 use crate::channel::Channel;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct ChannelCreate(pub Channel);
 
 impl Deref for ChannelCreate {
@@ -1,7 +1,9 @@
 use crate::id::{ChannelId, GuildId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct ChannelPinsUpdate {
     pub channel_id: ChannelId,
     pub guild_id: Option<GuildId>,
@@ -1,8 +1,10 @@
 use crate::guild::Guild;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct GuildCreate(pub Guild);
 
 impl Deref for GuildCreate {
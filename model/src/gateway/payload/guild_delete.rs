@@ -1,19 +1,25 @@
 use crate::id::GuildId;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Deserializer, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct GuildDelete {
     pub id: GuildId,
     // If `unavailable` is `None` the user was removed from the guild.
-    #[serde(default, deserialize_with = "nullable_unavailable")]
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(default, deserialize_with = "nullable_unavailable")
+    )]
     pub unavailable: bool,
 }
 
+#[cfg(feature = "serde-support")]
 fn nullable_unavailable<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
     Ok(Deserialize::deserialize(deserializer).unwrap_or_default())
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::super::GuildDelete;
     use crate::id::GuildId;
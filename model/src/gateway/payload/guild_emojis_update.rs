@@ -2,12 +2,14 @@ use crate::{
     guild::Emoji,
     id::{EmojiId, GuildId},
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct GuildEmojisUpdate {
-    #[serde(with = "serde_mappable_seq")]
+    #[cfg_attr(feature = "serde-support", serde(with = "serde_mappable_seq"))]
     pub emojis: HashMap<EmojiId, Emoji>,
     pub guild_id: GuildId,
 }
@@ -0,0 +1,11 @@
+use crate::id::{GuildId, ScheduledEventId, UserId};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct GuildScheduledEventUserRemove {
+    pub guild_id: GuildId,
+    pub guild_scheduled_event_id: ScheduledEventId,
+    pub user_id: UserId,
+}
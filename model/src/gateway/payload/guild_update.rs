@@ -1,8 +1,10 @@
 use crate::guild::PartialGuild;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct GuildUpdate(pub PartialGuild);
 
 impl Deref for GuildUpdate {
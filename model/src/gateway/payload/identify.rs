@@ -1,8 +1,10 @@
 use super::update_status::UpdateStatusInfo;
 use crate::gateway::{intents::Intents, opcode::OpCode};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Identify {
     pub d: IdentifyInfo,
     pub op: OpCode,
@@ -17,7 +19,8 @@ impl Identify {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct IdentifyInfo {
     pub compression: bool,
     pub intents: Intents,
@@ -29,17 +32,18 @@ pub struct IdentifyInfo {
     pub v: u64,
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct IdentifyProperties {
-    #[serde(rename = "$browser")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "$browser"))]
     pub browser: String,
-    #[serde(rename = "$device")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "$device"))]
     pub device: String,
-    #[serde(rename = "$os")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "$os"))]
     pub os: String,
-    #[serde(rename = "$referrer")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "$referrer"))]
     pub referrer: String,
-    #[serde(rename = "$referring_domain")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "$referring_domain"))]
     pub referring_domain: String,
 }
 
@@ -3,9 +3,11 @@ use crate::{
     invite::TargetUserType,
     user::User,
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct InviteCreate {
     pub channel_id: ChannelId,
     pub code: String,
@@ -20,7 +22,8 @@ pub struct InviteCreate {
     pub uses: u8, // will always be zero
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct PartialUser {
     avatar: Option<String>,
     discriminator: String,
@@ -1,8 +1,10 @@
 use crate::guild::Member;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct MemberAdd(pub Member);
 
 impl Deref for MemberAdd {
@@ -19,7 +21,7 @@ impl DerefMut for MemberAdd {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{Member, MemberAdd};
     use crate::{
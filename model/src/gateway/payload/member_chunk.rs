@@ -1,33 +1,41 @@
 use crate::{
-    gateway::presence::{Presence, PresenceMapDeserializer},
-    guild::member::{Member, MemberMapDeserializer},
+    gateway::presence::Presence,
+    guild::member::Member,
     id::{GuildId, UserId},
 };
+#[cfg(feature = "serde-support")]
+use crate::{gateway::presence::PresenceMapDeserializer, guild::member::MemberMapDeserializer};
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     Deserialize, Serialize,
 };
-use std::{
-    collections::HashMap,
-    fmt::{Formatter, Result as FmtResult},
-};
+use std::collections::HashMap;
+#[cfg(feature = "serde-support")]
+use std::fmt::{Formatter, Result as FmtResult};
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
 pub struct MemberChunk {
     pub chunk_count: u32,
     pub chunk_index: u32,
     pub guild_id: GuildId,
-    #[serde(with = "serde_mappable_seq")]
+    #[cfg_attr(feature = "serde-support", serde(with = "serde_mappable_seq"))]
     pub members: HashMap<UserId, Member>,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub nonce: Option<String>,
     pub not_found: Vec<UserId>,
-    #[serde(with = "serde_mappable_seq", default)]
+    #[cfg_attr(feature = "serde-support", serde(with = "serde_mappable_seq", default))]
     pub presences: HashMap<UserId, Presence>,
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(field_identifier, rename_all = "snake_case")]
+#[cfg(feature = "serde-support")]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize))]
+#[cfg_attr(
+    feature = "serde-support",
+    serde(field_identifier, rename_all = "snake_case")
+)]
 enum Field {
     ChunkCount,
     ChunkIndex,
@@ -38,8 +46,10 @@ enum Field {
     Presences,
 }
 
+#[cfg(feature = "serde-support")]
 struct MemberChunkVisitor;
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for MemberChunkVisitor {
     type Value = MemberChunk;
 
@@ -177,6 +187,7 @@ impl<'de> Visitor<'de> for MemberChunkVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> Deserialize<'de> for MemberChunk {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         const FIELDS: &[&str] = &[
@@ -193,7 +204,7 @@ impl<'de> Deserialize<'de> for MemberChunk {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::super::MemberChunk;
     use crate::{
@@ -2,9 +2,11 @@ use crate::{
     id::{GuildId, RoleId},
     user::User,
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct MemberUpdate {
     pub guild_id: GuildId,
     pub joined_at: String,
@@ -14,7 +16,7 @@ pub struct MemberUpdate {
     pub user: User,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::MemberUpdate;
     use crate::user::User;
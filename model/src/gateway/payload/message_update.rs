@@ -1,26 +1,30 @@
 use crate::{
+    application::component::Component,
     channel::{embed::Embed, message::MessageType, Attachment},
     id::{ChannelId, GuildId, MessageId, RoleId},
     user::User,
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct MessageUpdate {
     pub attachments: Option<Vec<Attachment>>,
     pub author: Option<User>,
     pub channel_id: ChannelId,
-    pub content: Option<String>,
-    pub edited_timestamp: Option<String>,
+    pub components: Option<Vec<Component>>,
+    pub content: Option<Box<str>>,
+    pub edited_timestamp: Option<Box<str>>,
     pub embeds: Option<Vec<Embed>>,
     pub guild_id: Option<GuildId>,
     pub id: MessageId,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: Option<MessageType>,
     pub mention_everyone: Option<bool>,
     pub mention_roles: Option<Vec<RoleId>>,
     pub mentions: Option<Vec<User>>,
     pub pinned: Option<bool>,
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Box<str>>,
     pub tts: Option<bool>,
 }
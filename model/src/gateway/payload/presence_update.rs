@@ -2,11 +2,13 @@ use crate::{
     gateway::presence::{Activity, ClientStatus, Status, UserOrId},
     id::GuildId,
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct PresenceUpdate {
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub activities: Vec<Activity>,
     pub client_status: ClientStatus,
     pub game: Option<Activity>,
@@ -1,7 +1,9 @@
 use crate::id::{ChannelId, EmojiId, GuildId, MessageId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct ReactionRemoveEmoji {
     pub channel_id: ChannelId,
     pub emoji: PartialEmoji,
@@ -9,7 +11,8 @@ pub struct ReactionRemoveEmoji {
     pub message_id: MessageId,
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct PartialEmoji {
     pub id: Option<EmojiId>,
     pub name: String,
@@ -1,14 +1,16 @@
 use crate::{guild::GuildStatus, id::GuildId, user::CurrentUser};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Ready {
-    #[serde(with = "serde_mappable_seq")]
+    #[cfg_attr(feature = "serde-support", serde(with = "serde_mappable_seq"))]
     pub guilds: HashMap<GuildId, GuildStatus>,
     pub session_id: String,
     pub shard: Option<[u64; 2]>,
     pub user: CurrentUser,
-    #[serde(rename = "v")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "v"))]
     pub version: u64,
 }
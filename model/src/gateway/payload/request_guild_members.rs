@@ -2,6 +2,7 @@ use crate::{
     gateway::opcode::OpCode,
     id::{GuildId, UserId},
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::{
     error::Error,
@@ -36,7 +37,8 @@ impl Display for UserIdsError {
 
 impl Error for UserIdsError {}
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct RequestGuildMembers {
     pub d: RequestGuildMembersInfo,
     pub op: OpCode,
@@ -227,26 +229,43 @@ impl RequestGuildMembersBuilder {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct RequestGuildMembersInfo {
     /// Guild ID.
     pub guild_id: GuildId,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     /// Maximum number of members to request.
     pub limit: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub nonce: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub presences: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub query: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub user_ids: Option<RequestGuildMemberId<UserId>>,
 }
 
 /// One or a list of IDs in a request.
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde-support", serde(untagged))]
 pub enum RequestGuildMemberId<T> {
     /// Single ID specified.
     One(T),
@@ -1,13 +1,15 @@
 use crate::id::{GuildId, RoleId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct RoleDelete {
     pub guild_id: GuildId,
     pub role_id: RoleId,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{GuildId, RoleDelete, RoleId};
     use serde_test::Token;
@@ -1,13 +1,15 @@
 use crate::{guild::Role, id::GuildId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct RoleUpdate {
     pub guild_id: GuildId,
     pub role: Role,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{GuildId, Role, RoleUpdate};
     use crate::{guild::Permissions, id::RoleId};
@@ -0,0 +1,16 @@
+use crate::{
+    channel::ChannelType,
+    id::{ChannelId, GuildId},
+};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct ThreadDelete {
+    pub guild_id: GuildId,
+    pub id: ChannelId,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: ChannelType,
+    pub parent_id: ChannelId,
+}
@@ -0,0 +1,20 @@
+use crate::{
+    channel::{thread::ThreadMember, GuildChannel},
+    id::{ChannelId, GuildId},
+};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct ThreadListSync {
+    /// Parent channels whose threads are being synced.
+    ///
+    /// If empty, then the threads of every channel in the guild are synced.
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    pub channel_ids: Vec<ChannelId>,
+    pub guild_id: GuildId,
+    /// Thread members of the current user.
+    pub members: Vec<ThreadMember>,
+    pub threads: Vec<GuildChannel>,
+}
@@ -0,0 +1,19 @@
+use crate::{
+    channel::thread::ThreadMember,
+    id::{ChannelId, GuildId, UserId},
+};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct ThreadMembersUpdate {
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    pub added_members: Vec<ThreadMember>,
+    pub guild_id: GuildId,
+    pub id: ChannelId,
+    /// Number of members in the thread, stopping at 50.
+    pub member_count: u8,
+    #[cfg_attr(feature = "serde-support", serde(default))]
+    pub removed_member_ids: Vec<UserId>,
+}
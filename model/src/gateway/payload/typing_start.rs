@@ -1,14 +1,19 @@
+#[cfg(feature = "serde-support")]
+use crate::guild::member::OptionalMemberDeserializer;
 use crate::{
-    guild::member::{Member, OptionalMemberDeserializer},
+    guild::member::Member,
     id::{ChannelId, GuildId, UserId},
 };
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     Deserialize, Serialize,
 };
+#[cfg(feature = "serde-support")]
 use std::fmt::{Formatter, Result as FmtResult};
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
 pub struct TypingStart {
     pub channel_id: ChannelId,
     pub guild_id: Option<GuildId>,
@@ -17,8 +22,13 @@ pub struct TypingStart {
     pub user_id: UserId,
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(field_identifier, rename_all = "snake_case")]
+#[cfg(feature = "serde-support")]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize))]
+#[cfg_attr(
+    feature = "serde-support",
+    serde(field_identifier, rename_all = "snake_case")
+)]
 enum Field {
     ChannelId,
     GuildId,
@@ -27,8 +37,10 @@ enum Field {
     UserId,
 }
 
+#[cfg(feature = "serde-support")]
 struct TypingStartVisitor;
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for TypingStartVisitor {
     type Value = TypingStart;
 
@@ -136,6 +148,7 @@ impl<'de> Visitor<'de> for TypingStartVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> Deserialize<'de> for TypingStart {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         const FIELDS: &[&str] = &["channel_id", "guild_id", "member", "timestamp", "user_id"];
@@ -144,7 +157,7 @@ impl<'de> Deserialize<'de> for TypingStart {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::super::TypingStart;
     use crate::{
@@ -2,9 +2,11 @@ use crate::gateway::{
     opcode::OpCode,
     presence::{Activity, Status},
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct UpdateStatus {
     pub d: UpdateStatusInfo,
     pub op: OpCode,
@@ -24,9 +26,13 @@ impl UpdateStatus {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct UpdateStatusInfo {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
     pub activities: Option<Vec<Activity>>,
     pub afk: bool,
     pub since: Option<u64>,
@@ -2,9 +2,11 @@ use crate::{
     gateway::opcode::OpCode,
     id::{ChannelId, GuildId},
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct UpdateVoiceState {
     pub d: UpdateVoiceStateInfo,
     pub op: OpCode,
@@ -24,7 +26,8 @@ impl UpdateVoiceState {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct UpdateVoiceStateInfo {
     pub channel_id: Option<ChannelId>,
     pub guild_id: GuildId,
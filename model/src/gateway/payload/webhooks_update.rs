@@ -1,13 +1,15 @@
 use crate::id::{ChannelId, GuildId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct WebhooksUpdate {
     pub channel_id: ChannelId,
     pub guild_id: GuildId,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ChannelId, GuildId, WebhooksUpdate};
     use serde_test::Token;
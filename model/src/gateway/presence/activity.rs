@@ -5,9 +5,11 @@ use crate::{
     },
     id::ApplicationId,
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Activity {
     pub application_id: Option<ApplicationId>,
     pub assets: Option<ActivityAssets>,
@@ -19,7 +21,10 @@ pub struct Activity {
     // Introduced with custom statuses.
     pub id: Option<String>,
     pub instance: Option<bool>,
-    #[serde(default = "ActivityType::default", rename = "type")]
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(default = "ActivityType::default", rename = "type")
+    )]
     pub kind: ActivityType,
     pub name: String,
     pub party: Option<ActivityParty>,
@@ -1,4 +1,5 @@
 use bitflags::bitflags;
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{Deserialize, Deserializer},
     ser::{Serialize, Serializer},
@@ -15,12 +16,14 @@ bitflags! {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> Deserialize<'de> for ActivityFlags {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         Ok(Self::from_bits_truncate(u64::deserialize(deserializer)?))
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl Serialize for ActivityFlags {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -30,7 +33,7 @@ impl Serialize for ActivityFlags {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::ActivityFlags;
     use serde_test::Token;
@@ -1,14 +1,16 @@
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct ActivitySecrets {
     pub join: Option<String>,
-    #[serde(rename = "match")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "match"))]
     pub match_: Option<String>,
     pub spectate: Option<String>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::ActivitySecrets;
     use serde_test::Token;
@@ -1,14 +1,16 @@
 use crate::gateway::presence::Status;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct ClientStatus {
     pub desktop: Option<Status>,
     pub mobile: Option<Status>,
     pub web: Option<Status>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ClientStatus, Status};
     use serde_test::Token;
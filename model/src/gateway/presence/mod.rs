@@ -20,21 +20,25 @@ use crate::{
     id::{GuildId, UserId},
     user::User,
 };
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{
         value::MapAccessDeserializer, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor,
     },
     Deserialize, Serialize,
 };
+#[cfg(feature = "serde-support")]
 use serde_mappable_seq::Key;
+#[cfg(feature = "serde-support")]
 use std::{
     collections::HashMap,
     fmt::{Formatter, Result as FmtResult},
 };
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Presence {
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub activities: Vec<Activity>,
     pub client_status: ClientStatus,
     pub guild_id: GuildId,
@@ -42,19 +46,22 @@ pub struct Presence {
     pub user: UserOrId,
 }
 
+#[cfg(feature = "serde-support")]
 impl Key<'_, UserId> for Presence {
     fn key(&self) -> UserId {
         self.user.key()
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde-support", serde(untagged))]
 pub enum UserOrId {
     User(User),
     UserId { id: UserId },
 }
 
+#[cfg(feature = "serde-support")]
 impl Key<'_, UserId> for UserOrId {
     fn key(&self) -> UserId {
         match *self {
@@ -64,9 +71,10 @@ impl Key<'_, UserId> for UserOrId {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize))]
 pub struct PresenceIntermediary {
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub activities: Vec<Activity>,
     pub client_status: ClientStatus,
     pub guild_id: Option<GuildId>,
@@ -75,8 +83,10 @@ pub struct PresenceIntermediary {
     pub user: UserOrId,
 }
 
+#[cfg(feature = "serde-support")]
 struct PresenceVisitor(GuildId);
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for PresenceVisitor {
     type Value = Presence;
 
@@ -109,6 +119,7 @@ impl PresenceDeserializer {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> DeserializeSeed<'de> for PresenceDeserializer {
     type Value = Presence;
 
@@ -128,8 +139,10 @@ impl PresenceMapDeserializer {
     }
 }
 
+#[cfg(feature = "serde-support")]
 struct PresenceMapDeserializerVisitor(GuildId);
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for PresenceMapDeserializerVisitor {
     type Value = HashMap<UserId, Presence>;
 
@@ -155,6 +168,7 @@ impl<'de> Visitor<'de> for PresenceMapDeserializerVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> DeserializeSeed<'de> for PresenceMapDeserializer {
     type Value = HashMap<UserId, Presence>;
 
@@ -163,7 +177,7 @@ impl<'de> DeserializeSeed<'de> for PresenceMapDeserializer {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{
         Activity, ActivityEmoji, ActivityType, ClientStatus, Presence, PresenceMapDeserializer,
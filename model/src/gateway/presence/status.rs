@@ -1,20 +1,22 @@
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub enum Status {
-    #[serde(rename = "dnd")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "dnd"))]
     DoNotDisturb,
-    #[serde(rename = "idle")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "idle"))]
     Idle,
-    #[serde(rename = "invisible")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "invisible"))]
     Invisible,
-    #[serde(rename = "offline")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "offline"))]
     Offline,
-    #[serde(rename = "online")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "online"))]
     Online,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::Status;
     use serde_test::Token;
@@ -1,7 +1,9 @@
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
 /// Current gateway session utilisation status.
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct SessionStartLimit {
     /// Maximum number of session that may be started concurrently.
     pub max_concurrency: u64,
@@ -14,7 +16,7 @@ pub struct SessionStartLimit {
     pub total: u64,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::SessionStartLimit;
     use serde_test::Token;
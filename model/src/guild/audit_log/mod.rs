@@ -1,23 +1,138 @@
+#[cfg(feature = "serde-support")]
 mod change;
 mod change_key;
+#[cfg(feature = "serde-support")]
 mod entry;
 mod event;
 mod optional_entry_info;
 mod partial_integration;
+#[cfg(feature = "serde-support")]
+mod resolved_entry;
+#[cfg(feature = "serde-support")]
+mod target;
 
+#[cfg(feature = "serde-support")]
 pub use self::{
-    change::AuditLogChange, change_key::AuditLogChangeKey, entry::AuditLogEntry,
-    event::AuditLogEvent, optional_entry_info::AuditLogOptionalEntryInfo,
-    partial_integration::PartialGuildIntegration,
+    change::AuditLogChange, entry::AuditLogEntry, resolved_entry::ResolvedAuditLogEntry,
+    target::AuditLogTarget,
+};
+pub use self::{
+    change_key::AuditLogChangeKey, event::AuditLogEvent,
+    optional_entry_info::AuditLogOptionalEntryInfo, partial_integration::PartialGuildIntegration,
 };
 
+#[cfg(feature = "serde-support")]
 use crate::{channel::Webhook, user::User};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
+/// Audit log of a guild.
+///
+/// Changes hold their values as arbitrary serde values, so the audit log is
+/// only available with the `serde` feature.
+#[cfg(feature = "serde-support")]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AuditLog {
-    audit_log_entries: Vec<AuditLogEntry>,
-    integrations: Vec<PartialGuildIntegration>,
-    users: Vec<User>,
-    webhooks: Vec<Webhook>,
+    pub audit_log_entries: Vec<AuditLogEntry>,
+    pub integrations: Vec<PartialGuildIntegration>,
+    pub users: Vec<User>,
+    pub webhooks: Vec<Webhook>,
+}
+
+#[cfg(feature = "serde-support")]
+impl AuditLog {
+    /// Iterator over the entries, with the users, webhooks, and integrations
+    /// that they reference resolved from the audit log.
+    ///
+    /// Entries are ordered from newest to oldest, as they're returned by the
+    /// API.
+    pub fn entries(&self) -> impl Iterator<Item = ResolvedAuditLogEntry<'_>> {
+        self.audit_log_entries.iter().map(move |entry| {
+            let user = self.users.iter().find(|user| user.id == entry.user_id);
+
+            ResolvedAuditLogEntry {
+                entry,
+                target: AuditLogTarget::resolve(self, entry),
+                user,
+            }
+        })
+    }
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{AuditLog, AuditLogEntry, AuditLogEvent, AuditLogTarget};
+    use crate::{
+        channel::{Webhook, WebhookType},
+        id::{AuditLogEntryId, ChannelId, UserId, WebhookId},
+        user::User,
+    };
+
+    fn entry(id: u64, action_type: AuditLogEvent, target_id: u64) -> AuditLogEntry {
+        AuditLogEntry {
+            action_type,
+            changes: None,
+            id: AuditLogEntryId(id),
+            options: None,
+            reason: None,
+            target_id: Some(target_id.to_string()),
+            user_id: UserId(1),
+        }
+    }
+
+    fn user(id: u64) -> User {
+        User {
+            avatar: None,
+            bot: false,
+            discriminator: "0001".to_owned(),
+            email: None,
+            flags: None,
+            id: UserId(id),
+            locale: None,
+            mfa_enabled: None,
+            name: "test".to_owned(),
+            premium_type: None,
+            public_flags: None,
+            system: None,
+            verified: None,
+        }
+    }
+
+    #[test]
+    fn test_entries_resolved() {
+        let webhook = Webhook {
+            avatar: None,
+            channel_id: ChannelId(4),
+            guild_id: None,
+            id: WebhookId(3),
+            kind: WebhookType::Incoming,
+            name: None,
+            token: None,
+            user: None,
+        };
+        let audit_log = AuditLog {
+            audit_log_entries: vec![
+                entry(7, AuditLogEvent::MemberKick, 2),
+                entry(6, AuditLogEvent::WebhookCreate, 3),
+                entry(5, AuditLogEvent::RoleCreate, 2),
+            ],
+            integrations: Vec::new(),
+            users: vec![user(1), user(2)],
+            webhooks: vec![webhook.clone()],
+        };
+
+        let entries = audit_log.entries().collect::<Vec<_>>();
+        assert_eq!(3, entries.len());
+        assert!(entries
+            .iter()
+            .all(|entry| entry.user == Some(&audit_log.users[0])));
+        assert_eq!(AuditLogEntryId(7), entries[0].entry.id);
+        assert_eq!(
+            Some(AuditLogTarget::User(&audit_log.users[1])),
+            entries[0].target
+        );
+        assert_eq!(Some(AuditLogTarget::Webhook(&webhook)), entries[1].target);
+        // Roles aren't included in audit logs.
+        assert!(entries[2].target.is_none());
+    }
 }
@@ -1,13 +1,15 @@
 use crate::id::{ChannelId, GenericId, MessageId, UserId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct AuditLogOptionalEntryInfo {
     pub channel_id: Option<ChannelId>,
     pub count: Option<String>,
     pub delete_member_days: Option<String>,
     pub id: Option<GenericId>,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: Option<String>,
     pub member_id: Option<UserId>,
     pub members_removed: Option<String>,
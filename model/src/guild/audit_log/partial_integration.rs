@@ -1,14 +1,16 @@
 use crate::{guild::IntegrationAccount, id::IntegrationId, user::User};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct PartialGuildIntegration {
     pub account: Option<IntegrationAccount>,
     pub enabled: Option<bool>,
     pub expire_behavior: Option<u64>,
     pub expire_grace_period: Option<u64>,
     pub id: Option<IntegrationId>,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: Option<String>,
     pub name: Option<String>,
     pub role_id: Option<IntegrationId>,
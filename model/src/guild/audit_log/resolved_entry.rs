@@ -0,0 +1,20 @@
+use crate::{
+    guild::audit_log::{AuditLogEntry, AuditLogTarget},
+    user::User,
+};
+
+/// Audit log entry with the resources it references resolved.
+///
+/// This is returned from [`AuditLog::entries`].
+///
+/// [`AuditLog::entries`]: struct.AuditLog.html#method.entries
+#[derive(Clone, Copy, Debug)]
+pub struct ResolvedAuditLogEntry<'a> {
+    /// The entry itself.
+    pub entry: &'a AuditLogEntry,
+    /// Target of the entry, if it's a user, webhook, or integration included
+    /// in the audit log.
+    pub target: Option<AuditLogTarget<'a>>,
+    /// User who made the change, if they're included in the audit log.
+    pub user: Option<&'a User>,
+}
@@ -0,0 +1,62 @@
+use crate::{
+    channel::Webhook,
+    guild::audit_log::{AuditLog, AuditLogEntry, AuditLogEvent, PartialGuildIntegration},
+    user::User,
+};
+
+/// Target of an audit log entry that's included in the audit log.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AuditLogTarget<'a> {
+    /// Integration that was created, updated, or deleted.
+    Integration(&'a PartialGuildIntegration),
+    /// User that was the target of a member action, such as a kick or ban,
+    /// or the author of a message that was deleted, pinned, or unpinned.
+    ///
+    /// Bots that were added to the guild are also users.
+    User(&'a User),
+    /// Webhook that was created, updated, or deleted.
+    Webhook(&'a Webhook),
+}
+
+impl<'a> AuditLogTarget<'a> {
+    /// Resolve the target of an entry from the users, webhooks, and
+    /// integrations of an audit log.
+    ///
+    /// The kind of the target is determined by the action type of the entry.
+    /// Returns `None` if the target isn't one of these kinds or isn't
+    /// included in the audit log.
+    pub(super) fn resolve(audit_log: &'a AuditLog, entry: &AuditLogEntry) -> Option<Self> {
+        let target_id = entry.target_id.as_ref()?.parse::<u64>().ok()?;
+
+        match entry.action_type {
+            AuditLogEvent::MemberKick
+            | AuditLogEvent::MemberBanAdd
+            | AuditLogEvent::MemberBanRemove
+            | AuditLogEvent::MemberUpdate
+            | AuditLogEvent::MemberRoleUpdate
+            | AuditLogEvent::BotAdd
+            | AuditLogEvent::MessageDelete
+            | AuditLogEvent::MessagePin
+            | AuditLogEvent::MessageUnpin => audit_log
+                .users
+                .iter()
+                .find(|user| user.id.0 == target_id)
+                .map(Self::User),
+            AuditLogEvent::WebhookCreate
+            | AuditLogEvent::WebhookUpdate
+            | AuditLogEvent::WebhookDelete => audit_log
+                .webhooks
+                .iter()
+                .find(|webhook| webhook.id.0 == target_id)
+                .map(Self::Webhook),
+            AuditLogEvent::IntegrationCreate
+            | AuditLogEvent::IntegrationUpdate
+            | AuditLogEvent::IntegrationDelete => audit_log
+                .integrations
+                .iter()
+                .find(|integration| integration.id.map(|id| id.0) == Some(target_id))
+                .map(Self::Integration),
+            _ => None,
+        }
+    }
+}
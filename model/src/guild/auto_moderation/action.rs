@@ -0,0 +1,114 @@
+use crate::id::ChannelId;
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Action taken when a rule is triggered.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct AutoModerationAction {
+    /// Additional information about the action, depending on its type.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub metadata: Option<AutoModerationActionMetadata>,
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
+    pub kind: AutoModerationActionType,
+}
+
+/// Additional information about an action.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct AutoModerationActionMetadata {
+    /// Channel to send the alert message to, for
+    /// [`AutoModerationActionType::SendAlertMessage`] actions.
+    ///
+    /// [`AutoModerationActionType::SendAlertMessage`]: enum.AutoModerationActionType.html#variant.SendAlertMessage
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub channel_id: Option<ChannelId>,
+    /// Message shown to the member when their message is blocked, for
+    /// [`AutoModerationActionType::BlockMessage`] actions.
+    ///
+    /// [`AutoModerationActionType::BlockMessage`]: enum.AutoModerationActionType.html#variant.BlockMessage
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub custom_message: Option<String>,
+    /// Number of seconds to time out the member for, up to 4 weeks, for
+    /// [`AutoModerationActionType::Timeout`] actions.
+    ///
+    /// [`AutoModerationActionType::Timeout`]: enum.AutoModerationActionType.html#variant.Timeout
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub duration_seconds: Option<u32>,
+}
+
+/// Type of an action.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum AutoModerationActionType {
+    /// Block the content of the message.
+    BlockMessage = 1,
+    /// Send an alert to a channel.
+    SendAlertMessage = 2,
+    /// Time out the member.
+    Timeout = 3,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{AutoModerationAction, AutoModerationActionMetadata, AutoModerationActionType};
+    use crate::id::ChannelId;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoModerationActionType::BlockMessage, &[Token::U8(1)]);
+        serde_test::assert_tokens(&AutoModerationActionType::SendAlertMessage, &[Token::U8(2)]);
+        serde_test::assert_tokens(&AutoModerationActionType::Timeout, &[Token::U8(3)]);
+    }
+
+    #[test]
+    fn test_send_alert_message() {
+        let value = AutoModerationAction {
+            metadata: Some(AutoModerationActionMetadata {
+                channel_id: Some(ChannelId(1)),
+                ..AutoModerationActionMetadata::default()
+            }),
+            kind: AutoModerationActionType::SendAlertMessage,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AutoModerationAction",
+                    len: 2,
+                },
+                Token::Str("metadata"),
+                Token::Some,
+                Token::Struct {
+                    name: "AutoModerationActionMetadata",
+                    len: 1,
+                },
+                Token::Str("channel_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("1"),
+                Token::StructEnd,
+                Token::Str("type"),
+                Token::U8(2),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
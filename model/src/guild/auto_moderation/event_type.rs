@@ -0,0 +1,22 @@
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Context in which a rule is checked.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum AutoModerationEventType {
+    /// Member sends or edits a message.
+    MessageSend = 1,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::AutoModerationEventType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoModerationEventType::MessageSend, &[Token::U8(1)]);
+    }
+}
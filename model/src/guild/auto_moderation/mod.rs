@@ -0,0 +1,131 @@
+//! Types for auto moderation, which blocks or flags content in guilds
+//! according to rules.
+
+mod action;
+mod event_type;
+mod preset_type;
+mod trigger_metadata;
+mod trigger_type;
+
+pub use self::{
+    action::{AutoModerationAction, AutoModerationActionMetadata, AutoModerationActionType},
+    event_type::AutoModerationEventType,
+    preset_type::AutoModerationKeywordPresetType,
+    trigger_metadata::AutoModerationTriggerMetadata,
+    trigger_type::AutoModerationTriggerType,
+};
+
+use crate::id::{AutoModerationRuleId, ChannelId, GuildId, RoleId, UserId};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Rule that checks content sent in a guild.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct AutoModerationRule {
+    /// Actions taken when the rule is triggered.
+    pub actions: Vec<AutoModerationAction>,
+    /// User that created the rule.
+    pub creator_id: UserId,
+    pub enabled: bool,
+    pub event_type: AutoModerationEventType,
+    /// Channels the rule doesn't apply to.
+    pub exempt_channels: Vec<ChannelId>,
+    /// Roles the rule doesn't apply to.
+    pub exempt_roles: Vec<RoleId>,
+    pub guild_id: GuildId,
+    pub id: AutoModerationRuleId,
+    pub name: String,
+    pub trigger_metadata: AutoModerationTriggerMetadata,
+    pub trigger_type: AutoModerationTriggerType,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{
+        AutoModerationAction, AutoModerationActionType, AutoModerationEventType,
+        AutoModerationRule, AutoModerationRuleId, AutoModerationTriggerMetadata,
+        AutoModerationTriggerType, GuildId, UserId,
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn test_auto_moderation_rule() {
+        let value = AutoModerationRule {
+            actions: vec![AutoModerationAction {
+                metadata: None,
+                kind: AutoModerationActionType::BlockMessage,
+            }],
+            creator_id: UserId(3),
+            enabled: true,
+            event_type: AutoModerationEventType::MessageSend,
+            exempt_channels: Vec::new(),
+            exempt_roles: Vec::new(),
+            guild_id: GuildId(1),
+            id: AutoModerationRuleId(2),
+            name: "no swearing".to_owned(),
+            trigger_metadata: AutoModerationTriggerMetadata {
+                keyword_filter: Some(vec!["heck".to_owned()]),
+                ..AutoModerationTriggerMetadata::default()
+            },
+            trigger_type: AutoModerationTriggerType::Keyword,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AutoModerationRule",
+                    len: 11,
+                },
+                Token::Str("actions"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "AutoModerationAction",
+                    len: 1,
+                },
+                Token::Str("type"),
+                Token::U8(1),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("creator_id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("3"),
+                Token::Str("enabled"),
+                Token::Bool(true),
+                Token::Str("event_type"),
+                Token::U8(1),
+                Token::Str("exempt_channels"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("exempt_roles"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "AutoModerationRuleId",
+                },
+                Token::Str("2"),
+                Token::Str("name"),
+                Token::Str("no swearing"),
+                Token::Str("trigger_metadata"),
+                Token::Struct {
+                    name: "AutoModerationTriggerMetadata",
+                    len: 1,
+                },
+                Token::Str("keyword_filter"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Str("heck"),
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::Str("trigger_type"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
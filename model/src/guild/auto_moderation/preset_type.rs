@@ -0,0 +1,31 @@
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// List of words defined by Discord that a rule can filter.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum AutoModerationKeywordPresetType {
+    /// Swearing or cursing.
+    Profanity = 1,
+    /// Sexually explicit behavior or activity.
+    SexualContent = 2,
+    /// Personal insults or words that may be considered hate speech.
+    Slurs = 3,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::AutoModerationKeywordPresetType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoModerationKeywordPresetType::Profanity, &[Token::U8(1)]);
+        serde_test::assert_tokens(
+            &AutoModerationKeywordPresetType::SexualContent,
+            &[Token::U8(2)],
+        );
+        serde_test::assert_tokens(&AutoModerationKeywordPresetType::Slurs, &[Token::U8(3)]);
+    }
+}
@@ -0,0 +1,43 @@
+use super::AutoModerationKeywordPresetType;
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Additional information used to determine whether a rule is triggered,
+/// depending on its trigger type.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct AutoModerationTriggerMetadata {
+    /// Substrings that don't trigger the rule, for keyword and keyword preset
+    /// rules.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub allow_list: Option<Vec<String>>,
+    /// Substrings that trigger the rule, for keyword rules.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub keyword_filter: Option<Vec<String>>,
+    /// Number of unique role and user mentions allowed in a message, for
+    /// mention spam rules.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub mention_total_limit: Option<u8>,
+    /// Lists of words defined by Discord that trigger the rule, for keyword
+    /// preset rules.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub presets: Option<Vec<AutoModerationKeywordPresetType>>,
+    /// Regular expressions that trigger the rule, for keyword rules.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub regex_patterns: Option<Vec<String>>,
+}
@@ -0,0 +1,31 @@
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of content that triggers a rule.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum AutoModerationTriggerType {
+    /// Content contains words from a user defined list of keywords.
+    Keyword = 1,
+    /// Content is generically spam.
+    Spam = 3,
+    /// Content contains words from lists of words defined by Discord.
+    KeywordPreset = 4,
+    /// Content contains more unique mentions than allowed.
+    MentionSpam = 5,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::AutoModerationTriggerType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoModerationTriggerType::Keyword, &[Token::U8(1)]);
+        serde_test::assert_tokens(&AutoModerationTriggerType::Spam, &[Token::U8(3)]);
+        serde_test::assert_tokens(&AutoModerationTriggerType::KeywordPreset, &[Token::U8(4)]);
+        serde_test::assert_tokens(&AutoModerationTriggerType::MentionSpam, &[Token::U8(5)]);
+    }
+}
@@ -2,48 +2,56 @@ use crate::{
     id::{EmojiId, RoleId},
     user::User,
 };
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{DeserializeSeed, Deserializer, SeqAccess, Visitor},
     Deserialize, Serialize,
 };
+#[cfg(feature = "serde-support")]
 use serde_mappable_seq::Key;
+#[cfg(feature = "serde-support")]
 use std::{
     collections::HashMap,
     fmt::{Formatter, Result as FmtResult},
 };
 
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Emoji {
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub animated: bool,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub available: bool,
     // This does not need to be optional here as it can only be optional
     // in a unicode emoji. Which can only happen in reactions, and we use
     // another struct for emojis in that case.
     pub id: EmojiId,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub managed: bool,
     pub name: String,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub require_colons: bool,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub roles: Vec<RoleId>,
     pub user: Option<User>,
 }
 
+#[cfg(feature = "serde-support")]
 impl Key<'_, EmojiId> for Emoji {
     fn key(&self) -> EmojiId {
         self.id
     }
 }
 
+#[cfg(feature = "serde-support")]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EmojiMapDeserializer;
 
+#[cfg(feature = "serde-support")]
 struct EmojiMapVisitor;
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for EmojiMapVisitor {
     type Value = HashMap<EmojiId, Emoji>;
 
@@ -69,6 +77,7 @@ impl<'de> Visitor<'de> for EmojiMapVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> DeserializeSeed<'de> for EmojiMapDeserializer {
     type Value = HashMap<EmojiId, Emoji>;
 
@@ -77,7 +86,7 @@ impl<'de> DeserializeSeed<'de> for EmojiMapDeserializer {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{Emoji, EmojiId, User};
     use crate::id::UserId;
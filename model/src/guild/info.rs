@@ -1,7 +1,9 @@
 use crate::{guild::Permissions, id::GuildId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct GuildInfo {
     pub icon: Option<String>,
     pub id: GuildId,
@@ -10,7 +12,7 @@ pub struct GuildInfo {
     pub permissions: Permissions,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{GuildId, GuildInfo, Permissions};
     use serde_test::Token;
@@ -3,10 +3,13 @@ use crate::{
     id::{IntegrationId, RoleId},
     user::User,
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde-support")]
 use serde_mappable_seq::Key;
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct GuildIntegration {
     pub account: IntegrationAccount,
     pub application: Option<IntegrationApplication>,
@@ -15,7 +18,7 @@ pub struct GuildIntegration {
     pub expire_behavior: Option<IntegrationExpireBehavior>,
     pub expire_grace_period: Option<u64>,
     pub id: IntegrationId,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: String,
     pub name: String,
     pub revoked: Option<bool>,
@@ -26,13 +29,14 @@ pub struct GuildIntegration {
     pub user: Option<User>,
 }
 
+#[cfg(feature = "serde-support")]
 impl Key<'_, IntegrationId> for GuildIntegration {
     fn key(&self) -> IntegrationId {
         self.id
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{
         GuildIntegration, IntegrationAccount, IntegrationExpireBehavior, IntegrationId, User,
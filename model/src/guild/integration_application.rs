@@ -1,8 +1,10 @@
 use crate::{id::ApplicationId, user::User};
 
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct IntegrationApplication {
     pub bot: Option<User>,
     pub description: String,
@@ -12,7 +14,7 @@ pub struct IntegrationApplication {
     pub summary: String,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::IntegrationApplication;
     use crate::id::ApplicationId;
@@ -1,9 +1,9 @@
+#[cfg(feature = "serde-support")]
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// Behavior to perform when the user's integration expires.
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
 #[repr(u8)]
 pub enum IntegrationExpireBehavior {
     /// Remove the role when the integration expires.
@@ -12,7 +12,7 @@ pub enum IntegrationExpireBehavior {
     Kick = 1,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::IntegrationExpireBehavior;
     use serde_test::Token;
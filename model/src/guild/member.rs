@@ -1,8 +1,11 @@
+#[cfg(feature = "serde-support")]
+use crate::id::UserId;
 use crate::{
-    id::{GuildId, RoleId, UserId},
+    id::{GuildId, RoleId},
     user::User,
 };
 
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{
         value::MapAccessDeserializer, DeserializeSeed, Deserializer, Error as DeError, MapAccess,
@@ -10,13 +13,16 @@ use serde::{
     },
     Deserialize, Serialize,
 };
+#[cfg(feature = "serde-support")]
 use serde_mappable_seq::Key;
+#[cfg(feature = "serde-support")]
 use std::{
     collections::HashMap,
     fmt::{Formatter, Result as FmtResult},
 };
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Member {
     pub deaf: bool,
     pub guild_id: GuildId,
@@ -29,6 +35,7 @@ pub struct Member {
     pub user: User,
 }
 
+#[cfg(feature = "serde-support")]
 impl Key<'_, UserId> for Member {
     fn key(&self) -> UserId {
         self.user.id
@@ -36,7 +43,8 @@ impl Key<'_, UserId> for Member {
 }
 
 // Used in the guild deserializer.
-#[derive(Deserialize, Serialize)]
+#[cfg(feature = "serde-support")]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub(crate) struct MemberIntermediary {
     pub deaf: bool,
     pub hoisted_role: Option<RoleId>,
@@ -64,6 +72,7 @@ impl MemberDeserializer {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> DeserializeSeed<'de> for MemberDeserializer {
     type Value = Member;
 
@@ -72,8 +81,10 @@ impl<'de> DeserializeSeed<'de> for MemberDeserializer {
     }
 }
 
+#[cfg(feature = "serde-support")]
 pub(crate) struct MemberVisitor(GuildId);
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for MemberVisitor {
     type Value = Member;
 
@@ -99,9 +110,11 @@ impl<'de> Visitor<'de> for MemberVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct OptionalMemberDeserializer(GuildId);
 
+#[cfg(feature = "serde-support")]
 impl OptionalMemberDeserializer {
     /// Create a new deserializer for a member when you know the ID but the
     /// payload probably doesn't contain it.
@@ -110,6 +123,7 @@ impl OptionalMemberDeserializer {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> DeserializeSeed<'de> for OptionalMemberDeserializer {
     type Value = Option<Member>;
 
@@ -118,8 +132,10 @@ impl<'de> DeserializeSeed<'de> for OptionalMemberDeserializer {
     }
 }
 
+#[cfg(feature = "serde-support")]
 struct OptionalMemberVisitor(GuildId);
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for OptionalMemberVisitor {
     type Value = Option<Member>;
 
@@ -147,8 +163,10 @@ impl MemberMapDeserializer {
     }
 }
 
+#[cfg(feature = "serde-support")]
 struct MemberMapVisitor(GuildId);
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for MemberMapVisitor {
     type Value = HashMap<UserId, Member>;
 
@@ -169,6 +187,7 @@ impl<'de> Visitor<'de> for MemberMapVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> DeserializeSeed<'de> for MemberMapDeserializer {
     type Value = HashMap<UserId, Member>;
 
@@ -177,7 +196,7 @@ impl<'de> DeserializeSeed<'de> for MemberMapDeserializer {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::Member;
     use crate::{
@@ -1,5 +1,7 @@
 pub mod audit_log;
+pub mod auto_moderation;
 pub mod member;
+pub mod scheduled_event;
 
 mod ban;
 mod default_message_notification_level;
@@ -36,24 +38,30 @@ pub use self::{
     widget::GuildWidget,
 };
 
+#[cfg(feature = "serde-support")]
 use self::{member::MemberMapDeserializer, role::RoleMapDeserializer};
 use crate::{
-    channel::{GuildChannel, GuildChannelMapDeserializer},
-    gateway::presence::{Presence, PresenceMapDeserializer},
-    guild::emoji::EmojiMapDeserializer,
+    channel::GuildChannel,
+    gateway::presence::Presence,
     id::{ApplicationId, ChannelId, EmojiId, GuildId, RoleId, UserId},
-    voice::voice_state::{VoiceState, VoiceStateMapDeserializer},
+    voice::voice_state::VoiceState,
 };
+#[cfg(feature = "serde-support")]
+use crate::{
+    channel::GuildChannelMapDeserializer, gateway::presence::PresenceMapDeserializer,
+    guild::emoji::EmojiMapDeserializer, voice::voice_state::VoiceStateMapDeserializer,
+};
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     Deserialize, Serialize,
 };
-use std::{
-    collections::HashMap,
-    fmt::{Formatter, Result as FmtResult},
-};
+use std::collections::HashMap;
+#[cfg(feature = "serde-support")]
+use std::fmt::{Formatter, Result as FmtResult};
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
 pub struct Guild {
     pub afk_channel_id: Option<ChannelId>,
     pub afk_timeout: u64,
@@ -61,19 +69,19 @@ pub struct Guild {
     pub approximate_member_count: Option<u64>,
     pub approximate_presence_count: Option<u64>,
     pub banner: Option<String>,
-    #[serde(default, with = "serde_mappable_seq")]
+    #[cfg_attr(feature = "serde-support", serde(default, with = "serde_mappable_seq"))]
     pub channels: HashMap<ChannelId, GuildChannel>,
     pub default_message_notifications: DefaultMessageNotificationLevel,
     pub description: Option<String>,
     pub discovery_splash: Option<String>,
-    #[serde(with = "serde_mappable_seq")]
+    #[cfg_attr(feature = "serde-support", serde(with = "serde_mappable_seq"))]
     pub emojis: HashMap<EmojiId, Emoji>,
     pub explicit_content_filter: ExplicitContentFilter,
     pub features: Vec<String>,
     pub icon: Option<String>,
     pub id: GuildId,
     pub joined_at: Option<String>,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub large: bool,
     // Not documented so I marked it as optional.
     pub lazy: Option<bool>,
@@ -81,7 +89,7 @@ pub struct Guild {
     pub max_presences: Option<u64>,
     pub max_video_channel_users: Option<u64>,
     pub member_count: Option<u64>,
-    #[serde(default, with = "serde_mappable_seq")]
+    #[cfg_attr(feature = "serde-support", serde(default, with = "serde_mappable_seq"))]
     pub members: HashMap<UserId, Member>,
     pub mfa_level: MfaLevel,
     pub name: String,
@@ -90,32 +98,39 @@ pub struct Guild {
     pub permissions: Option<Permissions>,
     pub preferred_locale: String,
     pub premium_subscription_count: Option<u64>,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub premium_tier: PremiumTier,
-    #[serde(default, with = "serde_mappable_seq")]
+    #[cfg_attr(feature = "serde-support", serde(default, with = "serde_mappable_seq"))]
     pub presences: HashMap<UserId, Presence>,
     pub region: String,
-    #[serde(with = "serde_mappable_seq")]
+    #[cfg_attr(feature = "serde-support", serde(with = "serde_mappable_seq"))]
     pub roles: HashMap<RoleId, Role>,
     pub rules_channel_id: Option<ChannelId>,
     pub splash: Option<String>,
     pub system_channel_flags: SystemChannelFlags,
     pub system_channel_id: Option<ChannelId>,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default, with = "serde_mappable_seq"))]
+    pub threads: HashMap<ChannelId, GuildChannel>,
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub unavailable: bool,
     pub vanity_url_code: Option<String>,
     pub verification_level: VerificationLevel,
-    #[serde(default, with = "serde_mappable_seq")]
+    #[cfg_attr(feature = "serde-support", serde(default, with = "serde_mappable_seq"))]
     pub voice_states: HashMap<UserId, VoiceState>,
     pub widget_channel_id: Option<ChannelId>,
     pub widget_enabled: Option<bool>,
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> Deserialize<'de> for Guild {
     #[allow(clippy::too_many_lines)]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        #[derive(Debug, Deserialize)]
-        #[serde(field_identifier, rename_all = "snake_case")]
+        #[derive(Debug)]
+        #[cfg_attr(feature = "serde-support", derive(Deserialize))]
+        #[cfg_attr(
+            feature = "serde-support",
+            serde(field_identifier, rename_all = "snake_case")
+        )]
         enum Field {
             AfkChannelId,
             AfkTimeout,
@@ -155,6 +170,7 @@ impl<'de> Deserialize<'de> for Guild {
             SystemChannelFlags,
             SystemChannelId,
             RulesChannelId,
+            Threads,
             Unavailable,
             VerificationLevel,
             VoiceStates,
@@ -165,6 +181,7 @@ impl<'de> Deserialize<'de> for Guild {
 
         struct GuildVisitor;
 
+        #[cfg(feature = "serde-support")]
         impl<'de> Visitor<'de> for GuildVisitor {
             type Value = Guild;
 
@@ -212,6 +229,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut system_channel_id = None::<Option<_>>;
                 let mut system_channel_flags = None;
                 let mut rules_channel_id = None::<Option<_>>;
+                let mut threads = None;
                 let mut unavailable = None;
                 let mut verification_level = None;
                 let mut voice_states = None;
@@ -516,6 +534,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             rules_channel_id = Some(map.next_value()?);
                         }
+                        Field::Threads => {
+                            if threads.is_some() {
+                                return Err(DeError::duplicate_field("threads"));
+                            }
+
+                            threads = Some(map.next_value_seed(GuildChannelMapDeserializer)?);
+                        }
                         Field::Unavailable => {
                             if unavailable.is_some() {
                                 return Err(DeError::duplicate_field("unavailable"));
@@ -607,6 +632,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let rules_channel_id = rules_channel_id.unwrap_or_default();
                 let splash = splash.unwrap_or_default();
                 let system_channel_id = system_channel_id.unwrap_or_default();
+                let mut threads = threads.unwrap_or_default();
                 let unavailable = unavailable.unwrap_or_default();
                 let vanity_url_code = vanity_url_code.unwrap_or_default();
                 let mut voice_states = voice_states.unwrap_or_default();
@@ -656,6 +682,7 @@ impl<'de> Deserialize<'de> for Guild {
                     ?splash,
                     ?system_channel_flags,
                     ?system_channel_id,
+                    ?threads,
                     ?unavailable,
                     ?vanity_url_code,
                     ?voice_states,
@@ -664,15 +691,24 @@ impl<'de> Deserialize<'de> for Guild {
                     ?verification_level,
                 );
 
-                for channel in channels.values_mut() {
+                for channel in channels.values_mut().chain(threads.values_mut()) {
                     match channel {
                         GuildChannel::Category(c) => {
                             c.guild_id.replace(id);
                         }
+                        GuildChannel::NewsThread(c) => {
+                            c.guild_id.replace(id);
+                        }
+                        GuildChannel::PrivateThread(c) => {
+                            c.guild_id.replace(id);
+                        }
+                        GuildChannel::PublicThread(c) => {
+                            c.guild_id.replace(id);
+                        }
                         GuildChannel::Text(c) => {
                             c.guild_id.replace(id);
                         }
-                        GuildChannel::Voice(c) => {
+                        GuildChannel::Stage(c) | GuildChannel::Voice(c) => {
                             c.guild_id.replace(id);
                         }
                     }
@@ -729,6 +765,7 @@ impl<'de> Deserialize<'de> for Guild {
                     system_channel_id,
                     system_channel_flags,
                     rules_channel_id,
+                    threads,
                     unavailable,
                     verification_level,
                     voice_states,
@@ -778,6 +815,7 @@ impl<'de> Deserialize<'de> for Guild {
             "system_channel_id",
             "system_channel_flags",
             "rules_channel_id",
+            "threads",
             "unavailable",
             "verification_level",
             "voice_states",
@@ -790,7 +828,7 @@ impl<'de> Deserialize<'de> for Guild {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{
         ApplicationId, ChannelId, DefaultMessageNotificationLevel, ExplicitContentFilter, Guild,
@@ -841,6 +879,7 @@ mod tests {
             splash: Some("splash hash".to_owned()),
             system_channel_flags: SystemChannelFlags::SUPPRESS_PREMIUM_SUBSCRIPTIONS,
             system_channel_id: Some(ChannelId(7)),
+            threads: HashMap::new(),
             unavailable: false,
             vanity_url_code: Some("twilight".to_owned()),
             verification_level: VerificationLevel::Medium,
@@ -854,7 +893,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Guild",
-                    len: 44,
+                    len: 45,
                 },
                 Token::Str("afk_channel_id"),
                 Token::Some,
@@ -967,6 +1006,9 @@ mod tests {
                 Token::Some,
                 Token::NewtypeStruct { name: "ChannelId" },
                 Token::Str("7"),
+                Token::Str("threads"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
                 Token::Str("unavailable"),
                 Token::Bool(false),
                 Token::Str("vanity_url_code"),
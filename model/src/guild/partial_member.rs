@@ -1,7 +1,9 @@
 use crate::id::RoleId;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct PartialMember {
     pub deaf: bool,
     pub joined_at: Option<String>,
@@ -10,7 +12,7 @@ pub struct PartialMember {
     pub roles: Vec<RoleId>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{PartialMember, RoleId};
     use serde_test::Token;
@@ -1,8 +1,10 @@
 use bitflags::bitflags;
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{Deserialize, Deserializer, Error as DeError, Visitor},
     ser::{Serialize, Serializer},
 };
+#[cfg(feature = "serde-support")]
 use std::fmt::{Formatter, Result as FmtResult};
 
 bitflags! {
@@ -38,11 +40,18 @@ bitflags! {
         const MANAGE_ROLES = 0x1000_0000;
         const MANAGE_WEBHOOKS = 0x2000_0000;
         const MANAGE_EMOJIS = 0x4000_0000;
+        const MANAGE_EVENTS = 0x0002_0000_0000;
+        const MANAGE_THREADS = 0x0004_0000_0000;
+        const CREATE_PUBLIC_THREADS = 0x0008_0000_0000;
+        const CREATE_PRIVATE_THREADS = 0x0010_0000_0000;
+        const SEND_MESSAGES_IN_THREADS = 0x0040_0000_0000;
     }
 }
 
+#[cfg(feature = "serde-support")]
 struct PermissionsVisitor;
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for PermissionsVisitor {
     type Value = Permissions;
 
@@ -63,19 +72,21 @@ impl<'de> Visitor<'de> for PermissionsVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> Deserialize<'de> for Permissions {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         deserializer.deserialize_any(PermissionsVisitor)
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl Serialize for Permissions {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         serializer.serialize_str(&self.bits().to_string())
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::Permissions;
     use serde_test::Token;
@@ -86,4 +97,11 @@ mod tests {
 
         serde_test::assert_tokens(&permissions, &[Token::Str("8388608")]);
     }
+
+    #[test]
+    fn test_thread_permissions() {
+        let permissions = Permissions::SEND_MESSAGES_IN_THREADS;
+
+        serde_test::assert_tokens(&permissions, &[Token::Str("274877906944")]);
+    }
 }
@@ -1,7 +1,9 @@
 use crate::{guild::Emoji, id::GuildId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct GuildPreview {
     pub approximate_member_count: u64,
     pub approximate_presence_count: u64,
@@ -15,7 +17,7 @@ pub struct GuildPreview {
     pub splash: Option<String>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{Emoji, GuildId, GuildPreview};
     use crate::id::EmojiId;
@@ -1,15 +1,19 @@
 use crate::{guild::Permissions, id::RoleId};
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{DeserializeSeed, Deserializer, SeqAccess, Visitor},
     Deserialize, Serialize,
 };
+#[cfg(feature = "serde-support")]
 use serde_mappable_seq::Key;
+#[cfg(feature = "serde-support")]
 use std::{
     collections::HashMap,
     fmt::{Formatter, Result as FmtResult},
 };
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Role {
     pub color: u32,
     pub hoist: bool,
@@ -21,17 +25,21 @@ pub struct Role {
     pub position: i64,
 }
 
+#[cfg(feature = "serde-support")]
 impl Key<'_, RoleId> for Role {
     fn key(&self) -> RoleId {
         self.id
     }
 }
 
+#[cfg(feature = "serde-support")]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RoleMapDeserializer;
 
+#[cfg(feature = "serde-support")]
 struct RoleMapDeserializerVisitor;
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for RoleMapDeserializerVisitor {
     type Value = HashMap<RoleId, Role>;
 
@@ -57,6 +65,7 @@ impl<'de> Visitor<'de> for RoleMapDeserializerVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> DeserializeSeed<'de> for RoleMapDeserializer {
     type Value = HashMap<RoleId, Role>;
 
@@ -65,7 +74,7 @@ impl<'de> DeserializeSeed<'de> for RoleMapDeserializer {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{Permissions, Role, RoleId};
     use serde_test::Token;
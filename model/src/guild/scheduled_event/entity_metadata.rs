@@ -0,0 +1,14 @@
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Additional information about the entity of a scheduled event.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct EntityMetadata {
+    /// Location of the event, for events with an external entity.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<String>,
+}
@@ -0,0 +1,28 @@
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of the entity that a scheduled event takes place in.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum EntityType {
+    /// Event takes place in a stage channel.
+    StageInstance = 1,
+    /// Event takes place in a voice channel.
+    Voice = 2,
+    /// Event takes place outside of Discord, at a location.
+    External = 3,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::EntityType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&EntityType::StageInstance, &[Token::U8(1)]);
+        serde_test::assert_tokens(&EntityType::Voice, &[Token::U8(2)]);
+        serde_test::assert_tokens(&EntityType::External, &[Token::U8(3)]);
+    }
+}
@@ -0,0 +1,166 @@
+//! Types for scheduled events, which members of a guild can subscribe to.
+
+mod entity_metadata;
+mod entity_type;
+mod privacy_level;
+mod recurrence_rule;
+mod status;
+mod user;
+
+pub use self::{
+    entity_metadata::EntityMetadata,
+    entity_type::EntityType,
+    privacy_level::PrivacyLevel,
+    recurrence_rule::{
+        RecurrenceRule, RecurrenceRuleFrequency, RecurrenceRuleMonth, RecurrenceRuleNWeekday,
+        RecurrenceRuleWeekday,
+    },
+    status::Status,
+    user::GuildScheduledEventUser,
+};
+
+use crate::{
+    id::{ChannelId, GenericId, GuildId, ScheduledEventId, UserId},
+    user::User,
+};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// Event scheduled in a guild.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct GuildScheduledEvent {
+    /// Channel the event takes place in, if it doesn't have an external
+    /// entity.
+    pub channel_id: Option<ChannelId>,
+    /// User that created the event, if it was created after creators started
+    /// being recorded.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub creator: Option<User>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub creator_id: Option<UserId>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<String>,
+    pub entity_id: Option<GenericId>,
+    pub entity_metadata: Option<EntityMetadata>,
+    pub entity_type: EntityType,
+    pub guild_id: GuildId,
+    pub id: ScheduledEventId,
+    /// Hash of the cover image of the event.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub image: Option<String>,
+    pub name: String,
+    pub privacy_level: PrivacyLevel,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub recurrence_rule: Option<RecurrenceRule>,
+    /// ISO 8601 timestamp of when the event ends, required for events with
+    /// an external entity.
+    pub scheduled_end_time: Option<String>,
+    /// ISO 8601 timestamp of when the event starts.
+    pub scheduled_start_time: String,
+    pub status: Status,
+    /// Number of users subscribed to the event, if requested.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub user_count: Option<u64>,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{
+        EntityMetadata, EntityType, GuildId, GuildScheduledEvent, PrivacyLevel, ScheduledEventId,
+        Status,
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn test_guild_scheduled_event() {
+        let value = GuildScheduledEvent {
+            channel_id: None,
+            creator: None,
+            creator_id: None,
+            description: Some("a meetup".to_owned()),
+            entity_id: None,
+            entity_metadata: Some(EntityMetadata {
+                location: Some("the park".to_owned()),
+            }),
+            entity_type: EntityType::External,
+            guild_id: GuildId(1),
+            id: ScheduledEventId(2),
+            image: None,
+            name: "meetup".to_owned(),
+            privacy_level: PrivacyLevel::GuildOnly,
+            recurrence_rule: None,
+            scheduled_end_time: Some("2022-01-01T20:00:00.000000+00:00".to_owned()),
+            scheduled_start_time: "2022-01-01T18:00:00.000000+00:00".to_owned(),
+            status: Status::Scheduled,
+            user_count: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "GuildScheduledEvent",
+                    len: 12,
+                },
+                Token::Str("channel_id"),
+                Token::None,
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("a meetup"),
+                Token::Str("entity_id"),
+                Token::None,
+                Token::Str("entity_metadata"),
+                Token::Some,
+                Token::Struct {
+                    name: "EntityMetadata",
+                    len: 1,
+                },
+                Token::Str("location"),
+                Token::Some,
+                Token::Str("the park"),
+                Token::StructEnd,
+                Token::Str("entity_type"),
+                Token::U8(3),
+                Token::Str("guild_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::Str("2"),
+                Token::Str("name"),
+                Token::Str("meetup"),
+                Token::Str("privacy_level"),
+                Token::U8(2),
+                Token::Str("scheduled_end_time"),
+                Token::Some,
+                Token::Str("2022-01-01T20:00:00.000000+00:00"),
+                Token::Str("scheduled_start_time"),
+                Token::Str("2022-01-01T18:00:00.000000+00:00"),
+                Token::Str("status"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
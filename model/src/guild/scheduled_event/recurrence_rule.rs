@@ -0,0 +1,163 @@
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde-support")]
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Rule for how often a scheduled event repeats.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct RecurrenceRule {
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub by_month: Option<Vec<RecurrenceRuleMonth>>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub by_month_day: Option<Vec<u8>>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub by_n_weekday: Option<Vec<RecurrenceRuleNWeekday>>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub by_weekday: Option<Vec<RecurrenceRuleWeekday>>,
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub by_year_day: Option<Vec<u16>>,
+    /// Number of times the event can repeat before stopping.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub count: Option<u32>,
+    /// ISO 8601 timestamp of when the event stops repeating.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub end: Option<String>,
+    pub frequency: RecurrenceRuleFrequency,
+    /// Spacing between repetitions, in units of the frequency.
+    pub interval: u16,
+    /// ISO 8601 timestamp of when the event starts repeating.
+    pub start: String,
+}
+
+/// How often a scheduled event repeats.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum RecurrenceRuleFrequency {
+    Yearly = 0,
+    Monthly = 1,
+    Weekly = 2,
+    Daily = 3,
+}
+
+/// Month that a scheduled event repeats in.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum RecurrenceRuleMonth {
+    January = 1,
+    February = 2,
+    March = 3,
+    April = 4,
+    May = 5,
+    June = 6,
+    July = 7,
+    August = 8,
+    September = 9,
+    October = 10,
+    November = 11,
+    December = 12,
+}
+
+/// Weekday of a specific week of the month that a scheduled event repeats
+/// on, such as the second Tuesday.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct RecurrenceRuleNWeekday {
+    pub day: RecurrenceRuleWeekday,
+    /// Week of the month, from 1 to 5.
+    pub n: u8,
+}
+
+/// Weekday that a scheduled event repeats on.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
+#[repr(u8)]
+pub enum RecurrenceRuleWeekday {
+    Monday = 0,
+    Tuesday = 1,
+    Wednesday = 2,
+    Thursday = 3,
+    Friday = 4,
+    Saturday = 5,
+    Sunday = 6,
+}
+
+#[cfg(all(test, feature = "serde-support"))]
+mod tests {
+    use super::{
+        RecurrenceRule, RecurrenceRuleFrequency, RecurrenceRuleNWeekday, RecurrenceRuleWeekday,
+    };
+    use serde_test::Token;
+
+    #[test]
+    fn test_recurrence_rule() {
+        let value = RecurrenceRule {
+            by_month: None,
+            by_month_day: None,
+            by_n_weekday: Some(vec![RecurrenceRuleNWeekday {
+                day: RecurrenceRuleWeekday::Tuesday,
+                n: 2,
+            }]),
+            by_weekday: None,
+            by_year_day: None,
+            count: None,
+            end: None,
+            frequency: RecurrenceRuleFrequency::Monthly,
+            interval: 1,
+            start: "2022-01-11T18:00:00.000000+00:00".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "RecurrenceRule",
+                    len: 4,
+                },
+                Token::Str("by_n_weekday"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "RecurrenceRuleNWeekday",
+                    len: 2,
+                },
+                Token::Str("day"),
+                Token::U8(1),
+                Token::Str("n"),
+                Token::U8(2),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("frequency"),
+                Token::U8(1),
+                Token::Str("interval"),
+                Token::U16(1),
+                Token::Str("start"),
+                Token::Str("2022-01-11T18:00:00.000000+00:00"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{guild::PartialMember, id::ScheduledEventId, user::User};
+#[cfg(feature = "serde-support")]
+use serde::{Deserialize, Serialize};
+
+/// User subscribed to a scheduled event.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct GuildScheduledEventUser {
+    pub guild_scheduled_event_id: ScheduledEventId,
+    /// Guild member of the user, if requested.
+    #[cfg_attr(
+        feature = "serde-support",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    pub member: Option<PartialMember>,
+    pub user: User,
+}
@@ -1,17 +1,20 @@
-use crate::{
-    guild::{Guild, UnavailableGuild},
-    id::GuildId,
-};
+use crate::guild::{Guild, UnavailableGuild};
+#[cfg(feature = "serde-support")]
+use crate::id::GuildId;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde-support")]
 use serde_mappable_seq::Key;
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde-support", serde(untagged))]
 pub enum GuildStatus {
     Online(Guild),
     Offline(UnavailableGuild),
 }
 
+#[cfg(feature = "serde-support")]
 impl Key<'_, GuildId> for GuildStatus {
     fn key(&self) -> GuildId {
         match self {
@@ -21,7 +24,7 @@ impl Key<'_, GuildId> for GuildStatus {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{GuildId, GuildStatus, UnavailableGuild};
     use serde_test::Token;
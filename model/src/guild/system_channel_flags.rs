@@ -1,4 +1,5 @@
 use bitflags::bitflags;
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{Deserialize, Deserializer},
     ser::{Serialize, Serializer},
@@ -11,12 +12,14 @@ bitflags! {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> Deserialize<'de> for SystemChannelFlags {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         Ok(Self::from_bits_truncate(u64::deserialize(deserializer)?))
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl Serialize for SystemChannelFlags {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -26,7 +29,7 @@ impl Serialize for SystemChannelFlags {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::SystemChannelFlags;
     use serde_test::Token;
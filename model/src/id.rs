@@ -6,6 +6,7 @@
 //! These IDs support deserializing from both integers and strings and serialize
 //! into strings.
 
+#[cfg(feature = "serde-support")]
 pub(crate) mod string {
     use serde::{
         de::{Deserializer, Error as DeError, Visitor},
@@ -48,13 +49,13 @@ pub(crate) mod string {
     }
 }
 
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
-pub struct ApplicationId(#[serde(with = "string")] pub u64);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct ApplicationId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
 
 impl Display for ApplicationId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -68,10 +69,9 @@ impl From<u64> for ApplicationId {
     }
 }
 
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
-pub struct AttachmentId(#[serde(with = "string")] pub u64);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct AttachmentId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
 
 impl Display for AttachmentId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -85,10 +85,9 @@ impl From<u64> for AttachmentId {
     }
 }
 
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
-pub struct AuditLogEntryId(#[serde(with = "string")] pub u64);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct AuditLogEntryId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
 
 impl Display for AuditLogEntryId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -102,10 +101,27 @@ impl From<u64> for AuditLogEntryId {
     }
 }
 
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
-pub struct ChannelId(#[serde(with = "string")] pub u64);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct AutoModerationRuleId(
+    #[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64,
+);
+
+impl Display for AutoModerationRuleId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for AutoModerationRuleId {
+    fn from(id: u64) -> Self {
+        AutoModerationRuleId(id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct ChannelId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
 
 impl Display for ChannelId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -119,10 +135,25 @@ impl From<u64> for ChannelId {
     }
 }
 
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
-pub struct EmojiId(#[serde(with = "string")] pub u64);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct CommandId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
+
+impl Display for CommandId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for CommandId {
+    fn from(id: u64) -> Self {
+        CommandId(id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct EmojiId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
 
 impl Display for EmojiId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -136,10 +167,9 @@ impl From<u64> for EmojiId {
     }
 }
 
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
-pub struct GenericId(#[serde(with = "string")] pub u64);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct GenericId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
 
 impl Display for GenericId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -153,10 +183,9 @@ impl From<u64> for GenericId {
     }
 }
 
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
-pub struct GuildId(#[serde(with = "string")] pub u64);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct GuildId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
 
 impl Display for GuildId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -170,10 +199,9 @@ impl From<u64> for GuildId {
     }
 }
 
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
-pub struct IntegrationId(#[serde(with = "string")] pub u64);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct IntegrationId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
 
 impl Display for IntegrationId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -187,10 +215,25 @@ impl From<u64> for IntegrationId {
     }
 }
 
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
-pub struct MessageId(#[serde(with = "string")] pub u64);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct InteractionId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
+
+impl Display for InteractionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for InteractionId {
+    fn from(id: u64) -> Self {
+        InteractionId(id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct MessageId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
 
 impl Display for MessageId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -204,10 +247,9 @@ impl From<u64> for MessageId {
     }
 }
 
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
-pub struct RoleId(#[serde(with = "string")] pub u64);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct RoleId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
 
 impl Display for RoleId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -221,10 +263,73 @@ impl From<u64> for RoleId {
     }
 }
 
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
-pub struct UserId(#[serde(with = "string")] pub u64);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct ScheduledEventId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
+
+impl Display for ScheduledEventId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for ScheduledEventId {
+    fn from(id: u64) -> Self {
+        ScheduledEventId(id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct StageId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
+
+impl Display for StageId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for StageId {
+    fn from(id: u64) -> Self {
+        StageId(id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct StickerId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
+
+impl Display for StickerId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for StickerId {
+    fn from(id: u64) -> Self {
+        StickerId(id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct StickerPackId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
+
+impl Display for StickerPackId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl From<u64> for StickerPackId {
+    fn from(id: u64) -> Self {
+        StickerPackId(id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct UserId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
 
 impl Display for UserId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -238,10 +343,9 @@ impl From<u64> for UserId {
     }
 }
 
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
-pub struct WebhookId(#[serde(with = "string")] pub u64);
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct WebhookId(#[cfg_attr(feature = "serde-support", serde(with = "string"))] pub u64);
 
 impl Display for WebhookId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -255,11 +359,12 @@ impl From<u64> for WebhookId {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{
-        ApplicationId, AttachmentId, AuditLogEntryId, ChannelId, EmojiId, GenericId, GuildId,
-        IntegrationId, MessageId, RoleId, UserId, WebhookId,
+        ApplicationId, AttachmentId, AuditLogEntryId, AutoModerationRuleId, ChannelId, CommandId,
+        EmojiId, GenericId, GuildId, IntegrationId, InteractionId, MessageId, RoleId,
+        ScheduledEventId, StageId, StickerId, StickerPackId, UserId, WebhookId,
     };
     use serde_test::Token;
 
@@ -320,6 +425,24 @@ mod tests {
                 Token::U64(114_941_315_417_899_012),
             ],
         );
+        serde_test::assert_tokens(
+            &AutoModerationRuleId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "AutoModerationRuleId",
+                },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &AutoModerationRuleId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "AutoModerationRuleId",
+                },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
         serde_test::assert_tokens(
             &ChannelId(114_941_315_417_899_012),
             &[
@@ -334,6 +457,20 @@ mod tests {
                 Token::U64(114_941_315_417_899_012),
             ],
         );
+        serde_test::assert_tokens(
+            &CommandId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct { name: "CommandId" },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &CommandId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct { name: "CommandId" },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
         serde_test::assert_tokens(
             &EmojiId(114_941_315_417_899_012),
             &[
@@ -394,6 +531,24 @@ mod tests {
                 Token::U64(114_941_315_417_899_012),
             ],
         );
+        serde_test::assert_tokens(
+            &InteractionId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "InteractionId",
+                },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &InteractionId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "InteractionId",
+                },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
         serde_test::assert_tokens(
             &MessageId(114_941_315_417_899_012),
             &[
@@ -422,6 +577,70 @@ mod tests {
                 Token::U64(114_941_315_417_899_012),
             ],
         );
+        serde_test::assert_tokens(
+            &ScheduledEventId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &ScheduledEventId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "ScheduledEventId",
+                },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
+        serde_test::assert_tokens(
+            &StageId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct { name: "StageId" },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &StageId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct { name: "StageId" },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
+        serde_test::assert_tokens(
+            &StickerId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct { name: "StickerId" },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &StickerId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct { name: "StickerId" },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
+        serde_test::assert_tokens(
+            &StickerPackId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "StickerPackId",
+                },
+                Token::Str("114941315417899012"),
+            ],
+        );
+        serde_test::assert_de_tokens(
+            &StickerPackId(114_941_315_417_899_012),
+            &[
+                Token::NewtypeStruct {
+                    name: "StickerPackId",
+                },
+                Token::U64(114_941_315_417_899_012),
+            ],
+        );
         serde_test::assert_tokens(
             &UserId(114_941_315_417_899_012),
             &[
@@ -1,18 +1,20 @@
 use crate::{channel::ChannelType, id::ChannelId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct InviteChannel {
     /// ID of the channel.
     pub id: ChannelId,
     /// Name of the channel.
     pub name: Option<String>,
     /// Type of the channel.
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: ChannelType,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ChannelId, ChannelType, InviteChannel};
     use serde_test::Token;
@@ -1,7 +1,9 @@
 use crate::{guild::VerificationLevel, id::GuildId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct InviteGuild {
     /// Hash of the banner image.
     pub banner: Option<String>,
@@ -23,7 +25,7 @@ pub struct InviteGuild {
     pub verification_level: VerificationLevel,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{GuildId, InviteGuild, VerificationLevel};
     use serde_test::Token;
@@ -1,6 +1,8 @@
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct InviteMetadata {
     pub created_at: String,
     pub max_age: u64,
@@ -9,7 +11,7 @@ pub struct InviteMetadata {
     pub uses: u64,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::InviteMetadata;
     use serde_test::Token;
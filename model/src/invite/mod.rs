@@ -9,9 +9,11 @@ pub use self::{
 };
 
 use super::user::User;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Invite {
     pub approximate_member_count: Option<u64>,
     pub approximate_presence_count: Option<u64>,
@@ -23,7 +25,7 @@ pub struct Invite {
     pub target_user: Option<User>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{Invite, InviteChannel, TargetUserType};
     use crate::{channel::ChannelType, id::ChannelId};
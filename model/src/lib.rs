@@ -21,6 +21,17 @@
 //! resource category. These types may be directly returned by, built on top of,
 //! or extended by other crates.
 //!
+//! ## Features
+//!
+//! ### `serde-support`
+//!
+//! The `serde-support` feature, enabled by default, implements `Deserialize`
+//! and `Serialize` for the models. Disabling it leaves only the plain types,
+//! which is useful for crates that build or inspect models without touching
+//! JSON.
+//! The audit log types hold arbitrary serde values and are only available with
+//! this feature.
+//!
 //! ## License
 //!
 //! [ISC][LICENSE.md]
@@ -46,6 +57,7 @@
 )]
 #![allow(clippy::module_name_repetitions, clippy::must_use_candidate)]
 
+pub mod application;
 pub mod channel;
 pub mod gateway;
 pub mod guild;
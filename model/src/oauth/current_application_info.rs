@@ -3,9 +3,11 @@ use crate::{
     oauth::{id::SkuId, team::Team},
     user::User,
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct CurrentApplicationInfo {
     pub bot_public: bool,
     pub bot_require_code_grant: bool,
@@ -17,7 +19,7 @@ pub struct CurrentApplicationInfo {
     pub name: String,
     pub owner: User,
     pub primary_sku_id: Option<SkuId>,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub rpc_origins: Vec<String>,
     pub slug: Option<String>,
     pub summary: String,
@@ -25,7 +27,7 @@ pub struct CurrentApplicationInfo {
     pub verify_key: String,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{CurrentApplicationInfo, GuildId, SkuId, Team, User};
     use crate::{id::ApplicationId, id::UserId, oauth::id::TeamId};
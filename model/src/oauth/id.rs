@@ -7,11 +7,13 @@
 //!
 //! [`id`]: ../id
 
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
-#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
-pub struct SkuId(#[serde(with = "crate::id::string")] pub u64);
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct SkuId(#[cfg_attr(feature = "serde-support", serde(with = "crate::id::string"))] pub u64);
 
 impl Display for SkuId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -19,8 +21,11 @@ impl Display for SkuId {
     }
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
-pub struct TeamId(#[serde(with = "crate::id::string")] pub u64);
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
+pub struct TeamId(
+    #[cfg_attr(feature = "serde-support", serde(with = "crate::id::string"))] pub u64,
+);
 
 impl Display for TeamId {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
@@ -28,7 +33,7 @@ impl Display for TeamId {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{SkuId, TeamId};
     use serde_test::Token;
@@ -2,9 +2,11 @@ use crate::{
     oauth::{id::TeamId, team::TeamMembershipState},
     user::User,
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct TeamMember {
     pub membership_state: TeamMembershipState,
     pub permissions: Vec<String>,
@@ -12,7 +14,7 @@ pub struct TeamMember {
     pub user: User,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{TeamId, TeamMember, TeamMembershipState, User};
     use crate::id::UserId;
@@ -4,9 +4,11 @@ mod membership_state;
 pub use self::{member::TeamMember, membership_state::TeamMembershipState};
 
 use crate::{id::UserId, oauth::id::TeamId};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Team {
     pub icon: Option<String>,
     pub id: TeamId,
@@ -14,7 +16,7 @@ pub struct Team {
     pub owner_user_id: UserId,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{Team, TeamId, UserId};
     use serde_test::Token;
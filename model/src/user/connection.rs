@@ -1,15 +1,17 @@
 use crate::{guild::GuildIntegration, id::IntegrationId, user::ConnectionVisibility};
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct Connection {
     pub friend_sync: bool,
     pub id: String,
-    #[serde(with = "serde_mappable_seq", default)]
+    #[cfg_attr(feature = "serde-support", serde(with = "serde_mappable_seq", default))]
     pub integrations: HashMap<IntegrationId, GuildIntegration>,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "type"))]
     pub kind: String,
     pub name: String,
     pub revoked: Option<bool>,
@@ -18,7 +20,7 @@ pub struct Connection {
     pub visibility: ConnectionVisibility,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{Connection, ConnectionVisibility};
     use serde_test::Token;
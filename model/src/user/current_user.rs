@@ -1,8 +1,10 @@
 use super::{PremiumType, UserFlags};
 use crate::id::UserId;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Default, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Default, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct CurrentUser {
     /// User's avatar hash.
     ///
@@ -12,7 +14,7 @@ pub struct CurrentUser {
     /// [Discord's documentation]: https://discord.com/developers/docs/reference#image-formatting
     pub avatar: Option<String>,
     /// Whether the user belongs to an OAuth2 application.
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub bot: bool,
     /// Discriminator used to differentiate people with the same username.
     ///
@@ -21,7 +23,7 @@ pub struct CurrentUser {
     /// The discriminator field can be deserialized from either a string or an
     /// integer. The field will always serialize into a string due to that being
     /// the type Discord's API uses.
-    #[serde(with = "super::discriminator")]
+    #[cfg_attr(feature = "serde-support", serde(with = "super::discriminator"))]
     pub discriminator: String,
     /// User's email address associated to the account.
     ///
@@ -39,7 +41,7 @@ pub struct CurrentUser {
     /// Whether the user has two factor enabled on their account.
     pub mfa_enabled: bool,
     /// User's username, not unique across the platform.
-    #[serde(rename = "username")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "username"))]
     pub name: String,
     /// Type of Nitro subscription on a user's account.
     pub premium_type: Option<PremiumType>,
@@ -54,7 +56,7 @@ pub struct CurrentUser {
     pub verified: Option<bool>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{CurrentUser, PremiumType, UserFlags, UserId};
     use serde_test::Token;
@@ -11,9 +11,12 @@ pub use self::{
 };
 
 use crate::id::UserId;
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde-support")]
 use serde_mappable_seq::Key;
 
+#[cfg(feature = "serde-support")]
 mod discriminator {
     use serde::{
         de::{Deserializer, Error as DeError, Visitor},
@@ -48,10 +51,11 @@ mod discriminator {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct User {
     pub avatar: Option<String>,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub bot: bool,
     /// Discriminator used to differentiate people with the same username.
     ///
@@ -60,14 +64,14 @@ pub struct User {
     /// The discriminator field can be deserialized from either a string or an
     /// integer. The field will always serialize into a string due to that being
     /// the type Discord's API uses.
-    #[serde(with = "discriminator")]
+    #[cfg_attr(feature = "serde-support", serde(with = "discriminator"))]
     pub discriminator: String,
     pub email: Option<String>,
     pub flags: Option<UserFlags>,
     pub id: UserId,
     pub locale: Option<String>,
     pub mfa_enabled: Option<bool>,
-    #[serde(rename = "username")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "username"))]
     pub name: String,
     pub premium_type: Option<PremiumType>,
     pub public_flags: Option<UserFlags>,
@@ -75,13 +79,14 @@ pub struct User {
     pub verified: Option<bool>,
 }
 
+#[cfg(feature = "serde-support")]
 impl Key<'_, UserId> for User {
     fn key(&self) -> UserId {
         self.id
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{PremiumType, User, UserFlags, UserId};
     use serde_test::Token;
@@ -2,12 +2,14 @@ use crate::{
     id::UserId,
     user::{PremiumType, UserFlags},
 };
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct UserProfile {
     pub avatar: Option<String>,
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub bot: bool,
     /// Discriminator used to differentiate people with the same username.
     ///
@@ -16,20 +18,20 @@ pub struct UserProfile {
     /// The discriminator field can be deserialized from either a string or an
     /// integer. The field will always serialize into a string due to that being
     /// the type Discord's API uses.
-    #[serde(with = "super::discriminator")]
+    #[cfg_attr(feature = "serde-support", serde(with = "super::discriminator"))]
     pub discriminator: String,
     pub email: Option<String>,
     pub flags: Option<UserFlags>,
     pub id: UserId,
     pub locale: Option<String>,
     pub mfa_enabled: Option<bool>,
-    #[serde(rename = "username")]
+    #[cfg_attr(feature = "serde-support", serde(rename = "username"))]
     pub name: String,
     pub premium_type: Option<PremiumType>,
     pub verified: Option<bool>,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{PremiumType, UserFlags, UserId, UserProfile};
     use serde_test::Token;
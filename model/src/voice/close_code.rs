@@ -1,3 +1,4 @@
+#[cfg(feature = "serde-support")]
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use std::convert::TryFrom;
 use std::{
@@ -6,9 +7,8 @@ use std::{
 };
 
 /// Voice gateway close event codes.
-#[derive(
-    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize_repr,
-)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize_repr, Serialize_repr))]
 #[non_exhaustive]
 #[repr(u16)]
 pub enum CloseCode {
@@ -85,7 +85,7 @@ impl TryFrom<u16> for CloseCode {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::CloseCode;
     use serde_test::Token;
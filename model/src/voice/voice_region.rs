@@ -1,7 +1,9 @@
+#[cfg(feature = "serde-support")]
 use serde::{Deserialize, Serialize};
 
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize, Serialize))]
 pub struct VoiceRegion {
     pub custom: bool,
     pub deprecated: bool,
@@ -11,7 +13,7 @@ pub struct VoiceRegion {
     pub vip: bool,
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::VoiceRegion;
     use serde_test::Token;
@@ -1,21 +1,27 @@
+#[cfg(feature = "serde-support")]
+use crate::guild::member::OptionalMemberDeserializer;
 use crate::{
-    guild::member::{Member, OptionalMemberDeserializer},
+    guild::member::Member,
     id::{ChannelId, GuildId, UserId},
 };
+#[cfg(feature = "serde-support")]
 use serde::{
     de::{
         DeserializeSeed, Deserializer, Error as DeError, IgnoredAny, MapAccess, SeqAccess, Visitor,
     },
     Deserialize, Serialize,
 };
+#[cfg(feature = "serde-support")]
 use serde_mappable_seq::Key;
+#[cfg(feature = "serde-support")]
 use std::{
     collections::HashMap,
     fmt::{Formatter, Result as FmtResult},
 };
 
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde-support", derive(Serialize))]
 pub struct VoiceState {
     pub channel_id: Option<ChannelId>,
     pub deaf: bool,
@@ -25,7 +31,7 @@ pub struct VoiceState {
     pub self_deaf: bool,
     pub self_mute: bool,
     /// Whether this user is streaming via "Go Live".
-    #[serde(default)]
+    #[cfg_attr(feature = "serde-support", serde(default))]
     pub self_stream: bool,
     pub session_id: String,
     pub suppress: bool,
@@ -33,14 +39,20 @@ pub struct VoiceState {
     pub user_id: UserId,
 }
 
+#[cfg(feature = "serde-support")]
 impl Key<'_, UserId> for VoiceState {
     fn key(&self) -> UserId {
         self.user_id
     }
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(field_identifier, rename_all = "snake_case")]
+#[cfg(feature = "serde-support")]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde-support", derive(Deserialize))]
+#[cfg_attr(
+    feature = "serde-support",
+    serde(field_identifier, rename_all = "snake_case")
+)]
 enum Field {
     ChannelId,
     Deaf,
@@ -56,8 +68,10 @@ enum Field {
     UserId,
 }
 
+#[cfg(feature = "serde-support")]
 struct VoiceStateVisitor;
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for VoiceStateVisitor {
     type Value = VoiceState;
 
@@ -238,6 +252,7 @@ impl<'de> Visitor<'de> for VoiceStateVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> Deserialize<'de> for VoiceState {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         const FIELDS: &[&str] = &[
@@ -259,11 +274,14 @@ impl<'de> Deserialize<'de> for VoiceState {
     }
 }
 
+#[cfg(feature = "serde-support")]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct VoiceStateMapDeserializer;
 
+#[cfg(feature = "serde-support")]
 struct VoiceStateMapVisitor;
 
+#[cfg(feature = "serde-support")]
 impl<'de> Visitor<'de> for VoiceStateMapVisitor {
     type Value = HashMap<UserId, VoiceState>;
 
@@ -284,6 +302,7 @@ impl<'de> Visitor<'de> for VoiceStateMapVisitor {
     }
 }
 
+#[cfg(feature = "serde-support")]
 impl<'de> DeserializeSeed<'de> for VoiceStateMapDeserializer {
     type Value = HashMap<UserId, VoiceState>;
 
@@ -292,7 +311,7 @@ impl<'de> DeserializeSeed<'de> for VoiceStateMapDeserializer {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "serde-support"))]
 mod tests {
     use super::{ChannelId, GuildId, UserId, VoiceState};
     use serde_test::Token;
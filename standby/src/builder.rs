@@ -0,0 +1,77 @@
+use super::{config::Config, Standby};
+
+/// Builder to configure and construct a [`Standby`].
+///
+/// [`Standby`]: struct.Standby.html
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StandbyBuilder(Config);
+
+impl StandbyBuilder {
+    /// Creates a builder to configure and construct a [`Standby`].
+    ///
+    /// [`Standby`]: struct.Standby.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consume the builder, returning a configured `Standby`.
+    pub fn build(self) -> Standby {
+        Standby::new_with_config(self.0)
+    }
+
+    /// Sets the maximum number of waiters that may be registered per guild,
+    /// channel, message, or custom ID, and in total for waiters that
+    /// aren't keyed on any of them, such as those created by
+    /// [`Standby::wait_for_event`].
+    ///
+    /// When the limit is reached, waiters whose futures or streams were
+    /// dropped are removed first. If the limit is still reached then the new
+    /// waiter isn't registered: its future resolves with a `Canceled` error
+    /// and its stream ends immediately.
+    ///
+    /// Defaults to no limit.
+    ///
+    /// [`Standby::wait_for_event`]: struct.Standby.html#method.wait_for_event
+    pub fn bystander_limit(mut self, bystander_limit: usize) -> Self {
+        self.0.bystander_limit.replace(bystander_limit);
+
+        self
+    }
+
+    /// Sets the number of processed events after which waiters whose futures
+    /// or streams were dropped are removed, as if by calling
+    /// [`Standby::sweep`].
+    ///
+    /// Dropped waiters are otherwise only removed when an event they could
+    /// match is processed, so waiters on a channel that becomes inactive are
+    /// kept forever. A value of 0 disables sweeping.
+    ///
+    /// Defaults to never sweeping.
+    ///
+    /// [`Standby::sweep`]: struct.Standby.html#method.sweep
+    pub fn sweep_interval(mut self, events: u64) -> Self {
+        self.0.sweep_interval = Some(events).filter(|events| *events > 0);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StandbyBuilder;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(StandbyBuilder: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_build() {
+        let standby = StandbyBuilder::new()
+            .bystander_limit(10)
+            .sweep_interval(0)
+            .build();
+
+        assert_eq!(Some(10), standby.config().bystander_limit());
+        assert!(standby.config().sweep_interval().is_none());
+    }
+}
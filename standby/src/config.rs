@@ -0,0 +1,39 @@
+/// Configuration for a [`Standby`] instance.
+///
+/// [`Standby`]: struct.Standby.html
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Config {
+    pub(super) bystander_limit: Option<usize>,
+    pub(super) sweep_interval: Option<u64>,
+}
+
+impl Config {
+    /// Maximum number of waiters that may be registered per guild, channel,
+    /// message, or custom ID, and in total for waiters not keyed on any of
+    /// them.
+    pub fn bystander_limit(&self) -> Option<usize> {
+        self.bystander_limit
+    }
+
+    /// Number of processed events after which waiters whose futures or
+    /// streams were dropped are removed.
+    pub fn sweep_interval(&self) -> Option<u64> {
+        self.sweep_interval
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(Config: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_defaults() {
+        let conf = Config::default();
+        assert!(conf.bystander_limit().is_none());
+        assert!(conf.sweep_interval().is_none());
+    }
+}
@@ -7,15 +7,186 @@ use futures_util::{
     stream::{Stream, StreamExt},
 };
 use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
     future::Future,
+    mem,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
-use twilight_model::gateway::{
-    event::Event,
-    payload::{MessageCreate, ReactionAdd},
+use tokio::time::Delay;
+use twilight_model::{
+    application::interaction::{MessageComponentInteraction, ModalSubmitInteraction},
+    gateway::{
+        event::Event,
+        payload::{MessageCreate, ReactionAdd},
+    },
+    guild::Member,
+    id::UserId,
 };
 
+/// Waiting for an event with a timeout failed.
+///
+/// This is returned by the futures of the `_with_timeout` methods of
+/// [`Standby`], such as [`Standby::wait_for_message_with_timeout`].
+///
+/// [`Standby`]: struct.Standby.html
+/// [`Standby::wait_for_message_with_timeout`]: struct.Standby.html#method.wait_for_message_with_timeout
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WaitForTimeoutError {
+    /// The `Standby` instance the waiter was registered with was dropped.
+    Canceled {
+        /// Reason for the error.
+        source: Canceled,
+    },
+    /// No matching event was processed before the timeout elapsed.
+    ///
+    /// The waiter has been deregistered from the `Standby` instance.
+    TimedOut {
+        /// Duration that was waited for.
+        timeout: Duration,
+    },
+}
+
+impl Display for WaitForTimeoutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Canceled { .. } => f.write_str("the standby instance was dropped"),
+            Self::TimedOut { timeout } => f.write_fmt(format_args!(
+                "no matching event was received within {:?}",
+                timeout
+            )),
+        }
+    }
+}
+
+impl Error for WaitForTimeoutError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Canceled { source } => Some(source),
+            Self::TimedOut { .. } => None,
+        }
+    }
+}
+
+/// Function removing a bystander from its `Standby` instance.
+pub(crate) struct Deregister(pub(crate) Box<dyn FnOnce() + Send + Sync>);
+
+impl Debug for Deregister {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("Deregister")
+            .field(&"deregister func")
+            .finish()
+    }
+}
+
+/// Receiver racing against a timeout, deregistering its bystander when the
+/// timeout elapses or it's dropped before being fulfilled.
+#[derive(Debug)]
+pub(crate) struct Timeout<T> {
+    pub(crate) delay: Delay,
+    pub(crate) deregister: Option<Deregister>,
+    pub(crate) rx: Receiver<T>,
+    pub(crate) timeout: Duration,
+}
+
+impl<T> Timeout<T> {
+    /// Create a new timeout over a receiver.
+    ///
+    /// This must be called within the context of a Tokio runtime.
+    pub(crate) fn new(
+        rx: Receiver<T>,
+        timeout: Duration,
+        deregister: impl FnOnce() + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            delay: tokio::time::delay_for(timeout),
+            deregister: Some(Deregister(Box::new(deregister))),
+            rx,
+            timeout,
+        }
+    }
+
+    fn deregister(&mut self) {
+        if let Some(Deregister(deregister)) = self.deregister.take() {
+            deregister();
+        }
+    }
+}
+
+impl<T> Future for Timeout<T> {
+    type Output = Result<T, WaitForTimeoutError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Poll::Ready(res) = self.rx.poll_unpin(cx) {
+            // The bystander was already removed when it was fulfilled or the
+            // standby instance was dropped, so there's nothing to deregister.
+            self.deregister.take();
+
+            return Poll::Ready(res.map_err(|source| WaitForTimeoutError::Canceled { source }));
+        }
+
+        if self.delay.poll_unpin(cx).is_ready() {
+            self.deregister();
+
+            return Poll::Ready(Err(WaitForTimeoutError::TimedOut {
+                timeout: self.timeout,
+            }));
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for Timeout<T> {
+    fn drop(&mut self) {
+        self.deregister();
+    }
+}
+
+/// The future returned from [`Standby::wait_for_chunks`].
+///
+/// [`Standby::wait_for_chunks`]: struct.Standby.html#method.wait_for_chunks
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForChunksFuture {
+    pub(crate) members: HashMap<UserId, Member>,
+    pub(crate) received: HashSet<u32>,
+    pub(crate) rx: MpscReceiver<Event>,
+}
+
+impl Future for WaitForChunksFuture {
+    type Output = Result<HashMap<UserId, Member>, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            let chunk = match self.rx.poll_next_unpin(cx) {
+                Poll::Ready(Some(Event::MemberChunk(chunk))) => chunk,
+                Poll::Ready(Some(_)) => continue,
+                Poll::Ready(None) => return Poll::Ready(Err(Canceled)),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            // Chunks may be duplicated if the request was resent, so only
+            // count each index once.
+            if self.received.insert(chunk.chunk_index) {
+                self.members.extend(chunk.members);
+            }
+
+            if self.received.len() >= chunk.chunk_count as usize {
+                // Close the receiver so that the bystander is removed the
+                // next time an event in the guild is processed.
+                self.rx.close();
+
+                return Poll::Ready(Ok(mem::take(&mut self.members)));
+            }
+        }
+    }
+}
+
 /// The future returned from [`Standby::wait_for_event`].
 ///
 /// [`Standby::wait_for_event`]: struct.Standby.html#method.wait_for_event
@@ -135,6 +306,40 @@ impl Future for WaitForReactionFuture {
     }
 }
 
+/// The future returned from [`Standby::wait_for_modal`].
+///
+/// [`Standby::wait_for_modal`]: struct.Standby.html#method.wait_for_modal
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForModalFuture {
+    pub(crate) rx: Receiver<ModalSubmitInteraction>,
+}
+
+impl Future for WaitForModalFuture {
+    type Output = Result<ModalSubmitInteraction, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.rx.poll_unpin(cx)
+    }
+}
+
+/// The stream returned from [`Standby::wait_for_modal_stream`].
+///
+/// [`Standby::wait_for_modal_stream`]: struct.Standby.html#method.wait_for_modal_stream
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct WaitForModalStream {
+    pub(crate) rx: MpscReceiver<ModalSubmitInteraction>,
+}
+
+impl Stream for WaitForModalStream {
+    type Item = ModalSubmitInteraction;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
 /// The stream returned from [`Standby::wait_for_reaction_stream`].
 ///
 /// [`Standby::wait_for_reaction_stream`]: struct.Standby.html#method.wait_for_reaction_stream
@@ -152,22 +357,175 @@ impl Stream for WaitForReactionStream {
     }
 }
 
+/// The future returned from [`Standby::wait_for_component`].
+///
+/// [`Standby::wait_for_component`]: struct.Standby.html#method.wait_for_component
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForComponentFuture {
+    pub(crate) rx: Receiver<MessageComponentInteraction>,
+}
+
+impl Future for WaitForComponentFuture {
+    type Output = Result<MessageComponentInteraction, Canceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.rx.poll_unpin(cx)
+    }
+}
+
+/// The stream returned from [`Standby::wait_for_component_stream`].
+///
+/// [`Standby::wait_for_component_stream`]: struct.Standby.html#method.wait_for_component_stream
+#[derive(Debug)]
+#[must_use = "streams do nothing unless you poll them"]
+pub struct WaitForComponentStream {
+    pub(crate) rx: MpscReceiver<MessageComponentInteraction>,
+}
+
+impl Stream for WaitForComponentStream {
+    type Item = MessageComponentInteraction;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+/// The future returned from [`Standby::wait_for_event_with_timeout`].
+///
+/// [`Standby::wait_for_event_with_timeout`]: struct.Standby.html#method.wait_for_event_with_timeout
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForEventTimeoutFuture {
+    pub(crate) inner: Timeout<Event>,
+}
+
+impl Future for WaitForEventTimeoutFuture {
+    type Output = Result<Event, WaitForTimeoutError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.poll_unpin(cx)
+    }
+}
+
+/// The future returned from [`Standby::wait_for_with_timeout`].
+///
+/// [`Standby::wait_for_with_timeout`]: struct.Standby.html#method.wait_for_with_timeout
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForGuildEventTimeoutFuture {
+    pub(crate) inner: Timeout<Event>,
+}
+
+impl Future for WaitForGuildEventTimeoutFuture {
+    type Output = Result<Event, WaitForTimeoutError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.poll_unpin(cx)
+    }
+}
+
+/// The future returned from [`Standby::wait_for_message_with_timeout`].
+///
+/// [`Standby::wait_for_message_with_timeout`]: struct.Standby.html#method.wait_for_message_with_timeout
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForMessageTimeoutFuture {
+    pub(crate) inner: Timeout<MessageCreate>,
+}
+
+impl Future for WaitForMessageTimeoutFuture {
+    type Output = Result<MessageCreate, WaitForTimeoutError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.poll_unpin(cx)
+    }
+}
+
+/// The future returned from [`Standby::wait_for_modal_with_timeout`].
+///
+/// [`Standby::wait_for_modal_with_timeout`]: struct.Standby.html#method.wait_for_modal_with_timeout
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForModalTimeoutFuture {
+    pub(crate) inner: Timeout<ModalSubmitInteraction>,
+}
+
+impl Future for WaitForModalTimeoutFuture {
+    type Output = Result<ModalSubmitInteraction, WaitForTimeoutError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.poll_unpin(cx)
+    }
+}
+
+/// The future returned from [`Standby::wait_for_reaction_with_timeout`].
+///
+/// [`Standby::wait_for_reaction_with_timeout`]: struct.Standby.html#method.wait_for_reaction_with_timeout
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForReactionTimeoutFuture {
+    pub(crate) inner: Timeout<ReactionAdd>,
+}
+
+impl Future for WaitForReactionTimeoutFuture {
+    type Output = Result<ReactionAdd, WaitForTimeoutError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.poll_unpin(cx)
+    }
+}
+
+/// The future returned from [`Standby::wait_for_component_with_timeout`].
+///
+/// [`Standby::wait_for_component_with_timeout`]: struct.Standby.html#method.wait_for_component_with_timeout
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForComponentTimeoutFuture {
+    pub(crate) inner: Timeout<MessageComponentInteraction>,
+}
+
+impl Future for WaitForComponentTimeoutFuture {
+    type Output = Result<MessageComponentInteraction, WaitForTimeoutError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.poll_unpin(cx)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        WaitForEventFuture, WaitForEventStream, WaitForGuildEventFuture, WaitForGuildEventStream,
-        WaitForMessageFuture, WaitForMessageStream, WaitForReactionFuture, WaitForReactionStream,
+        WaitForChunksFuture, WaitForComponentFuture, WaitForComponentStream,
+        WaitForComponentTimeoutFuture, WaitForEventFuture, WaitForEventStream,
+        WaitForEventTimeoutFuture, WaitForGuildEventFuture, WaitForGuildEventStream,
+        WaitForGuildEventTimeoutFuture, WaitForMessageFuture, WaitForMessageStream,
+        WaitForMessageTimeoutFuture, WaitForModalFuture, WaitForModalStream,
+        WaitForModalTimeoutFuture, WaitForReactionFuture, WaitForReactionStream,
+        WaitForReactionTimeoutFuture, WaitForTimeoutError,
     };
     use futures_util::stream::Stream;
     use static_assertions::assert_impl_all;
-    use std::{fmt::Debug, future::Future};
+    use std::{error::Error, fmt::Debug, future::Future};
 
+    assert_impl_all!(WaitForTimeoutError: Debug, Error, Send, Sync);
+    assert_impl_all!(WaitForComponentTimeoutFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForEventTimeoutFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForGuildEventTimeoutFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForMessageTimeoutFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForModalTimeoutFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForReactionTimeoutFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForChunksFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForComponentFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForEventFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForGuildEventFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForMessageFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForModalFuture: Debug, Future, Send, Sync);
     assert_impl_all!(WaitForReactionFuture: Debug, Future, Send, Sync);
+    assert_impl_all!(WaitForComponentStream: Debug, Stream, Send, Sync);
     assert_impl_all!(WaitForEventStream: Debug, Stream, Send, Sync);
     assert_impl_all!(WaitForGuildEventStream: Debug, Stream, Send, Sync);
     assert_impl_all!(WaitForMessageStream: Debug, Stream, Send, Sync);
+    assert_impl_all!(WaitForModalStream: Debug, Stream, Send, Sync);
     assert_impl_all!(WaitForReactionStream: Debug, Stream, Send, Sync);
 }
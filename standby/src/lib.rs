@@ -12,9 +12,11 @@
 //! Standby allows you to wait for things like an event in a certain guild
 //! ([`Standby::wait_for`]), a new message in a channel
 //! ([`Standby::wait_for_message`]), a new reaction on a message
-//! ([`Standby::wait_for_reaction`]), and any event that might not take place in
-//! a guild, such as a new `Ready` event ([`Standby::wait_for_event`]). Each
-//! method also has a stream variant.
+//! ([`Standby::wait_for_reaction`]), a component such as a button on a message
+//! being used ([`Standby::wait_for_component`] or
+//! [`Standby::wait_for_component_by_custom_id`]), and any event that might not
+//! take place in a guild, such as a new `Ready` event
+//! ([`Standby::wait_for_event`]). Each method also has a stream variant.
 //!
 //! To use Standby, you must process events with it in your main event loop.
 //! Check out the [`Standby::process`] method.
@@ -48,7 +50,7 @@
 //! let standby = Standby::new();
 //!
 //! let message = standby.wait_for_message(ChannelId(123), |event: &MessageCreate| {
-//!     event.author.id == UserId(456) && event.content == "test"
+//!     event.author.id == UserId(456) && &*event.content == "test"
 //! }).await?;
 //! # Ok(()) }
 //! ```
@@ -85,7 +87,7 @@
 //!         standby.process(&event);
 //!
 //!         match event {
-//!             Event::MessageCreate(msg) if msg.content == "!react" => {
+//!             Event::MessageCreate(msg) if &*msg.content == "!react" => {
 //!                 tokio::spawn(react(msg.0, standby.clone()));
 //!             },
 //!             _ => {},
@@ -115,6 +117,8 @@
 //! [`Standby`]: struct.Standby.html
 //! [`Standby::process`]: struct.Standby.html#method.process
 //! [`Standby::wait_for`]: struct.Standby.html#method.wait_for
+//! [`Standby::wait_for_component`]: struct.Standby.html#method.wait_for_component
+//! [`Standby::wait_for_component_by_custom_id`]: struct.Standby.html#method.wait_for_component_by_custom_id
 //! [`Standby::wait_for_event`]: struct.Standby.html#method.wait_for_event
 //! [`Standby::wait_for_message`]: struct.Standby.html#method.wait_for_message
 //! [`Standby::wait_for_message_stream`]: struct.Standby.html#method.wait_for_message_stream
@@ -127,29 +131,43 @@
 //! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
 //! [rust badge]: https://img.shields.io/badge/rust-stable-93450a.svg?style=for-the-badge&logo=rust
 
+mod builder;
+mod config;
 mod futures;
+mod stats;
 
+pub use self::{builder::StandbyBuilder, config::Config, stats::Stats};
 pub use futures::{
-    WaitForEventFuture, WaitForEventStream, WaitForGuildEventFuture, WaitForGuildEventStream,
-    WaitForMessageFuture, WaitForMessageStream, WaitForReactionFuture, WaitForReactionStream,
+    WaitForChunksFuture, WaitForComponentFuture, WaitForComponentStream,
+    WaitForComponentTimeoutFuture, WaitForEventFuture, WaitForEventStream,
+    WaitForEventTimeoutFuture, WaitForGuildEventFuture, WaitForGuildEventStream,
+    WaitForGuildEventTimeoutFuture, WaitForMessageFuture, WaitForMessageStream,
+    WaitForMessageTimeoutFuture, WaitForModalFuture, WaitForModalStream, WaitForModalTimeoutFuture,
+    WaitForReactionFuture, WaitForReactionStream, WaitForReactionTimeoutFuture,
+    WaitForTimeoutError,
 };
 
+use self::futures::Timeout;
 use dashmap::DashMap;
 use futures_channel::{
     mpsc::{self, UnboundedSender as MpscSender},
     oneshot::{self, Sender as OneshotSender},
 };
 use std::{
+    collections::{HashMap, HashSet},
     fmt::{Debug, Formatter, Result as FmtResult},
+    hash::Hash,
     sync::{
         atomic::{AtomicU64, Ordering},
         Arc,
     },
+    time::Duration,
 };
 use twilight_model::{
+    application::interaction::{Interaction, MessageComponentInteraction, ModalSubmitInteraction},
     channel::Channel,
     gateway::{
-        event::Event,
+        event::{Event, EventType},
         payload::{MessageCreate, ReactionAdd},
     },
     id::{ChannelId, GuildId, MessageId},
@@ -171,6 +189,7 @@ impl<E> Sender<E> {
 
 struct Bystander<E> {
     func: Box<dyn Fn(&E) -> bool + Send + Sync>,
+    id: u64,
     sender: Option<Sender<E>>,
 }
 
@@ -178,6 +197,7 @@ impl<E> Debug for Bystander<E> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("Bystander")
             .field("check", &"check func")
+            .field("id", &self.id)
             .field("sender", &"mpsc sender")
             .finish()
     }
@@ -185,10 +205,17 @@ impl<E> Debug for Bystander<E> {
 
 #[derive(Debug, Default)]
 struct StandbyRef {
+    bystander_counter: AtomicU64,
+    config: Config,
+    components: DashMap<MessageId, Vec<Bystander<MessageComponentInteraction>>>,
+    components_by_custom_id: DashMap<String, Vec<Bystander<MessageComponentInteraction>>>,
     events: DashMap<u64, Bystander<Event>>,
-    event_counter: AtomicU64,
+    events_processed: AtomicU64,
     guilds: DashMap<GuildId, Vec<Bystander<Event>>>,
+    matched: AtomicU64,
     messages: DashMap<ChannelId, Vec<Bystander<MessageCreate>>>,
+    modals: DashMap<String, Vec<Bystander<ModalSubmitInteraction>>>,
+    processed_since_sweep: AtomicU64,
     reactions: DashMap<MessageId, Vec<Bystander<ReactionAdd>>>,
 }
 
@@ -201,10 +228,99 @@ pub struct Standby(Arc<StandbyRef>);
 
 impl Standby {
     /// Create a new instance of `Standby`.
+    ///
+    /// Use [`builder`] to configure limits on the number of waiters.
+    ///
+    /// [`builder`]: #method.builder
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Create a builder to configure a new instance of `Standby`.
+    pub fn builder() -> StandbyBuilder {
+        StandbyBuilder::new()
+    }
+
+    fn new_with_config(config: Config) -> Self {
+        Self(Arc::new(StandbyRef {
+            config,
+            ..StandbyRef::default()
+        }))
+    }
+
+    /// Returns a copy of the config of the instance.
+    pub fn config(&self) -> Config {
+        self.0.config.clone()
+    }
+
+    /// Returns a snapshot of the counters of the instance.
+    ///
+    /// The number of registered waiters includes waiters whose futures or
+    /// streams were dropped but that haven't been removed yet; refer to
+    /// [`sweep`] for more information. Counting them is an O(n) operation over
+    /// the registered keys, so avoid calling this very frequently.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_model::{gateway::payload::MessageCreate, id::ChannelId};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    /// let _message = standby.wait_for_message(ChannelId(1), |_: &MessageCreate| true);
+    ///
+    /// let stats = standby.stats();
+    /// assert_eq!(1, stats.messages());
+    /// assert_eq!(0, stats.events_processed());
+    /// ```
+    ///
+    /// [`sweep`]: #method.sweep
+    pub fn stats(&self) -> Stats {
+        Stats {
+            components: count_map(&self.0.components) + count_map(&self.0.components_by_custom_id),
+            events: self.0.events.len(),
+            events_processed: self.0.events_processed.load(Ordering::Relaxed),
+            guilds: count_map(&self.0.guilds),
+            matched: self.0.matched.load(Ordering::Relaxed),
+            messages: count_map(&self.0.messages),
+            modals: count_map(&self.0.modals),
+            reactions: count_map(&self.0.reactions),
+        }
+    }
+
+    /// Remove all waiters whose futures or streams were dropped, returning
+    /// the number of waiters removed.
+    ///
+    /// Dropped waiters are otherwise only removed when an event they could
+    /// match is processed. This can be called periodically by configuring
+    /// [`StandbyBuilder::sweep_interval`].
+    ///
+    /// [`StandbyBuilder::sweep_interval`]: struct.StandbyBuilder.html#method.sweep_interval
+    pub fn sweep(&self) -> usize {
+        let mut removed = 0;
+
+        self.0.events.retain(|_, bystander| {
+            let open = bystander_open(bystander);
+
+            if !open {
+                removed += 1;
+            }
+
+            open
+        });
+
+        removed += sweep_map(&self.0.components);
+        removed += sweep_map(&self.0.components_by_custom_id);
+        removed += sweep_map(&self.0.guilds);
+        removed += sweep_map(&self.0.messages);
+        removed += sweep_map(&self.0.modals);
+        removed += sweep_map(&self.0.reactions);
+
+        tracing::debug!(%removed, "swept dropped bystanders");
+
+        removed
+    }
+
     /// Process an event, calling any bystanders that might be waiting on it.
     ///
     /// When a bystander checks to see if an event is what it's waiting for, it
@@ -214,10 +330,21 @@ impl Standby {
     /// futures returned by methods to fulfill.
     pub fn process(&self, event: &Event) {
         tracing::trace!(event_type = ?event.kind(), ?event, "processing event");
+        self.0.events_processed.fetch_add(1, Ordering::Relaxed);
 
         match event {
             Event::MessageCreate(e) => self.process_message(e.0.channel_id, &e),
             Event::ReactionAdd(e) => self.process_reaction(e.0.message_id, &e),
+            Event::InteractionCreate(e) => match &e.0 {
+                Interaction::MessageComponent(component) => {
+                    self.process_component(component.message.id, component);
+                    self.process_component_by_custom_id(&component.data.custom_id, component);
+                }
+                Interaction::ModalSubmit(modal) => {
+                    self.process_modal(&modal.data.custom_id, modal);
+                }
+                _ => {}
+            },
             _ => {}
         }
 
@@ -226,6 +353,15 @@ impl Standby {
         }
 
         self.process_event(event);
+
+        if let Some(interval) = self.0.config.sweep_interval {
+            let processed = self.0.processed_since_sweep.fetch_add(1, Ordering::Relaxed) + 1;
+
+            if processed >= interval {
+                self.0.processed_since_sweep.store(0, Ordering::Relaxed);
+                self.sweep();
+            }
+        }
     }
 
     /// Wait for an event in a certain guild.
@@ -266,13 +402,15 @@ impl Standby {
         tracing::trace!(%guild_id, "waiting for event in guild");
         let (tx, rx) = oneshot::channel();
 
-        {
-            let mut guild = self.0.guilds.entry(guild_id).or_default();
-            guild.push(Bystander {
+        self.push_bystander(
+            &self.0.guilds,
+            guild_id,
+            Bystander {
                 func: check.into(),
+                id: self.next_bystander_id(),
                 sender: Some(Sender::Oneshot(tx)),
-            });
-        }
+            },
+        );
 
         WaitForGuildEventFuture { rx }
     }
@@ -321,17 +459,80 @@ impl Standby {
         tracing::trace!(%guild_id, "waiting for event in guild");
         let (tx, rx) = mpsc::unbounded();
 
-        {
-            let mut guild = self.0.guilds.entry(guild_id).or_default();
-            guild.push(Bystander {
+        self.push_bystander(
+            &self.0.guilds,
+            guild_id,
+            Bystander {
                 func: check.into(),
+                id: self.next_bystander_id(),
                 sender: Some(Sender::Mpsc(tx)),
-            });
-        }
+            },
+        );
 
         WaitForGuildEventStream { rx }
     }
 
+    /// Wait for all of the member chunks sent in response to a request for
+    /// the members of a guild, returning the combined members of the chunks.
+    ///
+    /// The request must have been sent with a nonce, which is used to
+    /// identify the chunks belonging to it.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// # Examples
+    ///
+    /// Request all of the members of guild 123 and wait for them to arrive:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let shard = twilight_gateway::Shard::new("token", twilight_gateway::Intents::GUILD_MEMBERS);
+    /// use twilight_model::{gateway::payload::RequestGuildMembers, id::GuildId};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let chunks = standby.wait_for_chunks(GuildId(123), "members");
+    /// let request = RequestGuildMembers::builder(GuildId(123))
+    ///     .nonce("members")
+    ///     .query("", None);
+    /// shard.command(&request).await?;
+    ///
+    /// let members = chunks.await?;
+    /// println!("guild 123 has {} members", members.len());
+    /// # Ok(()) }
+    /// ```
+    pub fn wait_for_chunks(
+        &self,
+        guild_id: GuildId,
+        nonce: impl Into<String>,
+    ) -> WaitForChunksFuture {
+        let nonce = nonce.into();
+        tracing::trace!(%guild_id, %nonce, "waiting for member chunks in guild");
+        let (tx, rx) = mpsc::unbounded();
+
+        let check = move |event: &Event| match event {
+            Event::MemberChunk(chunk) => chunk.nonce.as_deref() == Some(nonce.as_str()),
+            _ => false,
+        };
+
+        self.push_bystander(
+            &self.0.guilds,
+            guild_id,
+            Bystander {
+                func: Box::new(check),
+                id: self.next_bystander_id(),
+                sender: Some(Sender::Mpsc(tx)),
+            },
+        );
+
+        WaitForChunksFuture {
+            members: HashMap::new(),
+            received: HashSet::new(),
+            rx,
+        }
+    }
+
     /// Wait for an event not in a certain guild. This must be filtered by an
     /// event type.
     ///
@@ -371,15 +572,11 @@ impl Standby {
         tracing::trace!("waiting for event");
         let (tx, rx) = oneshot::channel();
 
-        {
-            self.0.events.insert(
-                self.next_event_id(),
-                Bystander {
-                    func: check.into(),
-                    sender: Some(Sender::Oneshot(tx)),
-                },
-            );
-        }
+        self.push_event_bystander(Bystander {
+            func: check.into(),
+            id: self.next_bystander_id(),
+            sender: Some(Sender::Oneshot(tx)),
+        });
 
         WaitForEventFuture { rx }
     }
@@ -427,15 +624,131 @@ impl Standby {
         tracing::trace!("waiting for event");
         let (tx, rx) = mpsc::unbounded();
 
-        {
-            self.0.events.insert(
-                self.next_event_id(),
-                Bystander {
-                    func: check.into(),
-                    sender: Some(Sender::Mpsc(tx)),
-                },
-            );
-        }
+        self.push_event_bystander(Bystander {
+            func: check.into(),
+            id: self.next_bystander_id(),
+            sender: Some(Sender::Mpsc(tx)),
+        });
+
+        WaitForEventStream { rx }
+    }
+
+    /// Wait for an event of one of multiple event types, regardless of the
+    /// guild it's in.
+    ///
+    /// The predicate is only called with events of one of the given types, so
+    /// it doesn't need to check event types itself.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// If you need to wait for multiple events matching the given predicate,
+    /// use [`wait_for_event_types_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for either a reply or a reaction from user 456:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{
+    ///     gateway::event::{Event, EventType},
+    ///     id::UserId,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let event = standby.wait_for_event_types(
+    ///     [EventType::MessageCreate, EventType::ReactionAdd].iter().copied(),
+    ///     |event: &Event| match event {
+    ///         Event::MessageCreate(message) => message.author.id == UserId(456),
+    ///         Event::ReactionAdd(reaction) => reaction.user_id == UserId(456),
+    ///         _ => false,
+    ///     },
+    /// ).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Standby`]: struct.Standby.html
+    /// [`wait_for_event_types_stream`]: #method.wait_for_event_types_stream
+    pub fn wait_for_event_types<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+        &self,
+        event_types: impl IntoIterator<Item = EventType>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForEventFuture {
+        let event_types = event_types.into_iter().collect::<HashSet<_>>();
+        tracing::trace!(?event_types, "waiting for event of types");
+        let (tx, rx) = oneshot::channel();
+
+        let id = self.next_bystander_id();
+        self.push_event_bystander(Bystander {
+            func: event_types_check(event_types, check.into()),
+            id,
+            sender: Some(Sender::Oneshot(tx)),
+        });
+
+        WaitForEventFuture { rx }
+    }
+
+    /// Wait for a stream of events of multiple event types, regardless of the
+    /// guild they're in.
+    ///
+    /// This yields the events of each type through a single stream, so a
+    /// waiter doesn't need to be registered per event type. The predicate is
+    /// only called with events of one of the given types.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// If you need to wait for only one event matching the given predicate,
+    /// use [`wait_for_event_types`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for messages and reactions in channel 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_model::{
+    ///     gateway::event::{Event, EventType},
+    ///     id::ChannelId,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let mut events = standby.wait_for_event_types_stream(
+    ///     [EventType::MessageCreate, EventType::ReactionAdd].iter().copied(),
+    ///     |event: &Event| match event {
+    ///         Event::MessageCreate(message) => message.channel_id == ChannelId(123),
+    ///         Event::ReactionAdd(reaction) => reaction.channel_id == ChannelId(123),
+    ///         _ => false,
+    ///     },
+    /// );
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     println!("got event with type {:?}", event.kind());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Standby`]: struct.Standby.html
+    /// [`wait_for_event_types`]: #method.wait_for_event_types
+    pub fn wait_for_event_types_stream<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+        &self,
+        event_types: impl IntoIterator<Item = EventType>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForEventStream {
+        let event_types = event_types.into_iter().collect::<HashSet<_>>();
+        tracing::trace!(?event_types, "waiting for events of types");
+        let (tx, rx) = mpsc::unbounded();
+
+        let id = self.next_bystander_id();
+        self.push_event_bystander(Bystander {
+            func: event_types_check(event_types, check.into()),
+            id,
+            sender: Some(Sender::Mpsc(tx)),
+        });
 
         WaitForEventStream { rx }
     }
@@ -460,7 +773,7 @@ impl Standby {
     /// let standby = Standby::new();
     ///
     /// let message = standby.wait_for_message(ChannelId(123), |event: &MessageCreate| {
-    ///     event.author.id == UserId(456) && event.content == "test"
+    ///     event.author.id == UserId(456) && &*event.content == "test"
     /// }).await?;
     /// # Ok(()) }
     /// ```
@@ -475,13 +788,15 @@ impl Standby {
         tracing::trace!(%channel_id, "waiting for message in channel");
         let (tx, rx) = oneshot::channel();
 
-        {
-            let mut guild = self.0.messages.entry(channel_id).or_default();
-            guild.push(Bystander {
+        self.push_bystander(
+            &self.0.messages,
+            channel_id,
+            Bystander {
                 func: check.into(),
+                id: self.next_bystander_id(),
                 sender: Some(Sender::Oneshot(tx)),
-            });
-        }
+            },
+        );
 
         WaitForMessageFuture { rx }
     }
@@ -507,7 +822,7 @@ impl Standby {
     /// let standby = Standby::new();
     ///
     /// let mut messages = standby.wait_for_message_stream(ChannelId(123), |event: &MessageCreate| {
-    ///     event.author.id == UserId(456) && event.content == "test"
+    ///     event.author.id == UserId(456) && &*event.content == "test"
     /// });
     ///
     /// while let Some(message) = messages.next().await {
@@ -526,13 +841,15 @@ impl Standby {
         tracing::trace!(%channel_id, "waiting for message in channel");
         let (tx, rx) = mpsc::unbounded();
 
-        {
-            let mut guild = self.0.messages.entry(channel_id).or_default();
-            guild.push(Bystander {
+        self.push_bystander(
+            &self.0.messages,
+            channel_id,
+            Bystander {
                 func: check.into(),
+                id: self.next_bystander_id(),
                 sender: Some(Sender::Mpsc(tx)),
-            });
-        }
+            },
+        );
 
         WaitForMessageStream { rx }
     }
@@ -572,13 +889,15 @@ impl Standby {
         tracing::trace!(%message_id, "waiting for reaction on message");
         let (tx, rx) = oneshot::channel();
 
-        {
-            let mut guild = self.0.reactions.entry(message_id).or_default();
-            guild.push(Bystander {
+        self.push_bystander(
+            &self.0.reactions,
+            message_id,
+            Bystander {
                 func: check.into(),
+                id: self.next_bystander_id(),
                 sender: Some(Sender::Oneshot(tx)),
-            });
-        }
+            },
+        );
 
         WaitForReactionFuture { rx }
     }
@@ -620,25 +939,906 @@ impl Standby {
     /// [`wait_for_reaction`]: #method.wait_for_reaction
     pub fn wait_for_reaction_stream<F: Fn(&ReactionAdd) -> bool + Send + Sync + 'static>(
         &self,
-        message_id: MessageId,
-        check: impl Into<Box<F>>,
-    ) -> WaitForReactionStream {
-        tracing::trace!(%message_id, "waiting for reaction on message");
-        let (tx, rx) = mpsc::unbounded();
+        message_id: MessageId,
+        check: impl Into<Box<F>>,
+    ) -> WaitForReactionStream {
+        tracing::trace!(%message_id, "waiting for reaction on message");
+        let (tx, rx) = mpsc::unbounded();
+
+        self.push_bystander(
+            &self.0.reactions,
+            message_id,
+            Bystander {
+                func: check.into(),
+                id: self.next_bystander_id(),
+                sender: Some(Sender::Mpsc(tx)),
+            },
+        );
+
+        WaitForReactionStream { rx }
+    }
+
+    /// Wait for a component on a certain message to be used, such as a button
+    /// being clicked.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// To wait for a specific component, check its custom ID in the
+    /// predicate, or use [`wait_for_component_by_custom_id`] to wait for it on
+    /// any message. If you need to wait for multiple components to be used,
+    /// use [`wait_for_component_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for the "confirm" button on message 123 to be clicked by user 456:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{
+    ///     application::interaction::MessageComponentInteraction,
+    ///     id::{MessageId, UserId},
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let component = standby.wait_for_component(
+    ///     MessageId(123),
+    ///     |event: &MessageComponentInteraction| {
+    ///         let author_id = event.member.as_ref().map(|member| member.user.id);
+    ///
+    ///         event.data.custom_id == "confirm" && author_id == Some(UserId(456))
+    ///     },
+    /// ).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Standby`]: struct.Standby.html
+    /// [`wait_for_component_by_custom_id`]: #method.wait_for_component_by_custom_id
+    /// [`wait_for_component_stream`]: #method.wait_for_component_stream
+    pub fn wait_for_component<
+        F: Fn(&MessageComponentInteraction) -> bool + Send + Sync + 'static,
+    >(
+        &self,
+        message_id: MessageId,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentFuture {
+        tracing::trace!(%message_id, "waiting for component on message");
+        let (tx, rx) = oneshot::channel();
+
+        self.push_bystander(
+            &self.0.components,
+            message_id,
+            Bystander {
+                func: check.into(),
+                id: self.next_bystander_id(),
+                sender: Some(Sender::Oneshot(tx)),
+            },
+        );
+
+        WaitForComponentFuture { rx }
+    }
+
+    /// Wait for a stream of components on a certain message to be used.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// If you need to wait for only one component matching the given
+    /// predicate, use [`wait_for_component`].
+    ///
+    /// # Examples
+    ///
+    /// Paginate on the "previous" and "next" buttons of message 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_model::{
+    ///     application::interaction::MessageComponentInteraction,
+    ///     id::MessageId,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let mut components = standby.wait_for_component_stream(
+    ///     MessageId(123),
+    ///     |event: &MessageComponentInteraction| {
+    ///         matches!(event.data.custom_id.as_str(), "previous" | "next")
+    ///     },
+    /// );
+    ///
+    /// while let Some(component) = components.next().await {
+    ///     println!("got a click on {}", component.data.custom_id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Standby`]: struct.Standby.html
+    /// [`wait_for_component`]: #method.wait_for_component
+    pub fn wait_for_component_stream<
+        F: Fn(&MessageComponentInteraction) -> bool + Send + Sync + 'static,
+    >(
+        &self,
+        message_id: MessageId,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentStream {
+        tracing::trace!(%message_id, "waiting for components on message");
+        let (tx, rx) = mpsc::unbounded();
+
+        self.push_bystander(
+            &self.0.components,
+            message_id,
+            Bystander {
+                func: check.into(),
+                id: self.next_bystander_id(),
+                sender: Some(Sender::Mpsc(tx)),
+            },
+        );
+
+        WaitForComponentStream { rx }
+    }
+
+    /// Wait for a component with a certain custom ID to be used on any
+    /// message.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// This is useful for components whose message isn't known, such as
+    /// buttons on an ephemeral or followup message, or buttons with the same
+    /// custom ID on many messages. As with [`wait_for_modal`], components with
+    /// the same custom ID may be used by different users at the same time, so
+    /// check the user in the predicate to scope the waiter to a user.
+    ///
+    /// If you need to wait for multiple components to be used, use
+    /// [`wait_for_component_by_custom_id_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for the "confirm" button to be clicked by user 456:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{
+    ///     application::interaction::MessageComponentInteraction,
+    ///     id::UserId,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let component = standby.wait_for_component_by_custom_id(
+    ///     "confirm",
+    ///     |event: &MessageComponentInteraction| {
+    ///         event.member.as_ref().map(|member| member.user.id) == Some(UserId(456))
+    ///     },
+    /// ).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Standby`]: struct.Standby.html
+    /// [`wait_for_component_by_custom_id_stream`]: #method.wait_for_component_by_custom_id_stream
+    /// [`wait_for_modal`]: #method.wait_for_modal
+    pub fn wait_for_component_by_custom_id<
+        F: Fn(&MessageComponentInteraction) -> bool + Send + Sync + 'static,
+    >(
+        &self,
+        custom_id: impl Into<String>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentFuture {
+        let custom_id = custom_id.into();
+        tracing::trace!(%custom_id, "waiting for component by custom id");
+        let (tx, rx) = oneshot::channel();
+
+        self.push_bystander(
+            &self.0.components_by_custom_id,
+            custom_id,
+            Bystander {
+                func: check.into(),
+                id: self.next_bystander_id(),
+                sender: Some(Sender::Oneshot(tx)),
+            },
+        );
+
+        WaitForComponentFuture { rx }
+    }
+
+    /// Wait for a stream of uses of components with a certain custom ID on
+    /// any message.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// If you need to wait for only one component matching the given
+    /// predicate, use [`wait_for_component_by_custom_id`].
+    ///
+    /// # Examples
+    ///
+    /// Count every click of the "vote" button:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_model::application::interaction::MessageComponentInteraction;
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let mut votes = standby.wait_for_component_by_custom_id_stream(
+    ///     "vote",
+    ///     |_: &MessageComponentInteraction| true,
+    /// );
+    ///
+    /// while let Some(vote) = votes.next().await {
+    ///     println!("got a vote on message {}", vote.message.id);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Standby`]: struct.Standby.html
+    /// [`wait_for_component_by_custom_id`]: #method.wait_for_component_by_custom_id
+    pub fn wait_for_component_by_custom_id_stream<
+        F: Fn(&MessageComponentInteraction) -> bool + Send + Sync + 'static,
+    >(
+        &self,
+        custom_id: impl Into<String>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentStream {
+        let custom_id = custom_id.into();
+        tracing::trace!(%custom_id, "waiting for components by custom id");
+        let (tx, rx) = mpsc::unbounded();
+
+        self.push_bystander(
+            &self.0.components_by_custom_id,
+            custom_id,
+            Bystander {
+                func: check.into(),
+                id: self.next_bystander_id(),
+                sender: Some(Sender::Mpsc(tx)),
+            },
+        );
+
+        WaitForComponentStream { rx }
+    }
+
+    /// Wait for a modal with a certain custom ID to be submitted.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// Modals with the same custom ID may be submitted by different users at
+    /// the same time, so check the [`author_id`] of the submission in the
+    /// predicate to scope the waiter to a user. Submitted values can be
+    /// retrieved by the custom IDs of their text inputs with
+    /// [`ModalInteractionData::value`].
+    ///
+    /// If you need to wait for multiple modal submissions matching the given
+    /// predicate, use [`wait_for_modal_stream`].
+    ///
+    /// # Examples
+    ///
+    /// Wait for user 456 to submit the "report" modal and get its reason:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_model::{
+    ///     application::interaction::ModalSubmitInteraction,
+    ///     id::UserId,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let modal = standby.wait_for_modal("report", |event: &ModalSubmitInteraction| {
+    ///     event.author_id() == Some(UserId(456))
+    /// }).await?;
+    ///
+    /// println!("reason: {:?}", modal.data.value("reason"));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`ModalInteractionData::value`]: ../twilight_model/application/interaction/struct.ModalInteractionData.html#method.value
+    /// [`Standby`]: struct.Standby.html
+    /// [`author_id`]: ../twilight_model/application/interaction/struct.ModalSubmitInteraction.html#method.author_id
+    /// [`wait_for_modal_stream`]: #method.wait_for_modal_stream
+    pub fn wait_for_modal<F: Fn(&ModalSubmitInteraction) -> bool + Send + Sync + 'static>(
+        &self,
+        custom_id: impl Into<String>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForModalFuture {
+        let custom_id = custom_id.into();
+        tracing::trace!(%custom_id, "waiting for modal submission");
+        let (tx, rx) = oneshot::channel();
+
+        self.push_bystander(
+            &self.0.modals,
+            custom_id,
+            Bystander {
+                func: check.into(),
+                id: self.next_bystander_id(),
+                sender: Some(Sender::Oneshot(tx)),
+            },
+        );
+
+        WaitForModalFuture { rx }
+    }
+
+    /// Wait for a stream of submissions of a modal with a certain custom ID.
+    ///
+    /// Returns a `Canceled` error if the `Standby` struct was dropped.
+    ///
+    /// If you need to wait for only one submission matching the given
+    /// predicate, use [`wait_for_modal`].
+    ///
+    /// # Examples
+    ///
+    /// Collect every submission of the "feedback" modal:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_model::application::interaction::ModalSubmitInteraction;
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let mut modals = standby.wait_for_modal_stream("feedback", |_: &ModalSubmitInteraction| true);
+    ///
+    /// while let Some(modal) = modals.next().await {
+    ///     println!("got feedback from {:?}", modal.author_id());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`Standby`]: struct.Standby.html
+    /// [`wait_for_modal`]: #method.wait_for_modal
+    pub fn wait_for_modal_stream<F: Fn(&ModalSubmitInteraction) -> bool + Send + Sync + 'static>(
+        &self,
+        custom_id: impl Into<String>,
+        check: impl Into<Box<F>>,
+    ) -> WaitForModalStream {
+        let custom_id = custom_id.into();
+        tracing::trace!(%custom_id, "waiting for modal submissions");
+        let (tx, rx) = mpsc::unbounded();
+
+        self.push_bystander(
+            &self.0.modals,
+            custom_id,
+            Bystander {
+                func: check.into(),
+                id: self.next_bystander_id(),
+                sender: Some(Sender::Mpsc(tx)),
+            },
+        );
+
+        WaitForModalStream { rx }
+    }
+
+    /// Wait for an event in a certain guild, failing if no matching event is
+    /// processed within the timeout.
+    ///
+    /// If the timeout elapses or the returned future is dropped, the waiter is
+    /// deregistered so it doesn't need to wait for a matching event to be
+    /// cleaned up.
+    ///
+    /// This must be called within the context of a Tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// Wait up to 30 seconds for a `BanAdd` event in guild 123:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::Duration;
+    /// use twilight_model::{
+    ///     gateway::event::{EventType, Event},
+    ///     id::GuildId,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let ban = standby.wait_for_with_timeout(GuildId(123), Duration::from_secs(30), |event: &Event| {
+    ///     event.kind() == EventType::BanAdd
+    /// }).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WaitForTimeoutError::TimedOut`] if no matching event was
+    /// processed in time.
+    ///
+    /// [`WaitForTimeoutError::TimedOut`]: enum.WaitForTimeoutError.html#variant.TimedOut
+    pub fn wait_for_with_timeout<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+        &self,
+        guild_id: GuildId,
+        timeout: Duration,
+        check: impl Into<Box<F>>,
+    ) -> WaitForGuildEventTimeoutFuture {
+        tracing::trace!(%guild_id, ?timeout, "waiting for event in guild with timeout");
+        let (tx, rx) = oneshot::channel();
+        let id = self.next_bystander_id();
+
+        self.push_bystander(
+            &self.0.guilds,
+            guild_id,
+            Bystander {
+                func: check.into(),
+                id,
+                sender: Some(Sender::Oneshot(tx)),
+            },
+        );
+
+        let standby = Arc::downgrade(&self.0);
+
+        WaitForGuildEventTimeoutFuture {
+            inner: Timeout::new(rx, timeout, move || {
+                if let Some(standby) = standby.upgrade() {
+                    remove_bystander(&standby.guilds, &guild_id, id);
+                }
+            }),
+        }
+    }
+
+    /// Wait for an event not in a certain guild, failing if no matching event
+    /// is processed within the timeout. This must be filtered by an event
+    /// type.
+    ///
+    /// If the timeout elapses or the returned future is dropped, the waiter is
+    /// deregistered so it doesn't need to wait for a matching event to be
+    /// cleaned up.
+    ///
+    /// This must be called within the context of a Tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// Wait up to 10 seconds for a `Resumed` event:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::Duration;
+    /// use twilight_model::gateway::event::{EventType, Event};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let resumed = standby.wait_for_event_with_timeout(Duration::from_secs(10), |event: &Event| {
+    ///     event.kind() == EventType::Resumed
+    /// }).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WaitForTimeoutError::TimedOut`] if no matching event was
+    /// processed in time.
+    ///
+    /// [`WaitForTimeoutError::TimedOut`]: enum.WaitForTimeoutError.html#variant.TimedOut
+    pub fn wait_for_event_with_timeout<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+        &self,
+        timeout: Duration,
+        check: impl Into<Box<F>>,
+    ) -> WaitForEventTimeoutFuture {
+        tracing::trace!(?timeout, "waiting for event with timeout");
+        let (tx, rx) = oneshot::channel();
+        let id = self.next_bystander_id();
+
+        self.push_event_bystander(Bystander {
+            func: check.into(),
+            id,
+            sender: Some(Sender::Oneshot(tx)),
+        });
+
+        let standby = Arc::downgrade(&self.0);
+
+        WaitForEventTimeoutFuture {
+            inner: Timeout::new(rx, timeout, move || {
+                if let Some(standby) = standby.upgrade() {
+                    standby.events.remove(&id);
+                }
+            }),
+        }
+    }
+
+    /// Wait for a message in a certain channel, failing if no matching message
+    /// is received within the timeout.
+    ///
+    /// If the timeout elapses or the returned future is dropped, the waiter is
+    /// deregistered so it doesn't need to wait for a matching event to be
+    /// cleaned up.
+    ///
+    /// This must be called within the context of a Tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// Wait up to a minute for a message in channel 123 by user 456:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::Duration;
+    /// use twilight_model::{gateway::payload::MessageCreate, id::{ChannelId, UserId}};
+    /// use twilight_standby::{Standby, WaitForTimeoutError};
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let future = standby.wait_for_message_with_timeout(
+    ///     ChannelId(123),
+    ///     Duration::from_secs(60),
+    ///     |event: &MessageCreate| event.author.id == UserId(456),
+    /// );
+    ///
+    /// match future.await {
+    ///     Ok(message) => println!("got message: {}", message.content),
+    ///     Err(WaitForTimeoutError::TimedOut { .. }) => println!("user didn't respond in time"),
+    ///     Err(other) => return Err(other.into()),
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WaitForTimeoutError::TimedOut`] if no matching message was
+    /// processed in time.
+    ///
+    /// [`WaitForTimeoutError::TimedOut`]: enum.WaitForTimeoutError.html#variant.TimedOut
+    pub fn wait_for_message_with_timeout<F: Fn(&MessageCreate) -> bool + Send + Sync + 'static>(
+        &self,
+        channel_id: ChannelId,
+        timeout: Duration,
+        check: impl Into<Box<F>>,
+    ) -> WaitForMessageTimeoutFuture {
+        tracing::trace!(%channel_id, ?timeout, "waiting for message in channel with timeout");
+        let (tx, rx) = oneshot::channel();
+        let id = self.next_bystander_id();
+
+        self.push_bystander(
+            &self.0.messages,
+            channel_id,
+            Bystander {
+                func: check.into(),
+                id,
+                sender: Some(Sender::Oneshot(tx)),
+            },
+        );
+
+        let standby = Arc::downgrade(&self.0);
+
+        WaitForMessageTimeoutFuture {
+            inner: Timeout::new(rx, timeout, move || {
+                if let Some(standby) = standby.upgrade() {
+                    remove_bystander(&standby.messages, &channel_id, id);
+                }
+            }),
+        }
+    }
+
+    /// Wait for a reaction on a certain message, failing if no matching
+    /// reaction is added within the timeout.
+    ///
+    /// If the timeout elapses or the returned future is dropped, the waiter is
+    /// deregistered so it doesn't need to wait for a matching event to be
+    /// cleaned up.
+    ///
+    /// This must be called within the context of a Tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// Wait up to 30 seconds for a reaction on message 123 by user 456:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::Duration;
+    /// use twilight_model::{gateway::payload::ReactionAdd, id::{MessageId, UserId}};
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let reaction = standby.wait_for_reaction_with_timeout(
+    ///     MessageId(123),
+    ///     Duration::from_secs(30),
+    ///     |event: &ReactionAdd| event.user_id == UserId(456),
+    /// ).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WaitForTimeoutError::TimedOut`] if no matching reaction was
+    /// processed in time.
+    ///
+    /// [`WaitForTimeoutError::TimedOut`]: enum.WaitForTimeoutError.html#variant.TimedOut
+    pub fn wait_for_reaction_with_timeout<F: Fn(&ReactionAdd) -> bool + Send + Sync + 'static>(
+        &self,
+        message_id: MessageId,
+        timeout: Duration,
+        check: impl Into<Box<F>>,
+    ) -> WaitForReactionTimeoutFuture {
+        tracing::trace!(%message_id, ?timeout, "waiting for reaction on message with timeout");
+        let (tx, rx) = oneshot::channel();
+        let id = self.next_bystander_id();
+
+        self.push_bystander(
+            &self.0.reactions,
+            message_id,
+            Bystander {
+                func: check.into(),
+                id,
+                sender: Some(Sender::Oneshot(tx)),
+            },
+        );
+
+        let standby = Arc::downgrade(&self.0);
+
+        WaitForReactionTimeoutFuture {
+            inner: Timeout::new(rx, timeout, move || {
+                if let Some(standby) = standby.upgrade() {
+                    remove_bystander(&standby.reactions, &message_id, id);
+                }
+            }),
+        }
+    }
+
+    /// Wait for a component on a certain message to be used, failing if no
+    /// matching component is used within the timeout.
+    ///
+    /// If the timeout elapses or the returned future is dropped, the waiter is
+    /// deregistered so it doesn't need to wait for a matching event to be
+    /// cleaned up.
+    ///
+    /// This must be called within the context of a Tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// Wait up to a minute for a button on message 123 to be clicked:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::Duration;
+    /// use twilight_model::{
+    ///     application::interaction::MessageComponentInteraction,
+    ///     id::MessageId,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let component = standby.wait_for_component_with_timeout(
+    ///     MessageId(123),
+    ///     Duration::from_secs(60),
+    ///     |event: &MessageComponentInteraction| event.data.custom_id == "confirm",
+    /// ).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WaitForTimeoutError::TimedOut`] if no matching component was
+    /// used in time.
+    ///
+    /// [`WaitForTimeoutError::TimedOut`]: enum.WaitForTimeoutError.html#variant.TimedOut
+    pub fn wait_for_component_with_timeout<
+        F: Fn(&MessageComponentInteraction) -> bool + Send + Sync + 'static,
+    >(
+        &self,
+        message_id: MessageId,
+        timeout: Duration,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentTimeoutFuture {
+        tracing::trace!(%message_id, ?timeout, "waiting for component on message with timeout");
+        let (tx, rx) = oneshot::channel();
+        let id = self.next_bystander_id();
+
+        self.push_bystander(
+            &self.0.components,
+            message_id,
+            Bystander {
+                func: check.into(),
+                id,
+                sender: Some(Sender::Oneshot(tx)),
+            },
+        );
+
+        let standby = Arc::downgrade(&self.0);
+
+        WaitForComponentTimeoutFuture {
+            inner: Timeout::new(rx, timeout, move || {
+                if let Some(standby) = standby.upgrade() {
+                    remove_bystander(&standby.components, &message_id, id);
+                }
+            }),
+        }
+    }
+
+    /// Wait for a component with a certain custom ID to be used on any
+    /// message, failing if no matching component is used within the timeout.
+    ///
+    /// If the timeout elapses or the returned future is dropped, the waiter is
+    /// deregistered so it doesn't need to wait for a matching event to be
+    /// cleaned up.
+    ///
+    /// This must be called within the context of a Tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// Wait up to 60 seconds for the "confirm" button to be clicked:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::Duration;
+    /// use twilight_model::application::interaction::MessageComponentInteraction;
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let component = standby.wait_for_component_by_custom_id_with_timeout(
+    ///     "confirm",
+    ///     Duration::from_secs(60),
+    ///     |_: &MessageComponentInteraction| true,
+    /// ).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WaitForTimeoutError::TimedOut`] if no matching component was
+    /// used in time.
+    ///
+    /// [`WaitForTimeoutError::TimedOut`]: enum.WaitForTimeoutError.html#variant.TimedOut
+    pub fn wait_for_component_by_custom_id_with_timeout<
+        F: Fn(&MessageComponentInteraction) -> bool + Send + Sync + 'static,
+    >(
+        &self,
+        custom_id: impl Into<String>,
+        timeout: Duration,
+        check: impl Into<Box<F>>,
+    ) -> WaitForComponentTimeoutFuture {
+        let custom_id = custom_id.into();
+        tracing::trace!(%custom_id, ?timeout, "waiting for component by custom id with timeout");
+        let (tx, rx) = oneshot::channel();
+        let id = self.next_bystander_id();
+
+        self.push_bystander(
+            &self.0.components_by_custom_id,
+            custom_id.clone(),
+            Bystander {
+                func: check.into(),
+                id,
+                sender: Some(Sender::Oneshot(tx)),
+            },
+        );
+
+        let standby = Arc::downgrade(&self.0);
+
+        WaitForComponentTimeoutFuture {
+            inner: Timeout::new(rx, timeout, move || {
+                if let Some(standby) = standby.upgrade() {
+                    remove_bystander(&standby.components_by_custom_id, &custom_id, id);
+                }
+            }),
+        }
+    }
+
+    /// Wait for a modal with a certain custom ID to be submitted, failing if
+    /// no matching submission is received within the timeout.
+    ///
+    /// Users may close a modal without submitting it, and Discord doesn't
+    /// send an event when they do, so form flows should generally use this
+    /// rather than [`wait_for_modal`].
+    ///
+    /// If the timeout elapses or the returned future is dropped, the waiter is
+    /// deregistered so it doesn't need to wait for a matching event to be
+    /// cleaned up.
+    ///
+    /// This must be called within the context of a Tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// Wait up to 5 minutes for user 456 to submit the "report" modal:
+    ///
+    /// ```no_run
+    /// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::time::Duration;
+    /// use twilight_model::{
+    ///     application::interaction::ModalSubmitInteraction,
+    ///     id::UserId,
+    /// };
+    /// use twilight_standby::Standby;
+    ///
+    /// let standby = Standby::new();
+    ///
+    /// let modal = standby.wait_for_modal_with_timeout(
+    ///     "report",
+    ///     Duration::from_secs(300),
+    ///     |event: &ModalSubmitInteraction| event.author_id() == Some(UserId(456)),
+    /// ).await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WaitForTimeoutError::TimedOut`] if no matching modal was
+    /// submitted in time.
+    ///
+    /// [`WaitForTimeoutError::TimedOut`]: enum.WaitForTimeoutError.html#variant.TimedOut
+    /// [`wait_for_modal`]: #method.wait_for_modal
+    pub fn wait_for_modal_with_timeout<
+        F: Fn(&ModalSubmitInteraction) -> bool + Send + Sync + 'static,
+    >(
+        &self,
+        custom_id: impl Into<String>,
+        timeout: Duration,
+        check: impl Into<Box<F>>,
+    ) -> WaitForModalTimeoutFuture {
+        let custom_id = custom_id.into();
+        tracing::trace!(%custom_id, ?timeout, "waiting for modal submission with timeout");
+        let (tx, rx) = oneshot::channel();
+        let id = self.next_bystander_id();
+
+        self.push_bystander(
+            &self.0.modals,
+            custom_id.clone(),
+            Bystander {
+                func: check.into(),
+                id,
+                sender: Some(Sender::Oneshot(tx)),
+            },
+        );
+
+        let standby = Arc::downgrade(&self.0);
+
+        WaitForModalTimeoutFuture {
+            inner: Timeout::new(rx, timeout, move || {
+                if let Some(standby) = standby.upgrade() {
+                    remove_bystander(&standby.modals, &custom_id, id);
+                }
+            }),
+        }
+    }
+
+    /// Register a bystander under a key, enforcing the configured limit.
+    fn push_bystander<K: Debug + Eq + Hash, E>(
+        &self,
+        map: &DashMap<K, Vec<Bystander<E>>>,
+        key: K,
+        bystander: Bystander<E>,
+    ) {
+        let mut bystanders = map.entry(key).or_default();
+
+        if let Some(limit) = self.0.config.bystander_limit {
+            if bystanders.len() >= limit {
+                bystanders.retain(bystander_open);
+            }
 
-        {
-            let mut guild = self.0.reactions.entry(message_id).or_default();
-            guild.push(Bystander {
-                func: check.into(),
-                sender: Some(Sender::Mpsc(tx)),
-            });
+            if bystanders.len() >= limit {
+                tracing::debug!(key = ?bystanders.key(), %limit, "bystander limit reached");
+
+                return;
+            }
         }
 
-        WaitForReactionStream { rx }
+        bystanders.push(bystander);
+    }
+
+    /// Register a bystander for any event, enforcing the configured limit.
+    fn push_event_bystander(&self, bystander: Bystander<Event>) {
+        if let Some(limit) = self.0.config.bystander_limit {
+            if self.0.events.len() >= limit {
+                self.0
+                    .events
+                    .retain(|_, bystander| bystander_open(bystander));
+            }
+
+            if self.0.events.len() >= limit {
+                tracing::debug!(%limit, "event bystander limit reached");
+
+                return;
+            }
+        }
+
+        self.0.events.insert(bystander.id, bystander);
     }
 
-    fn next_event_id(&self) -> u64 {
-        self.0.event_counter.fetch_add(1, Ordering::SeqCst)
+    fn next_bystander_id(&self) -> u64 {
+        self.0.bystander_counter.fetch_add(1, Ordering::SeqCst)
     }
 
     #[tracing::instrument(level = "trace")]
@@ -724,6 +1924,69 @@ impl Standby {
         }
     }
 
+    fn process_component(&self, message_id: MessageId, event: &MessageComponentInteraction) {
+        let remove = match self.0.components.get_mut(&message_id) {
+            Some(mut bystanders) => {
+                self.bystander_iter(&mut bystanders, event);
+
+                bystanders.is_empty()
+            }
+            None => {
+                tracing::trace!(%message_id, "message has no component bystanders");
+
+                return;
+            }
+        };
+
+        if remove {
+            tracing::trace!(%message_id, "removing message from components");
+
+            self.0.components.remove(&message_id);
+        }
+    }
+
+    fn process_component_by_custom_id(&self, custom_id: &str, event: &MessageComponentInteraction) {
+        let remove = match self.0.components_by_custom_id.get_mut(custom_id) {
+            Some(mut bystanders) => {
+                self.bystander_iter(&mut bystanders, event);
+
+                bystanders.is_empty()
+            }
+            None => {
+                tracing::trace!(%custom_id, "custom id has no component bystanders");
+
+                return;
+            }
+        };
+
+        if remove {
+            tracing::trace!(%custom_id, "removing custom id from components");
+
+            self.0.components_by_custom_id.remove(custom_id);
+        }
+    }
+
+    fn process_modal(&self, custom_id: &str, event: &ModalSubmitInteraction) {
+        let remove = match self.0.modals.get_mut(custom_id) {
+            Some(mut bystanders) => {
+                self.bystander_iter(&mut bystanders, event);
+
+                bystanders.is_empty()
+            }
+            None => {
+                tracing::trace!(%custom_id, "modal has no bystanders");
+
+                return;
+            }
+        };
+
+        if remove {
+            tracing::trace!(%custom_id, "removing modal");
+
+            self.0.modals.remove(custom_id);
+        }
+    }
+
     /// Iterate over bystanders and remove the ones that match the predicate.
     #[tracing::instrument(level = "trace")]
     fn bystander_iter<E: Clone + Debug>(&self, bystanders: &mut Vec<Bystander<E>>, event: &E) {
@@ -778,13 +2041,17 @@ impl Standby {
 
         match sender {
             Sender::Oneshot(tx) => {
-                let _ = tx.send(event.clone());
+                if tx.send(event.clone()).is_ok() {
+                    self.0.matched.fetch_add(1, Ordering::Relaxed);
+                }
+
                 tracing::trace!("bystander matched event, indicating for removal");
 
                 true
             }
             Sender::Mpsc(tx) => {
                 if tx.unbounded_send(event.clone()).is_ok() {
+                    self.0.matched.fetch_add(1, Ordering::Relaxed);
                     tracing::trace!("bystander is a stream, retaining in map");
 
                     bystander.sender.replace(Sender::Mpsc(tx));
@@ -798,8 +2065,70 @@ impl Standby {
     }
 }
 
+/// Whether a bystander's future or stream is still waiting for an event.
+fn bystander_open<E>(bystander: &Bystander<E>) -> bool {
+    matches!(&bystander.sender, Some(sender) if !sender.is_closed())
+}
+
+/// Count the bystanders of all keys in a map of bystanders.
+fn count_map<K: Eq + Hash, E>(map: &DashMap<K, Vec<Bystander<E>>>) -> usize {
+    map.iter().map(|bystanders| bystanders.len()).sum()
+}
+
+/// Remove the dropped bystanders of each key in a map of bystanders, removing
+/// keys with no remaining bystanders. Returns the number of bystanders
+/// removed.
+fn sweep_map<K: Eq + Hash, E>(map: &DashMap<K, Vec<Bystander<E>>>) -> usize {
+    let mut removed = 0;
+
+    map.retain(|_, bystanders| {
+        let len = bystanders.len();
+        bystanders.retain(bystander_open);
+        removed += len - bystanders.len();
+
+        !bystanders.is_empty()
+    });
+
+    removed
+}
+
+/// Wrap a predicate so that it's only called with events of the given types.
+fn event_types_check<F: Fn(&Event) -> bool + Send + Sync + 'static>(
+    event_types: HashSet<EventType>,
+    check: Box<F>,
+) -> Box<dyn Fn(&Event) -> bool + Send + Sync> {
+    Box::new(move |event| event_types.contains(&event.kind()) && check(event))
+}
+
+/// Remove a bystander by ID from a map of bystanders, removing the map entry
+/// if no bystanders remain.
+fn remove_bystander<K: Debug + Eq + Hash, E>(
+    map: &DashMap<K, Vec<Bystander<E>>>,
+    key: &K,
+    id: u64,
+) {
+    let remove = match map.get_mut(key) {
+        Some(mut bystanders) => {
+            bystanders.retain(|bystander| bystander.id != id);
+
+            bystanders.is_empty()
+        }
+        None => return,
+    };
+
+    if remove {
+        tracing::trace!(?key, "removing key after deregistering bystander");
+
+        map.remove_if(key, |_, bystanders| bystanders.is_empty());
+    }
+}
+
 fn event_guild_id(event: &Event) -> Option<GuildId> {
     match event {
+        Event::AutoModerationActionExecution(e) => Some(e.guild_id),
+        Event::AutoModerationRuleCreate(e) => Some(e.guild_id),
+        Event::AutoModerationRuleDelete(e) => Some(e.guild_id),
+        Event::AutoModerationRuleUpdate(e) => Some(e.guild_id),
         Event::BanAdd(e) => Some(e.guild_id),
         Event::BanRemove(e) => Some(e.guild_id),
         Event::ChannelCreate(e) => channel_guild_id(e),
@@ -816,7 +2145,13 @@ fn event_guild_id(event: &Event) -> Option<GuildId> {
         Event::GuildDelete(e) => Some(e.id),
         Event::GuildEmojisUpdate(e) => Some(e.guild_id),
         Event::GuildIntegrationsUpdate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventCreate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventDelete(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUpdate(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUserAdd(e) => Some(e.guild_id),
+        Event::GuildScheduledEventUserRemove(e) => Some(e.guild_id),
         Event::GuildUpdate(e) => Some(e.id),
+        Event::InteractionCreate(e) => e.guild_id(),
         Event::InviteCreate(e) => Some(e.guild_id),
         Event::InviteDelete(e) => Some(e.guild_id),
         Event::MemberAdd(e) => Some(e.guild_id),
@@ -845,6 +2180,15 @@ fn event_guild_id(event: &Event) -> Option<GuildId> {
         Event::ShardPayload(_) => None,
         Event::ShardReconnecting(_) => None,
         Event::ShardResuming(_) => None,
+        Event::StageInstanceCreate(e) => Some(e.guild_id),
+        Event::StageInstanceDelete(e) => Some(e.guild_id),
+        Event::StageInstanceUpdate(e) => Some(e.guild_id),
+        Event::ThreadCreate(e) => e.guild_id(),
+        Event::ThreadDelete(e) => Some(e.guild_id),
+        Event::ThreadListSync(e) => Some(e.guild_id),
+        Event::ThreadMemberUpdate(_) => None,
+        Event::ThreadMembersUpdate(e) => Some(e.guild_id),
+        Event::ThreadUpdate(e) => e.guild_id(),
         Event::TypingStart(e) => e.guild_id,
         Event::UnavailableGuild(e) => Some(e.id),
         Event::UserUpdate(_) => None,
@@ -863,20 +2207,32 @@ fn channel_guild_id(channel: &Channel) -> Option<GuildId> {
 
 #[cfg(test)]
 mod tests {
-    use super::Standby;
+    use super::{Standby, WaitForTimeoutError};
     use futures_util::StreamExt;
     use static_assertions::assert_impl_all;
-    use std::{collections::HashMap, fmt::Debug};
+    use std::{collections::HashMap, fmt::Debug, time::Duration};
     use twilight_model::{
+        application::{
+            component::ComponentType,
+            interaction::{
+                Interaction, InteractionType, MessageComponentInteraction,
+                MessageComponentInteractionData, ModalInteractionData,
+                ModalInteractionDataActionRow, ModalInteractionDataComponent,
+                ModalSubmitInteraction,
+            },
+        },
         channel::{
             message::{Message, MessageType},
             Reaction, ReactionType,
         },
         gateway::{
             event::{Event, EventType},
-            payload::{MessageCreate, ReactionAdd, Ready, RoleDelete},
+            payload::{
+                InteractionCreate, MemberChunk, MessageCreate, ReactionAdd, Ready, RoleDelete,
+            },
         },
-        id::{ChannelId, GuildId, MessageId, RoleId, UserId},
+        guild::Member,
+        id::{ApplicationId, ChannelId, GuildId, InteractionId, MessageId, RoleId, UserId},
         user::{CurrentUser, User},
     };
 
@@ -904,7 +2260,8 @@ mod tests {
                 verified: None,
             },
             channel_id: ChannelId(1),
-            content: "test".to_owned(),
+            components: Vec::new(),
+            content: "test".into(),
             edited_timestamp: None,
             embeds: Vec::new(),
             flags: None,
@@ -918,7 +2275,8 @@ mod tests {
             pinned: false,
             reactions: Vec::new(),
             reference: None,
-            timestamp: String::new(),
+            sticker_items: Vec::new(),
+            timestamp: "".into(),
             tts: false,
             webhook_id: None,
         }
@@ -937,6 +2295,60 @@ mod tests {
         }
     }
 
+    fn component(custom_id: &str) -> MessageComponentInteraction {
+        MessageComponentInteraction {
+            application_id: ApplicationId(5),
+            channel_id: ChannelId(1),
+            data: MessageComponentInteractionData {
+                component_type: ComponentType::Button,
+                custom_id: custom_id.to_owned(),
+                values: Vec::new(),
+            },
+            guild_id: Some(GuildId(4)),
+            id: InteractionId(6),
+            kind: InteractionType::MessageComponent,
+            member: None,
+            message: message(),
+            token: "token".to_owned(),
+            user: None,
+        }
+    }
+
+    fn component_event(custom_id: &str) -> Event {
+        Event::InteractionCreate(Box::new(InteractionCreate(Interaction::MessageComponent(
+            Box::new(component(custom_id)),
+        ))))
+    }
+
+    fn modal_event(user_id: UserId, reason: &str) -> Event {
+        let mut user = message().author;
+        user.id = user_id;
+
+        Event::InteractionCreate(Box::new(InteractionCreate(Interaction::ModalSubmit(
+            Box::new(ModalSubmitInteraction {
+                application_id: ApplicationId(5),
+                channel_id: ChannelId(1),
+                data: ModalInteractionData {
+                    components: vec![ModalInteractionDataActionRow {
+                        components: vec![ModalInteractionDataComponent {
+                            custom_id: "reason".to_owned(),
+                            kind: ComponentType::TextInput,
+                            value: reason.to_owned(),
+                        }],
+                    }],
+                    custom_id: "report".to_owned(),
+                },
+                guild_id: None,
+                id: InteractionId(7),
+                kind: InteractionType::ModalSubmit,
+                member: None,
+                message: None,
+                token: "token".to_owned(),
+                user: Some(user),
+            }),
+        ))))
+    }
+
     #[tokio::test]
     async fn test_wait_for() {
         let standby = Standby::new();
@@ -1047,6 +2459,49 @@ mod tests {
         assert!(standby.0.events.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_wait_for_event_types_stream() {
+        let standby = Standby::new();
+        let mut stream = standby.wait_for_event_types_stream(
+            [EventType::MessageCreate, EventType::ReactionAdd]
+                .iter()
+                .copied(),
+            |_: &Event| true,
+        );
+        standby.process(&Event::RoleDelete(RoleDelete {
+            guild_id: GuildId(1),
+            role_id: RoleId(2),
+        }));
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
+
+        assert_eq!(
+            Some(EventType::MessageCreate),
+            stream.next().await.map(|event| event.kind())
+        );
+        assert_eq!(
+            Some(EventType::ReactionAdd),
+            stream.next().await.map(|event| event.kind())
+        );
+        assert_eq!(1, standby.0.events.len());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_event_types() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_event_types(
+            [EventType::ReactionAdd, EventType::Resumed].iter().copied(),
+            |event: &Event| event.kind() != EventType::Resumed,
+        );
+        standby.process(&Event::Resumed);
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        assert_eq!(1, standby.0.events.len());
+        standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
+
+        assert!(matches!(wait.await, Ok(Event::ReactionAdd(_))));
+        assert!(standby.0.events.is_empty());
+    }
+
     #[tokio::test]
     async fn test_wait_for_message() {
         let message = message();
@@ -1107,6 +2562,198 @@ mod tests {
         assert!(standby.0.reactions.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_wait_for_component() {
+        let standby = Standby::new();
+        let wait = standby
+            .wait_for_component(MessageId(3), |event: &MessageComponentInteraction| {
+                event.data.custom_id == "confirm"
+            });
+
+        standby.process(&component_event("cancel"));
+        assert_eq!(1, standby.0.components.len());
+        standby.process(&component_event("confirm"));
+
+        assert_eq!(
+            Ok("confirm".to_owned()),
+            wait.await.map(|component| component.data.custom_id)
+        );
+        assert!(standby.0.components.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_component_stream() {
+        let standby = Standby::new();
+        let mut stream =
+            standby.wait_for_component_stream(MessageId(3), |_: &MessageComponentInteraction| true);
+        standby.process(&component_event("previous"));
+        standby.process(&component_event("next"));
+
+        assert_eq!(
+            Some("previous".to_owned()),
+            stream
+                .next()
+                .await
+                .map(|component| component.data.custom_id)
+        );
+        assert_eq!(
+            Some("next".to_owned()),
+            stream
+                .next()
+                .await
+                .map(|component| component.data.custom_id)
+        );
+        drop(stream);
+        assert_eq!(1, standby.0.components.len());
+        standby.process(&component_event("next"));
+        assert!(standby.0.components.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_component_by_custom_id() {
+        let standby = Standby::new();
+        let wait = standby
+            .wait_for_component_by_custom_id("confirm", |_: &MessageComponentInteraction| true);
+        let mut stream = standby
+            .wait_for_component_by_custom_id_stream("next", |_: &MessageComponentInteraction| true);
+
+        standby.process(&component_event("cancel"));
+        assert_eq!(2, standby.0.components_by_custom_id.len());
+        assert_eq!(2, standby.stats().components());
+        standby.process(&component_event("confirm"));
+        standby.process(&component_event("next"));
+
+        assert_eq!(
+            Ok("confirm".to_owned()),
+            wait.await.map(|component| component.data.custom_id)
+        );
+        assert_eq!(
+            Some("next".to_owned()),
+            stream
+                .next()
+                .await
+                .map(|component| component.data.custom_id)
+        );
+        drop(stream);
+        assert_eq!(1, standby.sweep());
+        assert!(standby.0.components_by_custom_id.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_component_by_custom_id_with_timeout() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_component_by_custom_id_with_timeout(
+            "confirm",
+            Duration::from_millis(10),
+            |_: &MessageComponentInteraction| true,
+        );
+
+        assert!(matches!(
+            wait.await,
+            Err(WaitForTimeoutError::TimedOut { .. })
+        ));
+        assert!(standby.0.components_by_custom_id.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_modal() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_modal("report", |event: &ModalSubmitInteraction| {
+            event.author_id() == Some(UserId(2))
+        });
+
+        standby.process(&modal_event(UserId(3), "other user"));
+        assert_eq!(1, standby.0.modals.len());
+        standby.process(&modal_event(UserId(2), "spam"));
+
+        let modal = wait.await.unwrap();
+        assert_eq!(Some("spam"), modal.data.value("reason"));
+        assert!(standby.0.modals.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_modal_with_timeout() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_modal_with_timeout(
+            "report",
+            Duration::from_millis(10),
+            |_: &ModalSubmitInteraction| true,
+        );
+
+        assert!(matches!(
+            wait.await,
+            Err(WaitForTimeoutError::TimedOut { .. })
+        ));
+        assert!(standby.0.modals.is_empty());
+    }
+
+    /// Test that reaction waiters are scoped to their message, so that a menu
+    /// on one message isn't resolved by reactions on another.
+    #[tokio::test]
+    async fn test_wait_for_reaction_scoped_to_message() {
+        let standby = Standby::new();
+        let mut other = reaction();
+        other.message_id = MessageId(5);
+
+        let mut stream = standby.wait_for_reaction_stream(MessageId(4), |_: &ReactionAdd| true);
+        standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(other))));
+        standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
+
+        assert_eq!(
+            Some(MessageId(4)),
+            stream.next().await.map(|reaction| reaction.message_id)
+        );
+        assert_eq!(1, standby.0.reactions.len());
+        assert!(standby.0.reactions.contains_key(&MessageId(4)));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_message_with_timeout() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_message_with_timeout(
+            ChannelId(1),
+            Duration::from_secs(60),
+            |message: &MessageCreate| message.author.id == UserId(2),
+        );
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+
+        assert_eq!(MessageId(3), wait.await.unwrap().id);
+        assert!(standby.0.messages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_timeout_elapses() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_reaction_with_timeout(
+            MessageId(4),
+            Duration::from_millis(10),
+            |_: &ReactionAdd| true,
+        );
+        assert_eq!(1, standby.0.reactions.len());
+
+        assert!(matches!(
+            wait.await,
+            Err(WaitForTimeoutError::TimedOut { .. })
+        ));
+        assert!(standby.0.reactions.is_empty());
+
+        let wait =
+            standby.wait_for_event_with_timeout(Duration::from_millis(10), |_: &Event| false);
+        standby.process(&Event::Resumed);
+        assert!(wait.await.is_err());
+        assert!(standby.0.events.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_timeout_dropped() {
+        let standby = Standby::new();
+        let wait =
+            standby.wait_for_with_timeout(GuildId(1), Duration::from_secs(60), |_: &Event| true);
+        assert_eq!(1, standby.0.guilds.len());
+        drop(wait);
+        assert!(standby.0.guilds.is_empty());
+    }
+
     #[tokio::test]
     async fn test_handles_wrong_events() {
         let standby = Standby::new();
@@ -1152,4 +2799,133 @@ mod tests {
         standby.process(&Event::ReactionAdd(Box::new(ReactionAdd(reaction()))));
         assert!(matches!(wait.await, Ok(Event::ReactionAdd(_))));
     }
+
+    #[tokio::test]
+    async fn test_bystander_limit() {
+        let standby = Standby::builder().bystander_limit(1).build();
+        let first = standby.wait_for_message(ChannelId(1), |_: &MessageCreate| true);
+        let second = standby.wait_for_message(ChannelId(1), |_: &MessageCreate| true);
+        // Waiters on other channels have their own limit.
+        let other = standby.wait_for_message(ChannelId(2), |_: &MessageCreate| true);
+
+        assert!(second.await.is_err());
+        assert_eq!(1, standby.0.messages.get(&ChannelId(1)).unwrap().len());
+        assert_eq!(1, standby.0.messages.get(&ChannelId(2)).unwrap().len());
+
+        // Dropped waiters are removed to make room for a new one.
+        drop(first);
+        drop(other);
+        let third = standby.wait_for_message(ChannelId(1), |_: &MessageCreate| true);
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        assert_eq!(Ok(MessageId(3)), third.await.map(|msg| msg.id));
+    }
+
+    #[tokio::test]
+    async fn test_event_bystander_limit() {
+        let standby = Standby::builder().bystander_limit(1).build();
+        let _first = standby.wait_for_event(|_: &Event| true);
+        let mut second = standby.wait_for_event_stream(|_: &Event| true);
+
+        assert!(second.next().await.is_none());
+        assert_eq!(1, standby.0.events.len());
+    }
+
+    #[test]
+    fn test_sweep() {
+        let standby = Standby::new();
+        let message = standby.wait_for_message(ChannelId(1), |_: &MessageCreate| true);
+        let reaction = standby.wait_for_reaction(MessageId(3), |_: &ReactionAdd| true);
+        let _event = standby.wait_for_event(|_: &Event| true);
+        drop(message);
+        drop(reaction);
+
+        assert_eq!(2, standby.sweep());
+        assert!(standby.0.messages.is_empty());
+        assert!(standby.0.reactions.is_empty());
+        assert_eq!(1, standby.0.events.len());
+        assert_eq!(0, standby.sweep());
+    }
+
+    #[test]
+    fn test_sweep_interval() {
+        let standby = Standby::builder().sweep_interval(2).build();
+        drop(standby.wait_for_message(ChannelId(1), |_: &MessageCreate| true));
+        let event = Event::RoleDelete(RoleDelete {
+            guild_id: GuildId(1),
+            role_id: RoleId(2),
+        });
+
+        standby.process(&event);
+        assert_eq!(1, standby.0.messages.len());
+        standby.process(&event);
+        assert!(standby.0.messages.is_empty());
+    }
+
+    fn member_chunk(index: u32, user_id: UserId, nonce: &str) -> Event {
+        let mut user = message().author;
+        user.id = user_id;
+        let member = Member {
+            deaf: false,
+            guild_id: GuildId(1),
+            hoisted_role: None,
+            joined_at: None,
+            mute: false,
+            nick: None,
+            premium_since: None,
+            roles: Vec::new(),
+            user,
+        };
+        let mut members = HashMap::new();
+        members.insert(user_id, member);
+
+        Event::MemberChunk(MemberChunk {
+            chunk_count: 2,
+            chunk_index: index,
+            guild_id: GuildId(1),
+            members,
+            nonce: Some(nonce.to_owned()),
+            not_found: Vec::new(),
+            presences: HashMap::new(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_chunks() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_chunks(GuildId(1), "test");
+        standby.process(&member_chunk(1, UserId(3), "test"));
+        // Chunks of other requests and duplicate chunks are ignored.
+        standby.process(&member_chunk(0, UserId(4), "other"));
+        standby.process(&member_chunk(1, UserId(3), "test"));
+        standby.process(&member_chunk(0, UserId(5), "test"));
+
+        let members = wait.await.unwrap();
+        assert_eq!(2, members.len());
+        assert!(members.contains_key(&UserId(3)));
+        assert!(members.contains_key(&UserId(5)));
+    }
+
+    #[tokio::test]
+    async fn test_stats() {
+        let standby = Standby::new();
+        let wait = standby.wait_for_message(ChannelId(1), |_: &MessageCreate| true);
+        let _reaction = standby.wait_for_reaction(MessageId(3), |_: &ReactionAdd| true);
+        let mut events = standby.wait_for_event_stream(|_: &Event| true);
+
+        let stats = standby.stats();
+        assert_eq!(1, stats.messages());
+        assert_eq!(1, stats.reactions());
+        assert_eq!(1, stats.events());
+        assert_eq!(3, stats.waiters());
+
+        standby.process(&Event::MessageCreate(Box::new(MessageCreate(message()))));
+        assert!(wait.await.is_ok());
+        assert!(events.next().await.is_some());
+
+        let stats = standby.stats();
+        assert_eq!(1, stats.events_processed());
+        assert_eq!(2, stats.matched());
+        assert_eq!(0, stats.messages());
+        assert_eq!(2, stats.waiters());
+    }
 }
@@ -0,0 +1,74 @@
+/// Snapshot of the counters of a [`Standby`] instance.
+///
+/// This is returned by [`Standby::stats`].
+///
+/// [`Standby`]: struct.Standby.html
+/// [`Standby::stats`]: struct.Standby.html#method.stats
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub(super) components: usize,
+    pub(super) events: usize,
+    pub(super) events_processed: u64,
+    pub(super) guilds: usize,
+    pub(super) matched: u64,
+    pub(super) messages: usize,
+    pub(super) modals: usize,
+    pub(super) reactions: usize,
+}
+
+impl Stats {
+    /// Number of registered component waiters.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Number of registered waiters for events not in a certain guild.
+    pub fn events(&self) -> usize {
+        self.events
+    }
+
+    /// Total number of events processed.
+    pub fn events_processed(&self) -> u64 {
+        self.events_processed
+    }
+
+    /// Number of registered waiters for events in a certain guild.
+    pub fn guilds(&self) -> usize {
+        self.guilds
+    }
+
+    /// Total number of times an event was sent to a waiter whose predicate
+    /// matched it.
+    pub fn matched(&self) -> u64 {
+        self.matched
+    }
+
+    /// Number of registered message waiters.
+    pub fn messages(&self) -> usize {
+        self.messages
+    }
+
+    /// Number of registered modal waiters.
+    pub fn modals(&self) -> usize {
+        self.modals
+    }
+
+    /// Number of registered reaction waiters.
+    pub fn reactions(&self) -> usize {
+        self.reactions
+    }
+
+    /// Total number of registered waiters of all types.
+    pub fn waiters(&self) -> usize {
+        self.components + self.events + self.guilds + self.messages + self.modals + self.reactions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(Stats: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+}
@@ -88,6 +88,19 @@
 //!
 //! Utility crate for creating and validating message embeds, to be used when creating or updating messages.
 //!
+//! ### [`twilight-framework`]
+//!
+//! Batteries-included entry point that drives a cluster's events through the
+//! cache, standby, middleware, and typed handlers registered by the bot.
+//!
+//! ### [`twilight-interactions`]
+//!
+//! Define application commands as Rust types, deriving both their definitions
+//! and the parsing of their options from the same struct.
+//!
+//! Interactions can also be verified and parsed when they are received over an
+//! HTTP interactions endpoint instead of the gateway.
+//!
 //! ### [`twilight-lavalink`]
 //!
 //! Client for [Lavalink] as part of the twilight ecosystem.
@@ -109,12 +122,28 @@
 //! in any other crate. Currently, it contains a trait to make extracting data
 //! from Discord identifiers (Snowflakes) easier.
 //!
+//! ### [`twilight-voice`]
+//!
+//! Native voice client for sending Opus audio to voice channels over an
+//! encrypted UDP connection, without running a separate Lavalink server.
+//!
 //! ### [`twilight-gateway-queue`]
 //!
 //! A trait and some implementations that are used by the gateway to ratelimit
 //! identify calls. Developers should prefer to use the re-exports of these crates
 //! through the gateway.
 //!
+//! ### [`twilight-http-ratelimiting`]
+//!
+//! A trait and an in-memory implementation that are used by the HTTP client to
+//! ratelimit requests. Developers should prefer to use the re-exports of this
+//! crate through the HTTP client.
+//!
+//! ### [`twilight-http-proxy`]
+//!
+//! HTTP proxy that ratelimits the requests of many processes sharing one token
+//! in one place, so that horizontally scaled bots don't get ratelimited.
+//!
 //! ## Examples
 //!
 //! ```rust,no_run
@@ -180,7 +209,7 @@
 //!     http: HttpClient,
 //! ) -> Result<(), Box<dyn Error + Send + Sync>> {
 //!     match event {
-//!         Event::MessageCreate(msg) if msg.content == "!ping" => {
+//!         Event::MessageCreate(msg) if &*msg.content == "!ping" => {
 //!             http.create_message(msg.channel_id).content("Pong!")?.await?;
 //!         }
 //!         Event::ShardConnected(_) => {
@@ -226,11 +255,16 @@
 //! [`twilight-cache-inmemory`]: https://twilight.rs/chapter_1_crates/section_4_cache_inmemory.html
 //! [`twilight-command-parser`]: https://twilight.rs/chapter_1_crates/section_5_command_parser.html
 //! [`twilight-embed-builder`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_1_embed_builder.html
+//! [`twilight-framework`]: https://docs.rs/twilight-framework
 //! [`twilight-gateway-queue`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_5_gateway_queue.html
 //! [`twilight-gateway`]: https://twilight.rs/chapter_1_crates/section_3_gateway.html
 //! [`twilight-http`]: https://twilight.rs/chapter_1_crates/section_2_http.html
+//! [`twilight-http-proxy`]: https://docs.rs/twilight-http-proxy
+//! [`twilight-http-ratelimiting`]: https://docs.rs/twilight-http-ratelimiting
+//! [`twilight-interactions`]: https://docs.rs/twilight-interactions
 //! [`twilight-lavalink`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_3_lavalink.html
 //! [`twilight-mention`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_2_mention.html
 //! [`twilight-model`]: https://twilight.rs/chapter_1_crates/section_1_model.html
 //! [`twilight-standby`]: https://twilight.rs/chapter_1_crates/section_6_standby.html
 //! [`twilight-util`]: https://twilight.rs/chapter_1_crates/section_7_first_party/section_4_util.html
+//! [`twilight-voice`]: https://docs.rs/twilight-voice
@@ -0,0 +1,545 @@
+//! Build application commands and their options.
+//!
+//! # Examples
+//!
+//! Build a command to ban a user, with a required user option and an
+//! optional integer option with choices:
+//!
+//! ```rust
+//! use twilight_model::application::command::CommandOptionType;
+//! use twilight_util::builder::command::{CommandBuilder, CommandOptionBuilder};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let command = CommandBuilder::new("ban", "Ban a user")
+//!     .option(
+//!         CommandOptionBuilder::new(CommandOptionType::User, "user", "User to ban")
+//!             .required(true),
+//!     )
+//!     .option(
+//!         CommandOptionBuilder::new(
+//!             CommandOptionType::Integer,
+//!             "days",
+//!             "Days of messages to delete",
+//!         )
+//!         .integer_choice("None", 0)
+//!         .integer_choice("Week", 7),
+//!     )
+//!     .build()?;
+//!
+//! assert_eq!(2, command.options.len());
+//! # Ok(()) }
+//! ```
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::application::command::{
+    Command, CommandOption, CommandOptionChoice, CommandOptionChoiceValue, CommandOptionType,
+};
+
+/// Maximum number of choices of an option.
+pub const CHOICES_MAX: usize = 25;
+
+/// Maximum length of the description of a command or option.
+pub const DESCRIPTION_LENGTH_MAX: usize = 100;
+
+/// Maximum length of the name of a command, option, or choice.
+pub const NAME_LENGTH_MAX: usize = 32;
+
+/// Maximum number of options of a command or option.
+pub const OPTIONS_MAX: usize = 25;
+
+/// Building a command failed because it's invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CommandValidationError {
+    /// An option has more than 25 choices.
+    ChoiceCountInvalid {
+        /// Name of the option.
+        name: String,
+    },
+    /// An option has a choice despite not being a string or integer option,
+    /// or the value of a choice doesn't match the type of the option.
+    ChoiceValueInvalid {
+        /// Name of the option.
+        name: String,
+    },
+    /// Description of the command or an option isn't between 1 and 100
+    /// characters long.
+    DescriptionInvalid {
+        /// Provided description.
+        description: String,
+    },
+    /// Name of the command, an option, or a choice isn't between 1 and 32
+    /// characters long, or the name of the command or an option has
+    /// characters other than lowercase letters, numbers, `-`, and `_`.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+    /// The command or an option has more than 25 options.
+    OptionCountInvalid {
+        /// Name of the command or option.
+        name: String,
+    },
+    /// An option has nested options that it can't have.
+    ///
+    /// Subcommand groups may only have subcommands, subcommands may not have
+    /// subcommands or subcommand groups, and other options may not have
+    /// options.
+    OptionNestingInvalid {
+        /// Name of the nested option.
+        name: String,
+    },
+    /// A required option is after an optional option.
+    OptionRequiredAfterOptional {
+        /// Name of the required option.
+        name: String,
+    },
+}
+
+impl Display for CommandValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::ChoiceCountInvalid { name } => f.write_fmt(format_args!(
+                "option {} has more than {} choices",
+                name, CHOICES_MAX
+            )),
+            Self::ChoiceValueInvalid { name } => f.write_fmt(format_args!(
+                "option {} has a choice with a value of the wrong type",
+                name
+            )),
+            Self::DescriptionInvalid { description } => f.write_fmt(format_args!(
+                "description '{}' is not between 1 and {} characters long",
+                description, DESCRIPTION_LENGTH_MAX
+            )),
+            Self::NameInvalid { name } => {
+                f.write_fmt(format_args!("name '{}' is not a valid command name", name))
+            }
+            Self::OptionCountInvalid { name } => f.write_fmt(format_args!(
+                "{} has more than {} options",
+                name, OPTIONS_MAX
+            )),
+            Self::OptionNestingInvalid { name } => f.write_fmt(format_args!(
+                "option {} can't be nested in its parent",
+                name
+            )),
+            Self::OptionRequiredAfterOptional { name } => f.write_fmt(format_args!(
+                "required option {} is after an optional option",
+                name
+            )),
+        }
+    }
+}
+
+impl Error for CommandValidationError {}
+
+/// Builder for a [`Command`].
+///
+/// The command is validated when it's built.
+///
+/// [`Command`]: ../../../twilight_model/application/command/struct.Command.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "the builder must be built to get the command"]
+pub struct CommandBuilder(Command);
+
+impl CommandBuilder {
+    /// Create a new builder for a command with a name and description.
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self(Command {
+            application_id: None,
+            default_permission: None,
+            description: description.into(),
+            guild_id: None,
+            id: None,
+            name: name.into(),
+            options: Vec::new(),
+        })
+    }
+
+    /// Set whether the command is enabled by default when the application is
+    /// added to a guild.
+    pub fn default_permission(mut self, default_permission: bool) -> Self {
+        self.0.default_permission.replace(default_permission);
+
+        self
+    }
+
+    /// Add an option to the command.
+    pub fn option(mut self, option: CommandOptionBuilder) -> Self {
+        self.0.options.push(option.0);
+
+        self
+    }
+
+    /// Validate and build the command.
+    ///
+    /// # Errors
+    ///
+    /// Returns one of the variants of [`CommandValidationError`] if the
+    /// command or any of its options are invalid.
+    ///
+    /// [`CommandValidationError`]: enum.CommandValidationError.html
+    pub fn build(self) -> Result<Command, CommandValidationError> {
+        validate_name(&self.0.name)?;
+        validate_description(&self.0.description)?;
+        validate_options(&self.0.name, None, &self.0.options)?;
+
+        Ok(self.0)
+    }
+}
+
+/// Builder for a [`CommandOption`], added to a command via
+/// [`CommandBuilder::option`] or to a subcommand or subcommand group via
+/// [`CommandOptionBuilder::option`].
+///
+/// The option is validated when the command it's in is built.
+///
+/// [`CommandBuilder::option`]: struct.CommandBuilder.html#method.option
+/// [`CommandOption`]: ../../../twilight_model/application/command/struct.CommandOption.html
+/// [`CommandOptionBuilder::option`]: #method.option
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "the builder must be added to a command"]
+pub struct CommandOptionBuilder(CommandOption);
+
+impl CommandOptionBuilder {
+    /// Create a new builder for an option with a type, name, and
+    /// description.
+    pub fn new(
+        kind: CommandOptionType,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self(CommandOption {
+            choices: Vec::new(),
+            description: description.into(),
+            kind,
+            name: name.into(),
+            options: Vec::new(),
+            required: false,
+        })
+    }
+
+    /// Add a choice to an integer option.
+    pub fn integer_choice(self, name: impl Into<String>, value: i64) -> Self {
+        self.choice(name.into(), CommandOptionChoiceValue::Integer(value))
+    }
+
+    /// Add a nested option to a subcommand or subcommand group.
+    pub fn option(mut self, option: CommandOptionBuilder) -> Self {
+        self.0.options.push(option.0);
+
+        self
+    }
+
+    /// Set whether the option is required.
+    ///
+    /// Required options must be before optional options.
+    pub fn required(mut self, required: bool) -> Self {
+        self.0.required = required;
+
+        self
+    }
+
+    /// Add a choice to a string option.
+    pub fn string_choice(self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.choice(name.into(), CommandOptionChoiceValue::String(value.into()))
+    }
+
+    fn choice(mut self, name: String, value: CommandOptionChoiceValue) -> Self {
+        self.0.choices.push(CommandOptionChoice { name, value });
+
+        self
+    }
+}
+
+fn validate_description(description: &str) -> Result<(), CommandValidationError> {
+    let len = description.chars().count();
+
+    if len == 0 || len > DESCRIPTION_LENGTH_MAX {
+        return Err(CommandValidationError::DescriptionInvalid {
+            description: description.to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_name(name: &str) -> Result<(), CommandValidationError> {
+    let len = name.chars().count();
+    let chars_valid = name
+        .chars()
+        .all(|c| c == '-' || c == '_' || (c.is_alphanumeric() && !c.is_uppercase()));
+
+    if len == 0 || len > NAME_LENGTH_MAX || !chars_valid {
+        return Err(CommandValidationError::NameInvalid {
+            name: name.to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Validate the options of a command, or of an option if its type is
+/// provided.
+fn validate_options(
+    name: &str,
+    kind: Option<CommandOptionType>,
+    options: &[CommandOption],
+) -> Result<(), CommandValidationError> {
+    if options.len() > OPTIONS_MAX {
+        return Err(CommandValidationError::OptionCountInvalid {
+            name: name.to_owned(),
+        });
+    }
+
+    let mut optional_found = false;
+
+    for option in options {
+        let nestable = match kind {
+            None => true,
+            Some(CommandOptionType::SubCommandGroup) => {
+                option.kind == CommandOptionType::SubCommand
+            }
+            Some(CommandOptionType::SubCommand) => !is_subcommand(option.kind),
+            Some(_) => false,
+        };
+
+        if !nestable {
+            return Err(CommandValidationError::OptionNestingInvalid {
+                name: option.name.clone(),
+            });
+        }
+
+        if option.required && optional_found {
+            return Err(CommandValidationError::OptionRequiredAfterOptional {
+                name: option.name.clone(),
+            });
+        }
+
+        optional_found |= !option.required;
+
+        validate_option(option)?;
+    }
+
+    Ok(())
+}
+
+fn validate_option(option: &CommandOption) -> Result<(), CommandValidationError> {
+    validate_name(&option.name)?;
+    validate_description(&option.description)?;
+
+    if option.choices.len() > CHOICES_MAX {
+        return Err(CommandValidationError::ChoiceCountInvalid {
+            name: option.name.clone(),
+        });
+    }
+
+    for choice in &option.choices {
+        let len = choice.name.chars().count();
+
+        if len == 0 || len > NAME_LENGTH_MAX {
+            return Err(CommandValidationError::NameInvalid {
+                name: choice.name.clone(),
+            });
+        }
+
+        let matches = matches!(
+            (option.kind, &choice.value),
+            (
+                CommandOptionType::Integer,
+                CommandOptionChoiceValue::Integer(_)
+            ) | (
+                CommandOptionType::String,
+                CommandOptionChoiceValue::String(_)
+            )
+        );
+
+        if !matches {
+            return Err(CommandValidationError::ChoiceValueInvalid {
+                name: option.name.clone(),
+            });
+        }
+    }
+
+    validate_options(&option.name, Some(option.kind), &option.options)
+}
+
+fn is_subcommand(kind: CommandOptionType) -> bool {
+    matches!(
+        kind,
+        CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandBuilder, CommandOptionBuilder, CommandValidationError};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::application::command::CommandOptionType;
+
+    assert_fields!(CommandValidationError::ChoiceCountInvalid: name);
+    assert_fields!(CommandValidationError::ChoiceValueInvalid: name);
+    assert_fields!(CommandValidationError::DescriptionInvalid: description);
+    assert_fields!(CommandValidationError::NameInvalid: name);
+    assert_fields!(CommandValidationError::OptionCountInvalid: name);
+    assert_fields!(CommandValidationError::OptionNestingInvalid: name);
+    assert_fields!(CommandValidationError::OptionRequiredAfterOptional: name);
+    assert_impl_all!(CommandBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(CommandOptionBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(CommandValidationError: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+
+    fn option(kind: CommandOptionType, name: &str) -> CommandOptionBuilder {
+        CommandOptionBuilder::new(kind, name, "description")
+    }
+
+    #[test]
+    fn test_build() {
+        let command = CommandBuilder::new("config", "Configure the bot")
+            .default_permission(false)
+            .option(
+                option(CommandOptionType::SubCommandGroup, "prefix")
+                    .option(
+                        option(CommandOptionType::SubCommand, "set")
+                            .option(option(CommandOptionType::String, "prefix").required(true)),
+                    )
+                    .option(option(CommandOptionType::SubCommand, "reset")),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(Some(false), command.default_permission);
+        assert_eq!(1, command.options.len());
+        assert_eq!(2, command.options[0].options.len());
+        assert!(command.options[0].options[0].options[0].required);
+    }
+
+    #[test]
+    fn test_name() {
+        for name in &["", "Upper", "with space", &"a".repeat(33)] {
+            assert_eq!(
+                CommandValidationError::NameInvalid {
+                    name: (*name).to_owned(),
+                },
+                CommandBuilder::new(*name, "description")
+                    .build()
+                    .unwrap_err(),
+            );
+        }
+
+        assert!(CommandBuilder::new("ping-2_ü", "description")
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_description() {
+        assert_eq!(
+            CommandValidationError::DescriptionInvalid {
+                description: String::new(),
+            },
+            CommandBuilder::new("ping", "").build().unwrap_err(),
+        );
+        assert!(CommandBuilder::new("ping", "a".repeat(101))
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_option_order() {
+        assert_eq!(
+            CommandValidationError::OptionRequiredAfterOptional {
+                name: "b".to_owned(),
+            },
+            CommandBuilder::new("ping", "description")
+                .option(option(CommandOptionType::String, "a"))
+                .option(option(CommandOptionType::String, "b").required(true))
+                .build()
+                .unwrap_err(),
+        );
+    }
+
+    #[test]
+    fn test_option_count() {
+        let mut builder = CommandBuilder::new("ping", "description");
+
+        for idx in 0..26 {
+            builder = builder.option(option(CommandOptionType::String, &idx.to_string()));
+        }
+
+        assert_eq!(
+            CommandValidationError::OptionCountInvalid {
+                name: "ping".to_owned(),
+            },
+            builder.build().unwrap_err(),
+        );
+    }
+
+    #[test]
+    fn test_option_nesting() {
+        assert_eq!(
+            CommandValidationError::OptionNestingInvalid {
+                name: "nested".to_owned(),
+            },
+            CommandBuilder::new("ping", "description")
+                .option(
+                    option(CommandOptionType::String, "a")
+                        .option(option(CommandOptionType::String, "nested")),
+                )
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            CommandValidationError::OptionNestingInvalid {
+                name: "nested".to_owned(),
+            },
+            CommandBuilder::new("ping", "description")
+                .option(
+                    option(CommandOptionType::SubCommandGroup, "a")
+                        .option(option(CommandOptionType::String, "nested")),
+                )
+                .build()
+                .unwrap_err(),
+        );
+    }
+
+    #[test]
+    fn test_choices() {
+        assert_eq!(
+            CommandValidationError::ChoiceValueInvalid {
+                name: "a".to_owned(),
+            },
+            CommandBuilder::new("ping", "description")
+                .option(option(CommandOptionType::Integer, "a").string_choice("one", "1"))
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            CommandValidationError::ChoiceValueInvalid {
+                name: "a".to_owned(),
+            },
+            CommandBuilder::new("ping", "description")
+                .option(option(CommandOptionType::User, "a").integer_choice("one", 1))
+                .build()
+                .unwrap_err(),
+        );
+
+        let mut choices = option(CommandOptionType::Integer, "a");
+
+        for idx in 0..26 {
+            choices = choices.integer_choice(idx.to_string(), idx);
+        }
+
+        assert_eq!(
+            CommandValidationError::ChoiceCountInvalid {
+                name: "a".to_owned(),
+            },
+            CommandBuilder::new("ping", "description")
+                .option(choices)
+                .build()
+                .unwrap_err(),
+        );
+    }
+}
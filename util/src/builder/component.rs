@@ -0,0 +1,1013 @@
+//! Build message components and modals.
+//!
+//! Components are sent in action rows, which are added to a
+//! [`ComponentsBuilder`] for messages or a [`ModalBuilder`] for modals.
+//! Structural limits, such as the number of buttons in a row, are validated
+//! when building.
+//!
+//! # Examples
+//!
+//! Build a row of buttons and a row with a select menu for a message:
+//!
+//! ```rust
+//! use twilight_model::application::component::ButtonStyle;
+//! use twilight_util::builder::component::{
+//!     ActionRowBuilder, ButtonBuilder, ComponentsBuilder, SelectMenuBuilder,
+//!     SelectMenuOptionBuilder,
+//! };
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let components = ComponentsBuilder::new()
+//!     .row(
+//!         ActionRowBuilder::new()
+//!             .button(ButtonBuilder::new(ButtonStyle::Primary, "next").label("Next"))
+//!             .button(ButtonBuilder::link("https://twilight.rs").label("Docs")),
+//!     )
+//!     .row(
+//!         ActionRowBuilder::new().select_menu(
+//!             SelectMenuBuilder::new("color")
+//!                 .option(SelectMenuOptionBuilder::new("Red", "red"))
+//!                 .option(SelectMenuOptionBuilder::new("Blue", "blue")),
+//!         ),
+//!     )
+//!     .build()?;
+//!
+//! assert_eq!(2, components.len());
+//! # Ok(()) }
+//! ```
+//!
+//! [`ComponentsBuilder`]: struct.ComponentsBuilder.html
+//! [`ModalBuilder`]: struct.ModalBuilder.html
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    application::{
+        callback::Modal,
+        component::{
+            ActionRow, Button, ButtonStyle, Component, ComponentType, SelectMenu, SelectMenuOption,
+            TextInput, TextInputStyle,
+        },
+    },
+    channel::ReactionType,
+};
+
+/// Maximum number of action rows on a message or modal.
+pub const ACTION_ROWS_MAX: usize = 5;
+
+/// Maximum number of buttons in an action row.
+pub const BUTTONS_MAX: usize = 5;
+
+/// Maximum length of the label of a button.
+pub const BUTTON_LABEL_LENGTH_MAX: usize = 80;
+
+/// Maximum length of a custom ID.
+pub const CUSTOM_ID_LENGTH_MAX: usize = 100;
+
+/// Maximum length of the title of a modal.
+pub const MODAL_TITLE_LENGTH_MAX: usize = 45;
+
+/// Maximum length of the label, value, or description of a select menu
+/// option.
+pub const SELECT_MENU_OPTION_LENGTH_MAX: usize = 100;
+
+/// Maximum number of options of a select menu.
+pub const SELECT_MENU_OPTIONS_MAX: usize = 25;
+
+/// Maximum length of the placeholder of a select menu.
+pub const SELECT_MENU_PLACEHOLDER_LENGTH_MAX: usize = 150;
+
+/// Maximum length of the label of a text input.
+pub const TEXT_INPUT_LABEL_LENGTH_MAX: usize = 45;
+
+/// Maximum length of the value of a text input.
+pub const TEXT_INPUT_LENGTH_MAX: u16 = 4000;
+
+/// Maximum length of the placeholder of a text input.
+pub const TEXT_INPUT_PLACEHOLDER_LENGTH_MAX: usize = 100;
+
+/// Building components or a modal failed because they're invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ComponentValidationError {
+    /// An action row has too many components or mixes types of components.
+    ///
+    /// Rows may have up to 5 buttons, one select menu, or one text input.
+    ActionRowComponentsInvalid {
+        /// Number of components in the row.
+        count: usize,
+    },
+    /// There are more than 5 action rows, or a modal has none.
+    ActionRowCountInvalid {
+        /// Number of provided rows.
+        count: usize,
+    },
+    /// A link button has a custom ID, or another button doesn't.
+    ///
+    /// Link buttons must have a URL and other buttons must have a custom ID.
+    ButtonStyleInvalid {
+        /// Style of the button.
+        style: ButtonStyle,
+    },
+    /// A custom ID is empty or longer than 100 characters.
+    CustomIdInvalid {
+        /// Provided custom ID.
+        custom_id: String,
+    },
+    /// A label is too long, or the label of a text input is empty.
+    LabelInvalid {
+        /// Provided label.
+        label: String,
+    },
+    /// A placeholder is too long.
+    PlaceholderInvalid {
+        /// Provided placeholder.
+        placeholder: String,
+    },
+    /// A select menu has no options or more than 25.
+    SelectMenuOptionCountInvalid {
+        /// Number of provided options.
+        count: usize,
+    },
+    /// The label, value, or description of a select menu option is longer
+    /// than 100 characters, or its label or value is empty.
+    SelectMenuOptionInvalid {
+        /// Value of the option.
+        value: String,
+    },
+    /// The minimum or maximum number of values of a select menu is out of
+    /// bounds.
+    ///
+    /// The minimum must be at most the maximum, and the maximum must be
+    /// between 1 and the number of options.
+    SelectMenuValuesInvalid {
+        /// Provided maximum number of values.
+        max_values: Option<u8>,
+        /// Provided minimum number of values.
+        min_values: Option<u8>,
+    },
+    /// The minimum or maximum length of a text input is out of bounds, or
+    /// its pre-filled value is longer than 4000 characters.
+    TextInputLengthInvalid {
+        /// Provided maximum length.
+        max_length: Option<u16>,
+        /// Provided minimum length.
+        min_length: Option<u16>,
+    },
+    /// A text input was added to a message, which only modals may have.
+    TextInputOnMessage,
+    /// Title of a modal is empty or longer than 45 characters.
+    TitleInvalid {
+        /// Provided title.
+        title: String,
+    },
+}
+
+impl Display for ComponentValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::ActionRowComponentsInvalid { count } => f.write_fmt(format_args!(
+                "action row has {} components, but may only have up to {} buttons or one other component",
+                count, BUTTONS_MAX
+            )),
+            Self::ActionRowCountInvalid { count } => f.write_fmt(format_args!(
+                "{} action rows were provided, but there must be 1 to {}",
+                count, ACTION_ROWS_MAX
+            )),
+            Self::ButtonStyleInvalid { style } => f.write_fmt(format_args!(
+                "button with the {:?} style must have only a url if it's a link or otherwise only a custom id",
+                style
+            )),
+            Self::CustomIdInvalid { custom_id } => f.write_fmt(format_args!(
+                "custom id '{}' is not between 1 and {} characters long",
+                custom_id, CUSTOM_ID_LENGTH_MAX
+            )),
+            Self::LabelInvalid { label } => {
+                f.write_fmt(format_args!("label '{}' is not a valid length", label))
+            }
+            Self::PlaceholderInvalid { placeholder } => f.write_fmt(format_args!(
+                "placeholder '{}' is too long",
+                placeholder
+            )),
+            Self::SelectMenuOptionCountInvalid { count } => f.write_fmt(format_args!(
+                "select menu has {} options, but must have 1 to {}",
+                count, SELECT_MENU_OPTIONS_MAX
+            )),
+            Self::SelectMenuOptionInvalid { value } => f.write_fmt(format_args!(
+                "select menu option with the value '{}' has a field that is not a valid length",
+                value
+            )),
+            Self::SelectMenuValuesInvalid {
+                max_values,
+                min_values,
+            } => f.write_fmt(format_args!(
+                "select menu minimum values ({:?}) and maximum values ({:?}) are out of bounds",
+                min_values, max_values
+            )),
+            Self::TextInputLengthInvalid {
+                max_length,
+                min_length,
+            } => f.write_fmt(format_args!(
+                "text input minimum length ({:?}) and maximum length ({:?}) are out of bounds",
+                min_length, max_length
+            )),
+            Self::TextInputOnMessage => f.write_str("text inputs may only be sent in modals"),
+            Self::TitleInvalid { title } => f.write_fmt(format_args!(
+                "title '{}' is not between 1 and {} characters long",
+                title, MODAL_TITLE_LENGTH_MAX
+            )),
+        }
+    }
+}
+
+impl Error for ComponentValidationError {}
+
+/// Builder for the action rows of a message.
+///
+/// The built components can be sent when creating or updating a message, or
+/// when responding to an interaction with a message.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[must_use = "the builder must be built to get the components"]
+pub struct ComponentsBuilder(Vec<ActionRowBuilder>);
+
+impl ComponentsBuilder {
+    /// Create a new builder with no action rows.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an action row.
+    pub fn row(mut self, row: ActionRowBuilder) -> Self {
+        self.0.push(row);
+
+        self
+    }
+
+    /// Validate and build the components.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ComponentValidationError::ActionRowCountInvalid`] if there
+    /// are more than 5 action rows.
+    ///
+    /// Returns [`ComponentValidationError::TextInputOnMessage`] if an action
+    /// row contains a text input.
+    ///
+    /// Returns another variant of [`ComponentValidationError`] if a component
+    /// is invalid.
+    ///
+    /// [`ComponentValidationError::ActionRowCountInvalid`]: enum.ComponentValidationError.html#variant.ActionRowCountInvalid
+    /// [`ComponentValidationError::TextInputOnMessage`]: enum.ComponentValidationError.html#variant.TextInputOnMessage
+    /// [`ComponentValidationError`]: enum.ComponentValidationError.html
+    pub fn build(self) -> Result<Vec<Component>, ComponentValidationError> {
+        if self.0.len() > ACTION_ROWS_MAX {
+            return Err(ComponentValidationError::ActionRowCountInvalid {
+                count: self.0.len(),
+            });
+        }
+
+        self.0
+            .into_iter()
+            .map(|row| {
+                if row.0.iter().any(|c| matches!(c, Component::TextInput(_))) {
+                    return Err(ComponentValidationError::TextInputOnMessage);
+                }
+
+                row.build()
+            })
+            .collect()
+    }
+}
+
+/// Builder for a row of components.
+///
+/// Rows may have up to 5 buttons, one select menu, or one text input.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[must_use = "the builder must be added to components or a modal"]
+pub struct ActionRowBuilder(Vec<Component>);
+
+impl ActionRowBuilder {
+    /// Create a new builder for an empty row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a button to the row.
+    pub fn button(mut self, button: ButtonBuilder) -> Self {
+        self.0.push(Component::Button(button.0));
+
+        self
+    }
+
+    /// Add a select menu to the row.
+    pub fn select_menu(mut self, select_menu: SelectMenuBuilder) -> Self {
+        self.0.push(Component::SelectMenu(select_menu.0));
+
+        self
+    }
+
+    /// Add a text input to the row, which is only allowed in modals.
+    pub fn text_input(mut self, text_input: TextInputBuilder) -> Self {
+        self.0.push(Component::TextInput(text_input.0));
+
+        self
+    }
+
+    fn build(self) -> Result<Component, ComponentValidationError> {
+        let count = self.0.len();
+        let buttons = self
+            .0
+            .iter()
+            .filter(|c| matches!(c, Component::Button(_)))
+            .count();
+        let valid = count == 1 || (count > 0 && count == buttons && count <= BUTTONS_MAX);
+
+        if !valid {
+            return Err(ComponentValidationError::ActionRowComponentsInvalid { count });
+        }
+
+        for component in &self.0 {
+            match component {
+                Component::Button(button) => validate_button(button)?,
+                Component::SelectMenu(menu) => validate_select_menu(menu)?,
+                Component::TextInput(input) => validate_text_input(input)?,
+                Component::ActionRow(_) => {
+                    return Err(ComponentValidationError::ActionRowComponentsInvalid { count })
+                }
+            }
+        }
+
+        Ok(Component::ActionRow(ActionRow {
+            components: self.0,
+            kind: ComponentType::ActionRow,
+        }))
+    }
+}
+
+/// Builder for a button.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "the builder must be added to an action row"]
+pub struct ButtonBuilder(Button);
+
+impl ButtonBuilder {
+    /// Create a new builder for a button with a style other than
+    /// [`ButtonStyle::Link`] and the custom ID of the interactions it
+    /// creates.
+    ///
+    /// [`ButtonStyle::Link`]: ../../../twilight_model/application/component/enum.ButtonStyle.html#variant.Link
+    pub fn new(style: ButtonStyle, custom_id: impl Into<String>) -> Self {
+        Self(Button {
+            custom_id: Some(custom_id.into()),
+            disabled: false,
+            emoji: None,
+            kind: ComponentType::Button,
+            label: None,
+            style,
+            url: None,
+        })
+    }
+
+    /// Create a new builder for a button linking to a URL.
+    pub fn link(url: impl Into<String>) -> Self {
+        Self(Button {
+            custom_id: None,
+            disabled: false,
+            emoji: None,
+            kind: ComponentType::Button,
+            label: None,
+            style: ButtonStyle::Link,
+            url: Some(url.into()),
+        })
+    }
+
+    /// Set whether the button is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.0.disabled = disabled;
+
+        self
+    }
+
+    /// Set the emoji of the button.
+    pub fn emoji(mut self, emoji: ReactionType) -> Self {
+        self.0.emoji.replace(emoji);
+
+        self
+    }
+
+    /// Set the label of the button, which may be up to 80 characters long.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.0.label.replace(label.into());
+
+        self
+    }
+}
+
+/// Builder for a select menu.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "the builder must be added to an action row"]
+pub struct SelectMenuBuilder(SelectMenu);
+
+impl SelectMenuBuilder {
+    /// Create a new builder for a select menu with the custom ID of the
+    /// interactions it creates.
+    pub fn new(custom_id: impl Into<String>) -> Self {
+        Self(SelectMenu {
+            custom_id: custom_id.into(),
+            disabled: false,
+            kind: ComponentType::SelectMenu,
+            max_values: None,
+            min_values: None,
+            options: Vec::new(),
+            placeholder: None,
+        })
+    }
+
+    /// Set whether the select menu is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.0.disabled = disabled;
+
+        self
+    }
+
+    /// Set the maximum number of options that may be chosen.
+    pub fn max_values(mut self, max_values: u8) -> Self {
+        self.0.max_values.replace(max_values);
+
+        self
+    }
+
+    /// Set the minimum number of options that must be chosen.
+    pub fn min_values(mut self, min_values: u8) -> Self {
+        self.0.min_values.replace(min_values);
+
+        self
+    }
+
+    /// Add an option to the select menu.
+    ///
+    /// Select menus must have between 1 and 25 options.
+    pub fn option(mut self, option: SelectMenuOptionBuilder) -> Self {
+        self.0.options.push(option.0);
+
+        self
+    }
+
+    /// Set the placeholder shown when no option is chosen, which may be up to
+    /// 150 characters long.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.0.placeholder.replace(placeholder.into());
+
+        self
+    }
+}
+
+/// Builder for an option of a select menu.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "the builder must be added to a select menu"]
+pub struct SelectMenuOptionBuilder(SelectMenuOption);
+
+impl SelectMenuOptionBuilder {
+    /// Create a new builder for an option with the label shown to users and
+    /// the value provided in interactions.
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self(SelectMenuOption {
+            default: false,
+            description: None,
+            emoji: None,
+            label: label.into(),
+            value: value.into(),
+        })
+    }
+
+    /// Set whether the option is chosen by default.
+    pub fn default(mut self, default: bool) -> Self {
+        self.0.default = default;
+
+        self
+    }
+
+    /// Set the description of the option.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description.replace(description.into());
+
+        self
+    }
+
+    /// Set the emoji of the option.
+    pub fn emoji(mut self, emoji: ReactionType) -> Self {
+        self.0.emoji.replace(emoji);
+
+        self
+    }
+}
+
+/// Builder for a text input, which is only allowed in modals.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "the builder must be added to an action row"]
+pub struct TextInputBuilder(TextInput);
+
+impl TextInputBuilder {
+    /// Create a new builder for a text input with a style, the custom ID of
+    /// its value in the modal submit interaction, and a label.
+    pub fn new(
+        style: TextInputStyle,
+        custom_id: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self(TextInput {
+            custom_id: custom_id.into(),
+            kind: ComponentType::TextInput,
+            label: label.into(),
+            max_length: None,
+            min_length: None,
+            placeholder: None,
+            required: None,
+            style,
+            value: None,
+        })
+    }
+
+    /// Set the maximum length of the input, up to 4000.
+    pub fn max_length(mut self, max_length: u16) -> Self {
+        self.0.max_length.replace(max_length);
+
+        self
+    }
+
+    /// Set the minimum length of the input, up to 4000.
+    pub fn min_length(mut self, min_length: u16) -> Self {
+        self.0.min_length.replace(min_length);
+
+        self
+    }
+
+    /// Set the placeholder shown when the input is empty, which may be up to
+    /// 100 characters long.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.0.placeholder.replace(placeholder.into());
+
+        self
+    }
+
+    /// Set whether the input must be filled in.
+    pub fn required(mut self, required: bool) -> Self {
+        self.0.required.replace(required);
+
+        self
+    }
+
+    /// Set the text pre-filled in the input.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.0.value.replace(value.into());
+
+        self
+    }
+}
+
+/// Builder for a modal.
+///
+/// Each text input is added in its own action row.
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_model::application::component::TextInputStyle;
+/// use twilight_util::builder::component::{ModalBuilder, TextInputBuilder};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let modal = ModalBuilder::new("report", "Report a user")
+///     .text_input(TextInputBuilder::new(TextInputStyle::Paragraph, "reason", "Reason"))
+///     .build()?;
+///
+/// assert_eq!(1, modal.components.len());
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "the builder must be built to get the modal"]
+pub struct ModalBuilder {
+    custom_id: String,
+    rows: Vec<ActionRowBuilder>,
+    title: String,
+}
+
+impl ModalBuilder {
+    /// Create a new builder for a modal with the custom ID of the modal
+    /// submit interaction it creates and a title.
+    pub fn new(custom_id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            rows: Vec::new(),
+            title: title.into(),
+        }
+    }
+
+    /// Add a text input in a new action row.
+    pub fn text_input(mut self, text_input: TextInputBuilder) -> Self {
+        self.rows
+            .push(ActionRowBuilder::new().text_input(text_input));
+
+        self
+    }
+
+    /// Validate and build the modal.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ComponentValidationError::ActionRowCountInvalid`] if there
+    /// are no text inputs or more than 5.
+    ///
+    /// Returns [`ComponentValidationError::CustomIdInvalid`] if the custom ID
+    /// is empty or longer than 100 characters.
+    ///
+    /// Returns [`ComponentValidationError::TitleInvalid`] if the title is
+    /// empty or longer than 45 characters.
+    ///
+    /// Returns another variant of [`ComponentValidationError`] if a text
+    /// input is invalid.
+    ///
+    /// [`ComponentValidationError::ActionRowCountInvalid`]: enum.ComponentValidationError.html#variant.ActionRowCountInvalid
+    /// [`ComponentValidationError::CustomIdInvalid`]: enum.ComponentValidationError.html#variant.CustomIdInvalid
+    /// [`ComponentValidationError::TitleInvalid`]: enum.ComponentValidationError.html#variant.TitleInvalid
+    /// [`ComponentValidationError`]: enum.ComponentValidationError.html
+    pub fn build(self) -> Result<Modal, ComponentValidationError> {
+        validate_custom_id(&self.custom_id)?;
+
+        if !length_between(&self.title, 1, MODAL_TITLE_LENGTH_MAX) {
+            return Err(ComponentValidationError::TitleInvalid { title: self.title });
+        }
+
+        let count = self.rows.len();
+
+        if count == 0 || count > ACTION_ROWS_MAX {
+            return Err(ComponentValidationError::ActionRowCountInvalid { count });
+        }
+
+        let components = self
+            .rows
+            .into_iter()
+            .map(ActionRowBuilder::build)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Modal {
+            components,
+            custom_id: self.custom_id,
+            title: self.title,
+        })
+    }
+}
+
+/// Whether the length of a string in characters is within an inclusive
+/// range.
+fn length_between(value: &str, min: usize, max: usize) -> bool {
+    let len = value.chars().count();
+
+    len >= min && len <= max
+}
+
+/// Whether an optional string is at most a length in characters, if present.
+fn optional_length_valid(value: Option<&String>, max: usize) -> bool {
+    match value {
+        Some(value) => length_between(value, 0, max),
+        None => true,
+    }
+}
+
+fn validate_button(button: &Button) -> Result<(), ComponentValidationError> {
+    let link = button.style == ButtonStyle::Link;
+
+    if link == button.custom_id.is_some() || link != button.url.is_some() {
+        return Err(ComponentValidationError::ButtonStyleInvalid {
+            style: button.style,
+        });
+    }
+
+    if let Some(custom_id) = button.custom_id.as_ref() {
+        validate_custom_id(custom_id)?;
+    }
+
+    if let Some(label) = button.label.as_ref() {
+        if !length_between(label, 0, BUTTON_LABEL_LENGTH_MAX) {
+            return Err(ComponentValidationError::LabelInvalid {
+                label: label.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_custom_id(custom_id: &str) -> Result<(), ComponentValidationError> {
+    if !length_between(custom_id, 1, CUSTOM_ID_LENGTH_MAX) {
+        return Err(ComponentValidationError::CustomIdInvalid {
+            custom_id: custom_id.to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_placeholder(
+    placeholder: Option<&String>,
+    max: usize,
+) -> Result<(), ComponentValidationError> {
+    if let Some(placeholder) = placeholder {
+        if !length_between(placeholder, 0, max) {
+            return Err(ComponentValidationError::PlaceholderInvalid {
+                placeholder: placeholder.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_select_menu(menu: &SelectMenu) -> Result<(), ComponentValidationError> {
+    validate_custom_id(&menu.custom_id)?;
+    validate_placeholder(
+        menu.placeholder.as_ref(),
+        SELECT_MENU_PLACEHOLDER_LENGTH_MAX,
+    )?;
+
+    let count = menu.options.len();
+
+    if count == 0 || count > SELECT_MENU_OPTIONS_MAX {
+        return Err(ComponentValidationError::SelectMenuOptionCountInvalid { count });
+    }
+
+    for option in &menu.options {
+        let valid = length_between(&option.label, 1, SELECT_MENU_OPTION_LENGTH_MAX)
+            && length_between(&option.value, 1, SELECT_MENU_OPTION_LENGTH_MAX)
+            && optional_length_valid(option.description.as_ref(), SELECT_MENU_OPTION_LENGTH_MAX);
+
+        if !valid {
+            return Err(ComponentValidationError::SelectMenuOptionInvalid {
+                value: option.value.clone(),
+            });
+        }
+    }
+
+    let min = usize::from(menu.min_values.unwrap_or(1));
+    let max = usize::from(menu.max_values.unwrap_or(1));
+
+    if max == 0 || max > count || min > max {
+        return Err(ComponentValidationError::SelectMenuValuesInvalid {
+            max_values: menu.max_values,
+            min_values: menu.min_values,
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_text_input(input: &TextInput) -> Result<(), ComponentValidationError> {
+    validate_custom_id(&input.custom_id)?;
+    validate_placeholder(
+        input.placeholder.as_ref(),
+        TEXT_INPUT_PLACEHOLDER_LENGTH_MAX,
+    )?;
+
+    if !length_between(&input.label, 1, TEXT_INPUT_LABEL_LENGTH_MAX) {
+        return Err(ComponentValidationError::LabelInvalid {
+            label: input.label.clone(),
+        });
+    }
+
+    let min = input.min_length.unwrap_or(0);
+    let max = input.max_length.unwrap_or(TEXT_INPUT_LENGTH_MAX);
+    let value_valid =
+        optional_length_valid(input.value.as_ref(), usize::from(TEXT_INPUT_LENGTH_MAX));
+
+    if max == 0 || max > TEXT_INPUT_LENGTH_MAX || min > max || !value_valid {
+        return Err(ComponentValidationError::TextInputLengthInvalid {
+            max_length: input.max_length,
+            min_length: input.min_length,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ActionRowBuilder, ButtonBuilder, ComponentValidationError, ComponentsBuilder, ModalBuilder,
+        SelectMenuBuilder, SelectMenuOptionBuilder, TextInputBuilder,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::application::component::{ButtonStyle, Component, TextInputStyle};
+
+    assert_impl_all!(ActionRowBuilder: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ButtonBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ComponentsBuilder: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ComponentValidationError: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ModalBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(SelectMenuBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(SelectMenuOptionBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(TextInputBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    fn button(custom_id: &str) -> ButtonBuilder {
+        ButtonBuilder::new(ButtonStyle::Primary, custom_id)
+    }
+
+    fn menu() -> SelectMenuBuilder {
+        SelectMenuBuilder::new("menu").option(SelectMenuOptionBuilder::new("a", "a"))
+    }
+
+    fn text_input() -> TextInputBuilder {
+        TextInputBuilder::new(TextInputStyle::Short, "input", "Input")
+    }
+
+    #[test]
+    fn test_components() {
+        let components = ComponentsBuilder::new()
+            .row(
+                ActionRowBuilder::new()
+                    .button(button("a"))
+                    .button(button("b")),
+            )
+            .row(ActionRowBuilder::new().select_menu(menu()))
+            .build()
+            .unwrap();
+
+        assert_eq!(2, components.len());
+        assert!(matches!(&components[0], Component::ActionRow(row) if row.components.len() == 2));
+    }
+
+    #[test]
+    fn test_row_count() {
+        let mut builder = ComponentsBuilder::new();
+
+        for _ in 0..6 {
+            builder = builder.row(ActionRowBuilder::new().button(button("a")));
+        }
+
+        assert_eq!(
+            ComponentValidationError::ActionRowCountInvalid { count: 6 },
+            builder.build().unwrap_err(),
+        );
+    }
+
+    #[test]
+    fn test_row_components() {
+        let mut row = ActionRowBuilder::new();
+
+        for idx in 0..6 {
+            row = row.button(button(&idx.to_string()));
+        }
+
+        assert_eq!(
+            ComponentValidationError::ActionRowComponentsInvalid { count: 6 },
+            ComponentsBuilder::new().row(row).build().unwrap_err(),
+        );
+        assert_eq!(
+            ComponentValidationError::ActionRowComponentsInvalid { count: 2 },
+            ComponentsBuilder::new()
+                .row(
+                    ActionRowBuilder::new()
+                        .button(button("a"))
+                        .select_menu(menu())
+                )
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            ComponentValidationError::ActionRowComponentsInvalid { count: 0 },
+            ComponentsBuilder::new()
+                .row(ActionRowBuilder::new())
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            ComponentValidationError::TextInputOnMessage,
+            ComponentsBuilder::new()
+                .row(ActionRowBuilder::new().text_input(text_input()))
+                .build()
+                .unwrap_err(),
+        );
+    }
+
+    #[test]
+    fn test_button() {
+        assert!(ComponentsBuilder::new()
+            .row(ActionRowBuilder::new().button(ButtonBuilder::link("https://twilight.rs")))
+            .build()
+            .is_ok());
+        assert_eq!(
+            ComponentValidationError::ButtonStyleInvalid {
+                style: ButtonStyle::Link,
+            },
+            ComponentsBuilder::new()
+                .row(ActionRowBuilder::new().button(ButtonBuilder::new(ButtonStyle::Link, "a")))
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            ComponentValidationError::CustomIdInvalid {
+                custom_id: "a".repeat(101),
+            },
+            ComponentsBuilder::new()
+                .row(ActionRowBuilder::new().button(button(&"a".repeat(101))))
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            ComponentValidationError::LabelInvalid {
+                label: "a".repeat(81),
+            },
+            ComponentsBuilder::new()
+                .row(ActionRowBuilder::new().button(button("a").label("a".repeat(81))))
+                .build()
+                .unwrap_err(),
+        );
+    }
+
+    #[test]
+    fn test_select_menu() {
+        assert_eq!(
+            ComponentValidationError::SelectMenuOptionCountInvalid { count: 0 },
+            ComponentsBuilder::new()
+                .row(ActionRowBuilder::new().select_menu(SelectMenuBuilder::new("menu")))
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            ComponentValidationError::SelectMenuValuesInvalid {
+                max_values: Some(2),
+                min_values: None,
+            },
+            ComponentsBuilder::new()
+                .row(ActionRowBuilder::new().select_menu(menu().max_values(2)))
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            ComponentValidationError::SelectMenuOptionInvalid {
+                value: String::new(),
+            },
+            ComponentsBuilder::new()
+                .row(
+                    ActionRowBuilder::new()
+                        .select_menu(menu().option(SelectMenuOptionBuilder::new("b", ""))),
+                )
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            ComponentValidationError::PlaceholderInvalid {
+                placeholder: "a".repeat(151),
+            },
+            ComponentsBuilder::new()
+                .row(ActionRowBuilder::new().select_menu(menu().placeholder("a".repeat(151))))
+                .build()
+                .unwrap_err(),
+        );
+    }
+
+    #[test]
+    fn test_modal() {
+        let modal = ModalBuilder::new("modal", "Title")
+            .text_input(text_input().min_length(10).max_length(100))
+            .text_input(text_input().required(false))
+            .build()
+            .unwrap();
+
+        assert_eq!("modal", modal.custom_id);
+        assert_eq!(2, modal.components.len());
+
+        assert_eq!(
+            ComponentValidationError::ActionRowCountInvalid { count: 0 },
+            ModalBuilder::new("modal", "Title").build().unwrap_err(),
+        );
+        assert_eq!(
+            ComponentValidationError::TitleInvalid {
+                title: "a".repeat(46),
+            },
+            ModalBuilder::new("modal", "a".repeat(46))
+                .text_input(text_input())
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            ComponentValidationError::TextInputLengthInvalid {
+                max_length: Some(5),
+                min_length: Some(10),
+            },
+            ModalBuilder::new("modal", "Title")
+                .text_input(text_input().min_length(10).max_length(5))
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            ComponentValidationError::LabelInvalid {
+                label: String::new(),
+            },
+            ModalBuilder::new("modal", "Title")
+                .text_input(TextInputBuilder::new(TextInputStyle::Short, "input", ""))
+                .build()
+                .unwrap_err(),
+        );
+    }
+}
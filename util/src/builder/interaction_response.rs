@@ -0,0 +1,511 @@
+//! Build the data of messages sent in response to interactions.
+//!
+//! # Examples
+//!
+//! Build an ephemeral response with a button that can't ping anyone:
+//!
+//! ```rust
+//! use twilight_model::{
+//!     application::component::ButtonStyle,
+//!     channel::message::{AllowedMentions, MessageFlags},
+//! };
+//! use twilight_util::builder::{
+//!     component::{ActionRowBuilder, ButtonBuilder, ComponentsBuilder},
+//!     interaction_response::InteractionResponseDataBuilder,
+//! };
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let data = InteractionResponseDataBuilder::new()
+//!     .content("Are you sure?")
+//!     .components(ComponentsBuilder::new().row(
+//!         ActionRowBuilder::new().button(ButtonBuilder::new(ButtonStyle::Danger, "confirm")),
+//!     ))
+//!     .flags(MessageFlags::EPHEMERAL)
+//!     .allowed_mentions(AllowedMentions::default())
+//!     .build()?;
+//!
+//! assert_eq!(Some(MessageFlags::EPHEMERAL), data.flags);
+//! # Ok(()) }
+//! ```
+
+use super::component::{ComponentValidationError, ComponentsBuilder};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    application::callback::InteractionResponseData,
+    channel::{
+        embed::Embed,
+        message::{AllowedMentions, MentionType, MessageFlags},
+        Attachment,
+    },
+};
+
+/// Maximum number of users or roles that may explicitly be allowed to be
+/// mentioned.
+pub const ALLOWED_MENTIONS_MAX: usize = 100;
+
+/// Maximum length of the content of a message.
+pub const CONTENT_LENGTH_MAX: usize = 2000;
+
+/// Maximum number of embeds in a message.
+pub const EMBEDS_MAX: usize = 10;
+
+/// Maximum combined length of the text of all embeds in a message.
+pub const EMBEDS_LENGTH_MAX: usize = 6000;
+
+/// Building the data of an interaction response failed because it's
+/// invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum InteractionResponseDataValidationError {
+    /// Users or roles are allowed to be mentioned both by type and
+    /// explicitly, or more than 100 are explicitly allowed.
+    AllowedMentionsInvalid,
+    /// The components are invalid.
+    ComponentsInvalid {
+        /// Reason for the error.
+        source: ComponentValidationError,
+    },
+    /// The content is longer than 2000 characters.
+    ContentInvalid {
+        /// Provided content.
+        content: String,
+    },
+    /// There are more than 10 embeds.
+    EmbedCountInvalid {
+        /// Number of provided embeds.
+        count: usize,
+    },
+    /// The combined text of the embeds is longer than 6000 characters.
+    EmbedLengthInvalid {
+        /// Combined length of the embeds.
+        length: usize,
+    },
+    /// Flags other than `EPHEMERAL` and `SUPPRESS_EMBEDS` are set.
+    FlagsInvalid {
+        /// Provided flags.
+        flags: MessageFlags,
+    },
+}
+
+impl Display for InteractionResponseDataValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::AllowedMentionsInvalid => f.write_str("the allowed mentions are invalid"),
+            Self::ComponentsInvalid { .. } => f.write_str("the components are invalid"),
+            Self::ContentInvalid { .. } => f.write_str("the content is too long"),
+            Self::EmbedCountInvalid { count } => f.write_fmt(format_args!(
+                "{} embeds were provided but at most 10 are allowed",
+                count
+            )),
+            Self::EmbedLengthInvalid { length } => f.write_fmt(format_args!(
+                "the embeds are {} characters long but at most 6000 are allowed",
+                length
+            )),
+            Self::FlagsInvalid { .. } => {
+                f.write_str("only the ephemeral and suppress embeds flags may be set")
+            }
+        }
+    }
+}
+
+impl Error for InteractionResponseDataValidationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::ComponentsInvalid { source } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Builder for the data of a message sent in response to an interaction, or
+/// used to update the message a component is attached to.
+///
+/// Fields that aren't set aren't sent, so when updating a message they're
+/// left unchanged.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[must_use = "the builder must be built to get the response data"]
+pub struct InteractionResponseDataBuilder {
+    components: Option<ComponentsBuilder>,
+    data: InteractionResponseData,
+}
+
+impl InteractionResponseDataBuilder {
+    /// Create a new builder with no fields set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set which mentions in the content may ping anyone.
+    ///
+    /// If they aren't set, all mentions in the content may ping.
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.data.allowed_mentions = Some(allowed_mentions);
+
+        self
+    }
+
+    /// Add an attachment to keep when updating a message.
+    pub fn attachment(mut self, attachment: Attachment) -> Self {
+        self.data
+            .attachments
+            .get_or_insert_with(Vec::new)
+            .push(attachment);
+
+        self
+    }
+
+    /// Set the components, replacing any already set.
+    pub fn components(mut self, components: ComponentsBuilder) -> Self {
+        self.components = Some(components);
+
+        self
+    }
+
+    /// Set the content.
+    ///
+    /// The maximum length is 2000 characters.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.data.content = Some(content.into());
+
+        self
+    }
+
+    /// Add an embed.
+    ///
+    /// Up to 10 embeds may be added, and their combined text may be up to
+    /// 6000 characters long.
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.data.embeds.get_or_insert_with(Vec::new).push(embed);
+
+        self
+    }
+
+    /// Set the flags.
+    ///
+    /// Only [`EPHEMERAL`], which shows the message only to the user who
+    /// invoked the interaction, and [`SUPPRESS_EMBEDS`] may be set.
+    ///
+    /// [`EPHEMERAL`]: ../../../twilight_model/channel/message/struct.MessageFlags.html#associatedconstant.EPHEMERAL
+    /// [`SUPPRESS_EMBEDS`]: ../../../twilight_model/channel/message/struct.MessageFlags.html#associatedconstant.SUPPRESS_EMBEDS
+    pub fn flags(mut self, flags: MessageFlags) -> Self {
+        self.data.flags = Some(flags);
+
+        self
+    }
+
+    /// Set whether the message is read aloud with text to speech.
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.data.tts = Some(tts);
+
+        self
+    }
+
+    /// Validate and build the response data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InteractionResponseDataValidationError::ContentInvalid`] if
+    /// the content is too long.
+    ///
+    /// Returns [`InteractionResponseDataValidationError::EmbedCountInvalid`]
+    /// or [`InteractionResponseDataValidationError::EmbedLengthInvalid`] if
+    /// there are too many embeds or their text is too long.
+    ///
+    /// Returns [`InteractionResponseDataValidationError::FlagsInvalid`] if
+    /// a flag that isn't allowed on responses is set.
+    ///
+    /// Returns
+    /// [`InteractionResponseDataValidationError::AllowedMentionsInvalid`] if
+    /// the allowed mentions conflict or explicitly allow too many users or
+    /// roles.
+    ///
+    /// Returns [`InteractionResponseDataValidationError::ComponentsInvalid`]
+    /// if the components are invalid.
+    ///
+    /// [`InteractionResponseDataValidationError::AllowedMentionsInvalid`]: enum.InteractionResponseDataValidationError.html#variant.AllowedMentionsInvalid
+    /// [`InteractionResponseDataValidationError::ComponentsInvalid`]: enum.InteractionResponseDataValidationError.html#variant.ComponentsInvalid
+    /// [`InteractionResponseDataValidationError::ContentInvalid`]: enum.InteractionResponseDataValidationError.html#variant.ContentInvalid
+    /// [`InteractionResponseDataValidationError::EmbedCountInvalid`]: enum.InteractionResponseDataValidationError.html#variant.EmbedCountInvalid
+    /// [`InteractionResponseDataValidationError::EmbedLengthInvalid`]: enum.InteractionResponseDataValidationError.html#variant.EmbedLengthInvalid
+    /// [`InteractionResponseDataValidationError::FlagsInvalid`]: enum.InteractionResponseDataValidationError.html#variant.FlagsInvalid
+    pub fn build(self) -> Result<InteractionResponseData, InteractionResponseDataValidationError> {
+        let mut data = self.data;
+
+        if let Some(content) = data.content.as_ref() {
+            if content.chars().count() > CONTENT_LENGTH_MAX {
+                return Err(InteractionResponseDataValidationError::ContentInvalid {
+                    content: content.clone(),
+                });
+            }
+        }
+
+        if let Some(embeds) = data.embeds.as_ref() {
+            validate_embeds(embeds)?;
+        }
+
+        if let Some(flags) = data.flags {
+            if !(MessageFlags::EPHEMERAL | MessageFlags::SUPPRESS_EMBEDS).contains(flags) {
+                return Err(InteractionResponseDataValidationError::FlagsInvalid { flags });
+            }
+        }
+
+        if let Some(allowed_mentions) = data.allowed_mentions.as_ref() {
+            validate_allowed_mentions(allowed_mentions)?;
+        }
+
+        if let Some(components) = self.components {
+            let components = components.build().map_err(|source| {
+                InteractionResponseDataValidationError::ComponentsInvalid { source }
+            })?;
+
+            data.components = Some(components);
+        }
+
+        Ok(data)
+    }
+}
+
+fn validate_allowed_mentions(
+    allowed_mentions: &AllowedMentions,
+) -> Result<(), InteractionResponseDataValidationError> {
+    let parse = &allowed_mentions.parse;
+
+    let conflicting = (parse.contains(&MentionType::Roles) && !allowed_mentions.roles.is_empty())
+        || (parse.contains(&MentionType::Users) && !allowed_mentions.users.is_empty());
+
+    if conflicting
+        || allowed_mentions.roles.len() > ALLOWED_MENTIONS_MAX
+        || allowed_mentions.users.len() > ALLOWED_MENTIONS_MAX
+    {
+        return Err(InteractionResponseDataValidationError::AllowedMentionsInvalid);
+    }
+
+    Ok(())
+}
+
+fn validate_embeds(embeds: &[Embed]) -> Result<(), InteractionResponseDataValidationError> {
+    if embeds.len() > EMBEDS_MAX {
+        return Err(InteractionResponseDataValidationError::EmbedCountInvalid {
+            count: embeds.len(),
+        });
+    }
+
+    let length = embeds.iter().map(embed_length).sum();
+
+    if length > EMBEDS_LENGTH_MAX {
+        return Err(InteractionResponseDataValidationError::EmbedLengthInvalid { length });
+    }
+
+    Ok(())
+}
+
+/// Length in characters of the text of an embed that counts towards its
+/// limit.
+fn embed_length(embed: &Embed) -> usize {
+    let author = embed
+        .author
+        .as_ref()
+        .and_then(|author| author.name.as_ref());
+    let footer = embed.footer.as_ref().map(|footer| &footer.text);
+
+    let text = author
+        .into_iter()
+        .chain(embed.description.as_ref())
+        .chain(footer)
+        .chain(embed.title.as_ref())
+        .map(|text| text.chars().count())
+        .sum::<usize>();
+    let fields = embed
+        .fields
+        .iter()
+        .map(|field| field.name.chars().count() + field.value.chars().count())
+        .sum::<usize>();
+
+    text + fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InteractionResponseDataBuilder, InteractionResponseDataValidationError};
+    use crate::builder::component::{
+        ActionRowBuilder, ComponentValidationError, ComponentsBuilder,
+    };
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        channel::{
+            embed::{Embed, EmbedField},
+            message::{AllowedMentions, MentionType, MessageFlags},
+        },
+        id::UserId,
+    };
+
+    assert_fields!(InteractionResponseDataValidationError::ComponentsInvalid: source);
+    assert_fields!(InteractionResponseDataValidationError::ContentInvalid: content);
+    assert_fields!(InteractionResponseDataValidationError::EmbedCountInvalid: count);
+    assert_fields!(InteractionResponseDataValidationError::EmbedLengthInvalid: length);
+    assert_fields!(InteractionResponseDataValidationError::FlagsInvalid: flags);
+    assert_impl_all!(
+        InteractionResponseDataBuilder: Clone,
+        Debug,
+        Default,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+    assert_impl_all!(
+        InteractionResponseDataValidationError: Clone,
+        Debug,
+        Error,
+        Eq,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    fn embed(description: &str) -> Embed {
+        Embed {
+            author: None,
+            color: None,
+            description: Some(description.to_owned()),
+            fields: vec![EmbedField {
+                inline: false,
+                name: "name".to_owned(),
+                value: "value".to_owned(),
+            }],
+            footer: None,
+            image: None,
+            kind: "rich".to_owned(),
+            provider: None,
+            thumbnail: None,
+            timestamp: None,
+            title: None,
+            url: None,
+            video: None,
+        }
+    }
+
+    #[test]
+    fn test_build() {
+        let data = InteractionResponseDataBuilder::new()
+            .content("content")
+            .embed(embed("description"))
+            .flags(MessageFlags::EPHEMERAL)
+            .tts(false)
+            .build()
+            .unwrap();
+
+        assert_eq!(Some("content"), data.content.as_deref());
+        assert_eq!(1, data.embeds.unwrap().len());
+        assert_eq!(Some(MessageFlags::EPHEMERAL), data.flags);
+        assert!(data.allowed_mentions.is_none());
+        assert!(data.components.is_none());
+    }
+
+    #[test]
+    fn test_content() {
+        assert!(InteractionResponseDataBuilder::new()
+            .content("a".repeat(2000))
+            .build()
+            .is_ok());
+        assert!(matches!(
+            InteractionResponseDataBuilder::new()
+                .content("a".repeat(2001))
+                .build(),
+            Err(InteractionResponseDataValidationError::ContentInvalid { .. })
+        ));
+    }
+
+    #[test]
+    fn test_embeds() {
+        let builder = (0..11).fold(InteractionResponseDataBuilder::new(), |builder, _| {
+            builder.embed(embed("description"))
+        });
+
+        assert_eq!(
+            InteractionResponseDataValidationError::EmbedCountInvalid { count: 11 },
+            builder.build().unwrap_err()
+        );
+
+        let builder = InteractionResponseDataBuilder::new()
+            .embed(embed(&"a".repeat(3000)))
+            .embed(embed(&"a".repeat(3000)));
+
+        assert_eq!(
+            InteractionResponseDataValidationError::EmbedLengthInvalid { length: 6018 },
+            builder.build().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_flags() {
+        assert!(InteractionResponseDataBuilder::new()
+            .flags(MessageFlags::EPHEMERAL | MessageFlags::SUPPRESS_EMBEDS)
+            .build()
+            .is_ok());
+        assert_eq!(
+            InteractionResponseDataValidationError::FlagsInvalid {
+                flags: MessageFlags::URGENT,
+            },
+            InteractionResponseDataBuilder::new()
+                .flags(MessageFlags::URGENT)
+                .build()
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_allowed_mentions() {
+        let conflicting = AllowedMentions {
+            parse: vec![MentionType::Users],
+            users: vec![UserId(1)],
+            ..AllowedMentions::default()
+        };
+
+        assert_eq!(
+            InteractionResponseDataValidationError::AllowedMentionsInvalid,
+            InteractionResponseDataBuilder::new()
+                .allowed_mentions(conflicting)
+                .build()
+                .unwrap_err()
+        );
+
+        let explicit = AllowedMentions {
+            parse: vec![MentionType::Everyone],
+            users: vec![UserId(1)],
+            ..AllowedMentions::default()
+        };
+
+        assert!(InteractionResponseDataBuilder::new()
+            .allowed_mentions(explicit)
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_components() {
+        let data = InteractionResponseDataBuilder::new()
+            .components(ComponentsBuilder::new())
+            .build()
+            .unwrap();
+
+        assert_eq!(Some(Vec::new()), data.components);
+
+        let rows = (0..6).fold(ComponentsBuilder::new(), |builder, _| {
+            builder.row(ActionRowBuilder::new())
+        });
+
+        assert!(matches!(
+            InteractionResponseDataBuilder::new()
+                .components(rows)
+                .build(),
+            Err(InteractionResponseDataValidationError::ComponentsInvalid {
+                source: ComponentValidationError::ActionRowCountInvalid { count: 6 },
+            })
+        ));
+    }
+}
@@ -0,0 +1,6 @@
+//! Builders for models that are sent to Discord, validating their limits
+//! before they're sent rather than letting Discord reject them.
+
+pub mod command;
+pub mod component;
+pub mod interaction_response;
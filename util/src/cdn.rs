@@ -0,0 +1,469 @@
+//! Build URLs to images hosted on Discord's CDN.
+//!
+//! Start with one of the constructors of [`CdnUrlBuilder`], such as
+//! [`CdnUrlBuilder::user_avatar`], optionally select an [`ImageFormat`] and
+//! size, and then build the URL.
+//!
+//! [`CdnUrlBuilder`]: struct.CdnUrlBuilder.html
+//! [`CdnUrlBuilder::user_avatar`]: struct.CdnUrlBuilder.html#method.user_avatar
+//! [`ImageFormat`]: enum.ImageFormat.html
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::id::{EmojiId, GuildId, RoleId, UserId};
+
+/// Base URL of Discord's CDN.
+const BASE: &str = "https://cdn.discordapp.com";
+
+/// Minimum size of an image in pixels.
+const SIZE_MIN: u16 = 16;
+
+/// Maximum size of an image in pixels.
+const SIZE_MAX: u16 = 4096;
+
+/// Whether an image hash is of an animated image.
+///
+/// Hashes of animated images are prefixed with `a_`.
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_util::cdn;
+///
+/// assert!(cdn::is_animated("a_1269e74af4df7417b13759eae50c83dc"));
+/// assert!(!cdn::is_animated("1269e74af4df7417b13759eae50c83dc"));
+/// ```
+#[must_use]
+pub fn is_animated(hash: &str) -> bool {
+    hash.starts_with("a_")
+}
+
+/// Building a CDN URL failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CdnUrlError {
+    /// Format isn't supported by the type of image.
+    ///
+    /// For example, only animated images may be a GIF and default avatars may
+    /// only be a PNG.
+    FormatUnsupported {
+        /// Provided format.
+        format: ImageFormat,
+    },
+    /// Size isn't a power of two between 16 and 4096.
+    SizeInvalid {
+        /// Provided size.
+        size: u16,
+    },
+}
+
+impl Display for CdnUrlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::FormatUnsupported { format } => f.write_fmt(format_args!(
+                "the {} format is not supported by the image",
+                format.extension(),
+            )),
+            Self::SizeInvalid { size } => f.write_fmt(format_args!(
+                "the size {} is not a power of two between {} and {}",
+                size, SIZE_MIN, SIZE_MAX
+            )),
+        }
+    }
+}
+
+impl Error for CdnUrlError {}
+
+/// Format of an image.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    /// GIF, only supported by animated images.
+    Gif,
+    /// JPEG.
+    Jpeg,
+    /// PNG.
+    Png,
+    /// WebP.
+    WebP,
+}
+
+impl ImageFormat {
+    /// File extension of the format.
+    #[must_use]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::WebP => "webp",
+        }
+    }
+
+    /// MIME type of the format, such as `image/png`.
+    #[must_use]
+    pub const fn mime_type(self) -> &'static str {
+        match self {
+            Self::Gif => "image/gif",
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::WebP => "image/webp",
+        }
+    }
+}
+
+/// Kind of image, determining which formats it supports.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ImageKind {
+    /// Image that may be animated, such as an avatar or icon.
+    Image { animated: bool },
+    /// Default avatar, which is only a PNG.
+    DefaultAvatar,
+    /// Sticker, which is either a PNG or a GIF.
+    Sticker,
+}
+
+/// Builder for the URL of an image hosted on Discord's CDN.
+///
+/// If no format is selected then animated images are GIFs and all others
+/// are PNGs.
+///
+/// # Examples
+///
+/// Build the URL to a user's avatar as a 256 pixel WebP:
+///
+/// ```rust
+/// use twilight_model::id::UserId;
+/// use twilight_util::cdn::{CdnUrlBuilder, ImageFormat};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let url = CdnUrlBuilder::user_avatar(UserId(123), "1269e74af4df7417b13759eae50c83dc")
+///     .format(ImageFormat::WebP)
+///     .size(256)
+///     .build()?;
+///
+/// assert_eq!(
+///     "https://cdn.discordapp.com/avatars/123/1269e74af4df7417b13759eae50c83dc.webp?size=256",
+///     url,
+/// );
+/// # Ok(()) }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "the builder must be built to get the URL"]
+pub struct CdnUrlBuilder {
+    format: Option<ImageFormat>,
+    kind: ImageKind,
+    path: String,
+    size: Option<u16>,
+}
+
+impl CdnUrlBuilder {
+    fn new(path: String, kind: ImageKind) -> Self {
+        Self {
+            format: None,
+            kind,
+            path,
+            size: None,
+        }
+    }
+
+    fn hashed(path: String, hash: &str) -> Self {
+        Self::new(
+            path,
+            ImageKind::Image {
+                animated: is_animated(hash),
+            },
+        )
+    }
+
+    /// URL to the default avatar of a user, derived from their discriminator.
+    pub fn default_avatar(discriminator: u16) -> Self {
+        Self::new(
+            format!("embed/avatars/{}", discriminator % 5),
+            ImageKind::DefaultAvatar,
+        )
+    }
+
+    /// URL to an emoji.
+    ///
+    /// Unlike other images, whether an emoji is animated isn't part of its
+    /// ID, so it must be provided.
+    pub fn emoji(emoji_id: EmojiId, animated: bool) -> Self {
+        Self::new(
+            format!("emojis/{}", emoji_id),
+            ImageKind::Image { animated },
+        )
+    }
+
+    /// URL to the banner of a guild.
+    pub fn guild_banner(guild_id: GuildId, hash: &str) -> Self {
+        Self::hashed(format!("banners/{}/{}", guild_id, hash), hash)
+    }
+
+    /// URL to the icon of a guild.
+    pub fn guild_icon(guild_id: GuildId, hash: &str) -> Self {
+        Self::hashed(format!("icons/{}/{}", guild_id, hash), hash)
+    }
+
+    /// URL to the invite splash of a guild.
+    pub fn guild_splash(guild_id: GuildId, hash: &str) -> Self {
+        Self::hashed(format!("splashes/{}/{}", guild_id, hash), hash)
+    }
+
+    /// URL to the guild-specific avatar of a member.
+    pub fn member_avatar(guild_id: GuildId, user_id: UserId, hash: &str) -> Self {
+        Self::hashed(
+            format!("guilds/{}/users/{}/avatars/{}", guild_id, user_id, hash),
+            hash,
+        )
+    }
+
+    /// URL to the icon of a role.
+    pub fn role_icon(role_id: RoleId, hash: &str) -> Self {
+        Self::hashed(format!("role-icons/{}/{}", role_id, hash), hash)
+    }
+
+    /// URL to a sticker by its ID.
+    ///
+    /// Stickers are either a PNG or a GIF.
+    pub fn sticker(sticker_id: u64) -> Self {
+        Self::new(format!("stickers/{}", sticker_id), ImageKind::Sticker)
+    }
+
+    /// URL to the avatar of a user.
+    pub fn user_avatar(user_id: UserId, hash: &str) -> Self {
+        Self::hashed(format!("avatars/{}/{}", user_id, hash), hash)
+    }
+
+    /// Set the format of the image.
+    ///
+    /// Whether the image supports the format is checked when building.
+    pub fn format(mut self, format: ImageFormat) -> Self {
+        self.format.replace(format);
+
+        self
+    }
+
+    /// Set the size of the image in pixels.
+    ///
+    /// Sizes must be a power of two between 16 and 4096, which is checked
+    /// when building.
+    pub fn size(mut self, size: u16) -> Self {
+        self.size.replace(size);
+
+        self
+    }
+
+    /// Build the URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CdnUrlError::FormatUnsupported`] if the format isn't
+    /// supported by the image.
+    ///
+    /// Returns [`CdnUrlError::SizeInvalid`] if the size isn't a power of two
+    /// between 16 and 4096.
+    ///
+    /// [`CdnUrlError::FormatUnsupported`]: enum.CdnUrlError.html#variant.FormatUnsupported
+    /// [`CdnUrlError::SizeInvalid`]: enum.CdnUrlError.html#variant.SizeInvalid
+    pub fn build(self) -> Result<String, CdnUrlError> {
+        let format = self.format.unwrap_or(match self.kind {
+            ImageKind::Image { animated: true } => ImageFormat::Gif,
+            _ => ImageFormat::Png,
+        });
+
+        let supported = match self.kind {
+            ImageKind::DefaultAvatar => format == ImageFormat::Png,
+            ImageKind::Image { animated } => animated || format != ImageFormat::Gif,
+            ImageKind::Sticker => matches!(format, ImageFormat::Gif | ImageFormat::Png),
+        };
+
+        if !supported {
+            return Err(CdnUrlError::FormatUnsupported { format });
+        }
+
+        let mut url = format!("{}/{}.{}", BASE, self.path, format.extension());
+
+        if let Some(size) = self.size {
+            if !(SIZE_MIN..=SIZE_MAX).contains(&size) || !size.is_power_of_two() {
+                return Err(CdnUrlError::SizeInvalid { size });
+            }
+
+            url.push_str("?size=");
+            url.push_str(&size.to_string());
+        }
+
+        Ok(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CdnUrlBuilder, CdnUrlError, ImageFormat};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::id::{EmojiId, GuildId, RoleId, UserId};
+
+    assert_fields!(CdnUrlError::FormatUnsupported: format);
+    assert_fields!(CdnUrlError::SizeInvalid: size);
+    assert_impl_all!(CdnUrlBuilder: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(CdnUrlError: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ImageFormat: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
+
+    const HASH: &str = "1269e74af4df7417b13759eae50c83dc";
+    const HASH_ANIMATED: &str = "a_1269e74af4df7417b13759eae50c83dc";
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(
+            "https://cdn.discordapp.com/embed/avatars/2.png",
+            CdnUrlBuilder::default_avatar(1337).build().unwrap(),
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/emojis/1.png",
+            CdnUrlBuilder::emoji(EmojiId(1), false).build().unwrap(),
+        );
+        assert_eq!(
+            format!("https://cdn.discordapp.com/banners/1/{}.png", HASH),
+            CdnUrlBuilder::guild_banner(GuildId(1), HASH)
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            format!("https://cdn.discordapp.com/icons/1/{}.png", HASH),
+            CdnUrlBuilder::guild_icon(GuildId(1), HASH).build().unwrap(),
+        );
+        assert_eq!(
+            format!("https://cdn.discordapp.com/splashes/1/{}.png", HASH),
+            CdnUrlBuilder::guild_splash(GuildId(1), HASH)
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            format!(
+                "https://cdn.discordapp.com/guilds/1/users/2/avatars/{}.png",
+                HASH
+            ),
+            CdnUrlBuilder::member_avatar(GuildId(1), UserId(2), HASH)
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            format!("https://cdn.discordapp.com/role-icons/1/{}.png", HASH),
+            CdnUrlBuilder::role_icon(RoleId(1), HASH).build().unwrap(),
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/stickers/1.png",
+            CdnUrlBuilder::sticker(1).build().unwrap(),
+        );
+        assert_eq!(
+            format!("https://cdn.discordapp.com/avatars/1/{}.png", HASH),
+            CdnUrlBuilder::user_avatar(UserId(1), HASH).build().unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_animated() {
+        assert_eq!(
+            format!("https://cdn.discordapp.com/avatars/1/{}.gif", HASH_ANIMATED),
+            CdnUrlBuilder::user_avatar(UserId(1), HASH_ANIMATED)
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            format!(
+                "https://cdn.discordapp.com/avatars/1/{}.webp",
+                HASH_ANIMATED
+            ),
+            CdnUrlBuilder::user_avatar(UserId(1), HASH_ANIMATED)
+                .format(ImageFormat::WebP)
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/emojis/1.gif",
+            CdnUrlBuilder::emoji(EmojiId(1), true).build().unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_format_unsupported() {
+        assert_eq!(
+            CdnUrlError::FormatUnsupported {
+                format: ImageFormat::Gif,
+            },
+            CdnUrlBuilder::user_avatar(UserId(1), HASH)
+                .format(ImageFormat::Gif)
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            CdnUrlError::FormatUnsupported {
+                format: ImageFormat::Jpeg,
+            },
+            CdnUrlBuilder::default_avatar(1)
+                .format(ImageFormat::Jpeg)
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            CdnUrlError::FormatUnsupported {
+                format: ImageFormat::WebP,
+            },
+            CdnUrlBuilder::sticker(1)
+                .format(ImageFormat::WebP)
+                .build()
+                .unwrap_err(),
+        );
+        assert_eq!(
+            "https://cdn.discordapp.com/stickers/1.gif",
+            CdnUrlBuilder::sticker(1)
+                .format(ImageFormat::Gif)
+                .build()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_size() {
+        assert_eq!(
+            "https://cdn.discordapp.com/emojis/1.jpg?size=16",
+            CdnUrlBuilder::emoji(EmojiId(1), false)
+                .format(ImageFormat::Jpeg)
+                .size(16)
+                .build()
+                .unwrap(),
+        );
+        assert!(CdnUrlBuilder::emoji(EmojiId(1), false)
+            .size(4096)
+            .build()
+            .is_ok());
+
+        for size in &[0, 8, 100, 8192] {
+            assert_eq!(
+                CdnUrlError::SizeInvalid { size: *size },
+                CdnUrlBuilder::emoji(EmojiId(1), false)
+                    .size(*size)
+                    .build()
+                    .unwrap_err(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "the gif format is not supported by the image",
+            CdnUrlError::FormatUnsupported {
+                format: ImageFormat::Gif
+            }
+            .to_string(),
+        );
+        assert_eq!(
+            "the size 100 is not a power of two between 16 and 4096",
+            CdnUrlError::SizeInvalid { size: 100 }.to_string(),
+        );
+    }
+}
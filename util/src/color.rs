@@ -0,0 +1,266 @@
+//! Colors of embeds and roles.
+//!
+//! Discord represents colors as integers with the red, green, and blue
+//! components in the lowest three bytes, such as `0x5865F2`. [`Color`] can
+//! be created from a hex string or components and converted back to them.
+//!
+//! # Examples
+//!
+//! Parse a hex string and use it as the color of an embed:
+//!
+//! ```rust
+//! use twilight_util::color::Color;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let color = "#5865F2".parse::<Color>()?;
+//!
+//! assert_eq!(Color::BLURPLE, color);
+//! assert_eq!((0x58, 0x65, 0xF2), color.rgb());
+//!
+//! let embed_color: u32 = color.into();
+//! assert_eq!(0x0058_65F2, embed_color);
+//! # Ok(()) }
+//! ```
+//!
+//! [`Color`]: struct.Color.html
+
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+/// Maximum value of a color.
+pub const COLOR_MAX: u32 = 0x00FF_FFFF;
+
+/// Creating a color failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ColorError {
+    /// String isn't a hex color of 6 digits, optionally prefixed with `#`.
+    HexInvalid {
+        /// Provided string.
+        hex: String,
+    },
+    /// Value is greater than `0xFFFFFF`.
+    ValueInvalid {
+        /// Provided value.
+        value: u32,
+    },
+}
+
+impl Display for ColorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::HexInvalid { hex } => {
+                f.write_fmt(format_args!("`{}` is not a hex color of 6 digits", hex))
+            }
+            Self::ValueInvalid { value } => f.write_fmt(format_args!(
+                "{:#x} is greater than {:#x}",
+                value, COLOR_MAX
+            )),
+        }
+    }
+}
+
+impl Error for ColorError {}
+
+/// Color of an embed or role.
+///
+/// A role with a color of [`Color::DEFAULT`] doesn't affect the color of its
+/// members' names.
+///
+/// [`Color::DEFAULT`]: #associatedconstant.DEFAULT
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Color(u32);
+
+impl Color {
+    /// Black from Discord's brand colors.
+    pub const BLACK: Self = Self(0x0023_272A);
+
+    /// Blurple from Discord's brand colors.
+    pub const BLURPLE: Self = Self(0x0058_65F2);
+
+    /// Default color of roles, which has a value of 0.
+    pub const DEFAULT: Self = Self(0);
+
+    /// Fuchsia from Discord's brand colors.
+    pub const FUCHSIA: Self = Self(0x00EB_459E);
+
+    /// Green from Discord's brand colors.
+    pub const GREEN: Self = Self(0x0057_F287);
+
+    /// Blurple from Discord's brand colors before 2021.
+    pub const LEGACY_BLURPLE: Self = Self(0x0072_89DA);
+
+    /// Red from Discord's brand colors.
+    pub const RED: Self = Self(0x00ED_4245);
+
+    /// White from Discord's brand colors.
+    pub const WHITE: Self = Self(0x00FF_FFFF);
+
+    /// Yellow from Discord's brand colors.
+    pub const YELLOW: Self = Self(0x00FE_E75C);
+
+    /// Create a color from its value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ColorError::ValueInvalid`] if the value is greater than
+    /// `0xFFFFFF`.
+    ///
+    /// [`ColorError::ValueInvalid`]: enum.ColorError.html#variant.ValueInvalid
+    pub const fn new(value: u32) -> Result<Self, ColorError> {
+        if value > COLOR_MAX {
+            return Err(ColorError::ValueInvalid { value });
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Create a color from its red, green, and blue components.
+    #[must_use]
+    pub const fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self(u32::from_be_bytes([0, red, green, blue]))
+    }
+
+    /// Parse a color from a hex string of 6 digits, optionally prefixed with
+    /// `#`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_util::color::Color;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// assert_eq!(Color::from_rgb(255, 0, 136), Color::parse_hex("ff0088")?);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ColorError::HexInvalid`] if the string isn't a hex color of
+    /// 6 digits.
+    ///
+    /// [`ColorError::HexInvalid`]: enum.ColorError.html#variant.HexInvalid
+    pub fn parse_hex(hex: &str) -> Result<Self, ColorError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+
+        if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ColorError::HexInvalid {
+                hex: hex.to_owned(),
+            });
+        }
+
+        u32::from_str_radix(digits, 16)
+            .map(Self)
+            .map_err(|_| ColorError::HexInvalid {
+                hex: hex.to_owned(),
+            })
+    }
+
+    /// Red, green, and blue components of the color.
+    #[must_use]
+    pub const fn rgb(self) -> (u8, u8, u8) {
+        let [_, red, green, blue] = self.0.to_be_bytes();
+
+        (red, green, blue)
+    }
+
+    /// Value of the color, as used by embeds and roles.
+    #[must_use]
+    pub const fn value(self) -> u32 {
+        self.0
+    }
+}
+
+/// Formats the color as a hex string prefixed with `#`, such as `#5865F2`.
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_fmt(format_args!("#{:06X}", self.0))
+    }
+}
+
+impl From<Color> for u32 {
+    fn from(color: Color) -> Self {
+        color.0
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_hex(s)
+    }
+}
+
+impl TryFrom<u32> for Color {
+    type Error = ColorError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, ColorError};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{convert::TryFrom, error::Error, fmt::Debug, hash::Hash, str::FromStr};
+
+    assert_fields!(ColorError::HexInvalid: hex);
+    assert_fields!(ColorError::ValueInvalid: value);
+    assert_impl_all!(
+        Color: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        FromStr,
+        Hash,
+        Ord,
+        PartialEq,
+        Send,
+        Sync,
+        TryFrom<u32>
+    );
+    assert_impl_all!(ColorError: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_new() {
+        assert_eq!(0x00FF_FFFF, Color::new(0x00FF_FFFF).unwrap().value());
+        assert_eq!(
+            ColorError::ValueInvalid { value: 0x0100_0000 },
+            Color::try_from(0x0100_0000).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_rgb() {
+        let color = Color::from_rgb(0x12, 0x34, 0x56);
+
+        assert_eq!(0x0012_3456, u32::from(color));
+        assert_eq!((0x12, 0x34, 0x56), color.rgb());
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(Color::BLURPLE, Color::parse_hex("#5865F2").unwrap());
+        assert_eq!(Color::BLURPLE, "5865f2".parse().unwrap());
+        assert_eq!("#5865F2", Color::BLURPLE.to_string());
+        assert_eq!("#000000", Color::DEFAULT.to_string());
+
+        for hex in &[
+            "", "#", "#5865F", "#5865F2F", "+5865F2", "#5865G2", "##5865F2",
+        ] {
+            assert_eq!(
+                ColorError::HexInvalid {
+                    hex: (*hex).to_owned(),
+                },
+                Color::parse_hex(hex).unwrap_err()
+            );
+        }
+    }
+}
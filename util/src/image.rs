@@ -0,0 +1,248 @@
+//! Encode images as data URIs for the image fields of HTTP requests, such as
+//! the avatar of the current user or the image of an emoji.
+//!
+//! The format of an image is detected from its first bytes, and the image is
+//! validated against the formats and size that Discord accepts for what it's
+//! being uploaded as.
+//!
+//! # Examples
+//!
+//! Encode a PNG to set as the image of an emoji:
+//!
+//! ```rust
+//! use twilight_util::image::{self, ImageTarget};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let png = b"\x89PNG\r\n\x1a\n";
+//! let uri = image::data_uri(png, ImageTarget::Emoji)?;
+//!
+//! assert_eq!("data:image/png;base64,iVBORw0KGgo=", uri);
+//! # Ok(()) }
+//! ```
+
+use crate::cdn::ImageFormat;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Encoding an image as a data URI failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ImageError {
+    /// The format of the image isn't PNG, JPEG, GIF, or WebP.
+    FormatUnknown,
+    /// The format of the image isn't supported by the target.
+    FormatUnsupported {
+        /// Detected format.
+        format: ImageFormat,
+        /// Provided target.
+        target: ImageTarget,
+    },
+    /// The image is larger than the target allows.
+    SizeInvalid {
+        /// Maximum size of the target in bytes.
+        max: usize,
+        /// Size of the image in bytes.
+        size: usize,
+    },
+}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::FormatUnknown => f.write_str("the format of the image is unknown"),
+            Self::FormatUnsupported { format, target } => f.write_fmt(format_args!(
+                "the {} format is not supported by {}",
+                format.extension(),
+                target.name(),
+            )),
+            Self::SizeInvalid { max, size } => f.write_fmt(format_args!(
+                "the image is {} bytes but at most {} are allowed",
+                size, max
+            )),
+        }
+    }
+}
+
+impl Error for ImageError {}
+
+/// What an image is uploaded as, determining its supported formats and
+/// maximum size.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ImageTarget {
+    /// Avatar of a user or webhook, or the icon, banner, or splash of a
+    /// guild.
+    ///
+    /// May be in any format and up to 10 MiB.
+    Avatar,
+    /// Custom emoji.
+    ///
+    /// May be in any format and up to 256 KiB.
+    Emoji,
+    /// Sticker.
+    ///
+    /// May be a PNG or a GIF and up to 512 KiB.
+    Sticker,
+}
+
+impl ImageTarget {
+    /// Maximum size of an image in bytes.
+    #[must_use]
+    pub const fn max_size(self) -> usize {
+        match self {
+            Self::Avatar => 10 * 1024 * 1024,
+            Self::Emoji => 256 * 1024,
+            Self::Sticker => 512 * 1024,
+        }
+    }
+
+    /// Name of the target.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Avatar => "avatars",
+            Self::Emoji => "emojis",
+            Self::Sticker => "stickers",
+        }
+    }
+
+    /// Whether an image format is supported.
+    #[must_use]
+    pub const fn supports(self, format: ImageFormat) -> bool {
+        match self {
+            Self::Avatar | Self::Emoji => true,
+            Self::Sticker => matches!(format, ImageFormat::Gif | ImageFormat::Png),
+        }
+    }
+}
+
+/// Detect the format of an image from its magic bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_util::{cdn::ImageFormat, image};
+///
+/// assert_eq!(Some(ImageFormat::Gif), image::sniff(b"GIF89a..."));
+/// assert_eq!(None, image::sniff(b"not an image"));
+/// ```
+#[must_use]
+pub fn sniff(data: &[u8]) -> Option<ImageFormat> {
+    match data {
+        [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n', ..] => Some(ImageFormat::Png),
+        [0xFF, 0xD8, 0xFF, ..] => Some(ImageFormat::Jpeg),
+        [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some(ImageFormat::Gif),
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some(ImageFormat::WebP),
+        _ => None,
+    }
+}
+
+/// Encode an image as a base64 data URI, such as
+/// `data:image/png;base64,...`, after validating it for a target.
+///
+/// # Errors
+///
+/// Returns [`ImageError::FormatUnknown`] if the format of the image couldn't
+/// be detected.
+///
+/// Returns [`ImageError::FormatUnsupported`] if the target doesn't support
+/// the format of the image.
+///
+/// Returns [`ImageError::SizeInvalid`] if the image is larger than the
+/// target allows.
+///
+/// [`ImageError::FormatUnknown`]: enum.ImageError.html#variant.FormatUnknown
+/// [`ImageError::FormatUnsupported`]: enum.ImageError.html#variant.FormatUnsupported
+/// [`ImageError::SizeInvalid`]: enum.ImageError.html#variant.SizeInvalid
+pub fn data_uri(data: &[u8], target: ImageTarget) -> Result<String, ImageError> {
+    let format = sniff(data).ok_or(ImageError::FormatUnknown)?;
+
+    if !target.supports(format) {
+        return Err(ImageError::FormatUnsupported { format, target });
+    }
+
+    let max = target.max_size();
+
+    if data.len() > max {
+        return Err(ImageError::SizeInvalid {
+            max,
+            size: data.len(),
+        });
+    }
+
+    Ok(format!(
+        "data:{};base64,{}",
+        format.mime_type(),
+        base64::encode(data)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ImageError, ImageTarget};
+    use crate::cdn::ImageFormat;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug, hash::Hash};
+
+    assert_fields!(ImageError::FormatUnsupported: format, target);
+    assert_fields!(ImageError::SizeInvalid: max, size);
+    assert_impl_all!(ImageError: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ImageTarget: Clone, Copy, Debug, Eq, Hash, PartialEq, Send, Sync);
+
+    const GIF: &[u8] = b"GIF87a\x01\x00\x01\x00";
+    const JPEG: &[u8] = b"\xFF\xD8\xFF\xE0";
+    const PNG: &[u8] = b"\x89PNG\r\n\x1A\n";
+    const WEBP: &[u8] = b"RIFF\x24\x00\x00\x00WEBPVP8 ";
+
+    #[test]
+    fn test_sniff() {
+        assert_eq!(Some(ImageFormat::Gif), super::sniff(GIF));
+        assert_eq!(Some(ImageFormat::Jpeg), super::sniff(JPEG));
+        assert_eq!(Some(ImageFormat::Png), super::sniff(PNG));
+        assert_eq!(Some(ImageFormat::WebP), super::sniff(WEBP));
+        assert_eq!(None, super::sniff(b""));
+        assert_eq!(None, super::sniff(b"GIF88a"));
+        assert_eq!(None, super::sniff(b"RIFF\x24\x00\x00\x00WAVE"));
+    }
+
+    #[test]
+    fn test_data_uri() {
+        assert_eq!(
+            "data:image/gif;base64,R0lGODdhAQABAA==",
+            super::data_uri(GIF, ImageTarget::Sticker).unwrap()
+        );
+        assert_eq!(
+            "data:image/jpeg;base64,/9j/4A==",
+            super::data_uri(JPEG, ImageTarget::Avatar).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_data_uri_invalid() {
+        assert_eq!(
+            ImageError::FormatUnknown,
+            super::data_uri(b"text", ImageTarget::Avatar).unwrap_err()
+        );
+        assert_eq!(
+            ImageError::FormatUnsupported {
+                format: ImageFormat::Jpeg,
+                target: ImageTarget::Sticker,
+            },
+            super::data_uri(JPEG, ImageTarget::Sticker).unwrap_err()
+        );
+
+        let mut large = PNG.to_vec();
+        large.resize(256 * 1024 + 1, 0);
+
+        assert_eq!(
+            ImageError::SizeInvalid {
+                max: 256 * 1024,
+                size: 256 * 1024 + 1,
+            },
+            super::data_uri(&large, ImageTarget::Emoji).unwrap_err()
+        );
+        assert!(super::data_uri(&large, ImageTarget::Avatar).is_ok());
+    }
+}
@@ -7,10 +7,47 @@
 //!
 //! ## Features
 //!
+//! ### `builder`
+//!
+//! Allows the use of builders for models that are sent to Discord, such as
+//! application commands, message components, and interaction responses, which
+//! validate the models before they're sent.
+//!
+//! ### `cdn`
+//!
+//! Allows the use of a builder for the URLs of images hosted on Discord's
+//! CDN, such as avatars, icons, and emojis.
+//!
+//! ### `color`
+//!
+//! Allows the use of a type for the colors of embeds and roles, which can be
+//! converted to and from hex strings and RGB components.
+//!
+//! ### `image`
+//!
+//! Allows the use of functions to detect the formats of images and encode them
+//! as data URIs for uploading, such as for avatars and emojis. Enables the
+//! `cdn` feature.
+//!
+//! ### `link`
+//!
+//! Allows the use of a builder and parser for links to messages.
+//!
+//! ### `markdown`
+//!
+//! Allows the use of functions to escape markdown and sanitize mentions in user
+//! provided content.
+//!
+//! ### `permission-calculator`
+//!
+//! Allows the use of a calculator to determine the permissions of a member in
+//! a guild or a channel.
+//!
 //! ### `snowflake`
 //!
 //! Allows the use of the `Snowflake` trait, which provides methods for the extraction of
-//! structured information from [Discord snowflakes].
+//! structured information from [Discord snowflakes], and of a generator of unique
+//! Snowflake-style nonces.
 //!
 //! [`twilight-rs`]: https://github.com/twilight-rs/twilight
 //! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
@@ -35,6 +72,34 @@
 )]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "builder")]
+#[cfg_attr(docsrs, doc(cfg(feature = "builder")))]
+pub mod builder;
+
+#[cfg(feature = "cdn")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cdn")))]
+pub mod cdn;
+
+#[cfg(feature = "color")]
+#[cfg_attr(docsrs, doc(cfg(feature = "color")))]
+pub mod color;
+
+#[cfg(feature = "image")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+pub mod image;
+
+#[cfg(feature = "link")]
+#[cfg_attr(docsrs, doc(cfg(feature = "link")))]
+pub mod link;
+
+#[cfg(feature = "markdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
+pub mod markdown;
+
+#[cfg(feature = "permission-calculator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "permission-calculator")))]
+pub mod permission_calculator;
+
 #[cfg(feature = "snowflake")]
 #[cfg_attr(docsrs, doc(cfg(feature = "snowflake")))]
 pub mod snowflake;
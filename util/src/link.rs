@@ -0,0 +1,267 @@
+//! Build and parse links to messages.
+//!
+//! # Examples
+//!
+//! Parse a link to a message in a guild and build it back:
+//!
+//! ```rust
+//! use twilight_model::id::{ChannelId, GuildId, MessageId};
+//! use twilight_util::link::MessageLink;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let link = "https://discord.com/channels/1/2/3".parse::<MessageLink>()?;
+//!
+//! assert_eq!(Some(GuildId(1)), link.guild_id());
+//! assert_eq!(ChannelId(2), link.channel_id());
+//! assert_eq!(MessageId(3), link.message_id());
+//! assert_eq!("https://discord.com/channels/1/2/3", link.to_string());
+//! # Ok(()) }
+//! ```
+
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    num::ParseIntError,
+    str::FromStr,
+};
+use twilight_model::id::{ChannelId, GuildId, MessageId};
+
+/// Hosts that message links may have.
+const HOSTS: &[&str] = &[
+    "discord.com",
+    "discordapp.com",
+    "canary.discord.com",
+    "canary.discordapp.com",
+    "ptb.discord.com",
+    "ptb.discordapp.com",
+];
+
+/// Guild segment of links to messages in private channels.
+const PRIVATE: &str = "@me";
+
+/// Parsing a message link failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum MessageLinkParseError {
+    /// Provided string isn't a link to a message on Discord.
+    FormatInvalid,
+    /// An ID in the link isn't a u64.
+    IdInvalid {
+        /// Reason for the error.
+        source: ParseIntError,
+    },
+}
+
+impl Display for MessageLinkParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::FormatInvalid => f.write_str("the string is not a link to a message"),
+            Self::IdInvalid { .. } => f.write_str("an id in the message link is not a u64"),
+        }
+    }
+}
+
+impl Error for MessageLinkParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::FormatInvalid => None,
+            Self::IdInvalid { source } => Some(source),
+        }
+    }
+}
+
+/// Link to a message in a guild or private channel.
+///
+/// Links are displayed with the `discord.com` host, and links with the
+/// `discordapp.com` host or the Canary and PTB subdomains are parsed.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MessageLink {
+    channel: ChannelId,
+    guild: Option<GuildId>,
+    message: MessageId,
+}
+
+impl MessageLink {
+    /// Create a link to a message.
+    ///
+    /// Messages in private channels don't have a guild ID.
+    #[must_use]
+    pub const fn new(
+        guild_id: Option<GuildId>,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Self {
+        Self {
+            channel: channel_id,
+            guild: guild_id,
+            message: message_id,
+        }
+    }
+
+    /// Parse a link to a message.
+    ///
+    /// A link's query and fragment, if any, are ignored.
+    ///
+    /// # Examples
+    ///
+    /// Parse a link to a message in a private channel:
+    ///
+    /// ```rust
+    /// use twilight_util::link::MessageLink;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let link = MessageLink::parse("https://canary.discord.com/channels/@me/2/3")?;
+    /// assert!(link.guild_id().is_none());
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MessageLinkParseError::FormatInvalid`] if the string isn't a
+    /// link to a message on Discord.
+    ///
+    /// Returns [`MessageLinkParseError::IdInvalid`] if one of the IDs isn't a
+    /// u64.
+    ///
+    /// [`MessageLinkParseError::FormatInvalid`]: enum.MessageLinkParseError.html#variant.FormatInvalid
+    /// [`MessageLinkParseError::IdInvalid`]: enum.MessageLinkParseError.html#variant.IdInvalid
+    pub fn parse(link: &str) -> Result<Self, MessageLinkParseError> {
+        let rest = link
+            .strip_prefix("https://")
+            .or_else(|| link.strip_prefix("http://"))
+            .ok_or(MessageLinkParseError::FormatInvalid)?;
+        let end = rest.find(&['?', '#'][..]).unwrap_or(rest.len());
+        let segments = rest[..end]
+            .trim_end_matches('/')
+            .split('/')
+            .collect::<Vec<_>>();
+
+        match segments.as_slice() {
+            [host, "channels", guild, channel, message] if HOSTS.contains(host) => {
+                let guild = if *guild == PRIVATE {
+                    None
+                } else {
+                    Some(GuildId(parse_id(guild)?))
+                };
+
+                Ok(Self {
+                    channel: ChannelId(parse_id(channel)?),
+                    guild,
+                    message: MessageId(parse_id(message)?),
+                })
+            }
+            _ => Err(MessageLinkParseError::FormatInvalid),
+        }
+    }
+
+    /// ID of the channel the message is in.
+    #[must_use]
+    pub const fn channel_id(&self) -> ChannelId {
+        self.channel
+    }
+
+    /// ID of the guild the message is in, if it isn't in a private channel.
+    #[must_use]
+    pub const fn guild_id(&self) -> Option<GuildId> {
+        self.guild
+    }
+
+    /// ID of the message.
+    #[must_use]
+    pub const fn message_id(&self) -> MessageId {
+        self.message
+    }
+}
+
+impl Display for MessageLink {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("https://discord.com/channels/")?;
+
+        if let Some(guild_id) = self.guild {
+            Display::fmt(&guild_id, f)?;
+        } else {
+            f.write_str(PRIVATE)?;
+        }
+
+        f.write_fmt(format_args!("/{}/{}", self.channel, self.message))
+    }
+}
+
+impl FromStr for MessageLink {
+    type Err = MessageLinkParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+fn parse_id(segment: &str) -> Result<u64, MessageLinkParseError> {
+    segment
+        .parse()
+        .map_err(|source| MessageLinkParseError::IdInvalid { source })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MessageLink, MessageLinkParseError};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug, hash::Hash, str::FromStr};
+    use twilight_model::id::{ChannelId, GuildId, MessageId};
+
+    assert_fields!(MessageLinkParseError::IdInvalid: source);
+    assert_impl_all!(MessageLink: Clone, Copy, Debug, Eq, FromStr, Hash, PartialEq, Send, Sync);
+    assert_impl_all!(MessageLinkParseError: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "https://discord.com/channels/1/2/3",
+            MessageLink::new(Some(GuildId(1)), ChannelId(2), MessageId(3)).to_string()
+        );
+        assert_eq!(
+            "https://discord.com/channels/@me/2/3",
+            MessageLink::new(None, ChannelId(2), MessageId(3)).to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let expected = MessageLink::new(Some(GuildId(1)), ChannelId(2), MessageId(3));
+
+        for link in &[
+            "https://discord.com/channels/1/2/3",
+            "http://discordapp.com/channels/1/2/3/",
+            "https://ptb.discord.com/channels/1/2/3?query#fragment",
+        ] {
+            assert_eq!(expected, MessageLink::parse(link).unwrap());
+        }
+
+        assert_eq!(
+            MessageLink::new(None, ChannelId(2), MessageId(3)),
+            "https://discord.com/channels/@me/2/3"
+                .parse::<MessageLink>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for link in &[
+            "discord.com/channels/1/2/3",
+            "https://example.com/channels/1/2/3",
+            "https://discord.com/guilds/1/2/3",
+            "https://discord.com/channels/1/2",
+            "https://discord.com/channels/1/2/3/4",
+        ] {
+            assert_eq!(
+                MessageLinkParseError::FormatInvalid,
+                MessageLink::parse(link).unwrap_err()
+            );
+        }
+
+        assert!(matches!(
+            MessageLink::parse("https://discord.com/channels/1/two/3"),
+            Err(MessageLinkParseError::IdInvalid { .. })
+        ));
+    }
+}
@@ -0,0 +1,198 @@
+//! Escape Discord markdown and sanitize mentions in user provided content.
+//!
+//! When echoing content from users, such as in a quote or a log message, it
+//! should be escaped so that it's displayed as it was written rather than
+//! formatted, and sanitized so that it can't ping anyone.
+//!
+//! Sanitizing mentions is a fallback: when sending messages, prefer
+//! restricting who can be pinged via allowed mentions.
+//!
+//! # Examples
+//!
+//! Escape and sanitize the content of a message before quoting it:
+//!
+//! ```rust
+//! use twilight_util::markdown;
+//!
+//! let content = "**hello** @everyone";
+//! let quoted = format!("> {}", markdown::sanitize_mentions(&markdown::escape(content)));
+//!
+//! assert_eq!("> \\*\\*hello\\*\\* @\u{200b}everyone", quoted);
+//! ```
+
+/// Zero-width space, which breaks up syntax without being visible.
+const ZERO_WIDTH_SPACE: char = '\u{200b}';
+
+/// Characters that format text wherever they're found.
+const INLINE: &[char] = &['\\', '*', '_', '~', '`', '|', '[', ']'];
+
+/// Characters that format text when they're at the start of a line, such as
+/// block quotes, headers, and lists.
+const LINE_START: &[char] = &['>', '#', '-'];
+
+/// Escape markdown so that text is displayed as written.
+///
+/// This escapes bold, italics, underlines, strikethroughs, spoilers, inline
+/// code, code blocks, masked links, and block quotes, headers, and lists at
+/// the start of lines. Existing backslashes are escaped too.
+///
+/// To include text inside of a code block, where backslashes aren't escape
+/// characters, use [`escape_code_block`] instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_util::markdown;
+///
+/// assert_eq!("\\|\\|spoiler\\|\\|", markdown::escape("||spoiler||"));
+/// assert_eq!("\\[text\\](https://twilight.rs)", markdown::escape("[text](https://twilight.rs)"));
+/// assert_eq!("\\> not a quote", markdown::escape("> not a quote"));
+/// ```
+///
+/// [`escape_code_block`]: fn.escape_code_block.html
+#[must_use]
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut line_start = true;
+
+    for c in text.chars() {
+        if INLINE.contains(&c) || (line_start && LINE_START.contains(&c)) {
+            escaped.push('\\');
+        }
+
+        escaped.push(c);
+
+        if c == '\n' {
+            line_start = true;
+        } else if !c.is_whitespace() {
+            line_start = false;
+        }
+    }
+
+    escaped
+}
+
+/// Escape text so that it can be put inside of a code block without ending
+/// it early.
+///
+/// Backslashes aren't escape characters in code blocks, so a zero-width space
+/// is instead inserted into every sequence of three backticks.
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_util::markdown;
+///
+/// let code = "let s = \"```\";";
+/// let block = format!("```rust\n{}\n```", markdown::escape_code_block(code));
+///
+/// assert_eq!("```rust\nlet s = \"``\u{200b}`\";\n```", block);
+/// ```
+#[must_use]
+pub fn escape_code_block(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut backticks = 0;
+
+    for c in text.chars() {
+        if c == '`' {
+            backticks += 1;
+
+            if backticks == 3 {
+                escaped.push(ZERO_WIDTH_SPACE);
+                backticks = 1;
+            }
+        } else {
+            backticks = 0;
+        }
+
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Sanitize mentions of users, roles, `@everyone`, and `@here` so they
+/// don't ping anyone.
+///
+/// A zero-width space is inserted after the `@` of each mention, so they
+/// still look similar to their original text but aren't parsed as mentions.
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_util::markdown;
+///
+/// assert_eq!("<@\u{200b}123>", markdown::sanitize_mentions("<@123>"));
+/// assert_eq!("<@\u{200b}&456>", markdown::sanitize_mentions("<@&456>"));
+/// assert_eq!("@\u{200b}here", markdown::sanitize_mentions("@here"));
+/// assert_eq!("user@example.com", markdown::sanitize_mentions("user@example.com"));
+/// ```
+#[must_use]
+pub fn sanitize_mentions(text: &str) -> String {
+    let mut sanitized = String::with_capacity(text.len());
+
+    for (idx, c) in text.char_indices() {
+        sanitized.push(c);
+
+        if c != '@' {
+            continue;
+        }
+
+        let before = &text[..idx];
+        let after = &text[idx + 1..];
+
+        let mention =
+            before.ends_with('<') || after.starts_with("everyone") || after.starts_with("here");
+
+        if mention {
+            sanitized.push(ZERO_WIDTH_SPACE);
+        }
+    }
+
+    sanitized
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_escape() {
+        assert_eq!("\\*\\*bold\\*\\*", super::escape("**bold**"));
+        assert_eq!("\\_\\_underline\\_\\_", super::escape("__underline__"));
+        assert_eq!("\\~\\~strike\\~\\~", super::escape("~~strike~~"));
+        assert_eq!("\\`code\\`", super::escape("`code`"));
+        assert_eq!("\\`\\`\\`block\\`\\`\\`", super::escape("```block```"));
+        assert_eq!("back\\\\slash", super::escape("back\\slash"));
+        assert_eq!("plain text", super::escape("plain text"));
+    }
+
+    #[test]
+    fn test_escape_line_start() {
+        assert_eq!(
+            "\\> quote\n  \\# header\n\\- item",
+            super::escape("> quote\n  # header\n- item")
+        );
+        assert_eq!("a > b # c - d", super::escape("a > b # c - d"));
+    }
+
+    #[test]
+    fn test_escape_code_block() {
+        assert_eq!("plain", super::escape_code_block("plain"));
+        assert_eq!("``", super::escape_code_block("``"));
+        assert_eq!("``\u{200b}`", super::escape_code_block("```"));
+        assert_eq!("``\u{200b}``\u{200b}``", super::escape_code_block("``````"));
+        assert_eq!("`\\*`", super::escape_code_block("`\\*`"));
+    }
+
+    #[test]
+    fn test_sanitize_mentions() {
+        assert_eq!(
+            "@\u{200b}everyone and <@\u{200b}!1> and <#2>",
+            super::sanitize_mentions("@everyone and <@!1> and <#2>"),
+        );
+        assert_eq!("@someone", super::sanitize_mentions("@someone"));
+        assert_eq!(
+            "\u{e9}@\u{200b}here",
+            super::sanitize_mentions("\u{e9}@here")
+        );
+    }
+}
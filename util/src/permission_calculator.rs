@@ -0,0 +1,581 @@
+//! Calculate the permissions of a member in a guild or in one of its channels.
+//!
+//! This implements [Discord's algorithm] for computing permissions, starting
+//! from the `@everyone` role and the member's roles and applying the permission
+//! overwrites of a channel.
+//!
+//! [Discord's algorithm]: https://discord.com/developers/docs/topics/permissions#permission-overwrites
+
+use twilight_model::{
+    channel::{
+        permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+        ChannelType,
+    },
+    guild::Permissions,
+    id::{GuildId, RoleId, UserId},
+};
+
+/// Permissions that a member who is timed out keeps.
+const TIMED_OUT: Permissions = Permissions::from_bits_truncate(
+    Permissions::VIEW_CHANNEL.bits() | Permissions::READ_MESSAGE_HISTORY.bits(),
+);
+
+/// Permissions that are implicitly denied in text channels when
+/// [`SEND_MESSAGES`] is denied, or in threads when
+/// [`SEND_MESSAGES_IN_THREADS`] is denied.
+///
+/// [`SEND_MESSAGES`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+/// [`SEND_MESSAGES_IN_THREADS`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.SEND_MESSAGES_IN_THREADS
+const SEND_MESSAGES_IMPLIED: Permissions = Permissions::from_bits_truncate(
+    Permissions::ATTACH_FILES.bits()
+        | Permissions::EMBED_LINKS.bits()
+        | Permissions::MENTION_EVERYONE.bits()
+        | Permissions::SEND_TTS_MESSAGES.bits(),
+);
+
+/// Calculate the permissions of a member.
+///
+/// The calculator is created with the permissions of the guild's `@everyone`
+/// role and of the member's roles. Permissions can then be calculated at the
+/// guild level via [`root`] or in a channel via [`in_channel`].
+///
+/// The owner of the guild and members with the [`ADMINISTRATOR`] permission
+/// have all permissions. Members who are [timed out] only have the
+/// [`VIEW_CHANNEL`] and [`READ_MESSAGE_HISTORY`] permissions, unless they're
+/// the owner or an administrator.
+///
+/// # Examples
+///
+/// Calculate the permissions of a member in a channel where their role can't
+/// send messages:
+///
+/// ```
+/// use twilight_model::{
+///     channel::{
+///         permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+///         ChannelType,
+///     },
+///     guild::Permissions,
+///     id::{GuildId, RoleId, UserId},
+/// };
+/// use twilight_util::permission_calculator::PermissionCalculator;
+///
+/// let guild_id = GuildId(1);
+/// let everyone = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+/// let roles = &[(RoleId(2), Permissions::EMBED_LINKS)];
+///
+/// let overwrites = &[PermissionOverwrite {
+///     allow: Permissions::empty(),
+///     deny: Permissions::SEND_MESSAGES,
+///     kind: PermissionOverwriteType::Role(RoleId(2)),
+/// }];
+///
+/// let calculator = PermissionCalculator::new(guild_id, UserId(3), everyone, roles);
+/// let permissions = calculator.in_channel(ChannelType::GuildText, overwrites);
+///
+/// assert_eq!(Permissions::VIEW_CHANNEL, permissions);
+/// ```
+///
+/// [`ADMINISTRATOR`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.ADMINISTRATOR
+/// [`READ_MESSAGE_HISTORY`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+/// [`VIEW_CHANNEL`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.VIEW_CHANNEL
+/// [`in_channel`]: #method.in_channel
+/// [`root`]: #method.root
+/// [timed out]: #method.timed_out
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use = "calculators do nothing unless used"]
+pub struct PermissionCalculator<'a> {
+    everyone: Permissions,
+    guild_id: GuildId,
+    member_roles: &'a [(RoleId, Permissions)],
+    owner_id: Option<UserId>,
+    timed_out: bool,
+    user_id: UserId,
+}
+
+impl<'a> PermissionCalculator<'a> {
+    /// Create a calculator for a member of a guild.
+    ///
+    /// `everyone` is the permissions of the guild's `@everyone` role, and
+    /// `member_roles` is the IDs and permissions of the member's other roles.
+    pub fn new(
+        guild_id: GuildId,
+        user_id: UserId,
+        everyone: Permissions,
+        member_roles: &'a [(RoleId, Permissions)],
+    ) -> Self {
+        Self {
+            everyone,
+            guild_id,
+            member_roles,
+            owner_id: None,
+            timed_out: false,
+            user_id,
+        }
+    }
+
+    /// Set the ID of the owner of the guild.
+    ///
+    /// If the member is the owner, then they have all permissions.
+    pub fn owner_id(mut self, owner_id: UserId) -> Self {
+        self.owner_id.replace(owner_id);
+
+        self
+    }
+
+    /// Set whether the member is timed out.
+    ///
+    /// Members who are timed out only keep the [`VIEW_CHANNEL`] and
+    /// [`READ_MESSAGE_HISTORY`] permissions.
+    ///
+    /// [`READ_MESSAGE_HISTORY`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    /// [`VIEW_CHANNEL`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.VIEW_CHANNEL
+    pub fn timed_out(mut self, timed_out: bool) -> Self {
+        self.timed_out = timed_out;
+
+        self
+    }
+
+    /// Calculate the guild-level permissions of the member.
+    #[must_use]
+    pub fn root(&self) -> Permissions {
+        if self.has_all() {
+            return Permissions::all();
+        }
+
+        self.restrict(self.base())
+    }
+
+    /// Calculate the permissions of the member in a channel of the guild
+    /// with its permission overwrites.
+    ///
+    /// Overwrites are applied in order of the `@everyone` role, then the
+    /// member's roles, then the member. If the member can't view the channel,
+    /// then they have no permissions in it, and in text channels the
+    /// permissions that require sending messages are removed if they can't
+    /// send messages.
+    ///
+    /// Threads don't have their own overwrites, so for a thread the
+    /// overwrites of its parent channel must be provided. Sending messages in
+    /// a thread requires [`SEND_MESSAGES_IN_THREADS`] rather than
+    /// [`SEND_MESSAGES`]. Use [`in_thread`] to also account for the
+    /// visibility of private threads.
+    ///
+    /// [`SEND_MESSAGES`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    /// [`SEND_MESSAGES_IN_THREADS`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.SEND_MESSAGES_IN_THREADS
+    /// [`in_thread`]: #method.in_thread
+    #[must_use]
+    pub fn in_channel(
+        &self,
+        channel_type: ChannelType,
+        overwrites: &[PermissionOverwrite],
+    ) -> Permissions {
+        if self.has_all() {
+            return Permissions::all();
+        }
+
+        let mut permissions = self.base();
+
+        let everyone_id = RoleId(self.guild_id.0);
+        let mut role_allow = Permissions::empty();
+        let mut role_deny = Permissions::empty();
+        let mut member = None;
+
+        for overwrite in overwrites {
+            match overwrite.kind {
+                PermissionOverwriteType::Role(role_id) if role_id == everyone_id => {
+                    permissions.remove(overwrite.deny);
+                    permissions.insert(overwrite.allow);
+                }
+                PermissionOverwriteType::Role(role_id) => {
+                    if self.member_roles.iter().any(|(id, _)| *id == role_id) {
+                        role_allow.insert(overwrite.allow);
+                        role_deny.insert(overwrite.deny);
+                    }
+                }
+                PermissionOverwriteType::Member(user_id) => {
+                    if user_id == self.user_id {
+                        member.replace(overwrite);
+                    }
+                }
+            }
+        }
+
+        permissions.remove(role_deny);
+        permissions.insert(role_allow);
+
+        if let Some(overwrite) = member {
+            permissions.remove(overwrite.deny);
+            permissions.insert(overwrite.allow);
+        }
+
+        if !permissions.contains(Permissions::VIEW_CHANNEL) {
+            return Permissions::empty();
+        }
+
+        let text = matches!(
+            channel_type,
+            ChannelType::GuildNews | ChannelType::GuildStore | ChannelType::GuildText
+        );
+
+        if is_thread(channel_type) {
+            if !permissions.contains(Permissions::SEND_MESSAGES_IN_THREADS) {
+                permissions.remove(Permissions::SEND_MESSAGES | SEND_MESSAGES_IMPLIED);
+            }
+        } else if text && !permissions.contains(Permissions::SEND_MESSAGES) {
+            permissions.remove(SEND_MESSAGES_IMPLIED);
+        }
+
+        self.restrict(permissions)
+    }
+
+    /// Calculate the permissions of the member in a thread with the
+    /// permission overwrites of its parent channel.
+    ///
+    /// This is the same as [`in_channel`], except that private threads are
+    /// only visible to members of the thread and to members with the
+    /// [`MANAGE_THREADS`] permission. Otherwise the member has no permissions
+    /// in the thread.
+    ///
+    /// # Examples
+    ///
+    /// A member who can view a channel can't view a private thread in it
+    /// unless they've been added to it:
+    ///
+    /// ```
+    /// use twilight_model::{
+    ///     channel::ChannelType,
+    ///     guild::Permissions,
+    ///     id::{GuildId, UserId},
+    /// };
+    /// use twilight_util::permission_calculator::PermissionCalculator;
+    ///
+    /// let everyone = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES_IN_THREADS;
+    /// let calculator = PermissionCalculator::new(GuildId(1), UserId(2), everyone, &[]);
+    ///
+    /// assert_eq!(
+    ///     Permissions::empty(),
+    ///     calculator.in_thread(ChannelType::GuildPrivateThread, &[], false),
+    /// );
+    /// assert_eq!(
+    ///     everyone,
+    ///     calculator.in_thread(ChannelType::GuildPrivateThread, &[], true),
+    /// );
+    /// ```
+    ///
+    /// [`MANAGE_THREADS`]: ../../twilight_model/guild/struct.Permissions.html#associatedconstant.MANAGE_THREADS
+    /// [`in_channel`]: #method.in_channel
+    #[must_use]
+    pub fn in_thread(
+        &self,
+        thread_type: ChannelType,
+        parent_overwrites: &[PermissionOverwrite],
+        thread_member: bool,
+    ) -> Permissions {
+        let permissions = self.in_channel(thread_type, parent_overwrites);
+
+        let hidden = thread_type == ChannelType::GuildPrivateThread
+            && !thread_member
+            && !permissions.contains(Permissions::MANAGE_THREADS);
+
+        if hidden {
+            return Permissions::empty();
+        }
+
+        permissions
+    }
+
+    /// Return the permissions of the `@everyone` role and the member's roles.
+    fn base(&self) -> Permissions {
+        self.member_roles
+            .iter()
+            .fold(self.everyone, |permissions, (_, role)| permissions | *role)
+    }
+
+    /// Whether the member has all permissions as the owner or an
+    /// administrator.
+    fn has_all(&self) -> bool {
+        self.owner_id == Some(self.user_id) || self.base().contains(Permissions::ADMINISTRATOR)
+    }
+
+    /// Restrict the permissions of a member who is timed out.
+    fn restrict(&self, permissions: Permissions) -> Permissions {
+        if self.timed_out {
+            permissions & TIMED_OUT
+        } else {
+            permissions
+        }
+    }
+}
+
+/// Whether a type of channel is a thread.
+fn is_thread(channel_type: ChannelType) -> bool {
+    matches!(
+        channel_type,
+        ChannelType::GuildNewsThread
+            | ChannelType::GuildPrivateThread
+            | ChannelType::GuildPublicThread
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PermissionCalculator;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        channel::{
+            permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
+            ChannelType,
+        },
+        guild::Permissions,
+        id::{GuildId, RoleId, UserId},
+    };
+
+    assert_impl_all!(PermissionCalculator<'_>: Clone, Debug, Eq, PartialEq, Send, Sync);
+
+    const GUILD_ID: GuildId = GuildId(1);
+    const USER_ID: UserId = UserId(2);
+    const EVERYONE: Permissions = Permissions::VIEW_CHANNEL;
+
+    fn role_overwrite(id: u64, allow: Permissions, deny: Permissions) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow,
+            deny,
+            kind: PermissionOverwriteType::Role(RoleId(id)),
+        }
+    }
+
+    fn member_overwrite(allow: Permissions, deny: Permissions) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow,
+            deny,
+            kind: PermissionOverwriteType::Member(USER_ID),
+        }
+    }
+
+    #[test]
+    fn test_root_combines_roles() {
+        let roles = &[
+            (RoleId(3), Permissions::SEND_MESSAGES),
+            (RoleId(4), Permissions::KICK_MEMBERS),
+        ];
+        let calculator = PermissionCalculator::new(GUILD_ID, USER_ID, EVERYONE, roles);
+
+        assert_eq!(
+            Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES | Permissions::KICK_MEMBERS,
+            calculator.root(),
+        );
+    }
+
+    #[test]
+    fn test_owner_has_all() {
+        let calculator = PermissionCalculator::new(GUILD_ID, USER_ID, Permissions::empty(), &[])
+            .owner_id(USER_ID)
+            .timed_out(true);
+        let overwrites = &[member_overwrite(Permissions::empty(), Permissions::all())];
+
+        assert_eq!(Permissions::all(), calculator.root());
+        assert_eq!(
+            Permissions::all(),
+            calculator.in_channel(ChannelType::GuildText, overwrites),
+        );
+
+        let calculator =
+            PermissionCalculator::new(GUILD_ID, USER_ID, EVERYONE, &[]).owner_id(UserId(3));
+        assert_eq!(EVERYONE, calculator.root());
+    }
+
+    #[test]
+    fn test_administrator_has_all() {
+        let roles = &[(RoleId(3), Permissions::ADMINISTRATOR)];
+        let calculator = PermissionCalculator::new(GUILD_ID, USER_ID, EVERYONE, roles);
+        let overwrites = &[
+            role_overwrite(1, Permissions::empty(), Permissions::VIEW_CHANNEL),
+            member_overwrite(Permissions::empty(), Permissions::all()),
+        ];
+
+        assert_eq!(Permissions::all(), calculator.root());
+        assert_eq!(
+            Permissions::all(),
+            calculator.in_channel(ChannelType::GuildText, overwrites),
+        );
+    }
+
+    #[test]
+    fn test_overwrite_precedence() {
+        let everyone = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+        let roles = &[
+            (RoleId(3), Permissions::empty()),
+            (RoleId(4), Permissions::empty()),
+        ];
+        let calculator = PermissionCalculator::new(GUILD_ID, USER_ID, everyone, roles);
+
+        // The @everyone overwrite denies sending messages, a role allows it
+        // again, and a role's allow wins over another role's deny.
+        let overwrites = &[
+            role_overwrite(1, Permissions::empty(), Permissions::SEND_MESSAGES),
+            role_overwrite(3, Permissions::SEND_MESSAGES, Permissions::ADD_REACTIONS),
+            role_overwrite(4, Permissions::ADD_REACTIONS, Permissions::SEND_MESSAGES),
+        ];
+        assert_eq!(
+            everyone | Permissions::ADD_REACTIONS,
+            calculator.in_channel(ChannelType::GuildText, overwrites),
+        );
+
+        // The member's overwrite wins over the roles' overwrites.
+        let overwrites = &[
+            role_overwrite(3, Permissions::ADD_REACTIONS, Permissions::empty()),
+            member_overwrite(Permissions::empty(), Permissions::ADD_REACTIONS),
+        ];
+        assert_eq!(
+            everyone,
+            calculator.in_channel(ChannelType::GuildText, overwrites),
+        );
+    }
+
+    #[test]
+    fn test_overwrites_of_others_ignored() {
+        let calculator = PermissionCalculator::new(GUILD_ID, USER_ID, EVERYONE, &[]);
+        let overwrites = &[
+            role_overwrite(3, Permissions::SEND_MESSAGES, Permissions::empty()),
+            PermissionOverwrite {
+                allow: Permissions::SEND_MESSAGES,
+                deny: Permissions::empty(),
+                kind: PermissionOverwriteType::Member(UserId(4)),
+            },
+        ];
+
+        assert_eq!(
+            EVERYONE,
+            calculator.in_channel(ChannelType::GuildText, overwrites),
+        );
+    }
+
+    #[test]
+    fn test_view_channel_implicit() {
+        let everyone = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+        let calculator = PermissionCalculator::new(GUILD_ID, USER_ID, everyone, &[]);
+        let overwrites = &[member_overwrite(
+            Permissions::empty(),
+            Permissions::VIEW_CHANNEL,
+        )];
+
+        assert_eq!(
+            Permissions::empty(),
+            calculator.in_channel(ChannelType::GuildVoice, overwrites),
+        );
+    }
+
+    #[test]
+    fn test_send_messages_implicit() {
+        let everyone = Permissions::VIEW_CHANNEL
+            | Permissions::ATTACH_FILES
+            | Permissions::EMBED_LINKS
+            | Permissions::MENTION_EVERYONE
+            | Permissions::SEND_TTS_MESSAGES;
+        let calculator = PermissionCalculator::new(GUILD_ID, USER_ID, everyone, &[]);
+
+        assert_eq!(
+            Permissions::VIEW_CHANNEL,
+            calculator.in_channel(ChannelType::GuildText, &[]),
+        );
+        // Voice channels don't have messages.
+        assert_eq!(
+            everyone,
+            calculator.in_channel(ChannelType::GuildVoice, &[])
+        );
+    }
+
+    #[test]
+    fn test_timed_out() {
+        let everyone = Permissions::VIEW_CHANNEL
+            | Permissions::READ_MESSAGE_HISTORY
+            | Permissions::SEND_MESSAGES
+            | Permissions::CONNECT;
+        let calculator =
+            PermissionCalculator::new(GUILD_ID, USER_ID, everyone, &[]).timed_out(true);
+        let expected = Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY;
+
+        assert_eq!(expected, calculator.root());
+        assert_eq!(expected, calculator.in_channel(ChannelType::GuildText, &[]));
+    }
+
+    #[test]
+    fn test_send_messages_in_threads() {
+        let everyone = Permissions::VIEW_CHANNEL
+            | Permissions::SEND_MESSAGES
+            | Permissions::EMBED_LINKS
+            | Permissions::READ_MESSAGE_HISTORY;
+        let calculator = PermissionCalculator::new(GUILD_ID, USER_ID, everyone, &[]);
+
+        // Sending messages in the parent channel doesn't allow sending them
+        // in its threads.
+        assert_eq!(
+            Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY,
+            calculator.in_channel(ChannelType::GuildPublicThread, &[]),
+        );
+        assert_eq!(everyone, calculator.in_channel(ChannelType::GuildText, &[]));
+
+        let overwrites = &[member_overwrite(
+            Permissions::SEND_MESSAGES_IN_THREADS,
+            Permissions::SEND_MESSAGES,
+        )];
+
+        assert_eq!(
+            (everyone - Permissions::SEND_MESSAGES) | Permissions::SEND_MESSAGES_IN_THREADS,
+            calculator.in_channel(ChannelType::GuildNewsThread, overwrites),
+        );
+    }
+
+    #[test]
+    fn test_private_thread_visibility() {
+        let everyone = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES_IN_THREADS;
+        let calculator = PermissionCalculator::new(GUILD_ID, USER_ID, everyone, &[]);
+
+        assert_eq!(
+            Permissions::empty(),
+            calculator.in_thread(ChannelType::GuildPrivateThread, &[], false),
+        );
+        assert_eq!(
+            everyone,
+            calculator.in_thread(ChannelType::GuildPrivateThread, &[], true),
+        );
+        // Public threads are visible to everyone who can view the parent.
+        assert_eq!(
+            everyone,
+            calculator.in_thread(ChannelType::GuildPublicThread, &[], false),
+        );
+
+        let roles = &[(RoleId(3), Permissions::MANAGE_THREADS)];
+        let moderator = PermissionCalculator::new(GUILD_ID, USER_ID, everyone, roles);
+
+        assert_eq!(
+            everyone | Permissions::MANAGE_THREADS,
+            moderator.in_thread(ChannelType::GuildPrivateThread, &[], false),
+        );
+        // Timed out moderators lose the permission to manage threads, so
+        // they can't view private threads they aren't a member of.
+        assert_eq!(
+            Permissions::empty(),
+            moderator
+                .timed_out(true)
+                .in_thread(ChannelType::GuildPrivateThread, &[], false),
+        );
+    }
+
+    #[test]
+    fn test_timed_out_in_thread() {
+        let everyone = Permissions::VIEW_CHANNEL
+            | Permissions::READ_MESSAGE_HISTORY
+            | Permissions::SEND_MESSAGES_IN_THREADS
+            | Permissions::CREATE_PUBLIC_THREADS;
+        let calculator =
+            PermissionCalculator::new(GUILD_ID, USER_ID, everyone, &[]).timed_out(true);
+
+        assert_eq!(
+            Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY,
+            calculator.in_thread(ChannelType::GuildPublicThread, &[], true),
+        );
+    }
+}
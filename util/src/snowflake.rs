@@ -1,10 +1,184 @@
-//! Provides the Snowflake trait for defining extractable information from a Discord Snowflake.
+//! Provides the Snowflake trait for defining extractable information from a Discord Snowflake,
+//! [`from_timestamp`] for constructing one, and [`NonceGenerator`] for generating unique
+//! Snowflake-style nonces.
+//!
+//! [`NonceGenerator`]: struct.NonceGenerator.html
+//! [`from_timestamp`]: fn.from_timestamp.html
 
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
 use twilight_model::id::{
-    AttachmentId, AuditLogEntryId, ChannelId, EmojiId, GenericId, GuildId, IntegrationId,
-    MessageId, RoleId, UserId, WebhookId,
+    ApplicationId, AttachmentId, AuditLogEntryId, AutoModerationRuleId, ChannelId, CommandId,
+    EmojiId, GenericId, GuildId, IntegrationId, InteractionId, MessageId, RoleId, ScheduledEventId,
+    StageId, StickerId, StickerPackId, UserId, WebhookId,
 };
 
+/// Discord's custom epoch, the unix time in milliseconds for the first second of 2015.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// The largest number of milliseconds since the Discord epoch that fits in a Snowflake.
+const MAX_TIMESTAMP: u64 = (1 << 42) - 1;
+
+/// The largest node ID, which spans the worker ID and process ID bits of a Snowflake.
+const MAX_NODE_ID: u16 = (1 << 10) - 1;
+
+/// The largest increment of a Snowflake.
+const MAX_INCREMENT: u64 = (1 << 12) - 1;
+
+/// Construct the lowest Snowflake generated at a Unix epoch in milliseconds.
+///
+/// This is useful as a cursor for paginating resources by time, such as for the `before` and
+/// `after` parameters when getting the messages of a channel. The worker ID, process ID, and
+/// increment of the Snowflake are 0.
+///
+/// Timestamps before the Discord epoch, the first second of 2015, are clamped to it.
+///
+/// # Examples
+///
+/// Get a cursor for the messages sent after a user was created:
+///
+/// ```rust
+/// use twilight_util::snowflake::{self, Snowflake};
+/// use twilight_model::id::{MessageId, UserId};
+///
+/// let user_id = UserId(105484726235607040);
+/// let after = MessageId(snowflake::from_timestamp(user_id.timestamp()));
+///
+/// assert_eq!(user_id.timestamp(), after.timestamp());
+/// assert_eq!(0, after.increment());
+/// ```
+#[must_use]
+pub fn from_timestamp(timestamp: i64) -> u64 {
+    let elapsed = u64::try_from(timestamp)
+        .unwrap_or(0)
+        .saturating_sub(DISCORD_EPOCH)
+        .min(MAX_TIMESTAMP);
+
+    elapsed << 22
+}
+
+/// Creating a [`NonceGenerator`] failed.
+///
+/// [`NonceGenerator`]: struct.NonceGenerator.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum NonceGeneratorError {
+    /// The node ID is greater than 1023, so it doesn't fit in a Snowflake.
+    NodeIdInvalid {
+        /// Provided node ID.
+        node_id: u16,
+    },
+}
+
+impl Display for NonceGeneratorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NodeIdInvalid { node_id } => f.write_fmt(format_args!(
+                "the node id {} is greater than {}",
+                node_id, MAX_NODE_ID
+            )),
+        }
+    }
+}
+
+impl Error for NonceGeneratorError {}
+
+/// Generate unique, monotonically increasing Snowflake-style nonces.
+///
+/// Nonces are made from the current time, a node ID in the worker ID and process ID bits, and an
+/// increment. They're suitable for the `nonce` of a message or of a request for guild members,
+/// whose response can then be correlated with the request.
+///
+/// Generators are thread-safe, and the nonces of a generator never repeat and always increase.
+/// If more than 4096 nonces are generated in a millisecond then the following nonces borrow from
+/// the next millisecond. Generators with different node IDs, such as one per shard, never generate
+/// the same nonce.
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_util::snowflake::NonceGenerator;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let generator = NonceGenerator::new(3)?;
+///
+/// let first = generator.next();
+/// let second = generator.next();
+///
+/// assert!(second > first);
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct NonceGenerator {
+    last: AtomicU64,
+    node: u64,
+}
+
+impl NonceGenerator {
+    /// Create a generator with a node ID that is unique among the generators in use, such as a
+    /// shard ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NonceGeneratorError::NodeIdInvalid`] if the node ID is greater than 1023.
+    ///
+    /// [`NonceGeneratorError::NodeIdInvalid`]: enum.NonceGeneratorError.html#variant.NodeIdInvalid
+    pub fn new(node_id: u16) -> Result<Self, NonceGeneratorError> {
+        if node_id > MAX_NODE_ID {
+            return Err(NonceGeneratorError::NodeIdInvalid { node_id });
+        }
+
+        Ok(Self {
+            last: AtomicU64::new(0),
+            node: u64::from(node_id) << 12,
+        })
+    }
+
+    /// Generate the next nonce.
+    #[must_use]
+    pub fn next(&self) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .and_then(|elapsed| i64::try_from(elapsed.as_millis()).ok())
+            .unwrap_or(0);
+        let earliest = from_timestamp(now) | self.node;
+
+        let mut last = self.last.load(Ordering::Relaxed);
+
+        loop {
+            let next = if earliest > last {
+                earliest
+            } else {
+                self.successor(last)
+            };
+
+            match self
+                .last
+                .compare_exchange_weak(last, next, Ordering::Relaxed, Ordering::Relaxed)
+            {
+                Ok(_) => return next,
+                Err(actual) => last = actual,
+            }
+        }
+    }
+
+    /// The nonce after another, moving to the next millisecond if the increment is exhausted so
+    /// that the node ID is kept.
+    const fn successor(&self, nonce: u64) -> u64 {
+        if nonce & MAX_INCREMENT == MAX_INCREMENT {
+            ((nonce >> 22) + 1) << 22 | self.node
+        } else {
+            nonce + 1
+        }
+    }
+}
+
 /// Snowflake is a trait for defining extractable information from a Snowflake. A Snowflake is a
 /// u64 generated by Discord to uniquely identify a resource.
 pub trait Snowflake {
@@ -48,9 +222,6 @@ pub trait Snowflake {
     /// ```
     #[allow(clippy::cast_possible_wrap)]
     fn timestamp(&self) -> i64 {
-        // Discord's custom epoch, the unix time in milliseconds for the first second of 2015.
-        const DISCORD_EPOCH: u64 = 1_420_070_400_000;
-
         ((self.id() >> 22) + DISCORD_EPOCH) as i64
     }
 
@@ -80,6 +251,12 @@ pub trait Snowflake {
     }
 }
 
+impl Snowflake for ApplicationId {
+    fn id(&self) -> u64 {
+        self.0
+    }
+}
+
 impl Snowflake for AttachmentId {
     fn id(&self) -> u64 {
         self.0
@@ -92,12 +269,24 @@ impl Snowflake for AuditLogEntryId {
     }
 }
 
+impl Snowflake for AutoModerationRuleId {
+    fn id(&self) -> u64 {
+        self.0
+    }
+}
+
 impl Snowflake for ChannelId {
     fn id(&self) -> u64 {
         self.0
     }
 }
 
+impl Snowflake for CommandId {
+    fn id(&self) -> u64 {
+        self.0
+    }
+}
+
 impl Snowflake for EmojiId {
     fn id(&self) -> u64 {
         self.0
@@ -122,6 +311,12 @@ impl Snowflake for IntegrationId {
     }
 }
 
+impl Snowflake for InteractionId {
+    fn id(&self) -> u64 {
+        self.0
+    }
+}
+
 impl Snowflake for MessageId {
     fn id(&self) -> u64 {
         self.0
@@ -134,6 +329,30 @@ impl Snowflake for RoleId {
     }
 }
 
+impl Snowflake for ScheduledEventId {
+    fn id(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Snowflake for StageId {
+    fn id(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Snowflake for StickerId {
+    fn id(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Snowflake for StickerPackId {
+    fn id(&self) -> u64 {
+        self.0
+    }
+}
+
 impl Snowflake for UserId {
     fn id(&self) -> u64 {
         self.0
@@ -148,22 +367,35 @@ impl Snowflake for WebhookId {
 
 #[cfg(test)]
 mod tests {
-    use super::Snowflake;
-    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use super::{NonceGenerator, NonceGeneratorError, Snowflake};
+    use static_assertions::{assert_fields, assert_impl_all, assert_obj_safe};
+    use std::{collections::HashSet, error::Error, fmt::Debug, sync::Arc, thread};
     use twilight_model::id::{
-        AttachmentId, AuditLogEntryId, ChannelId, EmojiId, GenericId, GuildId, IntegrationId,
-        MessageId, RoleId, UserId, WebhookId,
+        ApplicationId, AttachmentId, AuditLogEntryId, AutoModerationRuleId, ChannelId, CommandId,
+        EmojiId, GenericId, GuildId, IntegrationId, InteractionId, MessageId, RoleId,
+        ScheduledEventId, StageId, StickerId, StickerPackId, UserId, WebhookId,
     };
 
+    assert_fields!(NonceGeneratorError::NodeIdInvalid: node_id);
+    assert_impl_all!(NonceGenerator: Debug, Send, Sync);
+    assert_impl_all!(NonceGeneratorError: Clone, Debug, Error, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(ApplicationId: Snowflake);
     assert_impl_all!(AttachmentId: Snowflake);
     assert_impl_all!(AuditLogEntryId: Snowflake);
+    assert_impl_all!(AutoModerationRuleId: Snowflake);
     assert_impl_all!(ChannelId: Snowflake);
+    assert_impl_all!(CommandId: Snowflake);
     assert_impl_all!(EmojiId: Snowflake);
     assert_impl_all!(GenericId: Snowflake);
     assert_impl_all!(GuildId: Snowflake);
     assert_impl_all!(IntegrationId: Snowflake);
+    assert_impl_all!(InteractionId: Snowflake);
     assert_impl_all!(MessageId: Snowflake);
     assert_impl_all!(RoleId: Snowflake);
+    assert_impl_all!(ScheduledEventId: Snowflake);
+    assert_impl_all!(StageId: Snowflake);
+    assert_impl_all!(StickerId: Snowflake);
+    assert_impl_all!(StickerPackId: Snowflake);
     assert_impl_all!(UserId: Snowflake);
     assert_impl_all!(WebhookId: Snowflake);
     assert_obj_safe!(Snowflake);
@@ -176,6 +408,25 @@ mod tests {
         assert_eq!(expected, id.timestamp())
     }
 
+    #[test]
+    fn test_from_timestamp() {
+        let id = GenericId(105_484_726_235_607_040);
+        let cursor = GenericId(super::from_timestamp(id.timestamp()));
+
+        assert_eq!(id.timestamp(), cursor.timestamp());
+        assert_eq!(0, cursor.worker_id());
+        assert_eq!(0, cursor.process_id());
+        assert_eq!(0, cursor.increment());
+        assert!(cursor.0 <= id.0);
+    }
+
+    #[test]
+    fn test_from_timestamp_clamped() {
+        assert_eq!(0, super::from_timestamp(-1));
+        assert_eq!(0, super::from_timestamp(1_000));
+        assert_eq!(u64::MAX << 22, super::from_timestamp(i64::MAX));
+    }
+
     #[test]
     fn test_worker_id() {
         let expected: u8 = 8;
@@ -199,4 +450,57 @@ mod tests {
 
         assert_eq!(expected, id.increment())
     }
+
+    #[test]
+    fn test_nonce_generator() {
+        let generator = NonceGenerator::new(0b10001_00011).unwrap();
+        let mut last = 0;
+
+        // More than can be generated in a millisecond, so that the increment
+        // is exhausted.
+        for _ in 0..10_000 {
+            let nonce = generator.next();
+            assert!(nonce > last);
+
+            let id = GenericId(nonce);
+            assert_eq!(0b10001, id.worker_id());
+            assert_eq!(0b00011, id.process_id());
+
+            last = nonce;
+        }
+    }
+
+    #[test]
+    fn test_nonce_generator_threads() {
+        let generators = (0..2)
+            .map(|node_id| Arc::new(NonceGenerator::new(node_id).unwrap()))
+            .collect::<Vec<_>>();
+
+        let handles = (0..4)
+            .map(|idx| {
+                let generator = Arc::clone(&generators[idx % 2]);
+
+                thread::spawn(move || (0..5000).map(|_| generator.next()).collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+
+        let mut nonces = HashSet::new();
+
+        for handle in handles {
+            for nonce in handle.join().unwrap() {
+                assert!(nonces.insert(nonce));
+            }
+        }
+
+        assert_eq!(20_000, nonces.len());
+    }
+
+    #[test]
+    fn test_nonce_generator_node_id_invalid() {
+        assert!(NonceGenerator::new(1023).is_ok());
+        assert_eq!(
+            NonceGeneratorError::NodeIdInvalid { node_id: 1024 },
+            NonceGenerator::new(1024).unwrap_err()
+        );
+    }
 }
@@ -0,0 +1,641 @@
+//! Connection to a voice server, used to send audio to a voice channel.
+//!
+//! A connection is established in two parts: a websocket connection to the
+//! voice gateway, over which the session is negotiated, and a UDP connection
+//! over which the encrypted audio is sent. Both parts are handled by
+//! [`Connection::connect`].
+//!
+//! [`Connection::connect`]: struct.Connection.html#method.connect
+
+use crate::{
+    model::{
+        Heartbeat, Hello, Identify, IdentifyInfo, SelectProtocol, Speaking, SpeakingFlags,
+        VoiceEvent,
+    },
+    udp::{self, RtpState, IP_DISCOVERY_LEN},
+};
+use async_tungstenite::{
+    tokio::ConnectStream,
+    tungstenite::{Error as TungsteniteError, Message},
+    WebSocketStream,
+};
+use futures_util::{
+    sink::SinkExt,
+    stream::{Stream, StreamExt},
+};
+use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    net::SocketAddr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::{
+    net::UdpSocket,
+    sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    time::{self as tokio_time, Instant},
+};
+use twilight_model::{
+    gateway::payload::VoiceServerUpdate,
+    id::{GuildId, UserId},
+    voice::VoiceState,
+};
+
+/// Encryption mode used for audio packets.
+const MODE: &str = "xsalsa20_poly1305";
+
+/// Duration of audio in each Opus frame.
+const FRAME_DURATION: Duration = Duration::from_millis(20);
+
+/// Opus frame of silence, sent after audio to avoid interpolation.
+const SILENCE_FRAME: [u8; 3] = [0xf8, 0xff, 0xfe];
+
+/// Number of silence frames sent after audio.
+const SILENCE_FRAMES: usize = 5;
+
+/// Time to wait for a response to the IP discovery request.
+const IP_DISCOVERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// An error occurred while either establishing a connection or sending audio
+/// over it.
+#[derive(Debug)]
+pub struct ConnectionError {
+    kind: ConnectionErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ConnectionError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ConnectionErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ConnectionErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    fn new(kind: ConnectionErrorType) -> Self {
+        Self { kind, source: None }
+    }
+
+    fn with_source(kind: ConnectionErrorType, source: impl Error + Send + Sync + 'static) -> Self {
+        Self {
+            kind,
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
+impl Display for ConnectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ConnectionErrorType::Closed => f.write_str("the websocket connection was closed"),
+            ConnectionErrorType::Connecting => f.write_str("failed to connect to the voice server"),
+            ConnectionErrorType::Encrypting => f.write_str("failed to encrypt a voice packet"),
+            ConnectionErrorType::IpDiscovery => {
+                f.write_str("failed to discover the external address")
+            }
+            ConnectionErrorType::Sending => f.write_str("failed to send a websocket message"),
+            ConnectionErrorType::Serializing => {
+                f.write_str("failed to serialize outgoing message as json")
+            }
+            ConnectionErrorType::Udp => f.write_str("failed to use the udp socket"),
+            ConnectionErrorType::UnsupportedMode { .. } => {
+                f.write_str("the voice server doesn't support the xsalsa20_poly1305 mode")
+            }
+        }
+    }
+}
+
+impl Error for ConnectionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ConnectionError`] that occurred.
+///
+/// [`ConnectionError`]: struct.ConnectionError.html
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConnectionErrorType {
+    /// The websocket connection was closed, either by the voice server or
+    /// because the connection was dropped.
+    Closed,
+    /// Connecting to the voice gateway failed.
+    Connecting,
+    /// Encrypting a frame of audio failed.
+    Encrypting,
+    /// The voice server didn't respond to the IP discovery request in time,
+    /// or the response was malformed.
+    IpDiscovery,
+    /// Sending a message over the websocket connection failed.
+    Sending,
+    /// Serializing a JSON message to be sent to the voice gateway failed.
+    Serializing,
+    /// Binding, connecting, or sending over the UDP socket failed.
+    Udp,
+    /// The voice server doesn't support the `xsalsa20_poly1305` encryption
+    /// mode.
+    UnsupportedMode {
+        /// Modes the voice server supports.
+        modes: Vec<String>,
+    },
+}
+
+/// Information needed to connect to a voice server.
+///
+/// This is assembled from the Voice State Update and Voice Server Update
+/// events received from the main gateway after joining a voice channel.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConnectionInfo {
+    /// Endpoint of the voice server.
+    pub endpoint: String,
+    /// ID of the guild the voice channel is in.
+    pub guild_id: GuildId,
+    /// Session ID of the current user's voice state.
+    pub session_id: String,
+    /// Token from the voice server update.
+    pub token: String,
+    /// ID of the current user.
+    pub user_id: UserId,
+}
+
+impl ConnectionInfo {
+    /// Assemble the connection information from the current user's voice
+    /// state and the voice server update of the guild.
+    ///
+    /// Returns `None` if the voice server update has no endpoint, which means
+    /// that the voice server went away, or if either event doesn't contain the
+    /// guild ID.
+    pub fn from_updates(state: &VoiceState, server: &VoiceServerUpdate) -> Option<Self> {
+        Some(Self {
+            endpoint: server.endpoint.clone()?,
+            guild_id: server.guild_id.or(state.guild_id)?,
+            session_id: state.session_id.clone(),
+            token: server.token.clone(),
+            user_id: state.user_id,
+        })
+    }
+}
+
+/// Connection to a voice server.
+///
+/// The websocket connection is kept alive in a background task, which is
+/// stopped when the connection is dropped.
+///
+/// # Examples
+///
+/// Connect to the voice server and send a stream of Opus frames:
+///
+/// ```no_run
+/// use futures_util::stream;
+/// use twilight_model::id::{GuildId, UserId};
+/// use twilight_voice::{Connection, ConnectionInfo};
+///
+/// # #[tokio::main] async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let info = ConnectionInfo {
+///     endpoint: "endpoint.discord.media".to_owned(),
+///     guild_id: GuildId(1),
+///     session_id: "session id".to_owned(),
+///     token: "token".to_owned(),
+///     user_id: UserId(2),
+/// };
+///
+/// let mut connection = Connection::connect(info).await?;
+///
+/// let frames: Vec<Vec<u8>> = Vec::new();
+/// connection.play(stream::iter(frames)).await?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct Connection {
+    guild_id: GuildId,
+    rtp: RtpState,
+    socket: UdpSocket,
+    tx: UnboundedSender<String>,
+}
+
+impl Connection {
+    /// Connect to a voice server, completing the websocket handshake and
+    /// establishing the encrypted UDP connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConnectionErrorType::Connecting`] error type if the
+    /// connection to the voice gateway couldn't be made.
+    ///
+    /// Returns a [`ConnectionErrorType::Closed`] error type if the voice
+    /// gateway closed the connection during the handshake.
+    ///
+    /// Returns a [`ConnectionErrorType::IpDiscovery`] error type if the
+    /// external address couldn't be discovered.
+    ///
+    /// Returns a [`ConnectionErrorType::UnsupportedMode`] error type if the
+    /// voice server doesn't support the encryption mode.
+    ///
+    /// [`ConnectionErrorType::Closed`]: enum.ConnectionErrorType.html#variant.Closed
+    /// [`ConnectionErrorType::Connecting`]: enum.ConnectionErrorType.html#variant.Connecting
+    /// [`ConnectionErrorType::IpDiscovery`]: enum.ConnectionErrorType.html#variant.IpDiscovery
+    /// [`ConnectionErrorType::UnsupportedMode`]: enum.ConnectionErrorType.html#variant.UnsupportedMode
+    pub async fn connect(info: ConnectionInfo) -> Result<Self, ConnectionError> {
+        // Endpoints may include a port that isn't used for the websocket.
+        let url = format!("wss://{}/?v=4", info.endpoint.trim_end_matches(":80"));
+
+        tracing::debug!("connecting to voice gateway {}", url);
+        let (mut stream, _) =
+            async_tungstenite::tokio::connect_async(url)
+                .await
+                .map_err(|source| {
+                    ConnectionError::with_source(ConnectionErrorType::Connecting, source)
+                })?;
+
+        send(
+            &mut stream,
+            &Identify::new(IdentifyInfo {
+                server_id: info.guild_id,
+                session_id: info.session_id,
+                token: info.token,
+                user_id: info.user_id,
+            }),
+        )
+        .await?;
+
+        let mut hello = None;
+
+        let ready = loop {
+            match next_event(&mut stream).await? {
+                VoiceEvent::Hello(value) => hello = Some(value),
+                VoiceEvent::Ready(ready) => break ready,
+                _ => {}
+            }
+        };
+
+        if !ready.modes.iter().any(|mode| mode == MODE) {
+            return Err(ConnectionError::new(ConnectionErrorType::UnsupportedMode {
+                modes: ready.modes,
+            }));
+        }
+
+        let mut socket = UdpSocket::bind(("0.0.0.0", 0))
+            .await
+            .map_err(|source| ConnectionError::with_source(ConnectionErrorType::Udp, source))?;
+        socket
+            .connect((ready.ip.as_str(), ready.port))
+            .await
+            .map_err(|source| ConnectionError::with_source(ConnectionErrorType::Udp, source))?;
+
+        let external = discover_ip(&mut socket, ready.ssrc).await?;
+        tracing::debug!("discovered external address {}", external);
+
+        send(
+            &mut stream,
+            &SelectProtocol::new(external.ip().to_string(), external.port(), MODE),
+        )
+        .await?;
+
+        let session = loop {
+            match next_event(&mut stream).await? {
+                VoiceEvent::Hello(value) => hello = Some(value),
+                VoiceEvent::SessionDescription(session) => break session,
+                _ => {}
+            }
+        };
+
+        let hello = match hello {
+            Some(hello) => hello,
+            None => loop {
+                if let VoiceEvent::Hello(hello) = next_event(&mut stream).await? {
+                    break hello;
+                }
+            },
+        };
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(run(stream, rx, heartbeat_interval(&hello)));
+
+        Ok(Self {
+            guild_id: info.guild_id,
+            rtp: RtpState {
+                secret_key: session.secret_key,
+                sequence: 0,
+                ssrc: ready.ssrc,
+                timestamp: 0,
+            },
+            socket,
+            tx,
+        })
+    }
+
+    /// ID of the guild the connection is in.
+    pub fn guild_id(&self) -> GuildId {
+        self.guild_id
+    }
+
+    /// SSRC assigned to the connection by the voice server.
+    pub fn ssrc(&self) -> u32 {
+        self.rtp.ssrc
+    }
+
+    /// Set whether the client is speaking.
+    ///
+    /// Audio sent while not speaking is ignored by the voice server.
+    /// [`play`] sets this automatically.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConnectionErrorType::Closed`] error type if the websocket
+    /// connection was closed.
+    ///
+    /// [`ConnectionErrorType::Closed`]: enum.ConnectionErrorType.html#variant.Closed
+    /// [`play`]: #method.play
+    pub fn speaking(&self, speaking: SpeakingFlags) -> Result<(), ConnectionError> {
+        let payload =
+            serde_json::to_string(&Speaking::new(speaking, self.rtp.ssrc)).map_err(|source| {
+                ConnectionError::with_source(ConnectionErrorType::Serializing, source)
+            })?;
+
+        self.tx
+            .send(payload)
+            .map_err(|_| ConnectionError::new(ConnectionErrorType::Closed))
+    }
+
+    /// Encrypt and send a single Opus frame of 20 milliseconds of audio.
+    ///
+    /// This doesn't pace the frames, so they must be sent every 20
+    /// milliseconds. Prefer using [`play`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConnectionErrorType::Encrypting`] error type if the frame
+    /// couldn't be encrypted.
+    ///
+    /// Returns a [`ConnectionErrorType::Udp`] error type if sending the
+    /// packet failed.
+    ///
+    /// [`ConnectionErrorType::Encrypting`]: enum.ConnectionErrorType.html#variant.Encrypting
+    /// [`ConnectionErrorType::Udp`]: enum.ConnectionErrorType.html#variant.Udp
+    /// [`play`]: #method.play
+    pub async fn send_frame(&mut self, frame: &[u8]) -> Result<(), ConnectionError> {
+        let packet = self
+            .rtp
+            .packet(frame)
+            .ok_or_else(|| ConnectionError::new(ConnectionErrorType::Encrypting))?;
+
+        self.socket
+            .send(&packet)
+            .await
+            .map_err(|source| ConnectionError::with_source(ConnectionErrorType::Udp, source))?;
+
+        Ok(())
+    }
+
+    /// Play a stream of Opus frames, each 20 milliseconds of 48kHz stereo
+    /// audio, until the stream ends.
+    ///
+    /// The frames are sent every 20 milliseconds, so the stream should be able
+    /// to produce frames at least that quickly. Speaking is set before the
+    /// first frame and unset after the last, or after sending a frame fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ConnectionErrorType::Closed`] error type if the websocket
+    /// connection was closed.
+    ///
+    /// Returns a [`ConnectionErrorType::Encrypting`] error type if a frame
+    /// couldn't be encrypted.
+    ///
+    /// Returns a [`ConnectionErrorType::Udp`] error type if sending a packet
+    /// failed.
+    ///
+    /// [`ConnectionErrorType::Closed`]: enum.ConnectionErrorType.html#variant.Closed
+    /// [`ConnectionErrorType::Encrypting`]: enum.ConnectionErrorType.html#variant.Encrypting
+    /// [`ConnectionErrorType::Udp`]: enum.ConnectionErrorType.html#variant.Udp
+    pub async fn play(
+        &mut self,
+        frames: impl Stream<Item = Vec<u8>> + Unpin,
+    ) -> Result<(), ConnectionError> {
+        self.speaking(SpeakingFlags::MICROPHONE)?;
+
+        let result = self.send_frames(frames).await;
+
+        // Unset speaking even if sending a frame failed, returning the error
+        // of sending the frame first.
+        let unset = self.speaking(SpeakingFlags::empty());
+
+        result.and(unset)
+    }
+
+    /// Send a stream of frames followed by silence, paced every 20
+    /// milliseconds.
+    async fn send_frames(
+        &mut self,
+        mut frames: impl Stream<Item = Vec<u8>> + Unpin,
+    ) -> Result<(), ConnectionError> {
+        let mut interval = tokio_time::interval(FRAME_DURATION);
+
+        while let Some(frame) = frames.next().await {
+            interval.tick().await;
+            self.send_frame(&frame).await?;
+        }
+
+        // Send a few frames of silence so that the end of the audio isn't
+        // interpolated by clients.
+        for _ in 0..SILENCE_FRAMES {
+            interval.tick().await;
+            self.send_frame(&SILENCE_FRAME).await?;
+        }
+
+        Ok(())
+    }
+}
+
+async fn discover_ip(socket: &mut UdpSocket, ssrc: u32) -> Result<SocketAddr, ConnectionError> {
+    socket
+        .send(&udp::ip_discovery_request(ssrc))
+        .await
+        .map_err(|source| ConnectionError::with_source(ConnectionErrorType::Udp, source))?;
+
+    let mut response = [0; IP_DISCOVERY_LEN];
+    let len = tokio_time::timeout(IP_DISCOVERY_TIMEOUT, socket.recv(&mut response))
+        .await
+        .map_err(|source| ConnectionError::with_source(ConnectionErrorType::IpDiscovery, source))?
+        .map_err(|source| ConnectionError::with_source(ConnectionErrorType::Udp, source))?;
+
+    udp::parse_ip_discovery_response(&response[..len])
+        .ok_or_else(|| ConnectionError::new(ConnectionErrorType::IpDiscovery))
+}
+
+fn heartbeat_interval(hello: &Hello) -> Duration {
+    Duration::from_millis(hello.heartbeat_interval as u64)
+}
+
+fn nonce() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis() as u64)
+}
+
+async fn send(
+    stream: &mut WebSocketStream<ConnectStream>,
+    payload: &impl Serialize,
+) -> Result<(), ConnectionError> {
+    let payload = serde_json::to_string(payload)
+        .map_err(|source| ConnectionError::with_source(ConnectionErrorType::Serializing, source))?;
+
+    stream
+        .send(Message::Text(payload))
+        .await
+        .map_err(|source| ConnectionError::with_source(ConnectionErrorType::Sending, source))
+}
+
+/// Wait for the next event, skipping events that aren't known.
+async fn next_event(
+    stream: &mut WebSocketStream<ConnectStream>,
+) -> Result<VoiceEvent, ConnectionError> {
+    loop {
+        let text = match stream.next().await {
+            Some(Ok(Message::Text(text))) => text,
+            Some(Ok(Message::Close(frame))) => {
+                tracing::warn!("voice gateway closed the connection: {:?}", frame);
+
+                return Err(ConnectionError::new(ConnectionErrorType::Closed));
+            }
+            Some(Ok(_)) => continue,
+            Some(Err(source)) => {
+                return Err(ConnectionError::with_source(
+                    ConnectionErrorType::Closed,
+                    source,
+                ))
+            }
+            None => return Err(ConnectionError::new(ConnectionErrorType::Closed)),
+        };
+
+        match serde_json::from_str(&text) {
+            Ok(event) => return Ok(event),
+            Err(source) => tracing::debug!("skipping unknown voice event {}: {}", text, source),
+        }
+    }
+}
+
+/// Keep the websocket connection alive and send queued messages until the
+/// connection is closed or dropped.
+async fn run(
+    mut stream: WebSocketStream<ConnectStream>,
+    mut rx: UnboundedReceiver<String>,
+    interval: Duration,
+) {
+    let mut heartbeats = tokio_time::interval_at(Instant::now() + interval, interval);
+
+    loop {
+        let result: Result<(), TungsteniteError> = tokio::select! {
+            _ = heartbeats.tick() => {
+                match serde_json::to_string(&Heartbeat::new(nonce())) {
+                    Ok(payload) => stream.send(Message::Text(payload)).await,
+                    Err(source) => {
+                        tracing::warn!("failed to serialize heartbeat: {}", source);
+
+                        Ok(())
+                    }
+                }
+            }
+            payload = rx.recv() => match payload {
+                Some(payload) => stream.send(Message::Text(payload)).await,
+                None => {
+                    tracing::debug!("connection dropped, closing voice gateway connection");
+                    let _ = stream.close(None).await;
+
+                    return;
+                }
+            },
+            message = stream.next() => match message {
+                Some(Ok(Message::Close(frame))) => {
+                    tracing::warn!("voice gateway closed the connection: {:?}", frame);
+
+                    return;
+                }
+                Some(Ok(Message::Text(text))) => {
+                    tracing::trace!("received voice event {}", text);
+
+                    Ok(())
+                }
+                Some(Ok(_)) => Ok(()),
+                Some(Err(source)) => Err(source),
+                None => return,
+            },
+        };
+
+        if let Err(source) = result {
+            tracing::warn!("voice gateway connection failed: {}", source);
+
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Connection, ConnectionError, ConnectionErrorType, ConnectionInfo};
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{error::Error, fmt::Debug};
+    use twilight_model::{
+        gateway::payload::VoiceServerUpdate,
+        id::{GuildId, UserId},
+        voice::VoiceState,
+    };
+
+    assert_fields!(ConnectionErrorType::UnsupportedMode: modes);
+    assert_impl_all!(ConnectionErrorType: Debug, Send, Sync);
+    assert_impl_all!(ConnectionError: Error, Send, Sync);
+    assert_impl_all!(ConnectionInfo: Clone, Debug, Eq, PartialEq, Send, Sync);
+    assert_impl_all!(Connection: Debug, Send, Sync);
+
+    #[test]
+    fn test_from_updates() {
+        let state = VoiceState {
+            channel_id: None,
+            deaf: false,
+            guild_id: Some(GuildId(1)),
+            member: None,
+            mute: false,
+            self_deaf: false,
+            self_mute: false,
+            self_stream: false,
+            session_id: "session".to_owned(),
+            suppress: false,
+            token: None,
+            user_id: UserId(2),
+        };
+        let mut server = VoiceServerUpdate {
+            channel_id: None,
+            endpoint: Some("endpoint:80".to_owned()),
+            guild_id: Some(GuildId(1)),
+            token: "token".to_owned(),
+        };
+
+        assert_eq!(
+            Some(ConnectionInfo {
+                endpoint: "endpoint:80".to_owned(),
+                guild_id: GuildId(1),
+                session_id: "session".to_owned(),
+                token: "token".to_owned(),
+                user_id: UserId(2),
+            }),
+            ConnectionInfo::from_updates(&state, &server),
+        );
+
+        server.endpoint.take();
+        assert!(ConnectionInfo::from_updates(&state, &server).is_none());
+    }
+}
@@ -0,0 +1,95 @@
+//! Encryption for the `xsalsa20_poly1305` mode used for voice packets.
+//!
+//! This is the `crypto_secretbox` construction from NaCl, provided by the
+//! [`xsalsa20poly1305`] crate.
+//!
+//! [`xsalsa20poly1305`]: https://crates.io/crates/xsalsa20poly1305
+
+use xsalsa20poly1305::{
+    aead::{AeadInPlace, NewAead},
+    Key, Nonce, XSalsa20Poly1305,
+};
+
+/// Length of a secret key.
+pub const KEY_LEN: usize = 32;
+
+/// Length of a nonce.
+pub const NONCE_LEN: usize = 24;
+
+/// Length of the authentication tag prepended to the ciphertext.
+pub const TAG_LEN: usize = 16;
+
+/// Encrypt a message in place, returning its authentication tag.
+///
+/// The packet sent over the wire is the tag followed by the encrypted message.
+///
+/// Returns `None` if the message is longer than the keystream.
+pub fn seal(
+    key: &[u8; KEY_LEN],
+    nonce: &[u8; NONCE_LEN],
+    message: &mut [u8],
+) -> Option<[u8; TAG_LEN]> {
+    let cipher = XSalsa20Poly1305::new(&Key::from(*key));
+
+    cipher
+        .encrypt_in_place_detached(&Nonce::from(*nonce), b"", message)
+        .ok()
+        .map(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{seal, TAG_LEN};
+    use xsalsa20poly1305::{
+        aead::{AeadInPlace, NewAead},
+        Key, Nonce, Tag, XSalsa20Poly1305,
+    };
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // Vector from the `secretbox` test of NaCl.
+    #[test]
+    fn test_seal() {
+        let mut key = [0; 32];
+        key.copy_from_slice(&hex(
+            "1b27556473e985d462cd51197a9a46c76009549eac6474f206c4ee0844f68389",
+        ));
+        let mut nonce = [0; 24];
+        nonce.copy_from_slice(&hex("69696ee955b62b73cd62bda875fc73d68219e0036b7a0b37"));
+
+        let plaintext = hex(concat!(
+            "be075fc53c81f2d5cf141316ebeb0c7b5228c52a4c62cbd44b66849b64244ffc",
+            "e5ecbaaf33bd751a1ac728d45e6c61296cdc3c01233561f41db66cce314adb31",
+            "0e3be8250c46f06dceea3a7fa1348057e2f6556ad6b1318a024a838f21af1fde",
+            "048977eb48f59ffd4924ca1c60902e52f0a089bc76897040e082f93776384864",
+            "5e0705",
+        ));
+        let mut message = plaintext.clone();
+        let tag = seal(&key, &nonce, &mut message).unwrap();
+
+        let mut packet = tag.to_vec();
+        packet.extend_from_slice(&message);
+        assert_eq!(
+            hex(concat!(
+                "f3ffc7703f9400e52a7dfb4b3d3305d98e993b9f48681273c29650ba32fc76ce",
+                "48332ea7164d96a4476fb8c531a1186ac0dfc17c98dce87b4da7f011ec48c972",
+                "71d2c20f9b928fe2270d6fb863d51738b48eeee314a7cc8ab932164548e526ae",
+                "90224368517acfeabd6bb3732bc0e9da99832b61ca01b6de56244a9e88d5f9b3",
+                "7973f622a43d14a6599b1f654cb45a74e355a5",
+            )),
+            packet,
+        );
+        assert_eq!(TAG_LEN, tag.len());
+
+        // Opening the packet gives back the original message.
+        XSalsa20Poly1305::new(&Key::from(key))
+            .decrypt_in_place_detached(&Nonce::from(nonce), b"", &mut message, &Tag::from(tag))
+            .unwrap();
+        assert_eq!(plaintext, message);
+    }
+}
@@ -0,0 +1,75 @@
+//! # twilight-voice
+//!
+//! [![discord badge][]][discord link] [![github badge][]][github link] [![license badge][]][license link] ![rust badge]
+//!
+//! `twilight-voice` is a native voice client as part of the twilight
+//! ecosystem, for sending audio to voice channels without running a separate
+//! server such as Lavalink.
+//!
+//! A [`Connection`] completes the voice websocket handshake, discovers the
+//! external address of the client, and establishes the UDP connection over
+//! which audio is sent, encrypted with the `xsalsa20_poly1305` mode. Once
+//! connected, it accepts a stream of Opus frames to transmit.
+//!
+//! Joining a voice channel is done over the main gateway by sending a voice
+//! state update; the resulting Voice State Update and Voice Server Update
+//! events are used to build the [`ConnectionInfo`]. This crate doesn't encode
+//! audio, so frames must already be encoded as 20 millisecond Opus frames of
+//! 48kHz stereo audio.
+//!
+//! ## Features
+//!
+//! ### TLS
+//!
+//! `twilight-voice` has features to enable [`async-tungstenite`]'s TLS
+//! features. These features are mutually exclusive. `rustls` is enabled by
+//! default.
+//!
+//! #### `native`
+//!
+//! The `native` feature enables [`async-tungstenite`]'s `tokio-native-tls`
+//! feature.
+//!
+//! To enable `native`, do something like this in your `Cargo.toml`:
+//!
+//! ```toml
+//! [dependencies]
+//! twilight-voice = { default-features = false, features = ["native"], version = "0.2" }
+//! ```
+//!
+//! #### `rustls`
+//!
+//! The `rustls` feature enables [`async-tungstenite`]'s `tokio-rustls` feature, which
+//! use [`rustls`] as the TLS backend.
+//!
+//! This is enabled by default.
+//!
+//! [`Connection`]: connection/struct.Connection.html
+//! [`ConnectionInfo`]: connection/struct.ConnectionInfo.html
+//! [`async-tungstenite`]: https://crates.io/crates/async-tungstenite
+//! [`rustls`]: https://crates.io/crates/rustls
+//! [discord badge]: https://img.shields.io/discord/745809834183753828?color=%237289DA&label=discord%20server&logo=discord&style=for-the-badge
+//! [discord link]: https://discord.gg/7jj8n7D
+//! [github badge]: https://img.shields.io/badge/github-twilight-6f42c1.svg?style=for-the-badge&logo=github
+//! [github link]: https://github.com/twilight-rs/twilight
+//! [license badge]: https://img.shields.io/badge/license-ISC-blue.svg?style=for-the-badge&logo=pastebin
+//! [license link]: https://github.com/twilight-rs/twilight/blob/trunk/LICENSE.md
+//! [rust badge]: https://img.shields.io/badge/rust-stable-93450a.svg?style=for-the-badge&logo=rust
+
+#![deny(
+    clippy::all,
+    future_incompatible,
+    missing_docs,
+    nonstandard_style,
+    rust_2018_idioms,
+    unused,
+    warnings
+)]
+
+pub mod connection;
+pub mod model;
+
+mod crypto;
+mod udp;
+
+pub use self::connection::{Connection, ConnectionError, ConnectionErrorType, ConnectionInfo};
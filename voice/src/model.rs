@@ -0,0 +1,417 @@
+//! Models of the payloads sent and received over a voice gateway connection.
+//!
+//! Refer to [Discord's documentation] for more information about them.
+//!
+//! [Discord's documentation]: https://discord.com/developers/docs/topics/voice-connections
+
+use bitflags::bitflags;
+use serde::{
+    de::{Deserializer, Error as DeError},
+    ser::Serializer,
+    Deserialize, Serialize,
+};
+use serde_json::Value;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use twilight_model::id::{GuildId, UserId};
+
+/// Opcode of a voice gateway payload.
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum OpCode {
+    /// Begin a voice websocket connection.
+    Identify = 0,
+    /// Select the voice protocol.
+    SelectProtocol = 1,
+    /// Complete the websocket handshake.
+    Ready = 2,
+    /// Keep the websocket connection alive.
+    Heartbeat = 3,
+    /// Describe the session, including the secret key to encrypt audio with.
+    SessionDescription = 4,
+    /// Indicate which users are speaking.
+    Speaking = 5,
+    /// Acknowledge a received heartbeat.
+    HeartbeatAck = 6,
+    /// Resume a connection.
+    Resume = 7,
+    /// Time to wait between sending heartbeats in milliseconds.
+    Hello = 8,
+    /// Acknowledge a successful session resume.
+    Resumed = 9,
+}
+
+bitflags! {
+    /// How audio is being transmitted, sent in [`Speaking`] payloads.
+    ///
+    /// [`Speaking`]: struct.Speaking.html
+    pub struct SpeakingFlags: u8 {
+        /// Normal transmission of voice audio.
+        const MICROPHONE = 1;
+        /// Transmission of context audio for video, no speaking indicator.
+        const SOUNDSHARE = 1 << 1;
+        /// Priority speaker, lowering audio of other speakers.
+        const PRIORITY = 1 << 2;
+    }
+}
+
+impl<'de> Deserialize<'de> for SpeakingFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from_bits_truncate(u8::deserialize(deserializer)?))
+    }
+}
+
+impl Serialize for SpeakingFlags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.bits())
+    }
+}
+
+/// Begin a voice websocket connection.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Identify {
+    /// Data of the payload.
+    pub d: IdentifyInfo,
+    /// Opcode of the payload.
+    pub op: OpCode,
+}
+
+impl Identify {
+    /// Create a new identify payload.
+    pub fn new(info: IdentifyInfo) -> Self {
+        Self {
+            d: info,
+            op: OpCode::Identify,
+        }
+    }
+}
+
+/// Data of an [`Identify`] payload.
+///
+/// [`Identify`]: struct.Identify.html
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct IdentifyInfo {
+    /// ID of the guild the voice connection is in.
+    pub server_id: GuildId,
+    /// Session ID of the current user's voice state.
+    pub session_id: String,
+    /// Token from the voice server update.
+    pub token: String,
+    /// ID of the current user.
+    pub user_id: UserId,
+}
+
+/// Select the protocol used to send audio after IP discovery.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SelectProtocol {
+    /// Data of the payload.
+    pub d: SelectProtocolInfo,
+    /// Opcode of the payload.
+    pub op: OpCode,
+}
+
+impl SelectProtocol {
+    /// Create a new payload selecting UDP as the protocol.
+    pub fn new(address: impl Into<String>, port: u16, mode: impl Into<String>) -> Self {
+        Self {
+            d: SelectProtocolInfo {
+                data: SelectProtocolData {
+                    address: address.into(),
+                    mode: mode.into(),
+                    port,
+                },
+                protocol: "udp".to_owned(),
+            },
+            op: OpCode::SelectProtocol,
+        }
+    }
+}
+
+/// Data of a [`SelectProtocol`] payload.
+///
+/// [`SelectProtocol`]: struct.SelectProtocol.html
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SelectProtocolInfo {
+    /// Details of the protocol.
+    pub data: SelectProtocolData,
+    /// Name of the protocol, which is always `udp`.
+    pub protocol: String,
+}
+
+/// Details of the UDP protocol selected by a [`SelectProtocol`] payload.
+///
+/// [`SelectProtocol`]: struct.SelectProtocol.html
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SelectProtocolData {
+    /// External address of the client found via IP discovery.
+    pub address: String,
+    /// Encryption mode of the audio packets.
+    pub mode: String,
+    /// External port of the client found via IP discovery.
+    pub port: u16,
+}
+
+/// Keep the websocket connection alive.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Heartbeat {
+    /// Nonce which the heartbeat acknowledgement will contain.
+    pub d: u64,
+    /// Opcode of the payload.
+    pub op: OpCode,
+}
+
+impl Heartbeat {
+    /// Create a new heartbeat payload with a nonce.
+    pub fn new(nonce: u64) -> Self {
+        Self {
+            d: nonce,
+            op: OpCode::Heartbeat,
+        }
+    }
+}
+
+/// Indicate whether the client is speaking.
+///
+/// This must be sent at least once before sending audio.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Speaking {
+    /// Data of the payload.
+    pub d: SpeakingInfo,
+    /// Opcode of the payload.
+    pub op: OpCode,
+}
+
+impl Speaking {
+    /// Create a new speaking payload.
+    pub fn new(speaking: SpeakingFlags, ssrc: u32) -> Self {
+        Self {
+            d: SpeakingInfo {
+                delay: 0,
+                speaking,
+                ssrc,
+            },
+            op: OpCode::Speaking,
+        }
+    }
+}
+
+/// Data of a [`Speaking`] payload.
+///
+/// [`Speaking`]: struct.Speaking.html
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SpeakingInfo {
+    /// Delay of the audio, which is always 0 for bots.
+    #[serde(default)]
+    pub delay: u64,
+    /// How the audio is being transmitted.
+    pub speaking: SpeakingFlags,
+    /// SSRC of the audio source.
+    pub ssrc: u32,
+}
+
+/// Resume a voice websocket connection.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Resume {
+    /// Data of the payload.
+    pub d: ResumeInfo,
+    /// Opcode of the payload.
+    pub op: OpCode,
+}
+
+impl Resume {
+    /// Create a new resume payload.
+    pub fn new(info: ResumeInfo) -> Self {
+        Self {
+            d: info,
+            op: OpCode::Resume,
+        }
+    }
+}
+
+/// Data of a [`Resume`] payload.
+///
+/// [`Resume`]: struct.Resume.html
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ResumeInfo {
+    /// ID of the guild the voice connection is in.
+    pub server_id: GuildId,
+    /// Session ID of the current user's voice state.
+    pub session_id: String,
+    /// Token from the voice server update.
+    pub token: String,
+}
+
+/// Information about the voice server, received after identifying.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Ready {
+    /// IP address of the voice UDP server.
+    pub ip: String,
+    /// Supported encryption modes.
+    pub modes: Vec<String>,
+    /// Port of the voice UDP server.
+    pub port: u16,
+    /// SSRC assigned to the connection.
+    pub ssrc: u32,
+}
+
+/// Description of the session, received after selecting the protocol.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SessionDescription {
+    /// Encryption mode that was selected.
+    pub mode: String,
+    /// Secret key used to encrypt audio packets.
+    pub secret_key: [u8; 32],
+}
+
+/// Interval at which heartbeats must be sent, received upon connecting.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Hello {
+    /// Time to wait between sending heartbeats in milliseconds.
+    pub heartbeat_interval: f64,
+}
+
+/// Event received over a voice websocket connection.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum VoiceEvent {
+    /// A heartbeat was acknowledged, containing its nonce.
+    HeartbeatAck(u64),
+    /// The connection was opened.
+    Hello(Hello),
+    /// The connection has been identified.
+    Ready(Ready),
+    /// The session was resumed.
+    Resumed,
+    /// The protocol was selected.
+    SessionDescription(SessionDescription),
+    /// A user's speaking state changed.
+    Speaking(SpeakingInfo),
+}
+
+impl<'de> Deserialize<'de> for VoiceEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Payload {
+            d: Value,
+            op: u8,
+        }
+
+        let Payload { d, op } = Payload::deserialize(deserializer)?;
+
+        let event = match op {
+            2 => Self::Ready(Ready::deserialize(d).map_err(DeError::custom)?),
+            4 => Self::SessionDescription(
+                SessionDescription::deserialize(d).map_err(DeError::custom)?,
+            ),
+            5 => Self::Speaking(SpeakingInfo::deserialize(d).map_err(DeError::custom)?),
+            6 => Self::HeartbeatAck(u64::deserialize(d).map_err(DeError::custom)?),
+            8 => Self::Hello(Hello::deserialize(d).map_err(DeError::custom)?),
+            9 => Self::Resumed,
+            other => return Err(DeError::custom(format!("unknown opcode {}", other))),
+        };
+
+        Ok(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Heartbeat, Identify, IdentifyInfo, SelectProtocol, Speaking, SpeakingFlags, VoiceEvent,
+    };
+    use serde_test::Token;
+    use twilight_model::id::{GuildId, UserId};
+
+    #[test]
+    fn test_identify() {
+        let value = Identify::new(IdentifyInfo {
+            server_id: GuildId(1),
+            session_id: "session".to_owned(),
+            token: "token".to_owned(),
+            user_id: UserId(2),
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Identify",
+                    len: 2,
+                },
+                Token::Str("d"),
+                Token::Struct {
+                    name: "IdentifyInfo",
+                    len: 4,
+                },
+                Token::Str("server_id"),
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("1"),
+                Token::Str("session_id"),
+                Token::Str("session"),
+                Token::Str("token"),
+                Token::Str("token"),
+                Token::Str("user_id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("2"),
+                Token::StructEnd,
+                Token::Str("op"),
+                Token::U8(0),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_outgoing_json() {
+        assert_eq!(
+            r#"{"d":{"data":{"address":"127.0.0.1","mode":"xsalsa20_poly1305","port":1234},"protocol":"udp"},"op":1}"#,
+            serde_json::to_string(&SelectProtocol::new("127.0.0.1", 1234, "xsalsa20_poly1305"))
+                .unwrap(),
+        );
+        assert_eq!(
+            r#"{"d":{"delay":0,"speaking":1,"ssrc":5},"op":5}"#,
+            serde_json::to_string(&Speaking::new(SpeakingFlags::MICROPHONE, 5)).unwrap(),
+        );
+        assert_eq!(
+            r#"{"d":7,"op":3}"#,
+            serde_json::to_string(&Heartbeat::new(7)).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_voice_event() {
+        let ready = r#"{"op":2,"d":{"ssrc":1,"ip":"127.0.0.1","port":1234,"modes":["xsalsa20_poly1305","xsalsa20_poly1305_suffix"],"heartbeat_interval":1}}"#;
+
+        match serde_json::from_str(ready).unwrap() {
+            VoiceEvent::Ready(ready) => {
+                assert_eq!(1, ready.ssrc);
+                assert_eq!(1234, ready.port);
+                assert_eq!(2, ready.modes.len());
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let hello = r#"{"op":8,"d":{"heartbeat_interval":41250.0}}"#;
+
+        match serde_json::from_str(hello).unwrap() {
+            VoiceEvent::Hello(hello) => assert!((hello.heartbeat_interval - 41250.0).abs() < 1.0),
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        let session = format!(
+            r#"{{"op":4,"d":{{"mode":"xsalsa20_poly1305","secret_key":{:?}}}}}"#,
+            [3u8; 32],
+        );
+
+        match serde_json::from_str(&session).unwrap() {
+            VoiceEvent::SessionDescription(session) => assert_eq!([3; 32], session.secret_key),
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        assert_eq!(
+            VoiceEvent::HeartbeatAck(7),
+            serde_json::from_str(r#"{"op":6,"d":7}"#).unwrap(),
+        );
+        assert!(serde_json::from_str::<VoiceEvent>(r#"{"op":100,"d":null}"#).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+//! Packets sent over the voice UDP connection.
+
+use crate::crypto::{self, KEY_LEN, NONCE_LEN, TAG_LEN};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    net::{IpAddr, SocketAddr},
+    str,
+};
+
+/// Length of an IP discovery request and response.
+pub const IP_DISCOVERY_LEN: usize = 74;
+
+/// Length of an RTP header.
+pub const RTP_HEADER_LEN: usize = 12;
+
+/// Create an IP discovery request for an SSRC.
+pub fn ip_discovery_request(ssrc: u32) -> [u8; IP_DISCOVERY_LEN] {
+    let mut packet = [0; IP_DISCOVERY_LEN];
+    // Request type, followed by the length of the rest of the packet.
+    packet[..2].copy_from_slice(&1u16.to_be_bytes());
+    packet[2..4].copy_from_slice(&70u16.to_be_bytes());
+    packet[4..8].copy_from_slice(&ssrc.to_be_bytes());
+
+    packet
+}
+
+/// Parse the external address of the client from an IP discovery response.
+///
+/// Returns `None` if the response is malformed.
+pub fn parse_ip_discovery_response(packet: &[u8]) -> Option<SocketAddr> {
+    if packet.len() != IP_DISCOVERY_LEN || packet[..2] != 2u16.to_be_bytes() {
+        return None;
+    }
+
+    // The address is a null-terminated string.
+    let address = &packet[8..72];
+    let end = address.iter().position(|byte| *byte == 0)?;
+    let ip = str::from_utf8(&address[..end])
+        .ok()?
+        .parse::<IpAddr>()
+        .ok()?;
+    let port = u16::from_be_bytes([packet[72], packet[73]]);
+
+    Some(SocketAddr::new(ip, port))
+}
+
+/// State of the RTP stream of audio being sent.
+pub struct RtpState {
+    pub secret_key: [u8; KEY_LEN],
+    pub sequence: u16,
+    pub ssrc: u32,
+    pub timestamp: u32,
+}
+
+impl Debug for RtpState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        // Don't leak the secret key in logs.
+        f.debug_struct("RtpState")
+            .field("sequence", &self.sequence)
+            .field("ssrc", &self.ssrc)
+            .field("timestamp", &self.timestamp)
+            .finish()
+    }
+}
+
+impl RtpState {
+    /// Number of samples per channel in a 20 millisecond frame at 48kHz.
+    const SAMPLES_PER_FRAME: u32 = 960;
+
+    /// Create an encrypted packet of an Opus frame, advancing the state to the
+    /// next frame.
+    ///
+    /// Returns `None` if the frame couldn't be encrypted.
+    pub fn packet(&mut self, frame: &[u8]) -> Option<Vec<u8>> {
+        let mut header = [0; RTP_HEADER_LEN];
+        header[0] = 0x80;
+        header[1] = 0x78;
+        header[2..4].copy_from_slice(&self.sequence.to_be_bytes());
+        header[4..8].copy_from_slice(&self.timestamp.to_be_bytes());
+        header[8..12].copy_from_slice(&self.ssrc.to_be_bytes());
+
+        // The `xsalsa20_poly1305` mode uses the header padded with zeroes as
+        // the nonce.
+        let mut nonce = [0; NONCE_LEN];
+        nonce[..RTP_HEADER_LEN].copy_from_slice(&header);
+
+        let mut packet = Vec::with_capacity(RTP_HEADER_LEN + TAG_LEN + frame.len());
+        packet.extend_from_slice(&header);
+        packet.extend_from_slice(&[0; TAG_LEN]);
+        packet.extend_from_slice(frame);
+
+        let tag = crypto::seal(
+            &self.secret_key,
+            &nonce,
+            &mut packet[RTP_HEADER_LEN + TAG_LEN..],
+        )?;
+        packet[RTP_HEADER_LEN..RTP_HEADER_LEN + TAG_LEN].copy_from_slice(&tag);
+
+        self.sequence = self.sequence.wrapping_add(1);
+        self.timestamp = self.timestamp.wrapping_add(Self::SAMPLES_PER_FRAME);
+
+        Some(packet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ip_discovery_request, parse_ip_discovery_response, RtpState, IP_DISCOVERY_LEN,
+        RTP_HEADER_LEN,
+    };
+    use crate::crypto::TAG_LEN;
+    use std::net::SocketAddr;
+
+    #[test]
+    fn test_ip_discovery() {
+        let request = ip_discovery_request(0x0102_0304);
+        assert_eq!([0, 1, 0, 70, 1, 2, 3, 4], request[..8]);
+        assert!(request[8..].iter().all(|byte| *byte == 0));
+
+        let mut response = [0; IP_DISCOVERY_LEN];
+        response[..8].copy_from_slice(&[0, 2, 0, 70, 1, 2, 3, 4]);
+        response[8..17].copy_from_slice(b"127.0.0.1");
+        response[72..].copy_from_slice(&50000u16.to_be_bytes());
+
+        assert_eq!(
+            Some("127.0.0.1:50000".parse::<SocketAddr>().unwrap()),
+            parse_ip_discovery_response(&response),
+        );
+        assert!(parse_ip_discovery_response(&request).is_none());
+        assert!(parse_ip_discovery_response(&response[..10]).is_none());
+    }
+
+    #[test]
+    fn test_rtp_packet() {
+        let mut state = RtpState {
+            secret_key: [1; 32],
+            sequence: u16::MAX,
+            ssrc: 5,
+            timestamp: 0,
+        };
+
+        let frame = [0xf8, 0xff, 0xfe];
+        let packet = state.packet(&frame).unwrap();
+
+        assert_eq!(RTP_HEADER_LEN + TAG_LEN + frame.len(), packet.len());
+        assert_eq!(
+            [0x80, 0x78, 0xff, 0xff, 0, 0, 0, 0, 0, 0, 0, 5],
+            packet[..12]
+        );
+        assert_ne!(frame, packet[RTP_HEADER_LEN + TAG_LEN..]);
+
+        let next = state.packet(&frame).unwrap();
+        assert_eq!([0, 0, 0, 0, 3, 0xc0], next[2..8]);
+        assert_ne!(packet[RTP_HEADER_LEN..], next[RTP_HEADER_LEN..]);
+    }
+}